@@ -81,7 +81,7 @@ impl<'a, S: Simd> NumberParserSimd<'a, S> {
                 let chars = S::Vi8::load_from_slice(self.string);
                 let mask = next_simd_match_vec(chars);
 
-                if let Some(index) = mask.index_of_first_truthy() {
+                if let Some(index) = mask.first_set() {
                     self.string = &self.string[index..];
                     break;
                 } else {
@@ -101,7 +101,7 @@ impl<'a, S: Simd> NumberParserSimd<'a, S> {
                 let chars = S::Vi8::load_from_slice(number_end_str);
                 let mask = next_simd_match_vec(chars);
 
-                if let Some(index) = mask.index_of_first_falsy() {
+                if let Some(index) = (!mask).first_set() {
                     number_end_str = &number_end_str[index..];
                     num_length += index;
                     break;
@@ -1,6 +1,8 @@
 use criterion::{criterion_group, criterion_main, Criterion};
 use std::time::Duration;
 
+#[path = "simd_math/core_extended.rs"]
+mod core_extended;
 #[path = "simd_math/f64_core.rs"]
 mod f64_core;
 #[path = "simd_math/hyperbolic.rs"]
@@ -20,6 +22,7 @@ fn criterion_benchmark(c: &mut Criterion) {
     hyperbolic::register(c);
     inverse_trig::register(c);
     trig::register(c);
+    core_extended::register(c);
 }
 
 criterion_group! {
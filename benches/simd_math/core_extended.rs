@@ -0,0 +1,228 @@
+//! Benches for the pow, cbrt, exp10, expm1, log1p and pi-scaled trig kernels against native
+//! scalar `std` calls, for both f32 and f64.
+
+use criterion::Criterion;
+
+use crate::shared::{self, BenchTargets, BenchTargetsF64, INPUT_LEN};
+
+const POW_EXPONENT: f64 = 2.5;
+const POWI_EXPONENT: i32 = 7;
+
+macro_rules! bench_kernel {
+    ($name:ident, f32, $scalar:expr, $simd:expr) => {
+        mod $name {
+            use simdeez::math::SimdMathF32Core;
+            use simdeez::{prelude::*, simd_unsafe_generate_all};
+
+            use crate::shared;
+
+            #[inline(never)]
+            fn scalar_native(input: &[f32]) -> f32 {
+                input.iter().copied().map($scalar).sum()
+            }
+
+            simd_unsafe_generate_all!(
+                fn simdeez(input: &[f32]) -> f32 {
+                    shared::simdeez_sum_impl::<S>(input, $simd)
+                }
+            );
+
+            #[cfg(not(any(target_arch = "x86_64", target_arch = "x86")))]
+            #[inline(never)]
+            fn simdeez_scalar(input: &[f32]) -> f32 {
+                shared::force_scalar_sum(input, $simd)
+            }
+
+            pub fn targets() -> shared::BenchTargets {
+                shared::BenchTargets {
+                    scalar_native,
+                    simdeez_runtime: simdeez,
+                    simdeez_scalar,
+                    #[cfg(any(target_arch = "x86_64", target_arch = "x86"))]
+                    simdeez_sse2,
+                    #[cfg(any(target_arch = "x86_64", target_arch = "x86"))]
+                    simdeez_sse41,
+                    #[cfg(any(target_arch = "x86_64", target_arch = "x86"))]
+                    simdeez_avx2,
+                    #[cfg(any(target_arch = "x86_64", target_arch = "x86"))]
+                    simdeez_avx512,
+                }
+            }
+        }
+    };
+    ($name:ident, f64, $scalar:expr, $simd:expr) => {
+        mod $name {
+            use simdeez::math::SimdMathF64Core;
+            use simdeez::{prelude::*, simd_unsafe_generate_all};
+
+            use crate::shared;
+
+            #[inline(never)]
+            fn scalar_native(input: &[f64]) -> f64 {
+                input.iter().copied().map($scalar).sum()
+            }
+
+            simd_unsafe_generate_all!(
+                fn simdeez(input: &[f64]) -> f64 {
+                    shared::simdeez_sum_impl_f64::<S>(input, $simd)
+                }
+            );
+
+            #[cfg(not(any(target_arch = "x86_64", target_arch = "x86")))]
+            #[inline(never)]
+            fn simdeez_scalar(input: &[f64]) -> f64 {
+                shared::force_scalar_sum_f64(input, $simd)
+            }
+
+            pub fn targets() -> shared::BenchTargetsF64 {
+                shared::BenchTargetsF64 {
+                    scalar_native,
+                    simdeez_runtime: simdeez,
+                    simdeez_scalar,
+                    #[cfg(any(target_arch = "x86_64", target_arch = "x86"))]
+                    simdeez_sse2,
+                    #[cfg(any(target_arch = "x86_64", target_arch = "x86"))]
+                    simdeez_sse41,
+                    #[cfg(any(target_arch = "x86_64", target_arch = "x86"))]
+                    simdeez_avx2,
+                    #[cfg(any(target_arch = "x86_64", target_arch = "x86"))]
+                    simdeez_avx512,
+                }
+            }
+        }
+    };
+}
+
+bench_kernel!(
+    pow_f32,
+    f32,
+    |x: f32| x.powf(super::POW_EXPONENT as f32),
+    |v| v.pow_u35(SimdBaseIo::set1(super::POW_EXPONENT as f32))
+);
+bench_kernel!(powi_f32, f32, |x: f32| x.powi(super::POWI_EXPONENT), |v| v
+    .powi(super::POWI_EXPONENT));
+bench_kernel!(cbrt_f32, f32, f32::cbrt, |v| v.cbrt_u35());
+bench_kernel!(exp10_f32, f32, |x: f32| 10.0f32.powf(x), |v| v.exp10_u35());
+bench_kernel!(expm1_f32, f32, f32::exp_m1, |v| v.expm1_u35());
+bench_kernel!(log1p_f32, f32, f32::ln_1p, |v| v.log1p_u35());
+bench_kernel!(
+    sincos_f32,
+    f32,
+    |x: f32| {
+        let (s, c) = x.sin_cos();
+        s + c
+    },
+    |v| {
+        let (s, c) = v.sincos_u35();
+        s + c
+    }
+);
+bench_kernel!(
+    sinpi_f32,
+    f32,
+    |x: f32| (x * core::f32::consts::PI).sin(),
+    |v| v.sinpi_u35()
+);
+bench_kernel!(
+    cospi_f32,
+    f32,
+    |x: f32| (x * core::f32::consts::PI).cos(),
+    |v| v.cospi_u35()
+);
+bench_kernel!(
+    tanpi_f32,
+    f32,
+    |x: f32| (x * core::f32::consts::PI).tan(),
+    |v| v.tanpi_u35()
+);
+
+bench_kernel!(pow_f64, f64, |x: f64| x.powf(super::POW_EXPONENT), |v| v
+    .pow_u35(SimdBaseIo::set1(super::POW_EXPONENT)));
+bench_kernel!(powi_f64, f64, |x: f64| x.powi(super::POWI_EXPONENT), |v| v
+    .powi(super::POWI_EXPONENT));
+bench_kernel!(cbrt_f64, f64, f64::cbrt, |v| v.cbrt_u35());
+bench_kernel!(exp10_f64, f64, |x: f64| 10.0f64.powf(x), |v| v.exp10_u35());
+bench_kernel!(expm1_f64, f64, f64::exp_m1, |v| v.expm1_u35());
+bench_kernel!(log1p_f64, f64, f64::ln_1p, |v| v.log1p_u35());
+bench_kernel!(
+    sincos_f64,
+    f64,
+    |x: f64| {
+        let (s, c) = x.sin_cos();
+        s + c
+    },
+    |v| {
+        let (s, c) = v.sincos_u35();
+        s + c
+    }
+);
+bench_kernel!(
+    sinpi_f64,
+    f64,
+    |x: f64| (x * core::f64::consts::PI).sin(),
+    |v| v.sinpi_u35()
+);
+bench_kernel!(
+    cospi_f64,
+    f64,
+    |x: f64| (x * core::f64::consts::PI).cos(),
+    |v| v.cospi_u35()
+);
+bench_kernel!(
+    tanpi_f64,
+    f64,
+    |x: f64| (x * core::f64::consts::PI).tan(),
+    |v| v.tanpi_u35()
+);
+
+pub fn register(c: &mut Criterion) {
+    let pow_inputs = shared::make_unary_inputs(INPUT_LEN, 0xC0E1_0001, 0.01..100.0);
+    let cbrt_inputs = shared::make_unary_inputs(INPUT_LEN, 0xC0E1_0002, -1.0e6..1.0e6);
+    let exp10_inputs = shared::make_unary_inputs(INPUT_LEN, 0xC0E1_0003, -35.0..35.0);
+    let small_inputs = shared::make_unary_inputs(INPUT_LEN, 0xC0E1_0004, -0.9..4.0);
+    let trig_inputs = shared::make_trig_inputs(INPUT_LEN, 0xC0E1_0005);
+    let pi_inputs = shared::make_unary_inputs(INPUT_LEN, 0xC0E1_0006, -64.0..64.0);
+
+    let f32_kernels: [(&str, &[f32], BenchTargets); 10] = [
+        ("pow_u35", &pow_inputs, pow_f32::targets()),
+        ("powi", &pow_inputs, powi_f32::targets()),
+        ("cbrt_u35", &cbrt_inputs, cbrt_f32::targets()),
+        ("exp10_u35", &exp10_inputs, exp10_f32::targets()),
+        ("expm1_u35", &small_inputs, expm1_f32::targets()),
+        ("log1p_u35", &small_inputs, log1p_f32::targets()),
+        ("sincos_u35", &trig_inputs, sincos_f32::targets()),
+        ("sinpi_u35", &pi_inputs, sinpi_f32::targets()),
+        ("cospi_u35", &pi_inputs, cospi_f32::targets()),
+        ("tanpi_u35", &pi_inputs, tanpi_f32::targets()),
+    ];
+    for (name, input, targets) in f32_kernels {
+        shared::bench_variants(c, &format!("simd_math/f32/{name}"), input, targets);
+    }
+
+    let widen = |input: &[f32]| input.iter().map(|&x| f64::from(x)).collect::<Vec<_>>();
+    let exp10_inputs_f64 = shared::make_unary_inputs(INPUT_LEN, 0xC0E1_0007, -300.0..300.0);
+    let (pow_inputs, cbrt_inputs, exp10_inputs, small_inputs, pi_inputs) = (
+        widen(&pow_inputs),
+        widen(&cbrt_inputs),
+        widen(&exp10_inputs_f64),
+        widen(&small_inputs),
+        widen(&pi_inputs),
+    );
+    let trig_inputs = shared::make_trig_inputs_f64(INPUT_LEN, 0xC0E1_0008);
+
+    let f64_kernels: [(&str, &[f64], BenchTargetsF64); 10] = [
+        ("pow_u35", &pow_inputs, pow_f64::targets()),
+        ("powi", &pow_inputs, powi_f64::targets()),
+        ("cbrt_u35", &cbrt_inputs, cbrt_f64::targets()),
+        ("exp10_u35", &exp10_inputs, exp10_f64::targets()),
+        ("expm1_u35", &small_inputs, expm1_f64::targets()),
+        ("log1p_u35", &small_inputs, log1p_f64::targets()),
+        ("sincos_u35", &trig_inputs, sincos_f64::targets()),
+        ("sinpi_u35", &pi_inputs, sinpi_f64::targets()),
+        ("cospi_u35", &pi_inputs, cospi_f64::targets()),
+        ("tanpi_u35", &pi_inputs, tanpi_f64::targets()),
+    ];
+    for (name, input, targets) in f64_kernels {
+        shared::bench_variants_f64(c, &format!("simd_math/f64/{name}"), input, targets);
+    }
+}
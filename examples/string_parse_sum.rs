@@ -4,22 +4,17 @@ use simdeez::{prelude::*, simd_runtime_generate};
 
 simd_runtime_generate!(
     fn sum(source: &[f32], target: &mut [f32]) {
-        let mut source = &source[..source.len()];
-        let mut target = &mut target[..source.len()];
-
-        loop {
-            let src = S::Vf32::load_from_slice(source);
-            let src2 = S::Vf32::load_from_slice(target);
+        let len = source.len().min(target.len());
+        let sources = source[..len].chunks(S::Vf32::WIDTH);
+        let targets = target[..len].chunks_mut(S::Vf32::WIDTH);
+
+        // The last chunk may be short; the partial load mask keeps it in the same loop.
+        for (source, target) in sources.zip(targets) {
+            let (src, mask) = S::Vf32::load_partial(source);
+            let (src2, _) = S::Vf32::load_partial(target);
             let sum = src + src2;
 
-            sum.copy_to_slice(target);
-
-            source = &source[S::Vf32::WIDTH..];
-            target = &mut target[S::Vf32::WIDTH..];
-
-            if source.len() <= S::Vf32::WIDTH {
-                break;
-            }
+            sum.store_partial(target, mask);
         }
     }
 );
@@ -100,7 +95,7 @@ impl<'a, S: Simd> NumberParserSimd<'a, S> {
             let chars = S::Vi8::load_from_slice(self.string);
             let mask = next_simd_match_vec(chars);
 
-            if let Some(index) = mask.index_of_first_truthy() {
+            if let Some(index) = mask.first_set() {
                 self.string = &self.string[index..];
                 break;
             } else {
@@ -120,7 +115,7 @@ impl<'a, S: Simd> NumberParserSimd<'a, S> {
             let chars = S::Vi8::load_from_slice(number_end_str);
             let mask = next_simd_match_vec(chars);
 
-            if let Some(index) = mask.index_of_first_falsy() {
+            if let Some(index) = (!mask).first_set() {
                 number_end_str = &number_end_str[index..];
                 num_length += index;
                 break;
@@ -0,0 +1,150 @@
+use crate::{Simd, SimdBaseIo};
+
+/// Integer vectors that can index 32 bit lanes in gathers and scatters (`Vi32` and `Vu32`).
+pub trait SimdIndex32: SimdBaseIo {
+    /// Reinterprets the index lanes as `i32` offsets.
+    fn index_lanes(self) -> <Self::Engine as Simd>::Vi32;
+}
+
+/// Integer vectors that can index 64 bit lanes in gathers and scatters (`Vi64` and `Vu64`).
+pub trait SimdIndex64: SimdBaseIo {
+    /// Reinterprets the index lanes as `i64` offsets.
+    fn index_lanes(self) -> <Self::Engine as Simd>::Vi64;
+}
+
+macro_rules! define_simd_gather {
+    ($name:ident, $index:ident, $bits:literal $(, { $($extra:tt)* })?) => {
+        #[doc = concat!("Gathers and scatters of ", $bits, " bit lanes.")]
+        ///
+        /// Lane `i` reads or writes `base[indices[i]]`. Indices are element offsets read as signed
+        /// integers, so an unsigned index with the highest bit set is out of bounds.
+        ///
+        /// The safe variants panic if any active lane's index is negative or not below
+        /// `base.len()`. The `_unchecked` variants skip that check and require every active lane
+        /// to point inside the allocation behind `base`.
+        ///
+        /// Masked variants only touch memory for lanes that are set in `mask`. Scatters write lanes
+        /// from lowest to highest, so when several lanes share an index the highest lane wins.
+        pub trait $name: SimdBaseIo {
+            /// Loads lane `i` from `base[indices[i]]`.
+            fn gather<I: $index<Engine = Self::Engine>>(base: &[Self::Scalar], indices: I) -> Self;
+
+            /// Loads lane `i` from `base.offset(indices[i])` without bounds checks.
+            unsafe fn gather_unchecked<I: $index<Engine = Self::Engine>>(
+                base: *const Self::Scalar,
+                indices: I,
+            ) -> Self;
+
+            /// Loads lane `i` from `base[indices[i]]` where `mask` is set, and takes lane `i` of
+            /// `fallback` elsewhere. Indices of inactive lanes are not checked.
+            fn gather_masked<I: $index<Engine = Self::Engine>>(
+                base: &[Self::Scalar],
+                indices: I,
+                mask: Self::Mask,
+                fallback: Self,
+            ) -> Self;
+
+            /// Masked gather without bounds checks.
+            unsafe fn gather_masked_unchecked<I: $index<Engine = Self::Engine>>(
+                base: *const Self::Scalar,
+                indices: I,
+                mask: Self::Mask,
+                fallback: Self,
+            ) -> Self;
+
+            /// Stores lane `i` to `base[indices[i]]`.
+            fn scatter<I: $index<Engine = Self::Engine>>(
+                self,
+                base: &mut [Self::Scalar],
+                indices: I,
+            );
+
+            /// Stores lane `i` to `base.offset(indices[i])` without bounds checks.
+            unsafe fn scatter_unchecked<I: $index<Engine = Self::Engine>>(
+                self,
+                base: *mut Self::Scalar,
+                indices: I,
+            );
+
+            /// Stores lane `i` to `base[indices[i]]` where `mask` is set. Indices of inactive lanes
+            /// are not checked.
+            fn scatter_masked<I: $index<Engine = Self::Engine>>(
+                self,
+                base: &mut [Self::Scalar],
+                indices: I,
+                mask: Self::Mask,
+            );
+
+            /// Masked scatter without bounds checks.
+            unsafe fn scatter_masked_unchecked<I: $index<Engine = Self::Engine>>(
+                self,
+                base: *mut Self::Scalar,
+                indices: I,
+                mask: Self::Mask,
+            );
+            $($($extra)*)?
+        }
+    };
+}
+
+define_simd_gather!(SimdGather32, SimdIndex32, "32");
+define_simd_gather!(SimdGather64, SimdIndex64, "64", {
+    /// Loads lane `i` from `base[indices[i]]`, taking the indices from the low `WIDTH` lanes of
+    /// a 32 bit index vector like AVX2's `vgatherdpd`. Lanes of `indices` above those are ignored.
+    fn gather_i32<I: SimdIndex32<Engine = Self::Engine>>(base: &[Self::Scalar], indices: I)
+        -> Self;
+
+    /// [`gather_i32`](Self::gather_i32) without bounds checks.
+    unsafe fn gather_i32_unchecked<I: SimdIndex32<Engine = Self::Engine>>(
+        base: *const Self::Scalar,
+        indices: I,
+    ) -> Self;
+
+    /// [`gather_masked`](Self::gather_masked) with 32 bit indices, as in
+    /// [`gather_i32`](Self::gather_i32).
+    fn gather_masked_i32<I: SimdIndex32<Engine = Self::Engine>>(
+        base: &[Self::Scalar],
+        indices: I,
+        mask: Self::Mask,
+        fallback: Self,
+    ) -> Self;
+
+    /// [`gather_masked_i32`](Self::gather_masked_i32) without bounds checks.
+    unsafe fn gather_masked_i32_unchecked<I: SimdIndex32<Engine = Self::Engine>>(
+        base: *const Self::Scalar,
+        indices: I,
+        mask: Self::Mask,
+        fallback: Self,
+    ) -> Self;
+
+    /// [`scatter`](Self::scatter) with 32 bit indices, as in [`gather_i32`](Self::gather_i32).
+    fn scatter_i32<I: SimdIndex32<Engine = Self::Engine>>(
+        self,
+        base: &mut [Self::Scalar],
+        indices: I,
+    );
+
+    /// [`scatter_i32`](Self::scatter_i32) without bounds checks.
+    unsafe fn scatter_i32_unchecked<I: SimdIndex32<Engine = Self::Engine>>(
+        self,
+        base: *mut Self::Scalar,
+        indices: I,
+    );
+
+    /// [`scatter_masked`](Self::scatter_masked) with 32 bit indices, as in
+    /// [`gather_i32`](Self::gather_i32).
+    fn scatter_masked_i32<I: SimdIndex32<Engine = Self::Engine>>(
+        self,
+        base: &mut [Self::Scalar],
+        indices: I,
+        mask: Self::Mask,
+    );
+
+    /// [`scatter_masked_i32`](Self::scatter_masked_i32) without bounds checks.
+    unsafe fn scatter_masked_i32_unchecked<I: SimdIndex32<Engine = Self::Engine>>(
+        self,
+        base: *mut Self::Scalar,
+        indices: I,
+        mask: Self::Mask,
+    );
+});
@@ -31,6 +31,13 @@ pub trait SimdBaseIo: SimdConsts {
     /// Store a vector to an unaligned raw pointer.
     unsafe fn copy_to_ptr_unaligned(self, ptr: *mut Self::Scalar);
 
+    /// Load the lanes of a vector that are set in `mask`. Inactive lanes are zero and their
+    /// memory is never read, so `ptr` only needs to be valid for the active lanes.
+    unsafe fn masked_load(ptr: *const Self::Scalar, mask: Self::Mask) -> Self;
+    /// Store the lanes of a vector that are set in `mask`. Memory for inactive lanes is never
+    /// written, so `ptr` only needs to be valid for the active lanes.
+    unsafe fn masked_store(self, ptr: *mut Self::Scalar, mask: Self::Mask);
+
     /// Load a vector from a 32 bit aligned raw pointer.
     unsafe fn load_from_ptr_aligned(ptr: *const Self::Scalar) -> Self;
     /// Store a vector to a 32 bit aligned raw pointer.
@@ -111,4 +118,53 @@ pub trait SimdBaseIo: SimdConsts {
             }
         }
     }
+
+    /// Loads up to `WIDTH` values from the start of a slice, returning the vector together with a
+    /// mask of the lanes that were loaded. Lanes past the end of the slice are zero.
+    ///
+    /// Unlike [`load_from_slice`](SimdBaseIo::load_from_slice) this uses masked loads where the
+    /// backend has them, and the mask can be passed straight to
+    /// [`store_partial`](SimdBaseIo::store_partial) to handle slice tails in the main loop.
+    fn load_partial(slice: &[Self::Scalar]) -> (Self, Self::Mask);
+
+    /// Stores the lanes selected by `mask` to the start of a slice. Lanes that would land past
+    /// the end of the slice are skipped even if they are set in the mask.
+    fn store_partial(self, slice: &mut [Self::Scalar], mask: Self::Mask);
+
+    /// Loads `2 * WIDTH` values stored as interleaved pairs, such as stereo audio frames, and
+    /// returns one vector per channel: value `2 * i + c` of the slice becomes lane `i` of vector
+    /// `c`. This is `vld2` on Neon and lane shuffles elsewhere.
+    ///
+    /// Panics if the slice is shorter than `2 * WIDTH`.
+    fn load_interleaved2(slice: &[Self::Scalar]) -> (Self, Self);
+
+    /// Loads `3 * WIDTH` values stored as interleaved triples, such as RGB pixels or xyz points,
+    /// and returns one vector per channel. This is `vld3` on Neon and lane shuffles elsewhere.
+    ///
+    /// Panics if the slice is shorter than `3 * WIDTH`.
+    fn load_interleaved3(slice: &[Self::Scalar]) -> (Self, Self, Self);
+
+    /// Loads `4 * WIDTH` values stored as interleaved quadruples, such as RGBA pixels, and
+    /// returns one vector per channel. This is `vld4` on Neon and lane shuffles elsewhere.
+    ///
+    /// Panics if the slice is shorter than `4 * WIDTH`.
+    fn load_interleaved4(slice: &[Self::Scalar]) -> (Self, Self, Self, Self);
+
+    /// Stores one vector per channel as `2 * WIDTH` interleaved values, undoing
+    /// [`load_interleaved2`](SimdBaseIo::load_interleaved2).
+    ///
+    /// Panics if the slice is shorter than `2 * WIDTH`.
+    fn store_interleaved2(vectors: (Self, Self), slice: &mut [Self::Scalar]);
+
+    /// Stores one vector per channel as `3 * WIDTH` interleaved values, undoing
+    /// [`load_interleaved3`](SimdBaseIo::load_interleaved3).
+    ///
+    /// Panics if the slice is shorter than `3 * WIDTH`.
+    fn store_interleaved3(vectors: (Self, Self, Self), slice: &mut [Self::Scalar]);
+
+    /// Stores one vector per channel as `4 * WIDTH` interleaved values, undoing
+    /// [`load_interleaved4`](SimdBaseIo::load_interleaved4).
+    ///
+    /// Panics if the slice is shorter than `4 * WIDTH`.
+    fn store_interleaved4(vectors: (Self, Self, Self, Self), slice: &mut [Self::Scalar]);
 }
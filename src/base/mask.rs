@@ -0,0 +1,322 @@
+use core::fmt::Debug;
+use core::marker::PhantomData;
+use core::ops::*;
+
+use crate::{SimdBaseOps, SimdConsts};
+
+/// A per-lane boolean mask for the vector type [`Vector`](SimdMask::Vector).
+///
+/// Masks are produced by the `cmp_*` functions and consumed by [`select`](SimdMask::select),
+/// masked memory operations and gathers. Because every vector type has its own mask type,
+/// mixing up masks of different lane widths is a type error rather than a backend-dependent
+/// result.
+///
+/// Bitmasks use bit `i` for lane `i`, so they fit in a `u64` for every supported width.
+pub trait SimdMask:
+    'static
+    + Copy
+    + Debug
+    + Send
+    + Sync
+    + BitAnd<Output = Self>
+    + BitAndAssign
+    + BitOr<Output = Self>
+    + BitOrAssign
+    + BitXor<Output = Self>
+    + BitXorAssign
+    + Not<Output = Self>
+{
+    /// The vector type this mask selects lanes of.
+    type Vector: SimdConsts<Mask = Self>;
+
+    /// Creates a mask with every lane set to `value`.
+    #[inline(always)]
+    fn splat(value: bool) -> Self {
+        Self::from_bitmask(if value { u64::MAX } else { 0 })
+    }
+
+    /// Creates a mask from a bitmask where bit `i` selects lane `i`. Bits above the lane count
+    /// are ignored.
+    fn from_bitmask(bits: u64) -> Self;
+
+    /// Returns a bitmask where bit `i` is set if lane `i` is set.
+    fn to_bitmask(self) -> u64;
+
+    /// Creates a mask from a vector, setting the lanes whose highest bit is set.
+    fn from_vector(vector: Self::Vector) -> Self;
+
+    /// Expands the mask into a vector with all bits set in the set lanes and all bits clear in
+    /// the others. This is the representation accepted by [`SimdBaseOps::blendv`].
+    fn to_vector(self) -> Self::Vector;
+
+    /// Selects lanes from `if_true` where the mask is set and from `if_false` elsewhere.
+    fn select(self, if_true: Self::Vector, if_false: Self::Vector) -> Self::Vector;
+
+    /// Returns true if any lane is set.
+    #[inline(always)]
+    fn any(self) -> bool {
+        self.to_bitmask() != 0
+    }
+
+    /// Returns true if every lane is set.
+    #[inline(always)]
+    fn all(self) -> bool {
+        self.to_bitmask() == lane_bits(<Self::Vector as SimdConsts>::WIDTH)
+    }
+
+    /// Returns true if no lane is set.
+    #[inline(always)]
+    fn none(self) -> bool {
+        self.to_bitmask() == 0
+    }
+
+    /// Counts the set lanes.
+    #[inline(always)]
+    fn count_ones(self) -> u32 {
+        self.to_bitmask().count_ones()
+    }
+
+    /// Returns the index of the first set lane, if any.
+    #[inline(always)]
+    fn first_set(self) -> Option<usize> {
+        let bits = self.to_bitmask();
+        if bits == 0 {
+            None
+        } else {
+            Some(bits.trailing_zeros() as usize)
+        }
+    }
+
+    /// Returns true if lane `index` is set.
+    #[inline(always)]
+    fn test(self, index: usize) -> bool {
+        assert!(index < <Self::Vector as SimdConsts>::WIDTH);
+        self.to_bitmask() >> index & 1 != 0
+    }
+}
+
+/// A bitmask with the low `width` bits set.
+#[inline(always)]
+pub(crate) fn lane_bits(width: usize) -> u64 {
+    if width >= 64 {
+        u64::MAX
+    } else {
+        (1 << width) - 1
+    }
+}
+
+/// For every byte value, the positions of its set bits from the lowest, one per byte.
+const COMPRESS_LUT: [u64; 256] = {
+    let mut lut = [0; 256];
+    let mut byte = 0;
+    while byte < 256 {
+        let (mut bit, mut count) = (0, 0);
+        while bit < 8 {
+            if byte >> bit & 1 != 0 {
+                lut[byte] |= (bit as u64) << (8 * count);
+                count += 1;
+            }
+            bit += 1;
+        }
+        byte += 1;
+    }
+    lut
+};
+
+/// For every byte value, the rank of each set bit among the set bits below it, and 0x80 for
+/// the clear bits.
+const EXPAND_LUT: [u64; 256] = {
+    let mut lut = [0; 256];
+    let mut byte = 0;
+    while byte < 256 {
+        let (mut bit, mut count) = (0, 0);
+        while bit < 8 {
+            if byte >> bit & 1 != 0 {
+                lut[byte] |= count << (8 * bit);
+                count += 1;
+            } else {
+                lut[byte] |= 0x80 << (8 * bit);
+            }
+            bit += 1;
+        }
+        byte += 1;
+    }
+    lut
+};
+
+/// Lane indices that pack the lanes set in `bits` to the front of a `width` lane vector, and
+/// how many lanes are set. The remaining indices are `width`, which `permute2` reads from its
+/// second vector. Built 8 lanes at a time from [`COMPRESS_LUT`].
+#[inline(always)]
+pub(crate) fn compress_indices(bits: u64, width: usize) -> ([u8; 72], usize) {
+    let mut indices = [width as u8; 72];
+    let mut count = 0;
+    for chunk in 0..width.div_ceil(8) {
+        let byte = (bits >> (8 * chunk)) as u8;
+        let entry = COMPRESS_LUT[byte as usize] + 0x0808_0808_0808_0808 * chunk as u64;
+        indices[count..count + 8].copy_from_slice(&entry.to_le_bytes());
+        count += byte.count_ones() as usize;
+    }
+    indices[count..count + 8].fill(width as u8);
+    (indices, count)
+}
+
+/// Lane indices that spread the low lanes of a `width` lane vector over the lanes set in
+/// `bits`, in order. Clear lanes get `width`, which `permute2` reads from its second vector.
+/// Built 8 lanes at a time from [`EXPAND_LUT`].
+#[inline(always)]
+pub(crate) fn expand_indices(bits: u64, width: usize) -> [u8; 64] {
+    let mut indices = [0; 64];
+    let mut count = 0;
+    for chunk in 0..width.div_ceil(8) {
+        let byte = (bits >> (8 * chunk)) as u8;
+        // Ranks stay below 0x80 after adding the lanes before this chunk, so the high bit of
+        // every byte still marks the clear lanes.
+        let entry = EXPAND_LUT[byte as usize] + 0x0101_0101_0101_0101 * count;
+        let clear = (entry >> 7 & 0x0101_0101_0101_0101) * 0xFF;
+        let entry = (entry & !clear) | (0x0101_0101_0101_0101 * width as u64) & clear;
+        indices[8 * chunk..8 * chunk + 8].copy_from_slice(&entry.to_le_bytes());
+        count += byte.count_ones() as u64;
+    }
+    indices
+}
+
+/// A mask stored as a vector with all bits set in the set lanes, used by engines without
+/// dedicated mask registers.
+#[derive(Copy, Clone, Debug)]
+#[repr(transparent)]
+pub struct LaneMask<V>(pub(crate) V);
+
+impl<V> LaneMask<V> {
+    /// Wraps a compare result, whose lanes are already all set or all clear.
+    #[inline(always)]
+    pub(crate) fn from_compare(vector: V) -> Self {
+        Self(vector)
+    }
+}
+
+impl<V: SimdBaseOps> BitAnd for LaneMask<V> {
+    type Output = Self;
+
+    #[inline(always)]
+    fn bitand(self, rhs: Self) -> Self {
+        Self(self.0.bit_and(rhs.0))
+    }
+}
+
+impl<V: SimdBaseOps> BitAndAssign for LaneMask<V> {
+    #[inline(always)]
+    fn bitand_assign(&mut self, rhs: Self) {
+        *self = *self & rhs;
+    }
+}
+
+impl<V: SimdBaseOps> BitOr for LaneMask<V> {
+    type Output = Self;
+
+    #[inline(always)]
+    fn bitor(self, rhs: Self) -> Self {
+        Self(self.0.bit_or(rhs.0))
+    }
+}
+
+impl<V: SimdBaseOps> BitOrAssign for LaneMask<V> {
+    #[inline(always)]
+    fn bitor_assign(&mut self, rhs: Self) {
+        *self = *self | rhs;
+    }
+}
+
+impl<V: SimdBaseOps> BitXor for LaneMask<V> {
+    type Output = Self;
+
+    #[inline(always)]
+    fn bitxor(self, rhs: Self) -> Self {
+        Self(self.0.bit_xor(rhs.0))
+    }
+}
+
+impl<V: SimdBaseOps> BitXorAssign for LaneMask<V> {
+    #[inline(always)]
+    fn bitxor_assign(&mut self, rhs: Self) {
+        *self = *self ^ rhs;
+    }
+}
+
+impl<V: SimdBaseOps> Not for LaneMask<V> {
+    type Output = Self;
+
+    #[inline(always)]
+    fn not(self) -> Self {
+        Self(self.0.bit_not())
+    }
+}
+
+/// A mask stored as one bit per lane, matching the AVX-512 `__mmask*` registers.
+#[derive(Copy, Clone, Debug)]
+pub struct KMask<V>(pub(crate) u64, PhantomData<V>);
+
+impl<V: SimdConsts> KMask<V> {
+    /// Creates a mask from a bitmask, clearing the bits above the lane count.
+    #[inline(always)]
+    pub(crate) fn from_bits(bits: u64) -> Self {
+        Self(bits & lane_bits(V::WIDTH), PhantomData)
+    }
+}
+
+impl<V: SimdConsts> BitAnd for KMask<V> {
+    type Output = Self;
+
+    #[inline(always)]
+    fn bitand(self, rhs: Self) -> Self {
+        Self(self.0 & rhs.0, PhantomData)
+    }
+}
+
+impl<V: SimdConsts> BitAndAssign for KMask<V> {
+    #[inline(always)]
+    fn bitand_assign(&mut self, rhs: Self) {
+        *self = *self & rhs;
+    }
+}
+
+impl<V: SimdConsts> BitOr for KMask<V> {
+    type Output = Self;
+
+    #[inline(always)]
+    fn bitor(self, rhs: Self) -> Self {
+        Self(self.0 | rhs.0, PhantomData)
+    }
+}
+
+impl<V: SimdConsts> BitOrAssign for KMask<V> {
+    #[inline(always)]
+    fn bitor_assign(&mut self, rhs: Self) {
+        *self = *self | rhs;
+    }
+}
+
+impl<V: SimdConsts> BitXor for KMask<V> {
+    type Output = Self;
+
+    #[inline(always)]
+    fn bitxor(self, rhs: Self) -> Self {
+        Self(self.0 ^ rhs.0, PhantomData)
+    }
+}
+
+impl<V: SimdConsts> BitXorAssign for KMask<V> {
+    #[inline(always)]
+    fn bitxor_assign(&mut self, rhs: Self) {
+        *self = *self ^ rhs;
+    }
+}
+
+impl<V: SimdConsts> Not for KMask<V> {
+    type Output = Self;
+
+    #[inline(always)]
+    fn not(self) -> Self {
+        Self::from_bits(!self.0)
+    }
+}
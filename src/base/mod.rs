@@ -1,3 +1,4 @@
+use core::array::TryFromSliceError;
 use core::fmt::Debug;
 use core::ops::*;
 
@@ -12,6 +13,12 @@ pub use transmute::*;
 mod specializations;
 pub use specializations::*;
 
+mod gather;
+pub use gather::*;
+
+mod mask;
+pub use mask::*;
+
 pub use io::SimdBaseIo;
 
 use crate::Simd;
@@ -29,6 +36,19 @@ pub trait SimdConsts: 'static + Copy + core::marker::Sync + core::marker::Send +
     type UnderlyingType: Copy + Debug + core::marker::Sync + core::marker::Send;
 
     type Engine: Simd;
+
+    /// The lane mask type returned by comparisons on this vector.
+    type Mask: SimdMask<Vector = Self>;
+
+    /// A `WIDTH`×`WIDTH` tile held as `WIDTH` row vectors, as taken by
+    /// [`transpose`](SimdBaseOps::transpose). This is `[Self; WIDTH]`, named here for the same
+    /// reason as `ArrayRepresentation`.
+    type Tile: Index<usize, Output = Self>
+        + IndexMut<usize>
+        + AsRef<[Self]>
+        + AsMut<[Self]>
+        + Copy
+        + for<'a> TryFrom<&'a [Self], Error = TryFromSliceError>;
 }
 
 /// Operations shared by all SIMD types
@@ -101,32 +121,33 @@ pub trait SimdBaseOps:
     ///
     /// Note: SSE2 will select B only when all bits are 1, while SSE41 and AVX2 only
     /// check the high bit. To maintain portability ensure all bits are 1 when using
-    /// blend. Results of comparison operations adhere to this.
+    /// blend. [`SimdMask::to_vector`] produces masks in that form; prefer
+    /// [`SimdMask::select`] on comparison results, which has no such caveat.
     fn blendv(self, a: Self, b: Self) -> Self;
 
-    /// Element-wise equality between two vectors. If two elements are equal, it returns all 1's
-    /// in the corresponding element of the result, otherwise it returns all 0's.
-    fn cmp_eq(self, rhs: Self) -> Self;
+    /// Element-wise equality between two vectors. If two elements are equal, the corresponding
+    /// lane of the returned mask is set.
+    fn cmp_eq(self, rhs: Self) -> Self::Mask;
 
-    /// Element-wise inequality between two vectors. If two elements are not equal, it returns all 1's
-    /// in the corresponding element of the result, otherwise it returns all 0's.
-    fn cmp_neq(self, rhs: Self) -> Self;
+    /// Element-wise inequality between two vectors. If two elements are not equal, the corresponding
+    /// lane of the returned mask is set.
+    fn cmp_neq(self, rhs: Self) -> Self::Mask;
 
     /// Element-wise less than between two vectors. If the first element is less than the second element,
-    /// it returns all 1's in the corresponding element of the result, otherwise it returns all 0's.
-    fn cmp_lt(self, rhs: Self) -> Self;
+    /// the corresponding lane of the returned mask is set.
+    fn cmp_lt(self, rhs: Self) -> Self::Mask;
 
     /// Element-wise less than or equal to between two vectors. If the first element is less than or equal to the second element,
-    /// it returns all 1's in the corresponding element of the result, otherwise it returns all 0's.
-    fn cmp_lte(self, rhs: Self) -> Self;
+    /// the corresponding lane of the returned mask is set.
+    fn cmp_lte(self, rhs: Self) -> Self::Mask;
 
     /// Element-wise greater than between two vectors. If the first element is greater than the second element,
-    /// it returns all 1's in the corresponding element of the result, otherwise it returns all 0's.
-    fn cmp_gt(self, rhs: Self) -> Self;
+    /// the corresponding lane of the returned mask is set.
+    fn cmp_gt(self, rhs: Self) -> Self::Mask;
 
     /// Element-wise greater than or equal to between two vectors. If the first element is greater than or equal to the second element,
-    /// it returns all 1's in the corresponding element of the result, otherwise it returns all 0's.
-    fn cmp_gte(self, rhs: Self) -> Self;
+    /// the corresponding lane of the returned mask is set.
+    fn cmp_gte(self, rhs: Self) -> Self::Mask;
 
     /// Element-wise maximum between two vectors.
     ///
@@ -136,6 +157,10 @@ pub trait SimdBaseOps:
     /// - ordered, non-equal inputs: returns the mathematically larger lane
     /// - unordered (`NaN`) or equal-tie (`+0.0` vs `-0.0`) inputs: backend dependent
     ///   (e.g. x86-style backends select `rhs`, while others may propagate `NaN`)
+    ///
+    /// Use [`SimdFloat::max_ieee`](crate::SimdFloat::max_ieee) or
+    /// [`SimdFloat::max_propagate_nan`](crate::SimdFloat::max_propagate_nan) for results that
+    /// are identical on every backend.
     fn max(self, rhs: Self) -> Self;
 
     /// Element-wise minimum between two vectors.
@@ -146,9 +171,84 @@ pub trait SimdBaseOps:
     /// - ordered, non-equal inputs: returns the mathematically smaller lane
     /// - unordered (`NaN`) or equal-tie (`+0.0` vs `-0.0`) inputs: backend dependent
     ///   (e.g. x86-style backends select `rhs`, while others may propagate `NaN`)
+    ///
+    /// Use [`SimdFloat::min_ieee`](crate::SimdFloat::min_ieee) or
+    /// [`SimdFloat::min_propagate_nan`](crate::SimdFloat::min_propagate_nan) for results that
+    /// are identical on every backend.
     fn min(self, rhs: Self) -> Self;
 
+    /// Rearranges lanes using a pattern of `N` source indices, where `N` is a power of two.
+    ///
+    /// The pattern applies to each group of `N` consecutive lanes: lane `i` of the result is
+    /// lane `indices[i % N] % N` of the group containing `i`. Vectors narrower than `N` use the
+    /// first `WIDTH` entries, with indices wrapping modulo `WIDTH`. For example
+    /// `v.shuffle([1, 0])` swaps every adjacent pair of lanes on all backends.
+    ///
+    /// The pattern is expected to be a constant so that it folds into a single permute.
+    fn shuffle<const N: usize>(self, indices: [usize; N]) -> Self;
+
+    /// Two-source variant of [`shuffle`](SimdBaseOps::shuffle). Indices `0..N` select from
+    /// `self` and `N..2 * N` select the corresponding lane of `other`; larger indices wrap
+    /// modulo `2 * N`.
+    fn shuffle2<const N: usize>(self, other: Self, indices: [usize; N]) -> Self;
+
+    /// Reverses the order of all lanes in the vector.
+    fn reverse_lanes(self) -> Self;
+
+    /// Rotates lanes towards lane 0, so lane `i` of the result is lane `(i + BY) % WIDTH`.
+    fn rotate_lanes_left<const BY: usize>(self) -> Self;
+
+    /// Rotates lanes away from lane 0, so lane `(i + BY) % WIDTH` of the result is lane `i`.
+    fn rotate_lanes_right<const BY: usize>(self) -> Self;
+
+    /// Copies lane `LANE % WIDTH` into every lane of the vector.
+    fn broadcast_lane<const LANE: usize>(self) -> Self;
+
+    /// Interleaves the low halves of `self` and `other`: `[a0, b0, a1, b1, ...]`. Together with
+    /// [`zip_hi`](SimdBaseOps::zip_hi) this interleaves the whole of both vectors.
+    fn zip_lo(self, other: Self) -> Self;
+
+    /// Interleaves the high halves of `self` and `other`, continuing where
+    /// [`zip_lo`](SimdBaseOps::zip_lo) stops.
+    fn zip_hi(self, other: Self) -> Self;
+
+    /// Takes the even lanes of `self` followed by the even lanes of `other`, undoing
+    /// [`zip_lo`](SimdBaseOps::zip_lo) and [`zip_hi`](SimdBaseOps::zip_hi).
+    fn unzip_even(self, other: Self) -> Self;
+
+    /// Takes the odd lanes of `self` followed by the odd lanes of `other`.
+    fn unzip_odd(self, other: Self) -> Self;
+
+    /// Transposes a `WIDTH`×`WIDTH` tile in place, so lane `j` of row `i` ends up in lane `i`
+    /// of row `j`. The tile is 4×4 for 32-bit lanes on SSE, Neon and wasm, 8×8 on AVX2 and 16×16
+    /// on AVX-512; on the scalar engine it is a single lane and is left unchanged.
+    fn transpose(rows: &mut Self::Tile);
+
+    /// Builds a tile whose row `i` is `f(i)`, for example to load `WIDTH` rows of an image for
+    /// [`transpose`](SimdBaseOps::transpose).
+    fn tile_from_fn(f: impl FnMut(usize) -> Self) -> Self::Tile;
+
+    /// Packs the lanes set in `mask` into the low lanes, keeping their order, and returns the
+    /// packed vector with the number of lanes set. Lanes from that count up are zero.
+    fn compress(self, mask: Self::Mask) -> (Self, usize);
+
+    /// Stores the lanes set in `mask` next to each other at the start of `slice`, and returns
+    /// how many were stored. Nothing past them is written.
+    ///
+    /// Panics if `slice` is shorter than the number of lanes set.
+    fn compress_store(self, slice: &mut [Self::Scalar], mask: Self::Mask) -> usize;
+
+    /// The inverse of [`compress`](SimdBaseOps::compress): moves the low lanes, keeping their
+    /// order, into the lanes set in `mask`. The other lanes are zero.
+    fn expand(self, mask: Self::Mask) -> Self;
+
     /// Add every number in the vector together
+    ///
+    /// Float lanes are summed in a fixed pairwise tree on every engine: adjacent lanes first,
+    /// then adjacent pairs, and so on, so one vector gives the same bits on every engine. Use
+    /// `sum_pairwise` on [`SimdFloat32`](crate::SimdFloat32) or
+    /// [`SimdFloat64`](crate::SimdFloat64) to sum a whole slice the same way whatever the
+    /// vector width.
     fn horizontal_add(self) -> Self::HorizontalAddScalar;
 }
 
@@ -1,5 +1,5 @@
 use super::transmute::*;
-use crate::{Simd, SimdBaseOps};
+use crate::{Simd, SimdBaseOps, SimdMask};
 use core::ops::*;
 
 pub trait SimdBitMask: Copy {
@@ -172,6 +172,9 @@ pub trait SimdInt:
 
     /// Shift each value right by n bits.
     ///
+    /// This is a logical shift for every lane type, signed or not: the vacated high bits are
+    /// filled with zeros. Use [`shr_arithmetic`](SimdInt::shr_arithmetic) to copy the sign bit.
+    ///
     /// Shift counts use wrapping semantics (`rhs mod lane_bit_width`) so behavior is
     /// defined for negative and out-of-range counts across all backends.
     ///
@@ -179,6 +182,19 @@ pub trait SimdInt:
     /// and is emulated here under SSE2, SSE4.1, and AVX2.
     fn shr(self, rhs: i32) -> Self;
 
+    /// Logical right shift by n bits, filling with zeros. Identical to [`shr`](SimdInt::shr),
+    /// spelled out for code that mixes logical and arithmetic shifts.
+    #[inline(always)]
+    fn shr_logical(self, rhs: i32) -> Self {
+        SimdInt::shr(self, rhs)
+    }
+
+    /// Arithmetic right shift by n bits, filling with copies of the top bit of each lane.
+    /// Shift counts wrap like [`shr`](SimdInt::shr).
+    ///
+    /// 8 bit lanes are emulated with 16 bit shifts on x86, as are 64 bit lanes before AVX-512.
+    fn shr_arithmetic(self, rhs: i32) -> Self;
+
     /// Shift each value left by a constant n bits. This operation is faster in some instruction sets.
     ///
     /// For 64 bits, this operations is missing in most implementations
@@ -197,17 +213,155 @@ pub trait SimdInt:
         SimdInt::shr(self, BY)
     }
 
+    /// Shift each lane left by the count in the matching lane of `counts`.
+    ///
+    /// Counts wrap like [`shl`](SimdInt::shl). This is `vpsllv` on AVX2 and AVX-512 and `vshl`
+    /// on Neon; 8 and 16 bit lanes are built from wider shifts there, and SSE and WebAssembly
+    /// shift lane by lane.
+    fn shl_lanes(self, counts: Self) -> Self;
+
+    /// Logical right shift of each lane by the count in the matching lane of `counts`.
+    /// Counts wrap like [`shr`](SimdInt::shr).
+    fn shr_lanes(self, counts: Self) -> Self;
+
+    /// Arithmetic right shift of each lane by the count in the matching lane of `counts`.
+    /// Counts wrap like [`shr`](SimdInt::shr).
+    fn shr_arithmetic_lanes(self, counts: Self) -> Self;
+
+    /// Rotate the bits of each lane left by n. Counts wrap modulo the lane width, so negative
+    /// counts rotate right. This is `vprolv` on AVX-512 for 32 and 64 bit lanes, and a pair of
+    /// shifts elsewhere.
+    fn rotate_left(self, rhs: i32) -> Self;
+
+    /// Rotate the bits of each lane right by n. Counts wrap modulo the lane width.
+    fn rotate_right(self, rhs: i32) -> Self;
+
+    /// Rotate the bits of each lane left by a constant n.
+    #[inline(always)]
+    fn rotate_left_const<const BY: i32>(self) -> Self {
+        SimdInt::rotate_left(self, BY)
+    }
+
+    /// Rotate the bits of each lane right by a constant n.
+    #[inline(always)]
+    fn rotate_right_const<const BY: i32>(self) -> Self {
+        SimdInt::rotate_right(self, BY)
+    }
+
+    /// Rotate each lane left by the count in the matching lane of `counts`, modulo the lane width.
+    fn rotate_left_lanes(self, counts: Self) -> Self;
+
+    /// Rotate each lane right by the count in the matching lane of `counts`, modulo the lane width.
+    fn rotate_right_lanes(self, counts: Self) -> Self;
+
+    /// Number of set bits in each lane.
+    ///
+    /// This is `vcnt` on Neon, a nibble lookup with `pshufb` from SSE4.1 up, and the AVX-512
+    /// VPOPCNTDQ/BITALG instructions on AVX-512 CPUs that have them.
+    fn count_ones(self) -> Self;
+
+    /// Number of leading zero bits in each lane; the lane width for zero lanes.
+    fn leading_zeros(self) -> Self;
+
+    /// Number of trailing zero bits in each lane; the lane width for zero lanes.
+    fn trailing_zeros(self) -> Self;
+
+    /// Reverses the order of the bits in each lane.
+    fn reverse_bits(self) -> Self;
+
+    /// Number of set bits in the whole vector.
+    fn popcount_total(self) -> u32;
+
     /// Add every number in the vector together in unsigned arithmetic. When expanding the size of each number,
     /// it treats the numbers as unsigned, meaning the sign bit doesn't get moved around.
     fn horizontal_unsigned_add(self) -> Self::HorizontalAddScalar;
 
+    /// Smallest lane of the vector.
+    #[inline(always)]
+    fn horizontal_min(self) -> Self::Scalar {
+        reduce_pairwise(self, SimdBaseOps::min)
+    }
+
+    /// Largest lane of the vector.
+    #[inline(always)]
+    fn horizontal_max(self) -> Self::Scalar {
+        reduce_pairwise(self, SimdBaseOps::max)
+    }
+
+    /// Wrapping product of every lane in the vector.
+    #[inline(always)]
+    fn horizontal_mul(self) -> Self::Scalar {
+        reduce_pairwise(self, |a, b| a * b)
+    }
+
+    /// Bitwise and of every lane in the vector.
+    #[inline(always)]
+    fn horizontal_and(self) -> Self::Scalar {
+        reduce_pairwise(self, |a, b| a & b)
+    }
+
+    /// Bitwise or of every lane in the vector.
+    #[inline(always)]
+    fn horizontal_or(self) -> Self::Scalar {
+        reduce_pairwise(self, |a, b| a | b)
+    }
+
+    /// Bitwise xor of every lane in the vector.
+    #[inline(always)]
+    fn horizontal_xor(self) -> Self::Scalar {
+        reduce_pairwise(self, |a, b| a ^ b)
+    }
+
+    /// Index of the first lane holding the [`horizontal_min`](SimdInt::horizontal_min).
+    #[inline(always)]
+    fn argmin(self) -> usize {
+        first_lane_equal(self, self.horizontal_min())
+    }
+
+    /// Index of the first lane holding the [`horizontal_max`](SimdInt::horizontal_max).
+    #[inline(always)]
+    fn argmax(self) -> usize {
+        first_lane_equal(self, self.horizontal_max())
+    }
+
+    /// Runtime lane permute: lane `i` of the result is lane `indices[i] % WIDTH` of `self`.
+    ///
+    /// Indices are interpreted as unsigned and wrap modulo the lane count, so out-of-range
+    /// values select a lane deterministically on every backend.
+    fn permute(self, indices: Self) -> Self;
+
     fn from_i64(value: i64) -> Self;
+
+    /// Adds each lane, clamping to the range of the lane type instead of wrapping.
+    ///
+    /// 32 and 64 bit lanes have no native instruction on x86 and WebAssembly and are emulated.
+    fn saturating_add(self, rhs: Self) -> Self;
+
+    /// Subtracts each lane, clamping to the range of the lane type instead of wrapping.
+    ///
+    /// 32 and 64 bit lanes have no native instruction on x86 and WebAssembly and are emulated.
+    fn saturating_sub(self, rhs: Self) -> Self;
+
+    /// Rounding average of each pair of lanes, `(a + b + 1) >> 1` computed without overflow.
+    /// This is `pavgb`/`pavgw` on x86 and `vrhadd` on Neon.
+    fn avg(self, rhs: Self) -> Self;
+
+    /// Absolute difference of each pair of lanes. For signed lanes, differences above the
+    /// maximum of the lane type wrap, so bitcast to the unsigned type to read them.
+    #[inline(always)]
+    fn abs_diff(self, rhs: Self) -> Self {
+        SimdBaseOps::max(self, rhs) - SimdBaseOps::min(self, rhs)
+    }
 }
 
 /// Operations shared by 8 bit int types
 pub trait SimdInt8: SimdInt<Scalar = i8, HorizontalAddScalar = i64> + SimdTransmuteI8 {
     type BitMask: SimdBitMask;
 
+    /// Bit cast to u8.
+    /// This function is only used for compilation and does not generate any instructions, thus it has zero latency.
+    fn bitcast_u8(self) -> <Self::Engine as Simd>::Vu8;
+
     /// Splits the vector into two halves, then extends them both to be i16. This is useful for horizontal adding.
     fn extend_to_i16(self) -> (<Self::Engine as Simd>::Vi16, <Self::Engine as Simd>::Vi16);
 
@@ -292,13 +446,29 @@ pub trait SimdInt8: SimdInt<Scalar = i8, HorizontalAddScalar = i64> + SimdTransm
     #[inline(always)]
     fn index_of_first_eq(self, value: i8) -> Option<usize> {
         let value = Self::set1(value);
-        let mask = self.cmp_eq(value);
-        mask.index_of_first_truthy()
+        self.cmp_eq(value).first_set()
     }
+
+    /// Looks up every lane in `table`: lane `i` becomes `table[indices[i]]`, or zero when the
+    /// index is outside `0..16`, negative indices included.
+    ///
+    /// Every lane indexes the whole table whatever the vector width. AVX2 and AVX-512 `pshufb`
+    /// only shuffles within 128 bit lanes, so those engines broadcast the table to each of them.
+    fn lookup16(table: &[i8; 16], indices: Self) -> Self;
+
+    /// Like [`lookup16`](SimdInt8::lookup16) with a 32 byte table, giving zero outside `0..32`.
+    fn lookup32(table: &[i8; 32], indices: Self) -> Self;
+
+    /// Like [`lookup16`](SimdInt8::lookup16) with a 64 byte table, giving zero outside `0..64`.
+    fn lookup64(table: &[i8; 64], indices: Self) -> Self;
 }
 
 /// Operations shared by 16 bit int types
 pub trait SimdInt16: SimdInt<Scalar = i16, HorizontalAddScalar = i64> + SimdTransmuteI16 {
+    /// Bit cast to u16.
+    /// This function is only used for compilation and does not generate any instructions, thus it has zero latency.
+    fn bitcast_u16(self) -> <Self::Engine as Simd>::Vu16;
+
     /// Splits the vector into two halves, then extends them both to be i32. This is useful for horizontal adding.
     fn extend_to_i32(self) -> (<Self::Engine as Simd>::Vi32, <Self::Engine as Simd>::Vi32);
 
@@ -307,6 +477,41 @@ pub trait SimdInt16: SimdInt<Scalar = i16, HorizontalAddScalar = i64> + SimdTran
     fn unsigned_extend_to_i32(self)
         -> (<Self::Engine as Simd>::Vi32, <Self::Engine as Simd>::Vi32);
 
+    /// Narrows `self` and `upper` into one i8 vector, keeping the low 8 bits of each lane. The
+    /// lanes of `self` come first, followed by the lanes of `upper`, which undoes
+    /// [`extend_to_i16`](SimdInt8::extend_to_i16). On the scalar engine `upper` is dropped.
+    fn narrow_to_i8(self, upper: Self) -> <Self::Engine as Simd>::Vi8;
+
+    /// Like [`narrow_to_i8`](SimdInt16::narrow_to_i8), but lanes outside the i8 range are
+    /// clamped to it.
+    fn saturating_narrow_to_i8(self, upper: Self) -> <Self::Engine as Simd>::Vi8;
+
+    /// Like [`narrow_to_i8`](SimdInt16::narrow_to_i8), but lanes are clamped to the u8 range
+    /// (`packus`).
+    fn saturating_narrow_to_u8(self, upper: Self) -> <Self::Engine as Simd>::Vu8;
+
+    /// The high 16 bits of each signed 32 bit product (`pmulhw`).
+    fn mul_hi(self, rhs: Self) -> Self;
+
+    /// Multiplies each lane into a full i32 product. The products are split in two halves like
+    /// [`extend_to_i32`](SimdInt16::extend_to_i32).
+    fn mul_widening(
+        self,
+        rhs: Self,
+    ) -> (<Self::Engine as Simd>::Vi32, <Self::Engine as Simd>::Vi32);
+
+    /// Multiplies each lane into an i32 product and adds adjacent pairs of products, wrapping
+    /// on overflow (`pmaddwd`). The scalar engine has no pair, so it returns the single product.
+    fn mul_add_pairs(self, rhs: Self) -> <Self::Engine as Simd>::Vi32;
+
+    /// Splits the vector into two halves, then converts them both to f32. Every i16 is exactly
+    /// representable, so no rounding happens.
+    #[inline(always)]
+    fn extend_to_f32(self) -> (<Self::Engine as Simd>::Vf32, <Self::Engine as Simd>::Vf32) {
+        let (a, b) = self.extend_to_i32();
+        (a.cast_f32(), b.cast_f32())
+    }
+
     /// Adds (arbitrary) pairs of values in the vector, returning a i32 version of the vector.
     /// The way the pairs are chosen is implementation-defined.
     #[inline(always)]
@@ -331,6 +536,10 @@ pub trait SimdInt32: SimdInt<Scalar = i32, HorizontalAddScalar = i64> + SimdTran
     /// This function is only used for compilation and does not generate any instructions, thus it has zero latency.
     fn bitcast_f32(self) -> <Self::Engine as Simd>::Vf32;
 
+    /// Bit cast to u32.
+    /// This function is only used for compilation and does not generate any instructions, thus it has zero latency.
+    fn bitcast_u32(self) -> <Self::Engine as Simd>::Vu32;
+
     /// Element-wise cast to f32
     fn cast_f32(self) -> <Self::Engine as Simd>::Vf32;
 
@@ -342,6 +551,33 @@ pub trait SimdInt32: SimdInt<Scalar = i32, HorizontalAddScalar = i64> + SimdTran
     fn unsigned_extend_to_i64(self)
         -> (<Self::Engine as Simd>::Vi64, <Self::Engine as Simd>::Vi64);
 
+    /// Narrows `self` and `upper` into one i16 vector, keeping the low 16 bits of each lane. The
+    /// lanes of `self` come first, followed by the lanes of `upper`, which undoes
+    /// [`extend_to_i32`](SimdInt16::extend_to_i32). On the scalar engine `upper` is dropped.
+    fn narrow_to_i16(self, upper: Self) -> <Self::Engine as Simd>::Vi16;
+
+    /// Like [`narrow_to_i16`](SimdInt32::narrow_to_i16), but lanes outside the i16 range are
+    /// clamped to it.
+    fn saturating_narrow_to_i16(self, upper: Self) -> <Self::Engine as Simd>::Vi16;
+
+    /// Like [`narrow_to_i16`](SimdInt32::narrow_to_i16), but lanes are clamped to the u16 range
+    /// (`packus`).
+    fn saturating_narrow_to_u16(self, upper: Self) -> <Self::Engine as Simd>::Vu16;
+
+    /// The high 32 bits of each signed 64 bit product.
+    fn mul_hi(self, rhs: Self) -> Self;
+
+    /// Multiplies each lane into a full i64 product. The products are split in two halves like
+    /// [`extend_to_i64`](SimdInt32::extend_to_i64).
+    fn mul_widening(
+        self,
+        rhs: Self,
+    ) -> (<Self::Engine as Simd>::Vi64, <Self::Engine as Simd>::Vi64);
+
+    /// Splits the vector into two halves, then converts them both to f64. Every i32 is exactly
+    /// representable, so no rounding happens.
+    fn extend_to_f64(self) -> (<Self::Engine as Simd>::Vf64, <Self::Engine as Simd>::Vf64);
+
     /// Adds (arbitrary) pairs of values in the vector, returning a i64 version of the vector.
     /// The way the pairs are chosen is implementation-defined.
     #[inline(always)]
@@ -366,10 +602,90 @@ pub trait SimdInt64: SimdInt<Scalar = i64, HorizontalAddScalar = i64> + SimdTran
     /// This function is only used for compilation and does not generate any instructions, thus it has zero latency.
     fn bitcast_f64(self) -> <Self::Engine as Simd>::Vf64;
 
+    /// Bit cast to u64.
+    /// This function is only used for compilation and does not generate any instructions, thus it has zero latency.
+    fn bitcast_u64(self) -> <Self::Engine as Simd>::Vu64;
+
     /// Element-wise cast to f64
     fn cast_f64(self) -> <Self::Engine as Simd>::Vf64;
 
     fn partial_horizontal_add(self) -> i64;
+
+    /// Narrows `self` and `upper` into one i32 vector, keeping the low 32 bits of each lane. The
+    /// lanes of `self` come first, followed by the lanes of `upper`, which undoes
+    /// [`extend_to_i64`](SimdInt32::extend_to_i64). On the scalar engine `upper` is dropped.
+    fn narrow_to_i32(self, upper: Self) -> <Self::Engine as Simd>::Vi32;
+
+    /// Like [`narrow_to_i32`](SimdInt64::narrow_to_i32), but lanes outside the i32 range are
+    /// clamped to it.
+    fn saturating_narrow_to_i32(self, upper: Self) -> <Self::Engine as Simd>::Vi32;
+
+    /// Like [`narrow_to_i32`](SimdInt64::narrow_to_i32), but lanes are clamped to the u32 range.
+    fn saturating_narrow_to_u32(self, upper: Self) -> <Self::Engine as Simd>::Vu32;
+}
+
+/// Operations shared by 8 bit unsigned int types
+///
+/// Ordering operations (`cmp_lt`, `min`, `max`, ...) compare the lanes as unsigned numbers,
+/// and `shr` is a logical shift.
+pub trait SimdUint8: SimdInt<Scalar = u8, HorizontalAddScalar = u64> + SimdTransmuteU8 {
+    /// Bit cast to i8.
+    /// This function is only used for compilation and does not generate any instructions, thus it has zero latency.
+    fn bitcast_i8(self) -> <Self::Engine as Simd>::Vi8;
+
+    /// Splits the vector into two halves, then zero-extends them both to be u16.
+    fn extend_to_u16(self) -> (<Self::Engine as Simd>::Vu16, <Self::Engine as Simd>::Vu16);
+
+    /// Multiplies the unsigned lanes of `self` with the signed lanes of `rhs` and adds adjacent
+    /// pairs of the products, saturating to i16 (`pmaddubsw`). The scalar engine has no pair, so
+    /// it returns the single product.
+    fn mul_add_pairs(self, rhs: <Self::Engine as Simd>::Vi8) -> <Self::Engine as Simd>::Vi16;
+
+    /// Sums the absolute differences of each group of 8 lanes into one u64 lane (`psadbw`).
+    /// The scalar engine has a single lane, so it returns its absolute difference.
+    fn sad(self, rhs: Self) -> <Self::Engine as Simd>::Vu64;
+}
+
+/// Operations shared by 16 bit unsigned int types
+///
+/// Ordering operations (`cmp_lt`, `min`, `max`, ...) compare the lanes as unsigned numbers,
+/// and `shr` is a logical shift.
+pub trait SimdUint16: SimdInt<Scalar = u16, HorizontalAddScalar = u64> + SimdTransmuteU16 {
+    /// Bit cast to i16.
+    /// This function is only used for compilation and does not generate any instructions, thus it has zero latency.
+    fn bitcast_i16(self) -> <Self::Engine as Simd>::Vi16;
+
+    /// Splits the vector into two halves, then zero-extends them both to be u32.
+    fn extend_to_u32(self) -> (<Self::Engine as Simd>::Vu32, <Self::Engine as Simd>::Vu32);
+
+    /// The high 16 bits of each unsigned 32 bit product (`pmulhuw`).
+    fn mul_hi(self, rhs: Self) -> Self;
+}
+
+/// Operations shared by 32 bit unsigned int types
+///
+/// Ordering operations (`cmp_lt`, `min`, `max`, ...) compare the lanes as unsigned numbers,
+/// and `shr` is a logical shift.
+pub trait SimdUint32: SimdInt<Scalar = u32, HorizontalAddScalar = u64> + SimdTransmuteU32 {
+    /// Bit cast to i32.
+    /// This function is only used for compilation and does not generate any instructions, thus it has zero latency.
+    fn bitcast_i32(self) -> <Self::Engine as Simd>::Vi32;
+
+    /// Splits the vector into two halves, then zero-extends them both to be u64.
+    fn extend_to_u64(self) -> (<Self::Engine as Simd>::Vu64, <Self::Engine as Simd>::Vu64);
+
+    /// The high 32 bits of each unsigned 64 bit product.
+    fn mul_hi(self, rhs: Self) -> Self;
+}
+
+/// Operations shared by 64 bit unsigned int types
+///
+/// Ordering operations (`cmp_lt`, `min`, `max`, ...) compare the lanes as unsigned numbers,
+/// and `shr` is a logical shift.
+pub trait SimdUint64: SimdInt<Scalar = u64, HorizontalAddScalar = u64> + SimdTransmuteU64 {
+    /// Bit cast to i64.
+    /// This function is only used for compilation and does not generate any instructions, thus it has zero latency.
+    fn bitcast_i64(self) -> <Self::Engine as Simd>::Vi64;
 }
 
 /// Operations shared by f32 and f64 floating point types
@@ -392,6 +708,16 @@ pub trait SimdFloat:
     /// Element-wise rounds between two vectors
     fn round(self) -> Self;
 
+    /// Element-wise rounding toward zero, keeping the sign of zero results.
+    fn trunc(self) -> Self;
+
+    /// Element-wise fractional part, `self - self.trunc()`, with the sign of `self`.
+    /// Infinite lanes give NaN.
+    #[inline(always)]
+    fn fract(self) -> Self {
+        self - self.trunc()
+    }
+
     /// Alternative element-wise ceilings between two vectors.
     /// When using Sse2, this uses a faster version of ceiling
     /// that only works on floating point values small enough to fit in
@@ -414,8 +740,25 @@ pub trait SimdFloat:
     fn fast_round(self) -> Self;
 
     /// Element-wise multiply add. This performs `Self * A + B`
+    ///
+    /// This is the fast variant: it and the other multiply-add variants below are fused,
+    /// rounding once, on AVX2, AVX-512 and Neon, and round the product separately elsewhere. Use
+    /// [`mul_add_fused`](Self::mul_add_fused) or [`mul_add_unfused`](Self::mul_add_unfused)
+    /// for the same result on every engine. On every engine a NaN in any operand gives NaN, as
+    /// does multiplying zero by infinity.
     fn mul_add(self, a: Self, b: Self) -> Self;
 
+    /// Element-wise `Self * A + B` rounded once, like the scalar `mul_add`, on every engine.
+    /// Engines without FMA instructions (SSE2, SSE4.1, AVX, WebAssembly and scalar) compute it
+    /// lane by lane in software, so this is much slower there than [`mul_add`](Self::mul_add).
+    fn mul_add_fused(self, a: Self, b: Self) -> Self;
+
+    /// Element-wise `Self * A + B` with the product rounded before the add, on every engine.
+    #[inline(always)]
+    fn mul_add_unfused(self, a: Self, b: Self) -> Self {
+        self * a + b
+    }
+
     /// Element-wise multiply subtract. This performs `Self * A - B`
     fn mul_sub(self, a: Self, b: Self) -> Self;
 
@@ -435,6 +778,140 @@ pub trait SimdFloat:
     /// produce a meaningful approximation of `1.0 / sqrt(x)`.
     fn rsqrt(self) -> Self;
 
+    /// Element-wise exact reciprocal, `1.0 / self`. See [`SimdFloat32::fast_inverse`] for an
+    /// approximation.
+    #[inline(always)]
+    fn recip(self) -> Self {
+        Self::from_f64(1.0) / self
+    }
+
+    /// Sets the lanes that are NaN.
+    #[inline(always)]
+    fn is_nan(self) -> Self::Mask {
+        self.cmp_neq(self)
+    }
+
+    /// Sets the lanes that are neither infinite nor NaN.
+    #[inline(always)]
+    fn is_finite(self) -> Self::Mask {
+        self.abs().cmp_lt(Self::from_f64(f64::INFINITY))
+    }
+
+    /// Sets the lanes that are positive or negative infinity.
+    #[inline(always)]
+    fn is_infinite(self) -> Self::Mask {
+        self.abs().cmp_eq(Self::from_f64(f64::INFINITY))
+    }
+
+    /// Sets the lanes with the sign bit set, including `-0.0` and NaNs with a negative sign.
+    #[inline(always)]
+    fn is_sign_negative(self) -> Self::Mask {
+        Self::Mask::from_vector(self)
+    }
+
+    /// Element-wise magnitude of `self` with the sign bit of `sign`. NaN lanes are kept as NaN
+    /// with the new sign.
+    #[inline(always)]
+    fn copysign(self, sign: Self) -> Self {
+        let sign_bit = Self::from_f64(-0.0);
+        self.and_not(sign_bit) | (sign & sign_bit)
+    }
+
+    /// Element-wise `1.0` with the sign of `self`, so `-1.0` for negative lanes including
+    /// `-0.0`. NaN lanes stay NaN.
+    #[inline(always)]
+    fn signum(self) -> Self {
+        self.is_nan()
+            .select(self, Self::from_f64(1.0).copysign(self))
+    }
+
+    /// Element-wise clamp of `self` to `[min, max]`. NaN lanes of `self` stay NaN, NaN bounds
+    /// are ignored, and lanes where `min > max` give `max`.
+    #[inline(always)]
+    fn clamp(self, min: Self, max: Self) -> Self {
+        let lower = self.cmp_lt(min).select(min, self);
+        lower.cmp_gt(max).select(max, lower)
+    }
+
+    /// Element-wise IEEE 754-2019 `minimumNumber`: a NaN lane loses to a number, `-0.0` is
+    /// smaller than `+0.0`, and lanes where both inputs are NaN give the canonical quiet NaN.
+    /// Unlike [`min`](SimdBaseOps::min) the result is bit-identical on every engine.
+    #[inline(always)]
+    fn min_ieee(self, rhs: Self) -> Self {
+        let lhs_nan = self.is_nan();
+        let rhs_nan = rhs.is_nan();
+        lhs_nan
+            .select(rhs, self)
+            .min_propagate_nan(rhs_nan.select(self, rhs))
+    }
+
+    /// Element-wise IEEE 754-2019 `maximumNumber`: a NaN lane loses to a number, `+0.0` is
+    /// larger than `-0.0`, and lanes where both inputs are NaN give the canonical quiet NaN.
+    /// Unlike [`max`](SimdBaseOps::max) the result is bit-identical on every engine.
+    #[inline(always)]
+    fn max_ieee(self, rhs: Self) -> Self {
+        let lhs_nan = self.is_nan();
+        let rhs_nan = rhs.is_nan();
+        lhs_nan
+            .select(rhs, self)
+            .max_propagate_nan(rhs_nan.select(self, rhs))
+    }
+
+    /// Element-wise IEEE 754-2019 `minimum`: a NaN in either input gives the canonical quiet NaN
+    /// and `-0.0` is smaller than `+0.0`. The result is bit-identical on every engine.
+    #[inline(always)]
+    fn min_propagate_nan(self, rhs: Self) -> Self {
+        let smaller = self.cmp_lt(rhs).select(self, rhs);
+        // Equal lanes only differ for signed zeros, where the set sign bit wins.
+        let smaller = self.cmp_eq(rhs).select(self | rhs, smaller);
+        (self.is_nan() | rhs.is_nan()).select(Self::from_f64(f64::NAN), smaller)
+    }
+
+    /// Element-wise IEEE 754-2019 `maximum`: a NaN in either input gives the canonical quiet NaN
+    /// and `+0.0` is larger than `-0.0`. The result is bit-identical on every engine.
+    #[inline(always)]
+    fn max_propagate_nan(self, rhs: Self) -> Self {
+        let larger = self.cmp_gt(rhs).select(self, rhs);
+        // Equal lanes only differ for signed zeros, where the clear sign bit wins.
+        let larger = self.cmp_eq(rhs).select(self & rhs, larger);
+        (self.is_nan() | rhs.is_nan()).select(Self::from_f64(f64::NAN), larger)
+    }
+
+    /// Smallest lane of the vector under [`min_ieee`](SimdFloat::min_ieee), so NaN lanes are
+    /// skipped and the result is the canonical quiet NaN only when every lane is NaN.
+    #[inline(always)]
+    fn horizontal_min(self) -> Self::Scalar {
+        reduce_pairwise(self, SimdFloat::min_ieee)
+    }
+
+    /// Largest lane of the vector under [`max_ieee`](SimdFloat::max_ieee), so NaN lanes are
+    /// skipped and the result is the canonical quiet NaN only when every lane is NaN.
+    #[inline(always)]
+    fn horizontal_max(self) -> Self::Scalar {
+        reduce_pairwise(self, SimdFloat::max_ieee)
+    }
+
+    /// Product of every lane in the vector, multiplied in the same pairwise order as
+    /// [`horizontal_add`](SimdBaseOps::horizontal_add).
+    #[inline(always)]
+    fn horizontal_mul(self) -> Self::Scalar {
+        reduce_pairwise(self, |a, b| a * b)
+    }
+
+    /// Index of the first lane comparing equal to the
+    /// [`horizontal_min`](SimdFloat::horizontal_min), or 0 if every lane is NaN.
+    #[inline(always)]
+    fn argmin(self) -> usize {
+        first_lane_equal(self, self.horizontal_min())
+    }
+
+    /// Index of the first lane comparing equal to the
+    /// [`horizontal_max`](SimdFloat::horizontal_max), or 0 if every lane is NaN.
+    #[inline(always)]
+    fn argmax(self) -> usize {
+        first_lane_equal(self, self.horizontal_max())
+    }
+
     fn from_f64(value: f64) -> Self;
 }
 
@@ -453,6 +930,40 @@ pub trait SimdFloat32:
 
     /// Element-wise fast reciprocal (1.0 / x)
     fn fast_inverse(self) -> Self;
+
+    /// Splits the vector into two halves, then converts them both to f64 exactly.
+    fn extend_to_f64(self) -> (<Self::Engine as Simd>::Vf64, <Self::Engine as Simd>::Vf64);
+
+    /// Runtime lane permute: lane `i` of the result is lane `indices[i] % WIDTH` of `self`.
+    /// See [`SimdInt::permute`].
+    fn permute(self, indices: <Self::Engine as Simd>::Vi32) -> Self;
+
+    /// Sums `slice` in one pairwise tree fixed by its length alone: adjacent elements first, then
+    /// adjacent pairs, and so on, with the blocks left over at a ragged end joined from the right.
+    /// Whole vectors go through [`horizontal_add`](SimdBaseOps::horizontal_add), so the result is
+    /// the same bits on every engine. An empty slice sums to `0.0`.
+    #[inline]
+    fn sum_pairwise(slice: &[f32]) -> f32 {
+        sum_pairwise::<Self>(slice)
+    }
+
+    /// Element-wise [`f32::total_cmp`] less than: `-NaN < -inf < ... < -0.0 < +0.0 < ... < +inf
+    /// < +NaN`, with NaNs further ordered by payload.
+    fn total_cmp_lt(self, rhs: Self) -> Self::Mask;
+
+    /// Element-wise minimum under [`total_cmp_lt`](SimdFloat32::total_cmp_lt), so the result
+    /// is always one of the inputs, bit for bit.
+    #[inline(always)]
+    fn total_min(self, rhs: Self) -> Self {
+        rhs.total_cmp_lt(self).select(rhs, self)
+    }
+
+    /// Element-wise maximum under [`total_cmp_lt`](SimdFloat32::total_cmp_lt), so the result
+    /// is always one of the inputs, bit for bit.
+    #[inline(always)]
+    fn total_max(self, rhs: Self) -> Self {
+        self.total_cmp_lt(rhs).select(rhs, self)
+    }
 }
 
 /// Operations shared by 64 bit float types
@@ -465,6 +976,117 @@ pub trait SimdFloat64:
 
     /// Element-wise cast to i64 (rounded to nearest, ties to even; not floored).
     fn cast_i64(self) -> <Self::Engine as Simd>::Vi64;
+
+    /// Narrows `self` and `upper` into one f32 vector, rounding to nearest. The lanes of `self`
+    /// come first, followed by the lanes of `upper`, which undoes
+    /// [`extend_to_f64`](SimdFloat32::extend_to_f64). On the scalar engine `upper` is dropped.
+    fn narrow_to_f32(self, upper: Self) -> <Self::Engine as Simd>::Vf32;
+
+    /// Converts `self` and `upper` into one i32 vector (rounded to nearest, ties to even; not
+    /// floored), with the same lane order as [`narrow_to_f32`](SimdFloat64::narrow_to_f32).
+    /// Lanes outside the range of i32 and NaN lanes give a backend dependent value.
+    fn narrow_to_i32(self, upper: Self) -> <Self::Engine as Simd>::Vi32;
+
+    /// Runtime lane permute: lane `i` of the result is lane `indices[i] % WIDTH` of `self`.
+    /// See [`SimdInt::permute`].
+    fn permute(self, indices: <Self::Engine as Simd>::Vi64) -> Self;
+
+    /// Sums `slice` in one pairwise tree fixed by its length alone: adjacent elements first, then
+    /// adjacent pairs, and so on, with the blocks left over at a ragged end joined from the right.
+    /// Whole vectors go through [`horizontal_add`](SimdBaseOps::horizontal_add), so the result is
+    /// the same bits on every engine. An empty slice sums to `0.0`.
+    #[inline]
+    fn sum_pairwise(slice: &[f64]) -> f64 {
+        sum_pairwise::<Self>(slice)
+    }
+
+    /// Element-wise [`f64::total_cmp`] less than: `-NaN < -inf < ... < -0.0 < +0.0 < ... < +inf
+    /// < +NaN`, with NaNs further ordered by payload.
+    fn total_cmp_lt(self, rhs: Self) -> Self::Mask;
+
+    /// Element-wise minimum under [`total_cmp_lt`](SimdFloat64::total_cmp_lt), so the result
+    /// is always one of the inputs, bit for bit.
+    #[inline(always)]
+    fn total_min(self, rhs: Self) -> Self {
+        rhs.total_cmp_lt(self).select(rhs, self)
+    }
+
+    /// Element-wise maximum under [`total_cmp_lt`](SimdFloat64::total_cmp_lt), so the result
+    /// is always one of the inputs, bit for bit.
+    #[inline(always)]
+    fn total_max(self, rhs: Self) -> Self {
+        self.total_cmp_lt(rhs).select(rhs, self)
+    }
+}
+
+/// A pattern for [`SimdBaseOps::shuffle`] that swaps the two halves of every group of `N` lanes.
+const fn swap_halves<const N: usize>() -> [usize; N] {
+    let mut indices = [0; N];
+    let mut lane = 0;
+    while lane < N {
+        indices[lane] = lane ^ (N / 2);
+        lane += 1;
+    }
+    indices
+}
+
+/// Reduces the lanes of `value` with `op` in a fixed pairwise tree: adjacent lanes first, then
+/// adjacent pairs, and so on. Lane 0 is always the left operand, so the order does not depend on
+/// the engine, and reducing consecutive vectors' results pairwise continues the same tree.
+#[inline(always)]
+fn reduce_pairwise<V: SimdBaseOps>(value: V, op: impl Fn(V, V) -> V) -> V::Scalar {
+    let mut value = value;
+    macro_rules! step {
+        ($($n:literal)*) => {$(
+            if V::WIDTH >= $n {
+                value = op(value, value.shuffle(swap_halves::<$n>()));
+            }
+        )*};
+    }
+    step!(2 4 8 16 32 64);
+    value[0]
+}
+
+/// Continues the [`reduce_pairwise`] tree across a whole slice. `pending[level]` holds the sum of
+/// the last `2^level` elements while it waits for the block to its right, like the carries of a
+/// binary counter, so the tree depends only on the length of `slice` and not on `V::WIDTH`.
+#[inline(always)]
+fn sum_pairwise<V>(slice: &[V::Scalar]) -> V::Scalar
+where
+    V: SimdBaseOps<HorizontalAddScalar = <V as crate::SimdConsts>::Scalar>,
+    V::Scalar: Add<Output = V::Scalar> + Default,
+{
+    let mut pending = [None; usize::BITS as usize + 1];
+    let mut push = |mut sum: V::Scalar, mut level: usize| {
+        while let Some(left) = pending[level].take() {
+            sum = left + sum;
+            level += 1;
+        }
+        pending[level] = Some(sum);
+    };
+
+    let mut chunks = slice.chunks_exact(V::WIDTH);
+    for chunk in &mut chunks {
+        push(
+            V::load_from_slice(chunk).horizontal_add(),
+            V::WIDTH.trailing_zeros() as usize,
+        );
+    }
+    for &value in chunks.remainder() {
+        push(value, 0);
+    }
+
+    pending
+        .iter()
+        .flatten()
+        .copied()
+        .reduce(|right, left| left + right)
+        .unwrap_or_default()
+}
+
+#[inline(always)]
+fn first_lane_equal<V: SimdBaseOps>(value: V, scalar: V::Scalar) -> usize {
+    value.cmp_eq(V::set1(scalar)).first_set().unwrap_or(0)
 }
 
 #[cfg(test)]
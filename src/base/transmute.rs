@@ -44,6 +44,18 @@ macro_rules! make_simd_transmute {
                 panic!("Invalid transmute: tried to transmute non-sse41 into sse41");
             }
 
+            #[cfg(any(target_arch = "x86_64", target_arch = "x86"))]
+            /// Tries to transmute the value into its underlying Avx type. Panics if the value is not a Avx.
+            fn try_transmute_avx(&self) -> $avx {
+                panic!("Invalid transmute: tried to transmute non-avx into avx");
+            }
+
+            #[cfg(any(target_arch = "x86_64", target_arch = "x86"))]
+            /// Tries to create the value from its underlying Avx type. Panics if the value is not a Avx.
+            fn try_transmute_from_avx(_avx: $avx) -> Self {
+                panic!("Invalid transmute: tried to transmute non-avx into avx");
+            }
+
             #[cfg(any(target_arch = "x86_64", target_arch = "x86"))]
             /// Tries to transmute the value into its underlying Avx2 type. Panics if the value is not a Avx2.
             fn try_transmute_avx2(&self) -> $avx {
@@ -149,3 +161,39 @@ make_simd_transmute!(
     int64x2_t,
     v128
 );
+make_simd_transmute!(
+    SimdTransmuteU8,
+    u8,
+    __m128i,
+    __m256i,
+    __m512i,
+    uint8x16_t,
+    v128
+);
+make_simd_transmute!(
+    SimdTransmuteU16,
+    u16,
+    __m128i,
+    __m256i,
+    __m512i,
+    uint16x8_t,
+    v128
+);
+make_simd_transmute!(
+    SimdTransmuteU32,
+    u32,
+    __m128i,
+    __m256i,
+    __m512i,
+    uint32x4_t,
+    v128
+);
+make_simd_transmute!(
+    SimdTransmuteU64,
+    u64,
+    __m128i,
+    __m256i,
+    __m512i,
+    uint64x2_t,
+    v128
+);
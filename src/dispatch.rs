@@ -0,0 +1,400 @@
+//! Runtime dispatch state: the detected instruction set, an optional cap on it, and the caches
+//! used by generated functions.
+//!
+//! The functions generated by [`simd_runtime_generate`](crate::simd_runtime_generate) and
+//! [`simd_unsafe_generate_all`](crate::simd_unsafe_generate_all) pick a level on their first
+//! call only. Plain functions then keep a pointer to the chosen version and call through it,
+//! while generic functions and methods match on the cached level.
+//!
+//! The level can be capped with [`set_max_level`], or in std builds with the
+//! `SIMDEEZ_MAX_LEVEL` environment variable (`scalar`, `sse2`, `sse41`, `avx`, `avx2`, `avx512`,
+//! `neon` or `wasm`), which is read when the level is first picked. A cap only restricts levels
+//! of its own architecture, except `scalar` which disables SIMD everywhere.
+//!
+//! A few operations also use a CPU extension beyond their level when the running CPU has it:
+//! SSSE3 `pshufb` for byte lookups on SSE2, and the AVX-512 VBMI, VBMI2, BITALG and VPOPCNTDQ
+//! extensions on AVX-512. Each extension is detected on its own, once, and checked by the
+//! operation that uses it, so a CPU gets every extension it has.
+
+use core::fmt;
+use core::sync::atomic::{AtomicPtr, AtomicU8, AtomicUsize, Ordering};
+
+#[cfg(target_arch = "aarch64")]
+use std::arch::is_aarch64_feature_detected;
+
+/// An instruction set runtime dispatch can pick.
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+#[repr(u8)]
+pub enum SimdLevel {
+    Scalar = 1,
+    Sse2,
+    Sse41,
+    Avx,
+    Avx2,
+    Avx512,
+    Neon,
+    Wasm,
+}
+
+impl SimdLevel {
+    /// The lowercase name accepted by `SIMDEEZ_MAX_LEVEL`.
+    pub fn name(self) -> &'static str {
+        match self {
+            Self::Scalar => "scalar",
+            Self::Sse2 => "sse2",
+            Self::Sse41 => "sse41",
+            Self::Avx => "avx",
+            Self::Avx2 => "avx2",
+            Self::Avx512 => "avx512",
+            Self::Neon => "neon",
+            Self::Wasm => "wasm",
+        }
+    }
+
+    /// Parses a level name, ignoring ASCII case. `sse4.1` and `avx-512` are also accepted.
+    #[cfg_attr(feature = "no_std", allow(dead_code))]
+    pub(crate) fn parse(name: &str) -> Option<Self> {
+        let name = name.trim();
+        let aliases = [("sse4.1", Self::Sse41), ("avx-512", Self::Avx512)];
+        [
+            Self::Scalar,
+            Self::Sse2,
+            Self::Sse41,
+            Self::Avx,
+            Self::Avx2,
+            Self::Avx512,
+            Self::Neon,
+            Self::Wasm,
+        ]
+        .into_iter()
+        .map(|level| (level.name(), level))
+        .chain(aliases)
+        .find(|(level_name, _)| level_name.eq_ignore_ascii_case(name))
+        .map(|(_, level)| level)
+    }
+
+    fn from_u8(value: u8) -> Option<Self> {
+        Some(match value {
+            1 => Self::Scalar,
+            2 => Self::Sse2,
+            3 => Self::Sse41,
+            4 => Self::Avx,
+            5 => Self::Avx2,
+            6 => Self::Avx512,
+            7 => Self::Neon,
+            8 => Self::Wasm,
+            _ => return None,
+        })
+    }
+
+    /// Whether this level may be used under the cap `max`.
+    fn is_within(self, max: Self) -> bool {
+        const X86: [SimdLevel; 5] = [
+            SimdLevel::Sse2,
+            SimdLevel::Sse41,
+            SimdLevel::Avx,
+            SimdLevel::Avx2,
+            SimdLevel::Avx512,
+        ];
+        let x86_rank = |level| X86.iter().position(|&x| x == level);
+
+        match (self, max) {
+            (Self::Scalar, _) => true,
+            (_, Self::Scalar) => false,
+            _ => match (x86_rank(self), x86_rank(max)) {
+                (Some(rank), Some(max_rank)) => rank <= max_rank,
+                // Neon and Wasm are the only level of their architecture.
+                _ => true,
+            },
+        }
+    }
+}
+
+impl fmt::Display for SimdLevel {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.name())
+    }
+}
+
+const UNRESOLVED: u8 = 0;
+
+static DETECTED: AtomicU8 = AtomicU8::new(UNRESOLVED);
+static SELECTED: AtomicU8 = AtomicU8::new(UNRESOLVED);
+
+/// The cap from [`set_max_level`], or [`UNRESOLVED`] to fall back to `SIMDEEZ_MAX_LEVEL`.
+static MAX_LEVEL: AtomicU8 = AtomicU8::new(UNRESOLVED);
+
+/// Bumped by [`reset`] to invalidate every [`__FnCache`]. Caches start at generation 0, so they
+/// are never valid before their first resolution.
+static GENERATION: AtomicUsize = AtomicUsize::new(1);
+
+/// Returns the best level supported by the running CPU, ignoring any cap.
+#[inline]
+pub fn detected_level() -> SimdLevel {
+    if let Some(level) = SimdLevel::from_u8(DETECTED.load(Ordering::Relaxed)) {
+        return level;
+    }
+    let level = supported_levels().next().unwrap_or(SimdLevel::Scalar);
+    DETECTED.store(level as u8, Ordering::Relaxed);
+    level
+}
+
+/// Returns the level runtime dispatch uses: the best supported level within the cap.
+#[inline(always)]
+pub fn selected_level() -> SimdLevel {
+    match SimdLevel::from_u8(SELECTED.load(Ordering::Relaxed)) {
+        Some(level) => level,
+        None => select_level(),
+    }
+}
+
+#[cold]
+fn select_level() -> SimdLevel {
+    let level = match max_level() {
+        Some(max) => supported_levels()
+            .find(|level| level.is_within(max))
+            .unwrap_or(SimdLevel::Scalar),
+        None => detected_level(),
+    };
+    SELECTED.store(level as u8, Ordering::Relaxed);
+    level
+}
+
+/// Returns the cap on runtime dispatch, from [`set_max_level`] or `SIMDEEZ_MAX_LEVEL`.
+pub fn max_level() -> Option<SimdLevel> {
+    match MAX_LEVEL.load(Ordering::Relaxed) {
+        UNRESOLVED => env_max_level(),
+        level => SimdLevel::from_u8(level),
+    }
+}
+
+/// Caps runtime dispatch at `level` for the whole process, overriding `SIMDEEZ_MAX_LEVEL`.
+/// `None` goes back to the environment variable.
+///
+/// Generated functions pick their version again on their next call. Calls racing with this may
+/// still use the previous choice.
+pub fn set_max_level(level: Option<SimdLevel>) {
+    MAX_LEVEL.store(
+        level.map_or(UNRESOLVED, |level| level as u8),
+        Ordering::Relaxed,
+    );
+    reset();
+}
+
+/// Forgets the detected level and every cached function pointer, so the next call of each
+/// generated function runs feature detection again. A cap set with [`set_max_level`] is kept.
+///
+/// This is meant for tests that change what dispatch should pick. Calls racing with a reset may
+/// still use the previous choice.
+pub fn reset() {
+    DETECTED.store(UNRESOLVED, Ordering::Relaxed);
+    SELECTED.store(UNRESOLVED, Ordering::Relaxed);
+    GENERATION.fetch_add(1, Ordering::Release);
+}
+
+/// A CPU extension beyond its [`SimdLevel`] that an operation uses when the running CPU has it.
+#[cfg(any(target_arch = "x86_64", target_arch = "x86"))]
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+#[repr(u8)]
+pub(crate) enum CpuFeature {
+    /// `pshufb`, for 16 byte lookups on SSE2.
+    Ssse3,
+    /// `vpermb`, for 32 and 64 byte lookups.
+    Avx512Vbmi,
+    /// Compress and expand of 8 and 16 bit lanes.
+    Avx512Vbmi2,
+    /// Bit counts of 8 and 16 bit lanes.
+    Avx512Bitalg,
+    /// Bit counts of 32 and 64 bit lanes.
+    Avx512Vpopcntdq,
+}
+
+#[cfg(any(target_arch = "x86_64", target_arch = "x86"))]
+impl CpuFeature {
+    /// Whether the crate is compiled with this feature, so it needs no runtime check.
+    #[inline(always)]
+    fn is_compiled_in(self) -> bool {
+        match self {
+            Self::Ssse3 => cfg!(target_feature = "ssse3"),
+            Self::Avx512Vbmi => cfg!(target_feature = "avx512vbmi"),
+            Self::Avx512Vbmi2 => cfg!(target_feature = "avx512vbmi2"),
+            Self::Avx512Bitalg => cfg!(target_feature = "avx512bitalg"),
+            Self::Avx512Vpopcntdq => cfg!(target_feature = "avx512vpopcntdq"),
+        }
+    }
+}
+
+/// The [`CpuFeature`] bits of the running CPU, with [`FEATURES_DETECTED`] set once detected.
+#[cfg(any(target_arch = "x86_64", target_arch = "x86"))]
+static FEATURES: AtomicU8 = AtomicU8::new(UNRESOLVED);
+
+#[cfg(any(target_arch = "x86_64", target_arch = "x86"))]
+const FEATURES_DETECTED: u8 = 1 << 7;
+
+/// Whether the running CPU has `feature`. Detection runs once; later calls load a cached bit.
+#[cfg(any(target_arch = "x86_64", target_arch = "x86"))]
+#[inline(always)]
+pub(crate) fn has_feature(feature: CpuFeature) -> bool {
+    #[cfg(test)]
+    if tests::is_disabled(feature) {
+        return false;
+    }
+    if feature.is_compiled_in() {
+        return true;
+    }
+    let mut features = FEATURES.load(Ordering::Relaxed);
+    if features == UNRESOLVED {
+        features = detect_features();
+    }
+    features & (1 << feature as u8) != 0
+}
+
+#[cfg(any(target_arch = "x86_64", target_arch = "x86"))]
+#[cold]
+fn detect_features() -> u8 {
+    let features = [
+        (CpuFeature::Ssse3, is_x86_feature_detected!("ssse3")),
+        (
+            CpuFeature::Avx512Vbmi,
+            is_x86_feature_detected!("avx512vbmi"),
+        ),
+        (
+            CpuFeature::Avx512Vbmi2,
+            is_x86_feature_detected!("avx512vbmi2"),
+        ),
+        (
+            CpuFeature::Avx512Bitalg,
+            is_x86_feature_detected!("avx512bitalg"),
+        ),
+        (
+            CpuFeature::Avx512Vpopcntdq,
+            is_x86_feature_detected!("avx512vpopcntdq"),
+        ),
+    ]
+    .into_iter()
+    .filter(|&(_, supported)| supported)
+    .fold(FEATURES_DETECTED, |bits, (feature, _)| {
+        bits | 1 << feature as u8
+    });
+    FEATURES.store(features, Ordering::Relaxed);
+    features
+}
+
+/// Lets tests take CPU features away from the current thread, to run the paths used without them.
+#[cfg(all(test, any(target_arch = "x86_64", target_arch = "x86")))]
+pub(crate) mod tests {
+    use super::CpuFeature;
+    use std::cell::Cell;
+
+    std::thread_local! {
+        static DISABLED: Cell<u8> = const { Cell::new(0) };
+    }
+
+    pub(crate) fn is_disabled(feature: CpuFeature) -> bool {
+        DISABLED.with(|disabled| disabled.get() & (1 << feature as u8) != 0)
+    }
+
+    /// Runs `f` as if the CPU lacked `features`.
+    pub(crate) fn without_features<R>(features: &[CpuFeature], f: impl FnOnce() -> R) -> R {
+        let bits = features
+            .iter()
+            .fold(0, |bits, &feature| bits | 1 << feature as u8);
+        let previous = DISABLED.with(|disabled| disabled.replace(disabled.get() | bits));
+        let result = f();
+        DISABLED.with(|disabled| disabled.set(previous));
+        result
+    }
+}
+
+#[cfg(not(feature = "no_std"))]
+fn env_max_level() -> Option<SimdLevel> {
+    // Unknown names are ignored rather than failing every dispatched call.
+    SimdLevel::parse(&std::env::var("SIMDEEZ_MAX_LEVEL").ok()?)
+}
+
+#[cfg(feature = "no_std")]
+fn env_max_level() -> Option<SimdLevel> {
+    None
+}
+
+/// The SIMD levels of the running CPU, best first.
+fn supported_levels() -> impl Iterator<Item = SimdLevel> {
+    #[cfg(any(target_arch = "x86_64", target_arch = "x86"))]
+    let levels = [
+        (
+            SimdLevel::Avx512,
+            is_x86_feature_detected!("avx512f")
+                && is_x86_feature_detected!("avx512bw")
+                && is_x86_feature_detected!("avx512dq"),
+        ),
+        (
+            SimdLevel::Avx2,
+            is_x86_feature_detected!("avx2") && is_x86_feature_detected!("fma"),
+        ),
+        (SimdLevel::Avx, is_x86_feature_detected!("avx")),
+        (SimdLevel::Sse41, is_x86_feature_detected!("sse4.1")),
+        (SimdLevel::Sse2, is_x86_feature_detected!("sse2")),
+    ];
+
+    #[cfg(target_arch = "aarch64")]
+    let levels = [(SimdLevel::Neon, is_aarch64_feature_detected!("neon"))];
+
+    // Note: there's currently no way to detect SIMD support in WebAssembly at runtime
+    #[cfg(target_arch = "wasm32")]
+    let levels = [(SimdLevel::Wasm, true)];
+
+    #[cfg(not(any(
+        target_arch = "x86_64",
+        target_arch = "x86",
+        target_arch = "aarch64",
+        target_arch = "wasm32"
+    )))]
+    let levels: [(SimdLevel, bool); 0] = [];
+
+    levels
+        .into_iter()
+        .filter(|&(_, supported)| supported)
+        .map(|(level, _)| level)
+}
+
+/// The function pointer a generated function resolved to, stored type-erased.
+#[doc(hidden)]
+pub struct __FnCache {
+    function: AtomicPtr<()>,
+    generation: AtomicUsize,
+}
+
+impl Default for __FnCache {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl __FnCache {
+    pub const fn new() -> Self {
+        Self {
+            function: AtomicPtr::new(core::ptr::null_mut()),
+            generation: AtomicUsize::new(0),
+        }
+    }
+
+    /// Returns the cached pointer, calling `resolve` first if it was never resolved or a
+    /// [`reset`] happened since.
+    #[inline(always)]
+    pub fn get_or_resolve(&self, resolve: impl FnOnce() -> *const ()) -> *const () {
+        let generation = GENERATION.load(Ordering::Acquire);
+        if self.generation.load(Ordering::Acquire) == generation {
+            return self.function.load(Ordering::Relaxed);
+        }
+        self.resolve(generation, resolve)
+    }
+
+    #[cold]
+    #[inline(never)]
+    fn resolve(&self, generation: usize, resolve: impl FnOnce() -> *const ()) -> *const () {
+        let function = resolve();
+        self.function.store(function as *mut (), Ordering::Relaxed);
+        self.generation.store(generation, Ordering::Release);
+        function
+    }
+}
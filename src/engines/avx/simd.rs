@@ -0,0 +1,92 @@
+#![allow(deprecated)]
+
+use super::*;
+use crate::Simd;
+use core::mem;
+
+pub struct Avx;
+impl Simd for Avx {
+    type Vi8 = I8x32;
+    type Vi16 = I16x16;
+    type Vi32 = I32x8;
+    type Vf32 = F32x8;
+    type Vf64 = F64x4;
+    type Vi64 = I64x4;
+    type Vu8 = U8x32;
+    type Vu16 = U16x16;
+    type Vu32 = U32x8;
+    type Vu64 = U64x4;
+
+    #[inline]
+    fn invoke<R>(f: impl FnOnce() -> R) -> R {
+        #[inline]
+        #[target_feature(enable = "avx")]
+        unsafe fn inner<R>(f: impl FnOnce() -> R) -> R {
+            f()
+        }
+
+        unsafe { inner(f) }
+    }
+
+    #[inline(always)]
+    unsafe fn castps_pd(a: Self::Vf32) -> Self::Vf64 {
+        F64x4(_mm256_castps_pd(a.0))
+    }
+
+    #[inline(always)]
+    unsafe fn castpd_ps(a: Self::Vf64) -> Self::Vf32 {
+        F32x8(_mm256_castpd_ps(a.0))
+    }
+
+    #[inline(always)]
+    unsafe fn i32gather_epi32(arr: &[i32], index: Self::Vi32) -> Self::Vi32 {
+        let index_as_arr = mem::transmute::<I32x8, [i32; 8]>(index);
+        I32x8(mem::transmute::<[i32; 8], __m256i>(
+            index_as_arr.map(|index| arr[index as usize]),
+        ))
+    }
+
+    #[inline(always)]
+    unsafe fn i64gather_epi64(arr: &[i64], index: Self::Vi64) -> Self::Vi64 {
+        let index_as_arr = mem::transmute::<I64x4, [i64; 4]>(index);
+        I64x4(mem::transmute::<[i64; 4], __m256i>(
+            index_as_arr.map(|index| arr[index as usize]),
+        ))
+    }
+
+    #[inline(always)]
+    unsafe fn i32gather_ps(arr: &[f32], index: Self::Vi32) -> Self::Vf32 {
+        let index_as_arr = mem::transmute::<I32x8, [i32; 8]>(index);
+        F32x8(mem::transmute::<[f32; 8], __m256>(
+            index_as_arr.map(|index| arr[index as usize]),
+        ))
+    }
+
+    #[inline(always)]
+    unsafe fn maskload_epi32(mem_addr: &i32, mask: Self::Vi32) -> Self::Vi32 {
+        let loaded = _mm256_maskload_ps(mem_addr as *const i32 as *const f32, mask.0);
+        I32x8(_mm256_castps_si256(loaded))
+    }
+
+    #[inline(always)]
+    unsafe fn maskload_epi64(mem_addr: &i64, mask: Self::Vi64) -> Self::Vi64 {
+        let loaded = _mm256_maskload_pd(mem_addr as *const i64 as *const f64, mask.0);
+        I64x4(_mm256_castpd_si256(loaded))
+    }
+
+    #[inline(always)]
+    unsafe fn maskload_ps(mem_addr: &f32, mask: Self::Vi32) -> Self::Vf32 {
+        F32x8(_mm256_maskload_ps(mem_addr as *const f32, mask.0))
+    }
+
+    #[inline(always)]
+    unsafe fn maskload_pd(mem_addr: &f64, mask: Self::Vi64) -> Self::Vf64 {
+        F64x4(_mm256_maskload_pd(mem_addr as *const f64, mask.0))
+    }
+
+    #[inline(always)]
+    unsafe fn shuffle_epi32<const IMM8: i32>(a: Self::Vi32) -> Self::Vi32 {
+        let shuffled = _mm256_permute_ps::<IMM8>(_mm256_castsi256_ps(a.0));
+        I32x8(_mm256_castps_si256(shuffled))
+    }
+}
@@ -11,6 +11,10 @@ impl Simd for Avx2 {
     type Vf32 = F32x8;
     type Vf64 = F64x4;
     type Vi64 = I64x4;
+    type Vu8 = U8x32;
+    type Vu16 = U16x16;
+    type Vu32 = U32x8;
+    type Vu64 = U64x4;
 
     #[inline]
     fn invoke<R>(f: impl FnOnce() -> R) -> R {
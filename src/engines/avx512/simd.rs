@@ -11,6 +11,10 @@ impl Simd for Avx512 {
     type Vf32 = F32x16;
     type Vf64 = F64x8;
     type Vi64 = I64x8;
+    type Vu8 = U8x64;
+    type Vu16 = U16x32;
+    type Vu32 = U32x16;
+    type Vu64 = U64x8;
 
     #[inline]
     fn invoke<R>(f: impl FnOnce() -> R) -> R {
@@ -1,5 +1,7 @@
 pub mod scalar;
 
+#[cfg(any(target_arch = "x86_64", target_arch = "x86"))]
+pub mod avx;
 #[cfg(any(target_arch = "x86_64", target_arch = "x86"))]
 pub mod avx2;
 #[cfg(any(target_arch = "x86_64", target_arch = "x86"))]
@@ -9,6 +9,10 @@ impl Simd for Neon {
     type Vf32 = F32x4Neon;
     type Vf64 = F64x2Neon;
     type Vi64 = I64x2Neon;
+    type Vu8 = U8x16Neon;
+    type Vu16 = U16x8Neon;
+    type Vu32 = U32x4Neon;
+    type Vu64 = U64x2Neon;
 
     #[inline]
     fn invoke<R>(f: impl FnOnce() -> R) -> R {
@@ -8,27 +8,43 @@ pub use self::simd::*;
 
 define_simd_type!(Scalar, i8, 1, i8);
 impl_simd_int_overloads!(I8x1);
-impl_i8_simd_type!(Scalar, I8x1, I16x1, u32);
+impl_i8_simd_type!(Scalar, I8x1, I16x1, U8x1, u32);
 
 define_simd_type!(Scalar, i16, 1, i16);
 impl_simd_int_overloads!(I16x1);
-impl_i16_simd_type!(Scalar, I16x1, I32x1);
+impl_i16_simd_type!(Scalar, I16x1, I32x1, U16x1, I8x1, U8x1);
 
 define_simd_type!(Scalar, i32, 1, i32);
 impl_simd_int_overloads!(I32x1);
-impl_i32_simd_type!(Scalar, I32x1, F32x1, I64x1);
+impl_i32_simd_type!(Scalar, I32x1, F32x1, I64x1, U32x1, I16x1, U16x1, F64x1);
 
 define_simd_type!(Scalar, i64, 1, i64);
 impl_simd_int_overloads!(I64x1);
-impl_i64_simd_type!(Scalar, I64x1, F64x1);
+impl_i64_simd_type!(Scalar, I64x1, F64x1, U64x1, I32x1, U32x1);
+
+define_simd_type!(Scalar, u8, 1, u8);
+impl_simd_int_overloads!(U8x1);
+impl_u8_simd_type!(Scalar, U8x1, I8x1, U16x1, I16x1, U64x1);
+
+define_simd_type!(Scalar, u16, 1, u16);
+impl_simd_int_overloads!(U16x1);
+impl_u16_simd_type!(Scalar, U16x1, I16x1, U32x1);
+
+define_simd_type!(Scalar, u32, 1, u32);
+impl_simd_int_overloads!(U32x1);
+impl_u32_simd_type!(Scalar, U32x1, I32x1, U64x1);
+
+define_simd_type!(Scalar, u64, 1, u64);
+impl_simd_int_overloads!(U64x1);
+impl_u64_simd_type!(Scalar, U64x1, I64x1);
 
 define_simd_type!(Scalar, f32, 1, f32);
 impl_simd_float_overloads!(F32x1);
-impl_f32_simd_type!(Scalar, F32x1, I32x1);
+impl_f32_simd_type!(Scalar, F32x1, I32x1, F64x1);
 
 define_simd_type!(Scalar, f64, 1, f64);
 impl_simd_float_overloads!(F64x1);
-impl_f64_simd_type!(Scalar, F64x1, I64x1);
+impl_f64_simd_type!(Scalar, F64x1, I64x1, F32x1, I32x1);
 
 impl From<i8> for I8x1 {
     fn from(val: i8) -> Self {
@@ -54,6 +70,30 @@ impl From<i64> for I64x1 {
     }
 }
 
+impl From<u8> for U8x1 {
+    fn from(val: u8) -> Self {
+        U8x1(val)
+    }
+}
+
+impl From<u16> for U16x1 {
+    fn from(val: u16) -> Self {
+        U16x1(val)
+    }
+}
+
+impl From<u32> for U32x1 {
+    fn from(val: u32) -> Self {
+        U32x1(val)
+    }
+}
+
+impl From<u64> for U64x1 {
+    fn from(val: u64) -> Self {
+        U64x1(val)
+    }
+}
+
 impl From<f32> for F32x1 {
     fn from(val: f32) -> Self {
         F32x1(val)
@@ -90,6 +130,30 @@ impl From<I64x1> for i64 {
     }
 }
 
+impl From<U8x1> for u8 {
+    fn from(v: U8x1) -> u8 {
+        v.0
+    }
+}
+
+impl From<U16x1> for u16 {
+    fn from(v: U16x1) -> u16 {
+        v.0
+    }
+}
+
+impl From<U32x1> for u32 {
+    fn from(v: U32x1) -> u32 {
+        v.0
+    }
+}
+
+impl From<U64x1> for u64 {
+    fn from(v: U64x1) -> u64 {
+        v.0
+    }
+}
+
 impl From<F32x1> for f32 {
     fn from(v: F32x1) -> f32 {
         v.0
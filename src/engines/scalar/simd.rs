@@ -9,6 +9,10 @@ impl Simd for Scalar {
     type Vf32 = F32x1;
     type Vf64 = F64x1;
     type Vi64 = I64x1;
+    type Vu8 = U8x1;
+    type Vu16 = U16x1;
+    type Vu32 = U32x1;
+    type Vu64 = U64x1;
 
     #[inline]
     fn invoke<R>(f: impl FnOnce() -> R) -> R {
@@ -13,24 +13,40 @@ pub use self::simd::*;
 
 define_simd_type!(Sse2, i8, 16, __m128i);
 impl_simd_int_overloads!(I8x16);
-impl_i8_simd_type!(Sse2, I8x16, I16x8, u32);
+impl_i8_simd_type!(Sse2, I8x16, I16x8, U8x16, u32);
 
 define_simd_type!(Sse2, i16, 8, __m128i);
 impl_simd_int_overloads!(I16x8);
-impl_i16_simd_type!(Sse2, I16x8, I32x4);
+impl_i16_simd_type!(Sse2, I16x8, I32x4, U16x8, I8x16, U8x16);
 
 define_simd_type!(Sse2, i32, 4, __m128i);
 impl_simd_int_overloads!(I32x4);
-impl_i32_simd_type!(Sse2, I32x4, F32x4, I64x2);
+impl_i32_simd_type!(Sse2, I32x4, F32x4, I64x2, U32x4, I16x8, U16x8, F64x2);
 
 define_simd_type!(Sse2, i64, 2, __m128i);
 impl_simd_int_overloads!(I64x2);
-impl_i64_simd_type!(Sse2, I64x2, F64x2);
+impl_i64_simd_type!(Sse2, I64x2, F64x2, U64x2, I32x4, U32x4);
+
+define_simd_type!(Sse2, u8, 16, __m128i);
+impl_simd_int_overloads!(U8x16);
+impl_u8_simd_type!(Sse2, U8x16, I8x16, U16x8, I16x8, U64x2);
+
+define_simd_type!(Sse2, u16, 8, __m128i);
+impl_simd_int_overloads!(U16x8);
+impl_u16_simd_type!(Sse2, U16x8, I16x8, U32x4);
+
+define_simd_type!(Sse2, u32, 4, __m128i);
+impl_simd_int_overloads!(U32x4);
+impl_u32_simd_type!(Sse2, U32x4, I32x4, U64x2);
+
+define_simd_type!(Sse2, u64, 2, __m128i);
+impl_simd_int_overloads!(U64x2);
+impl_u64_simd_type!(Sse2, U64x2, I64x2);
 
 define_simd_type!(Sse2, f32, 4, __m128);
 impl_simd_float_overloads!(F32x4);
-impl_f32_simd_type!(Sse2, F32x4, I32x4);
+impl_f32_simd_type!(Sse2, F32x4, I32x4, F64x2);
 
 define_simd_type!(Sse2, f64, 2, __m128d);
 impl_simd_float_overloads!(F64x2);
-impl_f64_simd_type!(Sse2, F64x2, I64x2);
+impl_f64_simd_type!(Sse2, F64x2, I64x2, F32x4, I32x4);
@@ -11,6 +11,10 @@ impl Simd for Sse2 {
     type Vf32 = F32x4;
     type Vf64 = F64x2;
     type Vi64 = I64x2;
+    type Vu8 = U8x16;
+    type Vu16 = U16x8;
+    type Vu32 = U32x4;
+    type Vu64 = U64x2;
 
     #[inline]
     fn invoke<R>(f: impl FnOnce() -> R) -> R {
@@ -13,24 +13,40 @@ pub use self::simd::*;
 
 define_simd_type!(Sse41, i8, 16, __m128i, _41);
 impl_simd_int_overloads!(I8x16_41);
-impl_i8_simd_type!(Sse41, I8x16_41, I16x8_41, u32);
+impl_i8_simd_type!(Sse41, I8x16_41, I16x8_41, U8x16_41, u32);
 
 define_simd_type!(Sse41, i16, 8, __m128i, _41);
 impl_simd_int_overloads!(I16x8_41);
-impl_i16_simd_type!(Sse41, I16x8_41, I32x4_41);
+impl_i16_simd_type!(Sse41, I16x8_41, I32x4_41, U16x8_41, I8x16_41, U8x16_41);
 
 define_simd_type!(Sse41, i32, 4, __m128i, _41);
 impl_simd_int_overloads!(I32x4_41);
-impl_i32_simd_type!(Sse41, I32x4_41, F32x4_41, I64x2_41);
+impl_i32_simd_type!(Sse41, I32x4_41, F32x4_41, I64x2_41, U32x4_41, I16x8_41, U16x8_41, F64x2_41);
 
 define_simd_type!(Sse41, i64, 2, __m128i, _41);
 impl_simd_int_overloads!(I64x2_41);
-impl_i64_simd_type!(Sse41, I64x2_41, F64x2_41);
+impl_i64_simd_type!(Sse41, I64x2_41, F64x2_41, U64x2_41, I32x4_41, U32x4_41);
+
+define_simd_type!(Sse41, u8, 16, __m128i, _41);
+impl_simd_int_overloads!(U8x16_41);
+impl_u8_simd_type!(Sse41, U8x16_41, I8x16_41, U16x8_41, I16x8_41, U64x2_41);
+
+define_simd_type!(Sse41, u16, 8, __m128i, _41);
+impl_simd_int_overloads!(U16x8_41);
+impl_u16_simd_type!(Sse41, U16x8_41, I16x8_41, U32x4_41);
+
+define_simd_type!(Sse41, u32, 4, __m128i, _41);
+impl_simd_int_overloads!(U32x4_41);
+impl_u32_simd_type!(Sse41, U32x4_41, I32x4_41, U64x2_41);
+
+define_simd_type!(Sse41, u64, 2, __m128i, _41);
+impl_simd_int_overloads!(U64x2_41);
+impl_u64_simd_type!(Sse41, U64x2_41, I64x2_41);
 
 define_simd_type!(Sse41, f32, 4, __m128, _41);
 impl_simd_float_overloads!(F32x4_41);
-impl_f32_simd_type!(Sse41, F32x4_41, I32x4_41);
+impl_f32_simd_type!(Sse41, F32x4_41, I32x4_41, F64x2_41);
 
 define_simd_type!(Sse41, f64, 2, __m128d, _41);
 impl_simd_float_overloads!(F64x2_41);
-impl_f64_simd_type!(Sse41, F64x2_41, I64x2_41);
+impl_f64_simd_type!(Sse41, F64x2_41, I64x2_41, F32x4_41, I32x4_41);
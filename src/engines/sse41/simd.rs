@@ -11,6 +11,10 @@ impl Simd for Sse41 {
     type Vf32 = F32x4_41;
     type Vf64 = F64x2_41;
     type Vi64 = I64x2_41;
+    type Vu8 = U8x16_41;
+    type Vu16 = U16x8_41;
+    type Vu32 = U32x4_41;
+    type Vu64 = U64x2_41;
 
     #[inline]
     fn invoke<R>(f: impl FnOnce() -> R) -> R {
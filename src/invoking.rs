@@ -1,7 +1,4 @@
-use crate::{engines, Simd};
-
-#[cfg(target_arch = "aarch64")]
-use std::arch::is_aarch64_feature_detected;
+use crate::{engines, Simd, SimdLevel};
 
 #[macro_export]
 macro_rules! fix_tuple_type {
@@ -50,176 +47,386 @@ macro_rules! __simd_generate_base {
 
 #[macro_export]
 macro_rules! simd_runtime_generate {
-    ($(#[$meta:meta])* $vis:vis fn $fn_name:ident $(<$($lt:lifetime),+>)? ($($arg:ident:$typ:ty),* $(,)? ) -> $rt:ty $body:block  ) => {
-        simdeez_paste_item! {
-            // In order to pass arguments via generics like this, we need to convert the arguments
-            // into tuples. This is part of the reason for the mess below.
-
-            $(#[$meta])*
-            #[inline(always)]
-            $vis fn $fn_name $(<$($lt),+>)?($($arg:$typ,)*) -> $rt {
-                let args_tuple = ($($arg,)*);
-                __run_simd_runtime_decide::<[<__ $fn_name _dispatch_struct>], fix_tuple_type!(($($typ),*)), $rt>(args_tuple)
-            }
-
-            $(#[$meta])*
-            #[inline(always)]
-            $vis fn [<$fn_name _scalar>] $(<$($lt),+>)?($($arg:$typ,)*) -> $rt {
-                let args_tuple = ($($arg,)*);
-                __run_simd_invoke_scalar::<[<__ $fn_name _dispatch_struct>], fix_tuple_type!(($($typ),*)), $rt>(args_tuple)
-            }
+    ($(#[$meta:meta])* $vis:vis fn $fn_name:ident $($rest:tt)+) => {
+        $crate::__simd_generate_dispatch!([runtime [$(#[$meta])*] [$vis] $fn_name] $($rest)+);
+    };
+}
 
-            __simd_generate_base!($(#[$meta])* $vis fn $fn_name $(<$($lt),+>)? ($($arg:$typ),* ) -> $rt $body);
-        }
+#[macro_export]
+macro_rules! simd_compiletime_select {
+    ($(#[$meta:meta])* $vis:vis fn $fn_name:ident $($rest:tt)+) => {
+        $crate::__simd_generate_dispatch!([compiletime [$(#[$meta])*] [$vis] $fn_name] $($rest)+);
     };
-    ($(#[$meta:meta])* $vis:vis fn $fn_name:ident ($($arg:ident:$typ:ty),* $(,)? ) $body:block  ) => {
-        simd_runtime_generate!($(#[$meta])* $vis fn $fn_name ($($arg:$typ),*) -> () $body);
+}
+
+#[macro_export]
+macro_rules! simd_unsafe_generate_all {
+    ($(#[$meta:meta])* $vis:vis fn $fn_name:ident $($rest:tt)+) => {
+        $crate::__simd_generate_dispatch!([all [$(#[$meta])*] [$vis] $fn_name] $($rest)+);
     };
 }
 
+/// Splits a function signature into generics, receiver, arguments, return type and where clause,
+/// then emits the functions generated by [`simd_runtime_generate`] and friends.
+///
+/// Instead of packing arguments into a tuple for a runner struct, the engine is passed to the
+/// generic body as a `PhantomData<S>` argument. That keeps every generic parameter, `impl Trait`
+/// argument and `self` receiver in the signature of the item that declares it, which also lets
+/// the macros be used inside `impl` blocks. Generic parameters must be inferable from the
+/// arguments or the return type.
+#[doc(hidden)]
 #[macro_export]
-macro_rules! simd_compiletime_select {
-    ($(#[$meta:meta])* $vis:vis fn $fn_name:ident $(<$($lt:lifetime),+>)? ($($arg:ident:$typ:ty),* $(,)? ) -> $rt:ty $body:block  ) => {
+macro_rules! __simd_generate_dispatch {
+    // Generic parameters are collected token by token while tracking `<` nesting. The trailing
+    // `[$sep]` records whether a comma is needed before appending the engine parameter.
+    ($ctx:tt < $($rest:tt)*) => {
+        $crate::__simd_generate_dispatch!(@generics $ctx [] [@] [] $($rest)*);
+    };
+    ($ctx:tt ($($args:tt)*) $($rest:tt)*) => {
+        $crate::__simd_generate_dispatch!(@receiver $ctx [] [$($args)*] $($rest)*);
+    };
+
+    (@generics $ctx:tt [$($gen:tt)*] [@] [$($sep:tt)?] > ($($args:tt)*) $($rest:tt)*) => {
+        $crate::__simd_generate_dispatch!(@receiver $ctx [$($gen)* $($sep)?] [$($args)*] $($rest)*);
+    };
+    (@generics $ctx:tt [$($gen:tt)*] [@ @] $sep:tt >> ($($args:tt)*) $($rest:tt)*) => {
+        $crate::__simd_generate_dispatch!(@receiver $ctx [$($gen)* >,] [$($args)*] $($rest)*);
+    };
+    (@generics $ctx:tt [$($gen:tt)*] [@ $($depth:tt)+] $sep:tt > $($rest:tt)*) => {
+        $crate::__simd_generate_dispatch!(@generics $ctx [$($gen)* >] [$($depth)+] [,] $($rest)*);
+    };
+    (@generics $ctx:tt [$($gen:tt)*] [@ @ $($depth:tt)+] $sep:tt >> $($rest:tt)*) => {
+        $crate::__simd_generate_dispatch!(@generics $ctx [$($gen)* >>] [$($depth)+] [,] $($rest)*);
+    };
+    (@generics $ctx:tt [$($gen:tt)*] [$($depth:tt)+] $sep:tt < $($rest:tt)*) => {
+        $crate::__simd_generate_dispatch!(@generics $ctx [$($gen)* <] [@ $($depth)+] [,] $($rest)*);
+    };
+    (@generics $ctx:tt [$($gen:tt)*] [@] $sep:tt , $($rest:tt)*) => {
+        $crate::__simd_generate_dispatch!(@generics $ctx [$($gen)* ,] [@] [] $($rest)*);
+    };
+    (@generics $ctx:tt [$($gen:tt)*] $depth:tt $sep:tt $token:tt $($rest:tt)*) => {
+        $crate::__simd_generate_dispatch!(@generics $ctx [$($gen)* $token] $depth [,] $($rest)*);
+    };
+
+    // The receiver is kept with its trailing comma, alongside the `self` token to forward.
+    (@receiver $ctx:tt $gen:tt [& $lt:lifetime mut $self_:ident $(, $($args:tt)*)?] $($rest:tt)*) => {
+        $crate::__simd_generate_dispatch!(@ret $ctx $gen [[& $lt mut $self_,] [$self_]] [$($($args)*)?] [] $($rest)*);
+    };
+    (@receiver $ctx:tt $gen:tt [& $lt:lifetime $self_:ident $(, $($args:tt)*)?] $($rest:tt)*) => {
+        $crate::__simd_generate_dispatch!(@ret $ctx $gen [[& $lt $self_,] [$self_]] [$($($args)*)?] [] $($rest)*);
+    };
+    (@receiver $ctx:tt $gen:tt [& mut $self_:ident $(, $($args:tt)*)?] $($rest:tt)*) => {
+        $crate::__simd_generate_dispatch!(@ret $ctx $gen [[& mut $self_,] [$self_]] [$($($args)*)?] [] $($rest)*);
+    };
+    (@receiver $ctx:tt $gen:tt [& $self_:ident $(, $($args:tt)*)?] $($rest:tt)*) => {
+        $crate::__simd_generate_dispatch!(@ret $ctx $gen [[& $self_,] [$self_]] [$($($args)*)?] [] $($rest)*);
+    };
+    (@receiver $ctx:tt $gen:tt [mut $self_:ident $(, $($args:tt)*)?] $($rest:tt)*) => {
+        $crate::__simd_generate_dispatch!(@ret $ctx $gen [[mut $self_,] [$self_]] [$($($args)*)?] [] $($rest)*);
+    };
+    (@receiver $ctx:tt $gen:tt [$self_:ident $(, $($args:tt)*)?] $($rest:tt)*) => {
+        $crate::__simd_generate_dispatch!(@ret $ctx $gen [[$self_,] [$self_]] [$($($args)*)?] [] $($rest)*);
+    };
+    (@receiver $ctx:tt $gen:tt [$($args:tt)*] $($rest:tt)*) => {
+        $crate::__simd_generate_dispatch!(@ret $ctx $gen [[] []] [$($args)*] [] $($rest)*);
+    };
+
+    // Everything up to `where` or the body is the return type, arrow included.
+    (@ret $ctx:tt $gen:tt $recv:tt $args:tt [$($ret:tt)*] where $($rest:tt)+) => {
+        $crate::__simd_generate_dispatch!(@where $ctx $gen $recv $args [$($ret)*] [where] $($rest)+);
+    };
+    (@ret $ctx:tt $gen:tt $recv:tt $args:tt [$($ret:tt)*] $body:block) => {
+        $crate::__simd_generate_dispatch!(@emit $ctx $gen $recv $args [$($ret)*] [] $body);
+    };
+    (@ret $ctx:tt $gen:tt $recv:tt $args:tt [$($ret:tt)*] $token:tt $($rest:tt)+) => {
+        $crate::__simd_generate_dispatch!(@ret $ctx $gen $recv $args [$($ret)* $token] $($rest)+);
+    };
+
+    (@where $ctx:tt $gen:tt $recv:tt $args:tt $ret:tt [$($wc:tt)*] $body:block) => {
+        $crate::__simd_generate_dispatch!(@emit $ctx $gen $recv $args $ret [$($wc)*] $body);
+    };
+    (@where $ctx:tt $gen:tt $recv:tt $args:tt $ret:tt [$($wc:tt)*] $token:tt $($rest:tt)+) => {
+        $crate::__simd_generate_dispatch!(@where $ctx $gen $recv $args $ret [$($wc)* $token] $($rest)+);
+    };
+
+    // Free functions call the generic body directly, methods go through `Self`.
+    // A static inside a generic item is shared by every instantiation, so only free functions
+    // whose signature can be written as a fn pointer type cache a pointer of their own. The rest
+    // match on the cached engine.
+    (@emit $ctx:tt [$($lt:lifetime,)*] [[] []] [$($args:tt)*] $ret:tt [] $body:block) => {
+        $crate::__simd_generate_dispatch!(@scan [$ctx [$($lt,)*] [$($args)*] $ret $body] $($args)*);
+    };
+    (@emit $ctx:tt $gen:tt [[] []] $args:tt $ret:tt $wc:tt $body:block) => {
+        $crate::__simd_generate_dispatch!(@items $ctx runtime $gen [[] [] []] $args $ret $wc $body);
+    };
+    (@emit $ctx:tt $gen:tt [[$($recv:tt)+] [$self_:ident]] $args:tt $ret:tt $wc:tt $body:block) => {
+        $crate::__simd_generate_dispatch!(@items $ctx runtime $gen [[$($recv)+] [$self_,] [Self::]] $args $ret $wc $body);
+    };
+
+    // `impl Trait` arguments can't be named in a fn pointer type.
+    (@scan [$ctx:tt $gen:tt $args:tt $ret:tt $body:block] impl $($rest:tt)*) => {
+        $crate::__simd_generate_dispatch!(@items $ctx runtime $gen [[] [] []] $args $ret [] $body);
+    };
+    (@scan $state:tt ($($inner:tt)*) $($rest:tt)*) => {
+        $crate::__simd_generate_dispatch!(@scan $state $($inner)* $($rest)*);
+    };
+    (@scan $state:tt [$($inner:tt)*] $($rest:tt)*) => {
+        $crate::__simd_generate_dispatch!(@scan $state $($inner)* $($rest)*);
+    };
+    (@scan $state:tt $token:tt $($rest:tt)*) => {
+        $crate::__simd_generate_dispatch!(@scan $state $($rest)*);
+    };
+    (@scan [$ctx:tt $gen:tt $args:tt $ret:tt $body:block]) => {
+        $crate::__simd_generate_dispatch!(@items $ctx cached $gen [[] [] []] $args $ret [] $body);
+    };
+
+    (@items
+        [$kind:ident [$($meta:tt)*] [$vis:vis] $fn_name:ident]
+        $runtime:ident
+        [$($gen:tt)*]
+        [[$($recv:tt)*] [$($self_:tt)*] [$($path:tt)*]]
+        [$($arg:ident : $typ:ty),* $(,)?]
+        [$($ret:tt)*]
+        [$($wc:tt)*]
+        $body:block
+    ) => {
         simdeez_paste_item! {
-            $(#[$meta])*
             #[inline(always)]
-            $vis fn $fn_name $(<$($lt),+>)?($($arg:$typ,)*) -> $rt {
-                let args_tuple = ($($arg,)*);
-                __run_simd_compiletime_select::<[<__ $fn_name _dispatch_struct>], fix_tuple_type!(($($typ),*)), $rt>(args_tuple)
+            #[allow(clippy::too_many_arguments)]
+            $vis unsafe fn [<__ $fn_name _generic>]<$($gen)* S: 'static + Simd>(
+                $($recv)* $($arg: $typ,)* _engine: ::core::marker::PhantomData<S>
+            ) $($ret)* $($wc)* {
+                S::invoke(#[inline(always)] || $body)
             }
 
-            $(#[$meta])*
+            $($meta)*
             #[inline(always)]
-            $vis fn [<$fn_name _scalar>] $(<$($lt),+>)?($($arg:$typ,)*) -> $rt {
-                let args_tuple = ($($arg,)*);
-                __run_simd_invoke_scalar::<[<__ $fn_name _dispatch_struct>], fix_tuple_type!(($($typ),*)), $rt>(args_tuple)
+            $vis fn [<$fn_name _generic>]<$($gen)* S: Simd>($($recv)* $($arg: $typ),*) $($ret)* $($wc)* {
+                unsafe {
+                    $($path)* [<__ $fn_name _generic>](
+                        $($self_)* $($arg,)* ::core::marker::PhantomData::<S>
+                    )
+                }
             }
 
-            __simd_generate_base!($(#[$meta])* $vis fn $fn_name $(<$($lt),+>)? ($($arg:$typ),* ) -> $rt $body);
+            $crate::__simd_generate_dispatch!(@entry $kind $runtime
+                [$($meta)*] [$vis] $fn_name [$($gen)*] [$($recv)*] [$($arg: $typ),*] [$($ret)*] [$($wc)*]
+                [$($path)* [<__ $fn_name _generic>]] [$($self_)* $($arg,)*]
+            );
         }
     };
-    ($(#[$meta:meta])* $vis:vis fn $fn_name:ident ($($arg:ident:$typ:ty),* $(,)? ) $body:block  ) => {
-        simd_compiletime_select!($(#[$meta])* $vis fn $fn_name ($($arg:$typ),*) -> () $body);
+
+    (@entry runtime $runtime:ident $meta:tt $vis:tt $fn_name:ident $gen:tt $recv:tt $args:tt $ret:tt $wc:tt $call:tt $params:tt) => {
+        $crate::__simd_generate_dispatch!(@variant $meta $vis $fn_name $gen $recv $args $ret $wc
+            [] [] [$runtime $call $params]);
+        $crate::__simd_generate_dispatch!(@variant $meta $vis $fn_name $gen $recv $args $ret $wc
+            [] [_scalar] [$crate::engines::scalar::Scalar $call $params]);
+    };
+    (@entry compiletime $runtime:ident $meta:tt $vis:tt $fn_name:ident $gen:tt $recv:tt $args:tt $ret:tt $wc:tt $call:tt $params:tt) => {
+        $crate::__simd_generate_dispatch!(@variant $meta $vis $fn_name $gen $recv $args $ret $wc
+            [] [] [$crate::prelude::__SimdCompiletimeEngine $call $params]);
+        $crate::__simd_generate_dispatch!(@variant $meta $vis $fn_name $gen $recv $args $ret $wc
+            [] [_scalar] [$crate::engines::scalar::Scalar $call $params]);
+    };
+    (@entry all $runtime:ident $meta:tt $vis:tt $fn_name:ident $gen:tt $recv:tt $args:tt $ret:tt $wc:tt $call:tt $params:tt) => {
+        $crate::__simd_generate_dispatch!(@variant $meta $vis $fn_name $gen $recv $args $ret $wc
+            [] [] [$runtime $call $params]);
+        $crate::__simd_generate_dispatch!(@variant $meta $vis $fn_name $gen $recv $args $ret $wc
+            [#[cfg(any(target_arch = "x86_64", target_arch = "x86"))]] [_scalar]
+            [$crate::engines::scalar::Scalar $call $params]);
+        $crate::__simd_generate_dispatch!(@variant $meta $vis $fn_name $gen $recv $args $ret $wc
+            [#[cfg(any(target_arch = "x86_64", target_arch = "x86"))] unsafe] [_sse2]
+            [$crate::engines::sse2::Sse2 $call $params]);
+        $crate::__simd_generate_dispatch!(@variant $meta $vis $fn_name $gen $recv $args $ret $wc
+            [#[cfg(any(target_arch = "x86_64", target_arch = "x86"))] unsafe] [_sse41]
+            [$crate::engines::sse41::Sse41 $call $params]);
+        $crate::__simd_generate_dispatch!(@variant $meta $vis $fn_name $gen $recv $args $ret $wc
+            [#[cfg(any(target_arch = "x86_64", target_arch = "x86"))] unsafe] [_avx]
+            [$crate::engines::avx::Avx $call $params]);
+        $crate::__simd_generate_dispatch!(@variant $meta $vis $fn_name $gen $recv $args $ret $wc
+            [#[cfg(any(target_arch = "x86_64", target_arch = "x86"))] unsafe] [_avx2]
+            [$crate::engines::avx2::Avx2 $call $params]);
+        $crate::__simd_generate_dispatch!(@variant $meta $vis $fn_name $gen $recv $args $ret $wc
+            [#[cfg(any(target_arch = "x86_64", target_arch = "x86"))] unsafe] [_avx512]
+            [$crate::engines::avx512::Avx512 $call $params]);
+        $crate::__simd_generate_dispatch!(@variant $meta $vis $fn_name $gen $recv $args $ret $wc
+            [#[cfg(target_arch = "aarch64")] unsafe] [_neon]
+            [$crate::engines::neon::Neon $call $params]);
+        $crate::__simd_generate_dispatch!(@variant $meta $vis $fn_name $gen $recv $args $ret $wc
+            [#[cfg(target_arch = "wasm32")] unsafe] [_wasm]
+            [$crate::engines::wasm32::Wasm $call $params]);
     };
-}
 
-#[macro_export]
-macro_rules! simd_unsafe_generate_all {
-    ($(#[$meta:meta])* $vis:vis fn $fn_name:ident $(<$($lt:lifetime),+>)? ($($arg:ident:$typ:ty),* $(,)? ) -> $rt:ty $body:block  ) => {
+    (@variant
+        [$($meta:tt)*] [$vis:vis] $fn_name:ident [$($gen:tt)*] [] [$($arg:ident : $typ:ty),*]
+        [$($ret:tt)*] [] [] [] [cached $call:tt $params:tt]
+    ) => {
         simdeez_paste_item! {
-            $(#[$meta])*
+            $($meta)*
             #[inline(always)]
-            $vis fn $fn_name $(<$($lt),+>)?($($arg:$typ,)*) -> $rt {
-                let args_tuple = ($($arg,)*);
-                __run_simd_runtime_decide::<[<__ $fn_name _dispatch_struct>], fix_tuple_type!(($($typ),*)), $rt>(args_tuple)
+            $vis fn $fn_name<$($gen)*>($($arg: $typ),*) $($ret)* {
+                $crate::__simd_dispatch!([cached [fn($($typ),*) $($ret)*] [<$fn_name _generic>] [$($arg),*]])
             }
-
-            $(#[$meta])*
+        }
+    };
+    (@variant
+        [$($meta:tt)*] [$vis:vis] $fn_name:ident [$($gen:tt)*] [$($recv:tt)*] [$($arg:ident : $typ:ty),*]
+        [$($ret:tt)*] [$($wc:tt)*] [$(#[$cfg:meta])* $($unsafe:ident)?] [$($suffix:ident)?] $dispatch:tt
+    ) => {
+        simdeez_paste_item! {
+            $($meta)*
             #[inline(always)]
-            #[cfg(any(target_arch = "x86_64", target_arch = "x86"))]
-            $vis fn [<$fn_name _scalar>] $(<$($lt),+>)?($($arg:$typ,)*) -> $rt {
-                let args_tuple = ($($arg,)*);
-                __run_simd_invoke_scalar::<[<__ $fn_name _dispatch_struct>], fix_tuple_type!(($($typ),*)), $rt>(args_tuple)
+            $(#[$cfg])*
+            $vis $($unsafe)? fn [<$fn_name $($suffix)?>]<$($gen)*>($($recv)* $($arg: $typ),*) $($ret)* $($wc)* {
+                $crate::__simd_dispatch!($dispatch)
             }
+        }
+    };
+}
 
-            $(#[$meta])*
-            #[inline(always)]
+/// Calls a generic body produced by [`__simd_generate_dispatch`] with the engine picked at
+/// runtime, or with a fixed engine.
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __simd_dispatch {
+    ([cached [$($fn_ty:tt)*] $callee:ident [$($arg:ident),*]]) => {{
+        static CACHE: $crate::dispatch::__FnCache = $crate::dispatch::__FnCache::new();
+        let callee = CACHE.get_or_resolve(|| match $crate::selected_level() {
             #[cfg(any(target_arch = "x86_64", target_arch = "x86"))]
-            $vis unsafe fn [<$fn_name _sse2>] $(<$($lt),+>)?($($arg:$typ,)*) -> $rt {
-                let args_tuple = ($($arg,)*);
-                __run_simd_invoke_sse2::<[<__ $fn_name _dispatch_struct>], fix_tuple_type!(($($typ),*)), $rt>(args_tuple)
+            $crate::SimdLevel::Avx512 => {
+                $callee::<$crate::engines::avx512::Avx512> as $($fn_ty)* as *const ()
             }
-
-            $(#[$meta])*
-            #[inline(always)]
             #[cfg(any(target_arch = "x86_64", target_arch = "x86"))]
-            $vis unsafe fn [<$fn_name _sse41>] $(<$($lt),+>)?($($arg:$typ,)*) -> $rt {
-                let args_tuple = ($($arg,)*);
-                __run_simd_invoke_sse41::<[<__ $fn_name _dispatch_struct>], fix_tuple_type!(($($typ),*)), $rt>(args_tuple)
+            $crate::SimdLevel::Avx2 => {
+                $callee::<$crate::engines::avx2::Avx2> as $($fn_ty)* as *const ()
             }
-
-            $(#[$meta])*
-            #[inline(always)]
             #[cfg(any(target_arch = "x86_64", target_arch = "x86"))]
-            $vis unsafe fn [<$fn_name _avx2>] $(<$($lt),+>)?($($arg:$typ,)*) -> $rt {
-                let args_tuple = ($($arg,)*);
-                __run_simd_invoke_avx2::<[<__ $fn_name _dispatch_struct>], fix_tuple_type!(($($typ),*)), $rt>(args_tuple)
+            $crate::SimdLevel::Avx => {
+                $callee::<$crate::engines::avx::Avx> as $($fn_ty)* as *const ()
             }
-
-            $(#[$meta])*
-            #[inline(always)]
             #[cfg(any(target_arch = "x86_64", target_arch = "x86"))]
-            $vis unsafe fn [<$fn_name _avx512>] $(<$($lt),+>)?($($arg:$typ,)*) -> $rt {
-                let args_tuple = ($($arg,)*);
-                __run_simd_invoke_avx512::<[<__ $fn_name _dispatch_struct>], fix_tuple_type!(($($typ),*)), $rt>(args_tuple)
+            $crate::SimdLevel::Sse41 => {
+                $callee::<$crate::engines::sse41::Sse41> as $($fn_ty)* as *const ()
+            }
+            #[cfg(any(target_arch = "x86_64", target_arch = "x86"))]
+            $crate::SimdLevel::Sse2 => {
+                $callee::<$crate::engines::sse2::Sse2> as $($fn_ty)* as *const ()
             }
-
-            $(#[$meta])*
-            #[inline(always)]
             #[cfg(target_arch = "aarch64")]
-            $vis unsafe fn [<$fn_name _neon>] $(<$($lt),+>)?($($arg:$typ,)*) -> $rt {
-                let args_tuple = ($($arg,)*);
-                __run_simd_invoke_neon::<[<__ $fn_name _dispatch_struct>], fix_tuple_type!(($($typ),*)), $rt>(args_tuple)
+            $crate::SimdLevel::Neon => {
+                $callee::<$crate::engines::neon::Neon> as $($fn_ty)* as *const ()
             }
-
-            $(#[$meta])*
-            #[inline(always)]
             #[cfg(target_arch = "wasm32")]
-            $vis unsafe fn [<$fn_name _wasm>] $(<$($lt),+>)?($($arg:$typ,)*) -> $rt {
-                let args_tuple = ($($arg,)*);
-                __run_simd_invoke_wasm::<[<__ $fn_name _dispatch_struct>], fix_tuple_type!(($($typ),*)), $rt>(args_tuple)
+            $crate::SimdLevel::Wasm => {
+                $callee::<$crate::engines::wasm32::Wasm> as $($fn_ty)* as *const ()
             }
-
-            __simd_generate_base!($(#[$meta])* $vis fn $fn_name $(<$($lt),+>)? ($($arg:$typ),* ) -> $rt $body);
+            _ => $callee::<$crate::engines::scalar::Scalar> as $($fn_ty)* as *const (),
+        });
+        // Only pointers of this exact type are ever stored in `CACHE`.
+        let callee = unsafe { ::core::mem::transmute::<*const (), $($fn_ty)*>(callee) };
+        callee($($arg),*)
+    }};
+    ([runtime [$($call:tt)*] [$($param:tt)*]]) => {
+        match $crate::selected_level() {
+            #[cfg(any(target_arch = "x86_64", target_arch = "x86"))]
+            $crate::SimdLevel::Avx512 => $crate::__simd_dispatch!(
+                [$crate::engines::avx512::Avx512 [$($call)*] [$($param)*]]
+            ),
+            #[cfg(any(target_arch = "x86_64", target_arch = "x86"))]
+            $crate::SimdLevel::Avx2 => $crate::__simd_dispatch!(
+                [$crate::engines::avx2::Avx2 [$($call)*] [$($param)*]]
+            ),
+            #[cfg(any(target_arch = "x86_64", target_arch = "x86"))]
+            $crate::SimdLevel::Avx => $crate::__simd_dispatch!(
+                [$crate::engines::avx::Avx [$($call)*] [$($param)*]]
+            ),
+            #[cfg(any(target_arch = "x86_64", target_arch = "x86"))]
+            $crate::SimdLevel::Sse41 => $crate::__simd_dispatch!(
+                [$crate::engines::sse41::Sse41 [$($call)*] [$($param)*]]
+            ),
+            #[cfg(any(target_arch = "x86_64", target_arch = "x86"))]
+            $crate::SimdLevel::Sse2 => $crate::__simd_dispatch!(
+                [$crate::engines::sse2::Sse2 [$($call)*] [$($param)*]]
+            ),
+            #[cfg(target_arch = "aarch64")]
+            $crate::SimdLevel::Neon => $crate::__simd_dispatch!(
+                [$crate::engines::neon::Neon [$($call)*] [$($param)*]]
+            ),
+            #[cfg(target_arch = "wasm32")]
+            $crate::SimdLevel::Wasm => $crate::__simd_dispatch!(
+                [$crate::engines::wasm32::Wasm [$($call)*] [$($param)*]]
+            ),
+            _ => $crate::__simd_dispatch!(
+                [$crate::engines::scalar::Scalar [$($call)*] [$($param)*]]
+            ),
         }
     };
-    ($(#[$meta:meta])* $vis:vis fn $fn_name:ident ($($arg:ident:$typ:ty),* $(,)? ) $body:block  ) => {
-        simd_unsafe_generate_all!($(#[$meta])* $vis fn $fn_name ($($arg:$typ),*) -> () $body);
+    ([$engine:path [$($call:tt)*] [$($param:tt)*]]) => {
+        unsafe { $($call)*($($param)* ::core::marker::PhantomData::<$engine>) }
     };
 }
 
+cfg_if::cfg_if! {
+    if #[cfg(all(
+        any(target_arch = "x86_64", target_arch = "x86"),
+        target_feature = "avx512f",
+        target_feature = "avx512bw",
+        target_feature = "avx512dq"
+    ))] {
+        /// The engine [`__run_simd_compiletime_select`] picks for the enabled target features.
+        #[doc(hidden)]
+        pub type __SimdCompiletimeEngine = engines::avx512::Avx512;
+    } else if #[cfg(all(
+        any(target_arch = "x86_64", target_arch = "x86"),
+        target_feature = "avx2",
+        target_feature = "fma"
+    ))] {
+        #[doc(hidden)]
+        pub type __SimdCompiletimeEngine = engines::avx2::Avx2;
+    } else if #[cfg(all(any(target_arch = "x86_64", target_arch = "x86"), target_feature = "avx"))] {
+        #[doc(hidden)]
+        pub type __SimdCompiletimeEngine = engines::avx::Avx;
+    } else if #[cfg(all(any(target_arch = "x86_64", target_arch = "x86"), target_feature = "sse4.1"))] {
+        #[doc(hidden)]
+        pub type __SimdCompiletimeEngine = engines::sse41::Sse41;
+    } else if #[cfg(all(any(target_arch = "x86_64", target_arch = "x86"), target_feature = "sse2"))] {
+        #[doc(hidden)]
+        pub type __SimdCompiletimeEngine = engines::sse2::Sse2;
+    } else if #[cfg(all(target_arch = "aarch64", target_feature = "neon"))] {
+        #[doc(hidden)]
+        pub type __SimdCompiletimeEngine = engines::neon::Neon;
+    } else if #[cfg(target_arch = "wasm32")] {
+        #[doc(hidden)]
+        pub type __SimdCompiletimeEngine = engines::wasm32::Wasm;
+    } else {
+        #[doc(hidden)]
+        pub type __SimdCompiletimeEngine = engines::scalar::Scalar;
+    }
+}
+
 pub trait __SimdRunner<A, R> {
     unsafe fn run<S: Simd>(args: A) -> R;
 }
 
 #[inline(always)]
 pub fn __run_simd_runtime_decide<S: __SimdRunner<A, R>, A, R>(args: A) -> R {
-    #![allow(unreachable_code)]
-
-    #[cfg(any(target_arch = "x86_64", target_arch = "x86"))]
-    {
-        if is_x86_feature_detected!("avx512f")
-            && is_x86_feature_detected!("avx512bw")
-            && is_x86_feature_detected!("avx512dq")
-        {
-            return unsafe { S::run::<engines::avx512::Avx512>(args) };
-        }
-
-        if is_x86_feature_detected!("avx2") && is_x86_feature_detected!("fma") {
-            return unsafe { S::run::<engines::avx2::Avx2>(args) };
-        }
-
-        if is_x86_feature_detected!("sse4.1") {
-            return unsafe { S::run::<engines::sse41::Sse41>(args) };
-        }
-
-        if is_x86_feature_detected!("sse2") {
-            return unsafe { S::run::<engines::sse2::Sse2>(args) };
-        }
-    }
-
-    #[cfg(target_arch = "aarch64")]
-    if is_aarch64_feature_detected!("neon") {
-        return unsafe { S::run::<engines::neon::Neon>(args) };
-    }
-
-    #[cfg(target_arch = "wasm32")]
-    {
-        // Note: there's currently no way to detect SIMD support in WebAssembly at runtime
-        return unsafe { S::run::<engines::wasm32::Wasm>(args) };
+    match crate::selected_level() {
+        #[cfg(any(target_arch = "x86_64", target_arch = "x86"))]
+        SimdLevel::Avx512 => unsafe { S::run::<engines::avx512::Avx512>(args) },
+        #[cfg(any(target_arch = "x86_64", target_arch = "x86"))]
+        SimdLevel::Avx2 => unsafe { S::run::<engines::avx2::Avx2>(args) },
+        #[cfg(any(target_arch = "x86_64", target_arch = "x86"))]
+        SimdLevel::Avx => unsafe { S::run::<engines::avx::Avx>(args) },
+        #[cfg(any(target_arch = "x86_64", target_arch = "x86"))]
+        SimdLevel::Sse41 => unsafe { S::run::<engines::sse41::Sse41>(args) },
+        #[cfg(any(target_arch = "x86_64", target_arch = "x86"))]
+        SimdLevel::Sse2 => unsafe { S::run::<engines::sse2::Sse2>(args) },
+        #[cfg(target_arch = "aarch64")]
+        SimdLevel::Neon => unsafe { S::run::<engines::neon::Neon>(args) },
+        #[cfg(target_arch = "wasm32")]
+        SimdLevel::Wasm => unsafe { S::run::<engines::wasm32::Wasm>(args) },
+        _ => unsafe { S::run::<engines::scalar::Scalar>(args) },
     }
-
-    unsafe { S::run::<engines::scalar::Scalar>(args) }
 }
 
 #[inline(always)]
@@ -244,6 +451,9 @@ pub fn __run_simd_compiletime_select<S: __SimdRunner<A, R>, A, R>(args: A) -> R
         #[cfg(all(target_feature = "avx2", target_feature = "fma"))]
         return unsafe { S::run::<engines::avx2::Avx2>(args) };
 
+        #[cfg(target_feature = "avx")]
+        return unsafe { S::run::<engines::avx::Avx>(args) };
+
         #[cfg(target_feature = "sse4.1")]
         return unsafe { S::run::<engines::sse41::Sse41>(args) };
 
@@ -282,6 +492,12 @@ pub unsafe fn __run_simd_invoke_sse41<S: __SimdRunner<A, R>, A, R>(args: A) -> R
     unsafe { S::run::<engines::sse41::Sse41>(args) }
 }
 
+#[inline(always)]
+#[cfg(any(target_arch = "x86_64", target_arch = "x86"))]
+pub unsafe fn __run_simd_invoke_avx<S: __SimdRunner<A, R>, A, R>(args: A) -> R {
+    unsafe { S::run::<engines::avx::Avx>(args) }
+}
+
 #[inline(always)]
 #[cfg(any(target_arch = "x86_64", target_arch = "x86"))]
 pub unsafe fn __run_simd_invoke_avx2<S: __SimdRunner<A, R>, A, R>(args: A) -> R {
@@ -1,5 +1,5 @@
 //! A library that abstracts over SIMD instruction sets, including ones with differing widths.
-//! SIMDeez is designed to allow you to write a function one time and produce scalar, SSE2, SSE41, AVX2, AVX-512, Neon, and WebAssembly SIMD versions of the function.
+//! SIMDeez is designed to allow you to write a function one time and produce scalar, SSE2, SSE41, AVX, AVX2, AVX-512, Neon, and WebAssembly SIMD versions of the function.
 //! You can either have the version you want selected automatically at runtime, at compiletime, or
 //! select yourself by hand.
 //!
@@ -8,12 +8,14 @@
 //!
 //! AVX-512 support is available on x86/x86_64 targets with `avx512f`, `avx512bw`, and `avx512dq`.
 //! Runtime dispatch selects it ahead of AVX2 when those features are available.
+//! Byte lookups, bit counts and 8 and 16 bit compress/expand also use `avx512vbmi`,
+//! `avx512vbmi2`, `avx512bitalg` and `avx512vpopcntdq`, each one when the CPU has it.
 //!
 //! Refer to the excellent [Intel Intrinsics Guide](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#) for documentation on these functions.
 //!
 //! # Features
 //!
-//! * SSE2, SSE41, AVX2, AVX-512, Neon, WebAssembly SIMD, and scalar fallback
+//! * SSE2, SSE41, AVX, AVX2, AVX-512, Neon, WebAssembly SIMD, and scalar fallback
 //! * Can be used with compile time or run time selection
 //! * No runtime overhead
 //! * Uses familiar intel intrinsic naming conventions, easy to port.
@@ -23,10 +25,31 @@
 //! * Can be used by `#[no_std]` projects
 //! * Operator overloading: `let sum = va + vb` or `s *= s`
 //! * Extract or set a single lane with the index operator: `let v1 = v[1];`
+//! * Unsigned lane types (`Vu8`, `Vu16`, `Vu32`, `Vu64`) with unsigned compares, min/max and zero-cost bitcasts to the signed types
+//! * Portable lane shuffles: `shuffle`, `shuffle2`, `reverse_lanes`, `rotate_lanes_left/right`, `broadcast_lane` and runtime `permute`
+//! * Gathers and scatters (`gather`, `scatter`, masked and `_unchecked` variants) on 32 and 64 bit lanes, with 32 bit indices also accepted for 64 bit lanes (`gather_i32`, `scatter_i32`), native on AVX2 and AVX-512
+//! * Masked and partial loads/stores (`load_partial`, `store_partial`, `masked_load`, `masked_store`) so slice tails need no scalar loop
+//! * Typed lane masks: `cmp_*` return a `Mask` with `&`, `|`, `!`, `any`, `all`, `count_ones`, `first_set`, `to_bitmask` and `select`, held in `__mmask` registers on AVX-512
+//! * Narrowing packs (`narrow_to_*`, `saturating_narrow_to_*` including unsigned `packus`) and widening `extend_to_f32`/`extend_to_f64`, plus `f64` to `f32`/`i32`
+//! * Saturating `saturating_add`/`saturating_sub`, rounding `avg`, `abs_diff`, `mul_hi`, `mul_widening`, `mul_add_pairs` (`pmaddwd`/`pmaddubsw`) and `sad` (`psadbw`) on the integer types
+//! * Per-lane shifts (`shl_lanes`/`shr_lanes`), arithmetic right shifts (`shr_arithmetic`) and bit rotates (`rotate_left`/`rotate_right`, by count, const or per lane); `shr` is always a logical shift
+//! * Per-lane `count_ones`, `leading_zeros`, `trailing_zeros` and `reverse_bits` plus a whole-vector `popcount_total` on the integer types, using AVX-512 VPOPCNTDQ/BITALG when the CPU has them
+//! * Float classification (`is_nan`, `is_finite`, `is_infinite`, `is_sign_negative`) and `copysign`, `signum`, `clamp`, `trunc`, `fract`, exact `recip` and `mul_add_fused`/`mul_add_unfused` on `Vf32`/`Vf64`, matching the scalar `f32`/`f64` methods on every engine
+//! * Portable float `min_ieee`/`max_ieee` (IEEE 754-2019 `minimumNumber`/`maximumNumber`), `min_propagate_nan`/`max_propagate_nan` and `total_cmp_lt`/`total_min`/`total_max`, bit-identical on every engine
+//! * Horizontal `horizontal_min`/`horizontal_max`/`horizontal_mul`, integer `horizontal_and`/`horizontal_or`/`horizontal_xor`, and `argmin`/`argmax`; float `horizontal_add` sums in a fixed pairwise order on every engine
+//! * `zip_lo`/`zip_hi`/`unzip_even`/`unzip_odd` and structure loads/stores (`load_interleaved2/3/4`, `store_interleaved2/3/4`) for interleaved data such as RGB(A) pixels, using `vld`/`vst` on Neon
+//! * In-register `transpose` of `WIDTH`×`WIDTH` tiles for every vector type: 4×4 for 32-bit lanes on SSE/Neon/wasm, 8×8 on AVX2 and 16×16 on AVX-512
+//! * `Vi8` table lookups `lookup16`/`lookup32`/`lookup64` that give zero outside the table, using `pshufb`, `vpermb` (AVX-512 VBMI when detected, SSSE3 on the SSE2 engine), `tbl` and `i8x16_swizzle`
+//! * `compress`/`compress_store`/`expand` (left-pack and its inverse) for every lane type, using AVX-512 `vpcompress`/`vpexpand` (VBMI2 for 8 and 16 bit lanes when detected) and lookup-table driven permutes elsewhere
+//! * An AVX engine for CPUs with AVX but not AVX2 (Sandy Bridge, Ivy Bridge, Bulldozer): 256-bit float ops, integer ops run on the two 128-bit halves
+//! * Dispatch macros accept type and const generics, `where` clauses, `impl Trait` arguments and `&self`/`&mut self` methods
+//! * Runtime dispatch detects CPU features once and calls through a cached function pointer afterwards; `simdeez::dispatch::reset()` forces detection to run again
+//! * `detected_level()` and `selected_level()` report the instruction set runtime dispatch uses, and `set_max_level()` or the `SIMDEEZ_MAX_LEVEL` environment variable cap it
 //!
 //! # SIMD math
 //! SIMDeez now provides a native, pure-Rust SIMD math surface via extension traits:
-//! `log2_u35`, `exp2_u35`, `ln_u35`, `exp_u35`, `sin_u35`, `cos_u35`, `tan_u35`,
+//! `log2_u35`, `exp2_u35`, `ln_u35`, `exp_u35`, `ln_u10`, `exp_u10`,
+//! `sin_u35`, `cos_u35`, `tan_u35`,
 //! `asin_u35`, `acos_u35`, `atan_u35`, `atan2_u35`,
 //! `sinh_u35`, `cosh_u35`, `tanh_u35`, `asinh_u35`, `acosh_u35`, `atanh_u35`,
 //! `log10_u35`, `hypot_u35`, and `fmod`.
@@ -144,6 +167,7 @@
 //! * `distance_scalar`  a scalar fallback
 //! * `distance_sse2`    SSE2 version
 //! * `distance_sse41`   SSE41 version
+//! * `distance_avx`     AVX version
 //! * `distance_avx2`    AVX2 version
 //! * `distance_avx512`  AVX-512 version
 //! * `distance_neon`    Neon version
@@ -163,6 +187,44 @@
 //! You may also forgo the macros if you know what you are doing, just keep in mind there are lots
 //! of arcane subtleties with inlining and target_features that must be managed. See how the macros
 //! expand for more detail.
+//!
+//! # Generics and methods
+//!
+//! The macros accept type and const generics, `where` clauses, `impl Trait` arguments and `self`
+//! receivers, so they can also be used inside `impl` blocks. Every generic parameter must be
+//! inferable from the arguments or the return type, since the engine is appended as the last one.
+//!
+//! ```rust
+//!use simdeez::prelude::*;
+//!
+//!struct Gain<const N: usize> {
+//!    factors: [f32; N],
+//!}
+//!
+//!impl<const N: usize> Gain<N> {
+//!    simd_runtime_generate!(
+//!        fn apply<T>(&self, samples: &mut [f32; N], offset: T)
+//!        where
+//!            T: Into<f32> + Copy,
+//!        {
+//!            let offset = S::Vf32::set1(offset.into());
+//!            for (chunk, factors) in samples
+//!                .chunks_mut(S::Vf32::WIDTH)
+//!                .zip(self.factors.chunks(S::Vf32::WIDTH))
+//!            {
+//!                let (v, mask) = S::Vf32::load_partial(chunk);
+//!                let (factors, _) = S::Vf32::load_partial(factors);
+//!                (v * factors + offset).store_partial(chunk, mask);
+//!            }
+//!        }
+//!    );
+//!}
+//!
+//!let gain = Gain { factors: [2.0; 5] };
+//!let mut samples = [1.0, 2.0, 3.0, 4.0, 5.0];
+//!gain.apply(&mut samples, 1u8);
+//!assert_eq!(samples, [3.0, 5.0, 7.0, 9.0, 11.0]);
+//! ```
 #![allow(clippy::missing_safety_doc)] // TODO: Work on the safety of functions
 #![cfg_attr(all(feature = "no_std", not(test)), no_std)]
 #[macro_use]
@@ -181,6 +243,9 @@ use core::ops::*;
 
 mod invoking;
 
+pub mod dispatch;
+pub use dispatch::{detected_level, max_level, selected_level, set_max_level, SimdLevel};
+
 #[macro_use]
 mod overloads;
 
@@ -201,32 +266,48 @@ pub trait Simd: 'static + Sync + Send {
     /// Vector of i8s.  Corresponds to __m128i when used
     /// with the Sse impl, __m256i when used with Avx2, or a single i8
     /// when used with Scalar.
-    type Vi8: SimdInt8<Scalar = i8> + SimdBaseIo;
+    type Vi8: SimdInt8<Engine = Self, Scalar = i8> + SimdBaseIo;
 
     /// Vector of i16s.  Corresponds to __m128i when used
     /// with the Sse impl, __m256i when used with Avx2, or a single i16
     /// when used with Scalar.
-    type Vi16: SimdInt16<Scalar = i16> + SimdBaseIo;
+    type Vi16: SimdInt16<Engine = Self, Scalar = i16> + SimdBaseIo;
 
     /// Vector of i32s.  Corresponds to __m128i when used
     /// with the Sse impl, __m256i when used with Avx2, or a single i32
     /// when used with Scalar.
-    type Vi32: SimdInt32<Engine = Self, Scalar = i32> + SimdBaseIo;
+    type Vi32: SimdInt32<Engine = Self, Scalar = i32> + SimdBaseIo + SimdIndex32 + SimdGather32;
 
     /// Vector of i64s.  Corresponds to __m128i when used
     /// with the Sse impl, __m256i when used with Avx2, or a single i64
     /// when used with Scalar.
-    type Vi64: SimdInt64<Engine = Self, Scalar = i64> + SimdBaseIo;
+    type Vi64: SimdInt64<Engine = Self, Scalar = i64> + SimdBaseIo + SimdIndex64 + SimdGather64;
+
+    /// Vector of u8s.  Shares its register type with Vi8 on x86 and WebAssembly,
+    /// and corresponds to a single u8 when used with Scalar.
+    type Vu8: SimdUint8<Engine = Self, Scalar = u8> + SimdBaseIo;
+
+    /// Vector of u16s.  Shares its register type with Vi16 on x86 and WebAssembly,
+    /// and corresponds to a single u16 when used with Scalar.
+    type Vu16: SimdUint16<Engine = Self, Scalar = u16> + SimdBaseIo;
+
+    /// Vector of u32s.  Shares its register type with Vi32 on x86 and WebAssembly,
+    /// and corresponds to a single u32 when used with Scalar.
+    type Vu32: SimdUint32<Engine = Self, Scalar = u32> + SimdBaseIo + SimdIndex32 + SimdGather32;
+
+    /// Vector of u64s.  Shares its register type with Vi64 on x86 and WebAssembly,
+    /// and corresponds to a single u64 when used with Scalar.
+    type Vu64: SimdUint64<Engine = Self, Scalar = u64> + SimdBaseIo + SimdIndex64 + SimdGather64;
 
     /// Vector of f32s.  Corresponds to __m128 when used
     /// with the Sse impl, __m256 when used with Avx2, or a single f32
     /// when used with Scalar.
-    type Vf32: SimdFloat32<Engine = Self, Scalar = f32> + SimdBaseIo;
+    type Vf32: SimdFloat32<Engine = Self, Scalar = f32> + SimdBaseIo + SimdGather32;
 
     /// Vector of f64s.  Corresponds to __m128d when used
     /// with the Sse impl, __m256d when used with Avx2, or a single f64
     /// when used with Scalar.
-    type Vf64: SimdFloat64<Engine = Self, Scalar = f64> + SimdBaseIo;
+    type Vf64: SimdFloat64<Engine = Self, Scalar = f64> + SimdBaseIo + SimdGather64;
 
     // The width of the vector lane.  Necessary for creating
     // lane width agnostic code.
@@ -595,145 +676,145 @@ pub trait Simd: 'static + Sync + Send {
         note = "Functions on the Simd trait are deprecated, please use the functions on the Vf32, Vf64, Vi16, Vi32, and Vi64 types instead."
     )]
     unsafe fn cmpeq_epi64(a: Self::Vi64, b: Self::Vi64) -> Self::Vi64 {
-        a.cmp_eq(b)
+        a.cmp_eq(b).to_vector()
     }
     #[deprecated(
         note = "Functions on the Simd trait are deprecated, please use the functions on the Vf32, Vf64, Vi16, Vi32, and Vi64 types instead."
     )]
     unsafe fn cmpneq_epi64(a: Self::Vi64, b: Self::Vi64) -> Self::Vi64 {
-        a.cmp_neq(b)
+        a.cmp_neq(b).to_vector()
     }
     #[deprecated(
         note = "Functions on the Simd trait are deprecated, please use the functions on the Vf32, Vf64, Vi16, Vi32, and Vi64 types instead."
     )]
     unsafe fn cmpge_epi64(a: Self::Vi64, b: Self::Vi64) -> Self::Vi64 {
-        a.cmp_gte(b)
+        a.cmp_gte(b).to_vector()
     }
     #[deprecated(
         note = "Functions on the Simd trait are deprecated, please use the functions on the Vf32, Vf64, Vi16, Vi32, and Vi64 types instead."
     )]
     unsafe fn cmpgt_epi64(a: Self::Vi64, b: Self::Vi64) -> Self::Vi64 {
-        a.cmp_gt(b)
+        a.cmp_gt(b).to_vector()
     }
     #[deprecated(
         note = "Functions on the Simd trait are deprecated, please use the functions on the Vf32, Vf64, Vi16, Vi32, and Vi64 types instead."
     )]
     unsafe fn cmple_epi64(a: Self::Vi64, b: Self::Vi64) -> Self::Vi64 {
-        a.cmp_lte(b)
+        a.cmp_lte(b).to_vector()
     }
     #[deprecated(
         note = "Functions on the Simd trait are deprecated, please use the functions on the Vf32, Vf64, Vi16, Vi32, and Vi64 types instead."
     )]
     unsafe fn cmplt_epi64(a: Self::Vi64, b: Self::Vi64) -> Self::Vi64 {
-        a.cmp_lt(b)
+        a.cmp_lt(b).to_vector()
     }
     #[deprecated(
         note = "Functions on the Simd trait are deprecated, please use the functions on the Vf32, Vf64, Vi16, Vi32, and Vi64 types instead."
     )]
     unsafe fn cmpeq_epi32(a: Self::Vi32, b: Self::Vi32) -> Self::Vi32 {
-        a.cmp_eq(b)
+        a.cmp_eq(b).to_vector()
     }
     #[deprecated(
         note = "Functions on the Simd trait are deprecated, please use the functions on the Vf32, Vf64, Vi16, Vi32, and Vi64 types instead."
     )]
     unsafe fn cmpneq_epi32(a: Self::Vi32, b: Self::Vi32) -> Self::Vi32 {
-        a.cmp_neq(b)
+        a.cmp_neq(b).to_vector()
     }
     #[deprecated(
         note = "Functions on the Simd trait are deprecated, please use the functions on the Vf32, Vf64, Vi16, Vi32, and Vi64 types instead."
     )]
     unsafe fn cmpge_epi32(a: Self::Vi32, b: Self::Vi32) -> Self::Vi32 {
-        a.cmp_gte(b)
+        a.cmp_gte(b).to_vector()
     }
     #[deprecated(
         note = "Functions on the Simd trait are deprecated, please use the functions on the Vf32, Vf64, Vi16, Vi32, and Vi64 types instead."
     )]
     unsafe fn cmpgt_epi32(a: Self::Vi32, b: Self::Vi32) -> Self::Vi32 {
-        a.cmp_gt(b)
+        a.cmp_gt(b).to_vector()
     }
     #[deprecated(
         note = "Functions on the Simd trait are deprecated, please use the functions on the Vf32, Vf64, Vi16, Vi32, and Vi64 types instead."
     )]
     unsafe fn cmple_epi32(a: Self::Vi32, b: Self::Vi32) -> Self::Vi32 {
-        a.cmp_lte(b)
+        a.cmp_lte(b).to_vector()
     }
     #[deprecated(
         note = "Functions on the Simd trait are deprecated, please use the functions on the Vf32, Vf64, Vi16, Vi32, and Vi64 types instead."
     )]
     unsafe fn cmplt_epi32(a: Self::Vi32, b: Self::Vi32) -> Self::Vi32 {
-        a.cmp_lt(b)
+        a.cmp_lt(b).to_vector()
     }
     #[deprecated(
         note = "Functions on the Simd trait are deprecated, please use the functions on the Vf32, Vf64, Vi16, Vi32, and Vi64 types instead."
     )]
     unsafe fn cmpeq_ps(a: Self::Vf32, b: Self::Vf32) -> Self::Vf32 {
-        a.cmp_eq(b)
+        a.cmp_eq(b).to_vector()
     }
     #[deprecated(
         note = "Functions on the Simd trait are deprecated, please use the functions on the Vf32, Vf64, Vi16, Vi32, and Vi64 types instead."
     )]
     unsafe fn cmpneq_ps(a: Self::Vf32, b: Self::Vf32) -> Self::Vf32 {
-        a.cmp_neq(b)
+        a.cmp_neq(b).to_vector()
     }
     #[deprecated(
         note = "Functions on the Simd trait are deprecated, please use the functions on the Vf32, Vf64, Vi16, Vi32, and Vi64 types instead."
     )]
     unsafe fn cmpge_ps(a: Self::Vf32, b: Self::Vf32) -> Self::Vf32 {
-        a.cmp_gte(b)
+        a.cmp_gte(b).to_vector()
     }
     #[deprecated(
         note = "Functions on the Simd trait are deprecated, please use the functions on the Vf32, Vf64, Vi16, Vi32, and Vi64 types instead."
     )]
     unsafe fn cmpgt_ps(a: Self::Vf32, b: Self::Vf32) -> Self::Vf32 {
-        a.cmp_gt(b)
+        a.cmp_gt(b).to_vector()
     }
     #[deprecated(
         note = "Functions on the Simd trait are deprecated, please use the functions on the Vf32, Vf64, Vi16, Vi32, and Vi64 types instead."
     )]
     unsafe fn cmple_ps(a: Self::Vf32, b: Self::Vf32) -> Self::Vf32 {
-        a.cmp_lte(b)
+        a.cmp_lte(b).to_vector()
     }
     #[deprecated(
         note = "Functions on the Simd trait are deprecated, please use the functions on the Vf32, Vf64, Vi16, Vi32, and Vi64 types instead."
     )]
     unsafe fn cmplt_ps(a: Self::Vf32, b: Self::Vf32) -> Self::Vf32 {
-        a.cmp_lt(b)
+        a.cmp_lt(b).to_vector()
     }
     #[deprecated(
         note = "Functions on the Simd trait are deprecated, please use the functions on the Vf32, Vf64, Vi16, Vi32, and Vi64 types instead."
     )]
     unsafe fn cmpeq_pd(a: Self::Vf64, b: Self::Vf64) -> Self::Vf64 {
-        a.cmp_eq(b)
+        a.cmp_eq(b).to_vector()
     }
     #[deprecated(
         note = "Functions on the Simd trait are deprecated, please use the functions on the Vf32, Vf64, Vi16, Vi32, and Vi64 types instead."
     )]
     unsafe fn cmpneq_pd(a: Self::Vf64, b: Self::Vf64) -> Self::Vf64 {
-        a.cmp_neq(b)
+        a.cmp_neq(b).to_vector()
     }
     #[deprecated(
         note = "Functions on the Simd trait are deprecated, please use the functions on the Vf32, Vf64, Vi16, Vi32, and Vi64 types instead."
     )]
     unsafe fn cmpge_pd(a: Self::Vf64, b: Self::Vf64) -> Self::Vf64 {
-        a.cmp_gte(b)
+        a.cmp_gte(b).to_vector()
     }
     #[deprecated(
         note = "Functions on the Simd trait are deprecated, please use the functions on the Vf32, Vf64, Vi16, Vi32, and Vi64 types instead."
     )]
     unsafe fn cmpgt_pd(a: Self::Vf64, b: Self::Vf64) -> Self::Vf64 {
-        a.cmp_gt(b)
+        a.cmp_gt(b).to_vector()
     }
     #[deprecated(
         note = "Functions on the Simd trait are deprecated, please use the functions on the Vf32, Vf64, Vi16, Vi32, and Vi64 types instead."
     )]
     unsafe fn cmple_pd(a: Self::Vf64, b: Self::Vf64) -> Self::Vf64 {
-        a.cmp_lte(b)
+        a.cmp_lte(b).to_vector()
     }
     #[deprecated(
         note = "Functions on the Simd trait are deprecated, please use the functions on the Vf32, Vf64, Vi16, Vi32, and Vi64 types instead."
     )]
     unsafe fn cmplt_pd(a: Self::Vf64, b: Self::Vf64) -> Self::Vf64 {
-        a.cmp_lt(b)
+        a.cmp_lt(b).to_vector()
     }
 
     #[deprecated(
@@ -885,13 +966,13 @@ pub trait Simd: 'static + Sync + Send {
     /// Sse2 and Sse41 paths will simulate a gather by breaking out and
     /// doing scalar array accesses, because gather doesn't exist until Avx2.
     #[deprecated(
-        note = "These functions have unpredictable behavior and will be deleted in the future. Please use a manual implementation instead."
+        note = "These functions have unpredictable behavior and will be deleted in the future. Please use `SimdGather32::gather` instead."
     )]
     unsafe fn i32gather_epi32(_arr: &[i32], _index: Self::Vi32) -> Self::Vi32 {
         panic!("Deprecated")
     }
     #[deprecated(
-        note = "These functions have unpredictable behavior and will be deleted in the future. Please use a manual implementation instead."
+        note = "These functions have unpredictable behavior and will be deleted in the future. Please use `SimdGather64::gather` instead."
     )]
     unsafe fn i64gather_epi64(_arr: &[i64], _index: Self::Vi64) -> Self::Vi64 {
         panic!("Deprecated")
@@ -899,7 +980,7 @@ pub trait Simd: 'static + Sync + Send {
     /// Sse2 and Sse41 paths will simulate a gather by breaking out and
     /// doing scalar array accesses, because gather doesn't exist until Avx2.
     #[deprecated(
-        note = "These functions have unpredictable behavior and will be deleted in the future. Please use a manual implementation instead."
+        note = "These functions have unpredictable behavior and will be deleted in the future. Please use `SimdGather32::gather` instead."
     )]
     unsafe fn i32gather_ps(_arr: &[f32], _index: Self::Vi32) -> Self::Vf32 {
         panic!("Deprecated")
@@ -1293,7 +1374,7 @@ pub trait Simd: 'static + Sync + Send {
     /// Using the shuffle function is undefined behavior because imm8 behaves differently on different
     /// architectures.
     #[deprecated(
-        note = "These functions have unpredictable behavior and will be deleted in the future. Please use a manual implementation instead."
+        note = "These functions have unpredictable behavior and will be deleted in the future. Please use `SimdBaseOps::shuffle` or `SimdInt::permute` instead."
     )]
     unsafe fn shuffle_epi32<const IMM8: i32>(_a: Self::Vi32) -> Self::Vi32 {
         panic!("Deprecated")
@@ -32,6 +32,8 @@ pub trait FloatExt: private::Sealed + Sized {
 
     fn m_exp2(self) -> Self;
 
+    fn m_exp10(self) -> Self;
+
     fn m_ln(self) -> Self;
 
     fn m_log(self, base: Self) -> Self;
@@ -81,6 +83,18 @@ pub trait FloatExt: private::Sealed + Sized {
     fn m_acosh(self) -> Self;
 
     fn m_atanh(self) -> Self;
+
+    #[cfg(feature = "special")]
+    fn m_erf(self) -> Self;
+
+    #[cfg(feature = "special")]
+    fn m_erfc(self) -> Self;
+
+    #[cfg(feature = "special")]
+    fn m_tgamma(self) -> Self;
+
+    #[cfg(feature = "special")]
+    fn m_lgamma(self) -> Self;
 }
 
 mod private {
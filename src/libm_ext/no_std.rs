@@ -65,6 +65,11 @@ impl FloatExt for f32 {
         exp2f(self)
     }
 
+    #[inline]
+    fn m_exp10(self) -> Self {
+        exp10f(self)
+    }
+
     #[inline]
     fn m_ln(self) -> Self {
         logf(self)
@@ -176,6 +181,30 @@ impl FloatExt for f32 {
     fn m_atanh(self) -> Self {
         0.5 * ((2.0 * self) / (1.0 - self)).m_ln_1p()
     }
+
+    #[cfg(feature = "special")]
+    #[inline]
+    fn m_erf(self) -> Self {
+        erff(self)
+    }
+
+    #[cfg(feature = "special")]
+    #[inline]
+    fn m_erfc(self) -> Self {
+        erfcf(self)
+    }
+
+    #[cfg(feature = "special")]
+    #[inline]
+    fn m_tgamma(self) -> Self {
+        tgammaf(self)
+    }
+
+    #[cfg(feature = "special")]
+    #[inline]
+    fn m_lgamma(self) -> Self {
+        lgammaf(self)
+    }
 }
 
 impl FloatExt for f64 {
@@ -239,6 +268,11 @@ impl FloatExt for f64 {
         exp2(self)
     }
 
+    #[inline]
+    fn m_exp10(self) -> Self {
+        exp10(self)
+    }
+
     #[inline]
     fn m_ln(self) -> Self {
         log(self)
@@ -350,4 +384,28 @@ impl FloatExt for f64 {
     fn m_atanh(self) -> Self {
         0.5 * ((2.0 * self) / (1.0 - self)).m_ln_1p()
     }
+
+    #[cfg(feature = "special")]
+    #[inline]
+    fn m_erf(self) -> Self {
+        erf(self)
+    }
+
+    #[cfg(feature = "special")]
+    #[inline]
+    fn m_erfc(self) -> Self {
+        erfc(self)
+    }
+
+    #[cfg(feature = "special")]
+    #[inline]
+    fn m_tgamma(self) -> Self {
+        tgamma(self)
+    }
+
+    #[cfg(feature = "special")]
+    #[inline]
+    fn m_lgamma(self) -> Self {
+        lgamma(self)
+    }
 }
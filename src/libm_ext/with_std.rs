@@ -61,6 +61,11 @@ impl FloatExt for f32 {
         f32::exp2(self)
     }
 
+    #[inline]
+    fn m_exp10(self) -> Self {
+        f32::powf(10.0, self)
+    }
+
     #[inline]
     fn m_ln(self) -> Self {
         f32::ln(self)
@@ -165,6 +170,30 @@ impl FloatExt for f32 {
     fn m_atanh(self) -> Self {
         f32::atanh(self)
     }
+
+    #[cfg(feature = "special")]
+    #[inline]
+    fn m_erf(self) -> Self {
+        libm::erff(self)
+    }
+
+    #[cfg(feature = "special")]
+    #[inline]
+    fn m_erfc(self) -> Self {
+        libm::erfcf(self)
+    }
+
+    #[cfg(feature = "special")]
+    #[inline]
+    fn m_tgamma(self) -> Self {
+        libm::tgammaf(self)
+    }
+
+    #[cfg(feature = "special")]
+    #[inline]
+    fn m_lgamma(self) -> Self {
+        libm::lgammaf(self)
+    }
 }
 
 impl FloatExt for f64 {
@@ -228,6 +257,11 @@ impl FloatExt for f64 {
         f64::exp2(self)
     }
 
+    #[inline]
+    fn m_exp10(self) -> Self {
+        f64::powf(10.0, self)
+    }
+
     #[inline]
     fn m_ln(self) -> Self {
         f64::ln(self)
@@ -332,4 +366,28 @@ impl FloatExt for f64 {
     fn m_atanh(self) -> Self {
         f64::atanh(self)
     }
+
+    #[cfg(feature = "special")]
+    #[inline]
+    fn m_erf(self) -> Self {
+        libm::erf(self)
+    }
+
+    #[cfg(feature = "special")]
+    #[inline]
+    fn m_erfc(self) -> Self {
+        libm::erfc(self)
+    }
+
+    #[cfg(feature = "special")]
+    #[inline]
+    fn m_tgamma(self) -> Self {
+        libm::tgamma(self)
+    }
+
+    #[cfg(feature = "special")]
+    #[inline]
+    fn m_lgamma(self) -> Self {
+        libm::lgamma(self)
+    }
 }
@@ -1,10 +1,28 @@
 pub const LOG2_U35_F32_MAX_ULP: u32 = 35;
 pub const EXP2_U35_F32_MAX_ULP: u32 = 35;
+// The vectorized ln/exp kernels keep the 1 ulp bound of the scalar references they replaced;
+// an exhaustive sweep of every f32 input stays within it on the SSE2, AVX2 and scalar engines.
 pub const LN_U35_F32_MAX_ULP: u32 = 1;
 pub const EXP_U35_F32_MAX_ULP: u32 = 1;
+// The u10 tier keeps the scalar-reference accuracy for callers that need it.
+pub const LN_U10_F32_MAX_ULP: u32 = 1;
+pub const EXP_U10_F32_MAX_ULP: u32 = 1;
+// The power and exp/log-adjacent kernels reuse the portable ln/exp reductions.
+pub const POW_U35_F32_MAX_ULP: u32 = 35;
+// powi multiplies out small exponents and defers to pow_u35 beyond that.
+pub const POWI_F32_MAX_ULP: u32 = 35;
+pub const CBRT_U35_F32_MAX_ULP: u32 = 35;
+pub const EXP10_U35_F32_MAX_ULP: u32 = 35;
+pub const EXPM1_U35_F32_MAX_ULP: u32 = 35;
+pub const LOG1P_U35_F32_MAX_ULP: u32 = 35;
 pub const SIN_U35_F32_MAX_ULP: u32 = 35;
 pub const COS_U35_F32_MAX_ULP: u32 = 35;
 pub const TAN_U35_F32_MAX_ULP: u32 = 35;
+// sincos shares the sin/cos kernels; the pi-scaled variants reduce exactly and only round `pi * r`.
+pub const SINCOS_U35_F32_MAX_ULP: u32 = 35;
+pub const SINPI_U35_F32_MAX_ULP: u32 = 35;
+pub const COSPI_U35_F32_MAX_ULP: u32 = 35;
+pub const TANPI_U35_F32_MAX_ULP: u32 = 35;
 
 // Portable inverse-trig kernels target the u35 contract on f32.
 pub const ASIN_U35_F32_MAX_ULP: u32 = 35;
@@ -23,14 +41,31 @@ pub const ATANH_U35_F32_MAX_ULP: u32 = 35;
 pub const HYPOT_U35_F32_MAX_ULP: u32 = 1;
 // f32 log10 now rides the portable log2_u35 kernel and inherits its relaxed envelope.
 pub const LOG10_U35_F32_MAX_ULP: u32 = 35;
+// Special functions run portable kernels and patch poles and far tails to the libm references.
+pub const ERF_U35_F32_MAX_ULP: u32 = 35;
+pub const ERFC_U35_F32_MAX_ULP: u32 = 35;
+pub const TGAMMA_U35_F32_MAX_ULP: u32 = 35;
+pub const LGAMMA_U35_F32_MAX_ULP: u32 = 35;
 
 pub const LOG2_U35_F64_MAX_ULP: u64 = 35;
 pub const EXP2_U35_F64_MAX_ULP: u64 = 35;
 pub const LN_U35_F64_MAX_ULP: u64 = 35;
 pub const EXP_U35_F64_MAX_ULP: u64 = 35;
+pub const LN_U10_F64_MAX_ULP: u64 = 1;
+pub const EXP_U10_F64_MAX_ULP: u64 = 1;
+pub const POW_U35_F64_MAX_ULP: u64 = 35;
+pub const POWI_F64_MAX_ULP: u64 = 35;
+pub const CBRT_U35_F64_MAX_ULP: u64 = 35;
+pub const EXP10_U35_F64_MAX_ULP: u64 = 35;
+pub const EXPM1_U35_F64_MAX_ULP: u64 = 35;
+pub const LOG1P_U35_F64_MAX_ULP: u64 = 35;
 pub const SIN_U35_F64_MAX_ULP: u64 = 35;
 pub const COS_U35_F64_MAX_ULP: u64 = 35;
 pub const TAN_U35_F64_MAX_ULP: u64 = 35;
+pub const SINCOS_U35_F64_MAX_ULP: u64 = 35;
+pub const SINPI_U35_F64_MAX_ULP: u64 = 35;
+pub const COSPI_U35_F64_MAX_ULP: u64 = 35;
+pub const TANPI_U35_F64_MAX_ULP: u64 = 35;
 
 // Portable f64 inverse-trig kernels now use the family-local SIMD implementation
 // rather than scalar lane mapping, so they carry the honest u35 contract.
@@ -46,3 +81,7 @@ pub const ACOSH_U35_F64_MAX_ULP: u64 = 1;
 pub const ATANH_U35_F64_MAX_ULP: u64 = 1;
 pub const HYPOT_U35_F64_MAX_ULP: u64 = 1;
 pub const LOG10_U35_F64_MAX_ULP: u64 = 1;
+pub const ERF_U35_F64_MAX_ULP: u64 = 35;
+pub const ERFC_U35_F64_MAX_ULP: u64 = 35;
+pub const TGAMMA_U35_F64_MAX_ULP: u64 = 35;
+pub const LGAMMA_U35_F64_MAX_ULP: u64 = 35;
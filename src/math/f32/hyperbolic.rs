@@ -1,9 +1,8 @@
 use crate::math::scalar;
-use crate::{Simd, SimdBaseIo, SimdBaseOps, SimdConsts, SimdFloat32, SimdInt32};
+use crate::{Simd, SimdBaseIo, SimdBaseOps, SimdConsts, SimdFloat32, SimdMask};
 
 type SimdI32<V> = <<V as SimdConsts>::Engine as Simd>::Vi32;
 
-const SIGN_MASK: i32 = 0x8000_0000u32 as i32;
 const SINH_COSH_SMALL_ABS: f32 = 0.5;
 const SINH_COSH_FAST_ABS_MAX: f32 = 40.0;
 const TANH_SMALL_ABS: f32 = 0.625;
@@ -65,25 +64,6 @@ where
     }
 }
 
-#[inline(always)]
-fn apply_input_sign<V>(magnitude: V, input: V) -> V
-where
-    V: SimdFloat32,
-    V::Engine: Simd<Vf32 = V>,
-{
-    let sign_bits = input.bitcast_i32() & SimdI32::<V>::set1(SIGN_MASK);
-    (magnitude.bitcast_i32() | sign_bits).bitcast_f32()
-}
-
-#[inline(always)]
-fn exp_u35<V>(input: V) -> V
-where
-    V: SimdFloat32,
-    V::Engine: Simd<Vf32 = V>,
-{
-    super::portable::exp2_u35(input * V::set1(core::f32::consts::LOG2_E))
-}
-
 #[inline(always)]
 fn sinh_small<V>(input: V, input_sq: V) -> V
 where
@@ -111,7 +91,7 @@ where
     V: SimdFloat32,
     V::Engine: Simd<Vf32 = V>,
 {
-    let exp_abs = exp_u35(abs_input);
+    let exp_abs = super::portable::exp_u35(abs_input);
     let exp_neg_abs = V::set1(1.0) / exp_abs;
     let half = V::set1(0.5);
 
@@ -128,9 +108,10 @@ where
     V::Engine: Simd<Vf32 = V>,
 {
     let abs_input = input.abs();
-    let finite_mask = input.cmp_eq(input).bitcast_i32();
+    let finite_mask = input.is_finite().to_vector().bitcast_i32();
     let within_fast_range = abs_input
         .cmp_lte(V::set1(SINH_COSH_FAST_ABS_MAX))
+        .to_vector()
         .bitcast_i32();
 
     (finite_mask & within_fast_range, abs_input, input * input)
@@ -143,12 +124,12 @@ where
     V::Engine: Simd<Vf32 = V>,
 {
     let (fast_mask, abs_input, input_sq) = sinh_cosh_masks(input);
-    let exceptional_mask = fast_mask.cmp_eq(SimdI32::<V>::zeroes());
-    let small_mask = abs_input.cmp_lt(V::set1(SINH_COSH_SMALL_ABS));
+    let exceptional_mask = fast_mask.cmp_eq(SimdI32::<V>::zeroes()).to_vector();
+    let small_mask = abs_input.cmp_lt(V::set1(SINH_COSH_SMALL_ABS)).to_vector();
 
     let fast_small = sinh_small(input, input_sq);
     let (sinh_medium, _) = sinh_cosh_medium(abs_input);
-    let fast = small_mask.blendv(apply_input_sign(sinh_medium, input), fast_small);
+    let fast = small_mask.blendv(sinh_medium.copysign(input), fast_small);
 
     patch_exceptional_lanes(input, fast, exceptional_mask, scalar::sinh_u35_f32)
 }
@@ -160,8 +141,8 @@ where
     V::Engine: Simd<Vf32 = V>,
 {
     let (fast_mask, abs_input, input_sq) = sinh_cosh_masks(input);
-    let exceptional_mask = fast_mask.cmp_eq(SimdI32::<V>::zeroes());
-    let small_mask = abs_input.cmp_lt(V::set1(SINH_COSH_SMALL_ABS));
+    let exceptional_mask = fast_mask.cmp_eq(SimdI32::<V>::zeroes()).to_vector();
+    let small_mask = abs_input.cmp_lt(V::set1(SINH_COSH_SMALL_ABS)).to_vector();
 
     let fast_small = cosh_small(input_sq);
     let (_, cosh_medium) = sinh_cosh_medium(abs_input);
@@ -177,17 +158,22 @@ where
     V::Engine: Simd<Vf32 = V>,
 {
     let abs_input = input.abs();
-    let finite_mask = input.cmp_eq(input).bitcast_i32();
-    let within_fast_range = abs_input.cmp_lte(V::set1(TANH_FAST_ABS_MAX)).bitcast_i32();
-    let exceptional_mask = (finite_mask & within_fast_range).cmp_eq(SimdI32::<V>::zeroes());
-    let small_mask = abs_input.cmp_lt(V::set1(TANH_SMALL_ABS));
+    let finite_mask = input.is_finite().to_vector().bitcast_i32();
+    let within_fast_range = abs_input
+        .cmp_lte(V::set1(TANH_FAST_ABS_MAX))
+        .to_vector()
+        .bitcast_i32();
+    let exceptional_mask = (finite_mask & within_fast_range)
+        .cmp_eq(SimdI32::<V>::zeroes())
+        .to_vector();
+    let small_mask = abs_input.cmp_lt(V::set1(TANH_SMALL_ABS)).to_vector();
 
     let input_sq = input * input;
     let fast_small = sinh_small(input, input_sq) / cosh_small(input_sq);
 
-    let exp_neg_2x = exp_u35(abs_input * V::set1(-2.0));
+    let exp_neg_2x = super::portable::exp_u35(abs_input * V::set1(-2.0));
     let tanh_medium = (V::set1(1.0) - exp_neg_2x) / (V::set1(1.0) + exp_neg_2x);
-    let fast = small_mask.blendv(apply_input_sign(tanh_medium, input), fast_small);
+    let fast = small_mask.blendv(tanh_medium.copysign(input), fast_small);
 
     patch_exceptional_lanes(input, fast, exceptional_mask, scalar::tanh_u35_f32)
 }
@@ -1,10 +1,13 @@
 //! f32 SIMD math kernel layering:
 //! - `portable`: backend-agnostic reduction/polynomial kernels + scalar lane patching.
+//! - `hyperbolic` / `special`: family-local portable kernels built on the `portable` exp/log backbone.
 //! - `x86_avx2`: the only currently justified backend override (`log2_u35`).
 //! - this module: dispatch glue selecting overrides without changing the public API.
 
 mod hyperbolic;
 mod portable;
+#[cfg(feature = "special")]
+mod special;
 
 #[cfg(any(target_arch = "x86_64", target_arch = "x86"))]
 mod x86_avx2;
@@ -44,6 +47,78 @@ where
     portable::exp2_u35(input)
 }
 
+#[inline(always)]
+pub(crate) fn ln_u35<V>(input: V) -> V
+where
+    V: SimdFloat32,
+    V::Engine: Simd<Vf32 = V>,
+{
+    portable::ln_u35(input)
+}
+
+#[inline(always)]
+pub(crate) fn exp_u35<V>(input: V) -> V
+where
+    V: SimdFloat32,
+    V::Engine: Simd<Vf32 = V>,
+{
+    portable::exp_u35(input)
+}
+
+#[inline(always)]
+pub(crate) fn pow_u35<V>(base: V, exponent: V) -> V
+where
+    V: SimdFloat32,
+    V::Engine: Simd<Vf32 = V>,
+{
+    portable::pow_u35(base, exponent)
+}
+
+#[inline(always)]
+pub(crate) fn powi<V>(base: V, exponent: i32) -> V
+where
+    V: SimdFloat32,
+    V::Engine: Simd<Vf32 = V>,
+{
+    portable::powi(base, exponent)
+}
+
+#[inline(always)]
+pub(crate) fn cbrt_u35<V>(input: V) -> V
+where
+    V: SimdFloat32,
+    V::Engine: Simd<Vf32 = V>,
+{
+    portable::cbrt_u35(input)
+}
+
+#[inline(always)]
+pub(crate) fn exp10_u35<V>(input: V) -> V
+where
+    V: SimdFloat32,
+    V::Engine: Simd<Vf32 = V>,
+{
+    portable::exp10_u35(input)
+}
+
+#[inline(always)]
+pub(crate) fn expm1_u35<V>(input: V) -> V
+where
+    V: SimdFloat32,
+    V::Engine: Simd<Vf32 = V>,
+{
+    portable::expm1_u35(input)
+}
+
+#[inline(always)]
+pub(crate) fn log1p_u35<V>(input: V) -> V
+where
+    V: SimdFloat32,
+    V::Engine: Simd<Vf32 = V>,
+{
+    portable::log1p_u35(input)
+}
+
 #[inline(always)]
 pub(crate) fn sin_u35<V>(input: V) -> V
 where
@@ -71,6 +146,42 @@ where
     portable::tan_u35(input)
 }
 
+#[inline(always)]
+pub(crate) fn sincos_u35<V>(input: V) -> (V, V)
+where
+    V: SimdFloat32,
+    V::Engine: Simd<Vf32 = V>,
+{
+    portable::sincos_u35(input)
+}
+
+#[inline(always)]
+pub(crate) fn sinpi_u35<V>(input: V) -> V
+where
+    V: SimdFloat32,
+    V::Engine: Simd<Vf32 = V>,
+{
+    portable::sinpi_u35(input)
+}
+
+#[inline(always)]
+pub(crate) fn cospi_u35<V>(input: V) -> V
+where
+    V: SimdFloat32,
+    V::Engine: Simd<Vf32 = V>,
+{
+    portable::cospi_u35(input)
+}
+
+#[inline(always)]
+pub(crate) fn tanpi_u35<V>(input: V) -> V
+where
+    V: SimdFloat32,
+    V::Engine: Simd<Vf32 = V>,
+{
+    portable::tanpi_u35(input)
+}
+
 #[inline(always)]
 pub(crate) fn sinh_u35<V>(input: V) -> V
 where
@@ -125,6 +236,46 @@ where
     portable::atanh_u35(input)
 }
 
+#[cfg(feature = "special")]
+#[inline(always)]
+pub(crate) fn erf_u35<V>(input: V) -> V
+where
+    V: SimdFloat32,
+    V::Engine: Simd<Vf32 = V>,
+{
+    special::erf_u35(input)
+}
+
+#[cfg(feature = "special")]
+#[inline(always)]
+pub(crate) fn erfc_u35<V>(input: V) -> V
+where
+    V: SimdFloat32,
+    V::Engine: Simd<Vf32 = V>,
+{
+    special::erfc_u35(input)
+}
+
+#[cfg(feature = "special")]
+#[inline(always)]
+pub(crate) fn tgamma_u35<V>(input: V) -> V
+where
+    V: SimdFloat32,
+    V::Engine: Simd<Vf32 = V>,
+{
+    special::tgamma_u35(input)
+}
+
+#[cfg(feature = "special")]
+#[inline(always)]
+pub(crate) fn lgamma_u35<V>(input: V) -> V
+where
+    V: SimdFloat32,
+    V::Engine: Simd<Vf32 = V>,
+{
+    special::lgamma_u35(input)
+}
+
 #[cfg(any(target_arch = "x86_64", target_arch = "x86"))]
 #[inline(always)]
 fn is_avx2_engine<E: Simd>() -> bool {
@@ -1,5 +1,5 @@
 use crate::math::scalar;
-use crate::{Simd, SimdBaseIo, SimdBaseOps, SimdConsts, SimdFloat32, SimdInt, SimdInt32};
+use crate::{Simd, SimdBaseIo, SimdBaseOps, SimdConsts, SimdFloat32, SimdInt, SimdInt32, SimdMask};
 
 pub(super) type SimdI32<V> = <<V as SimdConsts>::Engine as Simd>::Vi32;
 
@@ -8,6 +8,13 @@ pub(super) const F32_MANTISSA_MASK: i32 = 0x007F_FFFF;
 pub(super) const F32_LOG_NORM_MANTISSA: i32 = 0x3F00_0000;
 pub(super) const F32_EXPONENT_BIAS_ADJUST: i32 = 126;
 
+// log10(2) split so `n * F32_LOG10_2_HI` stays exact for every in-range `n`.
+const F32_LOG10_2_HI: f32 = 0.301_025_4;
+const F32_LOG10_2_LO: f32 = 4.605_039e-6;
+const F32_EXP10_FAST_ABS_MAX: f32 = 37.5;
+const F32_POW_FAST_ABS_MAX: f32 = 16.0;
+const F32_POWI_MULTIPLY_MAX: u32 = 16;
+
 // DECISION(2026-03-23): KEEP_SIMD_PORTABLE
 // Function(s): f32 log2_u35 portable fallback / exp2_u35
 // Why kept:
@@ -16,6 +23,31 @@ pub(super) const F32_EXPONENT_BIAS_ADJUST: i32 = 126;
 // Revisit when:
 // - a new approximation family lands or non-x86 evidence disagrees sharply
 
+// DECISION(2026-10-18): KEEP_SIMD_PORTABLE
+// Function(s): f32 ln_u35 / exp_u35
+// Why kept:
+// - `simd_math/f32/*` benches against native scalar, runtime-selected (AVX-512) / forced AVX2 /
+//   forced SSE2: ln 3.34x / 1.67x / 1.06x, exp 4.79x / 3.16x / 0.84x
+// - sharing the log2/exp2 polynomials instead of mapping scalar lanes still stays within 1 ulp
+// - exp reduces by a split ln 2 so large inputs keep their accuracy
+// Revisit when:
+// - the shared log2/exp2 polynomials change
+// - SSE2-only hosts matter: exp still trails native scalar there
+
+// DECISION(2026-10-18): KEEP_SIMD_PORTABLE
+// Function(s): f32 pow_u35 / powi / cbrt_u35 / exp10_u35 / expm1_u35 / log1p_u35
+// Why kept:
+// - `simd_math/f32/*` benches against native scalar, runtime-selected (AVX-512) / forced AVX2 /
+//   forced SSE2: pow 3.64x / 1.98x / 0.68x, powi 2.75x / 2.35x / 1.31x,
+//   cbrt 3.08x / 1.94x / 0.68x, exp10 10.2x / 5.51x / 1.33x, expm1 15.9x / 12.0x / 1.66x,
+//   log1p 13.8x / 9.11x / 4.64x
+// - each kernel reuses the ln/exp reductions above instead of adding new approximation families
+// - pow only takes the fast path while `|y * ln x|` is small enough for the u35 budget; the
+//   special-case table (negative, zero, and subnormal bases, non-finite operands) stays scalar
+// Revisit when:
+// - a double-f32 log lets pow widen its fast range
+// - SSE2-only hosts matter: pow and cbrt still trail native scalar there
+
 // DECISION(2026-03-23): KEEP_SIMD_PORTABLE
 // Function(s): f32 sin_u35 / cos_u35 / tan_u35
 // Why kept:
@@ -24,6 +56,17 @@ pub(super) const F32_EXPONENT_BIAS_ADJUST: i32 = 126;
 // Revisit when:
 // - large-argument reduction strategy changes materially
 
+// DECISION(2026-10-18): KEEP_SIMD_PORTABLE
+// Function(s): f32 sincos_u35 / sinpi_u35 / cospi_u35 / tanpi_u35
+// Why kept:
+// - `simd_math/f32/*` benches against native scalar (`sin_cos`, and `sin`/`cos`/`tan` of
+//   `x * PI`), runtime-selected (AVX-512) / forced AVX2 / forced SSE2: sincos 10.0x / 5.57x /
+//   2.64x, sinpi 15.9x / 8.26x / 2.05x, cospi 13.4x / 7.63x / 2.21x, tanpi 15.9x / 11.1x / 3.20x
+// - sincos shares one reduction between both outputs instead of running it twice
+// - the pi-scaled variants reduce exactly in half turns, so only non-finite lanes are patched
+// Revisit when:
+// - the shared sin/cos polynomials change
+
 // DECISION(2026-03-23): KEEP_SIMD_PORTABLE
 // Function(s): f32 asinh_u35 / acosh_u35 / atanh_u35
 // Why kept:
@@ -61,10 +104,14 @@ where
 
     let non_positive = input
         .cmp_gt(V::zeroes())
+        .to_vector()
         .bitcast_i32()
-        .cmp_eq(SimdI32::<V>::zeroes());
-    let subnormal_or_zero = exponent_bits.cmp_eq(SimdI32::<V>::zeroes());
-    let inf_or_nan = exponent_bits.cmp_eq(SimdI32::<V>::set1(F32_EXPONENT_MASK));
+        .cmp_eq(SimdI32::<V>::zeroes())
+        .to_vector();
+    let subnormal_or_zero = exponent_bits.cmp_eq(SimdI32::<V>::zeroes()).to_vector();
+    let inf_or_nan = exponent_bits
+        .cmp_eq(SimdI32::<V>::set1(F32_EXPONENT_MASK))
+        .to_vector();
 
     non_positive | subnormal_or_zero | inf_or_nan
 }
@@ -99,8 +146,9 @@ where
     }
 }
 
+/// Natural log for positive normal finite lanes; callers patch everything else.
 #[inline(always)]
-pub(super) fn log2_u35<V>(input: V) -> V
+pub(super) fn ln_fast<V>(input: V) -> V
 where
     V: SimdFloat32,
     V::Engine: Simd<Vf32 = V>,
@@ -109,8 +157,6 @@ where
     let exponent_bits = bits & F32_EXPONENT_MASK;
     let mantissa_bits = bits & F32_MANTISSA_MASK;
 
-    let exceptional_mask = log2_exceptional_mask(input);
-
     let exponent = (exponent_bits.shr(23) - F32_EXPONENT_BIAS_ADJUST).cast_f32();
     let normalized_mantissa = (mantissa_bits | F32_LOG_NORM_MANTISSA).bitcast_f32();
 
@@ -118,7 +164,7 @@ where
     let half = V::set1(0.5);
     let sqrt_half = V::set1(core::f32::consts::FRAC_1_SQRT_2);
 
-    let adjust_mask = normalized_mantissa.cmp_lt(sqrt_half);
+    let adjust_mask = normalized_mantissa.cmp_lt(sqrt_half).to_vector();
     let exponent = exponent - adjust_mask.blendv(V::zeroes(), one);
     let reduced = adjust_mask.blendv(
         normalized_mantissa - one,
@@ -142,28 +188,16 @@ where
     poly += exponent * V::set1(-2.121_944_4e-4);
     poly -= half * reduced_sq;
 
-    let ln_x = reduced + poly + (exponent * V::set1(0.693_359_4));
-    let fast = ln_x * V::set1(core::f32::consts::LOG2_E);
-
-    patch_exceptional_lanes(input, fast, exceptional_mask, scalar::log2_u35_f32)
+    reduced + poly + (exponent * V::set1(0.693_359_4))
 }
 
+/// `exp(reduced) - 1` for `|reduced|` up to about `ln 2`.
 #[inline(always)]
-pub(super) fn exp2_u35<V>(input: V) -> V
+pub(super) fn expm1_reduced_poly<V>(reduced: V) -> V
 where
     V: SimdFloat32,
     V::Engine: Simd<Vf32 = V>,
 {
-    let finite_mask = input.cmp_eq(input).bitcast_i32();
-    let in_lower_bound = input.cmp_gte(V::set1(-126.0)).bitcast_i32();
-    let in_upper_bound = input.cmp_lte(V::set1(126.0)).bitcast_i32();
-    let fast_mask = finite_mask & in_lower_bound & in_upper_bound;
-    let exceptional_mask = fast_mask.cmp_eq(SimdI32::<V>::zeroes());
-
-    let integral = input.floor().cast_i32();
-    let fractional = input - integral.cast_f32();
-    let reduced = fractional * V::set1(core::f32::consts::LN_2);
-
     let mut poly = V::set1(1.987_569_1e-4);
     poly = (poly * reduced) + V::set1(1.398_2e-3);
     poly = (poly * reduced) + V::set1(8.333_452e-3);
@@ -172,7 +206,95 @@ where
     poly = (poly * reduced) + V::set1(5e-1);
 
     let reduced_sq = reduced * reduced;
-    let exp_reduced = (poly * reduced_sq) + reduced + V::set1(1.0);
+    (poly * reduced_sq) + reduced
+}
+
+#[inline(always)]
+fn exp_reduced_poly<V>(reduced: V) -> V
+where
+    V: SimdFloat32,
+    V::Engine: Simd<Vf32 = V>,
+{
+    expm1_reduced_poly(reduced) + V::set1(1.0)
+}
+
+/// Splits `input` into `integral * ln 2 + reduced` with `|reduced| <= ln 2 / 2`.
+///
+/// The ln 2 split keeps `integral * hi` exact, so large inputs do not pick up the rounding
+/// error that scaling into exp2 by `log2(e)` would add.
+#[inline(always)]
+pub(super) fn exp_reduce<V>(input: V) -> (SimdI32<V>, V)
+where
+    V: SimdFloat32,
+    V::Engine: Simd<Vf32 = V>,
+{
+    let integral_f = ((input * V::set1(core::f32::consts::LOG2_E)) + V::set1(0.5)).floor();
+    let reduced = input - integral_f * V::set1(0.693_359_4);
+    let reduced = reduced - integral_f * V::set1(-2.121_944_4e-4);
+    (integral_f.cast_i32(), reduced)
+}
+
+/// `2^integral` for integrals that keep the result a normal float.
+#[inline(always)]
+pub(super) fn exp2_scale<V>(integral: SimdI32<V>) -> V
+where
+    V: SimdFloat32,
+    V::Engine: Simd<Vf32 = V>,
+{
+    (integral + 127).shl(23).bitcast_f32()
+}
+
+/// `exp(input)` for finite lanes in `[-87, 87]`; callers patch everything else.
+#[inline(always)]
+pub(super) fn exp_fast<V>(input: V) -> V
+where
+    V: SimdFloat32,
+    V::Engine: Simd<Vf32 = V>,
+{
+    let (integral, reduced) = exp_reduce(input);
+    exp_reduced_poly(reduced) * exp2_scale::<V>(integral)
+}
+
+#[inline(always)]
+pub(super) fn log2_u35<V>(input: V) -> V
+where
+    V: SimdFloat32,
+    V::Engine: Simd<Vf32 = V>,
+{
+    let exceptional_mask = log2_exceptional_mask(input);
+    let fast = ln_fast(input) * V::set1(core::f32::consts::LOG2_E);
+
+    patch_exceptional_lanes(input, fast, exceptional_mask, scalar::log2_u35_f32)
+}
+
+#[inline(always)]
+pub(super) fn ln_u35<V>(input: V) -> V
+where
+    V: SimdFloat32,
+    V::Engine: Simd<Vf32 = V>,
+{
+    let exceptional_mask = log2_exceptional_mask(input);
+    let fast = ln_fast(input);
+
+    patch_exceptional_lanes(input, fast, exceptional_mask, scalar::ln_u35_f32)
+}
+
+#[inline(always)]
+pub(super) fn exp2_u35<V>(input: V) -> V
+where
+    V: SimdFloat32,
+    V::Engine: Simd<Vf32 = V>,
+{
+    let finite_mask = input.is_finite().to_vector().bitcast_i32();
+    let in_lower_bound = input.cmp_gte(V::set1(-126.0)).to_vector().bitcast_i32();
+    let in_upper_bound = input.cmp_lte(V::set1(126.0)).to_vector().bitcast_i32();
+    let fast_mask = finite_mask & in_lower_bound & in_upper_bound;
+    let exceptional_mask = fast_mask.cmp_eq(SimdI32::<V>::zeroes()).to_vector();
+
+    let integral = input.floor().cast_i32();
+    let fractional = input - integral.cast_f32();
+    let reduced = fractional * V::set1(core::f32::consts::LN_2);
+    let exp_reduced = exp_reduced_poly(reduced);
 
     let exp_bits = (integral + 127).shl(23);
     let scale = exp_bits.bitcast_f32();
@@ -181,17 +303,258 @@ where
     patch_exceptional_lanes(input, fast, exceptional_mask, scalar::exp2_u35_f32)
 }
 
+#[inline(always)]
+pub(super) fn exp_u35<V>(input: V) -> V
+where
+    V: SimdFloat32,
+    V::Engine: Simd<Vf32 = V>,
+{
+    // Keep `2^integral` a normal float; anything outside goes to the scalar reference.
+    let finite_mask = input.is_finite().to_vector().bitcast_i32();
+    let in_lower_bound = input.cmp_gte(V::set1(-87.0)).to_vector().bitcast_i32();
+    let in_upper_bound = input.cmp_lte(V::set1(87.0)).to_vector().bitcast_i32();
+    let fast_mask = finite_mask & in_lower_bound & in_upper_bound;
+    let exceptional_mask = fast_mask.cmp_eq(SimdI32::<V>::zeroes()).to_vector();
+
+    let fast = exp_fast(input);
+
+    patch_exceptional_lanes(input, fast, exceptional_mask, scalar::exp_u35_f32)
+}
+
+#[inline(always)]
+fn patch_binary_exceptional_lanes<V>(
+    lhs: V,
+    rhs: V,
+    output: V,
+    exceptional_mask: SimdI32<V>,
+    scalar_fallback: fn(f32, f32) -> f32,
+) -> V
+where
+    V: SimdFloat32,
+    V::Engine: Simd<Vf32 = V>,
+{
+    if !any_lane_nonzero::<V>(exceptional_mask) {
+        return output;
+    }
+
+    unsafe {
+        let lhs_lanes = lhs.as_array();
+        let rhs_lanes = rhs.as_array();
+        let mask_lanes = exceptional_mask.as_array();
+        let mut output_lanes = output.as_array();
+
+        for lane in 0..V::WIDTH {
+            if mask_lanes[lane] != 0 {
+                output_lanes[lane] = scalar_fallback(lhs_lanes[lane], rhs_lanes[lane]);
+            }
+        }
+
+        V::load_from_ptr_unaligned(&output_lanes as *const V::ArrayRepresentation as *const f32)
+    }
+}
+
+/// Negates the lanes of `value` whose `sign_source` lane has its sign bit set.
+#[inline(always)]
+fn xor_sign_bit<V>(value: V, sign_source: V) -> V
+where
+    V: SimdFloat32,
+    V::Engine: Simd<Vf32 = V>,
+{
+    let sign = sign_source.bitcast_i32() & i32::MIN;
+    (value.bitcast_i32() ^ sign).bitcast_f32()
+}
+
+#[inline(always)]
+pub(super) fn pow_u35<V>(base: V, exponent: V) -> V
+where
+    V: SimdFloat32,
+    V::Engine: Simd<Vf32 = V>,
+{
+    // `exp(y * ln x)` scales the absolute error of `ln x` by `y`, so only lanes whose
+    // result stays within `e^±F32_POW_FAST_ABS_MAX` take the fast path. Non-positive bases,
+    // subnormal bases, and non-finite operands carry the special cases and go to scalar.
+    let product = exponent * ln_fast(base);
+    let exponent_finite = exponent.is_finite().to_vector().bitcast_i32();
+    let within_fast_range = product
+        .abs()
+        .cmp_lte(V::set1(F32_POW_FAST_ABS_MAX))
+        .to_vector()
+        .bitcast_i32();
+    let fast_mask = exponent_finite & within_fast_range;
+    let exceptional_mask =
+        log2_exceptional_mask(base) | fast_mask.cmp_eq(SimdI32::<V>::zeroes()).to_vector();
+
+    let fast = exp_fast(product);
+
+    patch_binary_exceptional_lanes(base, exponent, fast, exceptional_mask, scalar::pow_u35_f32)
+}
+
+#[inline(always)]
+pub(super) fn powi<V>(base: V, exponent: i32) -> V
+where
+    V: SimdFloat32,
+    V::Engine: Simd<Vf32 = V>,
+{
+    let abs_base = base.abs();
+    let mut remaining = exponent.unsigned_abs();
+
+    let magnitude = if remaining <= F32_POWI_MULTIPLY_MAX {
+        let mut power = abs_base;
+        let mut product = V::set1(1.0);
+        while remaining != 0 {
+            if remaining & 1 != 0 {
+                product *= power;
+            }
+            remaining >>= 1;
+            if remaining != 0 {
+                power *= power;
+            }
+        }
+
+        if exponent < 0 {
+            // The reciprocal of a zero product is already the right infinity, but subnormal
+            // or overflowed products lost the bits a finite result would need.
+            let exceptional_mask = log2_exceptional_mask(product)
+                & product.cmp_neq(V::zeroes()).to_vector().bitcast_i32();
+            let fast = V::set1(1.0) / product;
+            if any_lane_nonzero::<V>(exceptional_mask) {
+                unsafe {
+                    let input_lanes = abs_base.as_array();
+                    let mask_lanes = exceptional_mask.as_array();
+                    let mut output_lanes = fast.as_array();
+
+                    for lane in 0..V::WIDTH {
+                        if mask_lanes[lane] != 0 {
+                            output_lanes[lane] = scalar::powi_f32(input_lanes[lane], exponent);
+                        }
+                    }
+
+                    V::load_from_ptr_unaligned(
+                        &output_lanes as *const V::ArrayRepresentation as *const f32,
+                    )
+                }
+            } else {
+                fast
+            }
+        } else {
+            product
+        }
+    } else {
+        pow_u35(abs_base, V::set1(exponent as f32))
+    };
+
+    if exponent & 1 != 0 {
+        magnitude.copysign(base)
+    } else {
+        magnitude
+    }
+}
+
+#[inline(always)]
+pub(super) fn cbrt_u35<V>(input: V) -> V
+where
+    V: SimdFloat32,
+    V::Engine: Simd<Vf32 = V>,
+{
+    let abs_input = input.abs();
+    let exceptional_mask = log2_exceptional_mask(abs_input);
+
+    // The exp/ln estimate is good to a few parts in 1e6; one Newton step squares that away.
+    let estimate = exp_fast(ln_fast(abs_input) * V::set1(1.0 / 3.0));
+    let refined = estimate - (estimate - abs_input / (estimate * estimate)) * V::set1(1.0 / 3.0);
+    let fast = refined.copysign(input);
+
+    patch_exceptional_lanes(input, fast, exceptional_mask, scalar::cbrt_u35_f32)
+}
+
+#[inline(always)]
+pub(super) fn exp10_u35<V>(input: V) -> V
+where
+    V: SimdFloat32,
+    V::Engine: Simd<Vf32 = V>,
+{
+    let finite_mask = input.is_finite().to_vector().bitcast_i32();
+    let in_lower_bound = input
+        .cmp_gte(V::set1(-F32_EXP10_FAST_ABS_MAX))
+        .to_vector()
+        .bitcast_i32();
+    let in_upper_bound = input
+        .cmp_lte(V::set1(F32_EXP10_FAST_ABS_MAX))
+        .to_vector()
+        .bitcast_i32();
+    let fast_mask = finite_mask & in_lower_bound & in_upper_bound;
+    let exceptional_mask = fast_mask.cmp_eq(SimdI32::<V>::zeroes()).to_vector();
+
+    // Reduce by log10(2) in two pieces, then rescale the small remainder into the exp domain.
+    let integral_f = ((input * V::set1(core::f32::consts::LOG2_10)) + V::set1(0.5)).floor();
+    let reduced = input - integral_f * V::set1(F32_LOG10_2_HI);
+    let reduced = reduced - integral_f * V::set1(F32_LOG10_2_LO);
+    let reduced = reduced * V::set1(core::f32::consts::LN_10);
+
+    let fast = exp_reduced_poly(reduced) * exp2_scale::<V>(integral_f.cast_i32());
+
+    patch_exceptional_lanes(input, fast, exceptional_mask, scalar::exp10_u35_f32)
+}
+
+#[inline(always)]
+pub(super) fn expm1_u35<V>(input: V) -> V
+where
+    V: SimdFloat32,
+    V::Engine: Simd<Vf32 = V>,
+{
+    let finite_mask = input.is_finite().to_vector().bitcast_i32();
+    let in_lower_bound = input.cmp_gte(V::set1(-87.0)).to_vector().bitcast_i32();
+    let in_upper_bound = input.cmp_lte(V::set1(87.0)).to_vector().bitcast_i32();
+    let non_zero_mask = input.cmp_neq(V::zeroes()).to_vector().bitcast_i32();
+    let fast_mask = finite_mask & in_lower_bound & in_upper_bound & non_zero_mask;
+    let exceptional_mask = fast_mask.cmp_eq(SimdI32::<V>::zeroes()).to_vector();
+
+    // `2^n * expm1(r) + (2^n - 1)` never forms `exp(x)` near 1, so small inputs keep their
+    // relative accuracy; `n == 0` reduces to the bare polynomial.
+    let (integral, reduced) = exp_reduce(input);
+    let scale = exp2_scale::<V>(integral);
+    let fast = (scale * expm1_reduced_poly(reduced)) + (scale - V::set1(1.0));
+
+    patch_exceptional_lanes(input, fast, exceptional_mask, scalar::expm1_u35_f32)
+}
+
+#[inline(always)]
+pub(super) fn log1p_u35<V>(input: V) -> V
+where
+    V: SimdFloat32,
+    V::Engine: Simd<Vf32 = V>,
+{
+    let finite_mask = input.is_finite().to_vector().bitcast_i32();
+    let above_minus_one = input.cmp_gt(V::set1(-1.0)).to_vector().bitcast_i32();
+    let non_zero_mask = input.cmp_neq(V::zeroes()).to_vector().bitcast_i32();
+    let fast_mask = finite_mask & above_minus_one & non_zero_mask;
+    let exceptional_mask = fast_mask.cmp_eq(SimdI32::<V>::zeroes()).to_vector();
+
+    // `1 + x` drops the low bits of small inputs; `(x - (u - 1)) / u` adds back what the
+    // rounding took, to first order.
+    let one = V::set1(1.0);
+    let shifted = one + input;
+    let correction = (input - (shifted - one)) / shifted;
+    let fast = ln_fast(shifted) + correction;
+
+    patch_exceptional_lanes(input, fast, exceptional_mask, scalar::log1p_u35_f32)
+}
+
 #[inline(always)]
 fn trig_exceptional_mask<V>(input: V) -> SimdI32<V>
 where
     V: SimdFloat32,
     V::Engine: Simd<Vf32 = V>,
 {
-    let finite_mask = input.cmp_eq(input).bitcast_i32();
-    let within_fast_range = input.abs().cmp_lte(V::set1(8192.0)).bitcast_i32();
-    let non_zero_mask = input.cmp_neq(V::zeroes()).bitcast_i32();
+    let finite_mask = input.is_finite().to_vector().bitcast_i32();
+    let within_fast_range = input
+        .abs()
+        .cmp_lte(V::set1(8192.0))
+        .to_vector()
+        .bitcast_i32();
+    let non_zero_mask = input.cmp_neq(V::zeroes()).to_vector().bitcast_i32();
     let fast_mask = finite_mask & within_fast_range & non_zero_mask;
-    fast_mask.cmp_eq(SimdI32::<V>::zeroes())
+    fast_mask.cmp_eq(SimdI32::<V>::zeroes()).to_vector()
 }
 
 #[inline(always)]
@@ -206,6 +569,16 @@ where
     let n_f = n.cast_f32();
     let r = ((input - n_f * V::set1(1.570_312_5)) - n_f * V::set1(4.837_513e-4))
         - n_f * V::set1(7.549_789_4e-8);
+    sin_cos_quadrant(n, r)
+}
+
+/// `(sin, cos)` of `n * pi / 2 + r` for `|r| <= pi / 4`.
+#[inline(always)]
+fn sin_cos_quadrant<V>(n: SimdI32<V>, r: V) -> (V, V)
+where
+    V: SimdFloat32,
+    V::Engine: Simd<Vf32 = V>,
+{
     let r2 = r * r;
 
     let sin_poly = (((V::set1(-2.388_985_9e-8) * r2 + V::set1(2.752_556_2e-6)) * r2
@@ -225,19 +598,19 @@ where
     let cos_r = (cos_poly * r2) + V::set1(1.0);
 
     let q = n & SimdI32::<V>::set1(3);
-    let q0 = q.cmp_eq(SimdI32::<V>::zeroes()).bitcast_f32();
-    let q1 = q.cmp_eq(SimdI32::<V>::set1(1)).bitcast_f32();
-    let q2 = q.cmp_eq(SimdI32::<V>::set1(2)).bitcast_f32();
+    let q0 = q.cmp_eq(SimdI32::<V>::zeroes()).to_vector().bitcast_f32();
+    let q1 = q.cmp_eq(SimdI32::<V>::set1(1)).to_vector().bitcast_f32();
+    let q2 = q.cmp_eq(SimdI32::<V>::set1(2)).to_vector().bitcast_f32();
 
     let mut sin_out = q0.blendv(V::zeroes(), sin_r);
     sin_out = q1.blendv(sin_out, cos_r);
     sin_out = q2.blendv(sin_out, -sin_r);
-    sin_out = (q0 | q1 | q2).cmp_eq(V::zeroes()).blendv(sin_out, -cos_r);
+    sin_out = (q0 | q1 | q2).cmp_eq(V::zeroes()).select(-cos_r, sin_out);
 
     let mut cos_out = q0.blendv(V::zeroes(), cos_r);
     cos_out = q1.blendv(cos_out, -sin_r);
     cos_out = q2.blendv(cos_out, -cos_r);
-    cos_out = (q0 | q1 | q2).cmp_eq(V::zeroes()).blendv(cos_out, sin_r);
+    cos_out = (q0 | q1 | q2).cmp_eq(V::zeroes()).select(sin_r, cos_out);
 
     (sin_out, cos_out)
 }
@@ -272,29 +645,119 @@ where
 {
     let base_exceptional = trig_exceptional_mask(input);
     let (sin_fast, cos_fast) = sin_cos_fast(input);
-    let dangerous = cos_fast.abs().cmp_lt(V::set1(1.0e-4)).bitcast_i32();
+    let dangerous = cos_fast
+        .abs()
+        .cmp_lt(V::set1(1.0e-4))
+        .to_vector()
+        .bitcast_i32();
     let exceptional_mask = base_exceptional | dangerous;
     let fast = sin_fast / cos_fast;
     patch_exceptional_lanes(input, fast, exceptional_mask, scalar::tan_u35_f32)
 }
 
+#[inline(always)]
+pub(super) fn sincos_u35<V>(input: V) -> (V, V)
+where
+    V: SimdFloat32,
+    V::Engine: Simd<Vf32 = V>,
+{
+    let exceptional_mask = trig_exceptional_mask(input);
+    let (sin_fast, cos_fast) = sin_cos_fast(input);
+    (
+        patch_exceptional_lanes(input, sin_fast, exceptional_mask, scalar::sin_u35_f32),
+        patch_exceptional_lanes(input, cos_fast, exceptional_mask, scalar::cos_u35_f32),
+    )
+}
+
+#[inline(always)]
+fn non_finite_mask<V>(input: V) -> SimdI32<V>
+where
+    V: SimdFloat32,
+    V::Engine: Simd<Vf32 = V>,
+{
+    input
+        .is_finite()
+        .to_vector()
+        .bitcast_i32()
+        .cmp_eq(SimdI32::<V>::zeroes())
+        .to_vector()
+}
+
+/// `(sin(pi * |x|), cos(pi * |x|))` with exact, positive zeros at integers and half-integers.
+///
+/// Reducing `|x|` mod 2 and then by the nearest half turn is exact, so the only rounding before
+/// the polynomials is `pi * r`.
+#[inline(always)]
+fn sin_cos_pi_abs<V>(input: V) -> (V, V)
+where
+    V: SimdFloat32,
+    V::Engine: Simd<Vf32 = V>,
+{
+    let abs_input = input.abs();
+    let reduced = abs_input - (abs_input * V::set1(0.5)).floor() * V::set1(2.0);
+    let half_turns = (reduced * V::set1(2.0)).round();
+    let r = reduced - half_turns * V::set1(0.5);
+
+    let (sin_out, cos_out) =
+        sin_cos_quadrant(half_turns.cast_i32(), r * V::set1(core::f32::consts::PI));
+    // Adding +0 turns the -0 that negated quadrants produce into +0.
+    (sin_out + V::zeroes(), cos_out + V::zeroes())
+}
+
+#[inline(always)]
+pub(super) fn sinpi_u35<V>(input: V) -> V
+where
+    V: SimdFloat32,
+    V::Engine: Simd<Vf32 = V>,
+{
+    let exceptional_mask = non_finite_mask(input);
+    let (sin_abs, _) = sin_cos_pi_abs(input);
+    let fast = xor_sign_bit(sin_abs, input);
+    patch_exceptional_lanes(input, fast, exceptional_mask, scalar::sinpi_u35_f32)
+}
+
+#[inline(always)]
+pub(super) fn cospi_u35<V>(input: V) -> V
+where
+    V: SimdFloat32,
+    V::Engine: Simd<Vf32 = V>,
+{
+    let exceptional_mask = non_finite_mask(input);
+    let (_, fast) = sin_cos_pi_abs(input);
+    patch_exceptional_lanes(input, fast, exceptional_mask, scalar::cospi_u35_f32)
+}
+
+#[inline(always)]
+pub(super) fn tanpi_u35<V>(input: V) -> V
+where
+    V: SimdFloat32,
+    V::Engine: Simd<Vf32 = V>,
+{
+    // Both parts come from the exactly reduced remainder, so the quotient keeps its relative
+    // accuracy next to the poles and the zero `cos` at half-integers yields a signed infinity.
+    let exceptional_mask = non_finite_mask(input);
+    let (sin_abs, cos_abs) = sin_cos_pi_abs(input);
+    let fast = xor_sign_bit(sin_abs / cos_abs, input);
+    patch_exceptional_lanes(input, fast, exceptional_mask, scalar::tanpi_u35_f32)
+}
+
 #[inline(always)]
 pub(super) fn asinh_u35<V>(input: V) -> V
 where
     V: SimdFloat32,
     V::Engine: Simd<Vf32 = V>,
 {
-    let finite_mask = input.cmp_eq(input).bitcast_i32();
+    let finite_mask = input.is_finite().to_vector().bitcast_i32();
     let abs_x = input.abs();
-    let tiny_mask = abs_x.cmp_lt(V::set1(0.05)).bitcast_i32();
-    let large_mask = abs_x.cmp_gt(V::set1(1.0e19)).bitcast_i32();
-    let zero_mask = input.cmp_eq(V::zeroes()).bitcast_i32();
+    let tiny_mask = abs_x.cmp_lt(V::set1(0.05)).to_vector().bitcast_i32();
+    let large_mask = abs_x.cmp_gt(V::set1(1.0e19)).to_vector().bitcast_i32();
+    let zero_mask = input.cmp_eq(V::zeroes()).to_vector().bitcast_i32();
     let exceptional_mask =
-        finite_mask.cmp_eq(SimdI32::<V>::zeroes()) | tiny_mask | large_mask | zero_mask;
+        finite_mask.cmp_eq(SimdI32::<V>::zeroes()).to_vector() | tiny_mask | large_mask | zero_mask;
 
     let radicand = (abs_x * abs_x) + V::set1(1.0);
     let magnitude = log2_u35(abs_x + radicand.sqrt()) * V::set1(core::f32::consts::LN_2);
-    let negative_mask = input.cmp_lt(V::zeroes());
+    let negative_mask = input.cmp_lt(V::zeroes()).to_vector();
     let fast = negative_mask.blendv(magnitude, -magnitude);
 
     patch_exceptional_lanes(input, fast, exceptional_mask, scalar::asinh_u35_f32)
@@ -306,10 +769,10 @@ where
     V: SimdFloat32,
     V::Engine: Simd<Vf32 = V>,
 {
-    let finite_mask = input.cmp_eq(input).bitcast_i32();
-    let in_domain_mask = input.cmp_gte(V::set1(1.0)).bitcast_i32();
+    let finite_mask = input.is_finite().to_vector().bitcast_i32();
+    let in_domain_mask = input.cmp_gte(V::set1(1.0)).to_vector().bitcast_i32();
     let fast_mask = finite_mask & in_domain_mask;
-    let exceptional_mask = fast_mask.cmp_eq(SimdI32::<V>::zeroes());
+    let exceptional_mask = fast_mask.cmp_eq(SimdI32::<V>::zeroes()).to_vector();
 
     let root_term = ((input - V::set1(1.0)).sqrt()) * ((input + V::set1(1.0)).sqrt());
     let fast = log2_u35(input + root_term) * V::set1(core::f32::consts::LN_2);
@@ -323,15 +786,15 @@ where
     V: SimdFloat32,
     V::Engine: Simd<Vf32 = V>,
 {
-    let finite_mask = input.cmp_eq(input).bitcast_i32();
+    let finite_mask = input.is_finite().to_vector().bitcast_i32();
     let abs_x = input.abs();
-    let strict_domain_mask = abs_x.cmp_lt(V::set1(1.0)).bitcast_i32();
-    let non_zero_mask = input.cmp_neq(V::zeroes()).bitcast_i32();
-    let stable_range_mask = abs_x.cmp_lte(V::set1(0.75)).bitcast_i32();
-    let away_from_zero_mask = abs_x.cmp_gte(V::set1(0.05)).bitcast_i32();
+    let strict_domain_mask = abs_x.cmp_lt(V::set1(1.0)).to_vector().bitcast_i32();
+    let non_zero_mask = input.cmp_neq(V::zeroes()).to_vector().bitcast_i32();
+    let stable_range_mask = abs_x.cmp_lte(V::set1(0.75)).to_vector().bitcast_i32();
+    let away_from_zero_mask = abs_x.cmp_gte(V::set1(0.05)).to_vector().bitcast_i32();
     let fast_mask =
         finite_mask & strict_domain_mask & non_zero_mask & stable_range_mask & away_from_zero_mask;
-    let exceptional_mask = fast_mask.cmp_eq(SimdI32::<V>::zeroes());
+    let exceptional_mask = fast_mask.cmp_eq(SimdI32::<V>::zeroes()).to_vector();
 
     let one = V::set1(1.0);
     let ratio = (one + input) / (one - input);
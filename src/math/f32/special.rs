@@ -0,0 +1,395 @@
+use super::portable::{exp_fast, ln_fast, patch_exceptional_lanes, SimdI32};
+use crate::math::scalar;
+use crate::{Simd, SimdBaseIo, SimdBaseOps, SimdFloat32, SimdInt32, SimdMask};
+
+const F32_ERF_SMALL_ABS: f32 = 0.843_75;
+const F32_ERF_MEDIUM_ABS: f32 = 1.25;
+const F32_ERF_TAIL_SPLIT_ABS: f32 = 1.0 / 0.35;
+const F32_ERF_FAST_ABS_MAX: f32 = 6.0;
+const F32_ERFC_FAST_MIN: f32 = -6.0;
+const F32_ERFC_FAST_MAX: f32 = 9.0;
+const F32_ERF_TAIL_HIGH_MASK: i32 = 0xFFFF_E000u32 as i32;
+const F32_GAMMA_FAST_MIN: f32 = -30.0;
+const F32_TGAMMA_FAST_MAX: f32 = 35.0;
+const F32_LGAMMA_STIRLING_MIN: f32 = 8.0;
+const F32_LGAMMA_FAST_MAX: f32 = 1.0e30;
+const F32_HALF_LN_2PI: f32 = 0.918_938_5;
+
+// Coefficients for erf/erfc follow fdlibm's `s_erff.c`, one rational per range.
+const F32_ERX: f32 = 0.845_062_9;
+const F32_ERF_PP: [f32; 5] = [
+    0.128_379_17,
+    -0.325_042_1,
+    -0.028_481_75,
+    -0.005_770_270_2,
+    -2.376_301_7e-5,
+];
+const F32_ERF_QQ: [f32; 6] = [
+    1.0,
+    0.397_917_2,
+    0.065_022_25,
+    0.005_081_306,
+    1.324_947e-4,
+    -3.960_228_2e-6,
+];
+const F32_ERF_PA: [f32; 7] = [
+    -0.002_362_118_6,
+    0.414_856_1,
+    -0.372_207_88,
+    0.318_346_62,
+    -0.110_894_695,
+    0.035_478_305,
+    -0.002_166_375_5,
+];
+const F32_ERF_QA: [f32; 7] = [
+    1.0,
+    0.106_420_88,
+    0.540_397_94,
+    0.071_828_656,
+    0.126_171_22,
+    0.013_637_084,
+    0.011_984_5,
+];
+const F32_ERF_RA: [f32; 8] = [
+    -0.009_864_944,
+    -0.693_858_56,
+    -10.558_626,
+    -62.375_33,
+    -162.396_67,
+    -184.605_09,
+    -81.287_44,
+    -9.814_329,
+];
+const F32_ERF_SA: [f32; 9] = [
+    1.0,
+    19.651_272,
+    137.657_76,
+    434.5659,
+    645.387_27,
+    429.008_15,
+    108.635,
+    6.570_249_6,
+    -0.060_424_414,
+];
+const F32_ERF_RB: [f32; 7] = [
+    -0.009_864_943,
+    -0.799_283_27,
+    -17.757_956,
+    -160.636_38,
+    -637.566_47,
+    -1025.0951,
+    -483.5192,
+];
+const F32_ERF_SB: [f32; 8] = [
+    1.0,
+    30.338_06,
+    325.7925,
+    1536.7296,
+    3199.8582,
+    2553.0503,
+    474.528_53,
+    -22.440_952,
+];
+// `(gamma(s + 1.5) - 1) / ((s + 0.5) * (s - 0.5))` on `s` in `[-0.5, 0.5]`.
+const F32_GAMMA_R: [f32; 11] = [
+    0.455_092_3,
+    -0.129_353_64,
+    0.161_115_41,
+    -0.088_230_72,
+    0.065_877_125,
+    -0.042_946_007,
+    0.029_144_207,
+    -0.018_518_278,
+    0.012_387_521,
+    -0.011_910_531,
+    0.007_945_931_5,
+];
+const F32_LGAMMA_STIRLING: [f32; 4] = [1.0 / 12.0, -1.0 / 360.0, 1.0 / 1260.0, -1.0 / 1680.0];
+
+// DECISION(2026-10-18): KEEP_SIMD_PORTABLE
+// Function(s): f32 erf_u35 / erfc_u35
+// Why kept:
+// - the fdlibm rationals vectorize directly: every range is evaluated and blended, and the tail
+//   reuses the portable exp kernel
+// - NaN, subnormal, and far-tail lanes where the result saturates patch to the libm references
+// Revisit when:
+// - blending three ranges stops paying for itself against lane-wise libm
+
+// DECISION(2026-10-18): KEEP_SIMD_PORTABLE
+// Function(s): f32 tgamma_u35 / lgamma_u35
+// Why kept:
+// - one polynomial on `[1, 2)` plus a recurrence whose trip count follows the widest lane covers
+//   `[-30, 35]`; lgamma switches to Stirling above 8 and rides the portable ln kernel
+// - poles, subnormals, out-of-range lanes, and lgamma lanes next to its negative-axis zeros patch
+//   to the libm references
+// Revisit when:
+// - wide-range inputs become common enough that the recurrence trip count dominates
+
+/// Evaluates `coefficients[0] + coefficients[1] * x + ...` by Horner's rule.
+#[inline(always)]
+fn poly<V>(x: V, coefficients: &[f32]) -> V
+where
+    V: SimdFloat32,
+    V::Engine: Simd<Vf32 = V>,
+{
+    let (last, rest) = coefficients.split_last().unwrap();
+    let mut acc = V::set1(*last);
+    for coefficient in rest.iter().rev() {
+        acc = (acc * x) + V::set1(*coefficient);
+    }
+    acc
+}
+
+/// `erf(x) / x - 1` for `|x| < 0.84375`.
+#[inline(always)]
+fn erf_small_ratio<V>(input: V) -> V
+where
+    V: SimdFloat32,
+    V::Engine: Simd<Vf32 = V>,
+{
+    let input_sq = input * input;
+    poly(input_sq, &F32_ERF_PP) / poly(input_sq, &F32_ERF_QQ)
+}
+
+/// `erf(|x|) - erx` for `|x|` in `[0.84375, 1.25)`.
+#[inline(always)]
+fn erf_medium_ratio<V>(abs_input: V) -> V
+where
+    V: SimdFloat32,
+    V::Engine: Simd<Vf32 = V>,
+{
+    let s = abs_input - V::set1(1.0);
+    poly(s, &F32_ERF_PA) / poly(s, &F32_ERF_QA)
+}
+
+/// `erfc(|x|)` for `|x| >= 1.25`.
+#[inline(always)]
+fn erfc_tail<V>(abs_input: V) -> V
+where
+    V: SimdFloat32,
+    V::Engine: Simd<Vf32 = V>,
+{
+    let inv_sq = V::set1(1.0) / (abs_input * abs_input);
+    let near_mask = abs_input
+        .cmp_lt(V::set1(F32_ERF_TAIL_SPLIT_ABS))
+        .to_vector();
+    let r = near_mask.blendv(poly(inv_sq, &F32_ERF_RB), poly(inv_sq, &F32_ERF_RA));
+    let s = near_mask.blendv(poly(inv_sq, &F32_ERF_SB), poly(inv_sq, &F32_ERF_SA));
+
+    // Dropping the low mantissa bits keeps `z * z` exact, so the large part of the exponent
+    // picks up no rounding and `(z - x) * (z + x)` carries the small remainder.
+    let z = (abs_input.bitcast_i32() & F32_ERF_TAIL_HIGH_MASK).bitcast_f32();
+    let head = exp_fast(V::set1(-0.5625) - z * z);
+    let tail = exp_fast((z - abs_input) * (z + abs_input) + r / s);
+    head * tail / abs_input
+}
+
+#[inline(always)]
+pub(super) fn erf_u35<V>(input: V) -> V
+where
+    V: SimdFloat32,
+    V::Engine: Simd<Vf32 = V>,
+{
+    let abs_input = input.abs();
+    let normal_mask = abs_input
+        .cmp_gte(V::set1(f32::MIN_POSITIVE))
+        .to_vector()
+        .bitcast_i32();
+    let in_range = abs_input
+        .cmp_lt(V::set1(F32_ERF_FAST_ABS_MAX))
+        .to_vector()
+        .bitcast_i32();
+    let fast_mask = normal_mask & in_range;
+    let exceptional_mask = fast_mask.cmp_eq(SimdI32::<V>::zeroes()).to_vector();
+
+    let small = abs_input + abs_input * erf_small_ratio(abs_input);
+    let medium = V::set1(F32_ERX) + erf_medium_ratio(abs_input);
+    let tail = V::set1(1.0) - erfc_tail(abs_input);
+
+    let small_mask = abs_input.cmp_lt(V::set1(F32_ERF_SMALL_ABS)).to_vector();
+    let medium_mask = abs_input.cmp_lt(V::set1(F32_ERF_MEDIUM_ABS)).to_vector();
+    let fast = small_mask
+        .blendv(medium_mask.blendv(tail, medium), small)
+        .copysign(input);
+
+    patch_exceptional_lanes(input, fast, exceptional_mask, scalar::erf_u35_f32)
+}
+
+#[inline(always)]
+pub(super) fn erfc_u35<V>(input: V) -> V
+where
+    V: SimdFloat32,
+    V::Engine: Simd<Vf32 = V>,
+{
+    let abs_input = input.abs();
+    let in_lower_bound = input
+        .cmp_gt(V::set1(F32_ERFC_FAST_MIN))
+        .to_vector()
+        .bitcast_i32();
+    let in_upper_bound = input
+        .cmp_lte(V::set1(F32_ERFC_FAST_MAX))
+        .to_vector()
+        .bitcast_i32();
+    let fast_mask = in_lower_bound & in_upper_bound;
+    let exceptional_mask = fast_mask.cmp_eq(SimdI32::<V>::zeroes()).to_vector();
+
+    let one = V::set1(1.0);
+    let two = V::set1(2.0);
+    let half = V::set1(0.5);
+    let negative_mask = input.cmp_lt(V::zeroes()).to_vector();
+
+    // Above 1/4 the result drops below 3/4, so subtracting from 1/2 keeps the low bits.
+    let y = erf_small_ratio(input);
+    let small = input.cmp_lt(V::set1(0.25)).to_vector().blendv(
+        half - ((input - half) + input * y),
+        one - (input + input * y),
+    );
+
+    let medium_abs = (one - V::set1(F32_ERX)) - erf_medium_ratio(abs_input);
+    let medium = negative_mask.blendv(medium_abs, two - medium_abs);
+
+    let tail_abs = erfc_tail(abs_input);
+    let tail = negative_mask.blendv(tail_abs, two - tail_abs);
+
+    let small_mask = abs_input.cmp_lt(V::set1(F32_ERF_SMALL_ABS)).to_vector();
+    let medium_mask = abs_input.cmp_lt(V::set1(F32_ERF_MEDIUM_ABS)).to_vector();
+    let fast = small_mask.blendv(medium_mask.blendv(tail, medium), small);
+
+    patch_exceptional_lanes(input, fast, exceptional_mask, scalar::erfc_u35_f32)
+}
+
+/// Lanes that are not a pole of gamma, i.e. not zero or a negative integer.
+#[inline(always)]
+fn gamma_non_pole_mask<V>(input: V, floor: V) -> SimdI32<V>
+where
+    V: SimdFloat32,
+    V::Engine: Simd<Vf32 = V>,
+{
+    input.cmp_neq(floor).to_vector().bitcast_i32()
+        | input.cmp_gt(V::zeroes()).to_vector().bitcast_i32()
+}
+
+/// Splits `gamma(x)` into `(1 + uvr) * num / den`.
+///
+/// With `u = x - floor(x)`, `gamma(u + 1) = 1 + u * (u - 1) * R(u - 1/2)`, and the recurrence
+/// `gamma(x + 1) = x * gamma(x)` steps from `u + 1` to `x` through `num` (upwards) or `den`
+/// (downwards). Only `active` lanes set the trip count, so a lane the caller patches anyway
+/// cannot drag the loop along.
+#[inline(always)]
+fn gamma_reduce<V>(input: V, floor: V, active: SimdI32<V>) -> (V, V, V)
+where
+    V: SimdFloat32,
+    V::Engine: Simd<Vf32 = V>,
+{
+    let one = V::set1(1.0);
+    let u = input - floor;
+    let uvr = u * (u - one) * poly(u - V::set1(0.5), &F32_GAMMA_R);
+
+    let steps = floor - one;
+    let neg_steps = V::zeroes() - steps;
+    let trips = active
+        .bitcast_f32()
+        .blendv(V::zeroes(), steps.abs())
+        .horizontal_max();
+
+    let mut num = one;
+    let mut den = one;
+    for step in 1..=(trips as u32) {
+        let step = step as f32;
+        let step_v = V::set1(step);
+        num *= steps
+            .cmp_gte(step_v)
+            .to_vector()
+            .blendv(one, input - step_v);
+        den *= neg_steps
+            .cmp_gte(step_v)
+            .to_vector()
+            .blendv(one, input + V::set1(step - 1.0));
+    }
+
+    (uvr, num, den)
+}
+
+#[inline(always)]
+pub(super) fn tgamma_u35<V>(input: V) -> V
+where
+    V: SimdFloat32,
+    V::Engine: Simd<Vf32 = V>,
+{
+    let floor = input.floor();
+    let normal_mask = input
+        .abs()
+        .cmp_gte(V::set1(f32::MIN_POSITIVE))
+        .to_vector()
+        .bitcast_i32();
+    let in_lower_bound = input
+        .cmp_gte(V::set1(F32_GAMMA_FAST_MIN))
+        .to_vector()
+        .bitcast_i32();
+    let in_upper_bound = input
+        .cmp_lte(V::set1(F32_TGAMMA_FAST_MAX))
+        .to_vector()
+        .bitcast_i32();
+    let fast_mask =
+        normal_mask & in_lower_bound & in_upper_bound & gamma_non_pole_mask(input, floor);
+    let exceptional_mask = fast_mask.cmp_eq(SimdI32::<V>::zeroes()).to_vector();
+
+    let (uvr, num, den) = gamma_reduce(input, floor, fast_mask);
+    let fast = (V::set1(1.0) + uvr) * num / den;
+
+    patch_exceptional_lanes(input, fast, exceptional_mask, scalar::tgamma_u35_f32)
+}
+
+#[inline(always)]
+pub(super) fn lgamma_u35<V>(input: V) -> V
+where
+    V: SimdFloat32,
+    V::Engine: Simd<Vf32 = V>,
+{
+    let floor = input.floor();
+    let normal_mask = input
+        .abs()
+        .cmp_gte(V::set1(f32::MIN_POSITIVE))
+        .to_vector()
+        .bitcast_i32();
+    let in_lower_bound = input
+        .cmp_gte(V::set1(F32_GAMMA_FAST_MIN))
+        .to_vector()
+        .bitcast_i32();
+    let in_upper_bound = input
+        .cmp_lte(V::set1(F32_LGAMMA_FAST_MAX))
+        .to_vector()
+        .bitcast_i32();
+    let fast_mask =
+        normal_mask & in_lower_bound & in_upper_bound & gamma_non_pole_mask(input, floor);
+    let stirling_mask = input.cmp_gte(V::set1(F32_LGAMMA_STIRLING_MIN)).to_vector();
+    let reduce_mask = stirling_mask
+        .bitcast_i32()
+        .cmp_eq(SimdI32::<V>::zeroes())
+        .to_vector();
+
+    // The two logs stay separate: `num / den` can round to 1 while each is far from it, which
+    // would wipe out lgamma next to its zeros at 1 and 2. `ln(1 + uvr)` takes the log1p
+    // correction for the same reason.
+    let one = V::set1(1.0);
+    let (uvr, num, den) = gamma_reduce(input, floor, fast_mask & reduce_mask);
+    let shifted = one + uvr;
+    let ln_gamma_reduced = ln_fast(shifted) + (uvr - (shifted - one)) / shifted;
+    let reduced = ln_gamma_reduced + (ln_fast(num.abs()) - ln_fast(den.abs()));
+
+    let inv = one / input;
+    let series = poly(inv * inv, &F32_LGAMMA_STIRLING) * inv;
+    let stirling =
+        ((input - V::set1(0.5)) * ln_fast(input) - input) + V::set1(F32_HALF_LN_2PI) + series;
+
+    let fast = stirling_mask.blendv(reduced, stirling);
+
+    // On the negative axis lgamma crosses zero between every pair of integers, and the sum of
+    // logs cancels there.
+    let near_negative_zero = input.cmp_lt(V::zeroes()).to_vector().bitcast_i32()
+        & fast.abs().cmp_lt(one).to_vector().bitcast_i32();
+    let exceptional_mask =
+        fast_mask.cmp_eq(SimdI32::<V>::zeroes()).to_vector() | near_negative_zero;
+
+    patch_exceptional_lanes(input, fast, exceptional_mask, scalar::lgamma_u35_f32)
+}
@@ -1,5 +1,5 @@
 use crate::math::{f64, scalar};
-use crate::{Simd, SimdBaseIo, SimdBaseOps, SimdConsts, SimdFloat64, SimdInt64};
+use crate::{Simd, SimdBaseIo, SimdBaseOps, SimdConsts, SimdFloat64, SimdInt64, SimdMask};
 
 // DECISION(2026-03-23): KEEP_SIMD_PORTABLE
 // Function(s): f64 log10_u35
@@ -19,7 +19,6 @@ use crate::{Simd, SimdBaseIo, SimdBaseOps, SimdConsts, SimdFloat64, SimdInt64};
 
 type SimdI64<V> = <<V as SimdConsts>::Engine as Simd>::Vi64;
 
-const F64_SIGN_MASK: i64 = i64::MIN;
 const FAST_FMOD_MAX_QUOTIENT: f64 = 4_503_599_627_370_496.0;
 
 #[inline(always)]
@@ -102,27 +101,19 @@ where
     }
 }
 
-#[inline(always)]
-fn finite_mask<V>(input: V) -> SimdI64<V>
-where
-    V: SimdFloat64,
-    V::Engine: Simd<Vf64 = V>,
-{
-    input.cmp_eq(input).bitcast_i64() & input.abs().cmp_neq(V::set1(f64::INFINITY)).bitcast_i64()
-}
-
 #[inline(always)]
 pub(crate) fn log10_u35<V>(input: V) -> V
 where
     V: SimdFloat64,
     V::Engine: Simd<Vf64 = V>,
 {
-    let positive_finite = input.cmp_gt(V::zeroes()).bitcast_i64() & finite_mask(input);
+    let positive_finite = input.cmp_gt(V::zeroes()).to_vector().bitcast_i64()
+        & input.is_finite().to_vector().bitcast_i64();
     let fast = f64::log2_u35(input) * V::set1(core::f64::consts::LOG10_2);
     patch_unary_exceptional_lanes(
         input,
         fast,
-        positive_finite.cmp_eq(SimdI64::<V>::zeroes()),
+        positive_finite.cmp_eq(SimdI64::<V>::zeroes()).to_vector(),
         scalar::log10_u35_f64,
     )
 }
@@ -136,11 +127,13 @@ where
     let abs_y = y.abs();
     let abs_x = x.abs();
 
-    let x_is_zero = abs_x.cmp_eq(V::zeroes()).bitcast_i64();
-    let fast_mask = finite_mask(x) & finite_mask(y) & x_is_zero.cmp_eq(SimdI64::<V>::zeroes());
-    let exceptional_mask = fast_mask.cmp_eq(SimdI64::<V>::zeroes());
+    let x_is_zero = abs_x.cmp_eq(V::zeroes()).to_vector().bitcast_i64();
+    let fast_mask = x.is_finite().to_vector().bitcast_i64()
+        & y.is_finite().to_vector().bitcast_i64()
+        & x_is_zero.cmp_eq(SimdI64::<V>::zeroes()).to_vector();
+    let exceptional_mask = fast_mask.cmp_eq(SimdI64::<V>::zeroes()).to_vector();
 
-    let large_mask = abs_y.cmp_gt(abs_x).bitcast_i64();
+    let large_mask = abs_y.cmp_gt(abs_x).to_vector().bitcast_i64();
     let reduced_base = large_mask
         .bitcast_f64()
         .blendv(abs_y / abs_x, abs_x / abs_y);
@@ -149,12 +142,11 @@ where
 
     let pi_over_2 = V::set1(core::f64::consts::FRAC_PI_2);
     let pi = V::set1(core::f64::consts::PI);
-    let y_sign = y.bitcast_i64() & SimdI64::<V>::set1(F64_SIGN_MASK);
-    let x_negative = x.cmp_lt(V::zeroes()).bitcast_i64();
+    let x_negative = x.cmp_lt(V::zeroes()).to_vector().bitcast_i64();
 
     let base = large_mask.bitcast_f64().blendv(z_atan, pi_over_2 - z_atan);
     let with_x_quadrant = x_negative.bitcast_f64().blendv(base, pi - base);
-    let fast = (y_sign ^ with_x_quadrant.bitcast_i64()).bitcast_f64();
+    let fast = with_x_quadrant.copysign(y);
 
     patch_binary_exceptional_lanes(y, x, fast, exceptional_mask, scalar::atan2_u35_f64)
 }
@@ -167,12 +159,14 @@ where
 {
     let abs_x = x.abs();
     let abs_y = y.abs();
-    let x_gt_y = abs_x.cmp_gt(abs_y);
+    let x_gt_y = abs_x.cmp_gt(abs_y).to_vector();
     let max_xy = x_gt_y.blendv(abs_y, abs_x);
     let min_xy = x_gt_y.blendv(abs_x, abs_y);
 
-    let fast_mask = finite_mask(x) & finite_mask(y) & max_xy.cmp_neq(V::zeroes()).bitcast_i64();
-    let exceptional_mask = fast_mask.cmp_eq(SimdI64::<V>::zeroes());
+    let fast_mask = x.is_finite().to_vector().bitcast_i64()
+        & y.is_finite().to_vector().bitcast_i64()
+        & max_xy.cmp_neq(V::zeroes()).to_vector().bitcast_i64();
+    let exceptional_mask = fast_mask.cmp_eq(SimdI64::<V>::zeroes()).to_vector();
 
     let ratio = min_xy / max_xy;
     let fast = max_xy * (V::set1(1.0) + ratio * ratio).sqrt();
@@ -187,23 +181,21 @@ where
     V::Engine: Simd<Vf64 = V>,
 {
     let quotient = x / y;
-    let trunc = quotient
-        .cmp_lt(V::zeroes())
-        .blendv(quotient.floor(), quotient.ceil());
-    let fast = x - trunc * y;
-    let zero_mask = fast.cmp_eq(V::zeroes()).bitcast_i64();
-    let signed_zero = (x.bitcast_i64() & SimdI64::<V>::set1(F64_SIGN_MASK)).bitcast_f64();
+    let fast = x - quotient.trunc() * y;
+    let zero_mask = fast.cmp_eq(V::zeroes()).to_vector().bitcast_i64();
+    let signed_zero = V::zeroes().copysign(x);
     let fast = zero_mask.bitcast_f64().blendv(fast, signed_zero);
 
-    let finite_inputs = finite_mask(x) & finite_mask(y);
-    let y_nonzero = y.cmp_neq(V::zeroes()).bitcast_i64();
-    let x_not_inf = x.abs().cmp_neq(V::set1(f64::INFINITY)).bitcast_i64();
+    let finite_inputs =
+        x.is_finite().to_vector().bitcast_i64() & y.is_finite().to_vector().bitcast_i64();
+    let y_nonzero = y.cmp_neq(V::zeroes()).to_vector().bitcast_i64();
     let small_quotient = quotient
         .abs()
         .cmp_lt(V::set1(FAST_FMOD_MAX_QUOTIENT))
+        .to_vector()
         .bitcast_i64();
-    let fast_mask = finite_inputs & y_nonzero & x_not_inf & small_quotient;
-    let exceptional_mask = fast_mask.cmp_eq(SimdI64::<V>::zeroes());
+    let fast_mask = finite_inputs & y_nonzero & small_quotient;
+    let exceptional_mask = fast_mask.cmp_eq(SimdI64::<V>::zeroes()).to_vector();
 
     patch_binary_exceptional_lanes(x, y, fast, exceptional_mask, scalar::fmod_f64)
 }
@@ -1,7 +1,7 @@
-use crate::math::{map, scalar};
-use crate::{Simd, SimdBaseIo, SimdBaseOps, SimdConsts, SimdFloat64, SimdInt, SimdInt64};
+use crate::math::scalar;
+use crate::{Simd, SimdBaseIo, SimdBaseOps, SimdConsts, SimdFloat64, SimdInt, SimdInt64, SimdMask};
 
-type SimdI64<V> = <<V as SimdConsts>::Engine as Simd>::Vi64;
+pub(super) type SimdI64<V> = <<V as SimdConsts>::Engine as Simd>::Vi64;
 
 const F64_EXPONENT_MASK: i64 = 0x7FF0_0000_0000_0000u64 as i64;
 const F64_MANTISSA_MASK: i64 = 0x000F_FFFF_FFFF_FFFFu64 as i64;
@@ -9,6 +9,20 @@ const F64_LOG_NORM_MANTISSA: i64 = 0x3FE0_0000_0000_0000u64 as i64;
 const F64_EXPONENT_BIAS_ADJUST: i64 = 1022;
 const F64_EXP_LN2_HI: f64 = 6.931_471_803_691_238e-1;
 const F64_EXP_LN2_LO: f64 = 1.908_214_929_270_587_7e-10;
+// pi/2 split into three 33 bit chunks and a tail, so `n * chunk` is exact for `n < 2^20`.
+const F64_PIO2_1: f64 = 1.570_796_326_734_125_6;
+const F64_PIO2_2: f64 = 6.077_100_506_303_966e-11;
+const F64_PIO2_3: f64 = 2.022_266_248_711_166_5e-21;
+const F64_PIO2_3T: f64 = 8.478_427_660_368_9e-32;
+// log10(2) split so `n * F64_LOG10_2_HI` stays exact for every in-range `n`.
+const F64_LOG10_2_HI: f64 = 0.301_029_995_549_470_2;
+const F64_LOG10_2_LO: f64 = 1.145_110_089_802_183_8e-10;
+const F64_EXP10_FAST_ABS_MAX: f64 = 307.0;
+const F64_POW_FAST_ABS_MAX: f64 = 16.0;
+const F64_POWI_MULTIPLY_MAX: u32 = 16;
+const F64_TRIG_FAST_RANGE: f64 = 1_048_576.0;
+// Adding 1.5 * 2^52 rounds to an integer that lands in the low mantissa bits.
+const F64_ROUND_SHIFTER: f64 = 6_755_399_441_055_744.0;
 // DECISION(2026-03-23): KEEP_SIMD_PORTABLE
 // Function(s): f64 log2_u35 / exp2_u35 / ln_u35 / exp_u35
 // Why kept:
@@ -17,17 +31,40 @@ const F64_EXP_LN2_LO: f64 = 1.908_214_929_270_587_7e-10;
 // Revisit when:
 // - non-x86 evidence disagrees sharply or a cheaper approximation family appears
 
-// DECISION(2026-03-23): KEEP_SCALAR_REFERENCE
+// DECISION(2026-10-18): KEEP_SIMD_PORTABLE
+// Function(s): f64 pow_u35 / powi / cbrt_u35 / exp10_u35 / expm1_u35 / log1p_u35
+// Why kept:
+// - `simd_math/f64/*` benches against native scalar, runtime-selected (AVX-512) / forced AVX2 /
+//   forced SSE2: pow 2.86x / 1.04x / 0.59x, powi 1.70x / 1.31x / 0.91x,
+//   cbrt 3.32x / 1.51x / 0.84x, exp10 10.4x / 3.82x / 1.50x, expm1 6.37x / 2.54x / 1.12x,
+//   log1p 7.38x / 4.51x / 2.55x
+// - each kernel reuses the ln/exp reductions above, matching the f32 layout
+// - pow keeps its special-case table and large `|y * ln x|` lanes on the scalar reference
+// Revisit when:
+// - a double-double log lets pow widen its fast range
+// - SSE2-only hosts matter: pow, powi and cbrt still trail native scalar there
+
+// DECISION(2026-10-18): KEEP_SIMD_PORTABLE
 // Function(s): f64 sin_u35 / cos_u35 / tan_u35
-// Why scalar:
-// - the final retry of the old portable trig kernel failed the u35 contract around pi boundaries,
-//   tan-pole neighborhoods, and moderate finite lanes before it could justify a speed keep
-// - the refreshed scalar-reference recheck still leaves runtime-selected throughput behind native
-//   scalar on this host (`sin`: about 17.03 ms vs 15.97 ms, `cos`: about 16.58 ms vs 15.75 ms,
-//   `tan`: about 20.85 ms vs 20.19 ms)
-// - native scalar still remains the honest default while family ownership stays localized here
+// Why kept:
+// - `simd_math/f64/*` benches against native scalar, runtime-selected (AVX-512) / forced AVX2 /
+//   forced SSE2: sin 11.5x / 6.61x / 3.53x, cos 10.8x / 6.71x / 3.65x, tan 12.2x / 7.38x / 4.32x
+// - the Cody-Waite reduction below is exact for |x| <= 2^20, which fixes the pi-boundary and
+//   tan-pole misses that sank the old portable kernel
+// - larger and non-finite lanes patch back to the scalar references, which use Payne-Hanek
 // Revisit when:
-// - a stronger range-reduction strategy or cheaper trig kernel appears
+// - huge-argument lanes show up often enough to want a vector Payne-Hanek path
+
+// DECISION(2026-10-18): KEEP_SIMD_PORTABLE
+// Function(s): f64 sincos_u35 / sinpi_u35 / cospi_u35 / tanpi_u35
+// Why kept:
+// - `simd_math/f64/*` benches against native scalar (`sin_cos`, and `sin`/`cos`/`tan` of
+//   `x * PI`), runtime-selected (AVX-512) / forced AVX2 / forced SSE2: sincos 15.1x / 8.14x /
+//   4.46x, sinpi 10.7x / 3.76x / 1.58x, cospi 9.51x / 3.77x / 1.47x, tanpi 8.84x / 4.36x / 1.86x
+// - same layout as f32: one shared reduction for sincos, exact half-turn reduction for the
+//   pi-scaled variants, which leaves only non-finite lanes to patch
+// Revisit when:
+// - the shared sin/cos polynomials change
 
 #[inline(always)]
 fn any_lane_nonzero<V>(mask: SimdI64<V>) -> bool
@@ -48,7 +85,7 @@ where
 }
 
 #[inline(always)]
-fn patch_exceptional_lanes<V>(
+pub(super) fn patch_exceptional_lanes<V>(
     input: V,
     output: V,
     exceptional_mask: SimdI64<V>,
@@ -88,10 +125,14 @@ where
 
     let non_positive = input
         .cmp_gt(V::zeroes())
+        .to_vector()
         .bitcast_i64()
-        .cmp_eq(SimdI64::<V>::zeroes());
-    let subnormal_or_zero = exponent_bits.cmp_eq(SimdI64::<V>::zeroes());
-    let inf_or_nan = exponent_bits.cmp_eq(SimdI64::<V>::set1(F64_EXPONENT_MASK));
+        .cmp_eq(SimdI64::<V>::zeroes())
+        .to_vector();
+    let subnormal_or_zero = exponent_bits.cmp_eq(SimdI64::<V>::zeroes()).to_vector();
+    let inf_or_nan = exponent_bits
+        .cmp_eq(SimdI64::<V>::set1(F64_EXPONENT_MASK))
+        .to_vector();
 
     non_positive | subnormal_or_zero | inf_or_nan
 }
@@ -115,7 +156,7 @@ where
     let one = V::set1(1.0);
     let sqrt_half = V::set1(core::f64::consts::FRAC_1_SQRT_2);
 
-    let adjust_mask = normalized_mantissa.cmp_lt(sqrt_half);
+    let adjust_mask = normalized_mantissa.cmp_lt(sqrt_half).to_vector();
     let exponent = exponent - adjust_mask.blendv(V::zeroes(), one);
     let mantissa = adjust_mask.blendv(
         normalized_mantissa,
@@ -147,11 +188,11 @@ where
     V: SimdFloat64,
     V::Engine: Simd<Vf64 = V>,
 {
-    let finite_mask = input.cmp_eq(input).bitcast_i64();
-    let in_lower_bound = input.cmp_gte(V::set1(-1022.0)).bitcast_i64();
-    let in_upper_bound = input.cmp_lte(V::set1(1023.0)).bitcast_i64();
+    let finite_mask = input.is_finite().to_vector().bitcast_i64();
+    let in_lower_bound = input.cmp_gte(V::set1(-1022.0)).to_vector().bitcast_i64();
+    let in_upper_bound = input.cmp_lte(V::set1(1023.0)).to_vector().bitcast_i64();
     let fast_mask = finite_mask & in_lower_bound & in_upper_bound;
-    let exceptional_mask = fast_mask.cmp_eq(SimdI64::<V>::zeroes());
+    let exceptional_mask = fast_mask.cmp_eq(SimdI64::<V>::zeroes()).to_vector();
 
     let integral = input.round().cast_i64();
     let fractional = input - integral.cast_f64();
@@ -177,8 +218,9 @@ where
     patch_exceptional_lanes(input, fast, exceptional_mask, scalar::exp2_u35_f64)
 }
 
+/// Natural log for positive normal finite lanes; callers patch everything else.
 #[inline(always)]
-pub(crate) fn ln_u35<V>(input: V) -> V
+pub(super) fn ln_fast<V>(input: V) -> V
 where
     V: SimdFloat64,
     V::Engine: Simd<Vf64 = V>,
@@ -187,8 +229,6 @@ where
     let exponent_bits = bits & F64_EXPONENT_MASK;
     let mantissa_bits = bits & F64_MANTISSA_MASK;
 
-    let exceptional_mask = log2_exceptional_mask(input);
-
     let exponent =
         (exponent_bits.shr(52) - SimdI64::<V>::set1(F64_EXPONENT_BIAS_ADJUST)).cast_f64();
     let normalized_mantissa = (mantissa_bits | F64_LOG_NORM_MANTISSA).bitcast_f64();
@@ -196,7 +236,7 @@ where
     let one = V::set1(1.0);
     let sqrt_half = V::set1(core::f64::consts::FRAC_1_SQRT_2);
 
-    let adjust_mask = normalized_mantissa.cmp_lt(sqrt_half);
+    let adjust_mask = normalized_mantissa.cmp_lt(sqrt_half).to_vector();
     let exponent = exponent - adjust_mask.blendv(V::zeroes(), one);
     let mantissa = adjust_mask.blendv(
         normalized_mantissa,
@@ -217,29 +257,28 @@ where
     poly = (poly * t2) + V::set1(1.0 / 3.0);
 
     let ln_mantissa = V::set1(2.0) * t * ((poly * t2) + one);
-    let fast = exponent * V::set1(core::f64::consts::LN_2) + ln_mantissa;
-
-    patch_exceptional_lanes(input, fast, exceptional_mask, scalar::ln_u35_f64)
+    exponent * V::set1(core::f64::consts::LN_2) + ln_mantissa
 }
 
 #[inline(always)]
-pub(crate) fn exp_u35<V>(input: V) -> V
+pub(crate) fn ln_u35<V>(input: V) -> V
 where
     V: SimdFloat64,
     V::Engine: Simd<Vf64 = V>,
 {
-    let finite_mask = input.cmp_eq(input).bitcast_i64();
-    let in_lower_bound = input.cmp_gte(V::set1(-708.0)).bitcast_i64();
-    let in_upper_bound = input.cmp_lte(V::set1(709.0)).bitcast_i64();
-    let fast_mask = finite_mask & in_lower_bound & in_upper_bound;
-    let exceptional_mask = fast_mask.cmp_eq(SimdI64::<V>::zeroes());
+    let exceptional_mask = log2_exceptional_mask(input);
+    let fast = ln_fast(input);
 
-    let scaled = input * V::set1(core::f64::consts::LOG2_E);
-    let integral = scaled.round().cast_i64();
-    let integral_f = integral.cast_f64();
-    let reduced =
-        (input - integral_f * V::set1(F64_EXP_LN2_HI)) - integral_f * V::set1(F64_EXP_LN2_LO);
+    patch_exceptional_lanes(input, fast, exceptional_mask, scalar::ln_u35_f64)
+}
 
+/// `exp(reduced) - 1` for `|reduced|` up to about `ln 2 / 2`.
+#[inline(always)]
+fn expm1_reduced_poly<V>(reduced: V) -> V
+where
+    V: SimdFloat64,
+    V::Engine: Simd<Vf64 = V>,
+{
     let mut poly = V::set1(1.0 / 479_001_600.0);
     poly = (poly * reduced) + V::set1(1.0 / 39_916_800.0);
     poly = (poly * reduced) + V::set1(1.0 / 3_628_800.0);
@@ -252,34 +291,467 @@ where
     poly = (poly * reduced) + V::set1(1.0 / 6.0);
     poly = (poly * reduced) + V::set1(0.5);
 
-    let exp_reduced = (poly * reduced * reduced) + reduced + V::set1(1.0);
-    let scale_bits = (integral + SimdI64::<V>::set1(1023)).shl(52);
-    let scale = scale_bits.bitcast_f64();
-    let fast = exp_reduced * scale;
+    (poly * reduced * reduced) + reduced
+}
+
+/// Splits `input` into `integral * ln 2 + reduced` with `|reduced| <= ln 2 / 2`.
+#[inline(always)]
+fn exp_reduce<V>(input: V) -> (SimdI64<V>, V)
+where
+    V: SimdFloat64,
+    V::Engine: Simd<Vf64 = V>,
+{
+    let scaled = input * V::set1(core::f64::consts::LOG2_E);
+    let integral = scaled.round().cast_i64();
+    let integral_f = integral.cast_f64();
+    let reduced =
+        (input - integral_f * V::set1(F64_EXP_LN2_HI)) - integral_f * V::set1(F64_EXP_LN2_LO);
+    (integral, reduced)
+}
+
+/// `2^integral` for integrals that keep the result a normal float.
+#[inline(always)]
+fn exp2_scale<V>(integral: SimdI64<V>) -> V
+where
+    V: SimdFloat64,
+    V::Engine: Simd<Vf64 = V>,
+{
+    (integral + SimdI64::<V>::set1(1023)).shl(52).bitcast_f64()
+}
+
+/// `exp(input)` for finite lanes in `[-708, 709]`; callers patch everything else.
+#[inline(always)]
+pub(super) fn exp_fast<V>(input: V) -> V
+where
+    V: SimdFloat64,
+    V::Engine: Simd<Vf64 = V>,
+{
+    let (integral, reduced) = exp_reduce(input);
+    (expm1_reduced_poly(reduced) + V::set1(1.0)) * exp2_scale::<V>(integral)
+}
+
+#[inline(always)]
+pub(crate) fn exp_u35<V>(input: V) -> V
+where
+    V: SimdFloat64,
+    V::Engine: Simd<Vf64 = V>,
+{
+    let finite_mask = input.is_finite().to_vector().bitcast_i64();
+    let in_lower_bound = input.cmp_gte(V::set1(-708.0)).to_vector().bitcast_i64();
+    let in_upper_bound = input.cmp_lte(V::set1(709.0)).to_vector().bitcast_i64();
+    let fast_mask = finite_mask & in_lower_bound & in_upper_bound;
+    let exceptional_mask = fast_mask.cmp_eq(SimdI64::<V>::zeroes()).to_vector();
+
+    let fast = exp_fast(input);
 
     patch_exceptional_lanes(input, fast, exceptional_mask, scalar::exp_u35_f64)
 }
 
+#[inline(always)]
+fn patch_binary_exceptional_lanes<V>(
+    lhs: V,
+    rhs: V,
+    output: V,
+    exceptional_mask: SimdI64<V>,
+    scalar_fallback: fn(f64, f64) -> f64,
+) -> V
+where
+    V: SimdFloat64,
+    V::Engine: Simd<Vf64 = V>,
+{
+    if !any_lane_nonzero::<V>(exceptional_mask) {
+        return output;
+    }
+
+    unsafe {
+        let lhs_lanes = lhs.as_array();
+        let rhs_lanes = rhs.as_array();
+        let mask_lanes = exceptional_mask.as_array();
+        let mut output_lanes = output.as_array();
+
+        for lane in 0..V::WIDTH {
+            if mask_lanes[lane] != 0 {
+                output_lanes[lane] = scalar_fallback(lhs_lanes[lane], rhs_lanes[lane]);
+            }
+        }
+
+        V::load_from_ptr_unaligned(&output_lanes as *const V::ArrayRepresentation as *const f64)
+    }
+}
+
+/// Negates the lanes of `value` whose `sign_source` lane has its sign bit set.
+#[inline(always)]
+fn xor_sign_bit<V>(value: V, sign_source: V) -> V
+where
+    V: SimdFloat64,
+    V::Engine: Simd<Vf64 = V>,
+{
+    let sign = sign_source.bitcast_i64() & SimdI64::<V>::set1(i64::MIN);
+    (value.bitcast_i64() ^ sign).bitcast_f64()
+}
+
+#[inline(always)]
+pub(crate) fn pow_u35<V>(base: V, exponent: V) -> V
+where
+    V: SimdFloat64,
+    V::Engine: Simd<Vf64 = V>,
+{
+    // Same shape as the f32 kernel: the fast path only covers lanes where `y * ln x` stays small
+    // enough that its absolute error fits the u35 budget after exponentiation.
+    let product = exponent * ln_fast(base);
+    let exponent_finite = exponent.is_finite().to_vector().bitcast_i64();
+    let within_fast_range = product
+        .abs()
+        .cmp_lte(V::set1(F64_POW_FAST_ABS_MAX))
+        .to_vector()
+        .bitcast_i64();
+    let fast_mask = exponent_finite & within_fast_range;
+    let exceptional_mask =
+        log2_exceptional_mask(base) | fast_mask.cmp_eq(SimdI64::<V>::zeroes()).to_vector();
+
+    let fast = exp_fast(product);
+
+    patch_binary_exceptional_lanes(base, exponent, fast, exceptional_mask, scalar::pow_u35_f64)
+}
+
+#[inline(always)]
+pub(crate) fn powi<V>(base: V, exponent: i32) -> V
+where
+    V: SimdFloat64,
+    V::Engine: Simd<Vf64 = V>,
+{
+    let abs_base = base.abs();
+    let mut remaining = exponent.unsigned_abs();
+
+    let magnitude = if remaining <= F64_POWI_MULTIPLY_MAX {
+        let mut power = abs_base;
+        let mut product = V::set1(1.0);
+        while remaining != 0 {
+            if remaining & 1 != 0 {
+                product *= power;
+            }
+            remaining >>= 1;
+            if remaining != 0 {
+                power *= power;
+            }
+        }
+
+        if exponent < 0 {
+            let exceptional_mask = log2_exceptional_mask(product)
+                & product.cmp_neq(V::zeroes()).to_vector().bitcast_i64();
+            let fast = V::set1(1.0) / product;
+            if any_lane_nonzero::<V>(exceptional_mask) {
+                unsafe {
+                    let input_lanes = abs_base.as_array();
+                    let mask_lanes = exceptional_mask.as_array();
+                    let mut output_lanes = fast.as_array();
+
+                    for lane in 0..V::WIDTH {
+                        if mask_lanes[lane] != 0 {
+                            output_lanes[lane] = scalar::powi_f64(input_lanes[lane], exponent);
+                        }
+                    }
+
+                    V::load_from_ptr_unaligned(
+                        &output_lanes as *const V::ArrayRepresentation as *const f64,
+                    )
+                }
+            } else {
+                fast
+            }
+        } else {
+            product
+        }
+    } else {
+        pow_u35(abs_base, V::set1(exponent as f64))
+    };
+
+    if exponent & 1 != 0 {
+        magnitude.copysign(base)
+    } else {
+        magnitude
+    }
+}
+
+#[inline(always)]
+pub(crate) fn cbrt_u35<V>(input: V) -> V
+where
+    V: SimdFloat64,
+    V::Engine: Simd<Vf64 = V>,
+{
+    let abs_input = input.abs();
+    let exceptional_mask = log2_exceptional_mask(abs_input);
+
+    let estimate = exp_fast(ln_fast(abs_input) * V::set1(1.0 / 3.0));
+    let refined = estimate - (estimate - abs_input / (estimate * estimate)) * V::set1(1.0 / 3.0);
+    let fast = refined.copysign(input);
+
+    patch_exceptional_lanes(input, fast, exceptional_mask, scalar::cbrt_u35_f64)
+}
+
+#[inline(always)]
+pub(crate) fn exp10_u35<V>(input: V) -> V
+where
+    V: SimdFloat64,
+    V::Engine: Simd<Vf64 = V>,
+{
+    let finite_mask = input.is_finite().to_vector().bitcast_i64();
+    let in_lower_bound = input
+        .cmp_gte(V::set1(-F64_EXP10_FAST_ABS_MAX))
+        .to_vector()
+        .bitcast_i64();
+    let in_upper_bound = input
+        .cmp_lte(V::set1(F64_EXP10_FAST_ABS_MAX))
+        .to_vector()
+        .bitcast_i64();
+    let fast_mask = finite_mask & in_lower_bound & in_upper_bound;
+    let exceptional_mask = fast_mask.cmp_eq(SimdI64::<V>::zeroes()).to_vector();
+
+    let integral = (input * V::set1(core::f64::consts::LOG2_10))
+        .round()
+        .cast_i64();
+    let integral_f = integral.cast_f64();
+    let reduced =
+        (input - integral_f * V::set1(F64_LOG10_2_HI)) - integral_f * V::set1(F64_LOG10_2_LO);
+    let reduced = reduced * V::set1(core::f64::consts::LN_10);
+
+    let fast = (expm1_reduced_poly(reduced) + V::set1(1.0)) * exp2_scale::<V>(integral);
+
+    patch_exceptional_lanes(input, fast, exceptional_mask, scalar::exp10_u35_f64)
+}
+
+#[inline(always)]
+pub(crate) fn expm1_u35<V>(input: V) -> V
+where
+    V: SimdFloat64,
+    V::Engine: Simd<Vf64 = V>,
+{
+    let finite_mask = input.is_finite().to_vector().bitcast_i64();
+    let in_lower_bound = input.cmp_gte(V::set1(-708.0)).to_vector().bitcast_i64();
+    let in_upper_bound = input.cmp_lte(V::set1(709.0)).to_vector().bitcast_i64();
+    let non_zero = input.cmp_neq(V::zeroes()).to_vector().bitcast_i64();
+    let fast_mask = finite_mask & in_lower_bound & in_upper_bound & non_zero;
+    let exceptional_mask = fast_mask.cmp_eq(SimdI64::<V>::zeroes()).to_vector();
+
+    let (integral, reduced) = exp_reduce(input);
+    let scale = exp2_scale::<V>(integral);
+    let fast = (scale * expm1_reduced_poly(reduced)) + (scale - V::set1(1.0));
+
+    patch_exceptional_lanes(input, fast, exceptional_mask, scalar::expm1_u35_f64)
+}
+
+#[inline(always)]
+pub(crate) fn log1p_u35<V>(input: V) -> V
+where
+    V: SimdFloat64,
+    V::Engine: Simd<Vf64 = V>,
+{
+    let finite_mask = input.is_finite().to_vector().bitcast_i64();
+    let above_minus_one = input.cmp_gt(V::set1(-1.0)).to_vector().bitcast_i64();
+    let non_zero = input.cmp_neq(V::zeroes()).to_vector().bitcast_i64();
+    let fast_mask = finite_mask & above_minus_one & non_zero;
+    let exceptional_mask = fast_mask.cmp_eq(SimdI64::<V>::zeroes()).to_vector();
+
+    let one = V::set1(1.0);
+    let shifted = one + input;
+    let correction = (input - (shifted - one)) / shifted;
+    let fast = ln_fast(shifted) + correction;
+
+    patch_exceptional_lanes(input, fast, exceptional_mask, scalar::log1p_u35_f64)
+}
+
+#[inline(always)]
+fn trig_exceptional_mask<V>(input: V) -> SimdI64<V>
+where
+    V: SimdFloat64,
+    V::Engine: Simd<Vf64 = V>,
+{
+    let finite_mask = input.is_finite().to_vector().bitcast_i64();
+    let within_fast_range = input
+        .abs()
+        .cmp_lte(V::set1(F64_TRIG_FAST_RANGE))
+        .to_vector()
+        .bitcast_i64();
+    let non_zero = input.cmp_neq(V::zeroes()).to_vector().bitcast_i64();
+    let fast_mask = finite_mask & within_fast_range & non_zero;
+    fast_mask.cmp_eq(SimdI64::<V>::zeroes()).to_vector()
+}
+
+#[inline(always)]
+fn sin_cos_fast<V>(input: V) -> (V, V)
+where
+    V: SimdFloat64,
+    V::Engine: Simd<Vf64 = V>,
+{
+    let shifted = input * V::set1(core::f64::consts::FRAC_2_PI) + V::set1(F64_ROUND_SHIFTER);
+    let n = shifted.bitcast_i64();
+    let n_f = shifted - V::set1(F64_ROUND_SHIFTER);
+
+    // Each step cancels exactly against the next chunk, so only the tail product rounds.
+    let r = input - n_f * V::set1(F64_PIO2_1);
+    let r = r - n_f * V::set1(F64_PIO2_2);
+    let r = r - n_f * V::set1(F64_PIO2_3);
+    let r = r - n_f * V::set1(F64_PIO2_3T);
+    sin_cos_quadrant(n, r)
+}
+
+/// `(sin, cos)` of `n * pi / 2 + r` for `|r| <= pi / 4`; only the low two bits of `n` matter.
+#[inline(always)]
+fn sin_cos_quadrant<V>(n: SimdI64<V>, r: V) -> (V, V)
+where
+    V: SimdFloat64,
+    V::Engine: Simd<Vf64 = V>,
+{
+    let r2 = r * r;
+
+    let mut sin_poly = V::set1(1.589_690_995_211_55e-10);
+    sin_poly = (sin_poly * r2) + V::set1(-2.505_076_025_340_686_3e-8);
+    sin_poly = (sin_poly * r2) + V::set1(2.755_731_370_707_006_8e-6);
+    sin_poly = (sin_poly * r2) + V::set1(-1.984_126_982_985_795e-4);
+    sin_poly = (sin_poly * r2) + V::set1(8.333_333_333_322_49e-3);
+    sin_poly = (sin_poly * r2) + V::set1(-1.666_666_666_666_632_4e-1);
+    let sin_r = ((sin_poly * r2) * r) + r;
+
+    let mut cos_poly = V::set1(-1.135_964_755_778_819_5e-11);
+    cos_poly = (cos_poly * r2) + V::set1(2.087_572_321_298_175e-9);
+    cos_poly = (cos_poly * r2) + V::set1(-2.755_731_435_139_066_3e-7);
+    cos_poly = (cos_poly * r2) + V::set1(2.480_158_728_947_673e-5);
+    cos_poly = (cos_poly * r2) + V::set1(-1.388_888_888_887_411e-3);
+    cos_poly = (cos_poly * r2) + V::set1(4.166_666_666_666_66e-2);
+    let cos_r = (cos_poly * r2 * r2) + (V::set1(1.0) - V::set1(0.5) * r2);
+
+    // Odd quadrants swap sin and cos, and bit 1 of `n` (of `n + 1` for cos) flips the sign.
+    let odd = (n & SimdI64::<V>::set1(1))
+        .cmp_eq(SimdI64::<V>::set1(1))
+        .to_vector()
+        .bitcast_f64();
+    let sin_sign = (n & SimdI64::<V>::set1(2)).shl(62).bitcast_f64();
+    let cos_sign = ((n + SimdI64::<V>::set1(1)) & SimdI64::<V>::set1(2))
+        .shl(62)
+        .bitcast_f64();
+
+    let sin_out = odd.blendv(sin_r, cos_r) ^ sin_sign;
+    let cos_out = odd.blendv(cos_r, sin_r) ^ cos_sign;
+
+    (sin_out, cos_out)
+}
+
 #[inline(always)]
 pub(crate) fn sin_u35<V>(input: V) -> V
 where
     V: SimdFloat64,
+    V::Engine: Simd<Vf64 = V>,
 {
-    map::unary_f64(input, scalar::sin_u35_f64)
+    let exceptional_mask = trig_exceptional_mask(input);
+    let (sin_fast, _) = sin_cos_fast(input);
+    patch_exceptional_lanes(input, sin_fast, exceptional_mask, scalar::sin_u35_f64)
 }
 
 #[inline(always)]
 pub(crate) fn cos_u35<V>(input: V) -> V
 where
     V: SimdFloat64,
+    V::Engine: Simd<Vf64 = V>,
 {
-    map::unary_f64(input, scalar::cos_u35_f64)
+    let exceptional_mask = trig_exceptional_mask(input);
+    let (_, cos_fast) = sin_cos_fast(input);
+    patch_exceptional_lanes(input, cos_fast, exceptional_mask, scalar::cos_u35_f64)
 }
 
 #[inline(always)]
 pub(crate) fn tan_u35<V>(input: V) -> V
 where
     V: SimdFloat64,
+    V::Engine: Simd<Vf64 = V>,
+{
+    // Near a pole the small `cos` comes from `sin_r` of the exactly reduced argument, so the
+    // quotient keeps full relative accuracy without a pole patch.
+    let exceptional_mask = trig_exceptional_mask(input);
+    let (sin_fast, cos_fast) = sin_cos_fast(input);
+    patch_exceptional_lanes(
+        input,
+        sin_fast / cos_fast,
+        exceptional_mask,
+        scalar::tan_u35_f64,
+    )
+}
+
+#[inline(always)]
+pub(crate) fn sincos_u35<V>(input: V) -> (V, V)
+where
+    V: SimdFloat64,
+    V::Engine: Simd<Vf64 = V>,
+{
+    let exceptional_mask = trig_exceptional_mask(input);
+    let (sin_fast, cos_fast) = sin_cos_fast(input);
+    (
+        patch_exceptional_lanes(input, sin_fast, exceptional_mask, scalar::sin_u35_f64),
+        patch_exceptional_lanes(input, cos_fast, exceptional_mask, scalar::cos_u35_f64),
+    )
+}
+
+#[inline(always)]
+fn non_finite_mask<V>(input: V) -> SimdI64<V>
+where
+    V: SimdFloat64,
+    V::Engine: Simd<Vf64 = V>,
+{
+    input
+        .is_finite()
+        .to_vector()
+        .bitcast_i64()
+        .cmp_eq(SimdI64::<V>::zeroes())
+        .to_vector()
+}
+
+/// `(sin(pi * |x|), cos(pi * |x|))` with exact, positive zeros at integers and half-integers.
+#[inline(always)]
+fn sin_cos_pi_abs<V>(input: V) -> (V, V)
+where
+    V: SimdFloat64,
+    V::Engine: Simd<Vf64 = V>,
+{
+    let abs_input = input.abs();
+    let reduced = abs_input - (abs_input * V::set1(0.5)).floor() * V::set1(2.0);
+    let half_turns = (reduced * V::set1(2.0)).round();
+    let r = reduced - half_turns * V::set1(0.5);
+
+    let (sin_out, cos_out) =
+        sin_cos_quadrant(half_turns.cast_i64(), r * V::set1(core::f64::consts::PI));
+    (sin_out + V::zeroes(), cos_out + V::zeroes())
+}
+
+#[inline(always)]
+pub(crate) fn sinpi_u35<V>(input: V) -> V
+where
+    V: SimdFloat64,
+    V::Engine: Simd<Vf64 = V>,
+{
+    let exceptional_mask = non_finite_mask(input);
+    let (sin_abs, _) = sin_cos_pi_abs(input);
+    let fast = xor_sign_bit(sin_abs, input);
+    patch_exceptional_lanes(input, fast, exceptional_mask, scalar::sinpi_u35_f64)
+}
+
+#[inline(always)]
+pub(crate) fn cospi_u35<V>(input: V) -> V
+where
+    V: SimdFloat64,
+    V::Engine: Simd<Vf64 = V>,
+{
+    let exceptional_mask = non_finite_mask(input);
+    let (_, fast) = sin_cos_pi_abs(input);
+    patch_exceptional_lanes(input, fast, exceptional_mask, scalar::cospi_u35_f64)
+}
+
+#[inline(always)]
+pub(crate) fn tanpi_u35<V>(input: V) -> V
+where
+    V: SimdFloat64,
+    V::Engine: Simd<Vf64 = V>,
 {
-    map::unary_f64(input, scalar::tan_u35_f64)
+    let exceptional_mask = non_finite_mask(input);
+    let (sin_abs, cos_abs) = sin_cos_pi_abs(input);
+    let fast = xor_sign_bit(sin_abs / cos_abs, input);
+    patch_exceptional_lanes(input, fast, exceptional_mask, scalar::tanpi_u35_f64)
 }
@@ -1,9 +1,8 @@
 use crate::math::{f64, map, scalar};
-use crate::{Simd, SimdBaseIo, SimdBaseOps, SimdConsts, SimdFloat64, SimdInt64};
+use crate::{Simd, SimdBaseIo, SimdBaseOps, SimdConsts, SimdFloat64, SimdMask};
 
 type SimdI64<V> = <<V as SimdConsts>::Engine as Simd>::Vi64;
 
-const SIGN_MASK: i64 = i64::MIN;
 const SINH_COSH_SCALAR_PATCH_ABS: f64 = 1.0;
 const SINH_COSH_FAST_ABS_MAX: f64 = 20.0;
 const TANH_SCALAR_PATCH_ABS: f64 = 1.0;
@@ -66,16 +65,6 @@ where
     }
 }
 
-#[inline(always)]
-fn apply_input_sign<V>(magnitude: V, input: V) -> V
-where
-    V: SimdFloat64,
-    V::Engine: Simd<Vf64 = V>,
-{
-    let sign_bits = input.bitcast_i64() & SimdI64::<V>::set1(SIGN_MASK);
-    (magnitude.bitcast_i64() | sign_bits).bitcast_f64()
-}
-
 #[inline(always)]
 fn sinh_cosh_medium<V>(abs_input: V) -> (V, V)
 where
@@ -99,9 +88,10 @@ where
     V::Engine: Simd<Vf64 = V>,
 {
     let abs_input = input.abs();
-    let finite_mask = input.cmp_eq(input).bitcast_i64();
+    let finite_mask = input.is_finite().to_vector().bitcast_i64();
     let within_fast_range = abs_input
         .cmp_lte(V::set1(SINH_COSH_FAST_ABS_MAX))
+        .to_vector()
         .bitcast_i64();
 
     (finite_mask & within_fast_range, abs_input, input * input)
@@ -116,11 +106,12 @@ where
     let (fast_mask, abs_input, _) = sinh_cosh_masks(input);
     let small_scalar_mask = abs_input
         .cmp_lt(V::set1(SINH_COSH_SCALAR_PATCH_ABS))
+        .to_vector()
         .bitcast_i64();
-    let exceptional_mask = fast_mask.cmp_eq(SimdI64::<V>::zeroes()) | small_scalar_mask;
+    let exceptional_mask = fast_mask.cmp_eq(SimdI64::<V>::zeroes()).to_vector() | small_scalar_mask;
 
     let (sinh_medium, _) = sinh_cosh_medium(abs_input);
-    let fast = apply_input_sign(sinh_medium, input);
+    let fast = sinh_medium.copysign(input);
 
     patch_exceptional_lanes(input, fast, exceptional_mask, scalar::sinh_u35_f64)
 }
@@ -141,17 +132,23 @@ where
     V::Engine: Simd<Vf64 = V>,
 {
     let abs_input = input.abs();
-    let finite_mask = input.cmp_eq(input).bitcast_i64();
-    let within_fast_range = abs_input.cmp_lte(V::set1(TANH_FAST_ABS_MAX)).bitcast_i64();
+    let finite_mask = input.is_finite().to_vector().bitcast_i64();
+    let within_fast_range = abs_input
+        .cmp_lte(V::set1(TANH_FAST_ABS_MAX))
+        .to_vector()
+        .bitcast_i64();
     let small_scalar_mask = abs_input
         .cmp_lt(V::set1(TANH_SCALAR_PATCH_ABS))
+        .to_vector()
         .bitcast_i64();
-    let exceptional_mask =
-        (finite_mask & within_fast_range).cmp_eq(SimdI64::<V>::zeroes()) | small_scalar_mask;
+    let exceptional_mask = (finite_mask & within_fast_range)
+        .cmp_eq(SimdI64::<V>::zeroes())
+        .to_vector()
+        | small_scalar_mask;
 
     let exp_neg_2x = f64::exp_u35(abs_input * V::set1(-2.0));
     let tanh_medium = (V::set1(1.0) - exp_neg_2x) / (V::set1(1.0) + exp_neg_2x);
-    let fast = apply_input_sign(tanh_medium, input);
+    let fast = tanh_medium.copysign(input);
 
     patch_exceptional_lanes(input, fast, exceptional_mask, scalar::tanh_u35_f64)
 }
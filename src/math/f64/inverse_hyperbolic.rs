@@ -1,5 +1,5 @@
 use crate::math::{f64, map, scalar};
-use crate::{Simd, SimdBaseIo, SimdBaseOps, SimdConsts, SimdFloat64};
+use crate::{Simd, SimdBaseIo, SimdBaseOps, SimdConsts, SimdFloat64, SimdMask};
 
 // DECISION(2026-03-23): KEEP_MIXED
 // Function(s): f64 asinh_u35
@@ -74,17 +74,17 @@ where
     V: SimdFloat64,
     V::Engine: Simd<Vf64 = V>,
 {
-    let finite_mask = input.cmp_eq(input).bitcast_i64();
+    let finite_mask = input.is_finite().to_vector().bitcast_i64();
     let abs_x = input.abs();
-    let tiny_mask = abs_x.cmp_lt(V::set1(1.0)).bitcast_i64();
-    let large_mask = abs_x.cmp_gt(V::set1(1.0e150)).bitcast_i64();
-    let zero_mask = input.cmp_eq(V::zeroes()).bitcast_i64();
+    let tiny_mask = abs_x.cmp_lt(V::set1(1.0)).to_vector().bitcast_i64();
+    let large_mask = abs_x.cmp_gt(V::set1(1.0e150)).to_vector().bitcast_i64();
+    let zero_mask = input.cmp_eq(V::zeroes()).to_vector().bitcast_i64();
     let exceptional_mask =
-        finite_mask.cmp_eq(SimdI64::<V>::zeroes()) | tiny_mask | large_mask | zero_mask;
+        finite_mask.cmp_eq(SimdI64::<V>::zeroes()).to_vector() | tiny_mask | large_mask | zero_mask;
 
     let radicand = (abs_x * abs_x) + V::set1(1.0);
     let magnitude = map::unary_f64(abs_x + radicand.sqrt(), scalar::ln_u35_f64);
-    let negative_mask = input.cmp_lt(V::zeroes());
+    let negative_mask = input.cmp_lt(V::zeroes()).to_vector();
     let fast = negative_mask.blendv(magnitude, -magnitude);
 
     patch_exceptional_lanes(input, fast, exceptional_mask, scalar::asinh_u35_f64)
@@ -96,11 +96,11 @@ where
     V: SimdFloat64,
     V::Engine: Simd<Vf64 = V>,
 {
-    let finite_mask = input.cmp_eq(input).bitcast_i64();
-    let in_domain_mask = input.cmp_gte(V::set1(1.0)).bitcast_i64();
-    let away_from_one_mask = input.cmp_gte(V::set1(1.5)).bitcast_i64();
+    let finite_mask = input.is_finite().to_vector().bitcast_i64();
+    let in_domain_mask = input.cmp_gte(V::set1(1.0)).to_vector().bitcast_i64();
+    let away_from_one_mask = input.cmp_gte(V::set1(1.5)).to_vector().bitcast_i64();
     let fast_mask = finite_mask & in_domain_mask & away_from_one_mask;
-    let exceptional_mask = fast_mask.cmp_eq(SimdI64::<V>::zeroes());
+    let exceptional_mask = fast_mask.cmp_eq(SimdI64::<V>::zeroes()).to_vector();
 
     let root_term = ((input - V::set1(1.0)).sqrt()) * ((input + V::set1(1.0)).sqrt());
     let fast = f64::ln_u35(input + root_term);
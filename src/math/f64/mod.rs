@@ -1,8 +1,9 @@
 //! f64 SIMD math dispatch layering:
 //! - family-local modules own the public internal routing points for each math family.
 //! - current decisions are intentionally mixed:
-//!   portable SIMD for the revived core log/exp family, inverse trig, and binary misc,
-//!   scalar-reference for trig and the losing `cosh_u35` / `atanh_u35` defaults,
+//!   portable SIMD for the revived core log/exp family, trig, inverse trig, binary misc,
+//!   and special functions,
+//!   scalar-reference for the losing `cosh_u35` / `atanh_u35` defaults,
 //!   and hybrid paths where a stricter scalar sub-op or scalar-lane patch still underpins the fast path.
 //! - follow-up optimization work can still replace one family module at a time.
 
@@ -11,9 +12,16 @@ mod core;
 mod hyperbolic;
 mod inverse_hyperbolic;
 mod inverse_trig;
+#[cfg(feature = "special")]
+mod special;
 
 pub(crate) use binary_misc::{atan2_u35, fmod, hypot_u35, log10_u35};
-pub(crate) use core::{cos_u35, exp2_u35, exp_u35, ln_u35, log2_u35, sin_u35, tan_u35};
+pub(crate) use core::{
+    cbrt_u35, cos_u35, cospi_u35, exp10_u35, exp2_u35, exp_u35, expm1_u35, ln_u35, log1p_u35,
+    log2_u35, pow_u35, powi, sin_u35, sincos_u35, sinpi_u35, tan_u35, tanpi_u35,
+};
 pub(crate) use hyperbolic::{cosh_u35, sinh_u35, tanh_u35};
 pub(crate) use inverse_hyperbolic::{acosh_u35, asinh_u35, atanh_u35};
 pub(crate) use inverse_trig::{acos_u35, asin_u35, atan_u35};
+#[cfg(feature = "special")]
+pub(crate) use special::{erf_u35, erfc_u35, lgamma_u35, tgamma_u35};
@@ -0,0 +1,416 @@
+use super::core::{exp_fast, ln_fast, patch_exceptional_lanes, SimdI64};
+use crate::math::scalar;
+use crate::{Simd, SimdBaseIo, SimdBaseOps, SimdFloat64, SimdInt64, SimdMask};
+
+const F64_ERF_SMALL_ABS: f64 = 0.843_75;
+const F64_ERF_MEDIUM_ABS: f64 = 1.25;
+const F64_ERF_TAIL_SPLIT_ABS: f64 = 1.0 / 0.35;
+const F64_ERF_FAST_ABS_MAX: f64 = 6.0;
+const F64_ERFC_FAST_MIN: f64 = -6.0;
+const F64_ERFC_FAST_MAX: f64 = 26.0;
+const F64_ERF_TAIL_HIGH_MASK: i64 = 0xFFFF_FFFF_0000_0000u64 as i64;
+const F64_GAMMA_FAST_MIN: f64 = -30.0;
+const F64_TGAMMA_FAST_MAX: f64 = 40.0;
+const F64_LGAMMA_STIRLING_MIN: f64 = 10.0;
+const F64_LGAMMA_FAST_MAX: f64 = 1.0e300;
+const F64_HALF_LN_2PI: f64 = 0.918_938_533_204_672_8;
+
+// Coefficients for erf/erfc follow fdlibm's `s_erf.c`, one rational per range.
+const F64_ERX: f64 = 0.845_062_911_510_467_5;
+const F64_ERF_PP: [f64; 5] = [
+    0.128_379_167_095_512_56,
+    -0.325_042_107_247_001_5,
+    -0.028_481_749_575_598_51,
+    -0.005_770_270_296_489_442,
+    -2.376_301_665_665_016_3e-5,
+];
+const F64_ERF_QQ: [f64; 6] = [
+    1.0,
+    0.397_917_223_959_155_35,
+    0.065_022_249_988_767_3,
+    0.005_081_306_281_875_766,
+    1.324_947_380_043_216_4e-4,
+    -3.960_228_278_775_368e-6,
+];
+const F64_ERF_PA: [f64; 7] = [
+    -0.002_362_118_560_752_659_4,
+    0.414_856_118_683_748_33,
+    -0.372_207_876_035_701_3,
+    0.318_346_619_901_161_75,
+    -0.110_894_694_282_396_68,
+    0.035_478_304_325_618_236,
+    -0.002_166_375_594_868_791,
+];
+const F64_ERF_QA: [f64; 7] = [
+    1.0,
+    0.106_420_880_400_844_23,
+    0.540_397_917_702_171,
+    0.071_828_654_414_196_27,
+    0.126_171_219_808_761_64,
+    0.013_637_083_912_029_05,
+    0.011_984_499_846_799_107,
+];
+const F64_ERF_RA: [f64; 8] = [
+    -0.009_864_944_034_847_148,
+    -0.693_858_572_707_181_8,
+    -10.558_626_225_323_291,
+    -62.375_332_450_326_006,
+    -162.396_669_462_573_47,
+    -184.605_092_906_711_04,
+    -81.287_435_506_306_6,
+    -9.814_329_344_169_145,
+];
+const F64_ERF_SA: [f64; 9] = [
+    1.0,
+    19.651_271_667_439_257,
+    137.657_754_143_519_04,
+    434.565_877_475_229_23,
+    645.387_271_733_267_9,
+    429.008_140_027_567_83,
+    108.635_005_541_779_44,
+    6.570_249_770_319_282,
+    -0.060_424_415_214_858_1,
+];
+const F64_ERF_RB: [f64; 7] = [
+    -0.009_864_942_924_700_1,
+    -0.799_283_237_680_523,
+    -17.757_954_917_754_752,
+    -160.636_384_855_821_92,
+    -637.566_443_368_389_6,
+    -1_025.095_131_611_077_2,
+    -483.519_191_608_651_4,
+];
+const F64_ERF_SB: [f64; 8] = [
+    1.0,
+    30.338_060_743_482_46,
+    325.792_512_996_573_9,
+    1_536.729_586_084_437,
+    3_199.858_219_508_595_5,
+    2_553.050_406_433_164_4,
+    474.528_541_206_955_37,
+    -22.440_952_446_585_82,
+];
+// `(gamma(s + 1.5) - 1) / ((s + 0.5) * (s - 0.5))` on `s` in `[-0.5, 0.5]`.
+const F64_GAMMA_R: [f64; 23] = [
+    0.455_092_298_188_967_96,
+    -0.129_353_589_795_540_12,
+    0.161_115_378_002_667_15,
+    -0.088_235_140_923_044_77,
+    0.065_880_075_832_164_53,
+    -0.042_848_354_495_627_38,
+    0.029_079_088_061_863_02,
+    -0.019_385_675_620_255_55,
+    0.012_965_926_329_231_608,
+    -0.008_652_934_041_898_932,
+    0.005_773_646_423_820_777,
+    -0.003_850_820_538_117_832,
+    0.002_567_964_905_939_712_4,
+    -0.001_712_876_444_328_944_2,
+    0.001_142_036_756_353_654_3,
+    -7.572_610_907_592_942e-4,
+    5.048_597_870_145_582e-4,
+    -3.551_084_236_228_19e-4,
+    2.367_420_056_599_186_5e-4,
+    -1.058_219_100_430_239_2e-4,
+    7.054_841_507_909_283e-5,
+    -1.302_441_733_674_453_5e-4,
+    8.682_954_764_713_344e-5,
+];
+const F64_LGAMMA_STIRLING: [f64; 8] = [
+    1.0 / 12.0,
+    -1.0 / 360.0,
+    1.0 / 1260.0,
+    -1.0 / 1680.0,
+    1.0 / 1188.0,
+    -691.0 / 360_360.0,
+    1.0 / 156.0,
+    -3617.0 / 122_400.0,
+];
+
+// DECISION(2026-10-18): KEEP_SIMD_PORTABLE
+// Function(s): f64 erf_u35 / erfc_u35
+// Why kept:
+// - the fdlibm rationals vectorize directly: every range is evaluated and blended, and the tail
+//   reuses the portable exp kernel
+// - NaN, subnormal, and far-tail lanes where the result saturates patch to the libm references
+// Revisit when:
+// - blending three ranges stops paying for itself against lane-wise libm
+
+// DECISION(2026-10-18): KEEP_SIMD_PORTABLE
+// Function(s): f64 tgamma_u35 / lgamma_u35
+// Why kept:
+// - one polynomial on `[1, 2)` plus a recurrence whose trip count follows the widest lane covers
+//   `[-30, 40]`; lgamma switches to Stirling above 10 and rides the portable ln kernel
+// - poles, subnormals, out-of-range lanes, and lgamma lanes next to its negative-axis zeros patch
+//   to the libm references
+// Revisit when:
+// - wide-range inputs become common enough that the recurrence trip count dominates
+
+/// Evaluates `coefficients[0] + coefficients[1] * x + ...` by Horner's rule.
+#[inline(always)]
+fn poly<V>(x: V, coefficients: &[f64]) -> V
+where
+    V: SimdFloat64,
+    V::Engine: Simd<Vf64 = V>,
+{
+    let (last, rest) = coefficients.split_last().unwrap();
+    let mut acc = V::set1(*last);
+    for coefficient in rest.iter().rev() {
+        acc = (acc * x) + V::set1(*coefficient);
+    }
+    acc
+}
+
+/// `erf(x) / x - 1` for `|x| < 0.84375`.
+#[inline(always)]
+fn erf_small_ratio<V>(input: V) -> V
+where
+    V: SimdFloat64,
+    V::Engine: Simd<Vf64 = V>,
+{
+    let input_sq = input * input;
+    poly(input_sq, &F64_ERF_PP) / poly(input_sq, &F64_ERF_QQ)
+}
+
+/// `erf(|x|) - erx` for `|x|` in `[0.84375, 1.25)`.
+#[inline(always)]
+fn erf_medium_ratio<V>(abs_input: V) -> V
+where
+    V: SimdFloat64,
+    V::Engine: Simd<Vf64 = V>,
+{
+    let s = abs_input - V::set1(1.0);
+    poly(s, &F64_ERF_PA) / poly(s, &F64_ERF_QA)
+}
+
+/// `erfc(|x|)` for `|x| >= 1.25`.
+#[inline(always)]
+fn erfc_tail<V>(abs_input: V) -> V
+where
+    V: SimdFloat64,
+    V::Engine: Simd<Vf64 = V>,
+{
+    let inv_sq = V::set1(1.0) / (abs_input * abs_input);
+    let near_mask = abs_input
+        .cmp_lt(V::set1(F64_ERF_TAIL_SPLIT_ABS))
+        .to_vector();
+    let r = near_mask.blendv(poly(inv_sq, &F64_ERF_RB), poly(inv_sq, &F64_ERF_RA));
+    let s = near_mask.blendv(poly(inv_sq, &F64_ERF_SB), poly(inv_sq, &F64_ERF_SA));
+
+    // Dropping the low mantissa bits keeps `z * z` exact, so the large part of the exponent
+    // picks up no rounding and `(z - x) * (z + x)` carries the small remainder.
+    let z = (abs_input.bitcast_i64() & F64_ERF_TAIL_HIGH_MASK).bitcast_f64();
+    let head = exp_fast(V::set1(-0.5625) - z * z);
+    let tail = exp_fast((z - abs_input) * (z + abs_input) + r / s);
+    head * tail / abs_input
+}
+
+#[inline(always)]
+pub(crate) fn erf_u35<V>(input: V) -> V
+where
+    V: SimdFloat64,
+    V::Engine: Simd<Vf64 = V>,
+{
+    let abs_input = input.abs();
+    let normal_mask = abs_input
+        .cmp_gte(V::set1(f64::MIN_POSITIVE))
+        .to_vector()
+        .bitcast_i64();
+    let in_range = abs_input
+        .cmp_lt(V::set1(F64_ERF_FAST_ABS_MAX))
+        .to_vector()
+        .bitcast_i64();
+    let fast_mask = normal_mask & in_range;
+    let exceptional_mask = fast_mask.cmp_eq(SimdI64::<V>::zeroes()).to_vector();
+
+    let small = abs_input + abs_input * erf_small_ratio(abs_input);
+    let medium = V::set1(F64_ERX) + erf_medium_ratio(abs_input);
+    let tail = V::set1(1.0) - erfc_tail(abs_input);
+
+    let small_mask = abs_input.cmp_lt(V::set1(F64_ERF_SMALL_ABS)).to_vector();
+    let medium_mask = abs_input.cmp_lt(V::set1(F64_ERF_MEDIUM_ABS)).to_vector();
+    let fast = small_mask
+        .blendv(medium_mask.blendv(tail, medium), small)
+        .copysign(input);
+
+    patch_exceptional_lanes(input, fast, exceptional_mask, scalar::erf_u35_f64)
+}
+
+#[inline(always)]
+pub(crate) fn erfc_u35<V>(input: V) -> V
+where
+    V: SimdFloat64,
+    V::Engine: Simd<Vf64 = V>,
+{
+    let abs_input = input.abs();
+    let in_lower_bound = input
+        .cmp_gt(V::set1(F64_ERFC_FAST_MIN))
+        .to_vector()
+        .bitcast_i64();
+    let in_upper_bound = input
+        .cmp_lte(V::set1(F64_ERFC_FAST_MAX))
+        .to_vector()
+        .bitcast_i64();
+    let fast_mask = in_lower_bound & in_upper_bound;
+    let exceptional_mask = fast_mask.cmp_eq(SimdI64::<V>::zeroes()).to_vector();
+
+    let one = V::set1(1.0);
+    let two = V::set1(2.0);
+    let half = V::set1(0.5);
+    let negative_mask = input.cmp_lt(V::zeroes()).to_vector();
+
+    // Above 1/4 the result drops below 3/4, so subtracting from 1/2 keeps the low bits.
+    let y = erf_small_ratio(input);
+    let small = input.cmp_lt(V::set1(0.25)).to_vector().blendv(
+        half - ((input - half) + input * y),
+        one - (input + input * y),
+    );
+
+    let medium_abs = (one - V::set1(F64_ERX)) - erf_medium_ratio(abs_input);
+    let medium = negative_mask.blendv(medium_abs, two - medium_abs);
+
+    let tail_abs = erfc_tail(abs_input);
+    let tail = negative_mask.blendv(tail_abs, two - tail_abs);
+
+    let small_mask = abs_input.cmp_lt(V::set1(F64_ERF_SMALL_ABS)).to_vector();
+    let medium_mask = abs_input.cmp_lt(V::set1(F64_ERF_MEDIUM_ABS)).to_vector();
+    let fast = small_mask.blendv(medium_mask.blendv(tail, medium), small);
+
+    patch_exceptional_lanes(input, fast, exceptional_mask, scalar::erfc_u35_f64)
+}
+
+/// Lanes that are not a pole of gamma, i.e. not zero or a negative integer.
+#[inline(always)]
+fn gamma_non_pole_mask<V>(input: V, floor: V) -> SimdI64<V>
+where
+    V: SimdFloat64,
+    V::Engine: Simd<Vf64 = V>,
+{
+    input.cmp_neq(floor).to_vector().bitcast_i64()
+        | input.cmp_gt(V::zeroes()).to_vector().bitcast_i64()
+}
+
+/// Splits `gamma(x)` into `(1 + uvr) * num / den`.
+///
+/// With `u = x - floor(x)`, `gamma(u + 1) = 1 + u * (u - 1) * R(u - 1/2)`, and the recurrence
+/// `gamma(x + 1) = x * gamma(x)` steps from `u + 1` to `x` through `num` (upwards) or `den`
+/// (downwards). Only `active` lanes set the trip count, so a lane the caller patches anyway
+/// cannot drag the loop along.
+#[inline(always)]
+fn gamma_reduce<V>(input: V, floor: V, active: SimdI64<V>) -> (V, V, V)
+where
+    V: SimdFloat64,
+    V::Engine: Simd<Vf64 = V>,
+{
+    let one = V::set1(1.0);
+    let u = input - floor;
+    let uvr = u * (u - one) * poly(u - V::set1(0.5), &F64_GAMMA_R);
+
+    let steps = floor - one;
+    let neg_steps = V::zeroes() - steps;
+    let trips = active
+        .bitcast_f64()
+        .blendv(V::zeroes(), steps.abs())
+        .horizontal_max();
+
+    let mut num = one;
+    let mut den = one;
+    for step in 1..=(trips as u32) {
+        let step = step as f64;
+        let step_v = V::set1(step);
+        num *= steps
+            .cmp_gte(step_v)
+            .to_vector()
+            .blendv(one, input - step_v);
+        den *= neg_steps
+            .cmp_gte(step_v)
+            .to_vector()
+            .blendv(one, input + V::set1(step - 1.0));
+    }
+
+    (uvr, num, den)
+}
+
+#[inline(always)]
+pub(crate) fn tgamma_u35<V>(input: V) -> V
+where
+    V: SimdFloat64,
+    V::Engine: Simd<Vf64 = V>,
+{
+    let floor = input.floor();
+    let normal_mask = input
+        .abs()
+        .cmp_gte(V::set1(f64::MIN_POSITIVE))
+        .to_vector()
+        .bitcast_i64();
+    let in_lower_bound = input
+        .cmp_gte(V::set1(F64_GAMMA_FAST_MIN))
+        .to_vector()
+        .bitcast_i64();
+    let in_upper_bound = input
+        .cmp_lte(V::set1(F64_TGAMMA_FAST_MAX))
+        .to_vector()
+        .bitcast_i64();
+    let fast_mask =
+        normal_mask & in_lower_bound & in_upper_bound & gamma_non_pole_mask(input, floor);
+    let exceptional_mask = fast_mask.cmp_eq(SimdI64::<V>::zeroes()).to_vector();
+
+    let (uvr, num, den) = gamma_reduce(input, floor, fast_mask);
+    let fast = (V::set1(1.0) + uvr) * num / den;
+
+    patch_exceptional_lanes(input, fast, exceptional_mask, scalar::tgamma_u35_f64)
+}
+
+#[inline(always)]
+pub(crate) fn lgamma_u35<V>(input: V) -> V
+where
+    V: SimdFloat64,
+    V::Engine: Simd<Vf64 = V>,
+{
+    let floor = input.floor();
+    let normal_mask = input
+        .abs()
+        .cmp_gte(V::set1(f64::MIN_POSITIVE))
+        .to_vector()
+        .bitcast_i64();
+    let in_lower_bound = input
+        .cmp_gte(V::set1(F64_GAMMA_FAST_MIN))
+        .to_vector()
+        .bitcast_i64();
+    let in_upper_bound = input
+        .cmp_lte(V::set1(F64_LGAMMA_FAST_MAX))
+        .to_vector()
+        .bitcast_i64();
+    let fast_mask =
+        normal_mask & in_lower_bound & in_upper_bound & gamma_non_pole_mask(input, floor);
+    let stirling_mask = input.cmp_gte(V::set1(F64_LGAMMA_STIRLING_MIN)).to_vector();
+    let reduce_mask = stirling_mask
+        .bitcast_i64()
+        .cmp_eq(SimdI64::<V>::zeroes())
+        .to_vector();
+
+    // The two logs stay separate: `num / den` can round to 1 while each is far from it, which
+    // would wipe out lgamma next to its zeros at 1 and 2. `ln(1 + uvr)` takes the log1p
+    // correction for the same reason.
+    let one = V::set1(1.0);
+    let (uvr, num, den) = gamma_reduce(input, floor, fast_mask & reduce_mask);
+    let shifted = one + uvr;
+    let ln_gamma_reduced = ln_fast(shifted) + (uvr - (shifted - one)) / shifted;
+    let reduced = ln_gamma_reduced + (ln_fast(num.abs()) - ln_fast(den.abs()));
+
+    let inv = one / input;
+    let series = poly(inv * inv, &F64_LGAMMA_STIRLING) * inv;
+    let stirling =
+        ((input - V::set1(0.5)) * ln_fast(input) - input) + V::set1(F64_HALF_LN_2PI) + series;
+
+    let fast = stirling_mask.blendv(reduced, stirling);
+
+    // On the negative axis lgamma crosses zero between every pair of integers, and the sum of
+    // logs cancels there.
+    let near_negative_zero = input.cmp_lt(V::zeroes()).to_vector().bitcast_i64()
+        & fast.abs().cmp_lt(one).to_vector().bitcast_i64();
+    let exceptional_mask =
+        fast_mask.cmp_eq(SimdI64::<V>::zeroes()).to_vector() | near_negative_zero;
+
+    patch_exceptional_lanes(input, fast, exceptional_mask, scalar::lgamma_u35_f64)
+}
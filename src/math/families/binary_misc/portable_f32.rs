@@ -1,5 +1,5 @@
 use crate::math::{f32, scalar};
-use crate::{Simd, SimdBaseIo, SimdBaseOps, SimdFloat32, SimdInt32};
+use crate::{Simd, SimdBaseIo, SimdBaseOps, SimdFloat32, SimdInt32, SimdMask};
 
 // DECISION(2026-03-23): KEEP_SIMD_PORTABLE
 // Function(s): f32 log10_u35 / atan2_u35 / hypot_u35
@@ -97,13 +97,13 @@ where
     V: SimdFloat32,
     V::Engine: Simd<Vf32 = V>,
 {
-    let positive_finite =
-        input.cmp_gt(V::zeroes()).bitcast_i32() & input.cmp_eq(input).bitcast_i32();
+    let positive_finite = input.cmp_gt(V::zeroes()).to_vector().bitcast_i32()
+        & input.is_finite().to_vector().bitcast_i32();
     let fast = f32::log2_u35(input) * V::set1(core::f32::consts::LOG10_2);
     patch_unary_exceptional_lanes(
         input,
         fast,
-        positive_finite.cmp_eq(SimdI32::<V>::zeroes()),
+        positive_finite.cmp_eq(SimdI32::<V>::zeroes()).to_vector(),
         scalar::log10_u35_f32,
     )
 }
@@ -138,30 +138,32 @@ where
     let abs_y = y.abs();
     let abs_x = x.abs();
 
-    let x_is_zero = abs_x.cmp_eq(V::zeroes()).bitcast_i32();
-    let y_is_zero = abs_y.cmp_eq(V::zeroes()).bitcast_i32();
-    let x_finite = x.cmp_eq(x).bitcast_i32() & abs_x.cmp_neq(V::set1(f32::INFINITY)).bitcast_i32();
-    let y_finite = y.cmp_eq(y).bitcast_i32() & abs_y.cmp_neq(V::set1(f32::INFINITY)).bitcast_i32();
+    let x_is_zero = abs_x.cmp_eq(V::zeroes()).to_vector().bitcast_i32();
+    let y_is_zero = abs_y.cmp_eq(V::zeroes()).to_vector().bitcast_i32();
+    let x_finite = x.is_finite().to_vector().bitcast_i32();
+    let y_finite = y.is_finite().to_vector().bitcast_i32();
 
-    let fast_mask = x_finite & y_finite & x_is_zero.cmp_eq(SimdI32::<V>::zeroes());
-    let exceptional_mask = fast_mask.cmp_eq(SimdI32::<V>::zeroes());
+    let fast_mask = x_finite & y_finite & x_is_zero.cmp_eq(SimdI32::<V>::zeroes()).to_vector();
+    let exceptional_mask = fast_mask.cmp_eq(SimdI32::<V>::zeroes()).to_vector();
 
-    let use_inv = abs_y.cmp_gt(abs_x);
+    let use_inv = abs_y.cmp_gt(abs_x).to_vector();
     let z = use_inv.blendv(abs_y / abs_x, abs_x / abs_y);
     let z_atan = atan_u35_poly(z);
 
     let pi_over_2 = V::set1(core::f32::consts::FRAC_PI_2);
     let pi = V::set1(core::f32::consts::PI);
-    let y_sign = y.bitcast_i32() & SimdI32::<V>::set1(i32::MIN);
-    let x_negative = x.cmp_lt(V::zeroes()).bitcast_i32();
+    let x_negative = x.cmp_lt(V::zeroes()).to_vector().bitcast_i32();
 
     let base = use_inv.blendv(z_atan, pi_over_2 - z_atan);
     let with_x_quadrant = x_negative.bitcast_f32().blendv(base, pi - base);
-    let signed = (y_sign ^ with_x_quadrant.bitcast_i32()).bitcast_f32();
+    let signed = with_x_quadrant.copysign(y);
 
     let both_zero = x_is_zero & y_is_zero;
-    let y_neg_zero = y.bitcast_i32().cmp_eq(SimdI32::<V>::set1(i32::MIN));
-    let x_neg = x.cmp_lt(V::zeroes()).bitcast_i32();
+    let y_neg_zero = y
+        .bitcast_i32()
+        .cmp_eq(SimdI32::<V>::set1(i32::MIN))
+        .to_vector();
+    let x_neg = x.cmp_lt(V::zeroes()).to_vector().bitcast_i32();
     let signed_zero_result = y_neg_zero.bitcast_f32().blendv(V::zeroes(), V::set1(-0.0));
     let zero_quadrant = x_neg
         .bitcast_f32()
@@ -179,16 +181,14 @@ where
 {
     let abs_x = x.abs();
     let abs_y = y.abs();
-    let max_xy = abs_x.cmp_gt(abs_y).blendv(abs_y, abs_x);
-    let min_xy = abs_x.cmp_gt(abs_y).blendv(abs_x, abs_y);
-
-    let finite_mask = x.cmp_eq(x).bitcast_i32()
-        & y.cmp_eq(y).bitcast_i32()
-        & abs_x.cmp_neq(V::set1(f32::INFINITY)).bitcast_i32()
-        & abs_y.cmp_neq(V::set1(f32::INFINITY)).bitcast_i32();
-    let max_nonzero = max_xy.cmp_neq(V::zeroes()).bitcast_i32();
+    let max_xy = abs_x.cmp_gt(abs_y).select(abs_x, abs_y);
+    let min_xy = abs_x.cmp_gt(abs_y).select(abs_y, abs_x);
+
+    let finite_mask =
+        x.is_finite().to_vector().bitcast_i32() & y.is_finite().to_vector().bitcast_i32();
+    let max_nonzero = max_xy.cmp_neq(V::zeroes()).to_vector().bitcast_i32();
     let fast_mask = finite_mask & max_nonzero;
-    let exceptional_mask = fast_mask.cmp_eq(SimdI32::<V>::zeroes());
+    let exceptional_mask = fast_mask.cmp_eq(SimdI32::<V>::zeroes()).to_vector();
 
     let ratio = min_xy / max_xy;
     let fast = max_xy * (V::set1(1.0) + ratio * ratio).sqrt();
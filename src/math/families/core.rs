@@ -1,15 +1,13 @@
 use crate::math::{f32, f64, map, scalar};
 use crate::{Simd, SimdFloat32, SimdFloat64};
 
-// DECISION(2026-03-23): KEEP_SCALAR_REFERENCE
-// Function(s): f32 ln_u35 / exp_u35
+// DECISION(2026-10-18): KEEP_SCALAR_REFERENCE
+// Function(s): f32/f64 ln_u10 / exp_u10
 // Why scalar:
-// - the final combined-wave recheck still keeps runtime-selected ln_u35 and exp_u35 below native scalar
-//   on this host (`ln`: about 2.72 ms vs 2.46 ms, `exp`: about 2.34 ms vs 2.11 ms)
-// - these contracts are stricter than the relaxed portable f32 log2_u35 / exp2_u35 pieces they would
-//   naturally compose from, so there is no cheap honest rescue today
+// - the u10 tier exists for callers that need results within 1 ulp of the scalar references
+// - the portable u35 kernels do not meet that bound, so the honest default is lane-wise scalar
 // Revisit when:
-// - a better shared f32 log/exp kernel exists
+// - a shared log/exp kernel lands that holds 1 ulp without scalar sub-ops
 
 pub trait SimdMathF32Core: SimdFloat32 {
     #[inline(always)]
@@ -29,13 +27,78 @@ pub trait SimdMathF32Core: SimdFloat32 {
     }
 
     #[inline(always)]
-    fn ln_u35(self) -> Self {
-        map::unary_f32(self, scalar::ln_u35_f32)
+    fn ln_u35(self) -> Self
+    where
+        Self::Engine: Simd<Vf32 = Self>,
+    {
+        f32::ln_u35(self)
+    }
+
+    #[inline(always)]
+    fn exp_u35(self) -> Self
+    where
+        Self::Engine: Simd<Vf32 = Self>,
+    {
+        f32::exp_u35(self)
+    }
+
+    #[inline(always)]
+    fn ln_u10(self) -> Self {
+        map::unary_f32(self, scalar::ln_u10_f32)
+    }
+
+    #[inline(always)]
+    fn exp_u10(self) -> Self {
+        map::unary_f32(self, scalar::exp_u10_f32)
+    }
+
+    #[inline(always)]
+    fn pow_u35(self, exponent: Self) -> Self
+    where
+        Self::Engine: Simd<Vf32 = Self>,
+    {
+        f32::pow_u35(self, exponent)
+    }
+
+    /// Raises every lane to the same integer power.
+    #[inline(always)]
+    fn powi(self, exponent: i32) -> Self
+    where
+        Self::Engine: Simd<Vf32 = Self>,
+    {
+        f32::powi(self, exponent)
+    }
+
+    #[inline(always)]
+    fn cbrt_u35(self) -> Self
+    where
+        Self::Engine: Simd<Vf32 = Self>,
+    {
+        f32::cbrt_u35(self)
     }
 
     #[inline(always)]
-    fn exp_u35(self) -> Self {
-        map::unary_f32(self, scalar::exp_u35_f32)
+    fn exp10_u35(self) -> Self
+    where
+        Self::Engine: Simd<Vf32 = Self>,
+    {
+        f32::exp10_u35(self)
+    }
+
+    #[inline(always)]
+    fn expm1_u35(self) -> Self
+    where
+        Self::Engine: Simd<Vf32 = Self>,
+    {
+        f32::expm1_u35(self)
+    }
+
+    #[inline(always)]
+    fn log1p_u35(self) -> Self
+    where
+        Self::Engine: Simd<Vf32 = Self>,
+    {
+        f32::log1p_u35(self)
     }
 
     #[inline(always)]
@@ -61,6 +124,42 @@ pub trait SimdMathF32Core: SimdFloat32 {
     {
         f32::tan_u35(self)
     }
+
+    /// Returns `(sin, cos)` from a single range reduction.
+    #[inline(always)]
+    fn sincos_u35(self) -> (Self, Self)
+    where
+        Self::Engine: Simd<Vf32 = Self>,
+    {
+        f32::sincos_u35(self)
+    }
+
+    /// `sin(pi * x)`, exact at integers and half-integers.
+    #[inline(always)]
+    fn sinpi_u35(self) -> Self
+    where
+        Self::Engine: Simd<Vf32 = Self>,
+    {
+        f32::sinpi_u35(self)
+    }
+
+    /// `cos(pi * x)`, exact at integers and half-integers.
+    #[inline(always)]
+    fn cospi_u35(self) -> Self
+    where
+        Self::Engine: Simd<Vf32 = Self>,
+    {
+        f32::cospi_u35(self)
+    }
+
+    /// `tan(pi * x)`, exact at integers and infinite at half-integers.
+    #[inline(always)]
+    fn tanpi_u35(self) -> Self
+    where
+        Self::Engine: Simd<Vf32 = Self>,
+    {
+        f32::tanpi_u35(self)
+    }
 }
 
 impl<T: SimdFloat32> SimdMathF32Core for T {}
@@ -98,6 +197,65 @@ pub trait SimdMathF64Core: SimdFloat64 {
         f64::exp_u35(self)
     }
 
+    #[inline(always)]
+    fn ln_u10(self) -> Self {
+        map::unary_f64(self, scalar::ln_u10_f64)
+    }
+
+    #[inline(always)]
+    fn exp_u10(self) -> Self {
+        map::unary_f64(self, scalar::exp_u10_f64)
+    }
+
+    #[inline(always)]
+    fn pow_u35(self, exponent: Self) -> Self
+    where
+        Self::Engine: Simd<Vf64 = Self>,
+    {
+        f64::pow_u35(self, exponent)
+    }
+
+    /// Raises every lane to the same integer power.
+    #[inline(always)]
+    fn powi(self, exponent: i32) -> Self
+    where
+        Self::Engine: Simd<Vf64 = Self>,
+    {
+        f64::powi(self, exponent)
+    }
+
+    #[inline(always)]
+    fn cbrt_u35(self) -> Self
+    where
+        Self::Engine: Simd<Vf64 = Self>,
+    {
+        f64::cbrt_u35(self)
+    }
+
+    #[inline(always)]
+    fn exp10_u35(self) -> Self
+    where
+        Self::Engine: Simd<Vf64 = Self>,
+    {
+        f64::exp10_u35(self)
+    }
+
+    #[inline(always)]
+    fn expm1_u35(self) -> Self
+    where
+        Self::Engine: Simd<Vf64 = Self>,
+    {
+        f64::expm1_u35(self)
+    }
+
+    #[inline(always)]
+    fn log1p_u35(self) -> Self
+    where
+        Self::Engine: Simd<Vf64 = Self>,
+    {
+        f64::log1p_u35(self)
+    }
+
     #[inline(always)]
     fn sin_u35(self) -> Self
     where
@@ -121,6 +279,42 @@ pub trait SimdMathF64Core: SimdFloat64 {
     {
         f64::tan_u35(self)
     }
+
+    /// Returns `(sin, cos)` from a single range reduction.
+    #[inline(always)]
+    fn sincos_u35(self) -> (Self, Self)
+    where
+        Self::Engine: Simd<Vf64 = Self>,
+    {
+        f64::sincos_u35(self)
+    }
+
+    /// `sin(pi * x)`, exact at integers and half-integers.
+    #[inline(always)]
+    fn sinpi_u35(self) -> Self
+    where
+        Self::Engine: Simd<Vf64 = Self>,
+    {
+        f64::sinpi_u35(self)
+    }
+
+    /// `cos(pi * x)`, exact at integers and half-integers.
+    #[inline(always)]
+    fn cospi_u35(self) -> Self
+    where
+        Self::Engine: Simd<Vf64 = Self>,
+    {
+        f64::cospi_u35(self)
+    }
+
+    /// `tan(pi * x)`, exact at integers and infinite at half-integers.
+    #[inline(always)]
+    fn tanpi_u35(self) -> Self
+    where
+        Self::Engine: Simd<Vf64 = Self>,
+    {
+        f64::tanpi_u35(self)
+    }
 }
 
 impl<T: SimdFloat64> SimdMathF64Core for T {}
@@ -1,5 +1,5 @@
 use crate::math::scalar;
-use crate::{Simd, SimdBaseIo, SimdBaseOps, SimdConsts, SimdFloat32, SimdInt32};
+use crate::{Simd, SimdBaseIo, SimdBaseOps, SimdConsts, SimdFloat32, SimdInt32, SimdMask};
 
 // DECISION(2026-03-23): KEEP_SIMD_PORTABLE
 // Function(s): f32 asin_u35 / acos_u35 / atan_u35
@@ -11,9 +11,6 @@ use crate::{Simd, SimdBaseIo, SimdBaseOps, SimdConsts, SimdFloat32, SimdInt32};
 
 type SimdI32<V> = <<V as SimdConsts>::Engine as Simd>::Vi32;
 
-const F32_EXPONENT_MASK: i32 = 0x7F80_0000u32 as i32;
-const F32_SIGN_MASK: i32 = i32::MIN;
-
 const ASIN_SQRT_FALLBACK_BOUND_BITS: u32 = 0x3F7F_F000;
 
 const FRAC_PI_2_HI: f32 = f32::from_bits(0x3FC9_0F80);
@@ -74,25 +71,6 @@ where
     }
 }
 
-#[inline(always)]
-fn restore_sign<V>(magnitude: V, sign_bits: SimdI32<V>) -> V
-where
-    V: SimdFloat32,
-    V::Engine: Simd<Vf32 = V>,
-{
-    (magnitude.bitcast_i32() ^ sign_bits).bitcast_f32()
-}
-
-#[inline(always)]
-fn finite_mask<V>(input: V) -> SimdI32<V>
-where
-    V: SimdFloat32,
-    V::Engine: Simd<Vf32 = V>,
-{
-    let exponent_bits = input.bitcast_i32() & F32_EXPONENT_MASK;
-    exponent_bits.cmp_neq(SimdI32::<V>::set1(F32_EXPONENT_MASK))
-}
-
 #[inline(always)]
 fn asin_poly<V>(z: V) -> V
 where
@@ -124,13 +102,14 @@ where
     V: SimdFloat32,
     V::Engine: Simd<Vf32 = V>,
 {
-    let finite = finite_mask(input);
+    let finite = input.is_finite().to_vector().bitcast_i32();
     let abs_input = input.abs();
-    let out_of_domain = abs_input.cmp_gt(V::set1(1.0)).bitcast_i32();
+    let out_of_domain = abs_input.cmp_gt(V::set1(1.0)).to_vector().bitcast_i32();
     let near_edge = abs_input
         .bitcast_i32()
-        .cmp_gt(SimdI32::<V>::set1(ASIN_SQRT_FALLBACK_BOUND_BITS as i32));
-    finite.cmp_eq(SimdI32::<V>::zeroes()) | out_of_domain | near_edge
+        .cmp_gt(SimdI32::<V>::set1(ASIN_SQRT_FALLBACK_BOUND_BITS as i32))
+        .to_vector();
+    finite.cmp_eq(SimdI32::<V>::zeroes()).to_vector() | out_of_domain | near_edge
 }
 
 #[inline(always)]
@@ -139,13 +118,14 @@ where
     V: SimdFloat32,
     V::Engine: Simd<Vf32 = V>,
 {
-    let finite = finite_mask(input);
+    let finite = input.is_finite().to_vector().bitcast_i32();
     let abs_input = input.abs();
-    let out_of_domain = abs_input.cmp_gt(V::set1(1.0)).bitcast_i32();
+    let out_of_domain = abs_input.cmp_gt(V::set1(1.0)).to_vector().bitcast_i32();
     let near_one = input
         .bitcast_i32()
-        .cmp_gt(SimdI32::<V>::set1(ASIN_SQRT_FALLBACK_BOUND_BITS as i32));
-    finite.cmp_eq(SimdI32::<V>::zeroes()) | out_of_domain | near_one
+        .cmp_gt(SimdI32::<V>::set1(ASIN_SQRT_FALLBACK_BOUND_BITS as i32))
+        .to_vector();
+    finite.cmp_eq(SimdI32::<V>::zeroes()).to_vector() | out_of_domain | near_one
 }
 
 #[inline(always)]
@@ -157,9 +137,8 @@ where
     let one = V::set1(1.0);
     let half = V::set1(0.5);
     let abs_input = input.abs();
-    let sign_bits = input.bitcast_i32() & SimdI32::<V>::set1(F32_SIGN_MASK);
 
-    let large_mask = abs_input.cmp_gt(half).bitcast_i32();
+    let large_mask = abs_input.cmp_gt(half).to_vector().bitcast_i32();
     let large_mask_f = large_mask.bitcast_f32();
 
     let small_z = abs_input * abs_input;
@@ -172,13 +151,17 @@ where
     let large_mag = (V::set1(FRAC_PI_2_HI) - (large_s + large_term)) + V::set1(FRAC_PI_2_LO);
 
     let asin_mag = large_mask_f.blendv(small_mag, large_mag);
-    let asin_out = restore_sign(asin_mag, sign_bits);
+    let asin_out = asin_mag.copysign(input);
 
     let small_acos = (V::set1(FRAC_PI_2_HI) - asin_out) + V::set1(FRAC_PI_2_LO);
     let large_acos_pos = large_s + large_term;
     let large_acos_neg = (V::set1(PI_HI) - large_acos_pos) + V::set1(PI_LO);
 
-    let negative_mask = input.cmp_lt(V::zeroes()).bitcast_i32().bitcast_f32();
+    let negative_mask = input
+        .cmp_lt(V::zeroes())
+        .to_vector()
+        .bitcast_i32()
+        .bitcast_f32();
     let large_acos = negative_mask.blendv(large_acos_pos, large_acos_neg);
     let acos_out = large_mask_f.blendv(small_acos, large_acos);
 
@@ -213,12 +196,17 @@ where
     V: SimdFloat32,
     V::Engine: Simd<Vf32 = V>,
 {
-    let sign_bits = input.bitcast_i32() & SimdI32::<V>::set1(F32_SIGN_MASK);
     let abs_input = input.abs();
 
-    let large_mask = abs_input.cmp_gt(V::set1(TAN_3PI_8)).bitcast_i32();
-    let medium_base = abs_input.cmp_gt(V::set1(TAN_PI_8)).bitcast_i32();
-    let medium_mask = medium_base & large_mask.cmp_eq(SimdI32::<V>::zeroes());
+    let large_mask = abs_input
+        .cmp_gt(V::set1(TAN_3PI_8))
+        .to_vector()
+        .bitcast_i32();
+    let medium_base = abs_input
+        .cmp_gt(V::set1(TAN_PI_8))
+        .to_vector()
+        .bitcast_i32();
+    let medium_mask = medium_base & large_mask.cmp_eq(SimdI32::<V>::zeroes()).to_vector();
     let large_mask_f = large_mask.bitcast_f32();
     let medium_mask_f = medium_mask.bitcast_f32();
 
@@ -237,8 +225,13 @@ where
     offset = large_mask_f.blendv(offset, V::set1(FRAC_PI_2_HI + FRAC_PI_2_LO));
 
     let magnitude = offset + atan_reduced;
-    let fast = restore_sign(magnitude, sign_bits);
-    let exceptional_mask = finite_mask(input).cmp_eq(SimdI32::<V>::zeroes());
+    let fast = magnitude.copysign(input);
+    let exceptional_mask = input
+        .is_finite()
+        .to_vector()
+        .bitcast_i32()
+        .cmp_eq(SimdI32::<V>::zeroes())
+        .to_vector();
 
     patch_exceptional_lanes(input, fast, exceptional_mask, scalar::atan_u35_f32)
 }
@@ -1,5 +1,5 @@
 use crate::math::scalar;
-use crate::{Simd, SimdBaseIo, SimdBaseOps, SimdConsts, SimdFloat64, SimdInt64};
+use crate::{Simd, SimdBaseIo, SimdBaseOps, SimdConsts, SimdFloat64, SimdInt64, SimdMask};
 
 // DECISION(2026-03-23): KEEP_SIMD_PORTABLE
 // Function(s): f64 asin_u35 / acos_u35 / atan_u35
@@ -11,9 +11,6 @@ use crate::{Simd, SimdBaseIo, SimdBaseOps, SimdConsts, SimdFloat64, SimdInt64};
 
 type SimdI64<V> = <<V as SimdConsts>::Engine as Simd>::Vi64;
 
-const F64_EXPONENT_MASK: i64 = 0x7FF0_0000_0000_0000u64 as i64;
-const F64_SIGN_MASK: i64 = i64::MIN;
-
 const ASIN_SQRT_FALLBACK_BOUND_BITS: u64 = 0x3FEF_F000_0000_0000;
 
 const FRAC_PI_2: f64 = core::f64::consts::FRAC_PI_2;
@@ -71,25 +68,6 @@ where
     }
 }
 
-#[inline(always)]
-fn restore_sign<V>(magnitude: V, sign_bits: SimdI64<V>) -> V
-where
-    V: SimdFloat64,
-    V::Engine: Simd<Vf64 = V>,
-{
-    (magnitude.bitcast_i64() ^ sign_bits).bitcast_f64()
-}
-
-#[inline(always)]
-fn finite_mask<V>(input: V) -> SimdI64<V>
-where
-    V: SimdFloat64,
-    V::Engine: Simd<Vf64 = V>,
-{
-    let exponent_bits = input.bitcast_i64() & SimdI64::<V>::set1(F64_EXPONENT_MASK);
-    exponent_bits.cmp_neq(SimdI64::<V>::set1(F64_EXPONENT_MASK))
-}
-
 #[inline(always)]
 fn atan_poly<V>(z: V) -> V
 where
@@ -129,12 +107,17 @@ where
     V: SimdFloat64,
     V::Engine: Simd<Vf64 = V>,
 {
-    let sign_bits = input.bitcast_i64() & SimdI64::<V>::set1(F64_SIGN_MASK);
     let abs_input = input.abs();
 
-    let large_mask = abs_input.cmp_gt(V::set1(TAN_3PI_8)).bitcast_i64();
-    let medium_base = abs_input.cmp_gt(V::set1(TAN_PI_8)).bitcast_i64();
-    let medium_mask = medium_base & large_mask.cmp_eq(SimdI64::<V>::zeroes());
+    let large_mask = abs_input
+        .cmp_gt(V::set1(TAN_3PI_8))
+        .to_vector()
+        .bitcast_i64();
+    let medium_base = abs_input
+        .cmp_gt(V::set1(TAN_PI_8))
+        .to_vector()
+        .bitcast_i64();
+    let medium_mask = medium_base & large_mask.cmp_eq(SimdI64::<V>::zeroes()).to_vector();
     let large_mask_f = large_mask.bitcast_f64();
     let medium_mask_f = medium_mask.bitcast_f64();
 
@@ -150,7 +133,7 @@ where
     offset = large_mask_f.blendv(offset, V::set1(FRAC_PI_2));
 
     let magnitude = offset + atan_reduced(reduced);
-    restore_sign(magnitude, sign_bits)
+    magnitude.copysign(input)
 }
 
 #[inline(always)]
@@ -159,13 +142,14 @@ where
     V: SimdFloat64,
     V::Engine: Simd<Vf64 = V>,
 {
-    let finite = finite_mask(input);
+    let finite = input.is_finite().to_vector().bitcast_i64();
     let abs_input = input.abs();
-    let out_of_domain = abs_input.cmp_gt(V::set1(1.0)).bitcast_i64();
+    let out_of_domain = abs_input.cmp_gt(V::set1(1.0)).to_vector().bitcast_i64();
     let near_edge = abs_input
         .bitcast_i64()
-        .cmp_gt(SimdI64::<V>::set1(ASIN_SQRT_FALLBACK_BOUND_BITS as i64));
-    finite.cmp_eq(SimdI64::<V>::zeroes()) | out_of_domain | near_edge
+        .cmp_gt(SimdI64::<V>::set1(ASIN_SQRT_FALLBACK_BOUND_BITS as i64))
+        .to_vector();
+    finite.cmp_eq(SimdI64::<V>::zeroes()).to_vector() | out_of_domain | near_edge
 }
 
 #[inline(always)]
@@ -186,9 +170,8 @@ where
     let one = V::set1(1.0);
     let half = V::set1(0.5);
     let abs_input = input.abs();
-    let sign_bits = input.bitcast_i64() & SimdI64::<V>::set1(F64_SIGN_MASK);
 
-    let small_mask = abs_input.cmp_lt(half).bitcast_i64();
+    let small_mask = abs_input.cmp_lt(half).to_vector().bitcast_i64();
     let small_mask_f = small_mask.bitcast_f64();
 
     let small_t = abs_input / (one - abs_input * abs_input).sqrt();
@@ -199,12 +182,16 @@ where
     let large_asin_mag = V::set1(FRAC_PI_2) - large_angle;
 
     let asin_mag = small_mask_f.blendv(large_asin_mag, small_asin_mag);
-    let asin_out = restore_sign(asin_mag, sign_bits);
+    let asin_out = asin_mag.copysign(input);
 
     let small_acos = V::set1(FRAC_PI_2) - asin_out;
     let large_acos_pos = large_angle;
     let large_acos_neg = V::set1(PI) - large_angle;
-    let negative_mask = input.cmp_lt(V::zeroes()).bitcast_i64().bitcast_f64();
+    let negative_mask = input
+        .cmp_lt(V::zeroes())
+        .to_vector()
+        .bitcast_i64()
+        .bitcast_f64();
     let large_acos = negative_mask.blendv(large_acos_pos, large_acos_neg);
     let acos_out = small_mask_f.blendv(large_acos, small_acos);
 
@@ -240,6 +227,11 @@ where
     V::Engine: Simd<Vf64 = V>,
 {
     let fast = atan_fast(input);
-    let exceptional_mask = finite_mask(input).cmp_eq(SimdI64::<V>::zeroes());
+    let exceptional_mask = input
+        .is_finite()
+        .to_vector()
+        .bitcast_i64()
+        .cmp_eq(SimdI64::<V>::zeroes())
+        .to_vector();
     patch_exceptional_lanes(input, fast, exceptional_mask, scalar::atan_u35_f64)
 }
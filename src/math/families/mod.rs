@@ -3,6 +3,8 @@ mod core;
 mod hyperbolic;
 mod inverse_hyperbolic;
 pub(crate) mod inverse_trig;
+#[cfg(feature = "special")]
+mod special;
 
 use crate::{SimdFloat32, SimdFloat64};
 
@@ -11,45 +13,58 @@ pub use core::{SimdMathF32Core, SimdMathF64Core};
 pub use hyperbolic::{SimdMathF32Hyperbolic, SimdMathF64Hyperbolic};
 pub use inverse_hyperbolic::{SimdMathF32InverseHyperbolic, SimdMathF64InverseHyperbolic};
 pub use inverse_trig::{SimdMathF32InverseTrig, SimdMathF64InverseTrig};
+#[cfg(feature = "special")]
+pub use special::{SimdMathF32Special, SimdMathF64Special};
 
-/// Full SIMD math surface grouped into family-specific extension traits.
-pub trait SimdMathF32:
-    SimdFloat32
-    + SimdMathF32Core
-    + SimdMathF32InverseTrig
-    + SimdMathF32Hyperbolic
-    + SimdMathF32InverseHyperbolic
-    + SimdMathF32BinaryMisc
-{
+/// Declares an umbrella trait over the listed families, with a blanket impl for every type
+/// that implements all of them.
+macro_rules! define_math_surface {
+    ($name:ident: $base:ident $(+ $family:ident)*) => {
+        /// Full SIMD math surface grouped into family-specific extension traits.
+        pub trait $name: $base $(+ $family)* {}
+
+        impl<T> $name for T where T: $base $(+ $family)* {}
+    };
 }
 
-impl<T> SimdMathF32 for T where
-    T: SimdFloat32
+#[cfg(feature = "special")]
+define_math_surface!(
+    SimdMathF32: SimdFloat32
         + SimdMathF32Core
         + SimdMathF32InverseTrig
         + SimdMathF32Hyperbolic
         + SimdMathF32InverseHyperbolic
         + SimdMathF32BinaryMisc
-{
-}
+        + SimdMathF32Special
+);
 
-/// Full SIMD math surface grouped into family-specific extension traits.
-pub trait SimdMathF64:
-    SimdFloat64
-    + SimdMathF64Core
-    + SimdMathF64InverseTrig
-    + SimdMathF64Hyperbolic
-    + SimdMathF64InverseHyperbolic
-    + SimdMathF64BinaryMisc
-{
-}
+#[cfg(not(feature = "special"))]
+define_math_surface!(
+    SimdMathF32: SimdFloat32
+        + SimdMathF32Core
+        + SimdMathF32InverseTrig
+        + SimdMathF32Hyperbolic
+        + SimdMathF32InverseHyperbolic
+        + SimdMathF32BinaryMisc
+);
 
-impl<T> SimdMathF64 for T where
-    T: SimdFloat64
+#[cfg(feature = "special")]
+define_math_surface!(
+    SimdMathF64: SimdFloat64
         + SimdMathF64Core
         + SimdMathF64InverseTrig
         + SimdMathF64Hyperbolic
         + SimdMathF64InverseHyperbolic
         + SimdMathF64BinaryMisc
-{
-}
+        + SimdMathF64Special
+);
+
+#[cfg(not(feature = "special"))]
+define_math_surface!(
+    SimdMathF64: SimdFloat64
+        + SimdMathF64Core
+        + SimdMathF64InverseTrig
+        + SimdMathF64Hyperbolic
+        + SimdMathF64InverseHyperbolic
+        + SimdMathF64BinaryMisc
+);
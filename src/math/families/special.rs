@@ -0,0 +1,82 @@
+use crate::math::{f32, f64};
+use crate::{Simd, SimdFloat32, SimdFloat64};
+
+pub trait SimdMathF32Special: SimdFloat32 {
+    /// Error function.
+    #[inline(always)]
+    fn erf_u35(self) -> Self
+    where
+        Self::Engine: Simd<Vf32 = Self>,
+    {
+        f32::erf_u35(self)
+    }
+
+    /// Complementary error function, `1 - erf(x)` without the cancellation for large `x`.
+    #[inline(always)]
+    fn erfc_u35(self) -> Self
+    where
+        Self::Engine: Simd<Vf32 = Self>,
+    {
+        f32::erfc_u35(self)
+    }
+
+    /// Gamma function; non-positive integers are poles and return NaN, zeros return a signed infinity.
+    #[inline(always)]
+    fn tgamma_u35(self) -> Self
+    where
+        Self::Engine: Simd<Vf32 = Self>,
+    {
+        f32::tgamma_u35(self)
+    }
+
+    /// Natural log of `|gamma(x)|`.
+    #[inline(always)]
+    fn lgamma_u35(self) -> Self
+    where
+        Self::Engine: Simd<Vf32 = Self>,
+    {
+        f32::lgamma_u35(self)
+    }
+}
+
+impl<T: SimdFloat32> SimdMathF32Special for T {}
+
+pub trait SimdMathF64Special: SimdFloat64 {
+    /// Error function.
+    #[inline(always)]
+    fn erf_u35(self) -> Self
+    where
+        Self::Engine: Simd<Vf64 = Self>,
+    {
+        f64::erf_u35(self)
+    }
+
+    /// Complementary error function, `1 - erf(x)` without the cancellation for large `x`.
+    #[inline(always)]
+    fn erfc_u35(self) -> Self
+    where
+        Self::Engine: Simd<Vf64 = Self>,
+    {
+        f64::erfc_u35(self)
+    }
+
+    /// Gamma function; non-positive integers are poles and return NaN, zeros return a signed infinity.
+    #[inline(always)]
+    fn tgamma_u35(self) -> Self
+    where
+        Self::Engine: Simd<Vf64 = Self>,
+    {
+        f64::tgamma_u35(self)
+    }
+
+    /// Natural log of `|gamma(x)|`.
+    #[inline(always)]
+    fn lgamma_u35(self) -> Self
+    where
+        Self::Engine: Simd<Vf64 = Self>,
+    {
+        f64::lgamma_u35(self)
+    }
+}
+
+impl<T: SimdFloat64> SimdMathF64Special for T {}
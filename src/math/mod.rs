@@ -6,15 +6,22 @@
 //! `f32` `log2_u35` / `exp2_u35` flow through a layered kernel stack:
 //! portable SIMD kernels first, optional backend overrides where available,
 //! and scalar-lane fallback for exceptional semantics.
-//! `ln_u35` / `exp_u35` currently stay on deterministic scalar references.
+//! `ln_u35` / `exp_u35` share those polynomials behind their own range reduction;
+//! `ln_u10` / `exp_u10` keep the deterministic scalar references for callers that need 1 ulp.
+//! `pow_u35`, `cbrt_u35`, `exp10_u35`, `expm1_u35` and `log1p_u35` reuse the same ln/exp
+//! reductions and hand pow special cases and out-of-range lanes to the scalar references.
 //! `sin_u35` / `cos_u35` / `tan_u35` use portable SIMD range reduction with
 //! centralized fallback for non-finite, very-large, and tan-pole-adjacent lanes.
+//! `sincos_u35` shares one reduction between both outputs, and `sinpi_u35` / `cospi_u35` /
+//! `tanpi_u35` reduce exactly in half turns so integers and half-integers come out exact.
 //! `sinh_u35` / `cosh_u35` / `tanh_u35` now use family-local portable SIMD
 //! kernels with centralized scalar patching for exceptional lanes.
+//! `erf_u35` / `erfc_u35` / `tgamma_u35` / `lgamma_u35` evaluate rational and Stirling
+//! approximations on the exp/log backbone and patch poles and far tails to libm references;
+//! they sit behind the `special` feature so default builds do not depend on libm.
 //! The stabilized `f64` map is intentionally mixed:
-//! portable SIMD for the revived core log/exp family, inverse trig, and several binary-misc kernels,
-//! scalar-reference for the current losing trig family plus selected `f64` holdouts such as `cosh_u35`
-//! and `atanh_u35`,
+//! portable SIMD for the revived core log/exp family, trig, inverse trig, and several binary-misc
+//! kernels, scalar-reference for selected `f64` holdouts such as `cosh_u35` and `atanh_u35`,
 //! and hybrid keep decisions where SIMD structure still relies on scalar sub-ops.
 //!
 //! Structure notes:
@@ -36,10 +43,12 @@ mod f32;
 mod f64;
 mod families;
 mod map;
-mod scalar;
+pub(crate) mod scalar;
 
 pub use families::{
     SimdMathF32, SimdMathF32BinaryMisc, SimdMathF32Core, SimdMathF32Hyperbolic,
     SimdMathF32InverseHyperbolic, SimdMathF32InverseTrig, SimdMathF64, SimdMathF64BinaryMisc,
     SimdMathF64Core, SimdMathF64Hyperbolic, SimdMathF64InverseHyperbolic, SimdMathF64InverseTrig,
 };
+#[cfg(feature = "special")]
+pub use families::{SimdMathF32Special, SimdMathF64Special};
@@ -20,6 +20,53 @@ pub fn exp_u35_f32(x: f32) -> f32 {
     x.m_exp()
 }
 
+#[inline(always)]
+pub fn ln_u10_f32(x: f32) -> f32 {
+    x.m_ln()
+}
+
+#[inline(always)]
+pub fn exp_u10_f32(x: f32) -> f32 {
+    x.m_exp()
+}
+
+#[inline(always)]
+pub fn pow_u35_f32(x: f32, y: f32) -> f32 {
+    x.m_powf(y)
+}
+
+/// `pow` of `|x|` with every `i32` exponent exact, which `f32` can't hold beyond 2^24, and the
+/// sign put back from the parity of `n` itself.
+#[inline(always)]
+pub fn powi_f32(x: f32, n: i32) -> f32 {
+    let magnitude = (f64::from(x.m_abs()).m_powf(f64::from(n))) as f32;
+    if n & 1 != 0 {
+        magnitude.copysign(x)
+    } else {
+        magnitude
+    }
+}
+
+#[inline(always)]
+pub fn cbrt_u35_f32(x: f32) -> f32 {
+    x.m_cbrt()
+}
+
+#[inline(always)]
+pub fn exp10_u35_f32(x: f32) -> f32 {
+    x.m_exp10()
+}
+
+#[inline(always)]
+pub fn expm1_u35_f32(x: f32) -> f32 {
+    x.m_exp_m1()
+}
+
+#[inline(always)]
+pub fn log1p_u35_f32(x: f32) -> f32 {
+    x.m_ln_1p()
+}
+
 #[inline(always)]
 pub fn sin_u35_f32(x: f32) -> f32 {
     x.m_sin()
@@ -35,6 +82,21 @@ pub fn tan_u35_f32(x: f32) -> f32 {
     x.m_tan()
 }
 
+#[inline(always)]
+pub fn sinpi_u35_f32(x: f32) -> f32 {
+    sinpi_u35_f64(f64::from(x)) as f32
+}
+
+#[inline(always)]
+pub fn cospi_u35_f32(x: f32) -> f32 {
+    cospi_u35_f64(f64::from(x)) as f32
+}
+
+#[inline(always)]
+pub fn tanpi_u35_f32(x: f32) -> f32 {
+    tanpi_u35_f64(f64::from(x)) as f32
+}
+
 #[inline(always)]
 pub fn log2_u35_f64(x: f64) -> f64 {
     x.m_log2()
@@ -55,6 +117,52 @@ pub fn exp_u35_f64(x: f64) -> f64 {
     x.m_exp()
 }
 
+#[inline(always)]
+pub fn ln_u10_f64(x: f64) -> f64 {
+    x.m_ln()
+}
+
+#[inline(always)]
+pub fn exp_u10_f64(x: f64) -> f64 {
+    x.m_exp()
+}
+
+#[inline(always)]
+pub fn pow_u35_f64(x: f64, y: f64) -> f64 {
+    x.m_powf(y)
+}
+
+/// `pow` of `|x|`, with the sign put back from the parity of `n`.
+#[inline(always)]
+pub fn powi_f64(x: f64, n: i32) -> f64 {
+    let magnitude = x.m_abs().m_powf(f64::from(n));
+    if n & 1 != 0 {
+        magnitude.copysign(x)
+    } else {
+        magnitude
+    }
+}
+
+#[inline(always)]
+pub fn cbrt_u35_f64(x: f64) -> f64 {
+    x.m_cbrt()
+}
+
+#[inline(always)]
+pub fn exp10_u35_f64(x: f64) -> f64 {
+    x.m_exp10()
+}
+
+#[inline(always)]
+pub fn expm1_u35_f64(x: f64) -> f64 {
+    x.m_exp_m1()
+}
+
+#[inline(always)]
+pub fn log1p_u35_f64(x: f64) -> f64 {
+    x.m_ln_1p()
+}
+
 #[inline(always)]
 pub fn sin_u35_f64(x: f64) -> f64 {
     x.m_sin()
@@ -69,3 +177,57 @@ pub fn cos_u35_f64(x: f64) -> f64 {
 pub fn tan_u35_f64(x: f64) -> f64 {
     x.m_tan()
 }
+
+/// `(sin(pi * |x|), cos(pi * |x|))` with exact zeros at integers and half-integers.
+///
+/// `|x| mod 2` and the half-turn remainder are both exact, so only `pi * r` rounds.
+#[inline(always)]
+fn sin_cos_pi_abs_f64(x: f64) -> (f64, f64) {
+    let reduced = x.m_abs() % 2.0;
+    let half_turns = (reduced * 2.0).m_round();
+    let r = reduced - half_turns * 0.5;
+    let (s, c) = (r * core::f64::consts::PI).m_sin_cos();
+    let (s, c) = match half_turns as u8 & 3 {
+        0 => (s, c),
+        1 => (c, -s),
+        2 => (-s, -c),
+        _ => (-c, s),
+    };
+    // Adding +0 turns the -0 that negated quadrants produce into +0.
+    (s + 0.0, c + 0.0)
+}
+
+#[inline(always)]
+pub fn sinpi_u35_f64(x: f64) -> f64 {
+    if !x.is_finite() {
+        return f64::NAN;
+    }
+    let (s, _) = sin_cos_pi_abs_f64(x);
+    if x.is_sign_negative() {
+        -s
+    } else {
+        s
+    }
+}
+
+#[inline(always)]
+pub fn cospi_u35_f64(x: f64) -> f64 {
+    if !x.is_finite() {
+        return f64::NAN;
+    }
+    sin_cos_pi_abs_f64(x).1
+}
+
+#[inline(always)]
+pub fn tanpi_u35_f64(x: f64) -> f64 {
+    if !x.is_finite() {
+        return f64::NAN;
+    }
+    let (s, c) = sin_cos_pi_abs_f64(x);
+    let t = s / c;
+    if x.is_sign_negative() {
+        -t
+    } else {
+        t
+    }
+}
@@ -3,14 +3,20 @@ mod core;
 mod hyperbolic;
 mod inverse_hyperbolic;
 mod inverse_trig;
+#[cfg(feature = "special")]
+mod special;
 
 pub use binary_misc::{
     atan2_u35_f32, atan2_u35_f64, fmod_f32, fmod_f64, hypot_u35_f32, hypot_u35_f64, log10_u35_f32,
     log10_u35_f64,
 };
 pub use core::{
-    cos_u35_f32, cos_u35_f64, exp2_u35_f32, exp2_u35_f64, exp_u35_f32, exp_u35_f64, ln_u35_f32,
-    ln_u35_f64, log2_u35_f32, log2_u35_f64, sin_u35_f32, sin_u35_f64, tan_u35_f32, tan_u35_f64,
+    cbrt_u35_f32, cbrt_u35_f64, cos_u35_f32, cos_u35_f64, cospi_u35_f32, cospi_u35_f64,
+    exp10_u35_f32, exp10_u35_f64, exp2_u35_f32, exp2_u35_f64, exp_u10_f32, exp_u10_f64,
+    exp_u35_f32, exp_u35_f64, expm1_u35_f32, expm1_u35_f64, ln_u10_f32, ln_u10_f64, ln_u35_f32,
+    ln_u35_f64, log1p_u35_f32, log1p_u35_f64, log2_u35_f32, log2_u35_f64, pow_u35_f32, pow_u35_f64,
+    powi_f32, powi_f64, sin_u35_f32, sin_u35_f64, sinpi_u35_f32, sinpi_u35_f64, tan_u35_f32,
+    tan_u35_f64, tanpi_u35_f32, tanpi_u35_f64,
 };
 pub use hyperbolic::{
     cosh_u35_f32, cosh_u35_f64, sinh_u35_f32, sinh_u35_f64, tanh_u35_f32, tanh_u35_f64,
@@ -21,3 +27,8 @@ pub use inverse_hyperbolic::{
 pub use inverse_trig::{
     acos_u35_f32, acos_u35_f64, asin_u35_f32, asin_u35_f64, atan_u35_f32, atan_u35_f64,
 };
+#[cfg(feature = "special")]
+pub use special::{
+    erf_u35_f32, erf_u35_f64, erfc_u35_f32, erfc_u35_f64, lgamma_u35_f32, lgamma_u35_f64,
+    tgamma_u35_f32, tgamma_u35_f64,
+};
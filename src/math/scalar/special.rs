@@ -0,0 +1,41 @@
+use crate::libm_ext::FloatExt;
+
+#[inline(always)]
+pub fn erf_u35_f32(x: f32) -> f32 {
+    x.m_erf()
+}
+
+#[inline(always)]
+pub fn erfc_u35_f32(x: f32) -> f32 {
+    x.m_erfc()
+}
+
+#[inline(always)]
+pub fn tgamma_u35_f32(x: f32) -> f32 {
+    x.m_tgamma()
+}
+
+#[inline(always)]
+pub fn lgamma_u35_f32(x: f32) -> f32 {
+    x.m_lgamma()
+}
+
+#[inline(always)]
+pub fn erf_u35_f64(x: f64) -> f64 {
+    x.m_erf()
+}
+
+#[inline(always)]
+pub fn erfc_u35_f64(x: f64) -> f64 {
+    x.m_erfc()
+}
+
+#[inline(always)]
+pub fn tgamma_u35_f64(x: f64) -> f64 {
+    x.m_tgamma()
+}
+
+#[inline(always)]
+pub fn lgamma_u35_f64(x: f64) -> f64 {
+    x.m_lgamma()
+}
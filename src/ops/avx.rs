@@ -0,0 +1,993 @@
+use super::*;
+use crate::engines::avx::Avx;
+
+// AVX only widened the float instructions to 256 bits, so integer operations (and the float ones
+// with no 256 bit form, like the unfused `mul_add`) run the `Sse41` implementation on each 128 bit
+// half. Operations that move lanes across the halves are composed explicitly below.
+
+/// A 256 bit register viewed as its two 128 bit halves.
+trait Halves: Copy {
+    type Half: Copy;
+
+    unsafe fn low(self) -> Self::Half;
+    unsafe fn high(self) -> Self::Half;
+    unsafe fn join(low: Self::Half, high: Self::Half) -> Self;
+}
+
+impl Halves for __m256i {
+    type Half = __m128i;
+
+    #[inline(always)]
+    unsafe fn low(self) -> __m128i {
+        _mm256_castsi256_si128(self)
+    }
+
+    #[inline(always)]
+    unsafe fn high(self) -> __m128i {
+        _mm256_extractf128_si256::<1>(self)
+    }
+
+    #[inline(always)]
+    unsafe fn join(low: __m128i, high: __m128i) -> Self {
+        _mm256_setr_m128i(low, high)
+    }
+}
+
+impl Halves for __m256 {
+    type Half = __m128;
+
+    #[inline(always)]
+    unsafe fn low(self) -> __m128 {
+        _mm256_castps256_ps128(self)
+    }
+
+    #[inline(always)]
+    unsafe fn high(self) -> __m128 {
+        _mm256_extractf128_ps::<1>(self)
+    }
+
+    #[inline(always)]
+    unsafe fn join(low: __m128, high: __m128) -> Self {
+        _mm256_setr_m128(low, high)
+    }
+}
+
+impl Halves for __m256d {
+    type Half = __m128d;
+
+    #[inline(always)]
+    unsafe fn low(self) -> __m128d {
+        _mm256_castpd256_pd128(self)
+    }
+
+    #[inline(always)]
+    unsafe fn high(self) -> __m128d {
+        _mm256_extractf128_pd::<1>(self)
+    }
+
+    #[inline(always)]
+    unsafe fn join(low: __m128d, high: __m128d) -> Self {
+        _mm256_setr_m128d(low, high)
+    }
+}
+
+/// Implements lane-wise operations whose arguments and result are all `$vec` by running the
+/// `Sse41` operation on each half.
+macro_rules! on_halves {
+    ($scalar:ident, $vec:ty { $( $name:ident($($arg:ident),+); )+ }) => {
+        impl Ops<Avx, $scalar> {
+            $(
+                with_feature_flag!(
+                    Avx,
+                    #[inline]
+                    pub unsafe fn $name($($arg: $vec),+) -> $vec {
+                        <$vec>::join(
+                            Ops::<Sse41, $scalar>::$name($($arg.low()),+),
+                            Ops::<Sse41, $scalar>::$name($($arg.high()),+),
+                        )
+                    }
+                );
+            )+
+        }
+    };
+}
+
+/// Implements operations that map directly onto a 256 bit AVX instruction.
+macro_rules! native {
+    ($scalar:ident { $( fn $name:ident($($arg:ident: $arg_ty:ty),*) $(-> $ret:ty)? $body:block )+ }) => {
+        impl Ops<Avx, $scalar> {
+            $(
+                with_feature_flag!(
+                    Avx,
+                    #[inline]
+                    pub unsafe fn $name($($arg: $arg_ty),*) $(-> $ret)? $body
+                );
+            )+
+        }
+    };
+}
+
+/// Everything the integer lane types share: the lane-wise maths, shifts, loads and stores.
+macro_rules! int_ops {
+    ($scalar:ident, $lane_ty:ident, $set1:ident) => {
+        on_halves!($scalar, __m256i {
+            add(a, b);
+            sub(a, b);
+            mul(a, b);
+            min(a, b);
+            max(a, b);
+            eq(a, b);
+            neq(a, b);
+            lt(a, b);
+            lte(a, b);
+            gt(a, b);
+            gte(a, b);
+            saturating_add(a, b);
+            saturating_sub(a, b);
+            avg(a, b);
+            shl_lanes(a, b);
+            shr_lanes(a, b);
+            shr_arithmetic_lanes(a, b);
+            rotate_left_lanes(a, b);
+            rotate_right_lanes(a, b);
+            abs(a);
+            count_ones(a);
+            leading_zeros(a);
+            trailing_zeros(a);
+            reverse_bits(a);
+            blendv(a, b, mask);
+        });
+
+        impl Ops<Avx, $scalar> {
+            with_feature_flag!(
+                Avx,
+                #[inline]
+                pub unsafe fn shl(a: __m256i, rhs: i32) -> __m256i {
+                    __m256i::join(
+                        Ops::<Sse41, $scalar>::shl(a.low(), rhs),
+                        Ops::<Sse41, $scalar>::shl(a.high(), rhs),
+                    )
+                }
+            );
+
+            with_feature_flag!(
+                Avx,
+                #[inline]
+                pub unsafe fn shr(a: __m256i, rhs: i32) -> __m256i {
+                    __m256i::join(
+                        Ops::<Sse41, $scalar>::shr(a.low(), rhs),
+                        Ops::<Sse41, $scalar>::shr(a.high(), rhs),
+                    )
+                }
+            );
+
+            with_feature_flag!(
+                Avx,
+                #[inline]
+                pub unsafe fn shr_arithmetic(a: __m256i, rhs: i32) -> __m256i {
+                    __m256i::join(
+                        Ops::<Sse41, $scalar>::shr_arithmetic(a.low(), rhs),
+                        Ops::<Sse41, $scalar>::shr_arithmetic(a.high(), rhs),
+                    )
+                }
+            );
+
+            with_feature_flag!(
+                Avx,
+                #[inline]
+                pub unsafe fn rotate_left(a: __m256i, rhs: i32) -> __m256i {
+                    __m256i::join(
+                        Ops::<Sse41, $scalar>::rotate_left(a.low(), rhs),
+                        Ops::<Sse41, $scalar>::rotate_left(a.high(), rhs),
+                    )
+                }
+            );
+
+            with_feature_flag!(
+                Avx,
+                #[inline]
+                pub unsafe fn rotate_right(a: __m256i, rhs: i32) -> __m256i {
+                    __m256i::join(
+                        Ops::<Sse41, $scalar>::rotate_right(a.low(), rhs),
+                        Ops::<Sse41, $scalar>::rotate_right(a.high(), rhs),
+                    )
+                }
+            );
+
+            with_feature_flag!(
+                Avx,
+                #[inline]
+                pub unsafe fn shl_const<const BY: i32>(a: __m256i) -> __m256i {
+                    __m256i::join(
+                        Ops::<Sse41, $scalar>::shl_const::<BY>(a.low()),
+                        Ops::<Sse41, $scalar>::shl_const::<BY>(a.high()),
+                    )
+                }
+            );
+
+            with_feature_flag!(
+                Avx,
+                #[inline]
+                pub unsafe fn shr_const<const BY: i32>(a: __m256i) -> __m256i {
+                    __m256i::join(
+                        Ops::<Sse41, $scalar>::shr_const::<BY>(a.low()),
+                        Ops::<Sse41, $scalar>::shr_const::<BY>(a.high()),
+                    )
+                }
+            );
+
+            with_feature_flag!(
+                Avx,
+                #[inline]
+                pub unsafe fn popcount_total(a: __m256i) -> u32 {
+                    Ops::<Sse41, $scalar>::popcount_total(a.low())
+                        + Ops::<Sse41, $scalar>::popcount_total(a.high())
+                }
+            );
+        }
+
+        native!($scalar {
+            fn zeroes() -> __m256i {
+                _mm256_setzero_si256()
+            }
+            fn set1(val: $scalar) -> __m256i {
+                $set1(val as $lane_ty)
+            }
+            fn load_unaligned(ptr: *const $scalar) -> __m256i {
+                _mm256_loadu_si256(ptr as *const __m256i)
+            }
+            fn load_aligned(ptr: *const $scalar) -> __m256i {
+                _mm256_load_si256(ptr as *const __m256i)
+            }
+            fn store_unaligned(ptr: *mut $scalar, a: __m256i) {
+                _mm256_storeu_si256(ptr as *mut __m256i, a)
+            }
+            fn store_aligned(ptr: *mut $scalar, a: __m256i) {
+                _mm256_store_si256(ptr as *mut __m256i, a)
+            }
+            fn bitcast_binary(a: __m256i) -> __m256 {
+                _mm256_castsi256_ps(a)
+            }
+        });
+
+        paste::item! {
+            native!(binary {
+                fn [<bitcast_ $scalar>](a: __m256) -> __m256i {
+                    _mm256_castps_si256(a)
+                }
+            });
+        }
+    };
+}
+
+int_ops!(i8, i8, _mm256_set1_epi8);
+int_ops!(i16, i16, _mm256_set1_epi16);
+int_ops!(i32, i32, _mm256_set1_epi32);
+int_ops!(i64, i64, _mm256_set1_epi64x);
+int_ops!(u8, i8, _mm256_set1_epi8);
+int_ops!(u16, i16, _mm256_set1_epi16);
+int_ops!(u32, i32, _mm256_set1_epi32);
+int_ops!(u64, i64, _mm256_set1_epi64x);
+
+on_halves!(i16, __m256i {
+    mul_hi(a, b);
+    mul_add_pairs(a, b);
+});
+on_halves!(i32, __m256i {
+    mul_hi(a, b);
+});
+on_halves!(u8, __m256i {
+    mul_add_pairs(a, b);
+    sad(a, b);
+});
+on_halves!(u16, __m256i {
+    mul_hi(a, b);
+});
+on_halves!(u32, __m256i {
+    mul_hi(a, b);
+});
+
+/// Narrowing packs both halves of `a` into the low half of the result and both halves of `b`
+/// into the high half.
+macro_rules! narrowing {
+    ($scalar:ident { $($name:ident),+ }) => {
+        impl Ops<Avx, $scalar> {
+            $(
+                with_feature_flag!(
+                    Avx,
+                    #[inline]
+                    pub unsafe fn $name(a: __m256i, b: __m256i) -> __m256i {
+                        __m256i::join(
+                            Ops::<Sse41, $scalar>::$name(a.low(), a.high()),
+                            Ops::<Sse41, $scalar>::$name(b.low(), b.high()),
+                        )
+                    }
+                );
+            )+
+        }
+    };
+}
+
+narrowing!(i16 {
+    narrow_i8,
+    saturating_narrow_i8,
+    saturating_narrow_u8
+});
+narrowing!(i32 {
+    narrow_i16,
+    saturating_narrow_i16,
+    saturating_narrow_u16
+});
+narrowing!(i64 {
+    narrow_i32,
+    saturating_narrow_i32,
+    saturating_narrow_u32
+});
+
+/// Widening operations return the low half's results followed by the high half's, which is
+/// already lane order once each pair is joined.
+macro_rules! widening {
+    ($scalar:ident, $ret:ty { $( $name:ident($($arg:ident),+); )+ }) => {
+        impl Ops<Avx, $scalar> {
+            $(
+                with_feature_flag!(
+                    Avx,
+                    #[inline]
+                    pub unsafe fn $name($($arg: __m256i),+) -> ($ret, $ret) {
+                        let low = Ops::<Sse41, $scalar>::$name($($arg.low()),+);
+                        let high = Ops::<Sse41, $scalar>::$name($($arg.high()),+);
+                        (<$ret>::join(low.0, low.1), <$ret>::join(high.0, high.1))
+                    }
+                );
+            )+
+        }
+    };
+}
+
+widening!(i8, __m256i {
+    extend_i16(a);
+    unsigned_extend_i16(a);
+});
+widening!(i16, __m256i {
+    extend_i32(a);
+    unsigned_extend_i32(a);
+    mul_widening(a, b);
+});
+widening!(i32, __m256i {
+    extend_i64(a);
+    unsigned_extend_i64(a);
+    mul_widening(a, b);
+});
+widening!(u8, __m256i {
+    extend_u16(a);
+});
+widening!(u16, __m256i {
+    extend_u32(a);
+});
+widening!(u32, __m256i {
+    extend_u64(a);
+});
+
+/// `permute` picks a lane from each half with the `Sse41` shuffle and then chooses between them
+/// on the index bit that addresses the high half. `permute2` does the same across two vectors.
+macro_rules! permutes {
+    ($scalar:ident, $width:literal) => {
+        impl Ops<Avx, $scalar> {
+            with_feature_flag!(
+                Avx,
+                #[inline]
+                unsafe fn index_bit_set(idx: __m256i, bit: $scalar) -> __m256i {
+                    let bit = Ops::<Sse41, $scalar>::set1(bit);
+                    __m256i::join(
+                        Ops::<Sse41, $scalar>::eq(_mm_and_si128(idx.low(), bit), bit),
+                        Ops::<Sse41, $scalar>::eq(_mm_and_si128(idx.high(), bit), bit),
+                    )
+                }
+            );
+
+            with_feature_flag!(
+                Avx,
+                #[inline]
+                pub unsafe fn permute(a: __m256i, idx: __m256i) -> __m256i {
+                    let from_low = __m256i::join(
+                        Ops::<Sse41, $scalar>::permute(a.low(), idx.low()),
+                        Ops::<Sse41, $scalar>::permute(a.low(), idx.high()),
+                    );
+                    let from_high = __m256i::join(
+                        Ops::<Sse41, $scalar>::permute(a.high(), idx.low()),
+                        Ops::<Sse41, $scalar>::permute(a.high(), idx.high()),
+                    );
+                    Self::blendv(from_low, from_high, Self::index_bit_set(idx, $width / 2))
+                }
+            );
+
+            with_feature_flag!(
+                Avx,
+                #[inline]
+                pub unsafe fn permute2(a: __m256i, b: __m256i, idx: __m256i) -> __m256i {
+                    let from_a = Self::permute(a, idx);
+                    let from_b = Self::permute(b, idx);
+                    Self::blendv(from_a, from_b, Self::index_bit_set(idx, $width))
+                }
+            );
+        }
+    };
+}
+
+permutes!(i8, 32);
+permutes!(i16, 16);
+permutes!(i32, 8);
+permutes!(i64, 4);
+
+/// Masked loads and stores address the high half `$half` lanes further along, and stores write
+/// the low half first.
+macro_rules! masked_io {
+    ($scalar:ident, $half:literal) => {
+        impl Ops<Avx, $scalar> {
+            with_feature_flag!(
+                Avx,
+                #[inline]
+                pub unsafe fn masked_load(ptr: *const $scalar, mask: __m256i) -> __m256i {
+                    __m256i::join(
+                        Ops::<Sse41, $scalar>::masked_load(ptr, mask.low()),
+                        Ops::<Sse41, $scalar>::masked_load(ptr.add($half), mask.high()),
+                    )
+                }
+            );
+
+            with_feature_flag!(
+                Avx,
+                #[inline]
+                pub unsafe fn masked_store(ptr: *mut $scalar, a: __m256i, mask: __m256i) {
+                    Ops::<Sse41, $scalar>::masked_store(ptr, a.low(), mask.low());
+                    Ops::<Sse41, $scalar>::masked_store(ptr.add($half), a.high(), mask.high());
+                }
+            );
+        }
+    };
+}
+
+masked_io!(i8, 16);
+masked_io!(i16, 8);
+masked_io!(i32, 4);
+masked_io!(i64, 2);
+
+/// Gathers and scatters share one base pointer between the halves; scatters write the low half
+/// first so the highest lane still wins on repeated offsets.
+macro_rules! gather_scatter {
+    ($scalar:ident) => {
+        impl Ops<Avx, $scalar> {
+            with_feature_flag!(
+                Avx,
+                #[inline]
+                pub unsafe fn gather(base: *const $scalar, idx: __m256i) -> __m256i {
+                    __m256i::join(
+                        Ops::<Sse41, $scalar>::gather(base, idx.low()),
+                        Ops::<Sse41, $scalar>::gather(base, idx.high()),
+                    )
+                }
+            );
+
+            with_feature_flag!(
+                Avx,
+                #[inline]
+                pub unsafe fn gather_masked(
+                    src: __m256i,
+                    base: *const $scalar,
+                    idx: __m256i,
+                    mask: __m256i,
+                ) -> __m256i {
+                    __m256i::join(
+                        Ops::<Sse41, $scalar>::gather_masked(
+                            src.low(),
+                            base,
+                            idx.low(),
+                            mask.low(),
+                        ),
+                        Ops::<Sse41, $scalar>::gather_masked(
+                            src.high(),
+                            base,
+                            idx.high(),
+                            mask.high(),
+                        ),
+                    )
+                }
+            );
+
+            with_feature_flag!(
+                Avx,
+                #[inline]
+                pub unsafe fn scatter(base: *mut $scalar, idx: __m256i, a: __m256i) {
+                    Ops::<Sse41, $scalar>::scatter(base, idx.low(), a.low());
+                    Ops::<Sse41, $scalar>::scatter(base, idx.high(), a.high());
+                }
+            );
+
+            with_feature_flag!(
+                Avx,
+                #[inline]
+                pub unsafe fn scatter_masked(
+                    base: *mut $scalar,
+                    idx: __m256i,
+                    a: __m256i,
+                    mask: __m256i,
+                ) {
+                    Ops::<Sse41, $scalar>::scatter_masked(base, idx.low(), a.low(), mask.low());
+                    Ops::<Sse41, $scalar>::scatter_masked(base, idx.high(), a.high(), mask.high());
+                }
+            );
+        }
+    };
+}
+
+gather_scatter!(i32);
+gather_scatter!(i64);
+
+// The 32 bit indices of the 64 bit lanes are all in the low half; each Sse41 half takes the low
+// two of the index vector it is given.
+impl Ops<Avx, i64> {
+    with_feature_flag!(
+        Avx,
+        #[inline]
+        pub unsafe fn gather_i32(base: *const i64, idx: __m256i) -> __m256i {
+            let idx = idx.low();
+            __m256i::join(
+                Ops::<Sse41, i64>::gather_i32(base, idx),
+                Ops::<Sse41, i64>::gather_i32(base, _mm_unpackhi_epi64(idx, idx)),
+            )
+        }
+    );
+
+    with_feature_flag!(
+        Avx,
+        #[inline]
+        pub unsafe fn gather_masked_i32(
+            src: __m256i,
+            base: *const i64,
+            idx: __m256i,
+            mask: __m256i,
+        ) -> __m256i {
+            let idx = idx.low();
+            __m256i::join(
+                Ops::<Sse41, i64>::gather_masked_i32(src.low(), base, idx, mask.low()),
+                Ops::<Sse41, i64>::gather_masked_i32(
+                    src.high(),
+                    base,
+                    _mm_unpackhi_epi64(idx, idx),
+                    mask.high(),
+                ),
+            )
+        }
+    );
+
+    with_feature_flag!(
+        Avx,
+        #[inline]
+        pub unsafe fn scatter_i32(base: *mut i64, idx: __m256i, a: __m256i) {
+            let idx = idx.low();
+            Ops::<Sse41, i64>::scatter_i32(base, idx, a.low());
+            Ops::<Sse41, i64>::scatter_i32(base, _mm_unpackhi_epi64(idx, idx), a.high());
+        }
+    );
+
+    with_feature_flag!(
+        Avx,
+        #[inline]
+        pub unsafe fn scatter_masked_i32(base: *mut i64, idx: __m256i, a: __m256i, mask: __m256i) {
+            let idx = idx.low();
+            let high = _mm_unpackhi_epi64(idx, idx);
+            Ops::<Sse41, i64>::scatter_masked_i32(base, idx, a.low(), mask.low());
+            Ops::<Sse41, i64>::scatter_masked_i32(base, high, a.high(), mask.high());
+        }
+    );
+}
+
+impl Ops<Avx, i8> {
+    with_feature_flag!(
+        Avx,
+        #[inline]
+        pub unsafe fn get_mask(val: __m256i) -> u32 {
+            Ops::<Sse41, i8>::get_mask(val.low()) | (Ops::<Sse41, i8>::get_mask(val.high()) << 16)
+        }
+    );
+
+    with_feature_flag!(
+        Avx,
+        #[inline]
+        pub unsafe fn is_truthy(a: __m256i) -> bool {
+            Ops::<Sse41, i8>::is_truthy(a.low()) && Ops::<Sse41, i8>::is_truthy(a.high())
+        }
+    );
+
+    with_feature_flag!(
+        Avx,
+        #[inline]
+        pub unsafe fn lookup16(table: *const i8, indices: __m256i) -> __m256i {
+            __m256i::join(
+                Ops::<Sse41, i8>::lookup16(table, indices.low()),
+                Ops::<Sse41, i8>::lookup16(table, indices.high()),
+            )
+        }
+    );
+
+    with_feature_flag!(
+        Avx,
+        #[inline]
+        pub unsafe fn lookup32(table: *const i8, indices: __m256i) -> __m256i {
+            __m256i::join(
+                Ops::<Sse41, i8>::lookup32(table, indices.low()),
+                Ops::<Sse41, i8>::lookup32(table, indices.high()),
+            )
+        }
+    );
+
+    with_feature_flag!(
+        Avx,
+        #[inline]
+        pub unsafe fn lookup64(table: *const i8, indices: __m256i) -> __m256i {
+            __m256i::join(
+                Ops::<Sse41, i8>::lookup64(table, indices.low()),
+                Ops::<Sse41, i8>::lookup64(table, indices.high()),
+            )
+        }
+    );
+}
+
+/// `bitmask` places the high half's bits above the `$half` bits of the low half.
+macro_rules! bitmask {
+    ($scalar:ident, $half:literal) => {
+        impl Ops<Avx, $scalar> {
+            with_feature_flag!(
+                Avx,
+                #[inline]
+                pub unsafe fn bitmask(a: __m256i) -> u64 {
+                    Ops::<Sse41, $scalar>::bitmask(a.low())
+                        | (Ops::<Sse41, $scalar>::bitmask(a.high()) << $half)
+                }
+            );
+        }
+    };
+}
+
+bitmask!(i8, 16);
+bitmask!(i16, 8);
+bitmask!(i32, 4);
+bitmask!(i64, 2);
+
+impl Ops<Avx, i64> {
+    with_feature_flag!(
+        Avx,
+        #[inline]
+        pub unsafe fn horizontal_add(val: __m256i) -> i64 {
+            Ops::<Sse41, i64>::horizontal_add(val.low())
+                .wrapping_add(Ops::<Sse41, i64>::horizontal_add(val.high()))
+        }
+    );
+}
+
+native!(i32 {
+    fn cast_f32(a: __m256i) -> __m256 {
+        _mm256_cvtepi32_ps(a)
+    }
+    fn bitcast_f32(a: __m256i) -> __m256 {
+        _mm256_castsi256_ps(a)
+    }
+    fn extend_f64(val: __m256i) -> (__m256d, __m256d) {
+        let a = _mm256_cvtepi32_pd(val.low());
+        let b = _mm256_cvtepi32_pd(val.high());
+        (a, b)
+    }
+});
+
+impl Ops<Avx, i64> {
+    with_feature_flag!(
+        Avx,
+        #[inline]
+        pub unsafe fn cast_f64(a: __m256i) -> __m256d {
+            __m256d::join(
+                Ops::<Sse41, i64>::cast_f64(a.low()),
+                Ops::<Sse41, i64>::cast_f64(a.high()),
+            )
+        }
+    );
+
+    with_feature_flag!(
+        Avx,
+        #[inline]
+        pub unsafe fn bitcast_f64(a: __m256i) -> __m256d {
+            _mm256_castsi256_pd(a)
+        }
+    );
+}
+
+native!(f32 {
+    fn add(a: __m256, b: __m256) -> __m256 {
+        _mm256_add_ps(a, b)
+    }
+    fn sub(a: __m256, b: __m256) -> __m256 {
+        _mm256_sub_ps(a, b)
+    }
+    fn mul(a: __m256, b: __m256) -> __m256 {
+        _mm256_mul_ps(a, b)
+    }
+    fn div(a: __m256, b: __m256) -> __m256 {
+        _mm256_div_ps(a, b)
+    }
+    fn sqrt(a: __m256) -> __m256 {
+        _mm256_sqrt_ps(a)
+    }
+    fn recip(a: __m256) -> __m256 {
+        _mm256_rcp_ps(a)
+    }
+    fn rsqrt(a: __m256) -> __m256 {
+        _mm256_rsqrt_ps(a)
+    }
+    fn min(a: __m256, b: __m256) -> __m256 {
+        _mm256_min_ps(a, b)
+    }
+    fn max(a: __m256, b: __m256) -> __m256 {
+        _mm256_max_ps(a, b)
+    }
+    fn abs(a: __m256) -> __m256 {
+        _mm256_andnot_ps(_mm256_set1_ps(-0.0), a)
+    }
+    fn round(a: __m256) -> __m256 {
+        _mm256_round_ps(a, _MM_FROUND_TO_NEAREST_INT | _MM_FROUND_NO_EXC)
+    }
+    fn floor(a: __m256) -> __m256 {
+        _mm256_round_ps(a, _MM_FROUND_TO_NEG_INF | _MM_FROUND_NO_EXC)
+    }
+    fn ceil(a: __m256) -> __m256 {
+        _mm256_round_ps(a, _MM_FROUND_TO_POS_INF | _MM_FROUND_NO_EXC)
+    }
+    fn trunc(a: __m256) -> __m256 {
+        _mm256_round_ps(a, _MM_FROUND_TO_ZERO | _MM_FROUND_NO_EXC)
+    }
+    fn fast_round(a: __m256) -> __m256 {
+        Self::round(a)
+    }
+    fn fast_floor(a: __m256) -> __m256 {
+        Self::floor(a)
+    }
+    fn fast_ceil(a: __m256) -> __m256 {
+        Self::ceil(a)
+    }
+    fn eq(a: __m256, b: __m256) -> __m256 {
+        _mm256_cmp_ps(a, b, _CMP_EQ_OQ)
+    }
+    fn neq(a: __m256, b: __m256) -> __m256 {
+        _mm256_cmp_ps(a, b, _CMP_NEQ_UQ)
+    }
+    fn lt(a: __m256, b: __m256) -> __m256 {
+        _mm256_cmp_ps(a, b, _CMP_LT_OQ)
+    }
+    fn lte(a: __m256, b: __m256) -> __m256 {
+        _mm256_cmp_ps(a, b, _CMP_LE_OQ)
+    }
+    fn gt(a: __m256, b: __m256) -> __m256 {
+        _mm256_cmp_ps(a, b, _CMP_GT_OQ)
+    }
+    fn gte(a: __m256, b: __m256) -> __m256 {
+        _mm256_cmp_ps(a, b, _CMP_GE_OQ)
+    }
+    fn blendv(a: __m256, b: __m256, mask: __m256) -> __m256 {
+        _mm256_blendv_ps(a, b, mask)
+    }
+    fn horizontal_add(a: __m256) -> f32 {
+        Ops::<Sse41, f32>::horizontal_add(a.low()) + Ops::<Sse41, f32>::horizontal_add(a.high())
+    }
+    fn cast_i32(a: __m256) -> __m256i {
+        _mm256_cvtps_epi32(a)
+    }
+    fn bitcast_i32(a: __m256) -> __m256i {
+        _mm256_castps_si256(a)
+    }
+    fn bitcast_binary(a: __m256) -> __m256 {
+        a
+    }
+    fn zeroes() -> __m256 {
+        _mm256_setzero_ps()
+    }
+    fn set1(val: f32) -> __m256 {
+        _mm256_set1_ps(val)
+    }
+    fn load_unaligned(ptr: *const f32) -> __m256 {
+        _mm256_loadu_ps(ptr)
+    }
+    fn load_aligned(ptr: *const f32) -> __m256 {
+        _mm256_load_ps(ptr)
+    }
+    fn store_unaligned(ptr: *mut f32, a: __m256) {
+        _mm256_storeu_ps(ptr, a)
+    }
+    fn store_aligned(ptr: *mut f32, a: __m256) {
+        _mm256_store_ps(ptr, a)
+    }
+    fn extend_f64(val: __m256) -> (__m256d, __m256d) {
+        let a = _mm256_cvtps_pd(val.low());
+        let b = _mm256_cvtps_pd(val.high());
+        (a, b)
+    }
+});
+
+on_halves!(f32, __m256 {
+    mul_add(a, b, c);
+    mul_add_fused(a, b, c);
+    mul_sub(a, b, c);
+    neg_mul_add(a, b, c);
+    neg_mul_sub(a, b, c);
+});
+
+native!(f64 {
+    fn add(a: __m256d, b: __m256d) -> __m256d {
+        _mm256_add_pd(a, b)
+    }
+    fn sub(a: __m256d, b: __m256d) -> __m256d {
+        _mm256_sub_pd(a, b)
+    }
+    fn mul(a: __m256d, b: __m256d) -> __m256d {
+        _mm256_mul_pd(a, b)
+    }
+    fn div(a: __m256d, b: __m256d) -> __m256d {
+        _mm256_div_pd(a, b)
+    }
+    fn sqrt(a: __m256d) -> __m256d {
+        _mm256_sqrt_pd(a)
+    }
+    fn rsqrt(a: __m256d) -> __m256d {
+        let one = _mm256_set1_pd(1.0);
+        _mm256_div_pd(one, _mm256_sqrt_pd(a))
+    }
+    fn min(a: __m256d, b: __m256d) -> __m256d {
+        _mm256_min_pd(a, b)
+    }
+    fn max(a: __m256d, b: __m256d) -> __m256d {
+        _mm256_max_pd(a, b)
+    }
+    fn abs(a: __m256d) -> __m256d {
+        _mm256_andnot_pd(_mm256_set1_pd(-0.0), a)
+    }
+    fn round(a: __m256d) -> __m256d {
+        _mm256_round_pd(a, _MM_FROUND_TO_NEAREST_INT | _MM_FROUND_NO_EXC)
+    }
+    fn floor(a: __m256d) -> __m256d {
+        _mm256_round_pd(a, _MM_FROUND_TO_NEG_INF | _MM_FROUND_NO_EXC)
+    }
+    fn ceil(a: __m256d) -> __m256d {
+        _mm256_round_pd(a, _MM_FROUND_TO_POS_INF | _MM_FROUND_NO_EXC)
+    }
+    fn trunc(a: __m256d) -> __m256d {
+        _mm256_round_pd(a, _MM_FROUND_TO_ZERO | _MM_FROUND_NO_EXC)
+    }
+    fn fast_round(a: __m256d) -> __m256d {
+        Self::round(a)
+    }
+    fn fast_floor(a: __m256d) -> __m256d {
+        Self::floor(a)
+    }
+    fn fast_ceil(a: __m256d) -> __m256d {
+        Self::ceil(a)
+    }
+    fn eq(a: __m256d, b: __m256d) -> __m256d {
+        _mm256_cmp_pd(a, b, _CMP_EQ_OQ)
+    }
+    fn neq(a: __m256d, b: __m256d) -> __m256d {
+        _mm256_cmp_pd(a, b, _CMP_NEQ_UQ)
+    }
+    fn lt(a: __m256d, b: __m256d) -> __m256d {
+        _mm256_cmp_pd(a, b, _CMP_LT_OQ)
+    }
+    fn lte(a: __m256d, b: __m256d) -> __m256d {
+        _mm256_cmp_pd(a, b, _CMP_LE_OQ)
+    }
+    fn gt(a: __m256d, b: __m256d) -> __m256d {
+        _mm256_cmp_pd(a, b, _CMP_GT_OQ)
+    }
+    fn gte(a: __m256d, b: __m256d) -> __m256d {
+        _mm256_cmp_pd(a, b, _CMP_GE_OQ)
+    }
+    fn blendv(a: __m256d, b: __m256d, mask: __m256d) -> __m256d {
+        _mm256_blendv_pd(a, b, mask)
+    }
+    fn horizontal_add(a: __m256d) -> f64 {
+        Ops::<Sse41, f64>::horizontal_add(a.low()) + Ops::<Sse41, f64>::horizontal_add(a.high())
+    }
+    fn cast_i64(a: __m256d) -> __m256i {
+        __m256i::join(
+            Ops::<Sse41, f64>::cast_i64(a.low()),
+            Ops::<Sse41, f64>::cast_i64(a.high()),
+        )
+    }
+    fn bitcast_i64(a: __m256d) -> __m256i {
+        _mm256_castpd_si256(a)
+    }
+    fn bitcast_binary(a: __m256d) -> __m256 {
+        _mm256_castpd_ps(a)
+    }
+    fn zeroes() -> __m256d {
+        _mm256_setzero_pd()
+    }
+    fn set1(val: f64) -> __m256d {
+        _mm256_set1_pd(val)
+    }
+    fn load_unaligned(ptr: *const f64) -> __m256d {
+        _mm256_loadu_pd(ptr)
+    }
+    fn load_aligned(ptr: *const f64) -> __m256d {
+        _mm256_load_pd(ptr)
+    }
+    fn store_unaligned(ptr: *mut f64, a: __m256d) {
+        _mm256_storeu_pd(ptr, a)
+    }
+    fn store_aligned(ptr: *mut f64, a: __m256d) {
+        _mm256_store_pd(ptr, a)
+    }
+    fn narrow_f32(a: __m256d, b: __m256d) -> __m256 {
+        _mm256_set_m128(_mm256_cvtpd_ps(b), _mm256_cvtpd_ps(a))
+    }
+    fn narrow_i32(a: __m256d, b: __m256d) -> __m256i {
+        _mm256_set_m128i(_mm256_cvtpd_epi32(b), _mm256_cvtpd_epi32(a))
+    }
+});
+
+on_halves!(f64, __m256d {
+    mul_add(a, b, c);
+    mul_add_fused(a, b, c);
+    mul_sub(a, b, c);
+    neg_mul_add(a, b, c);
+    neg_mul_sub(a, b, c);
+});
+
+native!(binary {
+    fn bit_and(a: __m256, b: __m256) -> __m256 {
+        _mm256_and_ps(a, b)
+    }
+    fn bit_or(a: __m256, b: __m256) -> __m256 {
+        _mm256_or_ps(a, b)
+    }
+    fn bit_xor(a: __m256, b: __m256) -> __m256 {
+        _mm256_xor_ps(a, b)
+    }
+    fn bit_not(a: __m256) -> __m256 {
+        _mm256_xor_ps(a, _mm256_castsi256_ps(_mm256_set1_epi32(-1)))
+    }
+    fn bit_andnot(a: __m256, b: __m256) -> __m256 {
+        _mm256_andnot_ps(a, b)
+    }
+    fn bitcast_f32(a: __m256) -> __m256 {
+        a
+    }
+    fn bitcast_f64(a: __m256) -> __m256d {
+        _mm256_castps_pd(a)
+    }
+});
+
+impl Ops<Avx, binary> {
+    with_feature_flag!(
+        Avx,
+        #[inline]
+        pub unsafe fn transpose_blocks<const BITS: i32>(a: __m256, b: __m256) -> (__m256, __m256) {
+            match BITS {
+                64 => {
+                    let (a, b) = (_mm256_castps_pd(a), _mm256_castps_pd(b));
+                    (
+                        _mm256_castpd_ps(_mm256_unpacklo_pd(a, b)),
+                        _mm256_castpd_ps(_mm256_unpackhi_pd(a, b)),
+                    )
+                }
+                128 => (
+                    _mm256_permute2f128_ps(a, b, 0x20),
+                    _mm256_permute2f128_ps(a, b, 0x31),
+                ),
+                // Smaller blocks need integer shifts, so swap them within each half.
+                _ => {
+                    let (low_a, low_b) =
+                        Ops::<Sse41, binary>::transpose_blocks::<BITS>(a.low(), b.low());
+                    let (high_a, high_b) =
+                        Ops::<Sse41, binary>::transpose_blocks::<BITS>(a.high(), b.high());
+                    (__m256::join(low_a, high_a), __m256::join(low_b, high_b))
+                }
+            }
+        }
+    );
+}
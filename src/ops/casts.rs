@@ -311,3 +311,211 @@ impl_op! {
         }
     }
 }
+
+impl_op! {
+    fn bitcast_binary<u8> {
+        for Avx512(a: __m512i) -> __m512 {
+            _mm512_castsi512_ps(a)
+        }
+        for Avx2(a: __m256i) -> __m256 {
+            _mm256_castsi256_ps(a)
+        }
+        for Sse41(a: __m128i) -> __m128 {
+            _mm_castsi128_ps(a)
+        }
+        for Sse2(a: __m128i) -> __m128 {
+            _mm_castsi128_ps(a)
+        }
+        for Scalar(a: u8) -> u64 {
+            a as u64
+        }
+        for Neon(a: uint8x16_t) -> int8x16_t {
+            vreinterpretq_s8_u8(a)
+        }
+        for Wasm(a: v128) -> v128 {
+            a
+        }
+    }
+}
+
+impl_op! {
+    fn bitcast_u8<binary> {
+        for Avx512(a: __m512) -> __m512i {
+            _mm512_castps_si512(a)
+        }
+        for Avx2(a: __m256) -> __m256i {
+            _mm256_castps_si256(a)
+        }
+        for Sse41(a: __m128) -> __m128i {
+            _mm_castps_si128(a)
+        }
+        for Sse2(a: __m128) -> __m128i {
+            _mm_castps_si128(a)
+        }
+        for Scalar(a: u64) -> u8 {
+            a as u8
+        }
+        for Neon(a: int8x16_t) -> uint8x16_t {
+            vreinterpretq_u8_s8(a)
+        }
+        for Wasm(a: v128) -> v128 {
+            a
+        }
+    }
+}
+
+impl_op! {
+    fn bitcast_binary<u16> {
+        for Avx512(a: __m512i) -> __m512 {
+            _mm512_castsi512_ps(a)
+        }
+        for Avx2(a: __m256i) -> __m256 {
+            _mm256_castsi256_ps(a)
+        }
+        for Sse41(a: __m128i) -> __m128 {
+            _mm_castsi128_ps(a)
+        }
+        for Sse2(a: __m128i) -> __m128 {
+            _mm_castsi128_ps(a)
+        }
+        for Scalar(a: u16) -> u64 {
+            a as u64
+        }
+        for Neon(a: uint16x8_t) -> int8x16_t {
+            vreinterpretq_s8_u16(a)
+        }
+        for Wasm(a: v128) -> v128 {
+            a
+        }
+    }
+}
+
+impl_op! {
+    fn bitcast_u16<binary> {
+        for Avx512(a: __m512) -> __m512i {
+            _mm512_castps_si512(a)
+        }
+        for Avx2(a: __m256) -> __m256i {
+            _mm256_castps_si256(a)
+        }
+        for Sse41(a: __m128) -> __m128i {
+            _mm_castps_si128(a)
+        }
+        for Sse2(a: __m128) -> __m128i {
+            _mm_castps_si128(a)
+        }
+        for Scalar(a: u64) -> u16 {
+            a as u16
+        }
+        for Neon(a: int8x16_t) -> uint16x8_t {
+            vreinterpretq_u16_s8(a)
+        }
+        for Wasm(a: v128) -> v128 {
+            a
+        }
+    }
+}
+
+impl_op! {
+    fn bitcast_binary<u32> {
+        for Avx512(a: __m512i) -> __m512 {
+            _mm512_castsi512_ps(a)
+        }
+        for Avx2(a: __m256i) -> __m256 {
+            _mm256_castsi256_ps(a)
+        }
+        for Sse41(a: __m128i) -> __m128 {
+            _mm_castsi128_ps(a)
+        }
+        for Sse2(a: __m128i) -> __m128 {
+            _mm_castsi128_ps(a)
+        }
+        for Scalar(a: u32) -> u64 {
+            a as u64
+        }
+        for Neon(a: uint32x4_t) -> int8x16_t {
+            vreinterpretq_s8_u32(a)
+        }
+        for Wasm(a: v128) -> v128 {
+            a
+        }
+    }
+}
+
+impl_op! {
+    fn bitcast_u32<binary> {
+        for Avx512(a: __m512) -> __m512i {
+            _mm512_castps_si512(a)
+        }
+        for Avx2(a: __m256) -> __m256i {
+            _mm256_castps_si256(a)
+        }
+        for Sse41(a: __m128) -> __m128i {
+            _mm_castps_si128(a)
+        }
+        for Sse2(a: __m128) -> __m128i {
+            _mm_castps_si128(a)
+        }
+        for Scalar(a: u64) -> u32 {
+            a as u32
+        }
+        for Neon(a: int8x16_t) -> uint32x4_t {
+            vreinterpretq_u32_s8(a)
+        }
+        for Wasm(a: v128) -> v128 {
+            a
+        }
+    }
+}
+
+impl_op! {
+    fn bitcast_binary<u64> {
+        for Avx512(a: __m512i) -> __m512 {
+            _mm512_castsi512_ps(a)
+        }
+        for Avx2(a: __m256i) -> __m256 {
+            _mm256_castsi256_ps(a)
+        }
+        for Sse41(a: __m128i) -> __m128 {
+            _mm_castsi128_ps(a)
+        }
+        for Sse2(a: __m128i) -> __m128 {
+            _mm_castsi128_ps(a)
+        }
+        for Scalar(a: u64) -> u64 {
+            a
+        }
+        for Neon(a: uint64x2_t) -> int8x16_t {
+            vreinterpretq_s8_u64(a)
+        }
+        for Wasm(a: v128) -> v128 {
+            a
+        }
+    }
+}
+
+impl_op! {
+    fn bitcast_u64<binary> {
+        for Avx512(a: __m512) -> __m512i {
+            _mm512_castps_si512(a)
+        }
+        for Avx2(a: __m256) -> __m256i {
+            _mm256_castps_si256(a)
+        }
+        for Sse41(a: __m128) -> __m128i {
+            _mm_castps_si128(a)
+        }
+        for Sse2(a: __m128) -> __m128i {
+            _mm_castps_si128(a)
+        }
+        for Scalar(a: u64) -> u64 {
+            a
+        }
+        for Neon(a: int8x16_t) -> uint64x2_t {
+            vreinterpretq_u64_s8(a)
+        }
+        for Wasm(a: v128) -> v128 {
+            a
+        }
+    }
+}
@@ -0,0 +1,233 @@
+use super::*;
+
+// AVX-512 compresses and expands 32 and 64 bit lanes natively, and 8 and 16 bit lanes with
+// VBMI2 when the CPU has it. Without VBMI2 they are widened 16 lanes at a time to 32 bits,
+// compressed or expanded, narrowed back and joined with a word permute, all in registers.
+
+/// `0, 1, 2, ...` as 16 bit lanes.
+#[cfg(any(target_arch = "x86_64", target_arch = "x86"))]
+const WORD_INDICES: [i16; 32] = {
+    let mut indices = [0; 32];
+    let mut i = 0;
+    while i < 32 {
+        indices[i] = i as i16;
+        i += 1;
+    }
+    indices
+};
+
+#[cfg(any(target_arch = "x86_64", target_arch = "x86"))]
+#[inline(always)]
+unsafe fn word_indices() -> __m512i {
+    _mm512_loadu_si512(WORD_INDICES.as_ptr() as *const _)
+}
+
+/// The first `count` 16 bit lanes of `low` followed by the lanes of `high`.
+#[cfg(any(target_arch = "x86_64", target_arch = "x86"))]
+#[inline(always)]
+unsafe fn join_epi16(low: __m512i, high: __m512i, count: u32) -> __m512i {
+    let indices = word_indices();
+    let from_high = _mm512_cmpge_epu16_mask(indices, _mm512_set1_epi16(count as i16));
+    let shift = _mm512_set1_epi16(32 - count as i16);
+    let indices = _mm512_mask_add_epi16(indices, from_high, indices, shift);
+    _mm512_permutex2var_epi16(low, indices, high)
+}
+
+#[cfg(any(target_arch = "x86_64", target_arch = "x86"))]
+#[inline(always)]
+unsafe fn compress_epi16_by_halves(mask: __mmask32, a: __m512i) -> __m512i {
+    let low = _mm512_cvtepi16_epi32(_mm512_castsi512_si256(a));
+    let high = _mm512_cvtepi16_epi32(_mm512_extracti64x4_epi64::<1>(a));
+    let low = _mm512_maskz_compress_epi32(mask as __mmask16, low);
+    let high = _mm512_maskz_compress_epi32((mask >> 16) as __mmask16, high);
+    let low = _mm512_zextsi256_si512(_mm512_cvtepi32_epi16(low));
+    let high = _mm512_zextsi256_si512(_mm512_cvtepi32_epi16(high));
+    join_epi16(low, high, (mask as u16).count_ones())
+}
+
+#[cfg(any(target_arch = "x86_64", target_arch = "x86"))]
+#[inline(always)]
+unsafe fn compress_epi8_by_halves(mask: __mmask64, a: __m512i) -> __m512i {
+    let low = _mm512_cvtepi8_epi16(_mm512_castsi512_si256(a));
+    let high = _mm512_cvtepi8_epi16(_mm512_extracti64x4_epi64::<1>(a));
+    let low = compress_epi16_by_halves(mask as __mmask32, low);
+    let high = compress_epi16_by_halves((mask >> 32) as __mmask32, high);
+    // The packed lanes span two word vectors: `high` continues right after the `count` lanes of
+    // `low`, and whatever of it is left over spills into the second vector.
+    let count = (mask as u32).count_ones();
+    let indices = word_indices();
+    let spilled = _mm512_cmplt_epu16_mask(indices, _mm512_set1_epi16(count as i16));
+    let spill_indices = _mm512_add_epi16(indices, _mm512_set1_epi16(32 - count as i16));
+    let first = _mm512_cvtepi16_epi8(join_epi16(low, high, count));
+    let second = _mm512_cvtepi16_epi8(_mm512_maskz_permutexvar_epi16(spilled, spill_indices, high));
+    _mm512_inserti64x4::<1>(_mm512_castsi256_si512(first), second)
+}
+
+#[cfg(any(target_arch = "x86_64", target_arch = "x86"))]
+#[inline(always)]
+unsafe fn expand_epi16_by_halves(mask: __mmask32, a: __m512i) -> __m512i {
+    // The high half starts with the lane after the last one the low half takes.
+    let count = _mm512_set1_epi16((mask as u16).count_ones() as i16);
+    let rest = _mm512_permutexvar_epi16(_mm512_add_epi16(word_indices(), count), a);
+    let low = _mm512_cvtepi16_epi32(_mm512_castsi512_si256(a));
+    let high = _mm512_cvtepi16_epi32(_mm512_castsi512_si256(rest));
+    let low = _mm512_cvtepi32_epi16(_mm512_maskz_expand_epi32(mask as __mmask16, low));
+    let high = _mm512_cvtepi32_epi16(_mm512_maskz_expand_epi32((mask >> 16) as __mmask16, high));
+    _mm512_inserti64x4::<1>(_mm512_castsi256_si512(low), high)
+}
+
+#[cfg(any(target_arch = "x86_64", target_arch = "x86"))]
+#[inline(always)]
+unsafe fn expand_epi8_by_halves(mask: __mmask64, a: __m512i) -> __m512i {
+    let count = _mm512_set1_epi16((mask as u32).count_ones() as i16);
+    let low = _mm512_cvtepi8_epi16(_mm512_castsi512_si256(a));
+    let high = _mm512_cvtepi8_epi16(_mm512_extracti64x4_epi64::<1>(a));
+    let rest = _mm512_permutex2var_epi16(low, _mm512_add_epi16(word_indices(), count), high);
+    let low = _mm512_cvtepi16_epi8(expand_epi16_by_halves(mask as __mmask32, low));
+    let high = _mm512_cvtepi16_epi8(expand_epi16_by_halves((mask >> 32) as __mmask32, rest));
+    _mm512_inserti64x4::<1>(_mm512_castsi256_si512(low), high)
+}
+
+#[cfg(any(target_arch = "x86_64", target_arch = "x86"))]
+#[target_feature(enable = "avx512f", enable = "avx512bw", enable = "avx512vbmi2")]
+#[inline]
+unsafe fn avx512_vbmi2_compress_epi8(mask: __mmask64, a: __m512i) -> __m512i {
+    _mm512_maskz_compress_epi8(mask, a)
+}
+
+#[cfg(any(target_arch = "x86_64", target_arch = "x86"))]
+#[target_feature(enable = "avx512f", enable = "avx512bw", enable = "avx512vbmi2")]
+#[inline]
+unsafe fn avx512_vbmi2_compress_epi16(mask: __mmask32, a: __m512i) -> __m512i {
+    _mm512_maskz_compress_epi16(mask, a)
+}
+
+#[cfg(any(target_arch = "x86_64", target_arch = "x86"))]
+#[target_feature(enable = "avx512f", enable = "avx512bw", enable = "avx512vbmi2")]
+#[inline]
+unsafe fn avx512_vbmi2_expand_epi8(mask: __mmask64, a: __m512i) -> __m512i {
+    _mm512_maskz_expand_epi8(mask, a)
+}
+
+#[cfg(any(target_arch = "x86_64", target_arch = "x86"))]
+#[target_feature(enable = "avx512f", enable = "avx512bw", enable = "avx512vbmi2")]
+#[inline]
+unsafe fn avx512_vbmi2_expand_epi16(mask: __mmask32, a: __m512i) -> __m512i {
+    _mm512_maskz_expand_epi16(mask, a)
+}
+
+impl_op! {
+    fn compress<i8> {
+        for Avx512(a: __m512i, mask: u64) -> __m512i {
+                        if has_feature(CpuFeature::Avx512Vbmi2) {
+                return avx512_vbmi2_compress_epi8(mask, a);
+            }
+            compress_epi8_by_halves(mask, a)
+        }
+    }
+}
+
+impl_op! {
+    fn compress<i16> {
+        for Avx512(a: __m512i, mask: u64) -> __m512i {
+            let mask = mask as __mmask32;
+            if has_feature(CpuFeature::Avx512Vbmi2) {
+                return avx512_vbmi2_compress_epi16(mask, a);
+            }
+            compress_epi16_by_halves(mask, a)
+        }
+    }
+}
+
+impl_op! {
+    fn compress<i32> {
+        for Avx512(a: __m512i, mask: u64) -> __m512i {
+            _mm512_maskz_compress_epi32(mask as __mmask16, a)
+        }
+    }
+}
+
+impl_op! {
+    fn compress<i64> {
+        for Avx512(a: __m512i, mask: u64) -> __m512i {
+            _mm512_maskz_compress_epi64(mask as __mmask8, a)
+        }
+    }
+}
+
+impl_op! {
+    fn expand<i8> {
+        for Avx512(a: __m512i, mask: u64) -> __m512i {
+                        if has_feature(CpuFeature::Avx512Vbmi2) {
+                return avx512_vbmi2_expand_epi8(mask, a);
+            }
+            expand_epi8_by_halves(mask, a)
+        }
+    }
+}
+
+impl_op! {
+    fn expand<i16> {
+        for Avx512(a: __m512i, mask: u64) -> __m512i {
+            let mask = mask as __mmask32;
+            if has_feature(CpuFeature::Avx512Vbmi2) {
+                return avx512_vbmi2_expand_epi16(mask, a);
+            }
+            expand_epi16_by_halves(mask, a)
+        }
+    }
+}
+
+impl_op! {
+    fn expand<i32> {
+        for Avx512(a: __m512i, mask: u64) -> __m512i {
+            _mm512_maskz_expand_epi32(mask as __mmask16, a)
+        }
+    }
+}
+
+impl_op! {
+    fn expand<i64> {
+        for Avx512(a: __m512i, mask: u64) -> __m512i {
+            _mm512_maskz_expand_epi64(mask as __mmask8, a)
+        }
+    }
+}
+
+// The stores write only as many lanes as the mask has set, so `ptr` only needs room for those.
+
+impl_op! {
+    fn compress_store<i8> {
+        for Avx512(ptr: *mut i8, a: __m512i, mask: u64) {
+            let count = mask.count_ones() as usize;
+            let packed = Self::compress(a, mask);
+            _mm512_mask_storeu_epi8(ptr, crate::lane_bits(count), packed)
+        }
+    }
+}
+
+impl_op! {
+    fn compress_store<i16> {
+        for Avx512(ptr: *mut i16, a: __m512i, mask: u64) {
+            let count = mask.count_ones() as usize;
+            let packed = Self::compress(a, mask);
+            _mm512_mask_storeu_epi16(ptr, crate::lane_bits(count) as __mmask32, packed)
+        }
+    }
+}
+
+impl_op! {
+    fn compress_store<i32> {
+        for Avx512(ptr: *mut i32, a: __m512i, mask: u64) {
+            _mm512_mask_compressstoreu_epi32(ptr as *mut _, mask as __mmask16, a)
+        }
+    }
+}
+
+impl_op! {
+    fn compress_store<i64> {
+        for Avx512(ptr: *mut i64, a: __m512i, mask: u64) {
+            _mm512_mask_compressstoreu_epi64(ptr as *mut _, mask as __mmask8, a)
+        }
+    }
+}
@@ -148,6 +148,33 @@ impl_op! {
     }
 }
 
+// Engines without FMA instructions round each lane once in software.
+impl_op! {
+    fn mul_add_fused<f32> {
+        for Avx512(a: __m512, b: __m512, c: __m512) -> __m512 {
+            _mm512_fmadd_ps(a, b, c)
+        }
+        for Avx2(a: __m256, b: __m256, c: __m256) -> __m256 {
+            _mm256_fmadd_ps(a, b, c)
+        }
+        for Sse41(a: __m128, b: __m128, c: __m128) -> __m128 {
+            map_lanes3::<_, f32, 4>(a, b, c, f32::m_mul_add)
+        }
+        for Sse2(a: __m128, b: __m128, c: __m128) -> __m128 {
+            map_lanes3::<_, f32, 4>(a, b, c, f32::m_mul_add)
+        }
+        for Scalar(a: f32, b: f32, c: f32) -> f32 {
+            a.m_mul_add(b, c)
+        }
+        for Neon(a: float32x4_t, b: float32x4_t, c: float32x4_t) -> float32x4_t {
+            vfmaq_f32(c, a, b)
+        }
+        for Wasm(a: v128, b: v128, c: v128) -> v128 {
+            map_lanes3::<_, f32, 4>(a, b, c, f32::m_mul_add)
+        }
+    }
+}
+
 impl_op! {
     fn mul_sub<f32> {
         for Avx512(a: __m512, b: __m512, c: __m512) -> __m512 {
@@ -483,6 +510,37 @@ impl_op! {
     }
 }
 
+// SSE2 converts lanes below 2^23 through i32, as larger ones and NaN are already integral.
+impl_op! {
+    fn trunc<f32> {
+        for Avx512(a: __m512) -> __m512 {
+            _mm512_roundscale_ps::<0x0B>(a)
+        }
+        for Avx2(a: __m256) -> __m256 {
+            _mm256_round_ps(a, _MM_FROUND_TO_ZERO | _MM_FROUND_NO_EXC)
+        }
+        for Sse41(a: __m128) -> __m128 {
+            _mm_round_ps(a, _MM_FROUND_TO_ZERO | _MM_FROUND_NO_EXC)
+        }
+        for Sse2(a: __m128) -> __m128 {
+            let sign_mask = _mm_set1_ps(-0.0);
+            let small = _mm_cmplt_ps(_mm_andnot_ps(sign_mask, a), _mm_set1_ps(8388608.0));
+            let truncated = _mm_cvtepi32_ps(_mm_cvttps_epi32(a));
+            let truncated = _mm_or_ps(truncated, _mm_and_ps(a, sign_mask));
+            _mm_or_ps(_mm_and_ps(small, truncated), _mm_andnot_ps(small, a))
+        }
+        for Scalar(a: f32) -> f32 {
+            a.m_trunc()
+        }
+        for Neon(a: float32x4_t) -> float32x4_t {
+            vrndq_f32(a)
+        }
+        for Wasm(a: v128) -> v128 {
+            f32x4_trunc(a)
+        }
+    }
+}
+
 impl_op! {
     fn fast_round<f32> {
         for Avx512(a: __m512) -> __m512 {
@@ -781,7 +839,16 @@ impl_op! {
 impl_op! {
     fn horizontal_add<f32> {
         for Avx512(a: __m512) -> f32 {
-            _mm512_reduce_add_ps(a)
+            // Adjacent lanes are summed first, then adjacent pairs, matching every other engine.
+            let low = _mm512_castps512_ps256(a);
+            let high = _mm256_castpd_ps(_mm512_extractf64x4_pd(_mm512_castps_pd(a), 1));
+            let pairs = _mm256_hadd_ps(low, high);
+            let quads = _mm256_hadd_ps(pairs, pairs);
+            let octets = _mm_add_ps(
+                _mm256_extractf128_ps(quads, 0),
+                _mm256_extractf128_ps(quads, 1),
+            );
+            _mm_cvtss_f32(octets) + _mm_cvtss_f32(_mm_shuffle_ps(octets, octets, 1))
         }
         for Avx2(a: __m256) -> f32 {
             let a = _mm256_hadd_ps(a, a);
@@ -799,10 +866,10 @@ impl_op! {
             _mm_cvtss_f32(b)
         }
         for Sse2(a: __m128) -> f32 {
-            let t1 = _mm_movehl_ps(a, a);
-            let t2 = _mm_add_ps(a, t1);
-            let t3 = _mm_shuffle_ps(t2, t2, 1);
-            _mm_cvtss_f32(t2) + _mm_cvtss_f32(t3)
+            let even = _mm_shuffle_ps(a, a, 0b10_00_10_00);
+            let odd = _mm_shuffle_ps(a, a, 0b11_01_11_01);
+            let pairs = _mm_add_ps(even, odd);
+            _mm_cvtss_f32(pairs) + _mm_cvtss_f32(_mm_shuffle_ps(pairs, pairs, 1))
         }
         for Scalar(a: f32) -> f32 {
             a
@@ -817,7 +884,7 @@ impl_op! {
             let l1 = f32x4_extract_lane::<1>(a);
             let l2 = f32x4_extract_lane::<2>(a);
             let l3 = f32x4_extract_lane::<3>(a);
-            l0 + l1 + l2 + l3
+            (l0 + l1) + (l2 + l3)
         }
     }
 }
@@ -1032,3 +1099,39 @@ impl_op! {
         }
     }
 }
+
+impl_op! {
+    fn extend_f64<f32> {
+        for Avx512(val: __m512) -> (__m512d, __m512d) {
+            let a = _mm512_cvtps_pd(_mm512_castps512_ps256(val));
+            let b = _mm512_cvtps_pd(_mm512_extractf32x8_ps::<1>(val));
+            (a, b)
+        }
+        for Avx2(val: __m256) -> (__m256d, __m256d) {
+            let a = _mm256_cvtps_pd(_mm256_castps256_ps128(val));
+            let b = _mm256_cvtps_pd(_mm256_extractf128_ps::<1>(val));
+            (a, b)
+        }
+        for Sse41(val: __m128) -> (__m128d, __m128d) {
+            Ops::<Sse2, f32>::extend_f64(val)
+        }
+        for Sse2(val: __m128) -> (__m128d, __m128d) {
+            let a = _mm_cvtps_pd(val);
+            let b = _mm_cvtps_pd(_mm_movehl_ps(val, val));
+            (a, b)
+        }
+        for Scalar(val: f32) -> (f64, f64) {
+            (val as f64, 0.0)
+        }
+        for Neon(val: float32x4_t) -> (float64x2_t, float64x2_t) {
+            let a = vcvt_f64_f32(vget_low_f32(val));
+            let b = vcvt_high_f64_f32(val);
+            (a, b)
+        }
+        for Wasm(val: v128) -> (v128, v128) {
+            let a = f64x2_promote_low_f32x4(val);
+            let b = f64x2_promote_low_f32x4(i32x4_shuffle::<2, 3, 2, 3>(val, val));
+            (a, b)
+        }
+    }
+}
@@ -140,7 +140,7 @@ impl_op! {
             a * b + c
         }
         for Neon(a: float64x2_t, b: float64x2_t, c: float64x2_t) -> float64x2_t {
-            vmlaq_f64(c, a, b)
+            vfmaq_f64(c, a, b)
         }
         for Wasm(a: v128, b: v128, c: v128) -> v128 {
             f64x2_add(f64x2_mul(a, b), c)
@@ -148,6 +148,33 @@ impl_op! {
     }
 }
 
+// Engines without FMA instructions round each lane once in software.
+impl_op! {
+    fn mul_add_fused<f64> {
+        for Avx512(a: __m512d, b: __m512d, c: __m512d) -> __m512d {
+            _mm512_fmadd_pd(a, b, c)
+        }
+        for Avx2(a: __m256d, b: __m256d, c: __m256d) -> __m256d {
+            _mm256_fmadd_pd(a, b, c)
+        }
+        for Sse41(a: __m128d, b: __m128d, c: __m128d) -> __m128d {
+            map_lanes3::<_, f64, 2>(a, b, c, f64::m_mul_add)
+        }
+        for Sse2(a: __m128d, b: __m128d, c: __m128d) -> __m128d {
+            map_lanes3::<_, f64, 2>(a, b, c, f64::m_mul_add)
+        }
+        for Scalar(a: f64, b: f64, c: f64) -> f64 {
+            a.m_mul_add(b, c)
+        }
+        for Neon(a: float64x2_t, b: float64x2_t, c: float64x2_t) -> float64x2_t {
+            vfmaq_f64(c, a, b)
+        }
+        for Wasm(a: v128, b: v128, c: v128) -> v128 {
+            map_lanes3::<_, f64, 2>(a, b, c, f64::m_mul_add)
+        }
+    }
+}
+
 impl_op! {
     fn mul_sub<f64> {
         for Avx512(a: __m512d, b: __m512d, c: __m512d) -> __m512d {
@@ -458,6 +485,34 @@ impl_op! {
     }
 }
 
+impl_op! {
+    fn trunc<f64> {
+        for Avx512(a: __m512d) -> __m512d {
+            _mm512_roundscale_pd::<0x0B>(a)
+        }
+        for Avx2(a: __m256d) -> __m256d {
+            _mm256_round_pd(a, _MM_FROUND_TO_ZERO | _MM_FROUND_NO_EXC)
+        }
+        for Sse41(a: __m128d) -> __m128d {
+            _mm_round_pd(a, _MM_FROUND_TO_ZERO | _MM_FROUND_NO_EXC)
+        }
+        for Sse2(a: __m128d) -> __m128d {
+            let nums_arr = core::mem::transmute::<__m128d, [f64; 2]>(a);
+            let trunc = [nums_arr[0].m_trunc(), nums_arr[1].m_trunc()];
+            core::mem::transmute::<[f64; 2], __m128d>(trunc)
+        }
+        for Scalar(a: f64) -> f64 {
+            a.m_trunc()
+        }
+        for Neon(a: float64x2_t) -> float64x2_t {
+            vrndq_f64(a)
+        }
+        for Wasm(a: v128) -> v128 {
+            f64x2_trunc(a)
+        }
+    }
+}
+
 impl_op! {
     fn fast_round<f64> {
         for Avx512(a: __m512d) -> __m512d {
@@ -756,7 +811,13 @@ impl_op! {
 impl_op! {
     fn horizontal_add<f64> {
         for Avx512(a: __m512d) -> f64 {
-            _mm512_reduce_add_pd(a)
+            // Adjacent lanes are summed first, then adjacent pairs, matching every other engine.
+            let pairs = _mm256_hadd_pd(_mm512_castpd512_pd256(a), _mm512_extractf64x4_pd(a, 1));
+            let quads = _mm_add_pd(
+                _mm256_extractf128_pd(pairs, 0),
+                _mm256_extractf128_pd(pairs, 1),
+            );
+            _mm_cvtsd_f64(quads) + _mm_cvtsd_f64(_mm_unpackhi_pd(quads, quads))
         }
         for Avx2(a: __m256d) -> f64 {
             let a = _mm256_hadd_pd(a, a);
@@ -1020,3 +1081,63 @@ impl_op! {
         }
     }
 }
+
+// Narrowing conversions put the lanes of `a` first, then the lanes of `b`.
+
+impl_op! {
+    fn narrow_f32<f64> {
+        for Avx512(a: __m512d, b: __m512d) -> __m512 {
+            let lo = _mm512_cvtpd_ps(a);
+            let hi = _mm512_cvtpd_ps(b);
+            _mm512_insertf32x8::<1>(_mm512_castps256_ps512(lo), hi)
+        }
+        for Avx2(a: __m256d, b: __m256d) -> __m256 {
+            _mm256_set_m128(_mm256_cvtpd_ps(b), _mm256_cvtpd_ps(a))
+        }
+        for Sse41(a: __m128d, b: __m128d) -> __m128 {
+            Ops::<Sse2, f64>::narrow_f32(a, b)
+        }
+        for Sse2(a: __m128d, b: __m128d) -> __m128 {
+            _mm_movelh_ps(_mm_cvtpd_ps(a), _mm_cvtpd_ps(b))
+        }
+        for Scalar(a: f64, _b: f64) -> f32 {
+            a as f32
+        }
+        for Neon(a: float64x2_t, b: float64x2_t) -> float32x4_t {
+            vcvt_high_f32_f64(vcvt_f32_f64(a), b)
+        }
+        for Wasm(a: v128, b: v128) -> v128 {
+            i32x4_shuffle::<0, 1, 4, 5>(f32x4_demote_f64x2_zero(a), f32x4_demote_f64x2_zero(b))
+        }
+    }
+}
+
+impl_op! {
+    fn narrow_i32<f64> {
+        for Avx512(a: __m512d, b: __m512d) -> __m512i {
+            let lo = _mm512_cvtpd_epi32(a);
+            let hi = _mm512_cvtpd_epi32(b);
+            _mm512_inserti64x4::<1>(_mm512_castsi256_si512(lo), hi)
+        }
+        for Avx2(a: __m256d, b: __m256d) -> __m256i {
+            _mm256_set_m128i(_mm256_cvtpd_epi32(b), _mm256_cvtpd_epi32(a))
+        }
+        for Sse41(a: __m128d, b: __m128d) -> __m128i {
+            Ops::<Sse2, f64>::narrow_i32(a, b)
+        }
+        for Sse2(a: __m128d, b: __m128d) -> __m128i {
+            _mm_unpacklo_epi64(_mm_cvtpd_epi32(a), _mm_cvtpd_epi32(b))
+        }
+        for Scalar(a: f64, _b: f64) -> i32 {
+            a.m_round_ties_even() as i32
+        }
+        for Neon(a: float64x2_t, b: float64x2_t) -> int32x4_t {
+            vcombine_s32(vqmovn_s64(vcvtnq_s64_f64(a)), vqmovn_s64(vcvtnq_s64_f64(b)))
+        }
+        for Wasm(a: v128, b: v128) -> v128 {
+            let a = i32x4_trunc_sat_f64x2_zero(f64x2_nearest(a));
+            let b = i32x4_trunc_sat_f64x2_zero(f64x2_nearest(b));
+            i32x4_shuffle::<0, 1, 4, 5>(a, b)
+        }
+    }
+}
@@ -0,0 +1,374 @@
+use super::*;
+
+// Gathers and scatters take signed lane offsets (in elements) from `base`. Masked variants only
+// touch memory for lanes whose mask has the highest bit set; inactive gather lanes keep `src`.
+// Scatters with repeated offsets write lanes in order, so the highest lane wins.
+//
+// The `_i32` forms of the 64 bit ops take a 32 bit index vector and use its low lanes, one per
+// 64 bit lane, like `vpgatherdq`.
+
+/// Lane-by-lane gather for engines without gather instructions. Lane `i` loads
+/// `base[indices[i]]`, or with `masked` as `(src, mask)` only where `mask` is negative, keeping
+/// `src` elsewhere. Indices are `X` lanes, of which the low `N` are used.
+#[inline(always)]
+unsafe fn emulated_gather<V: Copy, I: Copy, T, X, const N: usize>(
+    base: *const T,
+    indices: I,
+    masked: Option<(V, V)>,
+) -> V
+where
+    T: Copy + Default + PartialOrd,
+    X: Copy + Into<i64>,
+{
+    let indices = core::mem::transmute_copy::<I, [X; N]>(&indices);
+    let (mut result, mask) = match masked {
+        Some((src, mask)) => (
+            core::mem::transmute_copy::<V, [T; N]>(&src),
+            Some(core::mem::transmute_copy::<V, [T; N]>(&mask)),
+        ),
+        None => ([T::default(); N], None),
+    };
+    for (i, slot) in result.iter_mut().enumerate() {
+        if mask.is_none_or(|mask| mask[i] < T::default()) {
+            *slot = *base.offset(indices[i].into() as isize);
+        }
+    }
+    core::mem::transmute_copy::<[T; N], V>(&result)
+}
+
+/// Lane-by-lane scatter for engines without scatter instructions. Lanes are written from lowest
+/// to highest, only where `mask` is negative when there is one. Indices are `X` lanes, of which
+/// the low `N` are used.
+#[inline(always)]
+unsafe fn emulated_scatter<V: Copy, I: Copy, T, X, const N: usize>(
+    base: *mut T,
+    indices: I,
+    values: V,
+    mask: Option<V>,
+) where
+    T: Copy + Default + PartialOrd,
+    X: Copy + Into<i64>,
+{
+    let indices = core::mem::transmute_copy::<I, [X; N]>(&indices);
+    let values = core::mem::transmute_copy::<V, [T; N]>(&values);
+    let mask = mask.map(|mask| core::mem::transmute_copy::<V, [T; N]>(&mask));
+    for i in 0..N {
+        if mask.is_none_or(|mask| mask[i] < T::default()) {
+            *base.offset(indices[i].into() as isize) = values[i];
+        }
+    }
+}
+
+impl_op! {
+    fn gather<i32> {
+        for Avx512(base: *const i32, idx: __m512i) -> __m512i {
+            _mm512_i32gather_epi32::<4>(idx, base)
+        }
+        for Avx2(base: *const i32, idx: __m256i) -> __m256i {
+            _mm256_i32gather_epi32::<4>(base, idx)
+        }
+        for Sse41(base: *const i32, idx: __m128i) -> __m128i {
+            emulated_gather::<_, _, i32, i32, 4>(base, idx, None)
+        }
+        for Sse2(base: *const i32, idx: __m128i) -> __m128i {
+            emulated_gather::<_, _, i32, i32, 4>(base, idx, None)
+        }
+        for Scalar(base: *const i32, idx: i32) -> i32 {
+            emulated_gather::<_, _, i32, i32, 1>(base, idx, None)
+        }
+        for Neon(base: *const i32, idx: int32x4_t) -> int32x4_t {
+            emulated_gather::<_, _, i32, i32, 4>(base, idx, None)
+        }
+        for Wasm(base: *const i32, idx: v128) -> v128 {
+            emulated_gather::<_, _, i32, i32, 4>(base, idx, None)
+        }
+    }
+}
+
+impl_op! {
+    fn gather_masked<i32> {
+        for Avx512(src: __m512i, base: *const i32, idx: __m512i, mask: u64) -> __m512i {
+            _mm512_mask_i32gather_epi32::<4>(src, mask as __mmask16, idx, base)
+        }
+        for Avx2(src: __m256i, base: *const i32, idx: __m256i, mask: __m256i) -> __m256i {
+            _mm256_mask_i32gather_epi32::<4>(src, base, idx, mask)
+        }
+        for Sse41(src: __m128i, base: *const i32, idx: __m128i, mask: __m128i) -> __m128i {
+            emulated_gather::<_, _, i32, i32, 4>(base, idx, Some((src, mask)))
+        }
+        for Sse2(src: __m128i, base: *const i32, idx: __m128i, mask: __m128i) -> __m128i {
+            emulated_gather::<_, _, i32, i32, 4>(base, idx, Some((src, mask)))
+        }
+        for Scalar(src: i32, base: *const i32, idx: i32, mask: i32) -> i32 {
+            emulated_gather::<_, _, i32, i32, 1>(base, idx, Some((src, mask)))
+        }
+        for Neon(src: int32x4_t, base: *const i32, idx: int32x4_t, mask: int32x4_t) -> int32x4_t {
+            emulated_gather::<_, _, i32, i32, 4>(base, idx, Some((src, mask)))
+        }
+        for Wasm(src: v128, base: *const i32, idx: v128, mask: v128) -> v128 {
+            emulated_gather::<_, _, i32, i32, 4>(base, idx, Some((src, mask)))
+        }
+    }
+}
+
+impl_op! {
+    fn scatter<i32> {
+        for Avx512(base: *mut i32, idx: __m512i, a: __m512i) {
+            _mm512_i32scatter_epi32::<4>(base, idx, a)
+        }
+        for Avx2(base: *mut i32, idx: __m256i, a: __m256i) {
+            emulated_scatter::<_, _, i32, i32, 8>(base, idx, a, None)
+        }
+        for Sse41(base: *mut i32, idx: __m128i, a: __m128i) {
+            emulated_scatter::<_, _, i32, i32, 4>(base, idx, a, None)
+        }
+        for Sse2(base: *mut i32, idx: __m128i, a: __m128i) {
+            emulated_scatter::<_, _, i32, i32, 4>(base, idx, a, None)
+        }
+        for Scalar(base: *mut i32, idx: i32, a: i32) {
+            emulated_scatter::<_, _, i32, i32, 1>(base, idx, a, None)
+        }
+        for Neon(base: *mut i32, idx: int32x4_t, a: int32x4_t) {
+            emulated_scatter::<_, _, i32, i32, 4>(base, idx, a, None)
+        }
+        for Wasm(base: *mut i32, idx: v128, a: v128) {
+            emulated_scatter::<_, _, i32, i32, 4>(base, idx, a, None)
+        }
+    }
+}
+
+impl_op! {
+    fn scatter_masked<i32> {
+        for Avx512(base: *mut i32, idx: __m512i, a: __m512i, mask: u64) {
+            _mm512_mask_i32scatter_epi32::<4>(base, mask as __mmask16, idx, a)
+        }
+        for Avx2(base: *mut i32, idx: __m256i, a: __m256i, mask: __m256i) {
+            emulated_scatter::<_, _, i32, i32, 8>(base, idx, a, Some(mask))
+        }
+        for Sse41(base: *mut i32, idx: __m128i, a: __m128i, mask: __m128i) {
+            emulated_scatter::<_, _, i32, i32, 4>(base, idx, a, Some(mask))
+        }
+        for Sse2(base: *mut i32, idx: __m128i, a: __m128i, mask: __m128i) {
+            emulated_scatter::<_, _, i32, i32, 4>(base, idx, a, Some(mask))
+        }
+        for Scalar(base: *mut i32, idx: i32, a: i32, mask: i32) {
+            emulated_scatter::<_, _, i32, i32, 1>(base, idx, a, Some(mask))
+        }
+        for Neon(base: *mut i32, idx: int32x4_t, a: int32x4_t, mask: int32x4_t) {
+            emulated_scatter::<_, _, i32, i32, 4>(base, idx, a, Some(mask))
+        }
+        for Wasm(base: *mut i32, idx: v128, a: v128, mask: v128) {
+            emulated_scatter::<_, _, i32, i32, 4>(base, idx, a, Some(mask))
+        }
+    }
+}
+
+impl_op! {
+    fn gather<i64> {
+        for Avx512(base: *const i64, idx: __m512i) -> __m512i {
+            _mm512_i64gather_epi64::<8>(idx, base)
+        }
+        for Avx2(base: *const i64, idx: __m256i) -> __m256i {
+            _mm256_i64gather_epi64::<8>(base, idx)
+        }
+        for Sse41(base: *const i64, idx: __m128i) -> __m128i {
+            emulated_gather::<_, _, i64, i64, 2>(base, idx, None)
+        }
+        for Sse2(base: *const i64, idx: __m128i) -> __m128i {
+            emulated_gather::<_, _, i64, i64, 2>(base, idx, None)
+        }
+        for Scalar(base: *const i64, idx: i64) -> i64 {
+            emulated_gather::<_, _, i64, i64, 1>(base, idx, None)
+        }
+        for Neon(base: *const i64, idx: int64x2_t) -> int64x2_t {
+            emulated_gather::<_, _, i64, i64, 2>(base, idx, None)
+        }
+        for Wasm(base: *const i64, idx: v128) -> v128 {
+            emulated_gather::<_, _, i64, i64, 2>(base, idx, None)
+        }
+    }
+}
+
+impl_op! {
+    fn gather_masked<i64> {
+        for Avx512(src: __m512i, base: *const i64, idx: __m512i, mask: u64) -> __m512i {
+            _mm512_mask_i64gather_epi64::<8>(src, mask as __mmask8, idx, base)
+        }
+        for Avx2(src: __m256i, base: *const i64, idx: __m256i, mask: __m256i) -> __m256i {
+            _mm256_mask_i64gather_epi64::<8>(src, base, idx, mask)
+        }
+        for Sse41(src: __m128i, base: *const i64, idx: __m128i, mask: __m128i) -> __m128i {
+            emulated_gather::<_, _, i64, i64, 2>(base, idx, Some((src, mask)))
+        }
+        for Sse2(src: __m128i, base: *const i64, idx: __m128i, mask: __m128i) -> __m128i {
+            emulated_gather::<_, _, i64, i64, 2>(base, idx, Some((src, mask)))
+        }
+        for Scalar(src: i64, base: *const i64, idx: i64, mask: i64) -> i64 {
+            emulated_gather::<_, _, i64, i64, 1>(base, idx, Some((src, mask)))
+        }
+        for Neon(src: int64x2_t, base: *const i64, idx: int64x2_t, mask: int64x2_t) -> int64x2_t {
+            emulated_gather::<_, _, i64, i64, 2>(base, idx, Some((src, mask)))
+        }
+        for Wasm(src: v128, base: *const i64, idx: v128, mask: v128) -> v128 {
+            emulated_gather::<_, _, i64, i64, 2>(base, idx, Some((src, mask)))
+        }
+    }
+}
+
+impl_op! {
+    fn scatter<i64> {
+        for Avx512(base: *mut i64, idx: __m512i, a: __m512i) {
+            _mm512_i64scatter_epi64::<8>(base, idx, a)
+        }
+        for Avx2(base: *mut i64, idx: __m256i, a: __m256i) {
+            emulated_scatter::<_, _, i64, i64, 4>(base, idx, a, None)
+        }
+        for Sse41(base: *mut i64, idx: __m128i, a: __m128i) {
+            emulated_scatter::<_, _, i64, i64, 2>(base, idx, a, None)
+        }
+        for Sse2(base: *mut i64, idx: __m128i, a: __m128i) {
+            emulated_scatter::<_, _, i64, i64, 2>(base, idx, a, None)
+        }
+        for Scalar(base: *mut i64, idx: i64, a: i64) {
+            emulated_scatter::<_, _, i64, i64, 1>(base, idx, a, None)
+        }
+        for Neon(base: *mut i64, idx: int64x2_t, a: int64x2_t) {
+            emulated_scatter::<_, _, i64, i64, 2>(base, idx, a, None)
+        }
+        for Wasm(base: *mut i64, idx: v128, a: v128) {
+            emulated_scatter::<_, _, i64, i64, 2>(base, idx, a, None)
+        }
+    }
+}
+
+impl_op! {
+    fn scatter_masked<i64> {
+        for Avx512(base: *mut i64, idx: __m512i, a: __m512i, mask: u64) {
+            _mm512_mask_i64scatter_epi64::<8>(base, mask as __mmask8, idx, a)
+        }
+        for Avx2(base: *mut i64, idx: __m256i, a: __m256i, mask: __m256i) {
+            emulated_scatter::<_, _, i64, i64, 4>(base, idx, a, Some(mask))
+        }
+        for Sse41(base: *mut i64, idx: __m128i, a: __m128i, mask: __m128i) {
+            emulated_scatter::<_, _, i64, i64, 2>(base, idx, a, Some(mask))
+        }
+        for Sse2(base: *mut i64, idx: __m128i, a: __m128i, mask: __m128i) {
+            emulated_scatter::<_, _, i64, i64, 2>(base, idx, a, Some(mask))
+        }
+        for Scalar(base: *mut i64, idx: i64, a: i64, mask: i64) {
+            emulated_scatter::<_, _, i64, i64, 1>(base, idx, a, Some(mask))
+        }
+        for Neon(base: *mut i64, idx: int64x2_t, a: int64x2_t, mask: int64x2_t) {
+            emulated_scatter::<_, _, i64, i64, 2>(base, idx, a, Some(mask))
+        }
+        for Wasm(base: *mut i64, idx: v128, a: v128, mask: v128) {
+            emulated_scatter::<_, _, i64, i64, 2>(base, idx, a, Some(mask))
+        }
+    }
+}
+
+impl_op! {
+    fn gather_i32<i64> {
+        for Avx512(base: *const i64, idx: __m512i) -> __m512i {
+            _mm512_i32gather_epi64::<8>(_mm512_castsi512_si256(idx), base)
+        }
+        for Avx2(base: *const i64, idx: __m256i) -> __m256i {
+            _mm256_i32gather_epi64::<8>(base, _mm256_castsi256_si128(idx))
+        }
+        for Sse41(base: *const i64, idx: __m128i) -> __m128i {
+            emulated_gather::<_, _, i64, i32, 2>(base, idx, None)
+        }
+        for Sse2(base: *const i64, idx: __m128i) -> __m128i {
+            emulated_gather::<_, _, i64, i32, 2>(base, idx, None)
+        }
+        for Scalar(base: *const i64, idx: i32) -> i64 {
+            emulated_gather::<_, _, i64, i32, 1>(base, idx, None)
+        }
+        for Neon(base: *const i64, idx: int32x4_t) -> int64x2_t {
+            emulated_gather::<_, _, i64, i32, 2>(base, idx, None)
+        }
+        for Wasm(base: *const i64, idx: v128) -> v128 {
+            emulated_gather::<_, _, i64, i32, 2>(base, idx, None)
+        }
+    }
+}
+
+impl_op! {
+    fn gather_masked_i32<i64> {
+        for Avx512(src: __m512i, base: *const i64, idx: __m512i, mask: u64) -> __m512i {
+            let idx = _mm512_castsi512_si256(idx);
+            _mm512_mask_i32gather_epi64::<8>(src, mask as __mmask8, idx, base)
+        }
+        for Avx2(src: __m256i, base: *const i64, idx: __m256i, mask: __m256i) -> __m256i {
+            _mm256_mask_i32gather_epi64::<8>(src, base, _mm256_castsi256_si128(idx), mask)
+        }
+        for Sse41(src: __m128i, base: *const i64, idx: __m128i, mask: __m128i) -> __m128i {
+            emulated_gather::<_, _, i64, i32, 2>(base, idx, Some((src, mask)))
+        }
+        for Sse2(src: __m128i, base: *const i64, idx: __m128i, mask: __m128i) -> __m128i {
+            emulated_gather::<_, _, i64, i32, 2>(base, idx, Some((src, mask)))
+        }
+        for Scalar(src: i64, base: *const i64, idx: i32, mask: i64) -> i64 {
+            emulated_gather::<_, _, i64, i32, 1>(base, idx, Some((src, mask)))
+        }
+        for Neon(src: int64x2_t, base: *const i64, idx: int32x4_t, mask: int64x2_t) -> int64x2_t {
+            emulated_gather::<_, _, i64, i32, 2>(base, idx, Some((src, mask)))
+        }
+        for Wasm(src: v128, base: *const i64, idx: v128, mask: v128) -> v128 {
+            emulated_gather::<_, _, i64, i32, 2>(base, idx, Some((src, mask)))
+        }
+    }
+}
+
+impl_op! {
+    fn scatter_i32<i64> {
+        for Avx512(base: *mut i64, idx: __m512i, a: __m512i) {
+            _mm512_i32scatter_epi64::<8>(base, _mm512_castsi512_si256(idx), a)
+        }
+        for Avx2(base: *mut i64, idx: __m256i, a: __m256i) {
+            emulated_scatter::<_, _, i64, i32, 4>(base, idx, a, None)
+        }
+        for Sse41(base: *mut i64, idx: __m128i, a: __m128i) {
+            emulated_scatter::<_, _, i64, i32, 2>(base, idx, a, None)
+        }
+        for Sse2(base: *mut i64, idx: __m128i, a: __m128i) {
+            emulated_scatter::<_, _, i64, i32, 2>(base, idx, a, None)
+        }
+        for Scalar(base: *mut i64, idx: i32, a: i64) {
+            emulated_scatter::<_, _, i64, i32, 1>(base, idx, a, None)
+        }
+        for Neon(base: *mut i64, idx: int32x4_t, a: int64x2_t) {
+            emulated_scatter::<_, _, i64, i32, 2>(base, idx, a, None)
+        }
+        for Wasm(base: *mut i64, idx: v128, a: v128) {
+            emulated_scatter::<_, _, i64, i32, 2>(base, idx, a, None)
+        }
+    }
+}
+
+impl_op! {
+    fn scatter_masked_i32<i64> {
+        for Avx512(base: *mut i64, idx: __m512i, a: __m512i, mask: u64) {
+            let idx = _mm512_castsi512_si256(idx);
+            _mm512_mask_i32scatter_epi64::<8>(base, mask as __mmask8, idx, a)
+        }
+        for Avx2(base: *mut i64, idx: __m256i, a: __m256i, mask: __m256i) {
+            emulated_scatter::<_, _, i64, i32, 4>(base, idx, a, Some(mask))
+        }
+        for Sse41(base: *mut i64, idx: __m128i, a: __m128i, mask: __m128i) {
+            emulated_scatter::<_, _, i64, i32, 2>(base, idx, a, Some(mask))
+        }
+        for Sse2(base: *mut i64, idx: __m128i, a: __m128i, mask: __m128i) {
+            emulated_scatter::<_, _, i64, i32, 2>(base, idx, a, Some(mask))
+        }
+        for Scalar(base: *mut i64, idx: i32, a: i64, mask: i64) {
+            emulated_scatter::<_, _, i64, i32, 1>(base, idx, a, Some(mask))
+        }
+        for Neon(base: *mut i64, idx: int32x4_t, a: int64x2_t, mask: int64x2_t) {
+            emulated_scatter::<_, _, i64, i32, 2>(base, idx, a, Some(mask))
+        }
+        for Wasm(base: *mut i64, idx: v128, a: v128, mask: v128) {
+            emulated_scatter::<_, _, i64, i32, 2>(base, idx, a, Some(mask))
+        }
+    }
+}
@@ -750,3 +750,712 @@ impl_op! {
         }
     }
 }
+
+// Narrowing packs put the lanes of `a` first, then the lanes of `b`. The x86 packs interleave
+// 128 bit blocks of `a` and `b`, so wider engines reorder the 64 bit chunks afterwards.
+
+impl_op! {
+    fn narrow_i8<i16> {
+        for Avx512(a: __m512i, b: __m512i) -> __m512i {
+            let lo = _mm512_cvtepi16_epi8(a);
+            let hi = _mm512_cvtepi16_epi8(b);
+            _mm512_inserti64x4::<1>(_mm512_castsi256_si512(lo), hi)
+        }
+        for Avx2(a: __m256i, b: __m256i) -> __m256i {
+            // Clearing the high bytes keeps the unsigned saturating pack from clamping.
+            let low_bytes = _mm256_set1_epi16(0xFF);
+            let packed = _mm256_packus_epi16(
+                _mm256_and_si256(a, low_bytes),
+                _mm256_and_si256(b, low_bytes),
+            );
+            _mm256_permute4x64_epi64::<0xD8>(packed)
+        }
+        for Sse41(a: __m128i, b: __m128i) -> __m128i {
+            Ops::<Sse2, i16>::narrow_i8(a, b)
+        }
+        for Sse2(a: __m128i, b: __m128i) -> __m128i {
+            let low_bytes = _mm_set1_epi16(0xFF);
+            _mm_packus_epi16(_mm_and_si128(a, low_bytes), _mm_and_si128(b, low_bytes))
+        }
+        for Scalar(a: i16, _b: i16) -> i8 {
+            a as i8
+        }
+        for Neon(a: int16x8_t, b: int16x8_t) -> int8x16_t {
+            vcombine_s8(vmovn_s16(a), vmovn_s16(b))
+        }
+        for Wasm(a: v128, b: v128) -> v128 {
+            i8x16_shuffle::<0, 2, 4, 6, 8, 10, 12, 14, 16, 18, 20, 22, 24, 26, 28, 30>(a, b)
+        }
+    }
+}
+
+impl_op! {
+    fn saturating_narrow_i8<i16> {
+        for Avx512(a: __m512i, b: __m512i) -> __m512i {
+            let lo = _mm512_cvtsepi16_epi8(a);
+            let hi = _mm512_cvtsepi16_epi8(b);
+            _mm512_inserti64x4::<1>(_mm512_castsi256_si512(lo), hi)
+        }
+        for Avx2(a: __m256i, b: __m256i) -> __m256i {
+            _mm256_permute4x64_epi64::<0xD8>(_mm256_packs_epi16(a, b))
+        }
+        for Sse41(a: __m128i, b: __m128i) -> __m128i {
+            _mm_packs_epi16(a, b)
+        }
+        for Sse2(a: __m128i, b: __m128i) -> __m128i {
+            _mm_packs_epi16(a, b)
+        }
+        for Scalar(a: i16, _b: i16) -> i8 {
+            a.clamp(i8::MIN as i16, i8::MAX as i16) as i8
+        }
+        for Neon(a: int16x8_t, b: int16x8_t) -> int8x16_t {
+            vcombine_s8(vqmovn_s16(a), vqmovn_s16(b))
+        }
+        for Wasm(a: v128, b: v128) -> v128 {
+            i8x16_narrow_i16x8(a, b)
+        }
+    }
+}
+
+impl_op! {
+    fn saturating_narrow_u8<i16> {
+        for Avx512(a: __m512i, b: __m512i) -> __m512i {
+            let order = _mm512_set_epi64(7, 5, 3, 1, 6, 4, 2, 0);
+            _mm512_permutexvar_epi64(order, _mm512_packus_epi16(a, b))
+        }
+        for Avx2(a: __m256i, b: __m256i) -> __m256i {
+            _mm256_permute4x64_epi64::<0xD8>(_mm256_packus_epi16(a, b))
+        }
+        for Sse41(a: __m128i, b: __m128i) -> __m128i {
+            _mm_packus_epi16(a, b)
+        }
+        for Sse2(a: __m128i, b: __m128i) -> __m128i {
+            _mm_packus_epi16(a, b)
+        }
+        for Scalar(a: i16, _b: i16) -> u8 {
+            a.clamp(0, u8::MAX as i16) as u8
+        }
+        for Neon(a: int16x8_t, b: int16x8_t) -> uint8x16_t {
+            vcombine_u8(vqmovun_s16(a), vqmovun_s16(b))
+        }
+        for Wasm(a: v128, b: v128) -> v128 {
+            u8x16_narrow_i16x8(a, b)
+        }
+    }
+}
+
+impl_op! {
+    fn saturating_add<i16> {
+        for Avx512(a: __m512i, b: __m512i) -> __m512i {
+            _mm512_adds_epi16(a, b)
+        }
+        for Avx2(a: __m256i, b: __m256i) -> __m256i {
+            _mm256_adds_epi16(a, b)
+        }
+        for Sse41(a: __m128i, b: __m128i) -> __m128i {
+            _mm_adds_epi16(a, b)
+        }
+        for Sse2(a: __m128i, b: __m128i) -> __m128i {
+            _mm_adds_epi16(a, b)
+        }
+        for Scalar(a: i16, b: i16) -> i16 {
+            a.saturating_add(b)
+        }
+        for Neon(a: int16x8_t, b: int16x8_t) -> int16x8_t {
+            vqaddq_s16(a, b)
+        }
+        for Wasm(a: v128, b: v128) -> v128 {
+            i16x8_add_sat(a, b)
+        }
+    }
+}
+
+impl_op! {
+    fn saturating_sub<i16> {
+        for Avx512(a: __m512i, b: __m512i) -> __m512i {
+            _mm512_subs_epi16(a, b)
+        }
+        for Avx2(a: __m256i, b: __m256i) -> __m256i {
+            _mm256_subs_epi16(a, b)
+        }
+        for Sse41(a: __m128i, b: __m128i) -> __m128i {
+            _mm_subs_epi16(a, b)
+        }
+        for Sse2(a: __m128i, b: __m128i) -> __m128i {
+            _mm_subs_epi16(a, b)
+        }
+        for Scalar(a: i16, b: i16) -> i16 {
+            a.saturating_sub(b)
+        }
+        for Neon(a: int16x8_t, b: int16x8_t) -> int16x8_t {
+            vqsubq_s16(a, b)
+        }
+        for Wasm(a: v128, b: v128) -> v128 {
+            i16x8_sub_sat(a, b)
+        }
+    }
+}
+
+// Same sign flip as the i8 version, on top of the unsigned 16 bit rounding average.
+impl_op! {
+    fn avg<i16> {
+        for Avx512(a: __m512i, b: __m512i) -> __m512i {
+            let bias = _mm512_set1_epi16(i16::MIN);
+            let avg = _mm512_avg_epu16(_mm512_xor_si512(a, bias), _mm512_xor_si512(b, bias));
+            _mm512_xor_si512(avg, bias)
+        }
+        for Avx2(a: __m256i, b: __m256i) -> __m256i {
+            let bias = _mm256_set1_epi16(i16::MIN);
+            let avg = _mm256_avg_epu16(_mm256_xor_si256(a, bias), _mm256_xor_si256(b, bias));
+            _mm256_xor_si256(avg, bias)
+        }
+        for Sse41(a: __m128i, b: __m128i) -> __m128i {
+            Ops::<Sse2, i16>::avg(a, b)
+        }
+        for Sse2(a: __m128i, b: __m128i) -> __m128i {
+            let bias = _mm_set1_epi16(i16::MIN);
+            let avg = _mm_avg_epu16(_mm_xor_si128(a, bias), _mm_xor_si128(b, bias));
+            _mm_xor_si128(avg, bias)
+        }
+        for Scalar(a: i16, b: i16) -> i16 {
+            ((a as i32 + b as i32 + 1) >> 1) as i16
+        }
+        for Neon(a: int16x8_t, b: int16x8_t) -> int16x8_t {
+            vrhaddq_s16(a, b)
+        }
+        for Wasm(a: v128, b: v128) -> v128 {
+            let bias = i16x8_splat(i16::MIN);
+            let avg = u16x8_avgr(v128_xor(a, bias), v128_xor(b, bias));
+            v128_xor(avg, bias)
+        }
+    }
+}
+
+impl_op! {
+    fn mul_hi<i16> {
+        for Avx512(a: __m512i, b: __m512i) -> __m512i {
+            _mm512_mulhi_epi16(a, b)
+        }
+        for Avx2(a: __m256i, b: __m256i) -> __m256i {
+            _mm256_mulhi_epi16(a, b)
+        }
+        for Sse41(a: __m128i, b: __m128i) -> __m128i {
+            _mm_mulhi_epi16(a, b)
+        }
+        for Sse2(a: __m128i, b: __m128i) -> __m128i {
+            _mm_mulhi_epi16(a, b)
+        }
+        for Scalar(a: i16, b: i16) -> i16 {
+            ((a as i32 * b as i32) >> 16) as i16
+        }
+        for Neon(a: int16x8_t, b: int16x8_t) -> int16x8_t {
+            let low = vmull_s16(vget_low_s16(a), vget_low_s16(b));
+            let high = vmull_high_s16(a, b);
+            vcombine_s16(vshrn_n_s32::<16>(low), vshrn_n_s32::<16>(high))
+        }
+        for Wasm(a: v128, b: v128) -> v128 {
+            let low = i32x4_extmul_low_i16x8(a, b);
+            let high = i32x4_extmul_high_i16x8(a, b);
+            i16x8_shuffle::<1, 3, 5, 7, 9, 11, 13, 15>(low, high)
+        }
+    }
+}
+
+// The full products are interleaved from the low and high halves. On AVX2 and AVX-512 the
+// unpacks work per 128 bit lane, so the lanes are put back in order afterwards.
+impl_op! {
+    fn mul_widening<i16> {
+        for Avx512(a: __m512i, b: __m512i) -> (__m512i, __m512i) {
+            let low = _mm512_mullo_epi16(a, b);
+            let high = _mm512_mulhi_epi16(a, b);
+            let first = _mm512_unpacklo_epi16(low, high);
+            let second = _mm512_unpackhi_epi16(low, high);
+            (
+                _mm512_permutex2var_epi64(first, _mm512_set_epi64(11, 10, 3, 2, 9, 8, 1, 0), second),
+                _mm512_permutex2var_epi64(first, _mm512_set_epi64(15, 14, 7, 6, 13, 12, 5, 4), second),
+            )
+        }
+        for Avx2(a: __m256i, b: __m256i) -> (__m256i, __m256i) {
+            let low = _mm256_mullo_epi16(a, b);
+            let high = _mm256_mulhi_epi16(a, b);
+            let first = _mm256_unpacklo_epi16(low, high);
+            let second = _mm256_unpackhi_epi16(low, high);
+            (
+                _mm256_permute2x128_si256::<0x20>(first, second),
+                _mm256_permute2x128_si256::<0x31>(first, second),
+            )
+        }
+        for Sse41(a: __m128i, b: __m128i) -> (__m128i, __m128i) {
+            Ops::<Sse2, i16>::mul_widening(a, b)
+        }
+        for Sse2(a: __m128i, b: __m128i) -> (__m128i, __m128i) {
+            let low = _mm_mullo_epi16(a, b);
+            let high = _mm_mulhi_epi16(a, b);
+            (_mm_unpacklo_epi16(low, high), _mm_unpackhi_epi16(low, high))
+        }
+        for Scalar(a: i16, b: i16) -> (i32, i32) {
+            (a as i32 * b as i32, 0)
+        }
+        for Neon(a: int16x8_t, b: int16x8_t) -> (int32x4_t, int32x4_t) {
+            (vmull_s16(vget_low_s16(a), vget_low_s16(b)), vmull_high_s16(a, b))
+        }
+        for Wasm(a: v128, b: v128) -> (v128, v128) {
+            (i32x4_extmul_low_i16x8(a, b), i32x4_extmul_high_i16x8(a, b))
+        }
+    }
+}
+
+// `pmaddwd`: adjacent pairs of i32 products are added, wrapping on overflow. The scalar engine
+// has a single product and no pair.
+impl_op! {
+    fn mul_add_pairs<i16> {
+        for Avx512(a: __m512i, b: __m512i) -> __m512i {
+            _mm512_madd_epi16(a, b)
+        }
+        for Avx2(a: __m256i, b: __m256i) -> __m256i {
+            _mm256_madd_epi16(a, b)
+        }
+        for Sse41(a: __m128i, b: __m128i) -> __m128i {
+            _mm_madd_epi16(a, b)
+        }
+        for Sse2(a: __m128i, b: __m128i) -> __m128i {
+            _mm_madd_epi16(a, b)
+        }
+        for Scalar(a: i16, b: i16) -> i32 {
+            a as i32 * b as i32
+        }
+        for Neon(a: int16x8_t, b: int16x8_t) -> int32x4_t {
+            vpaddq_s32(vmull_s16(vget_low_s16(a), vget_low_s16(b)), vmull_high_s16(a, b))
+        }
+        for Wasm(a: v128, b: v128) -> v128 {
+            i32x4_dot_i16x8(a, b)
+        }
+    }
+}
+
+impl_op! {
+    fn shr_arithmetic<i16> {
+        for Avx512(a: __m512i, rhs: i32) -> __m512i {
+            _mm512_sra_epi16(a, _mm_cvtsi32_si128(wrapping_shift_count(rhs)))
+        }
+        for Avx2(a: __m256i, rhs: i32) -> __m256i {
+            _mm256_sra_epi16(a, _mm_cvtsi32_si128(wrapping_shift_count(rhs)))
+        }
+        for Sse41(a: __m128i, rhs: i32) -> __m128i {
+            Ops::<Sse2, i16>::shr_arithmetic(a, rhs)
+        }
+        for Sse2(a: __m128i, rhs: i32) -> __m128i {
+            _mm_sra_epi16(a, _mm_cvtsi32_si128(wrapping_shift_count(rhs)))
+        }
+        for Scalar(a: i16, rhs: i32) -> i16 {
+            a.wrapping_shr(wrapping_shift_count(rhs) as u32)
+        }
+        for Neon(a: int16x8_t, rhs: i32) -> int16x8_t {
+            vshlq_s16(a, Self::set1(-wrapping_shift_count(rhs) as i16))
+        }
+        for Wasm(a: v128, rhs: i32) -> v128 {
+            i16x8_shr(a, wrapping_shift_count(rhs) as u32)
+        }
+    }
+}
+
+/// Shifts each 16 bit lane by its own count, one count bit at a time, as the SSE shifts take a
+/// single count for every lane.
+#[cfg(any(target_arch = "x86_64", target_arch = "x86"))]
+#[inline(always)]
+unsafe fn sse_shift_lanes_epi16(
+    a: __m128i,
+    counts: __m128i,
+    shift: impl Fn(__m128i, __m128i) -> __m128i,
+) -> __m128i {
+    let mut result = a;
+    for bit in (0..4).rev() {
+        // Move the count bit into the sign bit and broadcast it over the lane.
+        let take = _mm_srai_epi16(_mm_sll_epi16(counts, _mm_cvtsi32_si128(15 - bit)), 15);
+        let shifted = shift(result, _mm_cvtsi32_si128(1 << bit));
+        result = _mm_or_si128(_mm_and_si128(take, shifted), _mm_andnot_si128(take, result));
+    }
+    result
+}
+
+impl_op! {
+    fn shl_lanes<i16> {
+        for Avx512(a: __m512i, counts: __m512i) -> __m512i {
+            _mm512_sllv_epi16(a, _mm512_and_si512(counts, Self::set1(15)))
+        }
+        for Avx2(a: __m256i, counts: __m256i) -> __m256i {
+            // AVX2 only has 32 bit variable shifts, so shift the even and odd 16 bit lanes separately.
+            let counts = _mm256_and_si256(counts, Self::set1(15));
+            let low = _mm256_set1_epi32(0xFFFF);
+            let even = _mm256_sllv_epi32(a, _mm256_and_si256(counts, low));
+            let odd = _mm256_sllv_epi32(_mm256_andnot_si256(low, a), _mm256_srli_epi32(counts, 16));
+            _mm256_blend_epi16(even, odd, 0b1010_1010)
+        }
+        for Sse41(a: __m128i, counts: __m128i) -> __m128i {
+            Ops::<Sse2, i16>::shl_lanes(a, counts)
+        }
+        for Sse2(a: __m128i, counts: __m128i) -> __m128i {
+            sse_shift_lanes_epi16(a, counts, |a, count| _mm_sll_epi16(a, count))
+        }
+        for Scalar(a: i16, counts: i16) -> i16 {
+            a.wrapping_shl(counts as u32)
+        }
+        for Neon(a: int16x8_t, counts: int16x8_t) -> int16x8_t {
+            vshlq_s16(a, vandq_s16(counts, Self::set1(15)))
+        }
+        for Wasm(a: v128, counts: v128) -> v128 {
+            map_lanes::<_, i16, 8>(a, counts, |lane, count| lane.wrapping_shl(count as u32))
+        }
+    }
+}
+
+impl_op! {
+    fn shr_lanes<i16> {
+        for Avx512(a: __m512i, counts: __m512i) -> __m512i {
+            _mm512_srlv_epi16(a, _mm512_and_si512(counts, Self::set1(15)))
+        }
+        for Avx2(a: __m256i, counts: __m256i) -> __m256i {
+            // AVX2 only has 32 bit variable shifts, so shift the even and odd 16 bit lanes separately.
+            let counts = _mm256_and_si256(counts, Self::set1(15));
+            let low = _mm256_set1_epi32(0xFFFF);
+            let even = _mm256_srlv_epi32(_mm256_and_si256(a, low), _mm256_and_si256(counts, low));
+            let odd = _mm256_srlv_epi32(a, _mm256_srli_epi32(counts, 16));
+            _mm256_blend_epi16(even, odd, 0b1010_1010)
+        }
+        for Sse41(a: __m128i, counts: __m128i) -> __m128i {
+            Ops::<Sse2, i16>::shr_lanes(a, counts)
+        }
+        for Sse2(a: __m128i, counts: __m128i) -> __m128i {
+            sse_shift_lanes_epi16(a, counts, |a, count| _mm_srl_epi16(a, count))
+        }
+        for Scalar(a: i16, counts: i16) -> i16 {
+            (a as u16).wrapping_shr(counts as u32) as i16
+        }
+        for Neon(a: int16x8_t, counts: int16x8_t) -> int16x8_t {
+            let counts = vnegq_s16(vandq_s16(counts, Self::set1(15)));
+            vreinterpretq_s16_u16(vshlq_u16(vreinterpretq_u16_s16(a), counts))
+        }
+        for Wasm(a: v128, counts: v128) -> v128 {
+            map_lanes::<_, i16, 8>(a, counts, |lane, count| (lane as u16).wrapping_shr(count as u32) as i16)
+        }
+    }
+}
+
+impl_op! {
+    fn shr_arithmetic_lanes<i16> {
+        for Avx512(a: __m512i, counts: __m512i) -> __m512i {
+            _mm512_srav_epi16(a, _mm512_and_si512(counts, Self::set1(15)))
+        }
+        for Avx2(a: __m256i, counts: __m256i) -> __m256i {
+            // AVX2 only has 32 bit variable shifts, so shift the even and odd 16 bit lanes separately.
+            let counts = _mm256_and_si256(counts, Self::set1(15));
+            let low = _mm256_set1_epi32(0xFFFF);
+            let even = _mm256_srav_epi32(_mm256_slli_epi32(a, 16), _mm256_and_si256(counts, low));
+            let even = _mm256_srli_epi32(even, 16);
+            let odd = _mm256_srav_epi32(a, _mm256_srli_epi32(counts, 16));
+            _mm256_blend_epi16(even, odd, 0b1010_1010)
+        }
+        for Sse41(a: __m128i, counts: __m128i) -> __m128i {
+            Ops::<Sse2, i16>::shr_arithmetic_lanes(a, counts)
+        }
+        for Sse2(a: __m128i, counts: __m128i) -> __m128i {
+            sse_shift_lanes_epi16(a, counts, |a, count| _mm_sra_epi16(a, count))
+        }
+        for Scalar(a: i16, counts: i16) -> i16 {
+            a.wrapping_shr(counts as u32)
+        }
+        for Neon(a: int16x8_t, counts: int16x8_t) -> int16x8_t {
+            vshlq_s16(a, vnegq_s16(vandq_s16(counts, Self::set1(15))))
+        }
+        for Wasm(a: v128, counts: v128) -> v128 {
+            map_lanes::<_, i16, 8>(a, counts, |lane, count| lane.wrapping_shr(count as u32))
+        }
+    }
+}
+
+impl_op! {
+    fn rotate_left<i16> {
+        for Avx512(a: __m512i, rhs: i32) -> __m512i {
+            _mm512_or_si512(Self::shl(a, rhs), Self::shr(a, rhs.wrapping_neg()))
+        }
+        for Avx2(a: __m256i, rhs: i32) -> __m256i {
+            _mm256_or_si256(Self::shl(a, rhs), Self::shr(a, rhs.wrapping_neg()))
+        }
+        for Sse41(a: __m128i, rhs: i32) -> __m128i {
+            _mm_or_si128(Self::shl(a, rhs), Self::shr(a, rhs.wrapping_neg()))
+        }
+        for Sse2(a: __m128i, rhs: i32) -> __m128i {
+            _mm_or_si128(Self::shl(a, rhs), Self::shr(a, rhs.wrapping_neg()))
+        }
+        for Scalar(a: i16, rhs: i32) -> i16 {
+            a.rotate_left(rhs as u32)
+        }
+        for Neon(a: int16x8_t, rhs: i32) -> int16x8_t {
+            vorrq_s16(Self::shl(a, rhs), Self::shr(a, rhs.wrapping_neg()))
+        }
+        for Wasm(a: v128, rhs: i32) -> v128 {
+            v128_or(Self::shl(a, rhs), Self::shr(a, rhs.wrapping_neg()))
+        }
+    }
+}
+
+impl_op! {
+    fn rotate_left_lanes<i16> {
+        for Avx512(a: __m512i, counts: __m512i) -> __m512i {
+            let back = Self::sub(Self::zeroes(), counts);
+            _mm512_or_si512(Self::shl_lanes(a, counts), Self::shr_lanes(a, back))
+        }
+        for Avx2(a: __m256i, counts: __m256i) -> __m256i {
+            let back = Self::sub(Self::zeroes(), counts);
+            _mm256_or_si256(Self::shl_lanes(a, counts), Self::shr_lanes(a, back))
+        }
+        for Sse41(a: __m128i, counts: __m128i) -> __m128i {
+            let back = Self::sub(Self::zeroes(), counts);
+            _mm_or_si128(Self::shl_lanes(a, counts), Self::shr_lanes(a, back))
+        }
+        for Sse2(a: __m128i, counts: __m128i) -> __m128i {
+            let back = Self::sub(Self::zeroes(), counts);
+            _mm_or_si128(Self::shl_lanes(a, counts), Self::shr_lanes(a, back))
+        }
+        for Scalar(a: i16, counts: i16) -> i16 {
+            a.rotate_left(counts as u32)
+        }
+        for Neon(a: int16x8_t, counts: int16x8_t) -> int16x8_t {
+            let back = Self::sub(Self::zeroes(), counts);
+            vorrq_s16(Self::shl_lanes(a, counts), Self::shr_lanes(a, back))
+        }
+        for Wasm(a: v128, counts: v128) -> v128 {
+            let back = Self::sub(Self::zeroes(), counts);
+            v128_or(Self::shl_lanes(a, counts), Self::shr_lanes(a, back))
+        }
+    }
+}
+
+impl_op! {
+    fn rotate_right<i16> {
+        for Avx512(a: __m512i, rhs: i32) -> __m512i {
+            _mm512_or_si512(Self::shr(a, rhs), Self::shl(a, rhs.wrapping_neg()))
+        }
+        for Avx2(a: __m256i, rhs: i32) -> __m256i {
+            _mm256_or_si256(Self::shr(a, rhs), Self::shl(a, rhs.wrapping_neg()))
+        }
+        for Sse41(a: __m128i, rhs: i32) -> __m128i {
+            _mm_or_si128(Self::shr(a, rhs), Self::shl(a, rhs.wrapping_neg()))
+        }
+        for Sse2(a: __m128i, rhs: i32) -> __m128i {
+            _mm_or_si128(Self::shr(a, rhs), Self::shl(a, rhs.wrapping_neg()))
+        }
+        for Scalar(a: i16, rhs: i32) -> i16 {
+            a.rotate_right(rhs as u32)
+        }
+        for Neon(a: int16x8_t, rhs: i32) -> int16x8_t {
+            vorrq_s16(Self::shr(a, rhs), Self::shl(a, rhs.wrapping_neg()))
+        }
+        for Wasm(a: v128, rhs: i32) -> v128 {
+            v128_or(Self::shr(a, rhs), Self::shl(a, rhs.wrapping_neg()))
+        }
+    }
+}
+
+impl_op! {
+    fn rotate_right_lanes<i16> {
+        for Avx512(a: __m512i, counts: __m512i) -> __m512i {
+            let back = Self::sub(Self::zeroes(), counts);
+            _mm512_or_si512(Self::shr_lanes(a, counts), Self::shl_lanes(a, back))
+        }
+        for Avx2(a: __m256i, counts: __m256i) -> __m256i {
+            let back = Self::sub(Self::zeroes(), counts);
+            _mm256_or_si256(Self::shr_lanes(a, counts), Self::shl_lanes(a, back))
+        }
+        for Sse41(a: __m128i, counts: __m128i) -> __m128i {
+            let back = Self::sub(Self::zeroes(), counts);
+            _mm_or_si128(Self::shr_lanes(a, counts), Self::shl_lanes(a, back))
+        }
+        for Sse2(a: __m128i, counts: __m128i) -> __m128i {
+            let back = Self::sub(Self::zeroes(), counts);
+            _mm_or_si128(Self::shr_lanes(a, counts), Self::shl_lanes(a, back))
+        }
+        for Scalar(a: i16, counts: i16) -> i16 {
+            a.rotate_right(counts as u32)
+        }
+        for Neon(a: int16x8_t, counts: int16x8_t) -> int16x8_t {
+            let back = Self::sub(Self::zeroes(), counts);
+            vorrq_s16(Self::shr_lanes(a, counts), Self::shl_lanes(a, back))
+        }
+        for Wasm(a: v128, counts: v128) -> v128 {
+            let back = Self::sub(Self::zeroes(), counts);
+            v128_or(Self::shr_lanes(a, counts), Self::shl_lanes(a, back))
+        }
+    }
+}
+
+#[cfg(any(target_arch = "x86_64", target_arch = "x86"))]
+#[target_feature(enable = "avx512f", enable = "avx512bw", enable = "avx512bitalg")]
+#[inline]
+unsafe fn avx512_bitalg_popcnt_epi16(a: __m512i) -> __m512i {
+    _mm512_popcnt_epi16(a)
+}
+
+// Sums the byte counts of each lane.
+impl_op! {
+    fn count_ones<i16> {
+        for Avx512(a: __m512i) -> __m512i {
+            if has_feature(CpuFeature::Avx512Bitalg) {
+                return avx512_bitalg_popcnt_epi16(a);
+            }
+            let bytes = Ops::<Avx512, i8>::count_ones(a);
+            _mm512_add_epi16(_mm512_and_si512(bytes, _mm512_set1_epi16(0xFF)), _mm512_srli_epi16(bytes, 8))
+        }
+        for Avx2(a: __m256i) -> __m256i {
+            let bytes = Ops::<Avx2, i8>::count_ones(a);
+            _mm256_add_epi16(_mm256_and_si256(bytes, _mm256_set1_epi16(0xFF)), _mm256_srli_epi16(bytes, 8))
+        }
+        for Sse41(a: __m128i) -> __m128i {
+            let bytes = Ops::<Sse41, i8>::count_ones(a);
+            _mm_add_epi16(_mm_and_si128(bytes, _mm_set1_epi16(0xFF)), _mm_srli_epi16(bytes, 8))
+        }
+        for Sse2(a: __m128i) -> __m128i {
+            let bytes = Ops::<Sse2, i8>::count_ones(a);
+            _mm_add_epi16(_mm_and_si128(bytes, _mm_set1_epi16(0xFF)), _mm_srli_epi16(bytes, 8))
+        }
+        for Scalar(a: i16) -> i16 {
+            a.count_ones() as i16
+        }
+        for Neon(a: int16x8_t) -> int16x8_t {
+            vreinterpretq_s16_u16(vpaddlq_u8(vcntq_u8(vreinterpretq_u8_s16(a))))
+        }
+        for Wasm(a: v128) -> v128 {
+            i16x8_extadd_pairwise_u8x16(i8x16_popcnt(a))
+        }
+    }
+}
+
+// Fills in the bits below the highest set bit and counts the rest.
+impl_op! {
+    fn leading_zeros<i16> {
+        for Avx512(a: __m512i) -> __m512i {
+            let a = _mm512_or_si512(a, _mm512_srli_epi16(a, 1));
+            let a = _mm512_or_si512(a, _mm512_srli_epi16(a, 2));
+            let a = _mm512_or_si512(a, _mm512_srli_epi16(a, 4));
+            let a = _mm512_or_si512(a, _mm512_srli_epi16(a, 8));
+            _mm512_sub_epi16(_mm512_set1_epi16(16), Self::count_ones(a))
+        }
+        for Avx2(a: __m256i) -> __m256i {
+            let a = _mm256_or_si256(a, _mm256_srli_epi16(a, 1));
+            let a = _mm256_or_si256(a, _mm256_srli_epi16(a, 2));
+            let a = _mm256_or_si256(a, _mm256_srli_epi16(a, 4));
+            let a = _mm256_or_si256(a, _mm256_srli_epi16(a, 8));
+            _mm256_sub_epi16(_mm256_set1_epi16(16), Self::count_ones(a))
+        }
+        for Sse41(a: __m128i) -> __m128i {
+            Ops::<Sse2, i16>::leading_zeros(a)
+        }
+        for Sse2(a: __m128i) -> __m128i {
+            let a = _mm_or_si128(a, _mm_srli_epi16(a, 1));
+            let a = _mm_or_si128(a, _mm_srli_epi16(a, 2));
+            let a = _mm_or_si128(a, _mm_srli_epi16(a, 4));
+            let a = _mm_or_si128(a, _mm_srli_epi16(a, 8));
+            _mm_sub_epi16(_mm_set1_epi16(16), Self::count_ones(a))
+        }
+        for Scalar(a: i16) -> i16 {
+            a.leading_zeros() as i16
+        }
+        for Neon(a: int16x8_t) -> int16x8_t {
+            vclzq_s16(a)
+        }
+        for Wasm(a: v128) -> v128 {
+            let a = v128_or(a, u16x8_shr(a, 1));
+            let a = v128_or(a, u16x8_shr(a, 2));
+            let a = v128_or(a, u16x8_shr(a, 4));
+            let a = v128_or(a, u16x8_shr(a, 8));
+            i16x8_sub(i16x8_splat(16), Self::count_ones(a))
+        }
+    }
+}
+
+// Counts the set bits of `!a & (a - 1)`, which are the bits below the lowest set bit.
+impl_op! {
+    fn trailing_zeros<i16> {
+        for Avx512(a: __m512i) -> __m512i {
+            Self::count_ones(_mm512_andnot_si512(a, _mm512_sub_epi16(a, _mm512_set1_epi16(1))))
+        }
+        for Avx2(a: __m256i) -> __m256i {
+            Self::count_ones(_mm256_andnot_si256(a, _mm256_sub_epi16(a, _mm256_set1_epi16(1))))
+        }
+        for Sse41(a: __m128i) -> __m128i {
+            Self::count_ones(_mm_andnot_si128(a, _mm_sub_epi16(a, _mm_set1_epi16(1))))
+        }
+        for Sse2(a: __m128i) -> __m128i {
+            Self::count_ones(_mm_andnot_si128(a, _mm_sub_epi16(a, _mm_set1_epi16(1))))
+        }
+        for Scalar(a: i16) -> i16 {
+            a.trailing_zeros() as i16
+        }
+        for Neon(a: int16x8_t) -> int16x8_t {
+            Self::count_ones(vbicq_s16(vsubq_s16(a, vdupq_n_s16(1)), a))
+        }
+        for Wasm(a: v128) -> v128 {
+            Self::count_ones(v128_andnot(i16x8_sub(a, i16x8_splat(1)), a))
+        }
+    }
+}
+
+// Swaps the bytes of each lane, then reverses the bits of every byte.
+impl_op! {
+    fn reverse_bits<i16> {
+        for Avx512(a: __m512i) -> __m512i {
+            let indices = core::mem::transmute::<[i8; 16], __m128i>(byte_reverse_indices(2));
+            let a = _mm512_shuffle_epi8(a, _mm512_broadcast_i32x4(indices));
+            Ops::<Avx512, i8>::reverse_bits(a)
+        }
+        for Avx2(a: __m256i) -> __m256i {
+            let indices = core::mem::transmute::<[i8; 16], __m128i>(byte_reverse_indices(2));
+            let a = _mm256_shuffle_epi8(a, _mm256_broadcastsi128_si256(indices));
+            Ops::<Avx2, i8>::reverse_bits(a)
+        }
+        for Sse41(a: __m128i) -> __m128i {
+            let indices = core::mem::transmute::<[i8; 16], __m128i>(byte_reverse_indices(2));
+            Ops::<Sse41, i8>::reverse_bits(_mm_shuffle_epi8(a, indices))
+        }
+        for Sse2(a: __m128i) -> __m128i {
+            let a = _mm_or_si128(_mm_slli_epi16(a, 8), _mm_srli_epi16(a, 8));
+            Ops::<Sse2, i8>::reverse_bits(a)
+        }
+        for Scalar(a: i16) -> i16 {
+            a.reverse_bits()
+        }
+        for Neon(a: int16x8_t) -> int16x8_t {
+            vreinterpretq_s16_u8(vrbitq_u8(vrev16q_u8(vreinterpretq_u8_s16(a))))
+        }
+        for Wasm(a: v128) -> v128 {
+            let indices = core::mem::transmute::<[i8; 16], v128>(byte_reverse_indices(2));
+            Ops::<Wasm, i8>::reverse_bits(i8x16_swizzle(a, indices))
+        }
+    }
+}
+
+impl_op! {
+    fn popcount_total<i16> {
+        for Avx512(a: __m512i) -> u32 {
+            Ops::<Avx512, i8>::popcount_total(a)
+        }
+        for Avx2(a: __m256i) -> u32 {
+            Ops::<Avx2, i8>::popcount_total(a)
+        }
+        for Sse41(a: __m128i) -> u32 {
+            Ops::<Sse41, i8>::popcount_total(a)
+        }
+        for Sse2(a: __m128i) -> u32 {
+            Ops::<Sse2, i8>::popcount_total(a)
+        }
+        for Scalar(a: i16) -> u32 {
+            a.count_ones()
+        }
+        for Neon(a: int16x8_t) -> u32 {
+            Ops::<Neon, i8>::popcount_total(vreinterpretq_s8_s16(a))
+        }
+        for Wasm(a: v128) -> u32 {
+            Ops::<Wasm, i8>::popcount_total(a)
+        }
+    }
+}
@@ -805,3 +805,765 @@ impl_op! {
         }
     }
 }
+
+impl_op! {
+    fn extend_f64<i32> {
+        for Avx512(val: __m512i) -> (__m512d, __m512d) {
+            let a = _mm512_cvtepi32_pd(_mm512_castsi512_si256(val));
+            let b = _mm512_cvtepi32_pd(_mm512_extracti64x4_epi64::<1>(val));
+            (a, b)
+        }
+        for Avx2(val: __m256i) -> (__m256d, __m256d) {
+            let a = _mm256_cvtepi32_pd(_mm256_castsi256_si128(val));
+            let b = _mm256_cvtepi32_pd(_mm256_extracti128_si256::<1>(val));
+            (a, b)
+        }
+        for Sse41(val: __m128i) -> (__m128d, __m128d) {
+            Ops::<Sse2, i32>::extend_f64(val)
+        }
+        for Sse2(val: __m128i) -> (__m128d, __m128d) {
+            let a = _mm_cvtepi32_pd(val);
+            let b = _mm_cvtepi32_pd(_mm_unpackhi_epi64(val, val));
+            (a, b)
+        }
+        for Scalar(val: i32) -> (f64, f64) {
+            (val as f64, 0.0)
+        }
+        for Neon(val: int32x4_t) -> (float64x2_t, float64x2_t) {
+            let a = vcvtq_f64_s64(vmovl_s32(vget_low_s32(val)));
+            let b = vcvtq_f64_s64(vmovl_high_s32(val));
+            (a, b)
+        }
+        for Wasm(val: v128) -> (v128, v128) {
+            let a = f64x2_convert_low_i32x4(val);
+            let b = f64x2_convert_low_i32x4(i32x4_shuffle::<2, 3, 2, 3>(val, val));
+            (a, b)
+        }
+    }
+}
+
+// Narrowing packs put the lanes of `a` first, then the lanes of `b`. The x86 packs interleave
+// 128 bit blocks of `a` and `b`, so wider engines reorder the 64 bit chunks afterwards.
+
+impl_op! {
+    fn narrow_i16<i32> {
+        for Avx512(a: __m512i, b: __m512i) -> __m512i {
+            let lo = _mm512_cvtepi32_epi16(a);
+            let hi = _mm512_cvtepi32_epi16(b);
+            _mm512_inserti64x4::<1>(_mm512_castsi256_si512(lo), hi)
+        }
+        for Avx2(a: __m256i, b: __m256i) -> __m256i {
+            // Sign extending the low half makes every lane fit, so the pack can't saturate.
+            let a = _mm256_srai_epi32::<16>(_mm256_slli_epi32::<16>(a));
+            let b = _mm256_srai_epi32::<16>(_mm256_slli_epi32::<16>(b));
+            _mm256_permute4x64_epi64::<0xD8>(_mm256_packs_epi32(a, b))
+        }
+        for Sse41(a: __m128i, b: __m128i) -> __m128i {
+            Ops::<Sse2, i32>::narrow_i16(a, b)
+        }
+        for Sse2(a: __m128i, b: __m128i) -> __m128i {
+            let a = _mm_srai_epi32::<16>(_mm_slli_epi32::<16>(a));
+            let b = _mm_srai_epi32::<16>(_mm_slli_epi32::<16>(b));
+            _mm_packs_epi32(a, b)
+        }
+        for Scalar(a: i32, _b: i32) -> i16 {
+            a as i16
+        }
+        for Neon(a: int32x4_t, b: int32x4_t) -> int16x8_t {
+            vcombine_s16(vmovn_s32(a), vmovn_s32(b))
+        }
+        for Wasm(a: v128, b: v128) -> v128 {
+            i16x8_shuffle::<0, 2, 4, 6, 8, 10, 12, 14>(a, b)
+        }
+    }
+}
+
+impl_op! {
+    fn saturating_narrow_i16<i32> {
+        for Avx512(a: __m512i, b: __m512i) -> __m512i {
+            let lo = _mm512_cvtsepi32_epi16(a);
+            let hi = _mm512_cvtsepi32_epi16(b);
+            _mm512_inserti64x4::<1>(_mm512_castsi256_si512(lo), hi)
+        }
+        for Avx2(a: __m256i, b: __m256i) -> __m256i {
+            _mm256_permute4x64_epi64::<0xD8>(_mm256_packs_epi32(a, b))
+        }
+        for Sse41(a: __m128i, b: __m128i) -> __m128i {
+            _mm_packs_epi32(a, b)
+        }
+        for Sse2(a: __m128i, b: __m128i) -> __m128i {
+            _mm_packs_epi32(a, b)
+        }
+        for Scalar(a: i32, _b: i32) -> i16 {
+            a.clamp(i16::MIN as i32, i16::MAX as i32) as i16
+        }
+        for Neon(a: int32x4_t, b: int32x4_t) -> int16x8_t {
+            vcombine_s16(vqmovn_s32(a), vqmovn_s32(b))
+        }
+        for Wasm(a: v128, b: v128) -> v128 {
+            i16x8_narrow_i32x4(a, b)
+        }
+    }
+}
+
+impl_op! {
+    fn saturating_narrow_u16<i32> {
+        for Avx512(a: __m512i, b: __m512i) -> __m512i {
+            let order = _mm512_set_epi64(7, 5, 3, 1, 6, 4, 2, 0);
+            _mm512_permutexvar_epi64(order, _mm512_packus_epi32(a, b))
+        }
+        for Avx2(a: __m256i, b: __m256i) -> __m256i {
+            _mm256_permute4x64_epi64::<0xD8>(_mm256_packus_epi32(a, b))
+        }
+        for Sse41(a: __m128i, b: __m128i) -> __m128i {
+            _mm_packus_epi32(a, b)
+        }
+        for Sse2(a: __m128i, b: __m128i) -> __m128i {
+            // There's no unsigned pack before SSE4.1: clamp to the u16 range, then sign extend
+            // the low half so the signed pack keeps the bits.
+            let zero = _mm_setzero_si128();
+            let max = _mm_set1_epi32(u16::MAX as i32);
+            let a = _mm_and_si128(a, _mm_cmpgt_epi32(a, zero));
+            let b = _mm_and_si128(b, _mm_cmpgt_epi32(b, zero));
+            let a_over = _mm_cmpgt_epi32(a, max);
+            let b_over = _mm_cmpgt_epi32(b, max);
+            let a = _mm_or_si128(_mm_andnot_si128(a_over, a), _mm_and_si128(a_over, max));
+            let b = _mm_or_si128(_mm_andnot_si128(b_over, b), _mm_and_si128(b_over, max));
+            let a = _mm_srai_epi32::<16>(_mm_slli_epi32::<16>(a));
+            let b = _mm_srai_epi32::<16>(_mm_slli_epi32::<16>(b));
+            _mm_packs_epi32(a, b)
+        }
+        for Scalar(a: i32, _b: i32) -> u16 {
+            a.clamp(0, u16::MAX as i32) as u16
+        }
+        for Neon(a: int32x4_t, b: int32x4_t) -> uint16x8_t {
+            vcombine_u16(vqmovun_s32(a), vqmovun_s32(b))
+        }
+        for Wasm(a: v128, b: v128) -> v128 {
+            u16x8_narrow_i32x4(a, b)
+        }
+    }
+}
+
+// x86 and wasm have no saturating 32 bit add or subtract. A lane overflowed when both inputs of
+// the add (or `a` and `-b` for the subtract) have a sign different from the result, and then
+// saturates towards the sign of `a`.
+impl_op! {
+    fn saturating_add<i32> {
+        for Avx512(a: __m512i, b: __m512i) -> __m512i {
+            let sum = _mm512_add_epi32(a, b);
+            let overflow = _mm512_and_si512(_mm512_xor_si512(a, sum), _mm512_xor_si512(b, sum));
+            let saturated = _mm512_xor_si512(_mm512_srai_epi32(a, 31), _mm512_set1_epi32(i32::MAX));
+            _mm512_mask_mov_epi32(sum, _mm512_movepi32_mask(overflow), saturated)
+        }
+        for Avx2(a: __m256i, b: __m256i) -> __m256i {
+            let sum = _mm256_add_epi32(a, b);
+            let overflow = _mm256_and_si256(_mm256_xor_si256(a, sum), _mm256_xor_si256(b, sum));
+            let saturated = _mm256_xor_si256(_mm256_srai_epi32(a, 31), _mm256_set1_epi32(i32::MAX));
+            _mm256_castps_si256(_mm256_blendv_ps(
+                _mm256_castsi256_ps(sum),
+                _mm256_castsi256_ps(saturated),
+                _mm256_castsi256_ps(overflow),
+            ))
+        }
+        for Sse41(a: __m128i, b: __m128i) -> __m128i {
+            let sum = _mm_add_epi32(a, b);
+            let overflow = _mm_and_si128(_mm_xor_si128(a, sum), _mm_xor_si128(b, sum));
+            let saturated = _mm_xor_si128(_mm_srai_epi32(a, 31), _mm_set1_epi32(i32::MAX));
+            _mm_castps_si128(_mm_blendv_ps(
+                _mm_castsi128_ps(sum),
+                _mm_castsi128_ps(saturated),
+                _mm_castsi128_ps(overflow),
+            ))
+        }
+        for Sse2(a: __m128i, b: __m128i) -> __m128i {
+            let sum = _mm_add_epi32(a, b);
+            let overflow = _mm_and_si128(_mm_xor_si128(a, sum), _mm_xor_si128(b, sum));
+            let overflow = _mm_srai_epi32(overflow, 31);
+            let saturated = _mm_xor_si128(_mm_srai_epi32(a, 31), _mm_set1_epi32(i32::MAX));
+            _mm_or_si128(_mm_and_si128(overflow, saturated), _mm_andnot_si128(overflow, sum))
+        }
+        for Scalar(a: i32, b: i32) -> i32 {
+            a.saturating_add(b)
+        }
+        for Neon(a: int32x4_t, b: int32x4_t) -> int32x4_t {
+            vqaddq_s32(a, b)
+        }
+        for Wasm(a: v128, b: v128) -> v128 {
+            let sum = i32x4_add(a, b);
+            let overflow = i32x4_shr(v128_and(v128_xor(a, sum), v128_xor(b, sum)), 31);
+            let saturated = v128_xor(i32x4_shr(a, 31), i32x4_splat(i32::MAX));
+            v128_bitselect(saturated, sum, overflow)
+        }
+    }
+}
+
+impl_op! {
+    fn saturating_sub<i32> {
+        for Avx512(a: __m512i, b: __m512i) -> __m512i {
+            let diff = _mm512_sub_epi32(a, b);
+            let overflow = _mm512_and_si512(_mm512_xor_si512(a, b), _mm512_xor_si512(a, diff));
+            let saturated = _mm512_xor_si512(_mm512_srai_epi32(a, 31), _mm512_set1_epi32(i32::MAX));
+            _mm512_mask_mov_epi32(diff, _mm512_movepi32_mask(overflow), saturated)
+        }
+        for Avx2(a: __m256i, b: __m256i) -> __m256i {
+            let diff = _mm256_sub_epi32(a, b);
+            let overflow = _mm256_and_si256(_mm256_xor_si256(a, b), _mm256_xor_si256(a, diff));
+            let saturated = _mm256_xor_si256(_mm256_srai_epi32(a, 31), _mm256_set1_epi32(i32::MAX));
+            _mm256_castps_si256(_mm256_blendv_ps(
+                _mm256_castsi256_ps(diff),
+                _mm256_castsi256_ps(saturated),
+                _mm256_castsi256_ps(overflow),
+            ))
+        }
+        for Sse41(a: __m128i, b: __m128i) -> __m128i {
+            let diff = _mm_sub_epi32(a, b);
+            let overflow = _mm_and_si128(_mm_xor_si128(a, b), _mm_xor_si128(a, diff));
+            let saturated = _mm_xor_si128(_mm_srai_epi32(a, 31), _mm_set1_epi32(i32::MAX));
+            _mm_castps_si128(_mm_blendv_ps(
+                _mm_castsi128_ps(diff),
+                _mm_castsi128_ps(saturated),
+                _mm_castsi128_ps(overflow),
+            ))
+        }
+        for Sse2(a: __m128i, b: __m128i) -> __m128i {
+            let diff = _mm_sub_epi32(a, b);
+            let overflow = _mm_and_si128(_mm_xor_si128(a, b), _mm_xor_si128(a, diff));
+            let overflow = _mm_srai_epi32(overflow, 31);
+            let saturated = _mm_xor_si128(_mm_srai_epi32(a, 31), _mm_set1_epi32(i32::MAX));
+            _mm_or_si128(_mm_and_si128(overflow, saturated), _mm_andnot_si128(overflow, diff))
+        }
+        for Scalar(a: i32, b: i32) -> i32 {
+            a.saturating_sub(b)
+        }
+        for Neon(a: int32x4_t, b: int32x4_t) -> int32x4_t {
+            vqsubq_s32(a, b)
+        }
+        for Wasm(a: v128, b: v128) -> v128 {
+            let diff = i32x4_sub(a, b);
+            let overflow = i32x4_shr(v128_and(v128_xor(a, b), v128_xor(a, diff)), 31);
+            let saturated = v128_xor(i32x4_shr(a, 31), i32x4_splat(i32::MAX));
+            v128_bitselect(saturated, diff, overflow)
+        }
+    }
+}
+
+// `(a | b) - ((a ^ b) >> 1)` is the average rounded up, computed without overflow.
+impl_op! {
+    fn avg<i32> {
+        for Avx512(a: __m512i, b: __m512i) -> __m512i {
+            _mm512_sub_epi32(_mm512_or_si512(a, b), _mm512_srai_epi32(_mm512_xor_si512(a, b), 1))
+        }
+        for Avx2(a: __m256i, b: __m256i) -> __m256i {
+            _mm256_sub_epi32(_mm256_or_si256(a, b), _mm256_srai_epi32(_mm256_xor_si256(a, b), 1))
+        }
+        for Sse41(a: __m128i, b: __m128i) -> __m128i {
+            Ops::<Sse2, i32>::avg(a, b)
+        }
+        for Sse2(a: __m128i, b: __m128i) -> __m128i {
+            _mm_sub_epi32(_mm_or_si128(a, b), _mm_srai_epi32(_mm_xor_si128(a, b), 1))
+        }
+        for Scalar(a: i32, b: i32) -> i32 {
+            ((a as i64 + b as i64 + 1) >> 1) as i32
+        }
+        for Neon(a: int32x4_t, b: int32x4_t) -> int32x4_t {
+            vrhaddq_s32(a, b)
+        }
+        for Wasm(a: v128, b: v128) -> v128 {
+            i32x4_sub(v128_or(a, b), i32x4_shr(v128_xor(a, b), 1))
+        }
+    }
+}
+
+// x86 multiplies the even lanes into 64 bit products, so the odd lanes are shifted down and
+// multiplied separately, then the high halves of both are merged.
+impl_op! {
+    fn mul_hi<i32> {
+        for Avx512(a: __m512i, b: __m512i) -> __m512i {
+            let even = _mm512_mul_epi32(a, b);
+            let odd = _mm512_mul_epi32(_mm512_srli_epi64(a, 32), _mm512_srli_epi64(b, 32));
+            _mm512_mask_mov_epi32(_mm512_srli_epi64(even, 32), 0xAAAA, odd)
+        }
+        for Avx2(a: __m256i, b: __m256i) -> __m256i {
+            let even = _mm256_mul_epi32(a, b);
+            let odd = _mm256_mul_epi32(_mm256_srli_epi64(a, 32), _mm256_srli_epi64(b, 32));
+            _mm256_blend_epi32::<0b10101010>(_mm256_srli_epi64(even, 32), odd)
+        }
+        for Sse41(a: __m128i, b: __m128i) -> __m128i {
+            let even = _mm_mul_epi32(a, b);
+            let odd = _mm_mul_epi32(_mm_srli_epi64(a, 32), _mm_srli_epi64(b, 32));
+            _mm_blend_epi16::<0b11001100>(_mm_srli_epi64(even, 32), odd)
+        }
+        for Sse2(a: __m128i, b: __m128i) -> __m128i {
+            // SSE2 only has the unsigned multiply: the signed high half is the unsigned one minus
+            // `b` where `a` is negative and minus `a` where `b` is negative.
+            let high = Ops::<Sse2, u32>::mul_hi(a, b);
+            let high = _mm_sub_epi32(high, _mm_and_si128(_mm_srai_epi32(a, 31), b));
+            _mm_sub_epi32(high, _mm_and_si128(_mm_srai_epi32(b, 31), a))
+        }
+        for Scalar(a: i32, b: i32) -> i32 {
+            ((a as i64 * b as i64) >> 32) as i32
+        }
+        for Neon(a: int32x4_t, b: int32x4_t) -> int32x4_t {
+            let low = vmull_s32(vget_low_s32(a), vget_low_s32(b));
+            let high = vmull_high_s32(a, b);
+            vcombine_s32(vshrn_n_s64::<32>(low), vshrn_n_s64::<32>(high))
+        }
+        for Wasm(a: v128, b: v128) -> v128 {
+            let low = i64x2_extmul_low_i32x4(a, b);
+            let high = i64x2_extmul_high_i32x4(a, b);
+            i32x4_shuffle::<1, 3, 5, 7>(low, high)
+        }
+    }
+}
+
+// After sign extension the low 32 bits of each 64 bit lane hold the value, which is exactly
+// what `pmuldq` multiplies.
+impl_op! {
+    fn mul_widening<i32> {
+        for Avx512(a: __m512i, b: __m512i) -> (__m512i, __m512i) {
+            let (a_low, a_high) = Ops::<Avx512, i32>::extend_i64(a);
+            let (b_low, b_high) = Ops::<Avx512, i32>::extend_i64(b);
+            (_mm512_mul_epi32(a_low, b_low), _mm512_mul_epi32(a_high, b_high))
+        }
+        for Avx2(a: __m256i, b: __m256i) -> (__m256i, __m256i) {
+            let (a_low, a_high) = Ops::<Avx2, i32>::extend_i64(a);
+            let (b_low, b_high) = Ops::<Avx2, i32>::extend_i64(b);
+            (_mm256_mul_epi32(a_low, b_low), _mm256_mul_epi32(a_high, b_high))
+        }
+        for Sse41(a: __m128i, b: __m128i) -> (__m128i, __m128i) {
+            let (a_low, a_high) = Ops::<Sse41, i32>::extend_i64(a);
+            let (b_low, b_high) = Ops::<Sse41, i32>::extend_i64(b);
+            (_mm_mul_epi32(a_low, b_low), _mm_mul_epi32(a_high, b_high))
+        }
+        for Sse2(a: __m128i, b: __m128i) -> (__m128i, __m128i) {
+            let (a_low, a_high) = Ops::<Sse2, i32>::extend_i64(a);
+            let (b_low, b_high) = Ops::<Sse2, i32>::extend_i64(b);
+            (Ops::<Sse2, i64>::mul(a_low, b_low), Ops::<Sse2, i64>::mul(a_high, b_high))
+        }
+        for Scalar(a: i32, b: i32) -> (i64, i64) {
+            (a as i64 * b as i64, 0)
+        }
+        for Neon(a: int32x4_t, b: int32x4_t) -> (int64x2_t, int64x2_t) {
+            (vmull_s32(vget_low_s32(a), vget_low_s32(b)), vmull_high_s32(a, b))
+        }
+        for Wasm(a: v128, b: v128) -> (v128, v128) {
+            (i64x2_extmul_low_i32x4(a, b), i64x2_extmul_high_i32x4(a, b))
+        }
+    }
+}
+
+impl_op! {
+    fn shr_arithmetic<i32> {
+        for Avx512(a: __m512i, rhs: i32) -> __m512i {
+            _mm512_sra_epi32(a, _mm_cvtsi32_si128(wrapping_shift_count(rhs)))
+        }
+        for Avx2(a: __m256i, rhs: i32) -> __m256i {
+            _mm256_sra_epi32(a, _mm_cvtsi32_si128(wrapping_shift_count(rhs)))
+        }
+        for Sse41(a: __m128i, rhs: i32) -> __m128i {
+            Ops::<Sse2, i32>::shr_arithmetic(a, rhs)
+        }
+        for Sse2(a: __m128i, rhs: i32) -> __m128i {
+            _mm_sra_epi32(a, _mm_cvtsi32_si128(wrapping_shift_count(rhs)))
+        }
+        for Scalar(a: i32, rhs: i32) -> i32 {
+            a.wrapping_shr(wrapping_shift_count(rhs) as u32)
+        }
+        for Neon(a: int32x4_t, rhs: i32) -> int32x4_t {
+            vshlq_s32(a, Self::set1(-wrapping_shift_count(rhs)))
+        }
+        for Wasm(a: v128, rhs: i32) -> v128 {
+            i32x4_shr(a, wrapping_shift_count(rhs) as u32)
+        }
+    }
+}
+
+/// Shifts each 32 bit lane by its own count, as the SSE shifts take a single count for every lane.
+#[cfg(any(target_arch = "x86_64", target_arch = "x86"))]
+#[inline(always)]
+unsafe fn sse_shift_lanes_epi32(
+    a: __m128i,
+    counts: __m128i,
+    shift: impl Fn(__m128i, __m128i) -> __m128i,
+) -> __m128i {
+    // The shift count is the low 64 bits, so zero extend each count into its own half.
+    let counts = _mm_and_si128(counts, _mm_set1_epi32(31));
+    let low = _mm_unpacklo_epi32(counts, _mm_setzero_si128());
+    let high = _mm_unpackhi_epi32(counts, _mm_setzero_si128());
+    let first = _mm_unpacklo_epi64(shift(a, low), shift(a, _mm_srli_si128::<8>(low)));
+    let second = _mm_unpackhi_epi64(shift(a, high), shift(a, _mm_srli_si128::<8>(high)));
+    // Keep lane i of the i-th shift.
+    _mm_castps_si128(_mm_shuffle_ps::<0b11_00_11_00>(
+        _mm_castsi128_ps(first),
+        _mm_castsi128_ps(second),
+    ))
+}
+
+impl_op! {
+    fn shl_lanes<i32> {
+        for Avx512(a: __m512i, counts: __m512i) -> __m512i {
+            _mm512_sllv_epi32(a, _mm512_and_si512(counts, Self::set1(31)))
+        }
+        for Avx2(a: __m256i, counts: __m256i) -> __m256i {
+            _mm256_sllv_epi32(a, _mm256_and_si256(counts, Self::set1(31)))
+        }
+        for Sse41(a: __m128i, counts: __m128i) -> __m128i {
+            // Multiply by 1 << count, built by writing the count into the exponent of a float.
+            let counts = _mm_and_si128(counts, Self::set1(31));
+            let powers = _mm_add_epi32(_mm_slli_epi32(counts, 23), _mm_set1_epi32(0x3F80_0000));
+            _mm_mullo_epi32(a, _mm_cvttps_epi32(_mm_castsi128_ps(powers)))
+        }
+        for Sse2(a: __m128i, counts: __m128i) -> __m128i {
+            sse_shift_lanes_epi32(a, counts, |a, count| _mm_sll_epi32(a, count))
+        }
+        for Scalar(a: i32, counts: i32) -> i32 {
+            a.wrapping_shl(counts as u32)
+        }
+        for Neon(a: int32x4_t, counts: int32x4_t) -> int32x4_t {
+            vshlq_s32(a, vandq_s32(counts, Self::set1(31)))
+        }
+        for Wasm(a: v128, counts: v128) -> v128 {
+            map_lanes::<_, i32, 4>(a, counts, |lane, count| lane.wrapping_shl(count as u32))
+        }
+    }
+}
+
+impl_op! {
+    fn shr_lanes<i32> {
+        for Avx512(a: __m512i, counts: __m512i) -> __m512i {
+            _mm512_srlv_epi32(a, _mm512_and_si512(counts, Self::set1(31)))
+        }
+        for Avx2(a: __m256i, counts: __m256i) -> __m256i {
+            _mm256_srlv_epi32(a, _mm256_and_si256(counts, Self::set1(31)))
+        }
+        for Sse41(a: __m128i, counts: __m128i) -> __m128i {
+            Ops::<Sse2, i32>::shr_lanes(a, counts)
+        }
+        for Sse2(a: __m128i, counts: __m128i) -> __m128i {
+            sse_shift_lanes_epi32(a, counts, |a, count| _mm_srl_epi32(a, count))
+        }
+        for Scalar(a: i32, counts: i32) -> i32 {
+            (a as u32).wrapping_shr(counts as u32) as i32
+        }
+        for Neon(a: int32x4_t, counts: int32x4_t) -> int32x4_t {
+            let counts = vnegq_s32(vandq_s32(counts, Self::set1(31)));
+            vreinterpretq_s32_u32(vshlq_u32(vreinterpretq_u32_s32(a), counts))
+        }
+        for Wasm(a: v128, counts: v128) -> v128 {
+            map_lanes::<_, i32, 4>(a, counts, |lane, count| (lane as u32).wrapping_shr(count as u32) as i32)
+        }
+    }
+}
+
+impl_op! {
+    fn shr_arithmetic_lanes<i32> {
+        for Avx512(a: __m512i, counts: __m512i) -> __m512i {
+            _mm512_srav_epi32(a, _mm512_and_si512(counts, Self::set1(31)))
+        }
+        for Avx2(a: __m256i, counts: __m256i) -> __m256i {
+            _mm256_srav_epi32(a, _mm256_and_si256(counts, Self::set1(31)))
+        }
+        for Sse41(a: __m128i, counts: __m128i) -> __m128i {
+            Ops::<Sse2, i32>::shr_arithmetic_lanes(a, counts)
+        }
+        for Sse2(a: __m128i, counts: __m128i) -> __m128i {
+            sse_shift_lanes_epi32(a, counts, |a, count| _mm_sra_epi32(a, count))
+        }
+        for Scalar(a: i32, counts: i32) -> i32 {
+            a.wrapping_shr(counts as u32)
+        }
+        for Neon(a: int32x4_t, counts: int32x4_t) -> int32x4_t {
+            vshlq_s32(a, vnegq_s32(vandq_s32(counts, Self::set1(31))))
+        }
+        for Wasm(a: v128, counts: v128) -> v128 {
+            map_lanes::<_, i32, 4>(a, counts, |lane, count| lane.wrapping_shr(count as u32))
+        }
+    }
+}
+
+impl_op! {
+    fn rotate_left<i32> {
+        for Avx512(a: __m512i, rhs: i32) -> __m512i {
+            _mm512_rolv_epi32(a, Self::set1(rhs))
+        }
+        for Avx2(a: __m256i, rhs: i32) -> __m256i {
+            _mm256_or_si256(Self::shl(a, rhs), Self::shr(a, rhs.wrapping_neg()))
+        }
+        for Sse41(a: __m128i, rhs: i32) -> __m128i {
+            _mm_or_si128(Self::shl(a, rhs), Self::shr(a, rhs.wrapping_neg()))
+        }
+        for Sse2(a: __m128i, rhs: i32) -> __m128i {
+            _mm_or_si128(Self::shl(a, rhs), Self::shr(a, rhs.wrapping_neg()))
+        }
+        for Scalar(a: i32, rhs: i32) -> i32 {
+            a.rotate_left(rhs as u32)
+        }
+        for Neon(a: int32x4_t, rhs: i32) -> int32x4_t {
+            vorrq_s32(Self::shl(a, rhs), Self::shr(a, rhs.wrapping_neg()))
+        }
+        for Wasm(a: v128, rhs: i32) -> v128 {
+            v128_or(Self::shl(a, rhs), Self::shr(a, rhs.wrapping_neg()))
+        }
+    }
+}
+
+impl_op! {
+    fn rotate_left_lanes<i32> {
+        for Avx512(a: __m512i, counts: __m512i) -> __m512i {
+            _mm512_rolv_epi32(a, counts)
+        }
+        for Avx2(a: __m256i, counts: __m256i) -> __m256i {
+            let back = Self::sub(Self::zeroes(), counts);
+            _mm256_or_si256(Self::shl_lanes(a, counts), Self::shr_lanes(a, back))
+        }
+        for Sse41(a: __m128i, counts: __m128i) -> __m128i {
+            let back = Self::sub(Self::zeroes(), counts);
+            _mm_or_si128(Self::shl_lanes(a, counts), Self::shr_lanes(a, back))
+        }
+        for Sse2(a: __m128i, counts: __m128i) -> __m128i {
+            let back = Self::sub(Self::zeroes(), counts);
+            _mm_or_si128(Self::shl_lanes(a, counts), Self::shr_lanes(a, back))
+        }
+        for Scalar(a: i32, counts: i32) -> i32 {
+            a.rotate_left(counts as u32)
+        }
+        for Neon(a: int32x4_t, counts: int32x4_t) -> int32x4_t {
+            let back = Self::sub(Self::zeroes(), counts);
+            vorrq_s32(Self::shl_lanes(a, counts), Self::shr_lanes(a, back))
+        }
+        for Wasm(a: v128, counts: v128) -> v128 {
+            let back = Self::sub(Self::zeroes(), counts);
+            v128_or(Self::shl_lanes(a, counts), Self::shr_lanes(a, back))
+        }
+    }
+}
+
+impl_op! {
+    fn rotate_right<i32> {
+        for Avx512(a: __m512i, rhs: i32) -> __m512i {
+            _mm512_rorv_epi32(a, Self::set1(rhs))
+        }
+        for Avx2(a: __m256i, rhs: i32) -> __m256i {
+            _mm256_or_si256(Self::shr(a, rhs), Self::shl(a, rhs.wrapping_neg()))
+        }
+        for Sse41(a: __m128i, rhs: i32) -> __m128i {
+            _mm_or_si128(Self::shr(a, rhs), Self::shl(a, rhs.wrapping_neg()))
+        }
+        for Sse2(a: __m128i, rhs: i32) -> __m128i {
+            _mm_or_si128(Self::shr(a, rhs), Self::shl(a, rhs.wrapping_neg()))
+        }
+        for Scalar(a: i32, rhs: i32) -> i32 {
+            a.rotate_right(rhs as u32)
+        }
+        for Neon(a: int32x4_t, rhs: i32) -> int32x4_t {
+            vorrq_s32(Self::shr(a, rhs), Self::shl(a, rhs.wrapping_neg()))
+        }
+        for Wasm(a: v128, rhs: i32) -> v128 {
+            v128_or(Self::shr(a, rhs), Self::shl(a, rhs.wrapping_neg()))
+        }
+    }
+}
+
+impl_op! {
+    fn rotate_right_lanes<i32> {
+        for Avx512(a: __m512i, counts: __m512i) -> __m512i {
+            _mm512_rorv_epi32(a, counts)
+        }
+        for Avx2(a: __m256i, counts: __m256i) -> __m256i {
+            let back = Self::sub(Self::zeroes(), counts);
+            _mm256_or_si256(Self::shr_lanes(a, counts), Self::shl_lanes(a, back))
+        }
+        for Sse41(a: __m128i, counts: __m128i) -> __m128i {
+            let back = Self::sub(Self::zeroes(), counts);
+            _mm_or_si128(Self::shr_lanes(a, counts), Self::shl_lanes(a, back))
+        }
+        for Sse2(a: __m128i, counts: __m128i) -> __m128i {
+            let back = Self::sub(Self::zeroes(), counts);
+            _mm_or_si128(Self::shr_lanes(a, counts), Self::shl_lanes(a, back))
+        }
+        for Scalar(a: i32, counts: i32) -> i32 {
+            a.rotate_right(counts as u32)
+        }
+        for Neon(a: int32x4_t, counts: int32x4_t) -> int32x4_t {
+            let back = Self::sub(Self::zeroes(), counts);
+            vorrq_s32(Self::shr_lanes(a, counts), Self::shl_lanes(a, back))
+        }
+        for Wasm(a: v128, counts: v128) -> v128 {
+            let back = Self::sub(Self::zeroes(), counts);
+            v128_or(Self::shr_lanes(a, counts), Self::shl_lanes(a, back))
+        }
+    }
+}
+
+#[cfg(any(target_arch = "x86_64", target_arch = "x86"))]
+#[target_feature(enable = "avx512f", enable = "avx512vpopcntdq")]
+#[inline]
+unsafe fn avx512_vpopcntdq_popcnt_epi32(a: __m512i) -> __m512i {
+    _mm512_popcnt_epi32(a)
+}
+
+// Sums the 16 bit counts of each lane with `pmaddwd`.
+impl_op! {
+    fn count_ones<i32> {
+        for Avx512(a: __m512i) -> __m512i {
+            if has_feature(CpuFeature::Avx512Vpopcntdq) {
+                return avx512_vpopcntdq_popcnt_epi32(a);
+            }
+            _mm512_madd_epi16(Ops::<Avx512, i16>::count_ones(a), _mm512_set1_epi16(1))
+        }
+        for Avx2(a: __m256i) -> __m256i {
+            _mm256_madd_epi16(Ops::<Avx2, i16>::count_ones(a), _mm256_set1_epi16(1))
+        }
+        for Sse41(a: __m128i) -> __m128i {
+            _mm_madd_epi16(Ops::<Sse41, i16>::count_ones(a), _mm_set1_epi16(1))
+        }
+        for Sse2(a: __m128i) -> __m128i {
+            _mm_madd_epi16(Ops::<Sse2, i16>::count_ones(a), _mm_set1_epi16(1))
+        }
+        for Scalar(a: i32) -> i32 {
+            a.count_ones() as i32
+        }
+        for Neon(a: int32x4_t) -> int32x4_t {
+            let bytes = vcntq_u8(vreinterpretq_u8_s32(a));
+            vreinterpretq_s32_u32(vpaddlq_u16(vpaddlq_u8(bytes)))
+        }
+        for Wasm(a: v128) -> v128 {
+            i32x4_extadd_pairwise_u16x8(Ops::<Wasm, i16>::count_ones(a))
+        }
+    }
+}
+
+// Fills in the bits below the highest set bit and counts the rest.
+impl_op! {
+    fn leading_zeros<i32> {
+        for Avx512(a: __m512i) -> __m512i {
+            let a = _mm512_or_si512(a, _mm512_srli_epi32(a, 1));
+            let a = _mm512_or_si512(a, _mm512_srli_epi32(a, 2));
+            let a = _mm512_or_si512(a, _mm512_srli_epi32(a, 4));
+            let a = _mm512_or_si512(a, _mm512_srli_epi32(a, 8));
+            let a = _mm512_or_si512(a, _mm512_srli_epi32(a, 16));
+            _mm512_sub_epi32(_mm512_set1_epi32(32), Self::count_ones(a))
+        }
+        for Avx2(a: __m256i) -> __m256i {
+            let a = _mm256_or_si256(a, _mm256_srli_epi32(a, 1));
+            let a = _mm256_or_si256(a, _mm256_srli_epi32(a, 2));
+            let a = _mm256_or_si256(a, _mm256_srli_epi32(a, 4));
+            let a = _mm256_or_si256(a, _mm256_srli_epi32(a, 8));
+            let a = _mm256_or_si256(a, _mm256_srli_epi32(a, 16));
+            _mm256_sub_epi32(_mm256_set1_epi32(32), Self::count_ones(a))
+        }
+        for Sse41(a: __m128i) -> __m128i {
+            Ops::<Sse2, i32>::leading_zeros(a)
+        }
+        for Sse2(a: __m128i) -> __m128i {
+            let a = _mm_or_si128(a, _mm_srli_epi32(a, 1));
+            let a = _mm_or_si128(a, _mm_srli_epi32(a, 2));
+            let a = _mm_or_si128(a, _mm_srli_epi32(a, 4));
+            let a = _mm_or_si128(a, _mm_srli_epi32(a, 8));
+            let a = _mm_or_si128(a, _mm_srli_epi32(a, 16));
+            _mm_sub_epi32(_mm_set1_epi32(32), Self::count_ones(a))
+        }
+        for Scalar(a: i32) -> i32 {
+            a.leading_zeros() as i32
+        }
+        for Neon(a: int32x4_t) -> int32x4_t {
+            vclzq_s32(a)
+        }
+        for Wasm(a: v128) -> v128 {
+            let a = v128_or(a, u32x4_shr(a, 1));
+            let a = v128_or(a, u32x4_shr(a, 2));
+            let a = v128_or(a, u32x4_shr(a, 4));
+            let a = v128_or(a, u32x4_shr(a, 8));
+            let a = v128_or(a, u32x4_shr(a, 16));
+            i32x4_sub(i32x4_splat(32), Self::count_ones(a))
+        }
+    }
+}
+
+// Counts the set bits of `!a & (a - 1)`, which are the bits below the lowest set bit.
+impl_op! {
+    fn trailing_zeros<i32> {
+        for Avx512(a: __m512i) -> __m512i {
+            Self::count_ones(_mm512_andnot_si512(a, _mm512_sub_epi32(a, _mm512_set1_epi32(1))))
+        }
+        for Avx2(a: __m256i) -> __m256i {
+            Self::count_ones(_mm256_andnot_si256(a, _mm256_sub_epi32(a, _mm256_set1_epi32(1))))
+        }
+        for Sse41(a: __m128i) -> __m128i {
+            Self::count_ones(_mm_andnot_si128(a, _mm_sub_epi32(a, _mm_set1_epi32(1))))
+        }
+        for Sse2(a: __m128i) -> __m128i {
+            Self::count_ones(_mm_andnot_si128(a, _mm_sub_epi32(a, _mm_set1_epi32(1))))
+        }
+        for Scalar(a: i32) -> i32 {
+            a.trailing_zeros() as i32
+        }
+        for Neon(a: int32x4_t) -> int32x4_t {
+            Self::count_ones(vbicq_s32(vsubq_s32(a, vdupq_n_s32(1)), a))
+        }
+        for Wasm(a: v128) -> v128 {
+            Self::count_ones(v128_andnot(i32x4_sub(a, i32x4_splat(1)), a))
+        }
+    }
+}
+
+// Reverses the bytes of each lane, then the bits of every byte.
+impl_op! {
+    fn reverse_bits<i32> {
+        for Avx512(a: __m512i) -> __m512i {
+            let indices = core::mem::transmute::<[i8; 16], __m128i>(byte_reverse_indices(4));
+            let a = _mm512_shuffle_epi8(a, _mm512_broadcast_i32x4(indices));
+            Ops::<Avx512, i8>::reverse_bits(a)
+        }
+        for Avx2(a: __m256i) -> __m256i {
+            let indices = core::mem::transmute::<[i8; 16], __m128i>(byte_reverse_indices(4));
+            let a = _mm256_shuffle_epi8(a, _mm256_broadcastsi128_si256(indices));
+            Ops::<Avx2, i8>::reverse_bits(a)
+        }
+        for Sse41(a: __m128i) -> __m128i {
+            let indices = core::mem::transmute::<[i8; 16], __m128i>(byte_reverse_indices(4));
+            Ops::<Sse41, i8>::reverse_bits(_mm_shuffle_epi8(a, indices))
+        }
+        for Sse2(a: __m128i) -> __m128i {
+            let a = _mm_shufflehi_epi16(_mm_shufflelo_epi16(a, 0b10_11_00_01), 0b10_11_00_01);
+            Ops::<Sse2, i16>::reverse_bits(a)
+        }
+        for Scalar(a: i32) -> i32 {
+            a.reverse_bits()
+        }
+        for Neon(a: int32x4_t) -> int32x4_t {
+            vreinterpretq_s32_u8(vrbitq_u8(vrev32q_u8(vreinterpretq_u8_s32(a))))
+        }
+        for Wasm(a: v128) -> v128 {
+            let indices = core::mem::transmute::<[i8; 16], v128>(byte_reverse_indices(4));
+            Ops::<Wasm, i8>::reverse_bits(i8x16_swizzle(a, indices))
+        }
+    }
+}
+
+impl_op! {
+    fn popcount_total<i32> {
+        for Avx512(a: __m512i) -> u32 {
+            Ops::<Avx512, i8>::popcount_total(a)
+        }
+        for Avx2(a: __m256i) -> u32 {
+            Ops::<Avx2, i8>::popcount_total(a)
+        }
+        for Sse41(a: __m128i) -> u32 {
+            Ops::<Sse41, i8>::popcount_total(a)
+        }
+        for Sse2(a: __m128i) -> u32 {
+            Ops::<Sse2, i8>::popcount_total(a)
+        }
+        for Scalar(a: i32) -> u32 {
+            a.count_ones()
+        }
+        for Neon(a: int32x4_t) -> u32 {
+            Ops::<Neon, i8>::popcount_total(vreinterpretq_s8_s32(a))
+        }
+        for Wasm(a: v128) -> u32 {
+            Ops::<Wasm, i8>::popcount_total(a)
+        }
+    }
+}
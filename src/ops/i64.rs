@@ -848,3 +848,702 @@ impl_op! {
         }
     }
 }
+
+// Narrowing packs put the lanes of `a` first, then the lanes of `b`. Only AVX-512 and Neon can
+// saturate 64 bit lanes natively; the other engines clamp first and then truncate.
+
+impl_op! {
+    fn narrow_i32<i64> {
+        for Avx512(a: __m512i, b: __m512i) -> __m512i {
+            let lo = _mm512_cvtepi64_epi32(a);
+            let hi = _mm512_cvtepi64_epi32(b);
+            _mm512_inserti64x4::<1>(_mm512_castsi256_si512(lo), hi)
+        }
+        for Avx2(a: __m256i, b: __m256i) -> __m256i {
+            let packed = _mm256_shuffle_ps::<0b10_00_10_00>(
+                _mm256_castsi256_ps(a),
+                _mm256_castsi256_ps(b),
+            );
+            _mm256_permute4x64_epi64::<0xD8>(_mm256_castps_si256(packed))
+        }
+        for Sse41(a: __m128i, b: __m128i) -> __m128i {
+            Ops::<Sse2, i64>::narrow_i32(a, b)
+        }
+        for Sse2(a: __m128i, b: __m128i) -> __m128i {
+            let packed = _mm_shuffle_ps::<0b10_00_10_00>(_mm_castsi128_ps(a), _mm_castsi128_ps(b));
+            _mm_castps_si128(packed)
+        }
+        for Scalar(a: i64, _b: i64) -> i32 {
+            a as i32
+        }
+        for Neon(a: int64x2_t, b: int64x2_t) -> int32x4_t {
+            vcombine_s32(vmovn_s64(a), vmovn_s64(b))
+        }
+        for Wasm(a: v128, b: v128) -> v128 {
+            i32x4_shuffle::<0, 2, 4, 6>(a, b)
+        }
+    }
+}
+
+impl_op! {
+    fn saturating_narrow_i32<i64> {
+        for Avx512(a: __m512i, b: __m512i) -> __m512i {
+            let lo = _mm512_cvtsepi64_epi32(a);
+            let hi = _mm512_cvtsepi64_epi32(b);
+            _mm512_inserti64x4::<1>(_mm512_castsi256_si512(lo), hi)
+        }
+        for Avx2(a: __m256i, b: __m256i) -> __m256i {
+            let min = _mm256_set1_epi64x(i32::MIN as i64);
+            let max = _mm256_set1_epi64x(i32::MAX as i64);
+            let a = _mm256_blendv_epi8(a, max, _mm256_cmpgt_epi64(a, max));
+            let b = _mm256_blendv_epi8(b, max, _mm256_cmpgt_epi64(b, max));
+            let a = _mm256_blendv_epi8(a, min, _mm256_cmpgt_epi64(min, a));
+            let b = _mm256_blendv_epi8(b, min, _mm256_cmpgt_epi64(min, b));
+            Ops::<Avx2, i64>::narrow_i32(a, b)
+        }
+        for Sse41(a: __m128i, b: __m128i) -> __m128i {
+            Ops::<Sse2, i64>::saturating_narrow_i32(a, b)
+        }
+        for Sse2(a: __m128i, b: __m128i) -> __m128i {
+            let a = core::mem::transmute::<__m128i, [i64; 2]>(a);
+            let b = core::mem::transmute::<__m128i, [i64; 2]>(b);
+            let packed = [a[0], a[1], b[0], b[1]]
+                .map(|x| x.clamp(i32::MIN as i64, i32::MAX as i64) as i32);
+            core::mem::transmute::<[i32; 4], __m128i>(packed)
+        }
+        for Scalar(a: i64, _b: i64) -> i32 {
+            a.clamp(i32::MIN as i64, i32::MAX as i64) as i32
+        }
+        for Neon(a: int64x2_t, b: int64x2_t) -> int32x4_t {
+            vcombine_s32(vqmovn_s64(a), vqmovn_s64(b))
+        }
+        for Wasm(a: v128, b: v128) -> v128 {
+            let min = i64x2_splat(i32::MIN as i64);
+            let max = i64x2_splat(i32::MAX as i64);
+            let a = v128_bitselect(max, a, i64x2_gt(a, max));
+            let b = v128_bitselect(max, b, i64x2_gt(b, max));
+            let a = v128_bitselect(min, a, i64x2_lt(a, min));
+            let b = v128_bitselect(min, b, i64x2_lt(b, min));
+            i32x4_shuffle::<0, 2, 4, 6>(a, b)
+        }
+    }
+}
+
+impl_op! {
+    fn saturating_narrow_u32<i64> {
+        for Avx512(a: __m512i, b: __m512i) -> __m512i {
+            // The unsigned conversion reads its input as unsigned, so negative lanes go first.
+            let zero = _mm512_setzero_si512();
+            let lo = _mm512_cvtusepi64_epi32(_mm512_max_epi64(a, zero));
+            let hi = _mm512_cvtusepi64_epi32(_mm512_max_epi64(b, zero));
+            _mm512_inserti64x4::<1>(_mm512_castsi256_si512(lo), hi)
+        }
+        for Avx2(a: __m256i, b: __m256i) -> __m256i {
+            let zero = _mm256_setzero_si256();
+            let max = _mm256_set1_epi64x(u32::MAX as i64);
+            let a = _mm256_and_si256(a, _mm256_cmpgt_epi64(a, zero));
+            let b = _mm256_and_si256(b, _mm256_cmpgt_epi64(b, zero));
+            let a = _mm256_blendv_epi8(a, max, _mm256_cmpgt_epi64(a, max));
+            let b = _mm256_blendv_epi8(b, max, _mm256_cmpgt_epi64(b, max));
+            Ops::<Avx2, i64>::narrow_i32(a, b)
+        }
+        for Sse41(a: __m128i, b: __m128i) -> __m128i {
+            Ops::<Sse2, i64>::saturating_narrow_u32(a, b)
+        }
+        for Sse2(a: __m128i, b: __m128i) -> __m128i {
+            let a = core::mem::transmute::<__m128i, [i64; 2]>(a);
+            let b = core::mem::transmute::<__m128i, [i64; 2]>(b);
+            let packed = [a[0], a[1], b[0], b[1]].map(|x| x.clamp(0, u32::MAX as i64) as u32);
+            core::mem::transmute::<[u32; 4], __m128i>(packed)
+        }
+        for Scalar(a: i64, _b: i64) -> u32 {
+            a.clamp(0, u32::MAX as i64) as u32
+        }
+        for Neon(a: int64x2_t, b: int64x2_t) -> uint32x4_t {
+            vcombine_u32(vqmovun_s64(a), vqmovun_s64(b))
+        }
+        for Wasm(a: v128, b: v128) -> v128 {
+            let zero = i64x2_splat(0);
+            let max = i64x2_splat(u32::MAX as i64);
+            let a = v128_and(a, i64x2_gt(a, zero));
+            let b = v128_and(b, i64x2_gt(b, zero));
+            let a = v128_bitselect(max, a, i64x2_gt(a, max));
+            let b = v128_bitselect(max, b, i64x2_gt(b, max));
+            i32x4_shuffle::<0, 2, 4, 6>(a, b)
+        }
+    }
+}
+
+/// Broadcasts the sign bit of each 64 bit lane, as SSE has no 64 bit arithmetic shift.
+#[cfg(any(target_arch = "x86_64", target_arch = "x86"))]
+#[inline(always)]
+unsafe fn sse_sign_mask_epi64(a: __m128i) -> __m128i {
+    _mm_shuffle_epi32::<0b11_11_01_01>(_mm_srai_epi32(a, 31))
+}
+
+/// Shifts each 64 bit lane by its own count, as the SSE shifts take a single count for every lane.
+#[cfg(any(target_arch = "x86_64", target_arch = "x86"))]
+#[inline(always)]
+unsafe fn sse_shift_lanes_epi64(
+    a: __m128i,
+    counts: __m128i,
+    shift: impl Fn(__m128i, __m128i) -> __m128i,
+) -> __m128i {
+    let counts = _mm_and_si128(counts, _mm_set1_epi64x(63));
+    let low = shift(a, counts);
+    let high = shift(a, _mm_unpackhi_epi64(counts, counts));
+    _mm_castpd_si128(_mm_move_sd(_mm_castsi128_pd(high), _mm_castsi128_pd(low)))
+}
+
+// Same overflow test as the i32 version.
+impl_op! {
+    fn saturating_add<i64> {
+        for Avx512(a: __m512i, b: __m512i) -> __m512i {
+            let sum = _mm512_add_epi64(a, b);
+            let overflow = _mm512_and_si512(_mm512_xor_si512(a, sum), _mm512_xor_si512(b, sum));
+            let saturated = _mm512_xor_si512(_mm512_srai_epi64(a, 63), _mm512_set1_epi64(i64::MAX));
+            _mm512_mask_mov_epi64(sum, _mm512_movepi64_mask(overflow), saturated)
+        }
+        for Avx2(a: __m256i, b: __m256i) -> __m256i {
+            let sum = _mm256_add_epi64(a, b);
+            let overflow = _mm256_and_si256(_mm256_xor_si256(a, sum), _mm256_xor_si256(b, sum));
+            let sign = _mm256_cmpgt_epi64(_mm256_setzero_si256(), a);
+            let saturated = _mm256_xor_si256(sign, _mm256_set1_epi64x(i64::MAX));
+            _mm256_castpd_si256(_mm256_blendv_pd(
+                _mm256_castsi256_pd(sum),
+                _mm256_castsi256_pd(saturated),
+                _mm256_castsi256_pd(overflow),
+            ))
+        }
+        for Sse41(a: __m128i, b: __m128i) -> __m128i {
+            let sum = _mm_add_epi64(a, b);
+            let overflow = _mm_and_si128(_mm_xor_si128(a, sum), _mm_xor_si128(b, sum));
+            let saturated = _mm_xor_si128(sse_sign_mask_epi64(a), _mm_set1_epi64x(i64::MAX));
+            _mm_castpd_si128(_mm_blendv_pd(
+                _mm_castsi128_pd(sum),
+                _mm_castsi128_pd(saturated),
+                _mm_castsi128_pd(overflow),
+            ))
+        }
+        for Sse2(a: __m128i, b: __m128i) -> __m128i {
+            let sum = _mm_add_epi64(a, b);
+            let overflow = _mm_and_si128(_mm_xor_si128(a, sum), _mm_xor_si128(b, sum));
+            let overflow = sse_sign_mask_epi64(overflow);
+            let saturated = _mm_xor_si128(sse_sign_mask_epi64(a), _mm_set1_epi64x(i64::MAX));
+            _mm_or_si128(_mm_and_si128(overflow, saturated), _mm_andnot_si128(overflow, sum))
+        }
+        for Scalar(a: i64, b: i64) -> i64 {
+            a.saturating_add(b)
+        }
+        for Neon(a: int64x2_t, b: int64x2_t) -> int64x2_t {
+            vqaddq_s64(a, b)
+        }
+        for Wasm(a: v128, b: v128) -> v128 {
+            let sum = i64x2_add(a, b);
+            let overflow = i64x2_shr(v128_and(v128_xor(a, sum), v128_xor(b, sum)), 63);
+            let saturated = v128_xor(i64x2_shr(a, 63), i64x2_splat(i64::MAX));
+            v128_bitselect(saturated, sum, overflow)
+        }
+    }
+}
+
+impl_op! {
+    fn saturating_sub<i64> {
+        for Avx512(a: __m512i, b: __m512i) -> __m512i {
+            let diff = _mm512_sub_epi64(a, b);
+            let overflow = _mm512_and_si512(_mm512_xor_si512(a, b), _mm512_xor_si512(a, diff));
+            let saturated = _mm512_xor_si512(_mm512_srai_epi64(a, 63), _mm512_set1_epi64(i64::MAX));
+            _mm512_mask_mov_epi64(diff, _mm512_movepi64_mask(overflow), saturated)
+        }
+        for Avx2(a: __m256i, b: __m256i) -> __m256i {
+            let diff = _mm256_sub_epi64(a, b);
+            let overflow = _mm256_and_si256(_mm256_xor_si256(a, b), _mm256_xor_si256(a, diff));
+            let sign = _mm256_cmpgt_epi64(_mm256_setzero_si256(), a);
+            let saturated = _mm256_xor_si256(sign, _mm256_set1_epi64x(i64::MAX));
+            _mm256_castpd_si256(_mm256_blendv_pd(
+                _mm256_castsi256_pd(diff),
+                _mm256_castsi256_pd(saturated),
+                _mm256_castsi256_pd(overflow),
+            ))
+        }
+        for Sse41(a: __m128i, b: __m128i) -> __m128i {
+            let diff = _mm_sub_epi64(a, b);
+            let overflow = _mm_and_si128(_mm_xor_si128(a, b), _mm_xor_si128(a, diff));
+            let saturated = _mm_xor_si128(sse_sign_mask_epi64(a), _mm_set1_epi64x(i64::MAX));
+            _mm_castpd_si128(_mm_blendv_pd(
+                _mm_castsi128_pd(diff),
+                _mm_castsi128_pd(saturated),
+                _mm_castsi128_pd(overflow),
+            ))
+        }
+        for Sse2(a: __m128i, b: __m128i) -> __m128i {
+            let diff = _mm_sub_epi64(a, b);
+            let overflow = _mm_and_si128(_mm_xor_si128(a, b), _mm_xor_si128(a, diff));
+            let overflow = sse_sign_mask_epi64(overflow);
+            let saturated = _mm_xor_si128(sse_sign_mask_epi64(a), _mm_set1_epi64x(i64::MAX));
+            _mm_or_si128(_mm_and_si128(overflow, saturated), _mm_andnot_si128(overflow, diff))
+        }
+        for Scalar(a: i64, b: i64) -> i64 {
+            a.saturating_sub(b)
+        }
+        for Neon(a: int64x2_t, b: int64x2_t) -> int64x2_t {
+            vqsubq_s64(a, b)
+        }
+        for Wasm(a: v128, b: v128) -> v128 {
+            let diff = i64x2_sub(a, b);
+            let overflow = i64x2_shr(v128_and(v128_xor(a, b), v128_xor(a, diff)), 63);
+            let saturated = v128_xor(i64x2_shr(a, 63), i64x2_splat(i64::MAX));
+            v128_bitselect(saturated, diff, overflow)
+        }
+    }
+}
+
+// Same as the i32 version. Below AVX-512 the arithmetic shift by one is a logical shift that
+// keeps the sign bit.
+impl_op! {
+    fn avg<i64> {
+        for Avx512(a: __m512i, b: __m512i) -> __m512i {
+            _mm512_sub_epi64(_mm512_or_si512(a, b), _mm512_srai_epi64(_mm512_xor_si512(a, b), 1))
+        }
+        for Avx2(a: __m256i, b: __m256i) -> __m256i {
+            let x = _mm256_xor_si256(a, b);
+            let half = _mm256_or_si256(
+                _mm256_srli_epi64(x, 1),
+                _mm256_and_si256(x, _mm256_set1_epi64x(i64::MIN)),
+            );
+            _mm256_sub_epi64(_mm256_or_si256(a, b), half)
+        }
+        for Sse41(a: __m128i, b: __m128i) -> __m128i {
+            Ops::<Sse2, i64>::avg(a, b)
+        }
+        for Sse2(a: __m128i, b: __m128i) -> __m128i {
+            let x = _mm_xor_si128(a, b);
+            let half = _mm_or_si128(_mm_srli_epi64(x, 1), _mm_and_si128(x, _mm_set1_epi64x(i64::MIN)));
+            _mm_sub_epi64(_mm_or_si128(a, b), half)
+        }
+        for Scalar(a: i64, b: i64) -> i64 {
+            ((a as i128 + b as i128 + 1) >> 1) as i64
+        }
+        for Neon(a: int64x2_t, b: int64x2_t) -> int64x2_t {
+            vsubq_s64(vorrq_s64(a, b), vshrq_n_s64::<1>(veorq_s64(a, b)))
+        }
+        for Wasm(a: v128, b: v128) -> v128 {
+            i64x2_sub(v128_or(a, b), i64x2_shr(v128_xor(a, b), 1))
+        }
+    }
+}
+
+impl_op! {
+    fn shr_arithmetic<i64> {
+        for Avx512(a: __m512i, rhs: i32) -> __m512i {
+            _mm512_sra_epi64(a, _mm_cvtsi32_si128(wrapping_shift_count(rhs)))
+        }
+        for Avx2(a: __m256i, rhs: i32) -> __m256i {
+            // There is no 64 bit arithmetic shift before AVX-512, so shift the sign bits back in.
+            let rhs = wrapping_shift_count(rhs);
+            let sign = _mm256_cmpgt_epi64(_mm256_setzero_si256(), a);
+            let shifted = _mm256_srl_epi64(a, _mm_cvtsi32_si128(rhs));
+            _mm256_or_si256(shifted, _mm256_sll_epi64(sign, _mm_cvtsi32_si128(64 - rhs)))
+        }
+        for Sse41(a: __m128i, rhs: i32) -> __m128i {
+            Ops::<Sse2, i64>::shr_arithmetic(a, rhs)
+        }
+        for Sse2(a: __m128i, rhs: i32) -> __m128i {
+            let rhs = wrapping_shift_count(rhs);
+            let sign = sse_sign_mask_epi64(a);
+            let shifted = _mm_srl_epi64(a, _mm_cvtsi32_si128(rhs));
+            _mm_or_si128(shifted, _mm_sll_epi64(sign, _mm_cvtsi32_si128(64 - rhs)))
+        }
+        for Scalar(a: i64, rhs: i32) -> i64 {
+            a.wrapping_shr(wrapping_shift_count(rhs) as u32)
+        }
+        for Neon(a: int64x2_t, rhs: i32) -> int64x2_t {
+            vshlq_s64(a, Self::set1(-wrapping_shift_count(rhs) as i64))
+        }
+        for Wasm(a: v128, rhs: i32) -> v128 {
+            i64x2_shr(a, wrapping_shift_count(rhs) as u32)
+        }
+    }
+}
+
+impl_op! {
+    fn shl_lanes<i64> {
+        for Avx512(a: __m512i, counts: __m512i) -> __m512i {
+            _mm512_sllv_epi64(a, _mm512_and_si512(counts, Self::set1(63)))
+        }
+        for Avx2(a: __m256i, counts: __m256i) -> __m256i {
+            _mm256_sllv_epi64(a, _mm256_and_si256(counts, Self::set1(63)))
+        }
+        for Sse41(a: __m128i, counts: __m128i) -> __m128i {
+            Ops::<Sse2, i64>::shl_lanes(a, counts)
+        }
+        for Sse2(a: __m128i, counts: __m128i) -> __m128i {
+            sse_shift_lanes_epi64(a, counts, |a, count| _mm_sll_epi64(a, count))
+        }
+        for Scalar(a: i64, counts: i64) -> i64 {
+            a.wrapping_shl(counts as u32)
+        }
+        for Neon(a: int64x2_t, counts: int64x2_t) -> int64x2_t {
+            vshlq_s64(a, vandq_s64(counts, Self::set1(63)))
+        }
+        for Wasm(a: v128, counts: v128) -> v128 {
+            map_lanes::<_, i64, 2>(a, counts, |lane, count| lane.wrapping_shl(count as u32))
+        }
+    }
+}
+
+impl_op! {
+    fn shr_lanes<i64> {
+        for Avx512(a: __m512i, counts: __m512i) -> __m512i {
+            _mm512_srlv_epi64(a, _mm512_and_si512(counts, Self::set1(63)))
+        }
+        for Avx2(a: __m256i, counts: __m256i) -> __m256i {
+            _mm256_srlv_epi64(a, _mm256_and_si256(counts, Self::set1(63)))
+        }
+        for Sse41(a: __m128i, counts: __m128i) -> __m128i {
+            Ops::<Sse2, i64>::shr_lanes(a, counts)
+        }
+        for Sse2(a: __m128i, counts: __m128i) -> __m128i {
+            sse_shift_lanes_epi64(a, counts, |a, count| _mm_srl_epi64(a, count))
+        }
+        for Scalar(a: i64, counts: i64) -> i64 {
+            (a as u64).wrapping_shr(counts as u32) as i64
+        }
+        for Neon(a: int64x2_t, counts: int64x2_t) -> int64x2_t {
+            let counts = vnegq_s64(vandq_s64(counts, Self::set1(63)));
+            vreinterpretq_s64_u64(vshlq_u64(vreinterpretq_u64_s64(a), counts))
+        }
+        for Wasm(a: v128, counts: v128) -> v128 {
+            map_lanes::<_, i64, 2>(a, counts, |lane, count| (lane as u64).wrapping_shr(count as u32) as i64)
+        }
+    }
+}
+
+impl_op! {
+    fn shr_arithmetic_lanes<i64> {
+        for Avx512(a: __m512i, counts: __m512i) -> __m512i {
+            _mm512_srav_epi64(a, _mm512_and_si512(counts, Self::set1(63)))
+        }
+        for Avx2(a: __m256i, counts: __m256i) -> __m256i {
+            // There is no 64 bit arithmetic shift before AVX-512, so shift the sign bits back in.
+            let counts = _mm256_and_si256(counts, Self::set1(63));
+            let sign = _mm256_cmpgt_epi64(_mm256_setzero_si256(), a);
+            let fill = _mm256_sllv_epi64(sign, _mm256_sub_epi64(Self::set1(64), counts));
+            _mm256_or_si256(_mm256_srlv_epi64(a, counts), fill)
+        }
+        for Sse41(a: __m128i, counts: __m128i) -> __m128i {
+            Ops::<Sse2, i64>::shr_arithmetic_lanes(a, counts)
+        }
+        for Sse2(a: __m128i, counts: __m128i) -> __m128i {
+            // Flipping negative lanes turns the logical shift into an arithmetic one.
+            let sign = sse_sign_mask_epi64(a);
+            _mm_xor_si128(Self::shr_lanes(_mm_xor_si128(a, sign), counts), sign)
+        }
+        for Scalar(a: i64, counts: i64) -> i64 {
+            a.wrapping_shr(counts as u32)
+        }
+        for Neon(a: int64x2_t, counts: int64x2_t) -> int64x2_t {
+            vshlq_s64(a, vnegq_s64(vandq_s64(counts, Self::set1(63))))
+        }
+        for Wasm(a: v128, counts: v128) -> v128 {
+            map_lanes::<_, i64, 2>(a, counts, |lane, count| lane.wrapping_shr(count as u32))
+        }
+    }
+}
+
+impl_op! {
+    fn rotate_left<i64> {
+        for Avx512(a: __m512i, rhs: i32) -> __m512i {
+            _mm512_rolv_epi64(a, Self::set1(rhs as i64))
+        }
+        for Avx2(a: __m256i, rhs: i32) -> __m256i {
+            _mm256_or_si256(Self::shl(a, rhs), Self::shr(a, rhs.wrapping_neg()))
+        }
+        for Sse41(a: __m128i, rhs: i32) -> __m128i {
+            _mm_or_si128(Self::shl(a, rhs), Self::shr(a, rhs.wrapping_neg()))
+        }
+        for Sse2(a: __m128i, rhs: i32) -> __m128i {
+            _mm_or_si128(Self::shl(a, rhs), Self::shr(a, rhs.wrapping_neg()))
+        }
+        for Scalar(a: i64, rhs: i32) -> i64 {
+            a.rotate_left(rhs as u32)
+        }
+        for Neon(a: int64x2_t, rhs: i32) -> int64x2_t {
+            vorrq_s64(Self::shl(a, rhs), Self::shr(a, rhs.wrapping_neg()))
+        }
+        for Wasm(a: v128, rhs: i32) -> v128 {
+            v128_or(Self::shl(a, rhs), Self::shr(a, rhs.wrapping_neg()))
+        }
+    }
+}
+
+impl_op! {
+    fn rotate_left_lanes<i64> {
+        for Avx512(a: __m512i, counts: __m512i) -> __m512i {
+            _mm512_rolv_epi64(a, counts)
+        }
+        for Avx2(a: __m256i, counts: __m256i) -> __m256i {
+            let back = Self::sub(Self::zeroes(), counts);
+            _mm256_or_si256(Self::shl_lanes(a, counts), Self::shr_lanes(a, back))
+        }
+        for Sse41(a: __m128i, counts: __m128i) -> __m128i {
+            let back = Self::sub(Self::zeroes(), counts);
+            _mm_or_si128(Self::shl_lanes(a, counts), Self::shr_lanes(a, back))
+        }
+        for Sse2(a: __m128i, counts: __m128i) -> __m128i {
+            let back = Self::sub(Self::zeroes(), counts);
+            _mm_or_si128(Self::shl_lanes(a, counts), Self::shr_lanes(a, back))
+        }
+        for Scalar(a: i64, counts: i64) -> i64 {
+            a.rotate_left(counts as u32)
+        }
+        for Neon(a: int64x2_t, counts: int64x2_t) -> int64x2_t {
+            let back = Self::sub(Self::zeroes(), counts);
+            vorrq_s64(Self::shl_lanes(a, counts), Self::shr_lanes(a, back))
+        }
+        for Wasm(a: v128, counts: v128) -> v128 {
+            let back = Self::sub(Self::zeroes(), counts);
+            v128_or(Self::shl_lanes(a, counts), Self::shr_lanes(a, back))
+        }
+    }
+}
+
+impl_op! {
+    fn rotate_right<i64> {
+        for Avx512(a: __m512i, rhs: i32) -> __m512i {
+            _mm512_rorv_epi64(a, Self::set1(rhs as i64))
+        }
+        for Avx2(a: __m256i, rhs: i32) -> __m256i {
+            _mm256_or_si256(Self::shr(a, rhs), Self::shl(a, rhs.wrapping_neg()))
+        }
+        for Sse41(a: __m128i, rhs: i32) -> __m128i {
+            _mm_or_si128(Self::shr(a, rhs), Self::shl(a, rhs.wrapping_neg()))
+        }
+        for Sse2(a: __m128i, rhs: i32) -> __m128i {
+            _mm_or_si128(Self::shr(a, rhs), Self::shl(a, rhs.wrapping_neg()))
+        }
+        for Scalar(a: i64, rhs: i32) -> i64 {
+            a.rotate_right(rhs as u32)
+        }
+        for Neon(a: int64x2_t, rhs: i32) -> int64x2_t {
+            vorrq_s64(Self::shr(a, rhs), Self::shl(a, rhs.wrapping_neg()))
+        }
+        for Wasm(a: v128, rhs: i32) -> v128 {
+            v128_or(Self::shr(a, rhs), Self::shl(a, rhs.wrapping_neg()))
+        }
+    }
+}
+
+impl_op! {
+    fn rotate_right_lanes<i64> {
+        for Avx512(a: __m512i, counts: __m512i) -> __m512i {
+            _mm512_rorv_epi64(a, counts)
+        }
+        for Avx2(a: __m256i, counts: __m256i) -> __m256i {
+            let back = Self::sub(Self::zeroes(), counts);
+            _mm256_or_si256(Self::shr_lanes(a, counts), Self::shl_lanes(a, back))
+        }
+        for Sse41(a: __m128i, counts: __m128i) -> __m128i {
+            let back = Self::sub(Self::zeroes(), counts);
+            _mm_or_si128(Self::shr_lanes(a, counts), Self::shl_lanes(a, back))
+        }
+        for Sse2(a: __m128i, counts: __m128i) -> __m128i {
+            let back = Self::sub(Self::zeroes(), counts);
+            _mm_or_si128(Self::shr_lanes(a, counts), Self::shl_lanes(a, back))
+        }
+        for Scalar(a: i64, counts: i64) -> i64 {
+            a.rotate_right(counts as u32)
+        }
+        for Neon(a: int64x2_t, counts: int64x2_t) -> int64x2_t {
+            let back = Self::sub(Self::zeroes(), counts);
+            vorrq_s64(Self::shr_lanes(a, counts), Self::shl_lanes(a, back))
+        }
+        for Wasm(a: v128, counts: v128) -> v128 {
+            let back = Self::sub(Self::zeroes(), counts);
+            v128_or(Self::shr_lanes(a, counts), Self::shl_lanes(a, back))
+        }
+    }
+}
+
+#[cfg(any(target_arch = "x86_64", target_arch = "x86"))]
+#[target_feature(enable = "avx512f", enable = "avx512vpopcntdq")]
+#[inline]
+unsafe fn avx512_vpopcntdq_popcnt_epi64(a: __m512i) -> __m512i {
+    _mm512_popcnt_epi64(a)
+}
+
+// Sums the byte counts of each lane with `psadbw` against zero.
+impl_op! {
+    fn count_ones<i64> {
+        for Avx512(a: __m512i) -> __m512i {
+            if has_feature(CpuFeature::Avx512Vpopcntdq) {
+                return avx512_vpopcntdq_popcnt_epi64(a);
+            }
+            _mm512_sad_epu8(Ops::<Avx512, i8>::count_ones(a), _mm512_setzero_si512())
+        }
+        for Avx2(a: __m256i) -> __m256i {
+            _mm256_sad_epu8(Ops::<Avx2, i8>::count_ones(a), _mm256_setzero_si256())
+        }
+        for Sse41(a: __m128i) -> __m128i {
+            _mm_sad_epu8(Ops::<Sse41, i8>::count_ones(a), _mm_setzero_si128())
+        }
+        for Sse2(a: __m128i) -> __m128i {
+            _mm_sad_epu8(Ops::<Sse2, i8>::count_ones(a), _mm_setzero_si128())
+        }
+        for Scalar(a: i64) -> i64 {
+            a.count_ones() as i64
+        }
+        for Neon(a: int64x2_t) -> int64x2_t {
+            let bytes = vcntq_u8(vreinterpretq_u8_s64(a));
+            vreinterpretq_s64_u64(vpaddlq_u32(vpaddlq_u16(vpaddlq_u8(bytes))))
+        }
+        for Wasm(a: v128) -> v128 {
+            let halves = Ops::<Wasm, i32>::count_ones(a);
+            v128_and(i64x2_add(halves, u64x2_shr(halves, 32)), i64x2_splat(0xFF))
+        }
+    }
+}
+
+// Fills in the bits below the highest set bit and counts the rest.
+impl_op! {
+    fn leading_zeros<i64> {
+        for Avx512(a: __m512i) -> __m512i {
+            let a = _mm512_or_si512(a, _mm512_srli_epi64(a, 1));
+            let a = _mm512_or_si512(a, _mm512_srli_epi64(a, 2));
+            let a = _mm512_or_si512(a, _mm512_srli_epi64(a, 4));
+            let a = _mm512_or_si512(a, _mm512_srli_epi64(a, 8));
+            let a = _mm512_or_si512(a, _mm512_srli_epi64(a, 16));
+            let a = _mm512_or_si512(a, _mm512_srli_epi64(a, 32));
+            _mm512_sub_epi64(_mm512_set1_epi64(64), Self::count_ones(a))
+        }
+        for Avx2(a: __m256i) -> __m256i {
+            let a = _mm256_or_si256(a, _mm256_srli_epi64(a, 1));
+            let a = _mm256_or_si256(a, _mm256_srli_epi64(a, 2));
+            let a = _mm256_or_si256(a, _mm256_srli_epi64(a, 4));
+            let a = _mm256_or_si256(a, _mm256_srli_epi64(a, 8));
+            let a = _mm256_or_si256(a, _mm256_srli_epi64(a, 16));
+            let a = _mm256_or_si256(a, _mm256_srli_epi64(a, 32));
+            _mm256_sub_epi64(_mm256_set1_epi64x(64), Self::count_ones(a))
+        }
+        for Sse41(a: __m128i) -> __m128i {
+            Ops::<Sse2, i64>::leading_zeros(a)
+        }
+        for Sse2(a: __m128i) -> __m128i {
+            let a = _mm_or_si128(a, _mm_srli_epi64(a, 1));
+            let a = _mm_or_si128(a, _mm_srli_epi64(a, 2));
+            let a = _mm_or_si128(a, _mm_srli_epi64(a, 4));
+            let a = _mm_or_si128(a, _mm_srli_epi64(a, 8));
+            let a = _mm_or_si128(a, _mm_srli_epi64(a, 16));
+            let a = _mm_or_si128(a, _mm_srli_epi64(a, 32));
+            _mm_sub_epi64(_mm_set1_epi64x(64), Self::count_ones(a))
+        }
+        for Scalar(a: i64) -> i64 {
+            a.leading_zeros() as i64
+        }
+        for Neon(a: int64x2_t) -> int64x2_t {
+            let a = vreinterpretq_u64_s64(a);
+            let a = vorrq_u64(a, vshrq_n_u64::<1>(a));
+            let a = vorrq_u64(a, vshrq_n_u64::<2>(a));
+            let a = vorrq_u64(a, vshrq_n_u64::<4>(a));
+            let a = vorrq_u64(a, vshrq_n_u64::<8>(a));
+            let a = vorrq_u64(a, vshrq_n_u64::<16>(a));
+            let a = vorrq_u64(a, vshrq_n_u64::<32>(a));
+            vsubq_s64(vdupq_n_s64(64), Self::count_ones(vreinterpretq_s64_u64(a)))
+        }
+        for Wasm(a: v128) -> v128 {
+            let a = v128_or(a, u64x2_shr(a, 1));
+            let a = v128_or(a, u64x2_shr(a, 2));
+            let a = v128_or(a, u64x2_shr(a, 4));
+            let a = v128_or(a, u64x2_shr(a, 8));
+            let a = v128_or(a, u64x2_shr(a, 16));
+            let a = v128_or(a, u64x2_shr(a, 32));
+            i64x2_sub(i64x2_splat(64), Self::count_ones(a))
+        }
+    }
+}
+
+// Counts the set bits of `!a & (a - 1)`, which are the bits below the lowest set bit.
+impl_op! {
+    fn trailing_zeros<i64> {
+        for Avx512(a: __m512i) -> __m512i {
+            Self::count_ones(_mm512_andnot_si512(a, _mm512_sub_epi64(a, _mm512_set1_epi64(1))))
+        }
+        for Avx2(a: __m256i) -> __m256i {
+            Self::count_ones(_mm256_andnot_si256(a, _mm256_sub_epi64(a, _mm256_set1_epi64x(1))))
+        }
+        for Sse41(a: __m128i) -> __m128i {
+            Self::count_ones(_mm_andnot_si128(a, _mm_sub_epi64(a, _mm_set1_epi64x(1))))
+        }
+        for Sse2(a: __m128i) -> __m128i {
+            Self::count_ones(_mm_andnot_si128(a, _mm_sub_epi64(a, _mm_set1_epi64x(1))))
+        }
+        for Scalar(a: i64) -> i64 {
+            a.trailing_zeros() as i64
+        }
+        for Neon(a: int64x2_t) -> int64x2_t {
+            Self::count_ones(vbicq_s64(vsubq_s64(a, vdupq_n_s64(1)), a))
+        }
+        for Wasm(a: v128) -> v128 {
+            Self::count_ones(v128_andnot(i64x2_sub(a, i64x2_splat(1)), a))
+        }
+    }
+}
+
+// Reverses the bytes of each lane, then the bits of every byte.
+impl_op! {
+    fn reverse_bits<i64> {
+        for Avx512(a: __m512i) -> __m512i {
+            let indices = core::mem::transmute::<[i8; 16], __m128i>(byte_reverse_indices(8));
+            let a = _mm512_shuffle_epi8(a, _mm512_broadcast_i32x4(indices));
+            Ops::<Avx512, i8>::reverse_bits(a)
+        }
+        for Avx2(a: __m256i) -> __m256i {
+            let indices = core::mem::transmute::<[i8; 16], __m128i>(byte_reverse_indices(8));
+            let a = _mm256_shuffle_epi8(a, _mm256_broadcastsi128_si256(indices));
+            Ops::<Avx2, i8>::reverse_bits(a)
+        }
+        for Sse41(a: __m128i) -> __m128i {
+            let indices = core::mem::transmute::<[i8; 16], __m128i>(byte_reverse_indices(8));
+            Ops::<Sse41, i8>::reverse_bits(_mm_shuffle_epi8(a, indices))
+        }
+        for Sse2(a: __m128i) -> __m128i {
+            Ops::<Sse2, i32>::reverse_bits(_mm_shuffle_epi32(a, 0b10_11_00_01))
+        }
+        for Scalar(a: i64) -> i64 {
+            a.reverse_bits()
+        }
+        for Neon(a: int64x2_t) -> int64x2_t {
+            vreinterpretq_s64_u8(vrbitq_u8(vrev64q_u8(vreinterpretq_u8_s64(a))))
+        }
+        for Wasm(a: v128) -> v128 {
+            let indices = core::mem::transmute::<[i8; 16], v128>(byte_reverse_indices(8));
+            Ops::<Wasm, i8>::reverse_bits(i8x16_swizzle(a, indices))
+        }
+    }
+}
+
+impl_op! {
+    fn popcount_total<i64> {
+        for Avx512(a: __m512i) -> u32 {
+            Ops::<Avx512, i8>::popcount_total(a)
+        }
+        for Avx2(a: __m256i) -> u32 {
+            Ops::<Avx2, i8>::popcount_total(a)
+        }
+        for Sse41(a: __m128i) -> u32 {
+            Ops::<Sse41, i8>::popcount_total(a)
+        }
+        for Sse2(a: __m128i) -> u32 {
+            Ops::<Sse2, i8>::popcount_total(a)
+        }
+        for Scalar(a: i64) -> u32 {
+            a.count_ones()
+        }
+        for Neon(a: int64x2_t) -> u32 {
+            Ops::<Neon, i8>::popcount_total(vreinterpretq_s8_s64(a))
+        }
+        for Wasm(a: v128) -> u32 {
+            Ops::<Wasm, i8>::popcount_total(a)
+        }
+    }
+}
@@ -923,3 +923,779 @@ impl_op! {
         }
     }
 }
+
+impl_op! {
+    fn saturating_add<i8> {
+        for Avx512(a: __m512i, b: __m512i) -> __m512i {
+            _mm512_adds_epi8(a, b)
+        }
+        for Avx2(a: __m256i, b: __m256i) -> __m256i {
+            _mm256_adds_epi8(a, b)
+        }
+        for Sse41(a: __m128i, b: __m128i) -> __m128i {
+            _mm_adds_epi8(a, b)
+        }
+        for Sse2(a: __m128i, b: __m128i) -> __m128i {
+            _mm_adds_epi8(a, b)
+        }
+        for Scalar(a: i8, b: i8) -> i8 {
+            a.saturating_add(b)
+        }
+        for Neon(a: int8x16_t, b: int8x16_t) -> int8x16_t {
+            vqaddq_s8(a, b)
+        }
+        for Wasm(a: v128, b: v128) -> v128 {
+            i8x16_add_sat(a, b)
+        }
+    }
+}
+
+impl_op! {
+    fn saturating_sub<i8> {
+        for Avx512(a: __m512i, b: __m512i) -> __m512i {
+            _mm512_subs_epi8(a, b)
+        }
+        for Avx2(a: __m256i, b: __m256i) -> __m256i {
+            _mm256_subs_epi8(a, b)
+        }
+        for Sse41(a: __m128i, b: __m128i) -> __m128i {
+            _mm_subs_epi8(a, b)
+        }
+        for Sse2(a: __m128i, b: __m128i) -> __m128i {
+            _mm_subs_epi8(a, b)
+        }
+        for Scalar(a: i8, b: i8) -> i8 {
+            a.saturating_sub(b)
+        }
+        for Neon(a: int8x16_t, b: int8x16_t) -> int8x16_t {
+            vqsubq_s8(a, b)
+        }
+        for Wasm(a: v128, b: v128) -> v128 {
+            i8x16_sub_sat(a, b)
+        }
+    }
+}
+
+// x86 and wasm only have an unsigned rounding average. Flipping the sign bit maps i8 onto u8
+// while keeping the order, and `(a + b + 1) >> 1` is unchanged by the shared offset.
+impl_op! {
+    fn avg<i8> {
+        for Avx512(a: __m512i, b: __m512i) -> __m512i {
+            let bias = _mm512_set1_epi8(i8::MIN);
+            let avg = _mm512_avg_epu8(_mm512_xor_si512(a, bias), _mm512_xor_si512(b, bias));
+            _mm512_xor_si512(avg, bias)
+        }
+        for Avx2(a: __m256i, b: __m256i) -> __m256i {
+            let bias = _mm256_set1_epi8(i8::MIN);
+            let avg = _mm256_avg_epu8(_mm256_xor_si256(a, bias), _mm256_xor_si256(b, bias));
+            _mm256_xor_si256(avg, bias)
+        }
+        for Sse41(a: __m128i, b: __m128i) -> __m128i {
+            Ops::<Sse2, i8>::avg(a, b)
+        }
+        for Sse2(a: __m128i, b: __m128i) -> __m128i {
+            let bias = _mm_set1_epi8(i8::MIN);
+            let avg = _mm_avg_epu8(_mm_xor_si128(a, bias), _mm_xor_si128(b, bias));
+            _mm_xor_si128(avg, bias)
+        }
+        for Scalar(a: i8, b: i8) -> i8 {
+            ((a as i16 + b as i16 + 1) >> 1) as i8
+        }
+        for Neon(a: int8x16_t, b: int8x16_t) -> int8x16_t {
+            vrhaddq_s8(a, b)
+        }
+        for Wasm(a: v128, b: v128) -> v128 {
+            let bias = i8x16_splat(i8::MIN);
+            let avg = u8x16_avgr(v128_xor(a, bias), v128_xor(b, bias));
+            v128_xor(avg, bias)
+        }
+    }
+}
+
+impl_op! {
+    fn shr_arithmetic<i8> {
+        for Avx512(a: __m512i, rhs: i32) -> __m512i {
+            // There are no 8 bit shifts: shift the odd bytes in place, and the even bytes from the
+            // top half of each 16 bit lane.
+            let rhs = _mm_cvtsi32_si128(wrapping_shift_count(rhs));
+            let odd = _mm512_and_si512(_mm512_sra_epi16(a, rhs), _mm512_set1_epi16(0xFF00u16 as i16));
+            let even = _mm512_srli_epi16(_mm512_sra_epi16(_mm512_slli_epi16(a, 8), rhs), 8);
+            _mm512_or_si512(odd, even)
+        }
+        for Avx2(a: __m256i, rhs: i32) -> __m256i {
+            let rhs = _mm_cvtsi32_si128(wrapping_shift_count(rhs));
+            let odd = _mm256_and_si256(_mm256_sra_epi16(a, rhs), _mm256_set1_epi16(0xFF00u16 as i16));
+            let even = _mm256_srli_epi16(_mm256_sra_epi16(_mm256_slli_epi16(a, 8), rhs), 8);
+            _mm256_or_si256(odd, even)
+        }
+        for Sse41(a: __m128i, rhs: i32) -> __m128i {
+            Ops::<Sse2, i8>::shr_arithmetic(a, rhs)
+        }
+        for Sse2(a: __m128i, rhs: i32) -> __m128i {
+            let rhs = _mm_cvtsi32_si128(wrapping_shift_count(rhs));
+            let odd = _mm_and_si128(_mm_sra_epi16(a, rhs), _mm_set1_epi16(0xFF00u16 as i16));
+            let even = _mm_srli_epi16(_mm_sra_epi16(_mm_slli_epi16(a, 8), rhs), 8);
+            _mm_or_si128(odd, even)
+        }
+        for Scalar(a: i8, rhs: i32) -> i8 {
+            a.wrapping_shr(wrapping_shift_count(rhs) as u32)
+        }
+        for Neon(a: int8x16_t, rhs: i32) -> int8x16_t {
+            vshlq_s8(a, Self::set1(-wrapping_shift_count(rhs) as i8))
+        }
+        for Wasm(a: v128, rhs: i32) -> v128 {
+            i8x16_shr(a, wrapping_shift_count(rhs) as u32)
+        }
+    }
+}
+
+/// Shifts each 8 bit lane by its own count, one count bit at a time. SSE has no 8 bit shifts, so
+/// `shift` shifts the whole register by `bits` and masks off what crossed into the neighbouring lane.
+#[cfg(any(target_arch = "x86_64", target_arch = "x86"))]
+#[inline(always)]
+unsafe fn sse_shift_lanes_epi8(
+    a: __m128i,
+    counts: __m128i,
+    shift: impl Fn(__m128i, i32) -> __m128i,
+) -> __m128i {
+    let mut result = a;
+    for bit in (0..3).rev() {
+        // Move the count bit into the sign bit of its byte and broadcast it over the lane.
+        let take = _mm_cmplt_epi8(
+            _mm_sll_epi16(counts, _mm_cvtsi32_si128(7 - bit)),
+            _mm_setzero_si128(),
+        );
+        let shifted = shift(result, 1 << bit);
+        result = _mm_or_si128(_mm_and_si128(take, shifted), _mm_andnot_si128(take, result));
+    }
+    result
+}
+
+impl_op! {
+    fn shl_lanes<i8> {
+        for Avx512(a: __m512i, counts: __m512i) -> __m512i {
+            // There are no 8 bit variable shifts, so shift the even and odd bytes as 16 bit lanes.
+            let counts = _mm512_and_si512(counts, Self::set1(7));
+            let low = _mm512_set1_epi16(0x00FF);
+            let odd_counts = _mm512_srli_epi16(counts, 8);
+            let even = Ops::<Avx512, i16>::shl_lanes(a, _mm512_and_si512(counts, low));
+            let odd = Ops::<Avx512, i16>::shl_lanes(_mm512_andnot_si512(low, a), odd_counts);
+            _mm512_mask_blend_epi8(0xAAAA_AAAA_AAAA_AAAA, even, odd)
+        }
+        for Avx2(a: __m256i, counts: __m256i) -> __m256i {
+            let counts = _mm256_and_si256(counts, Self::set1(7));
+            let low = _mm256_set1_epi16(0x00FF);
+            let odd_counts = _mm256_srli_epi16(counts, 8);
+            let even = Ops::<Avx2, i16>::shl_lanes(a, _mm256_and_si256(counts, low));
+            let odd = Ops::<Avx2, i16>::shl_lanes(_mm256_andnot_si256(low, a), odd_counts);
+            _mm256_blendv_epi8(even, odd, _mm256_set1_epi16(0xFF00u16 as i16))
+        }
+        for Sse41(a: __m128i, counts: __m128i) -> __m128i {
+            Ops::<Sse2, i8>::shl_lanes(a, counts)
+        }
+        for Sse2(a: __m128i, counts: __m128i) -> __m128i {
+            sse_shift_lanes_epi8(a, counts, |a, bits| {
+                _mm_and_si128(_mm_sll_epi16(a, _mm_cvtsi32_si128(bits)), _mm_set1_epi8((0xFF << bits) as i8))
+            })
+        }
+        for Scalar(a: i8, counts: i8) -> i8 {
+            a.wrapping_shl(counts as u32)
+        }
+        for Neon(a: int8x16_t, counts: int8x16_t) -> int8x16_t {
+            vshlq_s8(a, vandq_s8(counts, Self::set1(7)))
+        }
+        for Wasm(a: v128, counts: v128) -> v128 {
+            map_lanes::<_, i8, 16>(a, counts, |lane, count| lane.wrapping_shl(count as u32))
+        }
+    }
+}
+
+impl_op! {
+    fn shr_lanes<i8> {
+        for Avx512(a: __m512i, counts: __m512i) -> __m512i {
+            // There are no 8 bit variable shifts, so shift the even and odd bytes as 16 bit lanes.
+            let counts = _mm512_and_si512(counts, Self::set1(7));
+            let low = _mm512_set1_epi16(0x00FF);
+            let odd_counts = _mm512_srli_epi16(counts, 8);
+            let even = Ops::<Avx512, i16>::shr_lanes(_mm512_and_si512(a, low), _mm512_and_si512(counts, low));
+            let odd = Ops::<Avx512, i16>::shr_lanes(a, odd_counts);
+            _mm512_mask_blend_epi8(0xAAAA_AAAA_AAAA_AAAA, even, odd)
+        }
+        for Avx2(a: __m256i, counts: __m256i) -> __m256i {
+            let counts = _mm256_and_si256(counts, Self::set1(7));
+            let low = _mm256_set1_epi16(0x00FF);
+            let odd_counts = _mm256_srli_epi16(counts, 8);
+            let even = Ops::<Avx2, i16>::shr_lanes(_mm256_and_si256(a, low), _mm256_and_si256(counts, low));
+            let odd = Ops::<Avx2, i16>::shr_lanes(a, odd_counts);
+            _mm256_blendv_epi8(even, odd, _mm256_set1_epi16(0xFF00u16 as i16))
+        }
+        for Sse41(a: __m128i, counts: __m128i) -> __m128i {
+            Ops::<Sse2, i8>::shr_lanes(a, counts)
+        }
+        for Sse2(a: __m128i, counts: __m128i) -> __m128i {
+            sse_shift_lanes_epi8(a, counts, |a, bits| {
+                _mm_and_si128(_mm_srl_epi16(a, _mm_cvtsi32_si128(bits)), _mm_set1_epi8((0xFF >> bits) as i8))
+            })
+        }
+        for Scalar(a: i8, counts: i8) -> i8 {
+            (a as u8).wrapping_shr(counts as u32) as i8
+        }
+        for Neon(a: int8x16_t, counts: int8x16_t) -> int8x16_t {
+            let counts = vnegq_s8(vandq_s8(counts, Self::set1(7)));
+            vreinterpretq_s8_u8(vshlq_u8(vreinterpretq_u8_s8(a), counts))
+        }
+        for Wasm(a: v128, counts: v128) -> v128 {
+            map_lanes::<_, i8, 16>(a, counts, |lane, count| (lane as u8).wrapping_shr(count as u32) as i8)
+        }
+    }
+}
+
+impl_op! {
+    fn shr_arithmetic_lanes<i8> {
+        for Avx512(a: __m512i, counts: __m512i) -> __m512i {
+            // There are no 8 bit variable shifts, so shift the even and odd bytes as 16 bit lanes.
+            let counts = _mm512_and_si512(counts, Self::set1(7));
+            let low = _mm512_set1_epi16(0x00FF);
+            let odd_counts = _mm512_srli_epi16(counts, 8);
+            let even = Ops::<Avx512, i16>::shr_arithmetic_lanes(_mm512_slli_epi16(a, 8), _mm512_and_si512(counts, low));
+            let even = _mm512_srli_epi16(even, 8);
+            let odd = Ops::<Avx512, i16>::shr_arithmetic_lanes(a, odd_counts);
+            _mm512_mask_blend_epi8(0xAAAA_AAAA_AAAA_AAAA, even, odd)
+        }
+        for Avx2(a: __m256i, counts: __m256i) -> __m256i {
+            let counts = _mm256_and_si256(counts, Self::set1(7));
+            let low = _mm256_set1_epi16(0x00FF);
+            let odd_counts = _mm256_srli_epi16(counts, 8);
+            let even = Ops::<Avx2, i16>::shr_arithmetic_lanes(_mm256_slli_epi16(a, 8), _mm256_and_si256(counts, low));
+            let even = _mm256_srli_epi16(even, 8);
+            let odd = Ops::<Avx2, i16>::shr_arithmetic_lanes(a, odd_counts);
+            _mm256_blendv_epi8(even, odd, _mm256_set1_epi16(0xFF00u16 as i16))
+        }
+        for Sse41(a: __m128i, counts: __m128i) -> __m128i {
+            Ops::<Sse2, i8>::shr_arithmetic_lanes(a, counts)
+        }
+        for Sse2(a: __m128i, counts: __m128i) -> __m128i {
+            // Flipping negative lanes turns the logical shift into an arithmetic one.
+            let sign = _mm_cmplt_epi8(a, _mm_setzero_si128());
+            _mm_xor_si128(Self::shr_lanes(_mm_xor_si128(a, sign), counts), sign)
+        }
+        for Scalar(a: i8, counts: i8) -> i8 {
+            a.wrapping_shr(counts as u32)
+        }
+        for Neon(a: int8x16_t, counts: int8x16_t) -> int8x16_t {
+            vshlq_s8(a, vnegq_s8(vandq_s8(counts, Self::set1(7))))
+        }
+        for Wasm(a: v128, counts: v128) -> v128 {
+            map_lanes::<_, i8, 16>(a, counts, |lane, count| lane.wrapping_shr(count as u32))
+        }
+    }
+}
+
+impl_op! {
+    fn rotate_left<i8> {
+        for Avx512(a: __m512i, rhs: i32) -> __m512i {
+            _mm512_or_si512(Self::shl(a, rhs), Self::shr(a, rhs.wrapping_neg()))
+        }
+        for Avx2(a: __m256i, rhs: i32) -> __m256i {
+            _mm256_or_si256(Self::shl(a, rhs), Self::shr(a, rhs.wrapping_neg()))
+        }
+        for Sse41(a: __m128i, rhs: i32) -> __m128i {
+            _mm_or_si128(Self::shl(a, rhs), Self::shr(a, rhs.wrapping_neg()))
+        }
+        for Sse2(a: __m128i, rhs: i32) -> __m128i {
+            _mm_or_si128(Self::shl(a, rhs), Self::shr(a, rhs.wrapping_neg()))
+        }
+        for Scalar(a: i8, rhs: i32) -> i8 {
+            a.rotate_left(rhs as u32)
+        }
+        for Neon(a: int8x16_t, rhs: i32) -> int8x16_t {
+            vorrq_s8(Self::shl(a, rhs), Self::shr(a, rhs.wrapping_neg()))
+        }
+        for Wasm(a: v128, rhs: i32) -> v128 {
+            v128_or(Self::shl(a, rhs), Self::shr(a, rhs.wrapping_neg()))
+        }
+    }
+}
+
+impl_op! {
+    fn rotate_left_lanes<i8> {
+        for Avx512(a: __m512i, counts: __m512i) -> __m512i {
+            let back = Self::sub(Self::zeroes(), counts);
+            _mm512_or_si512(Self::shl_lanes(a, counts), Self::shr_lanes(a, back))
+        }
+        for Avx2(a: __m256i, counts: __m256i) -> __m256i {
+            let back = Self::sub(Self::zeroes(), counts);
+            _mm256_or_si256(Self::shl_lanes(a, counts), Self::shr_lanes(a, back))
+        }
+        for Sse41(a: __m128i, counts: __m128i) -> __m128i {
+            let back = Self::sub(Self::zeroes(), counts);
+            _mm_or_si128(Self::shl_lanes(a, counts), Self::shr_lanes(a, back))
+        }
+        for Sse2(a: __m128i, counts: __m128i) -> __m128i {
+            let back = Self::sub(Self::zeroes(), counts);
+            _mm_or_si128(Self::shl_lanes(a, counts), Self::shr_lanes(a, back))
+        }
+        for Scalar(a: i8, counts: i8) -> i8 {
+            a.rotate_left(counts as u32)
+        }
+        for Neon(a: int8x16_t, counts: int8x16_t) -> int8x16_t {
+            let back = Self::sub(Self::zeroes(), counts);
+            vorrq_s8(Self::shl_lanes(a, counts), Self::shr_lanes(a, back))
+        }
+        for Wasm(a: v128, counts: v128) -> v128 {
+            let back = Self::sub(Self::zeroes(), counts);
+            v128_or(Self::shl_lanes(a, counts), Self::shr_lanes(a, back))
+        }
+    }
+}
+
+impl_op! {
+    fn rotate_right<i8> {
+        for Avx512(a: __m512i, rhs: i32) -> __m512i {
+            _mm512_or_si512(Self::shr(a, rhs), Self::shl(a, rhs.wrapping_neg()))
+        }
+        for Avx2(a: __m256i, rhs: i32) -> __m256i {
+            _mm256_or_si256(Self::shr(a, rhs), Self::shl(a, rhs.wrapping_neg()))
+        }
+        for Sse41(a: __m128i, rhs: i32) -> __m128i {
+            _mm_or_si128(Self::shr(a, rhs), Self::shl(a, rhs.wrapping_neg()))
+        }
+        for Sse2(a: __m128i, rhs: i32) -> __m128i {
+            _mm_or_si128(Self::shr(a, rhs), Self::shl(a, rhs.wrapping_neg()))
+        }
+        for Scalar(a: i8, rhs: i32) -> i8 {
+            a.rotate_right(rhs as u32)
+        }
+        for Neon(a: int8x16_t, rhs: i32) -> int8x16_t {
+            vorrq_s8(Self::shr(a, rhs), Self::shl(a, rhs.wrapping_neg()))
+        }
+        for Wasm(a: v128, rhs: i32) -> v128 {
+            v128_or(Self::shr(a, rhs), Self::shl(a, rhs.wrapping_neg()))
+        }
+    }
+}
+
+impl_op! {
+    fn rotate_right_lanes<i8> {
+        for Avx512(a: __m512i, counts: __m512i) -> __m512i {
+            let back = Self::sub(Self::zeroes(), counts);
+            _mm512_or_si512(Self::shr_lanes(a, counts), Self::shl_lanes(a, back))
+        }
+        for Avx2(a: __m256i, counts: __m256i) -> __m256i {
+            let back = Self::sub(Self::zeroes(), counts);
+            _mm256_or_si256(Self::shr_lanes(a, counts), Self::shl_lanes(a, back))
+        }
+        for Sse41(a: __m128i, counts: __m128i) -> __m128i {
+            let back = Self::sub(Self::zeroes(), counts);
+            _mm_or_si128(Self::shr_lanes(a, counts), Self::shl_lanes(a, back))
+        }
+        for Sse2(a: __m128i, counts: __m128i) -> __m128i {
+            let back = Self::sub(Self::zeroes(), counts);
+            _mm_or_si128(Self::shr_lanes(a, counts), Self::shl_lanes(a, back))
+        }
+        for Scalar(a: i8, counts: i8) -> i8 {
+            a.rotate_right(counts as u32)
+        }
+        for Neon(a: int8x16_t, counts: int8x16_t) -> int8x16_t {
+            let back = Self::sub(Self::zeroes(), counts);
+            vorrq_s8(Self::shr_lanes(a, counts), Self::shl_lanes(a, back))
+        }
+        for Wasm(a: v128, counts: v128) -> v128 {
+            let back = Self::sub(Self::zeroes(), counts);
+            v128_or(Self::shr_lanes(a, counts), Self::shl_lanes(a, back))
+        }
+    }
+}
+
+/// Set bits in each nibble value, for the `pshufb` lookups.
+const NIBBLE_POPCOUNT: [i8; 16] = [0, 1, 1, 2, 1, 2, 2, 3, 1, 2, 2, 3, 2, 3, 3, 4];
+
+/// Leading zeros of each nibble value within 4 bits.
+const NIBBLE_LEADING_ZEROS: [i8; 16] = [4, 3, 2, 2, 1, 1, 1, 1, 0, 0, 0, 0, 0, 0, 0, 0];
+
+/// Each nibble value with its 4 bits reversed.
+const NIBBLE_REVERSED: [i8; 16] = [0, 8, 4, 12, 2, 10, 6, 14, 1, 9, 5, 13, 3, 11, 7, 15];
+
+/// Looks up the low and the high nibble of every byte in `table`.
+#[cfg(any(target_arch = "x86_64", target_arch = "x86"))]
+#[target_feature(enable = "ssse3")]
+#[inline]
+unsafe fn sse_nibble_lookup(table: [i8; 16], a: __m128i) -> (__m128i, __m128i) {
+    let table = core::mem::transmute::<[i8; 16], __m128i>(table);
+    let nibble = _mm_set1_epi8(0x0F);
+    let low = _mm_shuffle_epi8(table, _mm_and_si128(a, nibble));
+    let high = _mm_shuffle_epi8(table, _mm_and_si128(_mm_srli_epi16(a, 4), nibble));
+    (low, high)
+}
+
+#[cfg(any(target_arch = "x86_64", target_arch = "x86"))]
+#[target_feature(enable = "avx2")]
+#[inline]
+unsafe fn avx2_nibble_lookup(table: [i8; 16], a: __m256i) -> (__m256i, __m256i) {
+    let table = _mm256_broadcastsi128_si256(core::mem::transmute::<[i8; 16], __m128i>(table));
+    let nibble = _mm256_set1_epi8(0x0F);
+    let low = _mm256_shuffle_epi8(table, _mm256_and_si256(a, nibble));
+    let high = _mm256_shuffle_epi8(table, _mm256_and_si256(_mm256_srli_epi16(a, 4), nibble));
+    (low, high)
+}
+
+#[cfg(any(target_arch = "x86_64", target_arch = "x86"))]
+#[target_feature(enable = "avx512f", enable = "avx512bw")]
+#[inline]
+unsafe fn avx512_nibble_lookup(table: [i8; 16], a: __m512i) -> (__m512i, __m512i) {
+    let table = _mm512_broadcast_i32x4(core::mem::transmute::<[i8; 16], __m128i>(table));
+    let nibble = _mm512_set1_epi8(0x0F);
+    let low = _mm512_shuffle_epi8(table, _mm512_and_si512(a, nibble));
+    let high = _mm512_shuffle_epi8(table, _mm512_and_si512(_mm512_srli_epi16(a, 4), nibble));
+    (low, high)
+}
+
+#[cfg(any(target_arch = "x86_64", target_arch = "x86"))]
+#[target_feature(enable = "avx512f", enable = "avx512bw", enable = "avx512bitalg")]
+#[inline]
+unsafe fn avx512_bitalg_popcnt_epi8(a: __m512i) -> __m512i {
+    _mm512_popcnt_epi8(a)
+}
+
+#[cfg(any(target_arch = "x86_64", target_arch = "x86"))]
+#[target_feature(enable = "avx512f", enable = "avx512vpopcntdq")]
+#[inline]
+unsafe fn avx512_vpopcntdq_total(a: __m512i) -> u32 {
+    _mm512_reduce_add_epi64(_mm512_popcnt_epi64(a)) as u32
+}
+
+// SSE2 has no byte shuffle, so it counts with the usual SWAR steps on 16 bit shifts.
+impl_op! {
+    fn count_ones<i8> {
+        for Avx512(a: __m512i) -> __m512i {
+            if has_feature(CpuFeature::Avx512Bitalg) {
+                return avx512_bitalg_popcnt_epi8(a);
+            }
+            let (low, high) = avx512_nibble_lookup(NIBBLE_POPCOUNT, a);
+            _mm512_add_epi8(low, high)
+        }
+        for Avx2(a: __m256i) -> __m256i {
+            let (low, high) = avx2_nibble_lookup(NIBBLE_POPCOUNT, a);
+            _mm256_add_epi8(low, high)
+        }
+        for Sse41(a: __m128i) -> __m128i {
+            let (low, high) = sse_nibble_lookup(NIBBLE_POPCOUNT, a);
+            _mm_add_epi8(low, high)
+        }
+        for Sse2(a: __m128i) -> __m128i {
+            let pairs = _mm_and_si128(_mm_srli_epi16(a, 1), _mm_set1_epi8(0x55));
+            let x = _mm_sub_epi8(a, pairs);
+            let quads = _mm_set1_epi8(0x33);
+            let x = _mm_add_epi8(_mm_and_si128(x, quads), _mm_and_si128(_mm_srli_epi16(x, 2), quads));
+            _mm_and_si128(_mm_add_epi8(x, _mm_srli_epi16(x, 4)), _mm_set1_epi8(0x0F))
+        }
+        for Scalar(a: i8) -> i8 {
+            a.count_ones() as i8
+        }
+        for Neon(a: int8x16_t) -> int8x16_t {
+            vcntq_s8(a)
+        }
+        for Wasm(a: v128) -> v128 {
+            i8x16_popcnt(a)
+        }
+    }
+}
+
+// With a nibble table, a byte has the leading zeros of its high nibble, plus those of its low
+// nibble when the high one is zero. Elsewhere the bits below the highest set bit are filled in
+// and counted.
+impl_op! {
+    fn leading_zeros<i8> {
+        for Avx512(a: __m512i) -> __m512i {
+            let (low, high) = avx512_nibble_lookup(NIBBLE_LEADING_ZEROS, a);
+            let high_empty = _mm512_movm_epi8(_mm512_cmpeq_epi8_mask(high, _mm512_set1_epi8(4)));
+            _mm512_add_epi8(high, _mm512_and_si512(high_empty, low))
+        }
+        for Avx2(a: __m256i) -> __m256i {
+            let (low, high) = avx2_nibble_lookup(NIBBLE_LEADING_ZEROS, a);
+            let high_empty = _mm256_cmpeq_epi8(high, _mm256_set1_epi8(4));
+            _mm256_add_epi8(high, _mm256_and_si256(high_empty, low))
+        }
+        for Sse41(a: __m128i) -> __m128i {
+            let (low, high) = sse_nibble_lookup(NIBBLE_LEADING_ZEROS, a);
+            let high_empty = _mm_cmpeq_epi8(high, _mm_set1_epi8(4));
+            _mm_add_epi8(high, _mm_and_si128(high_empty, low))
+        }
+        for Sse2(a: __m128i) -> __m128i {
+            let a = _mm_or_si128(a, Self::shr(a, 1));
+            let a = _mm_or_si128(a, Self::shr(a, 2));
+            let a = _mm_or_si128(a, Self::shr(a, 4));
+            _mm_sub_epi8(_mm_set1_epi8(8), Self::count_ones(a))
+        }
+        for Scalar(a: i8) -> i8 {
+            a.leading_zeros() as i8
+        }
+        for Neon(a: int8x16_t) -> int8x16_t {
+            vclzq_s8(a)
+        }
+        for Wasm(a: v128) -> v128 {
+            let a = v128_or(a, u8x16_shr(a, 1));
+            let a = v128_or(a, u8x16_shr(a, 2));
+            let a = v128_or(a, u8x16_shr(a, 4));
+            i8x16_sub(i8x16_splat(8), i8x16_popcnt(a))
+        }
+    }
+}
+
+// The bits below the lowest set bit are the set bits of `!a & (a - 1)`.
+impl_op! {
+    fn trailing_zeros<i8> {
+        for Avx512(a: __m512i) -> __m512i {
+            Self::count_ones(_mm512_andnot_si512(a, _mm512_sub_epi8(a, _mm512_set1_epi8(1))))
+        }
+        for Avx2(a: __m256i) -> __m256i {
+            Self::count_ones(_mm256_andnot_si256(a, _mm256_sub_epi8(a, _mm256_set1_epi8(1))))
+        }
+        for Sse41(a: __m128i) -> __m128i {
+            Self::count_ones(_mm_andnot_si128(a, _mm_sub_epi8(a, _mm_set1_epi8(1))))
+        }
+        for Sse2(a: __m128i) -> __m128i {
+            Self::count_ones(_mm_andnot_si128(a, _mm_sub_epi8(a, _mm_set1_epi8(1))))
+        }
+        for Scalar(a: i8) -> i8 {
+            a.trailing_zeros() as i8
+        }
+        for Neon(a: int8x16_t) -> int8x16_t {
+            vcntq_s8(vbicq_s8(vsubq_s8(a, vdupq_n_s8(1)), a))
+        }
+        for Wasm(a: v128) -> v128 {
+            i8x16_popcnt(v128_andnot(i8x16_sub(a, i8x16_splat(1)), a))
+        }
+    }
+}
+
+impl_op! {
+    fn reverse_bits<i8> {
+        for Avx512(a: __m512i) -> __m512i {
+            let (low, high) = avx512_nibble_lookup(NIBBLE_REVERSED, a);
+            _mm512_or_si512(_mm512_slli_epi16(low, 4), high)
+        }
+        for Avx2(a: __m256i) -> __m256i {
+            let (low, high) = avx2_nibble_lookup(NIBBLE_REVERSED, a);
+            _mm256_or_si256(_mm256_slli_epi16(low, 4), high)
+        }
+        for Sse41(a: __m128i) -> __m128i {
+            let (low, high) = sse_nibble_lookup(NIBBLE_REVERSED, a);
+            _mm_or_si128(_mm_slli_epi16(low, 4), high)
+        }
+        for Sse2(a: __m128i) -> __m128i {
+            let mask = _mm_set1_epi8(0x55);
+            let a = _mm_or_si128(
+                _mm_and_si128(_mm_srli_epi16(a, 1), mask),
+                _mm_slli_epi16(_mm_and_si128(a, mask), 1),
+            );
+            let mask = _mm_set1_epi8(0x33);
+            let a = _mm_or_si128(
+                _mm_and_si128(_mm_srli_epi16(a, 2), mask),
+                _mm_slli_epi16(_mm_and_si128(a, mask), 2),
+            );
+            let mask = _mm_set1_epi8(0x0F);
+            _mm_or_si128(
+                _mm_and_si128(_mm_srli_epi16(a, 4), mask),
+                _mm_slli_epi16(_mm_and_si128(a, mask), 4),
+            )
+        }
+        for Scalar(a: i8) -> i8 {
+            a.reverse_bits()
+        }
+        for Neon(a: int8x16_t) -> int8x16_t {
+            vrbitq_s8(a)
+        }
+        for Wasm(a: v128) -> v128 {
+            let mask = i8x16_splat(0x55);
+            let a = v128_or(v128_and(u8x16_shr(a, 1), mask), i8x16_shl(v128_and(a, mask), 1));
+            let mask = i8x16_splat(0x33);
+            let a = v128_or(v128_and(u8x16_shr(a, 2), mask), i8x16_shl(v128_and(a, mask), 2));
+            v128_or(u8x16_shr(a, 4), i8x16_shl(a, 4))
+        }
+    }
+}
+
+// Byte counts are summed into 64 bit lanes with `psadbw` against zero.
+impl_op! {
+    fn popcount_total<i8> {
+        for Avx512(a: __m512i) -> u32 {
+            if has_feature(CpuFeature::Avx512Vpopcntdq) {
+                return avx512_vpopcntdq_total(a);
+            }
+            let sums = _mm512_sad_epu8(Self::count_ones(a), _mm512_setzero_si512());
+            _mm512_reduce_add_epi64(sums) as u32
+        }
+        for Avx2(a: __m256i) -> u32 {
+            let sums = _mm256_sad_epu8(Self::count_ones(a), _mm256_setzero_si256());
+            let sums = _mm_add_epi64(_mm256_castsi256_si128(sums), _mm256_extracti128_si256(sums, 1));
+            _mm_cvtsi128_si32(_mm_add_epi64(sums, _mm_unpackhi_epi64(sums, sums))) as u32
+        }
+        for Sse41(a: __m128i) -> u32 {
+            let sums = _mm_sad_epu8(Self::count_ones(a), _mm_setzero_si128());
+            _mm_cvtsi128_si32(_mm_add_epi64(sums, _mm_unpackhi_epi64(sums, sums))) as u32
+        }
+        for Sse2(a: __m128i) -> u32 {
+            let sums = _mm_sad_epu8(Self::count_ones(a), _mm_setzero_si128());
+            _mm_cvtsi128_si32(_mm_add_epi64(sums, _mm_unpackhi_epi64(sums, sums))) as u32
+        }
+        for Scalar(a: i8) -> u32 {
+            a.count_ones()
+        }
+        for Neon(a: int8x16_t) -> u32 {
+            vaddlvq_u8(vcntq_u8(vreinterpretq_u8_s8(a))) as u32
+        }
+        for Wasm(a: v128) -> u32 {
+            let sums = i32x4_extadd_pairwise_u16x8(i16x8_extadd_pairwise_u8x16(i8x16_popcnt(a)));
+            (u32x4_extract_lane::<0>(sums)
+                + u32x4_extract_lane::<1>(sums)
+                + u32x4_extract_lane::<2>(sums)
+                + u32x4_extract_lane::<3>(sums))
+        }
+    }
+}
+
+// Table lookups give zero for every index outside the table, negative ones included, which is
+// what `vqtbl`, `i8x16_swizzle` and a masked `vpermb` do anyway. `pshufb` only zeroes indices
+// with the sign bit set, so a saturating add of 0x70 first keeps the low nibble of indices
+// below 16 and pushes every other index to 0x80 or above. Tables longer than 16 bytes are
+// looked up 16 bytes at a time, with each block's indices shifted down so only one block
+// matches.
+
+#[inline(always)]
+unsafe fn lookup_lane(table: *const i8, len: usize, index: i8) -> i8 {
+    let index = index as u8 as usize;
+    if index < len {
+        *table.add(index)
+    } else {
+        0
+    }
+}
+
+/// `vpermb` lookup in the first `len` bytes of `table`.
+#[cfg(any(target_arch = "x86_64", target_arch = "x86"))]
+#[target_feature(enable = "avx512f", enable = "avx512bw", enable = "avx512vbmi")]
+#[inline]
+unsafe fn avx512_vbmi_lookup(table: __m512i, len: i8, indices: __m512i) -> __m512i {
+    let in_table = _mm512_cmplt_epu8_mask(indices, _mm512_set1_epi8(len));
+    _mm512_maskz_permutexvar_epi8(in_table, indices, table)
+}
+
+/// `pshufb` lookup in a 16 byte `table`, for SSE2 engines running on an SSSE3 CPU.
+#[cfg(any(target_arch = "x86_64", target_arch = "x86"))]
+#[target_feature(enable = "ssse3")]
+#[inline]
+unsafe fn ssse3_lookup16(table: *const i8, indices: __m128i) -> __m128i {
+    let table = _mm_loadu_si128(table as *const __m128i);
+    _mm_shuffle_epi8(table, _mm_adds_epu8(indices, _mm_set1_epi8(0x70)))
+}
+
+// AVX2 and AVX-512 `pshufb` only shuffles within 128 bit lanes, so the table is broadcast to
+// every lane and each lane looks up the whole of it. Pure SSE2 has no byte shuffle and looks up
+// one lane at a time.
+impl_op! {
+    fn lookup16<i8> {
+        for Avx512(table: *const i8, indices: __m512i) -> __m512i {
+            let table = _mm512_broadcast_i32x4(_mm_loadu_si128(table as *const __m128i));
+            _mm512_shuffle_epi8(table, _mm512_adds_epu8(indices, _mm512_set1_epi8(0x70)))
+        }
+        for Avx2(table: *const i8, indices: __m256i) -> __m256i {
+            let table = _mm256_broadcastsi128_si256(_mm_loadu_si128(table as *const __m128i));
+            _mm256_shuffle_epi8(table, _mm256_adds_epu8(indices, _mm256_set1_epi8(0x70)))
+        }
+        for Sse41(table: *const i8, indices: __m128i) -> __m128i {
+            let table = _mm_loadu_si128(table as *const __m128i);
+            _mm_shuffle_epi8(table, _mm_adds_epu8(indices, _mm_set1_epi8(0x70)))
+        }
+        for Sse2(table: *const i8, indices: __m128i) -> __m128i {
+            if has_feature(CpuFeature::Ssse3) {
+                return ssse3_lookup16(table, indices);
+            }
+            let indices = core::mem::transmute::<__m128i, [i8; 16]>(indices);
+            let mut result = [0i8; 16];
+            for (slot, &index) in result.iter_mut().zip(indices.iter()) {
+                *slot = lookup_lane(table, 16, index);
+            }
+            core::mem::transmute::<[i8; 16], __m128i>(result)
+        }
+        for Scalar(table: *const i8, indices: i8) -> i8 {
+            lookup_lane(table, 16, indices)
+        }
+        for Neon(table: *const i8, indices: int8x16_t) -> int8x16_t {
+            vqtbl1q_s8(vld1q_s8(table), vreinterpretq_u8_s8(indices))
+        }
+        for Wasm(table: *const i8, indices: v128) -> v128 {
+            i8x16_swizzle(v128_load(table as *const v128), indices)
+        }
+    }
+}
+
+impl_op! {
+    fn lookup32<i8> {
+        for Avx512(table: *const i8, indices: __m512i) -> __m512i {
+            if has_feature(CpuFeature::Avx512Vbmi) {
+                let table = _mm512_castsi256_si512(_mm256_loadu_si256(table as *const __m256i));
+                return avx512_vbmi_lookup(table, 32, indices);
+            }
+            let high = Self::lookup16(table.add(16), _mm512_sub_epi8(indices, _mm512_set1_epi8(16)));
+            _mm512_or_si512(Self::lookup16(table, indices), high)
+        }
+        for Avx2(table: *const i8, indices: __m256i) -> __m256i {
+            let high = Self::lookup16(table.add(16), _mm256_sub_epi8(indices, _mm256_set1_epi8(16)));
+            _mm256_or_si256(Self::lookup16(table, indices), high)
+        }
+        for Sse41(table: *const i8, indices: __m128i) -> __m128i {
+            let high = Self::lookup16(table.add(16), _mm_sub_epi8(indices, _mm_set1_epi8(16)));
+            _mm_or_si128(Self::lookup16(table, indices), high)
+        }
+        for Sse2(table: *const i8, indices: __m128i) -> __m128i {
+            let high = Self::lookup16(table.add(16), _mm_sub_epi8(indices, _mm_set1_epi8(16)));
+            _mm_or_si128(Self::lookup16(table, indices), high)
+        }
+        for Scalar(table: *const i8, indices: i8) -> i8 {
+            lookup_lane(table, 32, indices)
+        }
+        for Neon(table: *const i8, indices: int8x16_t) -> int8x16_t {
+            vqtbl2q_s8(vld1q_s8_x2(table), vreinterpretq_u8_s8(indices))
+        }
+        for Wasm(table: *const i8, indices: v128) -> v128 {
+            let high = Self::lookup16(table.add(16), i8x16_sub(indices, i8x16_splat(16)));
+            v128_or(Self::lookup16(table, indices), high)
+        }
+    }
+}
+
+impl_op! {
+    fn lookup64<i8> {
+        for Avx512(table: *const i8, indices: __m512i) -> __m512i {
+            if has_feature(CpuFeature::Avx512Vbmi) {
+                let table = _mm512_loadu_si512(table as *const _);
+                return avx512_vbmi_lookup(table, 64, indices);
+            }
+            let high = Self::lookup32(table.add(32), _mm512_sub_epi8(indices, _mm512_set1_epi8(32)));
+            _mm512_or_si512(Self::lookup32(table, indices), high)
+        }
+        for Avx2(table: *const i8, indices: __m256i) -> __m256i {
+            let high = Self::lookup32(table.add(32), _mm256_sub_epi8(indices, _mm256_set1_epi8(32)));
+            _mm256_or_si256(Self::lookup32(table, indices), high)
+        }
+        for Sse41(table: *const i8, indices: __m128i) -> __m128i {
+            let high = Self::lookup32(table.add(32), _mm_sub_epi8(indices, _mm_set1_epi8(32)));
+            _mm_or_si128(Self::lookup32(table, indices), high)
+        }
+        for Sse2(table: *const i8, indices: __m128i) -> __m128i {
+            let high = Self::lookup32(table.add(32), _mm_sub_epi8(indices, _mm_set1_epi8(32)));
+            _mm_or_si128(Self::lookup32(table, indices), high)
+        }
+        for Scalar(table: *const i8, indices: i8) -> i8 {
+            lookup_lane(table, 64, indices)
+        }
+        for Neon(table: *const i8, indices: int8x16_t) -> int8x16_t {
+            vqtbl4q_s8(vld1q_s8_x4(table), vreinterpretq_u8_s8(indices))
+        }
+        for Wasm(table: *const i8, indices: v128) -> v128 {
+            let high = Self::lookup32(table.add(32), i8x16_sub(indices, i8x16_splat(32)));
+            v128_or(Self::lookup32(table, indices), high)
+        }
+    }
+}
@@ -0,0 +1,514 @@
+use super::*;
+
+// Structure loads and stores only have dedicated instructions on Neon. The other engines build
+// them from lane permutes in `overloads.rs`.
+
+impl_op! {
+    fn load_interleaved2<i8> {
+        for Neon(ptr: *const i8) -> (int8x16_t, int8x16_t) {
+            let v = vld2q_s8(ptr);
+            (v.0, v.1)
+        }
+    }
+}
+
+impl_op! {
+    fn store_interleaved2<i8> {
+        for Neon(ptr: *mut i8, a: int8x16_t, b: int8x16_t) {
+            vst2q_s8(ptr, int8x16x2_t(a, b))
+        }
+    }
+}
+
+impl_op! {
+    fn load_interleaved3<i8> {
+        for Neon(ptr: *const i8) -> (int8x16_t, int8x16_t, int8x16_t) {
+            let v = vld3q_s8(ptr);
+            (v.0, v.1, v.2)
+        }
+    }
+}
+
+impl_op! {
+    fn store_interleaved3<i8> {
+        for Neon(ptr: *mut i8, a: int8x16_t, b: int8x16_t, c: int8x16_t) {
+            vst3q_s8(ptr, int8x16x3_t(a, b, c))
+        }
+    }
+}
+
+impl_op! {
+    fn load_interleaved4<i8> {
+        for Neon(ptr: *const i8) -> (int8x16_t, int8x16_t, int8x16_t, int8x16_t) {
+            let v = vld4q_s8(ptr);
+            (v.0, v.1, v.2, v.3)
+        }
+    }
+}
+
+impl_op! {
+    fn store_interleaved4<i8> {
+        for Neon(ptr: *mut i8, a: int8x16_t, b: int8x16_t, c: int8x16_t, d: int8x16_t) {
+            vst4q_s8(ptr, int8x16x4_t(a, b, c, d))
+        }
+    }
+}
+
+impl_op! {
+    fn load_interleaved2<i16> {
+        for Neon(ptr: *const i16) -> (int16x8_t, int16x8_t) {
+            let v = vld2q_s16(ptr);
+            (v.0, v.1)
+        }
+    }
+}
+
+impl_op! {
+    fn store_interleaved2<i16> {
+        for Neon(ptr: *mut i16, a: int16x8_t, b: int16x8_t) {
+            vst2q_s16(ptr, int16x8x2_t(a, b))
+        }
+    }
+}
+
+impl_op! {
+    fn load_interleaved3<i16> {
+        for Neon(ptr: *const i16) -> (int16x8_t, int16x8_t, int16x8_t) {
+            let v = vld3q_s16(ptr);
+            (v.0, v.1, v.2)
+        }
+    }
+}
+
+impl_op! {
+    fn store_interleaved3<i16> {
+        for Neon(ptr: *mut i16, a: int16x8_t, b: int16x8_t, c: int16x8_t) {
+            vst3q_s16(ptr, int16x8x3_t(a, b, c))
+        }
+    }
+}
+
+impl_op! {
+    fn load_interleaved4<i16> {
+        for Neon(ptr: *const i16) -> (int16x8_t, int16x8_t, int16x8_t, int16x8_t) {
+            let v = vld4q_s16(ptr);
+            (v.0, v.1, v.2, v.3)
+        }
+    }
+}
+
+impl_op! {
+    fn store_interleaved4<i16> {
+        for Neon(ptr: *mut i16, a: int16x8_t, b: int16x8_t, c: int16x8_t, d: int16x8_t) {
+            vst4q_s16(ptr, int16x8x4_t(a, b, c, d))
+        }
+    }
+}
+
+impl_op! {
+    fn load_interleaved2<i32> {
+        for Neon(ptr: *const i32) -> (int32x4_t, int32x4_t) {
+            let v = vld2q_s32(ptr);
+            (v.0, v.1)
+        }
+    }
+}
+
+impl_op! {
+    fn store_interleaved2<i32> {
+        for Neon(ptr: *mut i32, a: int32x4_t, b: int32x4_t) {
+            vst2q_s32(ptr, int32x4x2_t(a, b))
+        }
+    }
+}
+
+impl_op! {
+    fn load_interleaved3<i32> {
+        for Neon(ptr: *const i32) -> (int32x4_t, int32x4_t, int32x4_t) {
+            let v = vld3q_s32(ptr);
+            (v.0, v.1, v.2)
+        }
+    }
+}
+
+impl_op! {
+    fn store_interleaved3<i32> {
+        for Neon(ptr: *mut i32, a: int32x4_t, b: int32x4_t, c: int32x4_t) {
+            vst3q_s32(ptr, int32x4x3_t(a, b, c))
+        }
+    }
+}
+
+impl_op! {
+    fn load_interleaved4<i32> {
+        for Neon(ptr: *const i32) -> (int32x4_t, int32x4_t, int32x4_t, int32x4_t) {
+            let v = vld4q_s32(ptr);
+            (v.0, v.1, v.2, v.3)
+        }
+    }
+}
+
+impl_op! {
+    fn store_interleaved4<i32> {
+        for Neon(ptr: *mut i32, a: int32x4_t, b: int32x4_t, c: int32x4_t, d: int32x4_t) {
+            vst4q_s32(ptr, int32x4x4_t(a, b, c, d))
+        }
+    }
+}
+
+impl_op! {
+    fn load_interleaved2<i64> {
+        for Neon(ptr: *const i64) -> (int64x2_t, int64x2_t) {
+            let v = vld2q_s64(ptr);
+            (v.0, v.1)
+        }
+    }
+}
+
+impl_op! {
+    fn store_interleaved2<i64> {
+        for Neon(ptr: *mut i64, a: int64x2_t, b: int64x2_t) {
+            vst2q_s64(ptr, int64x2x2_t(a, b))
+        }
+    }
+}
+
+impl_op! {
+    fn load_interleaved3<i64> {
+        for Neon(ptr: *const i64) -> (int64x2_t, int64x2_t, int64x2_t) {
+            let v = vld3q_s64(ptr);
+            (v.0, v.1, v.2)
+        }
+    }
+}
+
+impl_op! {
+    fn store_interleaved3<i64> {
+        for Neon(ptr: *mut i64, a: int64x2_t, b: int64x2_t, c: int64x2_t) {
+            vst3q_s64(ptr, int64x2x3_t(a, b, c))
+        }
+    }
+}
+
+impl_op! {
+    fn load_interleaved4<i64> {
+        for Neon(ptr: *const i64) -> (int64x2_t, int64x2_t, int64x2_t, int64x2_t) {
+            let v = vld4q_s64(ptr);
+            (v.0, v.1, v.2, v.3)
+        }
+    }
+}
+
+impl_op! {
+    fn store_interleaved4<i64> {
+        for Neon(ptr: *mut i64, a: int64x2_t, b: int64x2_t, c: int64x2_t, d: int64x2_t) {
+            vst4q_s64(ptr, int64x2x4_t(a, b, c, d))
+        }
+    }
+}
+
+impl_op! {
+    fn load_interleaved2<u8> {
+        for Neon(ptr: *const u8) -> (uint8x16_t, uint8x16_t) {
+            let v = vld2q_u8(ptr);
+            (v.0, v.1)
+        }
+    }
+}
+
+impl_op! {
+    fn store_interleaved2<u8> {
+        for Neon(ptr: *mut u8, a: uint8x16_t, b: uint8x16_t) {
+            vst2q_u8(ptr, uint8x16x2_t(a, b))
+        }
+    }
+}
+
+impl_op! {
+    fn load_interleaved3<u8> {
+        for Neon(ptr: *const u8) -> (uint8x16_t, uint8x16_t, uint8x16_t) {
+            let v = vld3q_u8(ptr);
+            (v.0, v.1, v.2)
+        }
+    }
+}
+
+impl_op! {
+    fn store_interleaved3<u8> {
+        for Neon(ptr: *mut u8, a: uint8x16_t, b: uint8x16_t, c: uint8x16_t) {
+            vst3q_u8(ptr, uint8x16x3_t(a, b, c))
+        }
+    }
+}
+
+impl_op! {
+    fn load_interleaved4<u8> {
+        for Neon(ptr: *const u8) -> (uint8x16_t, uint8x16_t, uint8x16_t, uint8x16_t) {
+            let v = vld4q_u8(ptr);
+            (v.0, v.1, v.2, v.3)
+        }
+    }
+}
+
+impl_op! {
+    fn store_interleaved4<u8> {
+        for Neon(ptr: *mut u8, a: uint8x16_t, b: uint8x16_t, c: uint8x16_t, d: uint8x16_t) {
+            vst4q_u8(ptr, uint8x16x4_t(a, b, c, d))
+        }
+    }
+}
+
+impl_op! {
+    fn load_interleaved2<u16> {
+        for Neon(ptr: *const u16) -> (uint16x8_t, uint16x8_t) {
+            let v = vld2q_u16(ptr);
+            (v.0, v.1)
+        }
+    }
+}
+
+impl_op! {
+    fn store_interleaved2<u16> {
+        for Neon(ptr: *mut u16, a: uint16x8_t, b: uint16x8_t) {
+            vst2q_u16(ptr, uint16x8x2_t(a, b))
+        }
+    }
+}
+
+impl_op! {
+    fn load_interleaved3<u16> {
+        for Neon(ptr: *const u16) -> (uint16x8_t, uint16x8_t, uint16x8_t) {
+            let v = vld3q_u16(ptr);
+            (v.0, v.1, v.2)
+        }
+    }
+}
+
+impl_op! {
+    fn store_interleaved3<u16> {
+        for Neon(ptr: *mut u16, a: uint16x8_t, b: uint16x8_t, c: uint16x8_t) {
+            vst3q_u16(ptr, uint16x8x3_t(a, b, c))
+        }
+    }
+}
+
+impl_op! {
+    fn load_interleaved4<u16> {
+        for Neon(ptr: *const u16) -> (uint16x8_t, uint16x8_t, uint16x8_t, uint16x8_t) {
+            let v = vld4q_u16(ptr);
+            (v.0, v.1, v.2, v.3)
+        }
+    }
+}
+
+impl_op! {
+    fn store_interleaved4<u16> {
+        for Neon(ptr: *mut u16, a: uint16x8_t, b: uint16x8_t, c: uint16x8_t, d: uint16x8_t) {
+            vst4q_u16(ptr, uint16x8x4_t(a, b, c, d))
+        }
+    }
+}
+
+impl_op! {
+    fn load_interleaved2<u32> {
+        for Neon(ptr: *const u32) -> (uint32x4_t, uint32x4_t) {
+            let v = vld2q_u32(ptr);
+            (v.0, v.1)
+        }
+    }
+}
+
+impl_op! {
+    fn store_interleaved2<u32> {
+        for Neon(ptr: *mut u32, a: uint32x4_t, b: uint32x4_t) {
+            vst2q_u32(ptr, uint32x4x2_t(a, b))
+        }
+    }
+}
+
+impl_op! {
+    fn load_interleaved3<u32> {
+        for Neon(ptr: *const u32) -> (uint32x4_t, uint32x4_t, uint32x4_t) {
+            let v = vld3q_u32(ptr);
+            (v.0, v.1, v.2)
+        }
+    }
+}
+
+impl_op! {
+    fn store_interleaved3<u32> {
+        for Neon(ptr: *mut u32, a: uint32x4_t, b: uint32x4_t, c: uint32x4_t) {
+            vst3q_u32(ptr, uint32x4x3_t(a, b, c))
+        }
+    }
+}
+
+impl_op! {
+    fn load_interleaved4<u32> {
+        for Neon(ptr: *const u32) -> (uint32x4_t, uint32x4_t, uint32x4_t, uint32x4_t) {
+            let v = vld4q_u32(ptr);
+            (v.0, v.1, v.2, v.3)
+        }
+    }
+}
+
+impl_op! {
+    fn store_interleaved4<u32> {
+        for Neon(ptr: *mut u32, a: uint32x4_t, b: uint32x4_t, c: uint32x4_t, d: uint32x4_t) {
+            vst4q_u32(ptr, uint32x4x4_t(a, b, c, d))
+        }
+    }
+}
+
+impl_op! {
+    fn load_interleaved2<u64> {
+        for Neon(ptr: *const u64) -> (uint64x2_t, uint64x2_t) {
+            let v = vld2q_u64(ptr);
+            (v.0, v.1)
+        }
+    }
+}
+
+impl_op! {
+    fn store_interleaved2<u64> {
+        for Neon(ptr: *mut u64, a: uint64x2_t, b: uint64x2_t) {
+            vst2q_u64(ptr, uint64x2x2_t(a, b))
+        }
+    }
+}
+
+impl_op! {
+    fn load_interleaved3<u64> {
+        for Neon(ptr: *const u64) -> (uint64x2_t, uint64x2_t, uint64x2_t) {
+            let v = vld3q_u64(ptr);
+            (v.0, v.1, v.2)
+        }
+    }
+}
+
+impl_op! {
+    fn store_interleaved3<u64> {
+        for Neon(ptr: *mut u64, a: uint64x2_t, b: uint64x2_t, c: uint64x2_t) {
+            vst3q_u64(ptr, uint64x2x3_t(a, b, c))
+        }
+    }
+}
+
+impl_op! {
+    fn load_interleaved4<u64> {
+        for Neon(ptr: *const u64) -> (uint64x2_t, uint64x2_t, uint64x2_t, uint64x2_t) {
+            let v = vld4q_u64(ptr);
+            (v.0, v.1, v.2, v.3)
+        }
+    }
+}
+
+impl_op! {
+    fn store_interleaved4<u64> {
+        for Neon(ptr: *mut u64, a: uint64x2_t, b: uint64x2_t, c: uint64x2_t, d: uint64x2_t) {
+            vst4q_u64(ptr, uint64x2x4_t(a, b, c, d))
+        }
+    }
+}
+
+impl_op! {
+    fn load_interleaved2<f32> {
+        for Neon(ptr: *const f32) -> (float32x4_t, float32x4_t) {
+            let v = vld2q_f32(ptr);
+            (v.0, v.1)
+        }
+    }
+}
+
+impl_op! {
+    fn store_interleaved2<f32> {
+        for Neon(ptr: *mut f32, a: float32x4_t, b: float32x4_t) {
+            vst2q_f32(ptr, float32x4x2_t(a, b))
+        }
+    }
+}
+
+impl_op! {
+    fn load_interleaved3<f32> {
+        for Neon(ptr: *const f32) -> (float32x4_t, float32x4_t, float32x4_t) {
+            let v = vld3q_f32(ptr);
+            (v.0, v.1, v.2)
+        }
+    }
+}
+
+impl_op! {
+    fn store_interleaved3<f32> {
+        for Neon(ptr: *mut f32, a: float32x4_t, b: float32x4_t, c: float32x4_t) {
+            vst3q_f32(ptr, float32x4x3_t(a, b, c))
+        }
+    }
+}
+
+impl_op! {
+    fn load_interleaved4<f32> {
+        for Neon(ptr: *const f32) -> (float32x4_t, float32x4_t, float32x4_t, float32x4_t) {
+            let v = vld4q_f32(ptr);
+            (v.0, v.1, v.2, v.3)
+        }
+    }
+}
+
+impl_op! {
+    fn store_interleaved4<f32> {
+        for Neon(ptr: *mut f32, a: float32x4_t, b: float32x4_t, c: float32x4_t, d: float32x4_t) {
+            vst4q_f32(ptr, float32x4x4_t(a, b, c, d))
+        }
+    }
+}
+
+impl_op! {
+    fn load_interleaved2<f64> {
+        for Neon(ptr: *const f64) -> (float64x2_t, float64x2_t) {
+            let v = vld2q_f64(ptr);
+            (v.0, v.1)
+        }
+    }
+}
+
+impl_op! {
+    fn store_interleaved2<f64> {
+        for Neon(ptr: *mut f64, a: float64x2_t, b: float64x2_t) {
+            vst2q_f64(ptr, float64x2x2_t(a, b))
+        }
+    }
+}
+
+impl_op! {
+    fn load_interleaved3<f64> {
+        for Neon(ptr: *const f64) -> (float64x2_t, float64x2_t, float64x2_t) {
+            let v = vld3q_f64(ptr);
+            (v.0, v.1, v.2)
+        }
+    }
+}
+
+impl_op! {
+    fn store_interleaved3<f64> {
+        for Neon(ptr: *mut f64, a: float64x2_t, b: float64x2_t, c: float64x2_t) {
+            vst3q_f64(ptr, float64x2x3_t(a, b, c))
+        }
+    }
+}
+
+impl_op! {
+    fn load_interleaved4<f64> {
+        for Neon(ptr: *const f64) -> (float64x2_t, float64x2_t, float64x2_t, float64x2_t) {
+            let v = vld4q_f64(ptr);
+            (v.0, v.1, v.2, v.3)
+        }
+    }
+}
+
+impl_op! {
+    fn store_interleaved4<f64> {
+        for Neon(ptr: *mut f64, a: float64x2_t, b: float64x2_t, c: float64x2_t, d: float64x2_t) {
+            vst4q_f64(ptr, float64x2x4_t(a, b, c, d))
+        }
+    }
+}
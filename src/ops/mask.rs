@@ -0,0 +1,229 @@
+use super::*;
+
+// `bitmask` packs the highest bit of every lane into a `u64`, lane 0 in bit 0. AVX-512 keeps
+// masks as bits, blends with them directly through `mask_blend` and expands them back into lane
+// vectors with `mask_to_vector`.
+
+impl_op! {
+    fn bitmask<i8> {
+        for Avx512(a: __m512i) -> u64 {
+            _mm512_movepi8_mask(a)
+        }
+        for Avx2(a: __m256i) -> u64 {
+            _mm256_movemask_epi8(a) as u32 as u64
+        }
+        for Sse41(a: __m128i) -> u64 {
+            _mm_movemask_epi8(a) as u64
+        }
+        for Sse2(a: __m128i) -> u64 {
+            _mm_movemask_epi8(a) as u64
+        }
+        for Scalar(a: i8) -> u64 {
+            (a < 0) as u64
+        }
+        for Neon(a: int8x16_t) -> u64 {
+            Ops::<Neon, i8>::get_mask(a) as u64
+        }
+        for Wasm(a: v128) -> u64 {
+            i8x16_bitmask(a) as u64
+        }
+    }
+}
+
+impl_op! {
+    fn bitmask<i16> {
+        for Avx512(a: __m512i) -> u64 {
+            _mm512_movepi16_mask(a) as u64
+        }
+        for Avx2(a: __m256i) -> u64 {
+            // Saturating packs keep the sign, but interleave the two 128 bit halves.
+            let packed = _mm256_packs_epi16(a, _mm256_setzero_si256());
+            let packed = _mm256_permute4x64_epi64::<0xD8>(packed);
+            _mm256_movemask_epi8(packed) as u32 as u64
+        }
+        for Sse41(a: __m128i) -> u64 {
+            _mm_movemask_epi8(_mm_packs_epi16(a, _mm_setzero_si128())) as u64
+        }
+        for Sse2(a: __m128i) -> u64 {
+            _mm_movemask_epi8(_mm_packs_epi16(a, _mm_setzero_si128())) as u64
+        }
+        for Scalar(a: i16) -> u64 {
+            (a < 0) as u64
+        }
+        for Neon(a: int16x8_t) -> u64 {
+            let weights: [u16; 8] = [1, 2, 4, 8, 16, 32, 64, 128];
+            let signs = vshrq_n_u16::<15>(vreinterpretq_u16_s16(a));
+            vaddvq_u16(vmulq_u16(signs, vld1q_u16(weights.as_ptr()))) as u64
+        }
+        for Wasm(a: v128) -> u64 {
+            i16x8_bitmask(a) as u64
+        }
+    }
+}
+
+impl_op! {
+    fn bitmask<i32> {
+        for Avx512(a: __m512i) -> u64 {
+            _mm512_movepi32_mask(a) as u64
+        }
+        for Avx2(a: __m256i) -> u64 {
+            _mm256_movemask_ps(_mm256_castsi256_ps(a)) as u64
+        }
+        for Sse41(a: __m128i) -> u64 {
+            _mm_movemask_ps(_mm_castsi128_ps(a)) as u64
+        }
+        for Sse2(a: __m128i) -> u64 {
+            _mm_movemask_ps(_mm_castsi128_ps(a)) as u64
+        }
+        for Scalar(a: i32) -> u64 {
+            (a < 0) as u64
+        }
+        for Neon(a: int32x4_t) -> u64 {
+            let weights: [u32; 4] = [1, 2, 4, 8];
+            let signs = vshrq_n_u32::<31>(vreinterpretq_u32_s32(a));
+            vaddvq_u32(vmulq_u32(signs, vld1q_u32(weights.as_ptr()))) as u64
+        }
+        for Wasm(a: v128) -> u64 {
+            i32x4_bitmask(a) as u64
+        }
+    }
+}
+
+impl_op! {
+    fn bitmask<i64> {
+        for Avx512(a: __m512i) -> u64 {
+            _mm512_movepi64_mask(a) as u64
+        }
+        for Avx2(a: __m256i) -> u64 {
+            _mm256_movemask_pd(_mm256_castsi256_pd(a)) as u64
+        }
+        for Sse41(a: __m128i) -> u64 {
+            _mm_movemask_pd(_mm_castsi128_pd(a)) as u64
+        }
+        for Sse2(a: __m128i) -> u64 {
+            _mm_movemask_pd(_mm_castsi128_pd(a)) as u64
+        }
+        for Scalar(a: i64) -> u64 {
+            (a < 0) as u64
+        }
+        for Neon(a: int64x2_t) -> u64 {
+            let signs = vshrq_n_u64::<63>(vreinterpretq_u64_s64(a));
+            vgetq_lane_u64::<0>(signs) | vgetq_lane_u64::<1>(signs) << 1
+        }
+        for Wasm(a: v128) -> u64 {
+            i64x2_bitmask(a) as u64
+        }
+    }
+}
+
+impl_op! {
+    fn mask_to_vector<i8> {
+        for Avx512(k: u64) -> __m512i {
+            _mm512_movm_epi8(k)
+        }
+    }
+}
+
+impl_op! {
+    fn mask_to_vector<i16> {
+        for Avx512(k: u64) -> __m512i {
+            _mm512_movm_epi16(k as __mmask32)
+        }
+    }
+}
+
+impl_op! {
+    fn mask_to_vector<i32> {
+        for Avx512(k: u64) -> __m512i {
+            _mm512_movm_epi32(k as __mmask16)
+        }
+    }
+}
+
+impl_op! {
+    fn mask_to_vector<i64> {
+        for Avx512(k: u64) -> __m512i {
+            _mm512_movm_epi64(k as __mmask8)
+        }
+    }
+}
+
+impl_op! {
+    fn mask_blend<i8> {
+        for Avx512(k: u64, a: __m512i, b: __m512i) -> __m512i {
+            _mm512_mask_blend_epi8(k, a, b)
+        }
+    }
+}
+
+impl_op! {
+    fn mask_blend<i16> {
+        for Avx512(k: u64, a: __m512i, b: __m512i) -> __m512i {
+            _mm512_mask_blend_epi16(k as __mmask32, a, b)
+        }
+    }
+}
+
+impl_op! {
+    fn mask_blend<i32> {
+        for Avx512(k: u64, a: __m512i, b: __m512i) -> __m512i {
+            _mm512_mask_blend_epi32(k as __mmask16, a, b)
+        }
+    }
+}
+
+impl_op! {
+    fn mask_blend<i64> {
+        for Avx512(k: u64, a: __m512i, b: __m512i) -> __m512i {
+            _mm512_mask_blend_epi64(k as __mmask8, a, b)
+        }
+    }
+}
+
+impl_op! {
+    fn mask_blend<u8> {
+        for Avx512(k: u64, a: __m512i, b: __m512i) -> __m512i {
+            _mm512_mask_blend_epi8(k, a, b)
+        }
+    }
+}
+
+impl_op! {
+    fn mask_blend<u16> {
+        for Avx512(k: u64, a: __m512i, b: __m512i) -> __m512i {
+            _mm512_mask_blend_epi16(k as __mmask32, a, b)
+        }
+    }
+}
+
+impl_op! {
+    fn mask_blend<u32> {
+        for Avx512(k: u64, a: __m512i, b: __m512i) -> __m512i {
+            _mm512_mask_blend_epi32(k as __mmask16, a, b)
+        }
+    }
+}
+
+impl_op! {
+    fn mask_blend<u64> {
+        for Avx512(k: u64, a: __m512i, b: __m512i) -> __m512i {
+            _mm512_mask_blend_epi64(k as __mmask8, a, b)
+        }
+    }
+}
+
+impl_op! {
+    fn mask_blend<f32> {
+        for Avx512(k: u64, a: __m512, b: __m512) -> __m512 {
+            _mm512_mask_blend_ps(k as __mmask16, a, b)
+        }
+    }
+}
+
+impl_op! {
+    fn mask_blend<f64> {
+        for Avx512(k: u64, a: __m512d, b: __m512d) -> __m512d {
+            _mm512_mask_blend_pd(k as __mmask8, a, b)
+        }
+    }
+}
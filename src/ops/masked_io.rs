@@ -0,0 +1,245 @@
+use super::*;
+
+// Masked loads and stores only touch lanes whose mask has the highest bit set. Inactive lanes
+// load as zero and are never read or written, so a masked access may run past the end of an
+// allocation as long as the lanes that do so are inactive.
+
+/// Lane-by-lane fallback for engines without masked memory instructions.
+#[inline(always)]
+unsafe fn emulated_masked_load<V: Copy, T: Copy + Default + PartialOrd, const N: usize>(
+    ptr: *const T,
+    mask: V,
+) -> V {
+    let mask = core::mem::transmute_copy::<V, [T; N]>(&mask);
+    let mut result = [T::default(); N];
+    for (i, (slot, &lane)) in result.iter_mut().zip(mask.iter()).enumerate() {
+        if lane < T::default() {
+            *slot = *ptr.add(i);
+        }
+    }
+    core::mem::transmute_copy::<[T; N], V>(&result)
+}
+
+/// Lane-by-lane fallback for engines without masked memory instructions.
+#[inline(always)]
+unsafe fn emulated_masked_store<V: Copy, T: Copy + Default + PartialOrd, const N: usize>(
+    ptr: *mut T,
+    values: V,
+    mask: V,
+) {
+    let values = core::mem::transmute_copy::<V, [T; N]>(&values);
+    let mask = core::mem::transmute_copy::<V, [T; N]>(&mask);
+    for (i, (&value, &lane)) in values.iter().zip(mask.iter()).enumerate() {
+        if lane < T::default() {
+            *ptr.add(i) = value;
+        }
+    }
+}
+
+impl_op! {
+    fn masked_load<i8> {
+        for Avx512(ptr: *const i8, mask: u64) -> __m512i {
+            _mm512_maskz_loadu_epi8(mask, ptr)
+        }
+        for Avx2(ptr: *const i8, mask: __m256i) -> __m256i {
+            emulated_masked_load::<_, i8, 32>(ptr, mask)
+        }
+        for Sse41(ptr: *const i8, mask: __m128i) -> __m128i {
+            emulated_masked_load::<_, i8, 16>(ptr, mask)
+        }
+        for Sse2(ptr: *const i8, mask: __m128i) -> __m128i {
+            emulated_masked_load::<_, i8, 16>(ptr, mask)
+        }
+        for Scalar(ptr: *const i8, mask: i8) -> i8 {
+            emulated_masked_load::<_, i8, 1>(ptr, mask)
+        }
+        for Neon(ptr: *const i8, mask: int8x16_t) -> int8x16_t {
+            emulated_masked_load::<_, i8, 16>(ptr, mask)
+        }
+        for Wasm(ptr: *const i8, mask: v128) -> v128 {
+            emulated_masked_load::<_, i8, 16>(ptr, mask)
+        }
+    }
+}
+
+impl_op! {
+    fn masked_store<i8> {
+        for Avx512(ptr: *mut i8, a: __m512i, mask: u64) {
+            _mm512_mask_storeu_epi8(ptr, mask, a)
+        }
+        for Avx2(ptr: *mut i8, a: __m256i, mask: __m256i) {
+            emulated_masked_store::<_, i8, 32>(ptr, a, mask)
+        }
+        for Sse41(ptr: *mut i8, a: __m128i, mask: __m128i) {
+            emulated_masked_store::<_, i8, 16>(ptr, a, mask)
+        }
+        for Sse2(ptr: *mut i8, a: __m128i, mask: __m128i) {
+            emulated_masked_store::<_, i8, 16>(ptr, a, mask)
+        }
+        for Scalar(ptr: *mut i8, a: i8, mask: i8) {
+            emulated_masked_store::<_, i8, 1>(ptr, a, mask)
+        }
+        for Neon(ptr: *mut i8, a: int8x16_t, mask: int8x16_t) {
+            emulated_masked_store::<_, i8, 16>(ptr, a, mask)
+        }
+        for Wasm(ptr: *mut i8, a: v128, mask: v128) {
+            emulated_masked_store::<_, i8, 16>(ptr, a, mask)
+        }
+    }
+}
+
+impl_op! {
+    fn masked_load<i16> {
+        for Avx512(ptr: *const i16, mask: u64) -> __m512i {
+            _mm512_maskz_loadu_epi16(mask as __mmask32, ptr)
+        }
+        for Avx2(ptr: *const i16, mask: __m256i) -> __m256i {
+            emulated_masked_load::<_, i16, 16>(ptr, mask)
+        }
+        for Sse41(ptr: *const i16, mask: __m128i) -> __m128i {
+            emulated_masked_load::<_, i16, 8>(ptr, mask)
+        }
+        for Sse2(ptr: *const i16, mask: __m128i) -> __m128i {
+            emulated_masked_load::<_, i16, 8>(ptr, mask)
+        }
+        for Scalar(ptr: *const i16, mask: i16) -> i16 {
+            emulated_masked_load::<_, i16, 1>(ptr, mask)
+        }
+        for Neon(ptr: *const i16, mask: int16x8_t) -> int16x8_t {
+            emulated_masked_load::<_, i16, 8>(ptr, mask)
+        }
+        for Wasm(ptr: *const i16, mask: v128) -> v128 {
+            emulated_masked_load::<_, i16, 8>(ptr, mask)
+        }
+    }
+}
+
+impl_op! {
+    fn masked_store<i16> {
+        for Avx512(ptr: *mut i16, a: __m512i, mask: u64) {
+            _mm512_mask_storeu_epi16(ptr, mask as __mmask32, a)
+        }
+        for Avx2(ptr: *mut i16, a: __m256i, mask: __m256i) {
+            emulated_masked_store::<_, i16, 16>(ptr, a, mask)
+        }
+        for Sse41(ptr: *mut i16, a: __m128i, mask: __m128i) {
+            emulated_masked_store::<_, i16, 8>(ptr, a, mask)
+        }
+        for Sse2(ptr: *mut i16, a: __m128i, mask: __m128i) {
+            emulated_masked_store::<_, i16, 8>(ptr, a, mask)
+        }
+        for Scalar(ptr: *mut i16, a: i16, mask: i16) {
+            emulated_masked_store::<_, i16, 1>(ptr, a, mask)
+        }
+        for Neon(ptr: *mut i16, a: int16x8_t, mask: int16x8_t) {
+            emulated_masked_store::<_, i16, 8>(ptr, a, mask)
+        }
+        for Wasm(ptr: *mut i16, a: v128, mask: v128) {
+            emulated_masked_store::<_, i16, 8>(ptr, a, mask)
+        }
+    }
+}
+
+impl_op! {
+    fn masked_load<i32> {
+        for Avx512(ptr: *const i32, mask: u64) -> __m512i {
+            _mm512_maskz_loadu_epi32(mask as __mmask16, ptr)
+        }
+        for Avx2(ptr: *const i32, mask: __m256i) -> __m256i {
+            _mm256_maskload_epi32(ptr, mask)
+        }
+        for Sse41(ptr: *const i32, mask: __m128i) -> __m128i {
+            emulated_masked_load::<_, i32, 4>(ptr, mask)
+        }
+        for Sse2(ptr: *const i32, mask: __m128i) -> __m128i {
+            emulated_masked_load::<_, i32, 4>(ptr, mask)
+        }
+        for Scalar(ptr: *const i32, mask: i32) -> i32 {
+            emulated_masked_load::<_, i32, 1>(ptr, mask)
+        }
+        for Neon(ptr: *const i32, mask: int32x4_t) -> int32x4_t {
+            emulated_masked_load::<_, i32, 4>(ptr, mask)
+        }
+        for Wasm(ptr: *const i32, mask: v128) -> v128 {
+            emulated_masked_load::<_, i32, 4>(ptr, mask)
+        }
+    }
+}
+
+impl_op! {
+    fn masked_store<i32> {
+        for Avx512(ptr: *mut i32, a: __m512i, mask: u64) {
+            _mm512_mask_storeu_epi32(ptr, mask as __mmask16, a)
+        }
+        for Avx2(ptr: *mut i32, a: __m256i, mask: __m256i) {
+            _mm256_maskstore_epi32(ptr, mask, a)
+        }
+        for Sse41(ptr: *mut i32, a: __m128i, mask: __m128i) {
+            emulated_masked_store::<_, i32, 4>(ptr, a, mask)
+        }
+        for Sse2(ptr: *mut i32, a: __m128i, mask: __m128i) {
+            emulated_masked_store::<_, i32, 4>(ptr, a, mask)
+        }
+        for Scalar(ptr: *mut i32, a: i32, mask: i32) {
+            emulated_masked_store::<_, i32, 1>(ptr, a, mask)
+        }
+        for Neon(ptr: *mut i32, a: int32x4_t, mask: int32x4_t) {
+            emulated_masked_store::<_, i32, 4>(ptr, a, mask)
+        }
+        for Wasm(ptr: *mut i32, a: v128, mask: v128) {
+            emulated_masked_store::<_, i32, 4>(ptr, a, mask)
+        }
+    }
+}
+
+impl_op! {
+    fn masked_load<i64> {
+        for Avx512(ptr: *const i64, mask: u64) -> __m512i {
+            _mm512_maskz_loadu_epi64(mask as __mmask8, ptr)
+        }
+        for Avx2(ptr: *const i64, mask: __m256i) -> __m256i {
+            _mm256_maskload_epi64(ptr, mask)
+        }
+        for Sse41(ptr: *const i64, mask: __m128i) -> __m128i {
+            emulated_masked_load::<_, i64, 2>(ptr, mask)
+        }
+        for Sse2(ptr: *const i64, mask: __m128i) -> __m128i {
+            emulated_masked_load::<_, i64, 2>(ptr, mask)
+        }
+        for Scalar(ptr: *const i64, mask: i64) -> i64 {
+            emulated_masked_load::<_, i64, 1>(ptr, mask)
+        }
+        for Neon(ptr: *const i64, mask: int64x2_t) -> int64x2_t {
+            emulated_masked_load::<_, i64, 2>(ptr, mask)
+        }
+        for Wasm(ptr: *const i64, mask: v128) -> v128 {
+            emulated_masked_load::<_, i64, 2>(ptr, mask)
+        }
+    }
+}
+
+impl_op! {
+    fn masked_store<i64> {
+        for Avx512(ptr: *mut i64, a: __m512i, mask: u64) {
+            _mm512_mask_storeu_epi64(ptr, mask as __mmask8, a)
+        }
+        for Avx2(ptr: *mut i64, a: __m256i, mask: __m256i) {
+            _mm256_maskstore_epi64(ptr, mask, a)
+        }
+        for Sse41(ptr: *mut i64, a: __m128i, mask: __m128i) {
+            emulated_masked_store::<_, i64, 2>(ptr, a, mask)
+        }
+        for Sse2(ptr: *mut i64, a: __m128i, mask: __m128i) {
+            emulated_masked_store::<_, i64, 2>(ptr, a, mask)
+        }
+        for Scalar(ptr: *mut i64, a: i64, mask: i64) {
+            emulated_masked_store::<_, i64, 1>(ptr, a, mask)
+        }
+        for Neon(ptr: *mut i64, a: int64x2_t, mask: int64x2_t) {
+            emulated_masked_store::<_, i64, 2>(ptr, a, mask)
+        }
+        for Wasm(ptr: *mut i64, a: v128, mask: v128) {
+            emulated_masked_store::<_, i64, 2>(ptr, a, mask)
+        }
+    }
+}
@@ -10,6 +10,8 @@ use crate::engines::wasm32::Wasm;
 #[cfg(any(target_arch = "x86_64", target_arch = "x86"))]
 use crate::engines::{avx2::Avx2, sse2::Sse2, sse41::Sse41};
 
+#[cfg(any(target_arch = "x86_64", target_arch = "x86"))]
+use crate::dispatch::{has_feature, CpuFeature};
 use crate::libm_ext::FloatExt;
 use core::marker::PhantomData;
 
@@ -30,6 +32,14 @@ mod i32;
 
 mod i64;
 
+mod u8;
+
+mod u16;
+
+mod u32;
+
+mod u64;
+
 mod f32;
 
 mod f64;
@@ -38,6 +48,61 @@ mod bit;
 
 mod casts;
 
+mod shuffle;
+
+mod gather;
+
+mod masked_io;
+
+mod interleave;
+
+mod compress;
+
+mod mask;
+
+#[cfg(any(target_arch = "x86_64", target_arch = "x86"))]
+mod avx;
+
+/// Applies `f` lane by lane to two vectors viewed as `[T; N]`, for operations an engine has no
+/// instruction for (such as per-lane shifts before AVX2).
+#[inline(always)]
+unsafe fn map_lanes<V: Copy, T: Copy, const N: usize>(a: V, b: V, f: impl Fn(T, T) -> T) -> V {
+    let mut lanes = core::mem::transmute_copy::<V, [T; N]>(&a);
+    let other = core::mem::transmute_copy::<V, [T; N]>(&b);
+    for (lane, other) in lanes.iter_mut().zip(other) {
+        *lane = f(*lane, other);
+    }
+    core::mem::transmute_copy::<[T; N], V>(&lanes)
+}
+
+/// Like [`map_lanes`] for three vectors.
+#[inline(always)]
+unsafe fn map_lanes3<V: Copy, T: Copy, const N: usize>(
+    a: V,
+    b: V,
+    c: V,
+    f: impl Fn(T, T, T) -> T,
+) -> V {
+    let mut lanes = core::mem::transmute_copy::<V, [T; N]>(&a);
+    let second = core::mem::transmute_copy::<V, [T; N]>(&b);
+    let third = core::mem::transmute_copy::<V, [T; N]>(&c);
+    for ((lane, second), third) in lanes.iter_mut().zip(second).zip(third) {
+        *lane = f(*lane, second, third);
+    }
+    core::mem::transmute_copy::<[T; N], V>(&lanes)
+}
+
+/// `pshufb`/`swizzle` indices that reverse the bytes within each `lane_bytes` wide lane.
+const fn byte_reverse_indices(lane_bytes: usize) -> [i8; 16] {
+    let mut indices = [0; 16];
+    let mut i = 0;
+    while i < 16 {
+        indices[i] = (i - i % lane_bytes + lane_bytes - 1 - i % lane_bytes) as i8;
+        i += 1;
+    }
+    indices
+}
+
 #[allow(non_camel_case_types)]
 pub struct binary;
 
@@ -54,6 +119,11 @@ macro_rules! with_feature_flag {
         #[target_feature(enable = "avx2", enable = "fma")]
         $($r)+
     };
+    (Avx, $($r:tt)+) => {
+        #[cfg(any(target_arch = "x86_64", target_arch = "x86"))]
+        #[target_feature(enable = "avx")]
+        $($r)+
+    };
     (Sse2, $($r:tt)+) => {
         #[cfg(any(target_arch = "x86_64", target_arch = "x86"))]
         #[target_feature(enable = "sse2")]
@@ -89,6 +159,10 @@ macro_rules! with_cfg_flag {
         #[cfg(any(target_arch = "x86_64", target_arch = "x86"))]
         $($r)+
     };
+    (Avx, $($r:tt)+) => {
+        #[cfg(any(target_arch = "x86_64", target_arch = "x86"))]
+        $($r)+
+    };
     (Sse2, $($r:tt)+) => {
         #[cfg(any(target_arch = "x86_64", target_arch = "x86"))]
         $($r)+
@@ -0,0 +1,480 @@
+use super::*;
+
+// Lane permutes take an index vector with the same lane width as the data. Indices are taken
+// modulo the lane count (`permute`) or twice the lane count (`permute2`, where the upper half
+// selects lanes from `b`), so out-of-range indices never fall back to backend-specific zeroing.
+
+impl_op! {
+    fn permute<i8> {
+        for Avx512(a: __m512i, idx: __m512i) -> __m512i {
+            let idx = _mm512_and_si512(idx, _mm512_set1_epi8(63));
+            let from_0 = _mm512_shuffle_epi8(_mm512_shuffle_i64x2(a, a, 0x00), idx);
+            let from_1 = _mm512_shuffle_epi8(_mm512_shuffle_i64x2(a, a, 0x55), idx);
+            let from_2 = _mm512_shuffle_epi8(_mm512_shuffle_i64x2(a, a, 0xAA), idx);
+            let from_3 = _mm512_shuffle_epi8(_mm512_shuffle_i64x2(a, a, 0xFF), idx);
+            let bit_4 = _mm512_test_epi8_mask(idx, _mm512_set1_epi8(16));
+            let bit_5 = _mm512_test_epi8_mask(idx, _mm512_set1_epi8(32));
+            let low = _mm512_mask_blend_epi8(bit_4, from_0, from_1);
+            let high = _mm512_mask_blend_epi8(bit_4, from_2, from_3);
+            _mm512_mask_blend_epi8(bit_5, low, high)
+        }
+        for Avx2(a: __m256i, idx: __m256i) -> __m256i {
+            let idx = _mm256_and_si256(idx, _mm256_set1_epi8(31));
+            let from_low = _mm256_shuffle_epi8(_mm256_permute2x128_si256(a, a, 0x00), idx);
+            let from_high = _mm256_shuffle_epi8(_mm256_permute2x128_si256(a, a, 0x11), idx);
+            // Move bit 4 of every index into the byte's sign bit to select the source half.
+            _mm256_blendv_epi8(from_low, from_high, _mm256_slli_epi16(idx, 3))
+        }
+        for Sse41(a: __m128i, idx: __m128i) -> __m128i {
+            _mm_shuffle_epi8(a, _mm_and_si128(idx, _mm_set1_epi8(15)))
+        }
+        for Sse2(a: __m128i, idx: __m128i) -> __m128i {
+            let values = core::mem::transmute::<__m128i, [i8; 16]>(a);
+            let indices = core::mem::transmute::<__m128i, [i8; 16]>(idx);
+            let mut result = [0i8; 16];
+            for (slot, &index) in result.iter_mut().zip(indices.iter()) {
+                *slot = values[(index & 15) as usize];
+            }
+            core::mem::transmute::<[i8; 16], __m128i>(result)
+        }
+        for Scalar(a: i8, _idx: i8) -> i8 {
+            a
+        }
+        for Neon(a: int8x16_t, idx: int8x16_t) -> int8x16_t {
+            vqtbl1q_s8(a, vandq_u8(vreinterpretq_u8_s8(idx), vdupq_n_u8(15)))
+        }
+        for Wasm(a: v128, idx: v128) -> v128 {
+            i8x16_swizzle(a, v128_and(idx, i8x16_splat(15)))
+        }
+    }
+}
+
+impl_op! {
+    fn permute<i16> {
+        for Avx512(a: __m512i, idx: __m512i) -> __m512i {
+            _mm512_permutexvar_epi16(idx, a)
+        }
+        for Avx2(a: __m256i, idx: __m256i) -> __m256i {
+            let idx = _mm256_and_si256(idx, _mm256_set1_epi16(15));
+            let bytes = _mm256_add_epi16(
+                _mm256_mullo_epi16(idx, _mm256_set1_epi16(0x0202)),
+                _mm256_set1_epi16(0x0100),
+            );
+            Ops::<Avx2, i8>::permute(a, bytes)
+        }
+        for Sse41(a: __m128i, idx: __m128i) -> __m128i {
+            let idx = _mm_and_si128(idx, _mm_set1_epi16(7));
+            let bytes = _mm_add_epi16(_mm_mullo_epi16(idx, _mm_set1_epi16(0x0202)), _mm_set1_epi16(0x0100));
+            _mm_shuffle_epi8(a, bytes)
+        }
+        for Sse2(a: __m128i, idx: __m128i) -> __m128i {
+            let values = core::mem::transmute::<__m128i, [i16; 8]>(a);
+            let indices = core::mem::transmute::<__m128i, [i16; 8]>(idx);
+            let mut result = [0i16; 8];
+            for (slot, &index) in result.iter_mut().zip(indices.iter()) {
+                *slot = values[(index & 7) as usize];
+            }
+            core::mem::transmute::<[i16; 8], __m128i>(result)
+        }
+        for Scalar(a: i16, _idx: i16) -> i16 {
+            a
+        }
+        for Neon(a: int16x8_t, idx: int16x8_t) -> int16x8_t {
+            let idx = vandq_s16(idx, vdupq_n_s16(7));
+            let bytes = vmlaq_s16(vdupq_n_s16(0x0100), idx, vdupq_n_s16(0x0202));
+            let result = Ops::<Neon, i8>::permute(vreinterpretq_s8_s16(a), vreinterpretq_s8_s16(bytes));
+            vreinterpretq_s16_s8(result)
+        }
+        for Wasm(a: v128, idx: v128) -> v128 {
+            let idx = v128_and(idx, i16x8_splat(7));
+            let bytes = i16x8_add(i16x8_mul(idx, i16x8_splat(0x0202)), i16x8_splat(0x0100));
+            i8x16_swizzle(a, bytes)
+        }
+    }
+}
+
+impl_op! {
+    fn permute<i32> {
+        for Avx512(a: __m512i, idx: __m512i) -> __m512i {
+            _mm512_permutexvar_epi32(idx, a)
+        }
+        for Avx2(a: __m256i, idx: __m256i) -> __m256i {
+            _mm256_permutevar8x32_epi32(a, idx)
+        }
+        for Sse41(a: __m128i, idx: __m128i) -> __m128i {
+            let idx = _mm_and_si128(idx, _mm_set1_epi32(3));
+            let bytes = _mm_add_epi32(
+                _mm_mullo_epi32(idx, _mm_set1_epi32(0x04040404)),
+                _mm_set1_epi32(0x03020100),
+            );
+            _mm_shuffle_epi8(a, bytes)
+        }
+        for Sse2(a: __m128i, idx: __m128i) -> __m128i {
+            let values = core::mem::transmute::<__m128i, [i32; 4]>(a);
+            let indices = core::mem::transmute::<__m128i, [i32; 4]>(idx);
+            let mut result = [0i32; 4];
+            for (slot, &index) in result.iter_mut().zip(indices.iter()) {
+                *slot = values[(index & 3) as usize];
+            }
+            core::mem::transmute::<[i32; 4], __m128i>(result)
+        }
+        for Scalar(a: i32, _idx: i32) -> i32 {
+            a
+        }
+        for Neon(a: int32x4_t, idx: int32x4_t) -> int32x4_t {
+            let idx = vandq_s32(idx, vdupq_n_s32(3));
+            let bytes = vmlaq_s32(vdupq_n_s32(0x03020100), idx, vdupq_n_s32(0x04040404));
+            let result = Ops::<Neon, i8>::permute(vreinterpretq_s8_s32(a), vreinterpretq_s8_s32(bytes));
+            vreinterpretq_s32_s8(result)
+        }
+        for Wasm(a: v128, idx: v128) -> v128 {
+            let idx = v128_and(idx, i32x4_splat(3));
+            let bytes = i32x4_add(i32x4_mul(idx, i32x4_splat(0x04040404)), i32x4_splat(0x03020100));
+            i8x16_swizzle(a, bytes)
+        }
+    }
+}
+
+impl_op! {
+    fn permute<i64> {
+        for Avx512(a: __m512i, idx: __m512i) -> __m512i {
+            _mm512_permutexvar_epi64(idx, a)
+        }
+        for Avx2(a: __m256i, idx: __m256i) -> __m256i {
+            // Each 64 bit index `i` becomes the pair of 32 bit indices `(2i, 2i + 1)`.
+            let low = _mm256_slli_epi64(_mm256_and_si256(idx, _mm256_set1_epi64x(3)), 1);
+            let high = _mm256_add_epi64(low, _mm256_set1_epi64x(1));
+            let dwords = _mm256_or_si256(low, _mm256_slli_epi64(high, 32));
+            _mm256_permutevar8x32_epi32(a, dwords)
+        }
+        for Sse41(a: __m128i, idx: __m128i) -> __m128i {
+            let offset = _mm_slli_epi64(_mm_and_si128(idx, _mm_set1_epi64x(1)), 3);
+            let offset = _mm_shuffle_epi8(offset, _mm_setr_epi8(0, 0, 0, 0, 0, 0, 0, 0, 8, 8, 8, 8, 8, 8, 8, 8));
+            let bytes = _mm_add_epi8(offset, _mm_set1_epi64x(0x0706050403020100));
+            _mm_shuffle_epi8(a, bytes)
+        }
+        for Sse2(a: __m128i, idx: __m128i) -> __m128i {
+            let values = core::mem::transmute::<__m128i, [i64; 2]>(a);
+            let indices = core::mem::transmute::<__m128i, [i64; 2]>(idx);
+            let result = [values[(indices[0] & 1) as usize], values[(indices[1] & 1) as usize]];
+            core::mem::transmute::<[i64; 2], __m128i>(result)
+        }
+        for Scalar(a: i64, _idx: i64) -> i64 {
+            a
+        }
+        for Neon(a: int64x2_t, idx: int64x2_t) -> int64x2_t {
+            let one = vdupq_n_s64(1);
+            let take_high = vceqq_s64(vandq_s64(idx, one), one);
+            vbslq_s64(take_high, vdupq_laneq_s64(a, 1), vdupq_laneq_s64(a, 0))
+        }
+        for Wasm(a: v128, idx: v128) -> v128 {
+            let one = i64x2_splat(1);
+            let take_high = i64x2_eq(v128_and(idx, one), one);
+            v128_bitselect(i64x2_shuffle::<1, 1>(a, a), i64x2_shuffle::<0, 0>(a, a), take_high)
+        }
+    }
+}
+
+impl_op! {
+    fn permute2<i8> {
+        for Avx512(a: __m512i, b: __m512i, idx: __m512i) -> __m512i {
+            let from_a = Ops::<Avx512, i8>::permute(a, idx);
+            let from_b = Ops::<Avx512, i8>::permute(b, idx);
+            _mm512_mask_blend_epi8(_mm512_test_epi8_mask(idx, _mm512_set1_epi8(64)), from_a, from_b)
+        }
+        for Avx2(a: __m256i, b: __m256i, idx: __m256i) -> __m256i {
+            let from_a = Ops::<Avx2, i8>::permute(a, idx);
+            let from_b = Ops::<Avx2, i8>::permute(b, idx);
+            let source_bit = _mm256_set1_epi8(32);
+            let take_b = _mm256_cmpeq_epi8(_mm256_and_si256(idx, source_bit), source_bit);
+            Ops::<Avx2, i8>::blendv(from_a, from_b, take_b)
+        }
+        for Sse41(a: __m128i, b: __m128i, idx: __m128i) -> __m128i {
+            let from_a = Ops::<Sse41, i8>::permute(a, idx);
+            let from_b = Ops::<Sse41, i8>::permute(b, idx);
+            let source_bit = _mm_set1_epi8(16);
+            let take_b = _mm_cmpeq_epi8(_mm_and_si128(idx, source_bit), source_bit);
+            Ops::<Sse41, i8>::blendv(from_a, from_b, take_b)
+        }
+        for Sse2(a: __m128i, b: __m128i, idx: __m128i) -> __m128i {
+            let from_a = Ops::<Sse2, i8>::permute(a, idx);
+            let from_b = Ops::<Sse2, i8>::permute(b, idx);
+            let source_bit = _mm_set1_epi8(16);
+            let take_b = _mm_cmpeq_epi8(_mm_and_si128(idx, source_bit), source_bit);
+            Ops::<Sse2, i8>::blendv(from_a, from_b, take_b)
+        }
+        for Scalar(a: i8, b: i8, idx: i8) -> i8 {
+            if idx & 1 == 0 {
+                a
+            } else {
+                b
+            }
+        }
+        for Neon(a: int8x16_t, b: int8x16_t, idx: int8x16_t) -> int8x16_t {
+            let from_a = Ops::<Neon, i8>::permute(a, idx);
+            let from_b = Ops::<Neon, i8>::permute(b, idx);
+            let take_b = vtstq_s8(idx, vdupq_n_s8(16));
+            vbslq_s8(take_b, from_b, from_a)
+        }
+        for Wasm(a: v128, b: v128, idx: v128) -> v128 {
+            let from_a = Ops::<Wasm, i8>::permute(a, idx);
+            let from_b = Ops::<Wasm, i8>::permute(b, idx);
+            let source_bit = i8x16_splat(16);
+            let take_b = i8x16_eq(v128_and(idx, source_bit), source_bit);
+            v128_bitselect(from_b, from_a, take_b)
+        }
+    }
+}
+
+impl_op! {
+    fn permute2<i16> {
+        for Avx512(a: __m512i, b: __m512i, idx: __m512i) -> __m512i {
+            _mm512_permutex2var_epi16(a, idx, b)
+        }
+        for Avx2(a: __m256i, b: __m256i, idx: __m256i) -> __m256i {
+            let from_a = Ops::<Avx2, i16>::permute(a, idx);
+            let from_b = Ops::<Avx2, i16>::permute(b, idx);
+            let source_bit = _mm256_set1_epi16(16);
+            let take_b = _mm256_cmpeq_epi16(_mm256_and_si256(idx, source_bit), source_bit);
+            Ops::<Avx2, i16>::blendv(from_a, from_b, take_b)
+        }
+        for Sse41(a: __m128i, b: __m128i, idx: __m128i) -> __m128i {
+            let from_a = Ops::<Sse41, i16>::permute(a, idx);
+            let from_b = Ops::<Sse41, i16>::permute(b, idx);
+            let source_bit = _mm_set1_epi16(8);
+            let take_b = _mm_cmpeq_epi16(_mm_and_si128(idx, source_bit), source_bit);
+            Ops::<Sse41, i16>::blendv(from_a, from_b, take_b)
+        }
+        for Sse2(a: __m128i, b: __m128i, idx: __m128i) -> __m128i {
+            let from_a = Ops::<Sse2, i16>::permute(a, idx);
+            let from_b = Ops::<Sse2, i16>::permute(b, idx);
+            let source_bit = _mm_set1_epi16(8);
+            let take_b = _mm_cmpeq_epi16(_mm_and_si128(idx, source_bit), source_bit);
+            Ops::<Sse2, i16>::blendv(from_a, from_b, take_b)
+        }
+        for Scalar(a: i16, b: i16, idx: i16) -> i16 {
+            if idx & 1 == 0 {
+                a
+            } else {
+                b
+            }
+        }
+        for Neon(a: int16x8_t, b: int16x8_t, idx: int16x8_t) -> int16x8_t {
+            let from_a = Ops::<Neon, i16>::permute(a, idx);
+            let from_b = Ops::<Neon, i16>::permute(b, idx);
+            let take_b = vtstq_s16(idx, vdupq_n_s16(8));
+            vbslq_s16(take_b, from_b, from_a)
+        }
+        for Wasm(a: v128, b: v128, idx: v128) -> v128 {
+            let from_a = Ops::<Wasm, i16>::permute(a, idx);
+            let from_b = Ops::<Wasm, i16>::permute(b, idx);
+            let source_bit = i16x8_splat(8);
+            let take_b = i16x8_eq(v128_and(idx, source_bit), source_bit);
+            v128_bitselect(from_b, from_a, take_b)
+        }
+    }
+}
+
+impl_op! {
+    fn permute2<i32> {
+        for Avx512(a: __m512i, b: __m512i, idx: __m512i) -> __m512i {
+            _mm512_permutex2var_epi32(a, idx, b)
+        }
+        for Avx2(a: __m256i, b: __m256i, idx: __m256i) -> __m256i {
+            let from_a = Ops::<Avx2, i32>::permute(a, idx);
+            let from_b = Ops::<Avx2, i32>::permute(b, idx);
+            let source_bit = _mm256_set1_epi32(8);
+            let take_b = _mm256_cmpeq_epi32(_mm256_and_si256(idx, source_bit), source_bit);
+            Ops::<Avx2, i32>::blendv(from_a, from_b, take_b)
+        }
+        for Sse41(a: __m128i, b: __m128i, idx: __m128i) -> __m128i {
+            let from_a = Ops::<Sse41, i32>::permute(a, idx);
+            let from_b = Ops::<Sse41, i32>::permute(b, idx);
+            let source_bit = _mm_set1_epi32(4);
+            let take_b = _mm_cmpeq_epi32(_mm_and_si128(idx, source_bit), source_bit);
+            Ops::<Sse41, i32>::blendv(from_a, from_b, take_b)
+        }
+        for Sse2(a: __m128i, b: __m128i, idx: __m128i) -> __m128i {
+            let from_a = Ops::<Sse2, i32>::permute(a, idx);
+            let from_b = Ops::<Sse2, i32>::permute(b, idx);
+            let source_bit = _mm_set1_epi32(4);
+            let take_b = _mm_cmpeq_epi32(_mm_and_si128(idx, source_bit), source_bit);
+            Ops::<Sse2, i32>::blendv(from_a, from_b, take_b)
+        }
+        for Scalar(a: i32, b: i32, idx: i32) -> i32 {
+            if idx & 1 == 0 {
+                a
+            } else {
+                b
+            }
+        }
+        for Neon(a: int32x4_t, b: int32x4_t, idx: int32x4_t) -> int32x4_t {
+            let from_a = Ops::<Neon, i32>::permute(a, idx);
+            let from_b = Ops::<Neon, i32>::permute(b, idx);
+            let take_b = vtstq_s32(idx, vdupq_n_s32(4));
+            vbslq_s32(take_b, from_b, from_a)
+        }
+        for Wasm(a: v128, b: v128, idx: v128) -> v128 {
+            let from_a = Ops::<Wasm, i32>::permute(a, idx);
+            let from_b = Ops::<Wasm, i32>::permute(b, idx);
+            let source_bit = i32x4_splat(4);
+            let take_b = i32x4_eq(v128_and(idx, source_bit), source_bit);
+            v128_bitselect(from_b, from_a, take_b)
+        }
+    }
+}
+
+impl_op! {
+    fn permute2<i64> {
+        for Avx512(a: __m512i, b: __m512i, idx: __m512i) -> __m512i {
+            _mm512_permutex2var_epi64(a, idx, b)
+        }
+        for Avx2(a: __m256i, b: __m256i, idx: __m256i) -> __m256i {
+            let from_a = Ops::<Avx2, i64>::permute(a, idx);
+            let from_b = Ops::<Avx2, i64>::permute(b, idx);
+            let source_bit = _mm256_set1_epi64x(4);
+            let take_b = _mm256_cmpeq_epi64(_mm256_and_si256(idx, source_bit), source_bit);
+            Ops::<Avx2, i64>::blendv(from_a, from_b, take_b)
+        }
+        for Sse41(a: __m128i, b: __m128i, idx: __m128i) -> __m128i {
+            let from_a = Ops::<Sse41, i64>::permute(a, idx);
+            let from_b = Ops::<Sse41, i64>::permute(b, idx);
+            let source_bit = _mm_set1_epi64x(2);
+            let take_b = _mm_cmpeq_epi64(_mm_and_si128(idx, source_bit), source_bit);
+            Ops::<Sse41, i64>::blendv(from_a, from_b, take_b)
+        }
+        for Sse2(a: __m128i, b: __m128i, idx: __m128i) -> __m128i {
+            let values = core::mem::transmute::<[__m128i; 2], [i64; 4]>([a, b]);
+            let indices = core::mem::transmute::<__m128i, [i64; 2]>(idx);
+            let result = [values[(indices[0] & 3) as usize], values[(indices[1] & 3) as usize]];
+            core::mem::transmute::<[i64; 2], __m128i>(result)
+        }
+        for Scalar(a: i64, b: i64, idx: i64) -> i64 {
+            if idx & 1 == 0 {
+                a
+            } else {
+                b
+            }
+        }
+        for Neon(a: int64x2_t, b: int64x2_t, idx: int64x2_t) -> int64x2_t {
+            let from_a = Ops::<Neon, i64>::permute(a, idx);
+            let from_b = Ops::<Neon, i64>::permute(b, idx);
+            let take_b = vtstq_s64(idx, vdupq_n_s64(2));
+            vbslq_s64(take_b, from_b, from_a)
+        }
+        for Wasm(a: v128, b: v128, idx: v128) -> v128 {
+            let from_a = Ops::<Wasm, i64>::permute(a, idx);
+            let from_b = Ops::<Wasm, i64>::permute(b, idx);
+            let source_bit = i64x2_splat(2);
+            let take_b = i64x2_eq(v128_and(idx, source_bit), source_bit);
+            v128_bitselect(from_b, from_a, take_b)
+        }
+    }
+}
+
+/// The low `bits` of every `2 * bits` bit block of a 64 bit lane.
+#[cfg(any(target_arch = "x86_64", target_arch = "x86"))]
+#[inline(always)]
+const fn low_blocks(bits: i32) -> i64 {
+    match bits {
+        8 => 0x00FF_00FF_00FF_00FF,
+        16 => 0x0000_FFFF_0000_FFFF,
+        _ => 0x0000_0000_FFFF_FFFF,
+    }
+}
+
+// One step of a tile transpose: splits both registers into `BITS` bit blocks and swaps the odd
+// blocks of `a` with the even blocks of `b`, returning `[a0, b0, a2, b2, ...]` and
+// `[a1, b1, a3, b3, ...]`. Blocks below 64 bits are moved with a shift and a mask.
+impl_imm8_op! {
+    fn transpose_blocks<binary, const BITS: i32> {
+        for Avx512(a: __m512, b: __m512) -> (__m512, __m512) {
+            let (a, b) = (_mm512_castps_pd(a), _mm512_castps_pd(b));
+            let (low, high) = match BITS {
+                64 => (_mm512_unpacklo_pd(a, b), _mm512_unpackhi_pd(a, b)),
+                128 => (
+                    _mm512_mask_shuffle_f64x2(a, 0b1100_1100, b, b, 0b10_00_00_00),
+                    _mm512_mask_shuffle_f64x2(b, 0b0011_0011, a, a, 0b11_11_01_01),
+                ),
+                256 => (_mm512_shuffle_f64x2(a, b, 0b01_00_01_00), _mm512_shuffle_f64x2(a, b, 0b11_10_11_10)),
+                _ => {
+                    let (a, b) = (_mm512_castpd_si512(a), _mm512_castpd_si512(b));
+                    let keep = _mm512_set1_epi64(low_blocks(BITS));
+                    let count = _mm_cvtsi32_si128(BITS);
+                    let low = _mm512_or_si512(_mm512_and_si512(keep, a), _mm512_andnot_si512(keep, _mm512_sll_epi64(b, count)));
+                    let high = _mm512_or_si512(_mm512_and_si512(keep, _mm512_srl_epi64(a, count)), _mm512_andnot_si512(keep, b));
+                    (_mm512_castsi512_pd(low), _mm512_castsi512_pd(high))
+                }
+            };
+            (_mm512_castpd_ps(low), _mm512_castpd_ps(high))
+        }
+        for Avx2(a: __m256, b: __m256) -> (__m256, __m256) {
+            match BITS {
+                64 => {
+                    let (a, b) = (_mm256_castps_pd(a), _mm256_castps_pd(b));
+                    (_mm256_castpd_ps(_mm256_unpacklo_pd(a, b)), _mm256_castpd_ps(_mm256_unpackhi_pd(a, b)))
+                }
+                128 => (_mm256_permute2f128_ps(a, b, 0x20), _mm256_permute2f128_ps(a, b, 0x31)),
+                _ => {
+                    let (a, b) = (_mm256_castps_si256(a), _mm256_castps_si256(b));
+                    let keep = _mm256_set1_epi64x(low_blocks(BITS));
+                    let count = _mm_cvtsi32_si128(BITS);
+                    let low = _mm256_or_si256(_mm256_and_si256(keep, a), _mm256_andnot_si256(keep, _mm256_sll_epi64(b, count)));
+                    let high = _mm256_or_si256(_mm256_and_si256(keep, _mm256_srl_epi64(a, count)), _mm256_andnot_si256(keep, b));
+                    (_mm256_castsi256_ps(low), _mm256_castsi256_ps(high))
+                }
+            }
+        }
+        for Sse41(a: __m128, b: __m128) -> (__m128, __m128) {
+            Ops::<Sse2, binary>::transpose_blocks::<BITS>(a, b)
+        }
+        for Sse2(a: __m128, b: __m128) -> (__m128, __m128) {
+            match BITS {
+                64 => (_mm_movelh_ps(a, b), _mm_movehl_ps(b, a)),
+                _ => {
+                    let (a, b) = (_mm_castps_si128(a), _mm_castps_si128(b));
+                    let keep = _mm_set1_epi64x(low_blocks(BITS));
+                    let count = _mm_cvtsi32_si128(BITS);
+                    let low = _mm_or_si128(_mm_and_si128(keep, a), _mm_andnot_si128(keep, _mm_sll_epi64(b, count)));
+                    let high = _mm_or_si128(_mm_and_si128(keep, _mm_srl_epi64(a, count)), _mm_andnot_si128(keep, b));
+                    (_mm_castsi128_ps(low), _mm_castsi128_ps(high))
+                }
+            }
+        }
+        for Scalar(a: u64, b: u64) -> (u64, u64) {
+            // A scalar tile is a single lane, so there is nothing to swap.
+            (a, b)
+        }
+        for Neon(a: int8x16_t, b: int8x16_t) -> (int8x16_t, int8x16_t) {
+            match BITS {
+                8 => (vtrn1q_s8(a, b), vtrn2q_s8(a, b)),
+                16 => {
+                    let (a, b) = (vreinterpretq_s16_s8(a), vreinterpretq_s16_s8(b));
+                    (vreinterpretq_s8_s16(vtrn1q_s16(a, b)), vreinterpretq_s8_s16(vtrn2q_s16(a, b)))
+                }
+                32 => {
+                    let (a, b) = (vreinterpretq_s32_s8(a), vreinterpretq_s32_s8(b));
+                    (vreinterpretq_s8_s32(vtrn1q_s32(a, b)), vreinterpretq_s8_s32(vtrn2q_s32(a, b)))
+                }
+                _ => {
+                    let (a, b) = (vreinterpretq_s64_s8(a), vreinterpretq_s64_s8(b));
+                    (vreinterpretq_s8_s64(vtrn1q_s64(a, b)), vreinterpretq_s8_s64(vtrn2q_s64(a, b)))
+                }
+            }
+        }
+        for Wasm(a: v128, b: v128) -> (v128, v128) {
+            match BITS {
+                8 => (
+                    i8x16_shuffle::<0, 16, 2, 18, 4, 20, 6, 22, 8, 24, 10, 26, 12, 28, 14, 30>(a, b),
+                    i8x16_shuffle::<1, 17, 3, 19, 5, 21, 7, 23, 9, 25, 11, 27, 13, 29, 15, 31>(a, b),
+                ),
+                16 => (
+                    i16x8_shuffle::<0, 8, 2, 10, 4, 12, 6, 14>(a, b),
+                    i16x8_shuffle::<1, 9, 3, 11, 5, 13, 7, 15>(a, b),
+                ),
+                32 => (i32x4_shuffle::<0, 4, 2, 6>(a, b), i32x4_shuffle::<1, 5, 3, 7>(a, b)),
+                _ => (i64x2_shuffle::<0, 2>(a, b), i64x2_shuffle::<1, 3>(a, b)),
+            }
+        }
+    }
+}
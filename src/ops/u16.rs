@@ -0,0 +1,1124 @@
+use super::*;
+
+// Most unsigned 16 bit operations share their bit patterns with the signed versions, so on the
+// x86 and wasm backends (where both use the same register type) they forward to the i16 ops.
+// Ordering-sensitive operations flip the sign bit before using the signed comparisons.
+
+#[cfg(any(target_arch = "x86_64", target_arch = "x86"))]
+const SIGN_BIT: i16 = i16::MIN;
+
+impl_op! {
+    fn add<u16> {
+        for Avx512(a: __m512i, b: __m512i) -> __m512i {
+            Ops::<Avx512, i16>::add(a, b)
+        }
+        for Avx2(a: __m256i, b: __m256i) -> __m256i {
+            Ops::<Avx2, i16>::add(a, b)
+        }
+        for Sse41(a: __m128i, b: __m128i) -> __m128i {
+            Ops::<Sse41, i16>::add(a, b)
+        }
+        for Sse2(a: __m128i, b: __m128i) -> __m128i {
+            Ops::<Sse2, i16>::add(a, b)
+        }
+        for Scalar(a: u16, b: u16) -> u16 {
+            a.wrapping_add(b)
+        }
+        for Neon(a: uint16x8_t, b: uint16x8_t) -> uint16x8_t {
+            vaddq_u16(a, b)
+        }
+        for Wasm(a: v128, b: v128) -> v128 {
+            Ops::<Wasm, i16>::add(a, b)
+        }
+    }
+}
+
+impl_op! {
+    fn sub<u16> {
+        for Avx512(a: __m512i, b: __m512i) -> __m512i {
+            Ops::<Avx512, i16>::sub(a, b)
+        }
+        for Avx2(a: __m256i, b: __m256i) -> __m256i {
+            Ops::<Avx2, i16>::sub(a, b)
+        }
+        for Sse41(a: __m128i, b: __m128i) -> __m128i {
+            Ops::<Sse41, i16>::sub(a, b)
+        }
+        for Sse2(a: __m128i, b: __m128i) -> __m128i {
+            Ops::<Sse2, i16>::sub(a, b)
+        }
+        for Scalar(a: u16, b: u16) -> u16 {
+            a.wrapping_sub(b)
+        }
+        for Neon(a: uint16x8_t, b: uint16x8_t) -> uint16x8_t {
+            vsubq_u16(a, b)
+        }
+        for Wasm(a: v128, b: v128) -> v128 {
+            Ops::<Wasm, i16>::sub(a, b)
+        }
+    }
+}
+
+impl_op! {
+    fn mul<u16> {
+        for Avx512(a: __m512i, b: __m512i) -> __m512i {
+            Ops::<Avx512, i16>::mul(a, b)
+        }
+        for Avx2(a: __m256i, b: __m256i) -> __m256i {
+            Ops::<Avx2, i16>::mul(a, b)
+        }
+        for Sse41(a: __m128i, b: __m128i) -> __m128i {
+            Ops::<Sse41, i16>::mul(a, b)
+        }
+        for Sse2(a: __m128i, b: __m128i) -> __m128i {
+            Ops::<Sse2, i16>::mul(a, b)
+        }
+        for Scalar(a: u16, b: u16) -> u16 {
+            a.wrapping_mul(b)
+        }
+        for Neon(a: uint16x8_t, b: uint16x8_t) -> uint16x8_t {
+            vmulq_u16(a, b)
+        }
+        for Wasm(a: v128, b: v128) -> v128 {
+            Ops::<Wasm, i16>::mul(a, b)
+        }
+    }
+}
+
+impl_op! {
+    fn min<u16> {
+        for Avx512(a: __m512i, b: __m512i) -> __m512i {
+            _mm512_min_epu16(a, b)
+        }
+        for Avx2(a: __m256i, b: __m256i) -> __m256i {
+            _mm256_min_epu16(a, b)
+        }
+        for Sse41(a: __m128i, b: __m128i) -> __m128i {
+            _mm_min_epu16(a, b)
+        }
+        for Sse2(a: __m128i, b: __m128i) -> __m128i {
+            _mm_sub_epi16(a, _mm_subs_epu16(a, b))
+        }
+        for Scalar(a: u16, b: u16) -> u16 {
+            a.min(b)
+        }
+        for Neon(a: uint16x8_t, b: uint16x8_t) -> uint16x8_t {
+            vminq_u16(a, b)
+        }
+        for Wasm(a: v128, b: v128) -> v128 {
+            u16x8_min(a, b)
+        }
+    }
+}
+
+impl_op! {
+    fn max<u16> {
+        for Avx512(a: __m512i, b: __m512i) -> __m512i {
+            _mm512_max_epu16(a, b)
+        }
+        for Avx2(a: __m256i, b: __m256i) -> __m256i {
+            _mm256_max_epu16(a, b)
+        }
+        for Sse41(a: __m128i, b: __m128i) -> __m128i {
+            _mm_max_epu16(a, b)
+        }
+        for Sse2(a: __m128i, b: __m128i) -> __m128i {
+            _mm_add_epi16(b, _mm_subs_epu16(a, b))
+        }
+        for Scalar(a: u16, b: u16) -> u16 {
+            a.max(b)
+        }
+        for Neon(a: uint16x8_t, b: uint16x8_t) -> uint16x8_t {
+            vmaxq_u16(a, b)
+        }
+        for Wasm(a: v128, b: v128) -> v128 {
+            u16x8_max(a, b)
+        }
+    }
+}
+
+impl_op! {
+    fn abs<u16> {
+        for Avx512(a: __m512i) -> __m512i {
+            a
+        }
+        for Avx2(a: __m256i) -> __m256i {
+            a
+        }
+        for Sse41(a: __m128i) -> __m128i {
+            a
+        }
+        for Sse2(a: __m128i) -> __m128i {
+            a
+        }
+        for Scalar(a: u16) -> u16 {
+            a
+        }
+        for Neon(a: uint16x8_t) -> uint16x8_t {
+            a
+        }
+        for Wasm(a: v128) -> v128 {
+            a
+        }
+    }
+}
+
+impl_op! {
+    fn eq<u16> {
+        for Avx512(a: __m512i, b: __m512i) -> __m512i {
+            Ops::<Avx512, i16>::eq(a, b)
+        }
+        for Avx2(a: __m256i, b: __m256i) -> __m256i {
+            Ops::<Avx2, i16>::eq(a, b)
+        }
+        for Sse41(a: __m128i, b: __m128i) -> __m128i {
+            Ops::<Sse41, i16>::eq(a, b)
+        }
+        for Sse2(a: __m128i, b: __m128i) -> __m128i {
+            Ops::<Sse2, i16>::eq(a, b)
+        }
+        for Scalar(a: u16, b: u16) -> u16 {
+            if a == b {
+                u16::MAX
+            } else {
+                0
+            }
+        }
+        for Neon(a: uint16x8_t, b: uint16x8_t) -> uint16x8_t {
+            vceqq_u16(a, b)
+        }
+        for Wasm(a: v128, b: v128) -> v128 {
+            Ops::<Wasm, i16>::eq(a, b)
+        }
+    }
+}
+
+impl_op! {
+    fn neq<u16> {
+        for Avx512(a: __m512i, b: __m512i) -> __m512i {
+            Ops::<Avx512, i16>::neq(a, b)
+        }
+        for Avx2(a: __m256i, b: __m256i) -> __m256i {
+            Ops::<Avx2, i16>::neq(a, b)
+        }
+        for Sse41(a: __m128i, b: __m128i) -> __m128i {
+            Ops::<Sse41, i16>::neq(a, b)
+        }
+        for Sse2(a: __m128i, b: __m128i) -> __m128i {
+            Ops::<Sse2, i16>::neq(a, b)
+        }
+        for Scalar(a: u16, b: u16) -> u16 {
+            if a != b {
+                u16::MAX
+            } else {
+                0
+            }
+        }
+        for Neon(a: uint16x8_t, b: uint16x8_t) -> uint16x8_t {
+            vmvnq_u16(vceqq_u16(a, b))
+        }
+        for Wasm(a: v128, b: v128) -> v128 {
+            Ops::<Wasm, i16>::neq(a, b)
+        }
+    }
+}
+
+impl_op! {
+    fn lt<u16> {
+        for Avx512(a: __m512i, b: __m512i) -> __m512i {
+            _mm512_movm_epi16(_mm512_cmplt_epu16_mask(a, b))
+        }
+        for Avx2(a: __m256i, b: __m256i) -> __m256i {
+            let sign = _mm256_set1_epi16(SIGN_BIT);
+            Ops::<Avx2, i16>::lt(_mm256_xor_si256(a, sign), _mm256_xor_si256(b, sign))
+        }
+        for Sse41(a: __m128i, b: __m128i) -> __m128i {
+            let sign = _mm_set1_epi16(SIGN_BIT);
+            Ops::<Sse41, i16>::lt(_mm_xor_si128(a, sign), _mm_xor_si128(b, sign))
+        }
+        for Sse2(a: __m128i, b: __m128i) -> __m128i {
+            let sign = _mm_set1_epi16(SIGN_BIT);
+            Ops::<Sse2, i16>::lt(_mm_xor_si128(a, sign), _mm_xor_si128(b, sign))
+        }
+        for Scalar(a: u16, b: u16) -> u16 {
+            if a < b {
+                u16::MAX
+            } else {
+                0
+            }
+        }
+        for Neon(a: uint16x8_t, b: uint16x8_t) -> uint16x8_t {
+            vcltq_u16(a, b)
+        }
+        for Wasm(a: v128, b: v128) -> v128 {
+            u16x8_lt(a, b)
+        }
+    }
+}
+
+impl_op! {
+    fn lte<u16> {
+        for Avx512(a: __m512i, b: __m512i) -> __m512i {
+            _mm512_movm_epi16(_mm512_cmple_epu16_mask(a, b))
+        }
+        for Avx2(a: __m256i, b: __m256i) -> __m256i {
+            let sign = _mm256_set1_epi16(SIGN_BIT);
+            Ops::<Avx2, i16>::lte(_mm256_xor_si256(a, sign), _mm256_xor_si256(b, sign))
+        }
+        for Sse41(a: __m128i, b: __m128i) -> __m128i {
+            let sign = _mm_set1_epi16(SIGN_BIT);
+            Ops::<Sse41, i16>::lte(_mm_xor_si128(a, sign), _mm_xor_si128(b, sign))
+        }
+        for Sse2(a: __m128i, b: __m128i) -> __m128i {
+            let sign = _mm_set1_epi16(SIGN_BIT);
+            Ops::<Sse2, i16>::lte(_mm_xor_si128(a, sign), _mm_xor_si128(b, sign))
+        }
+        for Scalar(a: u16, b: u16) -> u16 {
+            if a <= b {
+                u16::MAX
+            } else {
+                0
+            }
+        }
+        for Neon(a: uint16x8_t, b: uint16x8_t) -> uint16x8_t {
+            vcleq_u16(a, b)
+        }
+        for Wasm(a: v128, b: v128) -> v128 {
+            u16x8_le(a, b)
+        }
+    }
+}
+
+impl_op! {
+    fn gt<u16> {
+        for Avx512(a: __m512i, b: __m512i) -> __m512i {
+            _mm512_movm_epi16(_mm512_cmpgt_epu16_mask(a, b))
+        }
+        for Avx2(a: __m256i, b: __m256i) -> __m256i {
+            let sign = _mm256_set1_epi16(SIGN_BIT);
+            Ops::<Avx2, i16>::gt(_mm256_xor_si256(a, sign), _mm256_xor_si256(b, sign))
+        }
+        for Sse41(a: __m128i, b: __m128i) -> __m128i {
+            let sign = _mm_set1_epi16(SIGN_BIT);
+            Ops::<Sse41, i16>::gt(_mm_xor_si128(a, sign), _mm_xor_si128(b, sign))
+        }
+        for Sse2(a: __m128i, b: __m128i) -> __m128i {
+            let sign = _mm_set1_epi16(SIGN_BIT);
+            Ops::<Sse2, i16>::gt(_mm_xor_si128(a, sign), _mm_xor_si128(b, sign))
+        }
+        for Scalar(a: u16, b: u16) -> u16 {
+            if a > b {
+                u16::MAX
+            } else {
+                0
+            }
+        }
+        for Neon(a: uint16x8_t, b: uint16x8_t) -> uint16x8_t {
+            vcgtq_u16(a, b)
+        }
+        for Wasm(a: v128, b: v128) -> v128 {
+            u16x8_gt(a, b)
+        }
+    }
+}
+
+impl_op! {
+    fn gte<u16> {
+        for Avx512(a: __m512i, b: __m512i) -> __m512i {
+            _mm512_movm_epi16(_mm512_cmpge_epu16_mask(a, b))
+        }
+        for Avx2(a: __m256i, b: __m256i) -> __m256i {
+            let sign = _mm256_set1_epi16(SIGN_BIT);
+            Ops::<Avx2, i16>::gte(_mm256_xor_si256(a, sign), _mm256_xor_si256(b, sign))
+        }
+        for Sse41(a: __m128i, b: __m128i) -> __m128i {
+            let sign = _mm_set1_epi16(SIGN_BIT);
+            Ops::<Sse41, i16>::gte(_mm_xor_si128(a, sign), _mm_xor_si128(b, sign))
+        }
+        for Sse2(a: __m128i, b: __m128i) -> __m128i {
+            let sign = _mm_set1_epi16(SIGN_BIT);
+            Ops::<Sse2, i16>::gte(_mm_xor_si128(a, sign), _mm_xor_si128(b, sign))
+        }
+        for Scalar(a: u16, b: u16) -> u16 {
+            if a >= b {
+                u16::MAX
+            } else {
+                0
+            }
+        }
+        for Neon(a: uint16x8_t, b: uint16x8_t) -> uint16x8_t {
+            vcgeq_u16(a, b)
+        }
+        for Wasm(a: v128, b: v128) -> v128 {
+            u16x8_ge(a, b)
+        }
+    }
+}
+
+impl_op! {
+    fn blendv<u16> {
+        for Avx512(a: __m512i, b: __m512i, mask: __m512i) -> __m512i {
+            Ops::<Avx512, i16>::blendv(a, b, mask)
+        }
+        for Avx2(a: __m256i, b: __m256i, mask: __m256i) -> __m256i {
+            Ops::<Avx2, i16>::blendv(a, b, mask)
+        }
+        for Sse41(a: __m128i, b: __m128i, mask: __m128i) -> __m128i {
+            Ops::<Sse41, i16>::blendv(a, b, mask)
+        }
+        for Sse2(a: __m128i, b: __m128i, mask: __m128i) -> __m128i {
+            Ops::<Sse2, i16>::blendv(a, b, mask)
+        }
+        for Scalar(a: u16, b: u16, mask: u16) -> u16 {
+            if mask == 0 {
+                a
+            } else {
+                b
+            }
+        }
+        for Neon(a: uint16x8_t, b: uint16x8_t, mask: uint16x8_t) -> uint16x8_t {
+            vbslq_u16(mask, b, a)
+        }
+        for Wasm(a: v128, b: v128, mask: v128) -> v128 {
+            Ops::<Wasm, i16>::blendv(a, b, mask)
+        }
+    }
+}
+
+impl_op! {
+    fn shl<u16> {
+        for Avx512(a: __m512i, rhs: i32) -> __m512i {
+            Ops::<Avx512, i16>::shl(a, rhs)
+        }
+        for Avx2(a: __m256i, rhs: i32) -> __m256i {
+            Ops::<Avx2, i16>::shl(a, rhs)
+        }
+        for Sse41(a: __m128i, rhs: i32) -> __m128i {
+            Ops::<Sse41, i16>::shl(a, rhs)
+        }
+        for Sse2(a: __m128i, rhs: i32) -> __m128i {
+            Ops::<Sse2, i16>::shl(a, rhs)
+        }
+        for Scalar(a: u16, rhs: i32) -> u16 {
+            a.wrapping_shl((rhs & 15) as u32)
+        }
+        for Neon(a: uint16x8_t, rhs: i32) -> uint16x8_t {
+            vshlq_u16(a, Ops::<Neon, i16>::set1((rhs & 15) as i16))
+        }
+        for Wasm(a: v128, rhs: i32) -> v128 {
+            Ops::<Wasm, i16>::shl(a, rhs)
+        }
+    }
+}
+
+impl_op! {
+    fn shr<u16> {
+        for Avx512(a: __m512i, rhs: i32) -> __m512i {
+            Ops::<Avx512, i16>::shr(a, rhs)
+        }
+        for Avx2(a: __m256i, rhs: i32) -> __m256i {
+            Ops::<Avx2, i16>::shr(a, rhs)
+        }
+        for Sse41(a: __m128i, rhs: i32) -> __m128i {
+            Ops::<Sse41, i16>::shr(a, rhs)
+        }
+        for Sse2(a: __m128i, rhs: i32) -> __m128i {
+            Ops::<Sse2, i16>::shr(a, rhs)
+        }
+        for Scalar(a: u16, rhs: i32) -> u16 {
+            a.wrapping_shr((rhs & 15) as u32)
+        }
+        for Neon(a: uint16x8_t, rhs: i32) -> uint16x8_t {
+            vshlq_u16(a, Ops::<Neon, i16>::set1(-(rhs & 15) as i16))
+        }
+        for Wasm(a: v128, rhs: i32) -> v128 {
+            Ops::<Wasm, i16>::shr(a, rhs)
+        }
+    }
+}
+
+impl_imm8_op! {
+    fn shl_const<u16, const BY: i32> {
+        for Avx512(a: __m512i) -> __m512i {
+            Ops::<Avx512, i16>::shl_const::<BY>(a)
+        }
+        for Avx2(a: __m256i) -> __m256i {
+            Ops::<Avx2, i16>::shl_const::<BY>(a)
+        }
+        for Sse41(a: __m128i) -> __m128i {
+            Ops::<Sse41, i16>::shl_const::<BY>(a)
+        }
+        for Sse2(a: __m128i) -> __m128i {
+            Ops::<Sse2, i16>::shl_const::<BY>(a)
+        }
+        for Scalar(a: u16) -> u16 {
+            a << BY
+        }
+        for Neon(a: uint16x8_t) -> uint16x8_t {
+            vshlq_n_u16(a, BY)
+        }
+        for Wasm(a: v128) -> v128 {
+            Ops::<Wasm, i16>::shl_const::<BY>(a)
+        }
+    }
+}
+
+impl_imm8_op! {
+    fn shr_const<u16, const BY: i32> {
+        for Avx512(a: __m512i) -> __m512i {
+            Ops::<Avx512, i16>::shr_const::<BY>(a)
+        }
+        for Avx2(a: __m256i) -> __m256i {
+            Ops::<Avx2, i16>::shr_const::<BY>(a)
+        }
+        for Sse41(a: __m128i) -> __m128i {
+            Ops::<Sse41, i16>::shr_const::<BY>(a)
+        }
+        for Sse2(a: __m128i) -> __m128i {
+            Ops::<Sse2, i16>::shr_const::<BY>(a)
+        }
+        for Scalar(a: u16) -> u16 {
+            a >> BY
+        }
+        for Neon(a: uint16x8_t) -> uint16x8_t {
+            vshrq_n_u16(a, BY)
+        }
+        for Wasm(a: v128) -> v128 {
+            Ops::<Wasm, i16>::shr_const::<BY>(a)
+        }
+    }
+}
+
+impl_op! {
+    fn extend_u32<u16> {
+        for Avx512(val: __m512i) -> (__m512i, __m512i) {
+            Ops::<Avx512, i16>::unsigned_extend_i32(val)
+        }
+        for Avx2(val: __m256i) -> (__m256i, __m256i) {
+            Ops::<Avx2, i16>::unsigned_extend_i32(val)
+        }
+        for Sse41(val: __m128i) -> (__m128i, __m128i) {
+            Ops::<Sse41, i16>::unsigned_extend_i32(val)
+        }
+        for Sse2(val: __m128i) -> (__m128i, __m128i) {
+            let zero = _mm_setzero_si128();
+            (_mm_unpacklo_epi16(val, zero), _mm_unpackhi_epi16(val, zero))
+        }
+        for Scalar(val: u16) -> (u32, u32) {
+            (val as u32, 0)
+        }
+        for Neon(val: uint16x8_t) -> (uint32x4_t, uint32x4_t) {
+            (vmovl_u16(vget_low_u16(val)), vmovl_u16(vget_high_u16(val)))
+        }
+        for Wasm(val: v128) -> (v128, v128) {
+            Ops::<Wasm, i16>::unsigned_extend_i32(val)
+        }
+    }
+}
+
+impl_op! {
+    fn zeroes<u16> {
+        for Avx512() -> __m512i {
+            _mm512_setzero_si512()
+        }
+        for Avx2() -> __m256i {
+            _mm256_setzero_si256()
+        }
+        for Sse41() -> __m128i {
+            _mm_setzero_si128()
+        }
+        for Sse2() -> __m128i {
+            _mm_setzero_si128()
+        }
+        for Scalar() -> u16 {
+            0
+        }
+        for Neon() -> uint16x8_t {
+            vdupq_n_u16(0)
+        }
+        for Wasm() -> v128 {
+            u16x8_splat(0)
+        }
+    }
+}
+
+impl_op! {
+    fn set1<u16> {
+        for Avx512(val: u16) -> __m512i {
+            _mm512_set1_epi16(val as i16)
+        }
+        for Avx2(val: u16) -> __m256i {
+            _mm256_set1_epi16(val as i16)
+        }
+        for Sse41(val: u16) -> __m128i {
+            _mm_set1_epi16(val as i16)
+        }
+        for Sse2(val: u16) -> __m128i {
+            _mm_set1_epi16(val as i16)
+        }
+        for Scalar(val: u16) -> u16 {
+            val
+        }
+        for Neon(val: u16) -> uint16x8_t {
+            vdupq_n_u16(val)
+        }
+        for Wasm(val: u16) -> v128 {
+            u16x8_splat(val)
+        }
+    }
+}
+
+impl_op! {
+    fn load_unaligned<u16> {
+        for Avx512(ptr: *const u16) -> __m512i {
+            _mm512_loadu_si512(ptr as *const __m512i)
+        }
+        for Avx2(ptr: *const u16) -> __m256i {
+            _mm256_loadu_si256(ptr as *const __m256i)
+        }
+        for Sse41(ptr: *const u16) -> __m128i {
+            _mm_loadu_si128(ptr as *const __m128i)
+        }
+        for Sse2(ptr: *const u16) -> __m128i {
+            _mm_loadu_si128(ptr as *const __m128i)
+        }
+        for Scalar(ptr: *const u16) -> u16 {
+            unsafe { *ptr }
+        }
+        for Neon(ptr: *const u16) -> uint16x8_t {
+            vld1q_u16(ptr)
+        }
+        for Wasm(ptr: *const u16) -> v128 {
+            unsafe { v128_load(ptr as *const v128) }
+        }
+    }
+}
+
+impl_op! {
+    fn load_aligned<u16> {
+        for Avx512(ptr: *const u16) -> __m512i {
+            _mm512_load_si512(ptr as *const __m512i)
+        }
+        for Avx2(ptr: *const u16) -> __m256i {
+            _mm256_load_si256(ptr as *const __m256i)
+        }
+        for Sse41(ptr: *const u16) -> __m128i {
+            _mm_load_si128(ptr as *const __m128i)
+        }
+        for Sse2(ptr: *const u16) -> __m128i {
+            _mm_load_si128(ptr as *const __m128i)
+        }
+        for Scalar(ptr: *const u16) -> u16 {
+            unsafe { *ptr }
+        }
+        for Neon(ptr: *const u16) -> uint16x8_t {
+            vld1q_u16(ptr)
+        }
+        for Wasm(ptr: *const u16) -> v128 {
+            *(ptr as *const v128)
+        }
+    }
+}
+
+impl_op! {
+    fn store_unaligned<u16> {
+        for Avx512(ptr: *mut u16, a: __m512i) {
+            _mm512_storeu_si512(ptr as *mut __m512i, a)
+        }
+        for Avx2(ptr: *mut u16, a: __m256i) {
+            _mm256_storeu_si256(ptr as *mut __m256i, a)
+        }
+        for Sse41(ptr: *mut u16, a: __m128i) {
+            _mm_storeu_si128(ptr as *mut __m128i, a)
+        }
+        for Sse2(ptr: *mut u16, a: __m128i) {
+            _mm_storeu_si128(ptr as *mut __m128i, a)
+        }
+        for Scalar(ptr: *mut u16, a: u16) {
+            unsafe { *ptr = a }
+        }
+        for Neon(ptr: *mut u16, a: uint16x8_t) {
+            vst1q_u16(ptr, a)
+        }
+        for Wasm(ptr: *mut u16, a: v128) {
+            unsafe { v128_store(ptr as *mut v128, a) }
+        }
+    }
+}
+
+impl_op! {
+    fn store_aligned<u16> {
+        for Avx512(ptr: *mut u16, a: __m512i) {
+            _mm512_store_si512(ptr as *mut __m512i, a)
+        }
+        for Avx2(ptr: *mut u16, a: __m256i) {
+            _mm256_store_si256(ptr as *mut __m256i, a)
+        }
+        for Sse41(ptr: *mut u16, a: __m128i) {
+            _mm_store_si128(ptr as *mut __m128i, a)
+        }
+        for Sse2(ptr: *mut u16, a: __m128i) {
+            _mm_store_si128(ptr as *mut __m128i, a)
+        }
+        for Scalar(ptr: *mut u16, a: u16) {
+            unsafe { *ptr = a }
+        }
+        for Neon(ptr: *mut u16, a: uint16x8_t) {
+            vst1q_u16(ptr, a)
+        }
+        for Wasm(ptr: *mut u16, a: v128) {
+            *(ptr as *mut v128) = a;
+        }
+    }
+}
+
+impl_op! {
+    fn saturating_add<u16> {
+        for Avx512(a: __m512i, b: __m512i) -> __m512i {
+            _mm512_adds_epu16(a, b)
+        }
+        for Avx2(a: __m256i, b: __m256i) -> __m256i {
+            _mm256_adds_epu16(a, b)
+        }
+        for Sse41(a: __m128i, b: __m128i) -> __m128i {
+            _mm_adds_epu16(a, b)
+        }
+        for Sse2(a: __m128i, b: __m128i) -> __m128i {
+            _mm_adds_epu16(a, b)
+        }
+        for Scalar(a: u16, b: u16) -> u16 {
+            a.saturating_add(b)
+        }
+        for Neon(a: uint16x8_t, b: uint16x8_t) -> uint16x8_t {
+            vqaddq_u16(a, b)
+        }
+        for Wasm(a: v128, b: v128) -> v128 {
+            u16x8_add_sat(a, b)
+        }
+    }
+}
+
+impl_op! {
+    fn saturating_sub<u16> {
+        for Avx512(a: __m512i, b: __m512i) -> __m512i {
+            _mm512_subs_epu16(a, b)
+        }
+        for Avx2(a: __m256i, b: __m256i) -> __m256i {
+            _mm256_subs_epu16(a, b)
+        }
+        for Sse41(a: __m128i, b: __m128i) -> __m128i {
+            _mm_subs_epu16(a, b)
+        }
+        for Sse2(a: __m128i, b: __m128i) -> __m128i {
+            _mm_subs_epu16(a, b)
+        }
+        for Scalar(a: u16, b: u16) -> u16 {
+            a.saturating_sub(b)
+        }
+        for Neon(a: uint16x8_t, b: uint16x8_t) -> uint16x8_t {
+            vqsubq_u16(a, b)
+        }
+        for Wasm(a: v128, b: v128) -> v128 {
+            u16x8_sub_sat(a, b)
+        }
+    }
+}
+
+impl_op! {
+    fn avg<u16> {
+        for Avx512(a: __m512i, b: __m512i) -> __m512i {
+            _mm512_avg_epu16(a, b)
+        }
+        for Avx2(a: __m256i, b: __m256i) -> __m256i {
+            _mm256_avg_epu16(a, b)
+        }
+        for Sse41(a: __m128i, b: __m128i) -> __m128i {
+            _mm_avg_epu16(a, b)
+        }
+        for Sse2(a: __m128i, b: __m128i) -> __m128i {
+            _mm_avg_epu16(a, b)
+        }
+        for Scalar(a: u16, b: u16) -> u16 {
+            ((a as u32 + b as u32 + 1) >> 1) as u16
+        }
+        for Neon(a: uint16x8_t, b: uint16x8_t) -> uint16x8_t {
+            vrhaddq_u16(a, b)
+        }
+        for Wasm(a: v128, b: v128) -> v128 {
+            u16x8_avgr(a, b)
+        }
+    }
+}
+
+impl_op! {
+    fn mul_hi<u16> {
+        for Avx512(a: __m512i, b: __m512i) -> __m512i {
+            _mm512_mulhi_epu16(a, b)
+        }
+        for Avx2(a: __m256i, b: __m256i) -> __m256i {
+            _mm256_mulhi_epu16(a, b)
+        }
+        for Sse41(a: __m128i, b: __m128i) -> __m128i {
+            _mm_mulhi_epu16(a, b)
+        }
+        for Sse2(a: __m128i, b: __m128i) -> __m128i {
+            _mm_mulhi_epu16(a, b)
+        }
+        for Scalar(a: u16, b: u16) -> u16 {
+            ((a as u32 * b as u32) >> 16) as u16
+        }
+        for Neon(a: uint16x8_t, b: uint16x8_t) -> uint16x8_t {
+            let low = vmull_u16(vget_low_u16(a), vget_low_u16(b));
+            let high = vmull_high_u16(a, b);
+            vcombine_u16(vshrn_n_u32::<16>(low), vshrn_n_u32::<16>(high))
+        }
+        for Wasm(a: v128, b: v128) -> v128 {
+            let low = u32x4_extmul_low_u16x8(a, b);
+            let high = u32x4_extmul_high_u16x8(a, b);
+            i16x8_shuffle::<1, 3, 5, 7, 9, 11, 13, 15>(low, high)
+        }
+    }
+}
+
+impl_op! {
+    fn shr_arithmetic<u16> {
+        for Avx512(a: __m512i, rhs: i32) -> __m512i {
+            Ops::<Avx512, i16>::shr_arithmetic(a, rhs)
+        }
+        for Avx2(a: __m256i, rhs: i32) -> __m256i {
+            Ops::<Avx2, i16>::shr_arithmetic(a, rhs)
+        }
+        for Sse41(a: __m128i, rhs: i32) -> __m128i {
+            Ops::<Sse41, i16>::shr_arithmetic(a, rhs)
+        }
+        for Sse2(a: __m128i, rhs: i32) -> __m128i {
+            Ops::<Sse2, i16>::shr_arithmetic(a, rhs)
+        }
+        for Scalar(a: u16, rhs: i32) -> u16 {
+            (a as i16).wrapping_shr(rhs as u32) as u16
+        }
+        for Neon(a: uint16x8_t, rhs: i32) -> uint16x8_t {
+            vreinterpretq_u16_s16(Ops::<Neon, i16>::shr_arithmetic(vreinterpretq_s16_u16(a), rhs))
+        }
+        for Wasm(a: v128, rhs: i32) -> v128 {
+            Ops::<Wasm, i16>::shr_arithmetic(a, rhs)
+        }
+    }
+}
+
+impl_op! {
+    fn shl_lanes<u16> {
+        for Avx512(a: __m512i, counts: __m512i) -> __m512i {
+            Ops::<Avx512, i16>::shl_lanes(a, counts)
+        }
+        for Avx2(a: __m256i, counts: __m256i) -> __m256i {
+            Ops::<Avx2, i16>::shl_lanes(a, counts)
+        }
+        for Sse41(a: __m128i, counts: __m128i) -> __m128i {
+            Ops::<Sse41, i16>::shl_lanes(a, counts)
+        }
+        for Sse2(a: __m128i, counts: __m128i) -> __m128i {
+            Ops::<Sse2, i16>::shl_lanes(a, counts)
+        }
+        for Scalar(a: u16, counts: u16) -> u16 {
+            a.wrapping_shl(counts as u32)
+        }
+        for Neon(a: uint16x8_t, counts: uint16x8_t) -> uint16x8_t {
+            let counts = vreinterpretq_s16_u16(counts);
+            vreinterpretq_u16_s16(Ops::<Neon, i16>::shl_lanes(vreinterpretq_s16_u16(a), counts))
+        }
+        for Wasm(a: v128, counts: v128) -> v128 {
+            Ops::<Wasm, i16>::shl_lanes(a, counts)
+        }
+    }
+}
+
+impl_op! {
+    fn shr_lanes<u16> {
+        for Avx512(a: __m512i, counts: __m512i) -> __m512i {
+            Ops::<Avx512, i16>::shr_lanes(a, counts)
+        }
+        for Avx2(a: __m256i, counts: __m256i) -> __m256i {
+            Ops::<Avx2, i16>::shr_lanes(a, counts)
+        }
+        for Sse41(a: __m128i, counts: __m128i) -> __m128i {
+            Ops::<Sse41, i16>::shr_lanes(a, counts)
+        }
+        for Sse2(a: __m128i, counts: __m128i) -> __m128i {
+            Ops::<Sse2, i16>::shr_lanes(a, counts)
+        }
+        for Scalar(a: u16, counts: u16) -> u16 {
+            a.wrapping_shr(counts as u32)
+        }
+        for Neon(a: uint16x8_t, counts: uint16x8_t) -> uint16x8_t {
+            let counts = vreinterpretq_s16_u16(counts);
+            vreinterpretq_u16_s16(Ops::<Neon, i16>::shr_lanes(vreinterpretq_s16_u16(a), counts))
+        }
+        for Wasm(a: v128, counts: v128) -> v128 {
+            Ops::<Wasm, i16>::shr_lanes(a, counts)
+        }
+    }
+}
+
+impl_op! {
+    fn shr_arithmetic_lanes<u16> {
+        for Avx512(a: __m512i, counts: __m512i) -> __m512i {
+            Ops::<Avx512, i16>::shr_arithmetic_lanes(a, counts)
+        }
+        for Avx2(a: __m256i, counts: __m256i) -> __m256i {
+            Ops::<Avx2, i16>::shr_arithmetic_lanes(a, counts)
+        }
+        for Sse41(a: __m128i, counts: __m128i) -> __m128i {
+            Ops::<Sse41, i16>::shr_arithmetic_lanes(a, counts)
+        }
+        for Sse2(a: __m128i, counts: __m128i) -> __m128i {
+            Ops::<Sse2, i16>::shr_arithmetic_lanes(a, counts)
+        }
+        for Scalar(a: u16, counts: u16) -> u16 {
+            (a as i16).wrapping_shr(counts as u32) as u16
+        }
+        for Neon(a: uint16x8_t, counts: uint16x8_t) -> uint16x8_t {
+            let counts = vreinterpretq_s16_u16(counts);
+            vreinterpretq_u16_s16(Ops::<Neon, i16>::shr_arithmetic_lanes(vreinterpretq_s16_u16(a), counts))
+        }
+        for Wasm(a: v128, counts: v128) -> v128 {
+            Ops::<Wasm, i16>::shr_arithmetic_lanes(a, counts)
+        }
+    }
+}
+
+impl_op! {
+    fn rotate_left<u16> {
+        for Avx512(a: __m512i, rhs: i32) -> __m512i {
+            Ops::<Avx512, i16>::rotate_left(a, rhs)
+        }
+        for Avx2(a: __m256i, rhs: i32) -> __m256i {
+            Ops::<Avx2, i16>::rotate_left(a, rhs)
+        }
+        for Sse41(a: __m128i, rhs: i32) -> __m128i {
+            Ops::<Sse41, i16>::rotate_left(a, rhs)
+        }
+        for Sse2(a: __m128i, rhs: i32) -> __m128i {
+            Ops::<Sse2, i16>::rotate_left(a, rhs)
+        }
+        for Scalar(a: u16, rhs: i32) -> u16 {
+            a.rotate_left(rhs as u32)
+        }
+        for Neon(a: uint16x8_t, rhs: i32) -> uint16x8_t {
+            vreinterpretq_u16_s16(Ops::<Neon, i16>::rotate_left(vreinterpretq_s16_u16(a), rhs))
+        }
+        for Wasm(a: v128, rhs: i32) -> v128 {
+            Ops::<Wasm, i16>::rotate_left(a, rhs)
+        }
+    }
+}
+
+impl_op! {
+    fn rotate_right<u16> {
+        for Avx512(a: __m512i, rhs: i32) -> __m512i {
+            Ops::<Avx512, i16>::rotate_right(a, rhs)
+        }
+        for Avx2(a: __m256i, rhs: i32) -> __m256i {
+            Ops::<Avx2, i16>::rotate_right(a, rhs)
+        }
+        for Sse41(a: __m128i, rhs: i32) -> __m128i {
+            Ops::<Sse41, i16>::rotate_right(a, rhs)
+        }
+        for Sse2(a: __m128i, rhs: i32) -> __m128i {
+            Ops::<Sse2, i16>::rotate_right(a, rhs)
+        }
+        for Scalar(a: u16, rhs: i32) -> u16 {
+            a.rotate_right(rhs as u32)
+        }
+        for Neon(a: uint16x8_t, rhs: i32) -> uint16x8_t {
+            vreinterpretq_u16_s16(Ops::<Neon, i16>::rotate_right(vreinterpretq_s16_u16(a), rhs))
+        }
+        for Wasm(a: v128, rhs: i32) -> v128 {
+            Ops::<Wasm, i16>::rotate_right(a, rhs)
+        }
+    }
+}
+
+impl_op! {
+    fn rotate_left_lanes<u16> {
+        for Avx512(a: __m512i, counts: __m512i) -> __m512i {
+            Ops::<Avx512, i16>::rotate_left_lanes(a, counts)
+        }
+        for Avx2(a: __m256i, counts: __m256i) -> __m256i {
+            Ops::<Avx2, i16>::rotate_left_lanes(a, counts)
+        }
+        for Sse41(a: __m128i, counts: __m128i) -> __m128i {
+            Ops::<Sse41, i16>::rotate_left_lanes(a, counts)
+        }
+        for Sse2(a: __m128i, counts: __m128i) -> __m128i {
+            Ops::<Sse2, i16>::rotate_left_lanes(a, counts)
+        }
+        for Scalar(a: u16, counts: u16) -> u16 {
+            a.rotate_left(counts as u32)
+        }
+        for Neon(a: uint16x8_t, counts: uint16x8_t) -> uint16x8_t {
+            let counts = vreinterpretq_s16_u16(counts);
+            vreinterpretq_u16_s16(Ops::<Neon, i16>::rotate_left_lanes(vreinterpretq_s16_u16(a), counts))
+        }
+        for Wasm(a: v128, counts: v128) -> v128 {
+            Ops::<Wasm, i16>::rotate_left_lanes(a, counts)
+        }
+    }
+}
+
+impl_op! {
+    fn rotate_right_lanes<u16> {
+        for Avx512(a: __m512i, counts: __m512i) -> __m512i {
+            Ops::<Avx512, i16>::rotate_right_lanes(a, counts)
+        }
+        for Avx2(a: __m256i, counts: __m256i) -> __m256i {
+            Ops::<Avx2, i16>::rotate_right_lanes(a, counts)
+        }
+        for Sse41(a: __m128i, counts: __m128i) -> __m128i {
+            Ops::<Sse41, i16>::rotate_right_lanes(a, counts)
+        }
+        for Sse2(a: __m128i, counts: __m128i) -> __m128i {
+            Ops::<Sse2, i16>::rotate_right_lanes(a, counts)
+        }
+        for Scalar(a: u16, counts: u16) -> u16 {
+            a.rotate_right(counts as u32)
+        }
+        for Neon(a: uint16x8_t, counts: uint16x8_t) -> uint16x8_t {
+            let counts = vreinterpretq_s16_u16(counts);
+            vreinterpretq_u16_s16(Ops::<Neon, i16>::rotate_right_lanes(vreinterpretq_s16_u16(a), counts))
+        }
+        for Wasm(a: v128, counts: v128) -> v128 {
+            Ops::<Wasm, i16>::rotate_right_lanes(a, counts)
+        }
+    }
+}
+
+impl_op! {
+    fn count_ones<u16> {
+        for Avx512(a: __m512i) -> __m512i {
+            Ops::<Avx512, i16>::count_ones(a)
+        }
+        for Avx2(a: __m256i) -> __m256i {
+            Ops::<Avx2, i16>::count_ones(a)
+        }
+        for Sse41(a: __m128i) -> __m128i {
+            Ops::<Sse41, i16>::count_ones(a)
+        }
+        for Sse2(a: __m128i) -> __m128i {
+            Ops::<Sse2, i16>::count_ones(a)
+        }
+        for Scalar(a: u16) -> u16 {
+            a.count_ones() as u16
+        }
+        for Neon(a: uint16x8_t) -> uint16x8_t {
+            vreinterpretq_u16_s16(Ops::<Neon, i16>::count_ones(vreinterpretq_s16_u16(a)))
+        }
+        for Wasm(a: v128) -> v128 {
+            Ops::<Wasm, i16>::count_ones(a)
+        }
+    }
+}
+
+impl_op! {
+    fn leading_zeros<u16> {
+        for Avx512(a: __m512i) -> __m512i {
+            Ops::<Avx512, i16>::leading_zeros(a)
+        }
+        for Avx2(a: __m256i) -> __m256i {
+            Ops::<Avx2, i16>::leading_zeros(a)
+        }
+        for Sse41(a: __m128i) -> __m128i {
+            Ops::<Sse41, i16>::leading_zeros(a)
+        }
+        for Sse2(a: __m128i) -> __m128i {
+            Ops::<Sse2, i16>::leading_zeros(a)
+        }
+        for Scalar(a: u16) -> u16 {
+            a.leading_zeros() as u16
+        }
+        for Neon(a: uint16x8_t) -> uint16x8_t {
+            vreinterpretq_u16_s16(Ops::<Neon, i16>::leading_zeros(vreinterpretq_s16_u16(a)))
+        }
+        for Wasm(a: v128) -> v128 {
+            Ops::<Wasm, i16>::leading_zeros(a)
+        }
+    }
+}
+
+impl_op! {
+    fn trailing_zeros<u16> {
+        for Avx512(a: __m512i) -> __m512i {
+            Ops::<Avx512, i16>::trailing_zeros(a)
+        }
+        for Avx2(a: __m256i) -> __m256i {
+            Ops::<Avx2, i16>::trailing_zeros(a)
+        }
+        for Sse41(a: __m128i) -> __m128i {
+            Ops::<Sse41, i16>::trailing_zeros(a)
+        }
+        for Sse2(a: __m128i) -> __m128i {
+            Ops::<Sse2, i16>::trailing_zeros(a)
+        }
+        for Scalar(a: u16) -> u16 {
+            a.trailing_zeros() as u16
+        }
+        for Neon(a: uint16x8_t) -> uint16x8_t {
+            vreinterpretq_u16_s16(Ops::<Neon, i16>::trailing_zeros(vreinterpretq_s16_u16(a)))
+        }
+        for Wasm(a: v128) -> v128 {
+            Ops::<Wasm, i16>::trailing_zeros(a)
+        }
+    }
+}
+
+impl_op! {
+    fn reverse_bits<u16> {
+        for Avx512(a: __m512i) -> __m512i {
+            Ops::<Avx512, i16>::reverse_bits(a)
+        }
+        for Avx2(a: __m256i) -> __m256i {
+            Ops::<Avx2, i16>::reverse_bits(a)
+        }
+        for Sse41(a: __m128i) -> __m128i {
+            Ops::<Sse41, i16>::reverse_bits(a)
+        }
+        for Sse2(a: __m128i) -> __m128i {
+            Ops::<Sse2, i16>::reverse_bits(a)
+        }
+        for Scalar(a: u16) -> u16 {
+            a.reverse_bits()
+        }
+        for Neon(a: uint16x8_t) -> uint16x8_t {
+            vreinterpretq_u16_s16(Ops::<Neon, i16>::reverse_bits(vreinterpretq_s16_u16(a)))
+        }
+        for Wasm(a: v128) -> v128 {
+            Ops::<Wasm, i16>::reverse_bits(a)
+        }
+    }
+}
+
+impl_op! {
+    fn popcount_total<u16> {
+        for Avx512(a: __m512i) -> u32 {
+            Ops::<Avx512, i16>::popcount_total(a)
+        }
+        for Avx2(a: __m256i) -> u32 {
+            Ops::<Avx2, i16>::popcount_total(a)
+        }
+        for Sse41(a: __m128i) -> u32 {
+            Ops::<Sse41, i16>::popcount_total(a)
+        }
+        for Sse2(a: __m128i) -> u32 {
+            Ops::<Sse2, i16>::popcount_total(a)
+        }
+        for Scalar(a: u16) -> u32 {
+            a.count_ones()
+        }
+        for Neon(a: uint16x8_t) -> u32 {
+            Ops::<Neon, i16>::popcount_total(vreinterpretq_s16_u16(a))
+        }
+        for Wasm(a: v128) -> u32 {
+            Ops::<Wasm, i16>::popcount_total(a)
+        }
+    }
+}
@@ -0,0 +1,1144 @@
+use super::*;
+
+// Most unsigned 32 bit operations share their bit patterns with the signed versions, so on the
+// x86 and wasm backends (where both use the same register type) they forward to the i32 ops.
+// Ordering-sensitive operations flip the sign bit before using the signed comparisons.
+
+#[cfg(any(target_arch = "x86_64", target_arch = "x86"))]
+const SIGN_BIT: i32 = i32::MIN;
+
+impl_op! {
+    fn add<u32> {
+        for Avx512(a: __m512i, b: __m512i) -> __m512i {
+            Ops::<Avx512, i32>::add(a, b)
+        }
+        for Avx2(a: __m256i, b: __m256i) -> __m256i {
+            Ops::<Avx2, i32>::add(a, b)
+        }
+        for Sse41(a: __m128i, b: __m128i) -> __m128i {
+            Ops::<Sse41, i32>::add(a, b)
+        }
+        for Sse2(a: __m128i, b: __m128i) -> __m128i {
+            Ops::<Sse2, i32>::add(a, b)
+        }
+        for Scalar(a: u32, b: u32) -> u32 {
+            a.wrapping_add(b)
+        }
+        for Neon(a: uint32x4_t, b: uint32x4_t) -> uint32x4_t {
+            vaddq_u32(a, b)
+        }
+        for Wasm(a: v128, b: v128) -> v128 {
+            Ops::<Wasm, i32>::add(a, b)
+        }
+    }
+}
+
+impl_op! {
+    fn sub<u32> {
+        for Avx512(a: __m512i, b: __m512i) -> __m512i {
+            Ops::<Avx512, i32>::sub(a, b)
+        }
+        for Avx2(a: __m256i, b: __m256i) -> __m256i {
+            Ops::<Avx2, i32>::sub(a, b)
+        }
+        for Sse41(a: __m128i, b: __m128i) -> __m128i {
+            Ops::<Sse41, i32>::sub(a, b)
+        }
+        for Sse2(a: __m128i, b: __m128i) -> __m128i {
+            Ops::<Sse2, i32>::sub(a, b)
+        }
+        for Scalar(a: u32, b: u32) -> u32 {
+            a.wrapping_sub(b)
+        }
+        for Neon(a: uint32x4_t, b: uint32x4_t) -> uint32x4_t {
+            vsubq_u32(a, b)
+        }
+        for Wasm(a: v128, b: v128) -> v128 {
+            Ops::<Wasm, i32>::sub(a, b)
+        }
+    }
+}
+
+impl_op! {
+    fn mul<u32> {
+        for Avx512(a: __m512i, b: __m512i) -> __m512i {
+            Ops::<Avx512, i32>::mul(a, b)
+        }
+        for Avx2(a: __m256i, b: __m256i) -> __m256i {
+            Ops::<Avx2, i32>::mul(a, b)
+        }
+        for Sse41(a: __m128i, b: __m128i) -> __m128i {
+            Ops::<Sse41, i32>::mul(a, b)
+        }
+        for Sse2(a: __m128i, b: __m128i) -> __m128i {
+            Ops::<Sse2, i32>::mul(a, b)
+        }
+        for Scalar(a: u32, b: u32) -> u32 {
+            a.wrapping_mul(b)
+        }
+        for Neon(a: uint32x4_t, b: uint32x4_t) -> uint32x4_t {
+            vmulq_u32(a, b)
+        }
+        for Wasm(a: v128, b: v128) -> v128 {
+            Ops::<Wasm, i32>::mul(a, b)
+        }
+    }
+}
+
+impl_op! {
+    fn min<u32> {
+        for Avx512(a: __m512i, b: __m512i) -> __m512i {
+            _mm512_min_epu32(a, b)
+        }
+        for Avx2(a: __m256i, b: __m256i) -> __m256i {
+            _mm256_min_epu32(a, b)
+        }
+        for Sse41(a: __m128i, b: __m128i) -> __m128i {
+            _mm_min_epu32(a, b)
+        }
+        for Sse2(a: __m128i, b: __m128i) -> __m128i {
+            let sign = _mm_set1_epi32(SIGN_BIT);
+            let min = Ops::<Sse2, i32>::min(_mm_xor_si128(a, sign), _mm_xor_si128(b, sign));
+            _mm_xor_si128(min, sign)
+        }
+        for Scalar(a: u32, b: u32) -> u32 {
+            a.min(b)
+        }
+        for Neon(a: uint32x4_t, b: uint32x4_t) -> uint32x4_t {
+            vminq_u32(a, b)
+        }
+        for Wasm(a: v128, b: v128) -> v128 {
+            u32x4_min(a, b)
+        }
+    }
+}
+
+impl_op! {
+    fn max<u32> {
+        for Avx512(a: __m512i, b: __m512i) -> __m512i {
+            _mm512_max_epu32(a, b)
+        }
+        for Avx2(a: __m256i, b: __m256i) -> __m256i {
+            _mm256_max_epu32(a, b)
+        }
+        for Sse41(a: __m128i, b: __m128i) -> __m128i {
+            _mm_max_epu32(a, b)
+        }
+        for Sse2(a: __m128i, b: __m128i) -> __m128i {
+            let sign = _mm_set1_epi32(SIGN_BIT);
+            let max = Ops::<Sse2, i32>::max(_mm_xor_si128(a, sign), _mm_xor_si128(b, sign));
+            _mm_xor_si128(max, sign)
+        }
+        for Scalar(a: u32, b: u32) -> u32 {
+            a.max(b)
+        }
+        for Neon(a: uint32x4_t, b: uint32x4_t) -> uint32x4_t {
+            vmaxq_u32(a, b)
+        }
+        for Wasm(a: v128, b: v128) -> v128 {
+            u32x4_max(a, b)
+        }
+    }
+}
+
+impl_op! {
+    fn abs<u32> {
+        for Avx512(a: __m512i) -> __m512i {
+            a
+        }
+        for Avx2(a: __m256i) -> __m256i {
+            a
+        }
+        for Sse41(a: __m128i) -> __m128i {
+            a
+        }
+        for Sse2(a: __m128i) -> __m128i {
+            a
+        }
+        for Scalar(a: u32) -> u32 {
+            a
+        }
+        for Neon(a: uint32x4_t) -> uint32x4_t {
+            a
+        }
+        for Wasm(a: v128) -> v128 {
+            a
+        }
+    }
+}
+
+impl_op! {
+    fn eq<u32> {
+        for Avx512(a: __m512i, b: __m512i) -> __m512i {
+            Ops::<Avx512, i32>::eq(a, b)
+        }
+        for Avx2(a: __m256i, b: __m256i) -> __m256i {
+            Ops::<Avx2, i32>::eq(a, b)
+        }
+        for Sse41(a: __m128i, b: __m128i) -> __m128i {
+            Ops::<Sse41, i32>::eq(a, b)
+        }
+        for Sse2(a: __m128i, b: __m128i) -> __m128i {
+            Ops::<Sse2, i32>::eq(a, b)
+        }
+        for Scalar(a: u32, b: u32) -> u32 {
+            if a == b {
+                u32::MAX
+            } else {
+                0
+            }
+        }
+        for Neon(a: uint32x4_t, b: uint32x4_t) -> uint32x4_t {
+            vceqq_u32(a, b)
+        }
+        for Wasm(a: v128, b: v128) -> v128 {
+            Ops::<Wasm, i32>::eq(a, b)
+        }
+    }
+}
+
+impl_op! {
+    fn neq<u32> {
+        for Avx512(a: __m512i, b: __m512i) -> __m512i {
+            Ops::<Avx512, i32>::neq(a, b)
+        }
+        for Avx2(a: __m256i, b: __m256i) -> __m256i {
+            Ops::<Avx2, i32>::neq(a, b)
+        }
+        for Sse41(a: __m128i, b: __m128i) -> __m128i {
+            Ops::<Sse41, i32>::neq(a, b)
+        }
+        for Sse2(a: __m128i, b: __m128i) -> __m128i {
+            Ops::<Sse2, i32>::neq(a, b)
+        }
+        for Scalar(a: u32, b: u32) -> u32 {
+            if a != b {
+                u32::MAX
+            } else {
+                0
+            }
+        }
+        for Neon(a: uint32x4_t, b: uint32x4_t) -> uint32x4_t {
+            vmvnq_u32(vceqq_u32(a, b))
+        }
+        for Wasm(a: v128, b: v128) -> v128 {
+            Ops::<Wasm, i32>::neq(a, b)
+        }
+    }
+}
+
+impl_op! {
+    fn lt<u32> {
+        for Avx512(a: __m512i, b: __m512i) -> __m512i {
+            _mm512_movm_epi32(_mm512_cmplt_epu32_mask(a, b))
+        }
+        for Avx2(a: __m256i, b: __m256i) -> __m256i {
+            let sign = _mm256_set1_epi32(SIGN_BIT);
+            Ops::<Avx2, i32>::lt(_mm256_xor_si256(a, sign), _mm256_xor_si256(b, sign))
+        }
+        for Sse41(a: __m128i, b: __m128i) -> __m128i {
+            let sign = _mm_set1_epi32(SIGN_BIT);
+            Ops::<Sse41, i32>::lt(_mm_xor_si128(a, sign), _mm_xor_si128(b, sign))
+        }
+        for Sse2(a: __m128i, b: __m128i) -> __m128i {
+            let sign = _mm_set1_epi32(SIGN_BIT);
+            Ops::<Sse2, i32>::lt(_mm_xor_si128(a, sign), _mm_xor_si128(b, sign))
+        }
+        for Scalar(a: u32, b: u32) -> u32 {
+            if a < b {
+                u32::MAX
+            } else {
+                0
+            }
+        }
+        for Neon(a: uint32x4_t, b: uint32x4_t) -> uint32x4_t {
+            vcltq_u32(a, b)
+        }
+        for Wasm(a: v128, b: v128) -> v128 {
+            u32x4_lt(a, b)
+        }
+    }
+}
+
+impl_op! {
+    fn lte<u32> {
+        for Avx512(a: __m512i, b: __m512i) -> __m512i {
+            _mm512_movm_epi32(_mm512_cmple_epu32_mask(a, b))
+        }
+        for Avx2(a: __m256i, b: __m256i) -> __m256i {
+            let sign = _mm256_set1_epi32(SIGN_BIT);
+            Ops::<Avx2, i32>::lte(_mm256_xor_si256(a, sign), _mm256_xor_si256(b, sign))
+        }
+        for Sse41(a: __m128i, b: __m128i) -> __m128i {
+            let sign = _mm_set1_epi32(SIGN_BIT);
+            Ops::<Sse41, i32>::lte(_mm_xor_si128(a, sign), _mm_xor_si128(b, sign))
+        }
+        for Sse2(a: __m128i, b: __m128i) -> __m128i {
+            let sign = _mm_set1_epi32(SIGN_BIT);
+            Ops::<Sse2, i32>::lte(_mm_xor_si128(a, sign), _mm_xor_si128(b, sign))
+        }
+        for Scalar(a: u32, b: u32) -> u32 {
+            if a <= b {
+                u32::MAX
+            } else {
+                0
+            }
+        }
+        for Neon(a: uint32x4_t, b: uint32x4_t) -> uint32x4_t {
+            vcleq_u32(a, b)
+        }
+        for Wasm(a: v128, b: v128) -> v128 {
+            u32x4_le(a, b)
+        }
+    }
+}
+
+impl_op! {
+    fn gt<u32> {
+        for Avx512(a: __m512i, b: __m512i) -> __m512i {
+            _mm512_movm_epi32(_mm512_cmpgt_epu32_mask(a, b))
+        }
+        for Avx2(a: __m256i, b: __m256i) -> __m256i {
+            let sign = _mm256_set1_epi32(SIGN_BIT);
+            Ops::<Avx2, i32>::gt(_mm256_xor_si256(a, sign), _mm256_xor_si256(b, sign))
+        }
+        for Sse41(a: __m128i, b: __m128i) -> __m128i {
+            let sign = _mm_set1_epi32(SIGN_BIT);
+            Ops::<Sse41, i32>::gt(_mm_xor_si128(a, sign), _mm_xor_si128(b, sign))
+        }
+        for Sse2(a: __m128i, b: __m128i) -> __m128i {
+            let sign = _mm_set1_epi32(SIGN_BIT);
+            Ops::<Sse2, i32>::gt(_mm_xor_si128(a, sign), _mm_xor_si128(b, sign))
+        }
+        for Scalar(a: u32, b: u32) -> u32 {
+            if a > b {
+                u32::MAX
+            } else {
+                0
+            }
+        }
+        for Neon(a: uint32x4_t, b: uint32x4_t) -> uint32x4_t {
+            vcgtq_u32(a, b)
+        }
+        for Wasm(a: v128, b: v128) -> v128 {
+            u32x4_gt(a, b)
+        }
+    }
+}
+
+impl_op! {
+    fn gte<u32> {
+        for Avx512(a: __m512i, b: __m512i) -> __m512i {
+            _mm512_movm_epi32(_mm512_cmpge_epu32_mask(a, b))
+        }
+        for Avx2(a: __m256i, b: __m256i) -> __m256i {
+            let sign = _mm256_set1_epi32(SIGN_BIT);
+            Ops::<Avx2, i32>::gte(_mm256_xor_si256(a, sign), _mm256_xor_si256(b, sign))
+        }
+        for Sse41(a: __m128i, b: __m128i) -> __m128i {
+            let sign = _mm_set1_epi32(SIGN_BIT);
+            Ops::<Sse41, i32>::gte(_mm_xor_si128(a, sign), _mm_xor_si128(b, sign))
+        }
+        for Sse2(a: __m128i, b: __m128i) -> __m128i {
+            let sign = _mm_set1_epi32(SIGN_BIT);
+            Ops::<Sse2, i32>::gte(_mm_xor_si128(a, sign), _mm_xor_si128(b, sign))
+        }
+        for Scalar(a: u32, b: u32) -> u32 {
+            if a >= b {
+                u32::MAX
+            } else {
+                0
+            }
+        }
+        for Neon(a: uint32x4_t, b: uint32x4_t) -> uint32x4_t {
+            vcgeq_u32(a, b)
+        }
+        for Wasm(a: v128, b: v128) -> v128 {
+            u32x4_ge(a, b)
+        }
+    }
+}
+
+impl_op! {
+    fn blendv<u32> {
+        for Avx512(a: __m512i, b: __m512i, mask: __m512i) -> __m512i {
+            Ops::<Avx512, i32>::blendv(a, b, mask)
+        }
+        for Avx2(a: __m256i, b: __m256i, mask: __m256i) -> __m256i {
+            Ops::<Avx2, i32>::blendv(a, b, mask)
+        }
+        for Sse41(a: __m128i, b: __m128i, mask: __m128i) -> __m128i {
+            Ops::<Sse41, i32>::blendv(a, b, mask)
+        }
+        for Sse2(a: __m128i, b: __m128i, mask: __m128i) -> __m128i {
+            Ops::<Sse2, i32>::blendv(a, b, mask)
+        }
+        for Scalar(a: u32, b: u32, mask: u32) -> u32 {
+            if mask == 0 {
+                a
+            } else {
+                b
+            }
+        }
+        for Neon(a: uint32x4_t, b: uint32x4_t, mask: uint32x4_t) -> uint32x4_t {
+            vbslq_u32(mask, b, a)
+        }
+        for Wasm(a: v128, b: v128, mask: v128) -> v128 {
+            Ops::<Wasm, i32>::blendv(a, b, mask)
+        }
+    }
+}
+
+impl_op! {
+    fn shl<u32> {
+        for Avx512(a: __m512i, rhs: i32) -> __m512i {
+            Ops::<Avx512, i32>::shl(a, rhs)
+        }
+        for Avx2(a: __m256i, rhs: i32) -> __m256i {
+            Ops::<Avx2, i32>::shl(a, rhs)
+        }
+        for Sse41(a: __m128i, rhs: i32) -> __m128i {
+            Ops::<Sse41, i32>::shl(a, rhs)
+        }
+        for Sse2(a: __m128i, rhs: i32) -> __m128i {
+            Ops::<Sse2, i32>::shl(a, rhs)
+        }
+        for Scalar(a: u32, rhs: i32) -> u32 {
+            a.wrapping_shl((rhs & 31) as u32)
+        }
+        for Neon(a: uint32x4_t, rhs: i32) -> uint32x4_t {
+            vshlq_u32(a, Ops::<Neon, i32>::set1(rhs & 31))
+        }
+        for Wasm(a: v128, rhs: i32) -> v128 {
+            Ops::<Wasm, i32>::shl(a, rhs)
+        }
+    }
+}
+
+impl_op! {
+    fn shr<u32> {
+        for Avx512(a: __m512i, rhs: i32) -> __m512i {
+            Ops::<Avx512, i32>::shr(a, rhs)
+        }
+        for Avx2(a: __m256i, rhs: i32) -> __m256i {
+            Ops::<Avx2, i32>::shr(a, rhs)
+        }
+        for Sse41(a: __m128i, rhs: i32) -> __m128i {
+            Ops::<Sse41, i32>::shr(a, rhs)
+        }
+        for Sse2(a: __m128i, rhs: i32) -> __m128i {
+            Ops::<Sse2, i32>::shr(a, rhs)
+        }
+        for Scalar(a: u32, rhs: i32) -> u32 {
+            a.wrapping_shr((rhs & 31) as u32)
+        }
+        for Neon(a: uint32x4_t, rhs: i32) -> uint32x4_t {
+            vshlq_u32(a, Ops::<Neon, i32>::set1(-(rhs & 31)))
+        }
+        for Wasm(a: v128, rhs: i32) -> v128 {
+            Ops::<Wasm, i32>::shr(a, rhs)
+        }
+    }
+}
+
+impl_imm8_op! {
+    fn shl_const<u32, const BY: i32> {
+        for Avx512(a: __m512i) -> __m512i {
+            Ops::<Avx512, i32>::shl_const::<BY>(a)
+        }
+        for Avx2(a: __m256i) -> __m256i {
+            Ops::<Avx2, i32>::shl_const::<BY>(a)
+        }
+        for Sse41(a: __m128i) -> __m128i {
+            Ops::<Sse41, i32>::shl_const::<BY>(a)
+        }
+        for Sse2(a: __m128i) -> __m128i {
+            Ops::<Sse2, i32>::shl_const::<BY>(a)
+        }
+        for Scalar(a: u32) -> u32 {
+            a << BY
+        }
+        for Neon(a: uint32x4_t) -> uint32x4_t {
+            vshlq_n_u32(a, BY)
+        }
+        for Wasm(a: v128) -> v128 {
+            Ops::<Wasm, i32>::shl_const::<BY>(a)
+        }
+    }
+}
+
+impl_imm8_op! {
+    fn shr_const<u32, const BY: i32> {
+        for Avx512(a: __m512i) -> __m512i {
+            Ops::<Avx512, i32>::shr_const::<BY>(a)
+        }
+        for Avx2(a: __m256i) -> __m256i {
+            Ops::<Avx2, i32>::shr_const::<BY>(a)
+        }
+        for Sse41(a: __m128i) -> __m128i {
+            Ops::<Sse41, i32>::shr_const::<BY>(a)
+        }
+        for Sse2(a: __m128i) -> __m128i {
+            Ops::<Sse2, i32>::shr_const::<BY>(a)
+        }
+        for Scalar(a: u32) -> u32 {
+            a >> BY
+        }
+        for Neon(a: uint32x4_t) -> uint32x4_t {
+            vshrq_n_u32(a, BY)
+        }
+        for Wasm(a: v128) -> v128 {
+            Ops::<Wasm, i32>::shr_const::<BY>(a)
+        }
+    }
+}
+
+impl_op! {
+    fn extend_u64<u32> {
+        for Avx512(val: __m512i) -> (__m512i, __m512i) {
+            Ops::<Avx512, i32>::unsigned_extend_i64(val)
+        }
+        for Avx2(val: __m256i) -> (__m256i, __m256i) {
+            Ops::<Avx2, i32>::unsigned_extend_i64(val)
+        }
+        for Sse41(val: __m128i) -> (__m128i, __m128i) {
+            Ops::<Sse41, i32>::unsigned_extend_i64(val)
+        }
+        for Sse2(val: __m128i) -> (__m128i, __m128i) {
+            let zero = _mm_setzero_si128();
+            (_mm_unpacklo_epi32(val, zero), _mm_unpackhi_epi32(val, zero))
+        }
+        for Scalar(val: u32) -> (u64, u64) {
+            (val as u64, 0)
+        }
+        for Neon(val: uint32x4_t) -> (uint64x2_t, uint64x2_t) {
+            (vmovl_u32(vget_low_u32(val)), vmovl_u32(vget_high_u32(val)))
+        }
+        for Wasm(val: v128) -> (v128, v128) {
+            Ops::<Wasm, i32>::unsigned_extend_i64(val)
+        }
+    }
+}
+
+impl_op! {
+    fn zeroes<u32> {
+        for Avx512() -> __m512i {
+            _mm512_setzero_si512()
+        }
+        for Avx2() -> __m256i {
+            _mm256_setzero_si256()
+        }
+        for Sse41() -> __m128i {
+            _mm_setzero_si128()
+        }
+        for Sse2() -> __m128i {
+            _mm_setzero_si128()
+        }
+        for Scalar() -> u32 {
+            0
+        }
+        for Neon() -> uint32x4_t {
+            vdupq_n_u32(0)
+        }
+        for Wasm() -> v128 {
+            u32x4_splat(0)
+        }
+    }
+}
+
+impl_op! {
+    fn set1<u32> {
+        for Avx512(val: u32) -> __m512i {
+            _mm512_set1_epi32(val as i32)
+        }
+        for Avx2(val: u32) -> __m256i {
+            _mm256_set1_epi32(val as i32)
+        }
+        for Sse41(val: u32) -> __m128i {
+            _mm_set1_epi32(val as i32)
+        }
+        for Sse2(val: u32) -> __m128i {
+            _mm_set1_epi32(val as i32)
+        }
+        for Scalar(val: u32) -> u32 {
+            val
+        }
+        for Neon(val: u32) -> uint32x4_t {
+            vdupq_n_u32(val)
+        }
+        for Wasm(val: u32) -> v128 {
+            u32x4_splat(val)
+        }
+    }
+}
+
+impl_op! {
+    fn load_unaligned<u32> {
+        for Avx512(ptr: *const u32) -> __m512i {
+            _mm512_loadu_si512(ptr as *const __m512i)
+        }
+        for Avx2(ptr: *const u32) -> __m256i {
+            _mm256_loadu_si256(ptr as *const __m256i)
+        }
+        for Sse41(ptr: *const u32) -> __m128i {
+            _mm_loadu_si128(ptr as *const __m128i)
+        }
+        for Sse2(ptr: *const u32) -> __m128i {
+            _mm_loadu_si128(ptr as *const __m128i)
+        }
+        for Scalar(ptr: *const u32) -> u32 {
+            unsafe { *ptr }
+        }
+        for Neon(ptr: *const u32) -> uint32x4_t {
+            vld1q_u32(ptr)
+        }
+        for Wasm(ptr: *const u32) -> v128 {
+            unsafe { v128_load(ptr as *const v128) }
+        }
+    }
+}
+
+impl_op! {
+    fn load_aligned<u32> {
+        for Avx512(ptr: *const u32) -> __m512i {
+            _mm512_load_si512(ptr as *const __m512i)
+        }
+        for Avx2(ptr: *const u32) -> __m256i {
+            _mm256_load_si256(ptr as *const __m256i)
+        }
+        for Sse41(ptr: *const u32) -> __m128i {
+            _mm_load_si128(ptr as *const __m128i)
+        }
+        for Sse2(ptr: *const u32) -> __m128i {
+            _mm_load_si128(ptr as *const __m128i)
+        }
+        for Scalar(ptr: *const u32) -> u32 {
+            unsafe { *ptr }
+        }
+        for Neon(ptr: *const u32) -> uint32x4_t {
+            vld1q_u32(ptr)
+        }
+        for Wasm(ptr: *const u32) -> v128 {
+            *(ptr as *const v128)
+        }
+    }
+}
+
+impl_op! {
+    fn store_unaligned<u32> {
+        for Avx512(ptr: *mut u32, a: __m512i) {
+            _mm512_storeu_si512(ptr as *mut __m512i, a)
+        }
+        for Avx2(ptr: *mut u32, a: __m256i) {
+            _mm256_storeu_si256(ptr as *mut __m256i, a)
+        }
+        for Sse41(ptr: *mut u32, a: __m128i) {
+            _mm_storeu_si128(ptr as *mut __m128i, a)
+        }
+        for Sse2(ptr: *mut u32, a: __m128i) {
+            _mm_storeu_si128(ptr as *mut __m128i, a)
+        }
+        for Scalar(ptr: *mut u32, a: u32) {
+            unsafe { *ptr = a }
+        }
+        for Neon(ptr: *mut u32, a: uint32x4_t) {
+            vst1q_u32(ptr, a)
+        }
+        for Wasm(ptr: *mut u32, a: v128) {
+            unsafe { v128_store(ptr as *mut v128, a) }
+        }
+    }
+}
+
+impl_op! {
+    fn store_aligned<u32> {
+        for Avx512(ptr: *mut u32, a: __m512i) {
+            _mm512_store_si512(ptr as *mut __m512i, a)
+        }
+        for Avx2(ptr: *mut u32, a: __m256i) {
+            _mm256_store_si256(ptr as *mut __m256i, a)
+        }
+        for Sse41(ptr: *mut u32, a: __m128i) {
+            _mm_store_si128(ptr as *mut __m128i, a)
+        }
+        for Sse2(ptr: *mut u32, a: __m128i) {
+            _mm_store_si128(ptr as *mut __m128i, a)
+        }
+        for Scalar(ptr: *mut u32, a: u32) {
+            unsafe { *ptr = a }
+        }
+        for Neon(ptr: *mut u32, a: uint32x4_t) {
+            vst1q_u32(ptr, a)
+        }
+        for Wasm(ptr: *mut u32, a: v128) {
+            *(ptr as *mut v128) = a;
+        }
+    }
+}
+
+// An unsigned add overflowed exactly when the wrapped sum is below an input, and
+// `a - min(a, b)` is the clamped difference.
+impl_op! {
+    fn saturating_add<u32> {
+        for Avx512(a: __m512i, b: __m512i) -> __m512i {
+            let sum = _mm512_add_epi32(a, b);
+            _mm512_mask_mov_epi32(sum, _mm512_cmplt_epu32_mask(sum, a), _mm512_set1_epi32(-1))
+        }
+        for Avx2(a: __m256i, b: __m256i) -> __m256i {
+            let sum = _mm256_add_epi32(a, b);
+            _mm256_or_si256(sum, Ops::<Avx2, u32>::lt(sum, a))
+        }
+        for Sse41(a: __m128i, b: __m128i) -> __m128i {
+            let sum = _mm_add_epi32(a, b);
+            _mm_or_si128(sum, Ops::<Sse41, u32>::lt(sum, a))
+        }
+        for Sse2(a: __m128i, b: __m128i) -> __m128i {
+            let sum = _mm_add_epi32(a, b);
+            _mm_or_si128(sum, Ops::<Sse2, u32>::lt(sum, a))
+        }
+        for Scalar(a: u32, b: u32) -> u32 {
+            a.saturating_add(b)
+        }
+        for Neon(a: uint32x4_t, b: uint32x4_t) -> uint32x4_t {
+            vqaddq_u32(a, b)
+        }
+        for Wasm(a: v128, b: v128) -> v128 {
+            let sum = i32x4_add(a, b);
+            v128_or(sum, u32x4_lt(sum, a))
+        }
+    }
+}
+
+impl_op! {
+    fn saturating_sub<u32> {
+        for Avx512(a: __m512i, b: __m512i) -> __m512i {
+            _mm512_sub_epi32(a, _mm512_min_epu32(a, b))
+        }
+        for Avx2(a: __m256i, b: __m256i) -> __m256i {
+            _mm256_sub_epi32(a, _mm256_min_epu32(a, b))
+        }
+        for Sse41(a: __m128i, b: __m128i) -> __m128i {
+            _mm_sub_epi32(a, _mm_min_epu32(a, b))
+        }
+        for Sse2(a: __m128i, b: __m128i) -> __m128i {
+            _mm_sub_epi32(a, Ops::<Sse2, u32>::min(a, b))
+        }
+        for Scalar(a: u32, b: u32) -> u32 {
+            a.saturating_sub(b)
+        }
+        for Neon(a: uint32x4_t, b: uint32x4_t) -> uint32x4_t {
+            vqsubq_u32(a, b)
+        }
+        for Wasm(a: v128, b: v128) -> v128 {
+            i32x4_sub(a, u32x4_min(a, b))
+        }
+    }
+}
+
+impl_op! {
+    fn avg<u32> {
+        for Avx512(a: __m512i, b: __m512i) -> __m512i {
+            _mm512_sub_epi32(_mm512_or_si512(a, b), _mm512_srli_epi32(_mm512_xor_si512(a, b), 1))
+        }
+        for Avx2(a: __m256i, b: __m256i) -> __m256i {
+            _mm256_sub_epi32(_mm256_or_si256(a, b), _mm256_srli_epi32(_mm256_xor_si256(a, b), 1))
+        }
+        for Sse41(a: __m128i, b: __m128i) -> __m128i {
+            Ops::<Sse2, u32>::avg(a, b)
+        }
+        for Sse2(a: __m128i, b: __m128i) -> __m128i {
+            _mm_sub_epi32(_mm_or_si128(a, b), _mm_srli_epi32(_mm_xor_si128(a, b), 1))
+        }
+        for Scalar(a: u32, b: u32) -> u32 {
+            ((a as u64 + b as u64 + 1) >> 1) as u32
+        }
+        for Neon(a: uint32x4_t, b: uint32x4_t) -> uint32x4_t {
+            vrhaddq_u32(a, b)
+        }
+        for Wasm(a: v128, b: v128) -> v128 {
+            i32x4_sub(v128_or(a, b), u32x4_shr(v128_xor(a, b), 1))
+        }
+    }
+}
+
+impl_op! {
+    fn mul_hi<u32> {
+        for Avx512(a: __m512i, b: __m512i) -> __m512i {
+            let even = _mm512_mul_epu32(a, b);
+            let odd = _mm512_mul_epu32(_mm512_srli_epi64(a, 32), _mm512_srli_epi64(b, 32));
+            _mm512_mask_mov_epi32(_mm512_srli_epi64(even, 32), 0xAAAA, odd)
+        }
+        for Avx2(a: __m256i, b: __m256i) -> __m256i {
+            let even = _mm256_mul_epu32(a, b);
+            let odd = _mm256_mul_epu32(_mm256_srli_epi64(a, 32), _mm256_srli_epi64(b, 32));
+            _mm256_blend_epi32::<0b10101010>(_mm256_srli_epi64(even, 32), odd)
+        }
+        for Sse41(a: __m128i, b: __m128i) -> __m128i {
+            let even = _mm_mul_epu32(a, b);
+            let odd = _mm_mul_epu32(_mm_srli_epi64(a, 32), _mm_srli_epi64(b, 32));
+            _mm_blend_epi16::<0b11001100>(_mm_srli_epi64(even, 32), odd)
+        }
+        for Sse2(a: __m128i, b: __m128i) -> __m128i {
+            let even = _mm_mul_epu32(a, b);
+            let odd = _mm_mul_epu32(_mm_srli_epi64(a, 32), _mm_srli_epi64(b, 32));
+            let odd_lanes = _mm_set_epi32(-1, 0, -1, 0);
+            _mm_or_si128(_mm_srli_epi64(even, 32), _mm_and_si128(odd, odd_lanes))
+        }
+        for Scalar(a: u32, b: u32) -> u32 {
+            ((a as u64 * b as u64) >> 32) as u32
+        }
+        for Neon(a: uint32x4_t, b: uint32x4_t) -> uint32x4_t {
+            let low = vmull_u32(vget_low_u32(a), vget_low_u32(b));
+            let high = vmull_high_u32(a, b);
+            vcombine_u32(vshrn_n_u64::<32>(low), vshrn_n_u64::<32>(high))
+        }
+        for Wasm(a: v128, b: v128) -> v128 {
+            let low = u64x2_extmul_low_u32x4(a, b);
+            let high = u64x2_extmul_high_u32x4(a, b);
+            i32x4_shuffle::<1, 3, 5, 7>(low, high)
+        }
+    }
+}
+
+impl_op! {
+    fn shr_arithmetic<u32> {
+        for Avx512(a: __m512i, rhs: i32) -> __m512i {
+            Ops::<Avx512, i32>::shr_arithmetic(a, rhs)
+        }
+        for Avx2(a: __m256i, rhs: i32) -> __m256i {
+            Ops::<Avx2, i32>::shr_arithmetic(a, rhs)
+        }
+        for Sse41(a: __m128i, rhs: i32) -> __m128i {
+            Ops::<Sse41, i32>::shr_arithmetic(a, rhs)
+        }
+        for Sse2(a: __m128i, rhs: i32) -> __m128i {
+            Ops::<Sse2, i32>::shr_arithmetic(a, rhs)
+        }
+        for Scalar(a: u32, rhs: i32) -> u32 {
+            (a as i32).wrapping_shr(rhs as u32) as u32
+        }
+        for Neon(a: uint32x4_t, rhs: i32) -> uint32x4_t {
+            vreinterpretq_u32_s32(Ops::<Neon, i32>::shr_arithmetic(vreinterpretq_s32_u32(a), rhs))
+        }
+        for Wasm(a: v128, rhs: i32) -> v128 {
+            Ops::<Wasm, i32>::shr_arithmetic(a, rhs)
+        }
+    }
+}
+
+impl_op! {
+    fn shl_lanes<u32> {
+        for Avx512(a: __m512i, counts: __m512i) -> __m512i {
+            Ops::<Avx512, i32>::shl_lanes(a, counts)
+        }
+        for Avx2(a: __m256i, counts: __m256i) -> __m256i {
+            Ops::<Avx2, i32>::shl_lanes(a, counts)
+        }
+        for Sse41(a: __m128i, counts: __m128i) -> __m128i {
+            Ops::<Sse41, i32>::shl_lanes(a, counts)
+        }
+        for Sse2(a: __m128i, counts: __m128i) -> __m128i {
+            Ops::<Sse2, i32>::shl_lanes(a, counts)
+        }
+        for Scalar(a: u32, counts: u32) -> u32 {
+            a.wrapping_shl(counts as u32)
+        }
+        for Neon(a: uint32x4_t, counts: uint32x4_t) -> uint32x4_t {
+            let counts = vreinterpretq_s32_u32(counts);
+            vreinterpretq_u32_s32(Ops::<Neon, i32>::shl_lanes(vreinterpretq_s32_u32(a), counts))
+        }
+        for Wasm(a: v128, counts: v128) -> v128 {
+            Ops::<Wasm, i32>::shl_lanes(a, counts)
+        }
+    }
+}
+
+impl_op! {
+    fn shr_lanes<u32> {
+        for Avx512(a: __m512i, counts: __m512i) -> __m512i {
+            Ops::<Avx512, i32>::shr_lanes(a, counts)
+        }
+        for Avx2(a: __m256i, counts: __m256i) -> __m256i {
+            Ops::<Avx2, i32>::shr_lanes(a, counts)
+        }
+        for Sse41(a: __m128i, counts: __m128i) -> __m128i {
+            Ops::<Sse41, i32>::shr_lanes(a, counts)
+        }
+        for Sse2(a: __m128i, counts: __m128i) -> __m128i {
+            Ops::<Sse2, i32>::shr_lanes(a, counts)
+        }
+        for Scalar(a: u32, counts: u32) -> u32 {
+            a.wrapping_shr(counts as u32)
+        }
+        for Neon(a: uint32x4_t, counts: uint32x4_t) -> uint32x4_t {
+            let counts = vreinterpretq_s32_u32(counts);
+            vreinterpretq_u32_s32(Ops::<Neon, i32>::shr_lanes(vreinterpretq_s32_u32(a), counts))
+        }
+        for Wasm(a: v128, counts: v128) -> v128 {
+            Ops::<Wasm, i32>::shr_lanes(a, counts)
+        }
+    }
+}
+
+impl_op! {
+    fn shr_arithmetic_lanes<u32> {
+        for Avx512(a: __m512i, counts: __m512i) -> __m512i {
+            Ops::<Avx512, i32>::shr_arithmetic_lanes(a, counts)
+        }
+        for Avx2(a: __m256i, counts: __m256i) -> __m256i {
+            Ops::<Avx2, i32>::shr_arithmetic_lanes(a, counts)
+        }
+        for Sse41(a: __m128i, counts: __m128i) -> __m128i {
+            Ops::<Sse41, i32>::shr_arithmetic_lanes(a, counts)
+        }
+        for Sse2(a: __m128i, counts: __m128i) -> __m128i {
+            Ops::<Sse2, i32>::shr_arithmetic_lanes(a, counts)
+        }
+        for Scalar(a: u32, counts: u32) -> u32 {
+            (a as i32).wrapping_shr(counts as u32) as u32
+        }
+        for Neon(a: uint32x4_t, counts: uint32x4_t) -> uint32x4_t {
+            let counts = vreinterpretq_s32_u32(counts);
+            vreinterpretq_u32_s32(Ops::<Neon, i32>::shr_arithmetic_lanes(vreinterpretq_s32_u32(a), counts))
+        }
+        for Wasm(a: v128, counts: v128) -> v128 {
+            Ops::<Wasm, i32>::shr_arithmetic_lanes(a, counts)
+        }
+    }
+}
+
+impl_op! {
+    fn rotate_left<u32> {
+        for Avx512(a: __m512i, rhs: i32) -> __m512i {
+            Ops::<Avx512, i32>::rotate_left(a, rhs)
+        }
+        for Avx2(a: __m256i, rhs: i32) -> __m256i {
+            Ops::<Avx2, i32>::rotate_left(a, rhs)
+        }
+        for Sse41(a: __m128i, rhs: i32) -> __m128i {
+            Ops::<Sse41, i32>::rotate_left(a, rhs)
+        }
+        for Sse2(a: __m128i, rhs: i32) -> __m128i {
+            Ops::<Sse2, i32>::rotate_left(a, rhs)
+        }
+        for Scalar(a: u32, rhs: i32) -> u32 {
+            a.rotate_left(rhs as u32)
+        }
+        for Neon(a: uint32x4_t, rhs: i32) -> uint32x4_t {
+            vreinterpretq_u32_s32(Ops::<Neon, i32>::rotate_left(vreinterpretq_s32_u32(a), rhs))
+        }
+        for Wasm(a: v128, rhs: i32) -> v128 {
+            Ops::<Wasm, i32>::rotate_left(a, rhs)
+        }
+    }
+}
+
+impl_op! {
+    fn rotate_right<u32> {
+        for Avx512(a: __m512i, rhs: i32) -> __m512i {
+            Ops::<Avx512, i32>::rotate_right(a, rhs)
+        }
+        for Avx2(a: __m256i, rhs: i32) -> __m256i {
+            Ops::<Avx2, i32>::rotate_right(a, rhs)
+        }
+        for Sse41(a: __m128i, rhs: i32) -> __m128i {
+            Ops::<Sse41, i32>::rotate_right(a, rhs)
+        }
+        for Sse2(a: __m128i, rhs: i32) -> __m128i {
+            Ops::<Sse2, i32>::rotate_right(a, rhs)
+        }
+        for Scalar(a: u32, rhs: i32) -> u32 {
+            a.rotate_right(rhs as u32)
+        }
+        for Neon(a: uint32x4_t, rhs: i32) -> uint32x4_t {
+            vreinterpretq_u32_s32(Ops::<Neon, i32>::rotate_right(vreinterpretq_s32_u32(a), rhs))
+        }
+        for Wasm(a: v128, rhs: i32) -> v128 {
+            Ops::<Wasm, i32>::rotate_right(a, rhs)
+        }
+    }
+}
+
+impl_op! {
+    fn rotate_left_lanes<u32> {
+        for Avx512(a: __m512i, counts: __m512i) -> __m512i {
+            Ops::<Avx512, i32>::rotate_left_lanes(a, counts)
+        }
+        for Avx2(a: __m256i, counts: __m256i) -> __m256i {
+            Ops::<Avx2, i32>::rotate_left_lanes(a, counts)
+        }
+        for Sse41(a: __m128i, counts: __m128i) -> __m128i {
+            Ops::<Sse41, i32>::rotate_left_lanes(a, counts)
+        }
+        for Sse2(a: __m128i, counts: __m128i) -> __m128i {
+            Ops::<Sse2, i32>::rotate_left_lanes(a, counts)
+        }
+        for Scalar(a: u32, counts: u32) -> u32 {
+            a.rotate_left(counts as u32)
+        }
+        for Neon(a: uint32x4_t, counts: uint32x4_t) -> uint32x4_t {
+            let counts = vreinterpretq_s32_u32(counts);
+            vreinterpretq_u32_s32(Ops::<Neon, i32>::rotate_left_lanes(vreinterpretq_s32_u32(a), counts))
+        }
+        for Wasm(a: v128, counts: v128) -> v128 {
+            Ops::<Wasm, i32>::rotate_left_lanes(a, counts)
+        }
+    }
+}
+
+impl_op! {
+    fn rotate_right_lanes<u32> {
+        for Avx512(a: __m512i, counts: __m512i) -> __m512i {
+            Ops::<Avx512, i32>::rotate_right_lanes(a, counts)
+        }
+        for Avx2(a: __m256i, counts: __m256i) -> __m256i {
+            Ops::<Avx2, i32>::rotate_right_lanes(a, counts)
+        }
+        for Sse41(a: __m128i, counts: __m128i) -> __m128i {
+            Ops::<Sse41, i32>::rotate_right_lanes(a, counts)
+        }
+        for Sse2(a: __m128i, counts: __m128i) -> __m128i {
+            Ops::<Sse2, i32>::rotate_right_lanes(a, counts)
+        }
+        for Scalar(a: u32, counts: u32) -> u32 {
+            a.rotate_right(counts as u32)
+        }
+        for Neon(a: uint32x4_t, counts: uint32x4_t) -> uint32x4_t {
+            let counts = vreinterpretq_s32_u32(counts);
+            vreinterpretq_u32_s32(Ops::<Neon, i32>::rotate_right_lanes(vreinterpretq_s32_u32(a), counts))
+        }
+        for Wasm(a: v128, counts: v128) -> v128 {
+            Ops::<Wasm, i32>::rotate_right_lanes(a, counts)
+        }
+    }
+}
+
+impl_op! {
+    fn count_ones<u32> {
+        for Avx512(a: __m512i) -> __m512i {
+            Ops::<Avx512, i32>::count_ones(a)
+        }
+        for Avx2(a: __m256i) -> __m256i {
+            Ops::<Avx2, i32>::count_ones(a)
+        }
+        for Sse41(a: __m128i) -> __m128i {
+            Ops::<Sse41, i32>::count_ones(a)
+        }
+        for Sse2(a: __m128i) -> __m128i {
+            Ops::<Sse2, i32>::count_ones(a)
+        }
+        for Scalar(a: u32) -> u32 {
+            a.count_ones()
+        }
+        for Neon(a: uint32x4_t) -> uint32x4_t {
+            vreinterpretq_u32_s32(Ops::<Neon, i32>::count_ones(vreinterpretq_s32_u32(a)))
+        }
+        for Wasm(a: v128) -> v128 {
+            Ops::<Wasm, i32>::count_ones(a)
+        }
+    }
+}
+
+impl_op! {
+    fn leading_zeros<u32> {
+        for Avx512(a: __m512i) -> __m512i {
+            Ops::<Avx512, i32>::leading_zeros(a)
+        }
+        for Avx2(a: __m256i) -> __m256i {
+            Ops::<Avx2, i32>::leading_zeros(a)
+        }
+        for Sse41(a: __m128i) -> __m128i {
+            Ops::<Sse41, i32>::leading_zeros(a)
+        }
+        for Sse2(a: __m128i) -> __m128i {
+            Ops::<Sse2, i32>::leading_zeros(a)
+        }
+        for Scalar(a: u32) -> u32 {
+            a.leading_zeros()
+        }
+        for Neon(a: uint32x4_t) -> uint32x4_t {
+            vreinterpretq_u32_s32(Ops::<Neon, i32>::leading_zeros(vreinterpretq_s32_u32(a)))
+        }
+        for Wasm(a: v128) -> v128 {
+            Ops::<Wasm, i32>::leading_zeros(a)
+        }
+    }
+}
+
+impl_op! {
+    fn trailing_zeros<u32> {
+        for Avx512(a: __m512i) -> __m512i {
+            Ops::<Avx512, i32>::trailing_zeros(a)
+        }
+        for Avx2(a: __m256i) -> __m256i {
+            Ops::<Avx2, i32>::trailing_zeros(a)
+        }
+        for Sse41(a: __m128i) -> __m128i {
+            Ops::<Sse41, i32>::trailing_zeros(a)
+        }
+        for Sse2(a: __m128i) -> __m128i {
+            Ops::<Sse2, i32>::trailing_zeros(a)
+        }
+        for Scalar(a: u32) -> u32 {
+            a.trailing_zeros()
+        }
+        for Neon(a: uint32x4_t) -> uint32x4_t {
+            vreinterpretq_u32_s32(Ops::<Neon, i32>::trailing_zeros(vreinterpretq_s32_u32(a)))
+        }
+        for Wasm(a: v128) -> v128 {
+            Ops::<Wasm, i32>::trailing_zeros(a)
+        }
+    }
+}
+
+impl_op! {
+    fn reverse_bits<u32> {
+        for Avx512(a: __m512i) -> __m512i {
+            Ops::<Avx512, i32>::reverse_bits(a)
+        }
+        for Avx2(a: __m256i) -> __m256i {
+            Ops::<Avx2, i32>::reverse_bits(a)
+        }
+        for Sse41(a: __m128i) -> __m128i {
+            Ops::<Sse41, i32>::reverse_bits(a)
+        }
+        for Sse2(a: __m128i) -> __m128i {
+            Ops::<Sse2, i32>::reverse_bits(a)
+        }
+        for Scalar(a: u32) -> u32 {
+            a.reverse_bits()
+        }
+        for Neon(a: uint32x4_t) -> uint32x4_t {
+            vreinterpretq_u32_s32(Ops::<Neon, i32>::reverse_bits(vreinterpretq_s32_u32(a)))
+        }
+        for Wasm(a: v128) -> v128 {
+            Ops::<Wasm, i32>::reverse_bits(a)
+        }
+    }
+}
+
+impl_op! {
+    fn popcount_total<u32> {
+        for Avx512(a: __m512i) -> u32 {
+            Ops::<Avx512, i32>::popcount_total(a)
+        }
+        for Avx2(a: __m256i) -> u32 {
+            Ops::<Avx2, i32>::popcount_total(a)
+        }
+        for Sse41(a: __m128i) -> u32 {
+            Ops::<Sse41, i32>::popcount_total(a)
+        }
+        for Sse2(a: __m128i) -> u32 {
+            Ops::<Sse2, i32>::popcount_total(a)
+        }
+        for Scalar(a: u32) -> u32 {
+            a.count_ones()
+        }
+        for Neon(a: uint32x4_t) -> u32 {
+            Ops::<Neon, i32>::popcount_total(vreinterpretq_s32_u32(a))
+        }
+        for Wasm(a: v128) -> u32 {
+            Ops::<Wasm, i32>::popcount_total(a)
+        }
+    }
+}
@@ -0,0 +1,1094 @@
+use super::*;
+
+// Most unsigned 64 bit operations share their bit patterns with the signed versions, so on the
+// x86 and wasm backends (where both use the same register type) they forward to the i64 ops.
+// Ordering-sensitive operations flip the sign bit before using the signed comparisons.
+
+#[cfg(any(target_arch = "x86_64", target_arch = "x86"))]
+const SIGN_BIT: i64 = i64::MIN;
+
+impl_op! {
+    fn add<u64> {
+        for Avx512(a: __m512i, b: __m512i) -> __m512i {
+            Ops::<Avx512, i64>::add(a, b)
+        }
+        for Avx2(a: __m256i, b: __m256i) -> __m256i {
+            Ops::<Avx2, i64>::add(a, b)
+        }
+        for Sse41(a: __m128i, b: __m128i) -> __m128i {
+            Ops::<Sse41, i64>::add(a, b)
+        }
+        for Sse2(a: __m128i, b: __m128i) -> __m128i {
+            Ops::<Sse2, i64>::add(a, b)
+        }
+        for Scalar(a: u64, b: u64) -> u64 {
+            a.wrapping_add(b)
+        }
+        for Neon(a: uint64x2_t, b: uint64x2_t) -> uint64x2_t {
+            vaddq_u64(a, b)
+        }
+        for Wasm(a: v128, b: v128) -> v128 {
+            Ops::<Wasm, i64>::add(a, b)
+        }
+    }
+}
+
+impl_op! {
+    fn sub<u64> {
+        for Avx512(a: __m512i, b: __m512i) -> __m512i {
+            Ops::<Avx512, i64>::sub(a, b)
+        }
+        for Avx2(a: __m256i, b: __m256i) -> __m256i {
+            Ops::<Avx2, i64>::sub(a, b)
+        }
+        for Sse41(a: __m128i, b: __m128i) -> __m128i {
+            Ops::<Sse41, i64>::sub(a, b)
+        }
+        for Sse2(a: __m128i, b: __m128i) -> __m128i {
+            Ops::<Sse2, i64>::sub(a, b)
+        }
+        for Scalar(a: u64, b: u64) -> u64 {
+            a.wrapping_sub(b)
+        }
+        for Neon(a: uint64x2_t, b: uint64x2_t) -> uint64x2_t {
+            vsubq_u64(a, b)
+        }
+        for Wasm(a: v128, b: v128) -> v128 {
+            Ops::<Wasm, i64>::sub(a, b)
+        }
+    }
+}
+
+impl_op! {
+    fn mul<u64> {
+        for Avx512(a: __m512i, b: __m512i) -> __m512i {
+            Ops::<Avx512, i64>::mul(a, b)
+        }
+        for Avx2(a: __m256i, b: __m256i) -> __m256i {
+            Ops::<Avx2, i64>::mul(a, b)
+        }
+        for Sse41(a: __m128i, b: __m128i) -> __m128i {
+            Ops::<Sse41, i64>::mul(a, b)
+        }
+        for Sse2(a: __m128i, b: __m128i) -> __m128i {
+            Ops::<Sse2, i64>::mul(a, b)
+        }
+        for Scalar(a: u64, b: u64) -> u64 {
+            a.wrapping_mul(b)
+        }
+        for Neon(a: uint64x2_t, b: uint64x2_t) -> uint64x2_t {
+            let product = Ops::<Neon, i64>::mul(vreinterpretq_s64_u64(a), vreinterpretq_s64_u64(b));
+            vreinterpretq_u64_s64(product)
+        }
+        for Wasm(a: v128, b: v128) -> v128 {
+            Ops::<Wasm, i64>::mul(a, b)
+        }
+    }
+}
+
+impl_op! {
+    fn min<u64> {
+        for Avx512(a: __m512i, b: __m512i) -> __m512i {
+            _mm512_min_epu64(a, b)
+        }
+        for Avx2(a: __m256i, b: __m256i) -> __m256i {
+            let sign = _mm256_set1_epi64x(SIGN_BIT);
+            let min = Ops::<Avx2, i64>::min(_mm256_xor_si256(a, sign), _mm256_xor_si256(b, sign));
+            _mm256_xor_si256(min, sign)
+        }
+        for Sse41(a: __m128i, b: __m128i) -> __m128i {
+            let sign = _mm_set1_epi64x(SIGN_BIT);
+            let min = Ops::<Sse41, i64>::min(_mm_xor_si128(a, sign), _mm_xor_si128(b, sign));
+            _mm_xor_si128(min, sign)
+        }
+        for Sse2(a: __m128i, b: __m128i) -> __m128i {
+            let sign = _mm_set1_epi64x(SIGN_BIT);
+            let min = Ops::<Sse2, i64>::min(_mm_xor_si128(a, sign), _mm_xor_si128(b, sign));
+            _mm_xor_si128(min, sign)
+        }
+        for Scalar(a: u64, b: u64) -> u64 {
+            a.min(b)
+        }
+        for Neon(a: uint64x2_t, b: uint64x2_t) -> uint64x2_t {
+            vbslq_u64(vcgtq_u64(a, b), b, a)
+        }
+        for Wasm(a: v128, b: v128) -> v128 {
+            let sign = i64x2_splat(i64::MIN);
+            let min = Ops::<Wasm, i64>::min(v128_xor(a, sign), v128_xor(b, sign));
+            v128_xor(min, sign)
+        }
+    }
+}
+
+impl_op! {
+    fn max<u64> {
+        for Avx512(a: __m512i, b: __m512i) -> __m512i {
+            _mm512_max_epu64(a, b)
+        }
+        for Avx2(a: __m256i, b: __m256i) -> __m256i {
+            let sign = _mm256_set1_epi64x(SIGN_BIT);
+            let max = Ops::<Avx2, i64>::max(_mm256_xor_si256(a, sign), _mm256_xor_si256(b, sign));
+            _mm256_xor_si256(max, sign)
+        }
+        for Sse41(a: __m128i, b: __m128i) -> __m128i {
+            let sign = _mm_set1_epi64x(SIGN_BIT);
+            let max = Ops::<Sse41, i64>::max(_mm_xor_si128(a, sign), _mm_xor_si128(b, sign));
+            _mm_xor_si128(max, sign)
+        }
+        for Sse2(a: __m128i, b: __m128i) -> __m128i {
+            let sign = _mm_set1_epi64x(SIGN_BIT);
+            let max = Ops::<Sse2, i64>::max(_mm_xor_si128(a, sign), _mm_xor_si128(b, sign));
+            _mm_xor_si128(max, sign)
+        }
+        for Scalar(a: u64, b: u64) -> u64 {
+            a.max(b)
+        }
+        for Neon(a: uint64x2_t, b: uint64x2_t) -> uint64x2_t {
+            vbslq_u64(vcgtq_u64(a, b), a, b)
+        }
+        for Wasm(a: v128, b: v128) -> v128 {
+            let sign = i64x2_splat(i64::MIN);
+            let max = Ops::<Wasm, i64>::max(v128_xor(a, sign), v128_xor(b, sign));
+            v128_xor(max, sign)
+        }
+    }
+}
+
+impl_op! {
+    fn abs<u64> {
+        for Avx512(a: __m512i) -> __m512i {
+            a
+        }
+        for Avx2(a: __m256i) -> __m256i {
+            a
+        }
+        for Sse41(a: __m128i) -> __m128i {
+            a
+        }
+        for Sse2(a: __m128i) -> __m128i {
+            a
+        }
+        for Scalar(a: u64) -> u64 {
+            a
+        }
+        for Neon(a: uint64x2_t) -> uint64x2_t {
+            a
+        }
+        for Wasm(a: v128) -> v128 {
+            a
+        }
+    }
+}
+
+impl_op! {
+    fn eq<u64> {
+        for Avx512(a: __m512i, b: __m512i) -> __m512i {
+            Ops::<Avx512, i64>::eq(a, b)
+        }
+        for Avx2(a: __m256i, b: __m256i) -> __m256i {
+            Ops::<Avx2, i64>::eq(a, b)
+        }
+        for Sse41(a: __m128i, b: __m128i) -> __m128i {
+            Ops::<Sse41, i64>::eq(a, b)
+        }
+        for Sse2(a: __m128i, b: __m128i) -> __m128i {
+            Ops::<Sse2, i64>::eq(a, b)
+        }
+        for Scalar(a: u64, b: u64) -> u64 {
+            if a == b {
+                u64::MAX
+            } else {
+                0
+            }
+        }
+        for Neon(a: uint64x2_t, b: uint64x2_t) -> uint64x2_t {
+            vceqq_u64(a, b)
+        }
+        for Wasm(a: v128, b: v128) -> v128 {
+            Ops::<Wasm, i64>::eq(a, b)
+        }
+    }
+}
+
+impl_op! {
+    fn neq<u64> {
+        for Avx512(a: __m512i, b: __m512i) -> __m512i {
+            Ops::<Avx512, i64>::neq(a, b)
+        }
+        for Avx2(a: __m256i, b: __m256i) -> __m256i {
+            Ops::<Avx2, i64>::neq(a, b)
+        }
+        for Sse41(a: __m128i, b: __m128i) -> __m128i {
+            Ops::<Sse41, i64>::neq(a, b)
+        }
+        for Sse2(a: __m128i, b: __m128i) -> __m128i {
+            Ops::<Sse2, i64>::neq(a, b)
+        }
+        for Scalar(a: u64, b: u64) -> u64 {
+            if a != b {
+                u64::MAX
+            } else {
+                0
+            }
+        }
+        for Neon(a: uint64x2_t, b: uint64x2_t) -> uint64x2_t {
+            vreinterpretq_u64_u32(vmvnq_u32(vreinterpretq_u32_u64(vceqq_u64(a, b))))
+        }
+        for Wasm(a: v128, b: v128) -> v128 {
+            Ops::<Wasm, i64>::neq(a, b)
+        }
+    }
+}
+
+impl_op! {
+    fn lt<u64> {
+        for Avx512(a: __m512i, b: __m512i) -> __m512i {
+            _mm512_movm_epi64(_mm512_cmplt_epu64_mask(a, b))
+        }
+        for Avx2(a: __m256i, b: __m256i) -> __m256i {
+            let sign = _mm256_set1_epi64x(SIGN_BIT);
+            Ops::<Avx2, i64>::lt(_mm256_xor_si256(a, sign), _mm256_xor_si256(b, sign))
+        }
+        for Sse41(a: __m128i, b: __m128i) -> __m128i {
+            let sign = _mm_set1_epi64x(SIGN_BIT);
+            Ops::<Sse41, i64>::lt(_mm_xor_si128(a, sign), _mm_xor_si128(b, sign))
+        }
+        for Sse2(a: __m128i, b: __m128i) -> __m128i {
+            let sign = _mm_set1_epi64x(SIGN_BIT);
+            Ops::<Sse2, i64>::lt(_mm_xor_si128(a, sign), _mm_xor_si128(b, sign))
+        }
+        for Scalar(a: u64, b: u64) -> u64 {
+            if a < b {
+                u64::MAX
+            } else {
+                0
+            }
+        }
+        for Neon(a: uint64x2_t, b: uint64x2_t) -> uint64x2_t {
+            vcltq_u64(a, b)
+        }
+        for Wasm(a: v128, b: v128) -> v128 {
+            let sign = i64x2_splat(i64::MIN);
+            Ops::<Wasm, i64>::lt(v128_xor(a, sign), v128_xor(b, sign))
+        }
+    }
+}
+
+impl_op! {
+    fn lte<u64> {
+        for Avx512(a: __m512i, b: __m512i) -> __m512i {
+            _mm512_movm_epi64(_mm512_cmple_epu64_mask(a, b))
+        }
+        for Avx2(a: __m256i, b: __m256i) -> __m256i {
+            let sign = _mm256_set1_epi64x(SIGN_BIT);
+            Ops::<Avx2, i64>::lte(_mm256_xor_si256(a, sign), _mm256_xor_si256(b, sign))
+        }
+        for Sse41(a: __m128i, b: __m128i) -> __m128i {
+            let sign = _mm_set1_epi64x(SIGN_BIT);
+            Ops::<Sse41, i64>::lte(_mm_xor_si128(a, sign), _mm_xor_si128(b, sign))
+        }
+        for Sse2(a: __m128i, b: __m128i) -> __m128i {
+            let sign = _mm_set1_epi64x(SIGN_BIT);
+            Ops::<Sse2, i64>::lte(_mm_xor_si128(a, sign), _mm_xor_si128(b, sign))
+        }
+        for Scalar(a: u64, b: u64) -> u64 {
+            if a <= b {
+                u64::MAX
+            } else {
+                0
+            }
+        }
+        for Neon(a: uint64x2_t, b: uint64x2_t) -> uint64x2_t {
+            vcleq_u64(a, b)
+        }
+        for Wasm(a: v128, b: v128) -> v128 {
+            let sign = i64x2_splat(i64::MIN);
+            Ops::<Wasm, i64>::lte(v128_xor(a, sign), v128_xor(b, sign))
+        }
+    }
+}
+
+impl_op! {
+    fn gt<u64> {
+        for Avx512(a: __m512i, b: __m512i) -> __m512i {
+            _mm512_movm_epi64(_mm512_cmpgt_epu64_mask(a, b))
+        }
+        for Avx2(a: __m256i, b: __m256i) -> __m256i {
+            let sign = _mm256_set1_epi64x(SIGN_BIT);
+            Ops::<Avx2, i64>::gt(_mm256_xor_si256(a, sign), _mm256_xor_si256(b, sign))
+        }
+        for Sse41(a: __m128i, b: __m128i) -> __m128i {
+            let sign = _mm_set1_epi64x(SIGN_BIT);
+            Ops::<Sse41, i64>::gt(_mm_xor_si128(a, sign), _mm_xor_si128(b, sign))
+        }
+        for Sse2(a: __m128i, b: __m128i) -> __m128i {
+            let sign = _mm_set1_epi64x(SIGN_BIT);
+            Ops::<Sse2, i64>::gt(_mm_xor_si128(a, sign), _mm_xor_si128(b, sign))
+        }
+        for Scalar(a: u64, b: u64) -> u64 {
+            if a > b {
+                u64::MAX
+            } else {
+                0
+            }
+        }
+        for Neon(a: uint64x2_t, b: uint64x2_t) -> uint64x2_t {
+            vcgtq_u64(a, b)
+        }
+        for Wasm(a: v128, b: v128) -> v128 {
+            let sign = i64x2_splat(i64::MIN);
+            Ops::<Wasm, i64>::gt(v128_xor(a, sign), v128_xor(b, sign))
+        }
+    }
+}
+
+impl_op! {
+    fn gte<u64> {
+        for Avx512(a: __m512i, b: __m512i) -> __m512i {
+            _mm512_movm_epi64(_mm512_cmpge_epu64_mask(a, b))
+        }
+        for Avx2(a: __m256i, b: __m256i) -> __m256i {
+            let sign = _mm256_set1_epi64x(SIGN_BIT);
+            Ops::<Avx2, i64>::gte(_mm256_xor_si256(a, sign), _mm256_xor_si256(b, sign))
+        }
+        for Sse41(a: __m128i, b: __m128i) -> __m128i {
+            let sign = _mm_set1_epi64x(SIGN_BIT);
+            Ops::<Sse41, i64>::gte(_mm_xor_si128(a, sign), _mm_xor_si128(b, sign))
+        }
+        for Sse2(a: __m128i, b: __m128i) -> __m128i {
+            let sign = _mm_set1_epi64x(SIGN_BIT);
+            Ops::<Sse2, i64>::gte(_mm_xor_si128(a, sign), _mm_xor_si128(b, sign))
+        }
+        for Scalar(a: u64, b: u64) -> u64 {
+            if a >= b {
+                u64::MAX
+            } else {
+                0
+            }
+        }
+        for Neon(a: uint64x2_t, b: uint64x2_t) -> uint64x2_t {
+            vcgeq_u64(a, b)
+        }
+        for Wasm(a: v128, b: v128) -> v128 {
+            let sign = i64x2_splat(i64::MIN);
+            Ops::<Wasm, i64>::gte(v128_xor(a, sign), v128_xor(b, sign))
+        }
+    }
+}
+
+impl_op! {
+    fn blendv<u64> {
+        for Avx512(a: __m512i, b: __m512i, mask: __m512i) -> __m512i {
+            Ops::<Avx512, i64>::blendv(a, b, mask)
+        }
+        for Avx2(a: __m256i, b: __m256i, mask: __m256i) -> __m256i {
+            Ops::<Avx2, i64>::blendv(a, b, mask)
+        }
+        for Sse41(a: __m128i, b: __m128i, mask: __m128i) -> __m128i {
+            Ops::<Sse41, i64>::blendv(a, b, mask)
+        }
+        for Sse2(a: __m128i, b: __m128i, mask: __m128i) -> __m128i {
+            Ops::<Sse2, i64>::blendv(a, b, mask)
+        }
+        for Scalar(a: u64, b: u64, mask: u64) -> u64 {
+            if mask == 0 {
+                a
+            } else {
+                b
+            }
+        }
+        for Neon(a: uint64x2_t, b: uint64x2_t, mask: uint64x2_t) -> uint64x2_t {
+            vbslq_u64(mask, b, a)
+        }
+        for Wasm(a: v128, b: v128, mask: v128) -> v128 {
+            Ops::<Wasm, i64>::blendv(a, b, mask)
+        }
+    }
+}
+
+impl_op! {
+    fn shl<u64> {
+        for Avx512(a: __m512i, rhs: i32) -> __m512i {
+            Ops::<Avx512, i64>::shl(a, rhs)
+        }
+        for Avx2(a: __m256i, rhs: i32) -> __m256i {
+            Ops::<Avx2, i64>::shl(a, rhs)
+        }
+        for Sse41(a: __m128i, rhs: i32) -> __m128i {
+            Ops::<Sse41, i64>::shl(a, rhs)
+        }
+        for Sse2(a: __m128i, rhs: i32) -> __m128i {
+            Ops::<Sse2, i64>::shl(a, rhs)
+        }
+        for Scalar(a: u64, rhs: i32) -> u64 {
+            a.wrapping_shl((rhs & 63) as u32)
+        }
+        for Neon(a: uint64x2_t, rhs: i32) -> uint64x2_t {
+            vshlq_u64(a, Ops::<Neon, i64>::set1((rhs & 63) as i64))
+        }
+        for Wasm(a: v128, rhs: i32) -> v128 {
+            Ops::<Wasm, i64>::shl(a, rhs)
+        }
+    }
+}
+
+impl_op! {
+    fn shr<u64> {
+        for Avx512(a: __m512i, rhs: i32) -> __m512i {
+            Ops::<Avx512, i64>::shr(a, rhs)
+        }
+        for Avx2(a: __m256i, rhs: i32) -> __m256i {
+            Ops::<Avx2, i64>::shr(a, rhs)
+        }
+        for Sse41(a: __m128i, rhs: i32) -> __m128i {
+            Ops::<Sse41, i64>::shr(a, rhs)
+        }
+        for Sse2(a: __m128i, rhs: i32) -> __m128i {
+            Ops::<Sse2, i64>::shr(a, rhs)
+        }
+        for Scalar(a: u64, rhs: i32) -> u64 {
+            a.wrapping_shr((rhs & 63) as u32)
+        }
+        for Neon(a: uint64x2_t, rhs: i32) -> uint64x2_t {
+            vshlq_u64(a, Ops::<Neon, i64>::set1(-(rhs & 63) as i64))
+        }
+        for Wasm(a: v128, rhs: i32) -> v128 {
+            Ops::<Wasm, i64>::shr(a, rhs)
+        }
+    }
+}
+
+impl_imm8_op! {
+    fn shl_const<u64, const BY: i32> {
+        for Avx512(a: __m512i) -> __m512i {
+            Ops::<Avx512, i64>::shl_const::<BY>(a)
+        }
+        for Avx2(a: __m256i) -> __m256i {
+            Ops::<Avx2, i64>::shl_const::<BY>(a)
+        }
+        for Sse41(a: __m128i) -> __m128i {
+            Ops::<Sse41, i64>::shl_const::<BY>(a)
+        }
+        for Sse2(a: __m128i) -> __m128i {
+            Ops::<Sse2, i64>::shl_const::<BY>(a)
+        }
+        for Scalar(a: u64) -> u64 {
+            a << BY
+        }
+        for Neon(a: uint64x2_t) -> uint64x2_t {
+            vshlq_n_u64(a, BY)
+        }
+        for Wasm(a: v128) -> v128 {
+            Ops::<Wasm, i64>::shl_const::<BY>(a)
+        }
+    }
+}
+
+impl_imm8_op! {
+    fn shr_const<u64, const BY: i32> {
+        for Avx512(a: __m512i) -> __m512i {
+            Ops::<Avx512, i64>::shr_const::<BY>(a)
+        }
+        for Avx2(a: __m256i) -> __m256i {
+            Ops::<Avx2, i64>::shr_const::<BY>(a)
+        }
+        for Sse41(a: __m128i) -> __m128i {
+            Ops::<Sse41, i64>::shr_const::<BY>(a)
+        }
+        for Sse2(a: __m128i) -> __m128i {
+            Ops::<Sse2, i64>::shr_const::<BY>(a)
+        }
+        for Scalar(a: u64) -> u64 {
+            a >> BY
+        }
+        for Neon(a: uint64x2_t) -> uint64x2_t {
+            vshrq_n_u64(a, BY)
+        }
+        for Wasm(a: v128) -> v128 {
+            Ops::<Wasm, i64>::shr_const::<BY>(a)
+        }
+    }
+}
+
+impl_op! {
+    fn zeroes<u64> {
+        for Avx512() -> __m512i {
+            _mm512_setzero_si512()
+        }
+        for Avx2() -> __m256i {
+            _mm256_setzero_si256()
+        }
+        for Sse41() -> __m128i {
+            _mm_setzero_si128()
+        }
+        for Sse2() -> __m128i {
+            _mm_setzero_si128()
+        }
+        for Scalar() -> u64 {
+            0
+        }
+        for Neon() -> uint64x2_t {
+            vdupq_n_u64(0)
+        }
+        for Wasm() -> v128 {
+            u64x2_splat(0)
+        }
+    }
+}
+
+impl_op! {
+    fn set1<u64> {
+        for Avx512(val: u64) -> __m512i {
+            _mm512_set1_epi64(val as i64)
+        }
+        for Avx2(val: u64) -> __m256i {
+            _mm256_set1_epi64x(val as i64)
+        }
+        for Sse41(val: u64) -> __m128i {
+            _mm_set1_epi64x(val as i64)
+        }
+        for Sse2(val: u64) -> __m128i {
+            _mm_set1_epi64x(val as i64)
+        }
+        for Scalar(val: u64) -> u64 {
+            val
+        }
+        for Neon(val: u64) -> uint64x2_t {
+            vdupq_n_u64(val)
+        }
+        for Wasm(val: u64) -> v128 {
+            u64x2_splat(val)
+        }
+    }
+}
+
+impl_op! {
+    fn load_unaligned<u64> {
+        for Avx512(ptr: *const u64) -> __m512i {
+            _mm512_loadu_si512(ptr as *const __m512i)
+        }
+        for Avx2(ptr: *const u64) -> __m256i {
+            _mm256_loadu_si256(ptr as *const __m256i)
+        }
+        for Sse41(ptr: *const u64) -> __m128i {
+            _mm_loadu_si128(ptr as *const __m128i)
+        }
+        for Sse2(ptr: *const u64) -> __m128i {
+            _mm_loadu_si128(ptr as *const __m128i)
+        }
+        for Scalar(ptr: *const u64) -> u64 {
+            unsafe { *ptr }
+        }
+        for Neon(ptr: *const u64) -> uint64x2_t {
+            vld1q_u64(ptr)
+        }
+        for Wasm(ptr: *const u64) -> v128 {
+            unsafe { v128_load(ptr as *const v128) }
+        }
+    }
+}
+
+impl_op! {
+    fn load_aligned<u64> {
+        for Avx512(ptr: *const u64) -> __m512i {
+            _mm512_load_si512(ptr as *const __m512i)
+        }
+        for Avx2(ptr: *const u64) -> __m256i {
+            _mm256_load_si256(ptr as *const __m256i)
+        }
+        for Sse41(ptr: *const u64) -> __m128i {
+            _mm_load_si128(ptr as *const __m128i)
+        }
+        for Sse2(ptr: *const u64) -> __m128i {
+            _mm_load_si128(ptr as *const __m128i)
+        }
+        for Scalar(ptr: *const u64) -> u64 {
+            unsafe { *ptr }
+        }
+        for Neon(ptr: *const u64) -> uint64x2_t {
+            vld1q_u64(ptr)
+        }
+        for Wasm(ptr: *const u64) -> v128 {
+            *(ptr as *const v128)
+        }
+    }
+}
+
+impl_op! {
+    fn store_unaligned<u64> {
+        for Avx512(ptr: *mut u64, a: __m512i) {
+            _mm512_storeu_si512(ptr as *mut __m512i, a)
+        }
+        for Avx2(ptr: *mut u64, a: __m256i) {
+            _mm256_storeu_si256(ptr as *mut __m256i, a)
+        }
+        for Sse41(ptr: *mut u64, a: __m128i) {
+            _mm_storeu_si128(ptr as *mut __m128i, a)
+        }
+        for Sse2(ptr: *mut u64, a: __m128i) {
+            _mm_storeu_si128(ptr as *mut __m128i, a)
+        }
+        for Scalar(ptr: *mut u64, a: u64) {
+            unsafe { *ptr = a }
+        }
+        for Neon(ptr: *mut u64, a: uint64x2_t) {
+            vst1q_u64(ptr, a)
+        }
+        for Wasm(ptr: *mut u64, a: v128) {
+            unsafe { v128_store(ptr as *mut v128, a) }
+        }
+    }
+}
+
+impl_op! {
+    fn store_aligned<u64> {
+        for Avx512(ptr: *mut u64, a: __m512i) {
+            _mm512_store_si512(ptr as *mut __m512i, a)
+        }
+        for Avx2(ptr: *mut u64, a: __m256i) {
+            _mm256_store_si256(ptr as *mut __m256i, a)
+        }
+        for Sse41(ptr: *mut u64, a: __m128i) {
+            _mm_store_si128(ptr as *mut __m128i, a)
+        }
+        for Sse2(ptr: *mut u64, a: __m128i) {
+            _mm_store_si128(ptr as *mut __m128i, a)
+        }
+        for Scalar(ptr: *mut u64, a: u64) {
+            unsafe { *ptr = a }
+        }
+        for Neon(ptr: *mut u64, a: uint64x2_t) {
+            vst1q_u64(ptr, a)
+        }
+        for Wasm(ptr: *mut u64, a: v128) {
+            *(ptr as *mut v128) = a;
+        }
+    }
+}
+
+// Same as the u32 versions.
+impl_op! {
+    fn saturating_add<u64> {
+        for Avx512(a: __m512i, b: __m512i) -> __m512i {
+            let sum = _mm512_add_epi64(a, b);
+            _mm512_mask_mov_epi64(sum, _mm512_cmplt_epu64_mask(sum, a), _mm512_set1_epi64(-1))
+        }
+        for Avx2(a: __m256i, b: __m256i) -> __m256i {
+            let sum = _mm256_add_epi64(a, b);
+            _mm256_or_si256(sum, Ops::<Avx2, u64>::lt(sum, a))
+        }
+        for Sse41(a: __m128i, b: __m128i) -> __m128i {
+            let sum = _mm_add_epi64(a, b);
+            _mm_or_si128(sum, Ops::<Sse41, u64>::lt(sum, a))
+        }
+        for Sse2(a: __m128i, b: __m128i) -> __m128i {
+            let sum = _mm_add_epi64(a, b);
+            _mm_or_si128(sum, Ops::<Sse2, u64>::lt(sum, a))
+        }
+        for Scalar(a: u64, b: u64) -> u64 {
+            a.saturating_add(b)
+        }
+        for Neon(a: uint64x2_t, b: uint64x2_t) -> uint64x2_t {
+            vqaddq_u64(a, b)
+        }
+        for Wasm(a: v128, b: v128) -> v128 {
+            let sum = i64x2_add(a, b);
+            v128_or(sum, Ops::<Wasm, u64>::lt(sum, a))
+        }
+    }
+}
+
+impl_op! {
+    fn saturating_sub<u64> {
+        for Avx512(a: __m512i, b: __m512i) -> __m512i {
+            _mm512_sub_epi64(a, _mm512_min_epu64(a, b))
+        }
+        for Avx2(a: __m256i, b: __m256i) -> __m256i {
+            _mm256_sub_epi64(a, Ops::<Avx2, u64>::min(a, b))
+        }
+        for Sse41(a: __m128i, b: __m128i) -> __m128i {
+            _mm_sub_epi64(a, Ops::<Sse41, u64>::min(a, b))
+        }
+        for Sse2(a: __m128i, b: __m128i) -> __m128i {
+            _mm_sub_epi64(a, Ops::<Sse2, u64>::min(a, b))
+        }
+        for Scalar(a: u64, b: u64) -> u64 {
+            a.saturating_sub(b)
+        }
+        for Neon(a: uint64x2_t, b: uint64x2_t) -> uint64x2_t {
+            vqsubq_u64(a, b)
+        }
+        for Wasm(a: v128, b: v128) -> v128 {
+            i64x2_sub(a, Ops::<Wasm, u64>::min(a, b))
+        }
+    }
+}
+
+impl_op! {
+    fn avg<u64> {
+        for Avx512(a: __m512i, b: __m512i) -> __m512i {
+            _mm512_sub_epi64(_mm512_or_si512(a, b), _mm512_srli_epi64(_mm512_xor_si512(a, b), 1))
+        }
+        for Avx2(a: __m256i, b: __m256i) -> __m256i {
+            _mm256_sub_epi64(_mm256_or_si256(a, b), _mm256_srli_epi64(_mm256_xor_si256(a, b), 1))
+        }
+        for Sse41(a: __m128i, b: __m128i) -> __m128i {
+            Ops::<Sse2, u64>::avg(a, b)
+        }
+        for Sse2(a: __m128i, b: __m128i) -> __m128i {
+            _mm_sub_epi64(_mm_or_si128(a, b), _mm_srli_epi64(_mm_xor_si128(a, b), 1))
+        }
+        for Scalar(a: u64, b: u64) -> u64 {
+            ((a as u128 + b as u128 + 1) >> 1) as u64
+        }
+        for Neon(a: uint64x2_t, b: uint64x2_t) -> uint64x2_t {
+            vsubq_u64(vorrq_u64(a, b), vshrq_n_u64::<1>(veorq_u64(a, b)))
+        }
+        for Wasm(a: v128, b: v128) -> v128 {
+            i64x2_sub(v128_or(a, b), u64x2_shr(v128_xor(a, b), 1))
+        }
+    }
+}
+
+impl_op! {
+    fn shr_arithmetic<u64> {
+        for Avx512(a: __m512i, rhs: i32) -> __m512i {
+            Ops::<Avx512, i64>::shr_arithmetic(a, rhs)
+        }
+        for Avx2(a: __m256i, rhs: i32) -> __m256i {
+            Ops::<Avx2, i64>::shr_arithmetic(a, rhs)
+        }
+        for Sse41(a: __m128i, rhs: i32) -> __m128i {
+            Ops::<Sse41, i64>::shr_arithmetic(a, rhs)
+        }
+        for Sse2(a: __m128i, rhs: i32) -> __m128i {
+            Ops::<Sse2, i64>::shr_arithmetic(a, rhs)
+        }
+        for Scalar(a: u64, rhs: i32) -> u64 {
+            (a as i64).wrapping_shr(rhs as u32) as u64
+        }
+        for Neon(a: uint64x2_t, rhs: i32) -> uint64x2_t {
+            vreinterpretq_u64_s64(Ops::<Neon, i64>::shr_arithmetic(vreinterpretq_s64_u64(a), rhs))
+        }
+        for Wasm(a: v128, rhs: i32) -> v128 {
+            Ops::<Wasm, i64>::shr_arithmetic(a, rhs)
+        }
+    }
+}
+
+impl_op! {
+    fn shl_lanes<u64> {
+        for Avx512(a: __m512i, counts: __m512i) -> __m512i {
+            Ops::<Avx512, i64>::shl_lanes(a, counts)
+        }
+        for Avx2(a: __m256i, counts: __m256i) -> __m256i {
+            Ops::<Avx2, i64>::shl_lanes(a, counts)
+        }
+        for Sse41(a: __m128i, counts: __m128i) -> __m128i {
+            Ops::<Sse41, i64>::shl_lanes(a, counts)
+        }
+        for Sse2(a: __m128i, counts: __m128i) -> __m128i {
+            Ops::<Sse2, i64>::shl_lanes(a, counts)
+        }
+        for Scalar(a: u64, counts: u64) -> u64 {
+            a.wrapping_shl(counts as u32)
+        }
+        for Neon(a: uint64x2_t, counts: uint64x2_t) -> uint64x2_t {
+            let counts = vreinterpretq_s64_u64(counts);
+            vreinterpretq_u64_s64(Ops::<Neon, i64>::shl_lanes(vreinterpretq_s64_u64(a), counts))
+        }
+        for Wasm(a: v128, counts: v128) -> v128 {
+            Ops::<Wasm, i64>::shl_lanes(a, counts)
+        }
+    }
+}
+
+impl_op! {
+    fn shr_lanes<u64> {
+        for Avx512(a: __m512i, counts: __m512i) -> __m512i {
+            Ops::<Avx512, i64>::shr_lanes(a, counts)
+        }
+        for Avx2(a: __m256i, counts: __m256i) -> __m256i {
+            Ops::<Avx2, i64>::shr_lanes(a, counts)
+        }
+        for Sse41(a: __m128i, counts: __m128i) -> __m128i {
+            Ops::<Sse41, i64>::shr_lanes(a, counts)
+        }
+        for Sse2(a: __m128i, counts: __m128i) -> __m128i {
+            Ops::<Sse2, i64>::shr_lanes(a, counts)
+        }
+        for Scalar(a: u64, counts: u64) -> u64 {
+            a.wrapping_shr(counts as u32)
+        }
+        for Neon(a: uint64x2_t, counts: uint64x2_t) -> uint64x2_t {
+            let counts = vreinterpretq_s64_u64(counts);
+            vreinterpretq_u64_s64(Ops::<Neon, i64>::shr_lanes(vreinterpretq_s64_u64(a), counts))
+        }
+        for Wasm(a: v128, counts: v128) -> v128 {
+            Ops::<Wasm, i64>::shr_lanes(a, counts)
+        }
+    }
+}
+
+impl_op! {
+    fn shr_arithmetic_lanes<u64> {
+        for Avx512(a: __m512i, counts: __m512i) -> __m512i {
+            Ops::<Avx512, i64>::shr_arithmetic_lanes(a, counts)
+        }
+        for Avx2(a: __m256i, counts: __m256i) -> __m256i {
+            Ops::<Avx2, i64>::shr_arithmetic_lanes(a, counts)
+        }
+        for Sse41(a: __m128i, counts: __m128i) -> __m128i {
+            Ops::<Sse41, i64>::shr_arithmetic_lanes(a, counts)
+        }
+        for Sse2(a: __m128i, counts: __m128i) -> __m128i {
+            Ops::<Sse2, i64>::shr_arithmetic_lanes(a, counts)
+        }
+        for Scalar(a: u64, counts: u64) -> u64 {
+            (a as i64).wrapping_shr(counts as u32) as u64
+        }
+        for Neon(a: uint64x2_t, counts: uint64x2_t) -> uint64x2_t {
+            let counts = vreinterpretq_s64_u64(counts);
+            vreinterpretq_u64_s64(Ops::<Neon, i64>::shr_arithmetic_lanes(vreinterpretq_s64_u64(a), counts))
+        }
+        for Wasm(a: v128, counts: v128) -> v128 {
+            Ops::<Wasm, i64>::shr_arithmetic_lanes(a, counts)
+        }
+    }
+}
+
+impl_op! {
+    fn rotate_left<u64> {
+        for Avx512(a: __m512i, rhs: i32) -> __m512i {
+            Ops::<Avx512, i64>::rotate_left(a, rhs)
+        }
+        for Avx2(a: __m256i, rhs: i32) -> __m256i {
+            Ops::<Avx2, i64>::rotate_left(a, rhs)
+        }
+        for Sse41(a: __m128i, rhs: i32) -> __m128i {
+            Ops::<Sse41, i64>::rotate_left(a, rhs)
+        }
+        for Sse2(a: __m128i, rhs: i32) -> __m128i {
+            Ops::<Sse2, i64>::rotate_left(a, rhs)
+        }
+        for Scalar(a: u64, rhs: i32) -> u64 {
+            a.rotate_left(rhs as u32)
+        }
+        for Neon(a: uint64x2_t, rhs: i32) -> uint64x2_t {
+            vreinterpretq_u64_s64(Ops::<Neon, i64>::rotate_left(vreinterpretq_s64_u64(a), rhs))
+        }
+        for Wasm(a: v128, rhs: i32) -> v128 {
+            Ops::<Wasm, i64>::rotate_left(a, rhs)
+        }
+    }
+}
+
+impl_op! {
+    fn rotate_right<u64> {
+        for Avx512(a: __m512i, rhs: i32) -> __m512i {
+            Ops::<Avx512, i64>::rotate_right(a, rhs)
+        }
+        for Avx2(a: __m256i, rhs: i32) -> __m256i {
+            Ops::<Avx2, i64>::rotate_right(a, rhs)
+        }
+        for Sse41(a: __m128i, rhs: i32) -> __m128i {
+            Ops::<Sse41, i64>::rotate_right(a, rhs)
+        }
+        for Sse2(a: __m128i, rhs: i32) -> __m128i {
+            Ops::<Sse2, i64>::rotate_right(a, rhs)
+        }
+        for Scalar(a: u64, rhs: i32) -> u64 {
+            a.rotate_right(rhs as u32)
+        }
+        for Neon(a: uint64x2_t, rhs: i32) -> uint64x2_t {
+            vreinterpretq_u64_s64(Ops::<Neon, i64>::rotate_right(vreinterpretq_s64_u64(a), rhs))
+        }
+        for Wasm(a: v128, rhs: i32) -> v128 {
+            Ops::<Wasm, i64>::rotate_right(a, rhs)
+        }
+    }
+}
+
+impl_op! {
+    fn rotate_left_lanes<u64> {
+        for Avx512(a: __m512i, counts: __m512i) -> __m512i {
+            Ops::<Avx512, i64>::rotate_left_lanes(a, counts)
+        }
+        for Avx2(a: __m256i, counts: __m256i) -> __m256i {
+            Ops::<Avx2, i64>::rotate_left_lanes(a, counts)
+        }
+        for Sse41(a: __m128i, counts: __m128i) -> __m128i {
+            Ops::<Sse41, i64>::rotate_left_lanes(a, counts)
+        }
+        for Sse2(a: __m128i, counts: __m128i) -> __m128i {
+            Ops::<Sse2, i64>::rotate_left_lanes(a, counts)
+        }
+        for Scalar(a: u64, counts: u64) -> u64 {
+            a.rotate_left(counts as u32)
+        }
+        for Neon(a: uint64x2_t, counts: uint64x2_t) -> uint64x2_t {
+            let counts = vreinterpretq_s64_u64(counts);
+            vreinterpretq_u64_s64(Ops::<Neon, i64>::rotate_left_lanes(vreinterpretq_s64_u64(a), counts))
+        }
+        for Wasm(a: v128, counts: v128) -> v128 {
+            Ops::<Wasm, i64>::rotate_left_lanes(a, counts)
+        }
+    }
+}
+
+impl_op! {
+    fn rotate_right_lanes<u64> {
+        for Avx512(a: __m512i, counts: __m512i) -> __m512i {
+            Ops::<Avx512, i64>::rotate_right_lanes(a, counts)
+        }
+        for Avx2(a: __m256i, counts: __m256i) -> __m256i {
+            Ops::<Avx2, i64>::rotate_right_lanes(a, counts)
+        }
+        for Sse41(a: __m128i, counts: __m128i) -> __m128i {
+            Ops::<Sse41, i64>::rotate_right_lanes(a, counts)
+        }
+        for Sse2(a: __m128i, counts: __m128i) -> __m128i {
+            Ops::<Sse2, i64>::rotate_right_lanes(a, counts)
+        }
+        for Scalar(a: u64, counts: u64) -> u64 {
+            a.rotate_right(counts as u32)
+        }
+        for Neon(a: uint64x2_t, counts: uint64x2_t) -> uint64x2_t {
+            let counts = vreinterpretq_s64_u64(counts);
+            vreinterpretq_u64_s64(Ops::<Neon, i64>::rotate_right_lanes(vreinterpretq_s64_u64(a), counts))
+        }
+        for Wasm(a: v128, counts: v128) -> v128 {
+            Ops::<Wasm, i64>::rotate_right_lanes(a, counts)
+        }
+    }
+}
+
+impl_op! {
+    fn count_ones<u64> {
+        for Avx512(a: __m512i) -> __m512i {
+            Ops::<Avx512, i64>::count_ones(a)
+        }
+        for Avx2(a: __m256i) -> __m256i {
+            Ops::<Avx2, i64>::count_ones(a)
+        }
+        for Sse41(a: __m128i) -> __m128i {
+            Ops::<Sse41, i64>::count_ones(a)
+        }
+        for Sse2(a: __m128i) -> __m128i {
+            Ops::<Sse2, i64>::count_ones(a)
+        }
+        for Scalar(a: u64) -> u64 {
+            a.count_ones() as u64
+        }
+        for Neon(a: uint64x2_t) -> uint64x2_t {
+            vreinterpretq_u64_s64(Ops::<Neon, i64>::count_ones(vreinterpretq_s64_u64(a)))
+        }
+        for Wasm(a: v128) -> v128 {
+            Ops::<Wasm, i64>::count_ones(a)
+        }
+    }
+}
+
+impl_op! {
+    fn leading_zeros<u64> {
+        for Avx512(a: __m512i) -> __m512i {
+            Ops::<Avx512, i64>::leading_zeros(a)
+        }
+        for Avx2(a: __m256i) -> __m256i {
+            Ops::<Avx2, i64>::leading_zeros(a)
+        }
+        for Sse41(a: __m128i) -> __m128i {
+            Ops::<Sse41, i64>::leading_zeros(a)
+        }
+        for Sse2(a: __m128i) -> __m128i {
+            Ops::<Sse2, i64>::leading_zeros(a)
+        }
+        for Scalar(a: u64) -> u64 {
+            a.leading_zeros() as u64
+        }
+        for Neon(a: uint64x2_t) -> uint64x2_t {
+            vreinterpretq_u64_s64(Ops::<Neon, i64>::leading_zeros(vreinterpretq_s64_u64(a)))
+        }
+        for Wasm(a: v128) -> v128 {
+            Ops::<Wasm, i64>::leading_zeros(a)
+        }
+    }
+}
+
+impl_op! {
+    fn trailing_zeros<u64> {
+        for Avx512(a: __m512i) -> __m512i {
+            Ops::<Avx512, i64>::trailing_zeros(a)
+        }
+        for Avx2(a: __m256i) -> __m256i {
+            Ops::<Avx2, i64>::trailing_zeros(a)
+        }
+        for Sse41(a: __m128i) -> __m128i {
+            Ops::<Sse41, i64>::trailing_zeros(a)
+        }
+        for Sse2(a: __m128i) -> __m128i {
+            Ops::<Sse2, i64>::trailing_zeros(a)
+        }
+        for Scalar(a: u64) -> u64 {
+            a.trailing_zeros() as u64
+        }
+        for Neon(a: uint64x2_t) -> uint64x2_t {
+            vreinterpretq_u64_s64(Ops::<Neon, i64>::trailing_zeros(vreinterpretq_s64_u64(a)))
+        }
+        for Wasm(a: v128) -> v128 {
+            Ops::<Wasm, i64>::trailing_zeros(a)
+        }
+    }
+}
+
+impl_op! {
+    fn reverse_bits<u64> {
+        for Avx512(a: __m512i) -> __m512i {
+            Ops::<Avx512, i64>::reverse_bits(a)
+        }
+        for Avx2(a: __m256i) -> __m256i {
+            Ops::<Avx2, i64>::reverse_bits(a)
+        }
+        for Sse41(a: __m128i) -> __m128i {
+            Ops::<Sse41, i64>::reverse_bits(a)
+        }
+        for Sse2(a: __m128i) -> __m128i {
+            Ops::<Sse2, i64>::reverse_bits(a)
+        }
+        for Scalar(a: u64) -> u64 {
+            a.reverse_bits()
+        }
+        for Neon(a: uint64x2_t) -> uint64x2_t {
+            vreinterpretq_u64_s64(Ops::<Neon, i64>::reverse_bits(vreinterpretq_s64_u64(a)))
+        }
+        for Wasm(a: v128) -> v128 {
+            Ops::<Wasm, i64>::reverse_bits(a)
+        }
+    }
+}
+
+impl_op! {
+    fn popcount_total<u64> {
+        for Avx512(a: __m512i) -> u32 {
+            Ops::<Avx512, i64>::popcount_total(a)
+        }
+        for Avx2(a: __m256i) -> u32 {
+            Ops::<Avx2, i64>::popcount_total(a)
+        }
+        for Sse41(a: __m128i) -> u32 {
+            Ops::<Sse41, i64>::popcount_total(a)
+        }
+        for Sse2(a: __m128i) -> u32 {
+            Ops::<Sse2, i64>::popcount_total(a)
+        }
+        for Scalar(a: u64) -> u32 {
+            a.count_ones()
+        }
+        for Neon(a: uint64x2_t) -> u32 {
+            Ops::<Neon, i64>::popcount_total(vreinterpretq_s64_u64(a))
+        }
+        for Wasm(a: v128) -> u32 {
+            Ops::<Wasm, i64>::popcount_total(a)
+        }
+    }
+}
@@ -0,0 +1,1174 @@
+use super::*;
+
+// Most unsigned 8 bit operations share their bit patterns with the signed versions, so on the
+// x86 and wasm backends (where both use the same register type) they forward to the i8 ops.
+// Ordering-sensitive operations flip the sign bit before using the signed comparisons.
+
+#[cfg(any(target_arch = "x86_64", target_arch = "x86"))]
+const SIGN_BIT: i8 = i8::MIN;
+
+impl_op! {
+    fn add<u8> {
+        for Avx512(a: __m512i, b: __m512i) -> __m512i {
+            Ops::<Avx512, i8>::add(a, b)
+        }
+        for Avx2(a: __m256i, b: __m256i) -> __m256i {
+            Ops::<Avx2, i8>::add(a, b)
+        }
+        for Sse41(a: __m128i, b: __m128i) -> __m128i {
+            Ops::<Sse41, i8>::add(a, b)
+        }
+        for Sse2(a: __m128i, b: __m128i) -> __m128i {
+            Ops::<Sse2, i8>::add(a, b)
+        }
+        for Scalar(a: u8, b: u8) -> u8 {
+            a.wrapping_add(b)
+        }
+        for Neon(a: uint8x16_t, b: uint8x16_t) -> uint8x16_t {
+            vaddq_u8(a, b)
+        }
+        for Wasm(a: v128, b: v128) -> v128 {
+            Ops::<Wasm, i8>::add(a, b)
+        }
+    }
+}
+
+impl_op! {
+    fn sub<u8> {
+        for Avx512(a: __m512i, b: __m512i) -> __m512i {
+            Ops::<Avx512, i8>::sub(a, b)
+        }
+        for Avx2(a: __m256i, b: __m256i) -> __m256i {
+            Ops::<Avx2, i8>::sub(a, b)
+        }
+        for Sse41(a: __m128i, b: __m128i) -> __m128i {
+            Ops::<Sse41, i8>::sub(a, b)
+        }
+        for Sse2(a: __m128i, b: __m128i) -> __m128i {
+            Ops::<Sse2, i8>::sub(a, b)
+        }
+        for Scalar(a: u8, b: u8) -> u8 {
+            a.wrapping_sub(b)
+        }
+        for Neon(a: uint8x16_t, b: uint8x16_t) -> uint8x16_t {
+            vsubq_u8(a, b)
+        }
+        for Wasm(a: v128, b: v128) -> v128 {
+            Ops::<Wasm, i8>::sub(a, b)
+        }
+    }
+}
+
+impl_op! {
+    fn mul<u8> {
+        for Avx512(a: __m512i, b: __m512i) -> __m512i {
+            Ops::<Avx512, i8>::mul(a, b)
+        }
+        for Avx2(a: __m256i, b: __m256i) -> __m256i {
+            Ops::<Avx2, i8>::mul(a, b)
+        }
+        for Sse41(a: __m128i, b: __m128i) -> __m128i {
+            Ops::<Sse41, i8>::mul(a, b)
+        }
+        for Sse2(a: __m128i, b: __m128i) -> __m128i {
+            Ops::<Sse2, i8>::mul(a, b)
+        }
+        for Scalar(a: u8, b: u8) -> u8 {
+            a.wrapping_mul(b)
+        }
+        for Neon(a: uint8x16_t, b: uint8x16_t) -> uint8x16_t {
+            vmulq_u8(a, b)
+        }
+        for Wasm(a: v128, b: v128) -> v128 {
+            Ops::<Wasm, i8>::mul(a, b)
+        }
+    }
+}
+
+impl_op! {
+    fn min<u8> {
+        for Avx512(a: __m512i, b: __m512i) -> __m512i {
+            _mm512_min_epu8(a, b)
+        }
+        for Avx2(a: __m256i, b: __m256i) -> __m256i {
+            _mm256_min_epu8(a, b)
+        }
+        for Sse41(a: __m128i, b: __m128i) -> __m128i {
+            _mm_min_epu8(a, b)
+        }
+        for Sse2(a: __m128i, b: __m128i) -> __m128i {
+            _mm_min_epu8(a, b)
+        }
+        for Scalar(a: u8, b: u8) -> u8 {
+            a.min(b)
+        }
+        for Neon(a: uint8x16_t, b: uint8x16_t) -> uint8x16_t {
+            vminq_u8(a, b)
+        }
+        for Wasm(a: v128, b: v128) -> v128 {
+            u8x16_min(a, b)
+        }
+    }
+}
+
+impl_op! {
+    fn max<u8> {
+        for Avx512(a: __m512i, b: __m512i) -> __m512i {
+            _mm512_max_epu8(a, b)
+        }
+        for Avx2(a: __m256i, b: __m256i) -> __m256i {
+            _mm256_max_epu8(a, b)
+        }
+        for Sse41(a: __m128i, b: __m128i) -> __m128i {
+            _mm_max_epu8(a, b)
+        }
+        for Sse2(a: __m128i, b: __m128i) -> __m128i {
+            _mm_max_epu8(a, b)
+        }
+        for Scalar(a: u8, b: u8) -> u8 {
+            a.max(b)
+        }
+        for Neon(a: uint8x16_t, b: uint8x16_t) -> uint8x16_t {
+            vmaxq_u8(a, b)
+        }
+        for Wasm(a: v128, b: v128) -> v128 {
+            u8x16_max(a, b)
+        }
+    }
+}
+
+impl_op! {
+    fn abs<u8> {
+        for Avx512(a: __m512i) -> __m512i {
+            a
+        }
+        for Avx2(a: __m256i) -> __m256i {
+            a
+        }
+        for Sse41(a: __m128i) -> __m128i {
+            a
+        }
+        for Sse2(a: __m128i) -> __m128i {
+            a
+        }
+        for Scalar(a: u8) -> u8 {
+            a
+        }
+        for Neon(a: uint8x16_t) -> uint8x16_t {
+            a
+        }
+        for Wasm(a: v128) -> v128 {
+            a
+        }
+    }
+}
+
+impl_op! {
+    fn eq<u8> {
+        for Avx512(a: __m512i, b: __m512i) -> __m512i {
+            Ops::<Avx512, i8>::eq(a, b)
+        }
+        for Avx2(a: __m256i, b: __m256i) -> __m256i {
+            Ops::<Avx2, i8>::eq(a, b)
+        }
+        for Sse41(a: __m128i, b: __m128i) -> __m128i {
+            Ops::<Sse41, i8>::eq(a, b)
+        }
+        for Sse2(a: __m128i, b: __m128i) -> __m128i {
+            Ops::<Sse2, i8>::eq(a, b)
+        }
+        for Scalar(a: u8, b: u8) -> u8 {
+            if a == b {
+                u8::MAX
+            } else {
+                0
+            }
+        }
+        for Neon(a: uint8x16_t, b: uint8x16_t) -> uint8x16_t {
+            vceqq_u8(a, b)
+        }
+        for Wasm(a: v128, b: v128) -> v128 {
+            Ops::<Wasm, i8>::eq(a, b)
+        }
+    }
+}
+
+impl_op! {
+    fn neq<u8> {
+        for Avx512(a: __m512i, b: __m512i) -> __m512i {
+            Ops::<Avx512, i8>::neq(a, b)
+        }
+        for Avx2(a: __m256i, b: __m256i) -> __m256i {
+            Ops::<Avx2, i8>::neq(a, b)
+        }
+        for Sse41(a: __m128i, b: __m128i) -> __m128i {
+            Ops::<Sse41, i8>::neq(a, b)
+        }
+        for Sse2(a: __m128i, b: __m128i) -> __m128i {
+            Ops::<Sse2, i8>::neq(a, b)
+        }
+        for Scalar(a: u8, b: u8) -> u8 {
+            if a != b {
+                u8::MAX
+            } else {
+                0
+            }
+        }
+        for Neon(a: uint8x16_t, b: uint8x16_t) -> uint8x16_t {
+            vmvnq_u8(vceqq_u8(a, b))
+        }
+        for Wasm(a: v128, b: v128) -> v128 {
+            Ops::<Wasm, i8>::neq(a, b)
+        }
+    }
+}
+
+impl_op! {
+    fn lt<u8> {
+        for Avx512(a: __m512i, b: __m512i) -> __m512i {
+            _mm512_movm_epi8(_mm512_cmplt_epu8_mask(a, b))
+        }
+        for Avx2(a: __m256i, b: __m256i) -> __m256i {
+            let sign = _mm256_set1_epi8(SIGN_BIT);
+            Ops::<Avx2, i8>::lt(_mm256_xor_si256(a, sign), _mm256_xor_si256(b, sign))
+        }
+        for Sse41(a: __m128i, b: __m128i) -> __m128i {
+            let sign = _mm_set1_epi8(SIGN_BIT);
+            Ops::<Sse41, i8>::lt(_mm_xor_si128(a, sign), _mm_xor_si128(b, sign))
+        }
+        for Sse2(a: __m128i, b: __m128i) -> __m128i {
+            let sign = _mm_set1_epi8(SIGN_BIT);
+            Ops::<Sse2, i8>::lt(_mm_xor_si128(a, sign), _mm_xor_si128(b, sign))
+        }
+        for Scalar(a: u8, b: u8) -> u8 {
+            if a < b {
+                u8::MAX
+            } else {
+                0
+            }
+        }
+        for Neon(a: uint8x16_t, b: uint8x16_t) -> uint8x16_t {
+            vcltq_u8(a, b)
+        }
+        for Wasm(a: v128, b: v128) -> v128 {
+            u8x16_lt(a, b)
+        }
+    }
+}
+
+impl_op! {
+    fn lte<u8> {
+        for Avx512(a: __m512i, b: __m512i) -> __m512i {
+            _mm512_movm_epi8(_mm512_cmple_epu8_mask(a, b))
+        }
+        for Avx2(a: __m256i, b: __m256i) -> __m256i {
+            let sign = _mm256_set1_epi8(SIGN_BIT);
+            Ops::<Avx2, i8>::lte(_mm256_xor_si256(a, sign), _mm256_xor_si256(b, sign))
+        }
+        for Sse41(a: __m128i, b: __m128i) -> __m128i {
+            let sign = _mm_set1_epi8(SIGN_BIT);
+            Ops::<Sse41, i8>::lte(_mm_xor_si128(a, sign), _mm_xor_si128(b, sign))
+        }
+        for Sse2(a: __m128i, b: __m128i) -> __m128i {
+            let sign = _mm_set1_epi8(SIGN_BIT);
+            Ops::<Sse2, i8>::lte(_mm_xor_si128(a, sign), _mm_xor_si128(b, sign))
+        }
+        for Scalar(a: u8, b: u8) -> u8 {
+            if a <= b {
+                u8::MAX
+            } else {
+                0
+            }
+        }
+        for Neon(a: uint8x16_t, b: uint8x16_t) -> uint8x16_t {
+            vcleq_u8(a, b)
+        }
+        for Wasm(a: v128, b: v128) -> v128 {
+            u8x16_le(a, b)
+        }
+    }
+}
+
+impl_op! {
+    fn gt<u8> {
+        for Avx512(a: __m512i, b: __m512i) -> __m512i {
+            _mm512_movm_epi8(_mm512_cmpgt_epu8_mask(a, b))
+        }
+        for Avx2(a: __m256i, b: __m256i) -> __m256i {
+            let sign = _mm256_set1_epi8(SIGN_BIT);
+            Ops::<Avx2, i8>::gt(_mm256_xor_si256(a, sign), _mm256_xor_si256(b, sign))
+        }
+        for Sse41(a: __m128i, b: __m128i) -> __m128i {
+            let sign = _mm_set1_epi8(SIGN_BIT);
+            Ops::<Sse41, i8>::gt(_mm_xor_si128(a, sign), _mm_xor_si128(b, sign))
+        }
+        for Sse2(a: __m128i, b: __m128i) -> __m128i {
+            let sign = _mm_set1_epi8(SIGN_BIT);
+            Ops::<Sse2, i8>::gt(_mm_xor_si128(a, sign), _mm_xor_si128(b, sign))
+        }
+        for Scalar(a: u8, b: u8) -> u8 {
+            if a > b {
+                u8::MAX
+            } else {
+                0
+            }
+        }
+        for Neon(a: uint8x16_t, b: uint8x16_t) -> uint8x16_t {
+            vcgtq_u8(a, b)
+        }
+        for Wasm(a: v128, b: v128) -> v128 {
+            u8x16_gt(a, b)
+        }
+    }
+}
+
+impl_op! {
+    fn gte<u8> {
+        for Avx512(a: __m512i, b: __m512i) -> __m512i {
+            _mm512_movm_epi8(_mm512_cmpge_epu8_mask(a, b))
+        }
+        for Avx2(a: __m256i, b: __m256i) -> __m256i {
+            let sign = _mm256_set1_epi8(SIGN_BIT);
+            Ops::<Avx2, i8>::gte(_mm256_xor_si256(a, sign), _mm256_xor_si256(b, sign))
+        }
+        for Sse41(a: __m128i, b: __m128i) -> __m128i {
+            let sign = _mm_set1_epi8(SIGN_BIT);
+            Ops::<Sse41, i8>::gte(_mm_xor_si128(a, sign), _mm_xor_si128(b, sign))
+        }
+        for Sse2(a: __m128i, b: __m128i) -> __m128i {
+            let sign = _mm_set1_epi8(SIGN_BIT);
+            Ops::<Sse2, i8>::gte(_mm_xor_si128(a, sign), _mm_xor_si128(b, sign))
+        }
+        for Scalar(a: u8, b: u8) -> u8 {
+            if a >= b {
+                u8::MAX
+            } else {
+                0
+            }
+        }
+        for Neon(a: uint8x16_t, b: uint8x16_t) -> uint8x16_t {
+            vcgeq_u8(a, b)
+        }
+        for Wasm(a: v128, b: v128) -> v128 {
+            u8x16_ge(a, b)
+        }
+    }
+}
+
+impl_op! {
+    fn blendv<u8> {
+        for Avx512(a: __m512i, b: __m512i, mask: __m512i) -> __m512i {
+            Ops::<Avx512, i8>::blendv(a, b, mask)
+        }
+        for Avx2(a: __m256i, b: __m256i, mask: __m256i) -> __m256i {
+            Ops::<Avx2, i8>::blendv(a, b, mask)
+        }
+        for Sse41(a: __m128i, b: __m128i, mask: __m128i) -> __m128i {
+            Ops::<Sse41, i8>::blendv(a, b, mask)
+        }
+        for Sse2(a: __m128i, b: __m128i, mask: __m128i) -> __m128i {
+            Ops::<Sse2, i8>::blendv(a, b, mask)
+        }
+        for Scalar(a: u8, b: u8, mask: u8) -> u8 {
+            if mask == 0 {
+                a
+            } else {
+                b
+            }
+        }
+        for Neon(a: uint8x16_t, b: uint8x16_t, mask: uint8x16_t) -> uint8x16_t {
+            vbslq_u8(mask, b, a)
+        }
+        for Wasm(a: v128, b: v128, mask: v128) -> v128 {
+            Ops::<Wasm, i8>::blendv(a, b, mask)
+        }
+    }
+}
+
+impl_op! {
+    fn shl<u8> {
+        for Avx512(a: __m512i, rhs: i32) -> __m512i {
+            Ops::<Avx512, i8>::shl(a, rhs)
+        }
+        for Avx2(a: __m256i, rhs: i32) -> __m256i {
+            Ops::<Avx2, i8>::shl(a, rhs)
+        }
+        for Sse41(a: __m128i, rhs: i32) -> __m128i {
+            Ops::<Sse41, i8>::shl(a, rhs)
+        }
+        for Sse2(a: __m128i, rhs: i32) -> __m128i {
+            Ops::<Sse2, i8>::shl(a, rhs)
+        }
+        for Scalar(a: u8, rhs: i32) -> u8 {
+            a.wrapping_shl((rhs & 7) as u32)
+        }
+        for Neon(a: uint8x16_t, rhs: i32) -> uint8x16_t {
+            vshlq_u8(a, Ops::<Neon, i8>::set1((rhs & 7) as i8))
+        }
+        for Wasm(a: v128, rhs: i32) -> v128 {
+            Ops::<Wasm, i8>::shl(a, rhs)
+        }
+    }
+}
+
+impl_op! {
+    fn shr<u8> {
+        for Avx512(a: __m512i, rhs: i32) -> __m512i {
+            Ops::<Avx512, i8>::shr(a, rhs)
+        }
+        for Avx2(a: __m256i, rhs: i32) -> __m256i {
+            Ops::<Avx2, i8>::shr(a, rhs)
+        }
+        for Sse41(a: __m128i, rhs: i32) -> __m128i {
+            Ops::<Sse41, i8>::shr(a, rhs)
+        }
+        for Sse2(a: __m128i, rhs: i32) -> __m128i {
+            Ops::<Sse2, i8>::shr(a, rhs)
+        }
+        for Scalar(a: u8, rhs: i32) -> u8 {
+            a.wrapping_shr((rhs & 7) as u32)
+        }
+        for Neon(a: uint8x16_t, rhs: i32) -> uint8x16_t {
+            vshlq_u8(a, Ops::<Neon, i8>::set1(-(rhs & 7) as i8))
+        }
+        for Wasm(a: v128, rhs: i32) -> v128 {
+            Ops::<Wasm, i8>::shr(a, rhs)
+        }
+    }
+}
+
+impl_imm8_op! {
+    fn shl_const<u8, const BY: i32> {
+        for Avx512(a: __m512i) -> __m512i {
+            Ops::<Avx512, i8>::shl_const::<BY>(a)
+        }
+        for Avx2(a: __m256i) -> __m256i {
+            Ops::<Avx2, i8>::shl_const::<BY>(a)
+        }
+        for Sse41(a: __m128i) -> __m128i {
+            Ops::<Sse41, i8>::shl_const::<BY>(a)
+        }
+        for Sse2(a: __m128i) -> __m128i {
+            Ops::<Sse2, i8>::shl_const::<BY>(a)
+        }
+        for Scalar(a: u8) -> u8 {
+            a << BY
+        }
+        for Neon(a: uint8x16_t) -> uint8x16_t {
+            vshlq_n_u8(a, BY)
+        }
+        for Wasm(a: v128) -> v128 {
+            Ops::<Wasm, i8>::shl_const::<BY>(a)
+        }
+    }
+}
+
+impl_imm8_op! {
+    fn shr_const<u8, const BY: i32> {
+        for Avx512(a: __m512i) -> __m512i {
+            Ops::<Avx512, i8>::shr_const::<BY>(a)
+        }
+        for Avx2(a: __m256i) -> __m256i {
+            Ops::<Avx2, i8>::shr_const::<BY>(a)
+        }
+        for Sse41(a: __m128i) -> __m128i {
+            Ops::<Sse41, i8>::shr_const::<BY>(a)
+        }
+        for Sse2(a: __m128i) -> __m128i {
+            Ops::<Sse2, i8>::shr_const::<BY>(a)
+        }
+        for Scalar(a: u8) -> u8 {
+            a >> BY
+        }
+        for Neon(a: uint8x16_t) -> uint8x16_t {
+            vshrq_n_u8(a, BY)
+        }
+        for Wasm(a: v128) -> v128 {
+            Ops::<Wasm, i8>::shr_const::<BY>(a)
+        }
+    }
+}
+
+impl_op! {
+    fn extend_u16<u8> {
+        for Avx512(val: __m512i) -> (__m512i, __m512i) {
+            Ops::<Avx512, i8>::unsigned_extend_i16(val)
+        }
+        for Avx2(val: __m256i) -> (__m256i, __m256i) {
+            Ops::<Avx2, i8>::unsigned_extend_i16(val)
+        }
+        for Sse41(val: __m128i) -> (__m128i, __m128i) {
+            Ops::<Sse41, i8>::unsigned_extend_i16(val)
+        }
+        for Sse2(val: __m128i) -> (__m128i, __m128i) {
+            let zero = _mm_setzero_si128();
+            (_mm_unpacklo_epi8(val, zero), _mm_unpackhi_epi8(val, zero))
+        }
+        for Scalar(val: u8) -> (u16, u16) {
+            (val as u16, 0)
+        }
+        for Neon(val: uint8x16_t) -> (uint16x8_t, uint16x8_t) {
+            (vmovl_u8(vget_low_u8(val)), vmovl_u8(vget_high_u8(val)))
+        }
+        for Wasm(val: v128) -> (v128, v128) {
+            Ops::<Wasm, i8>::unsigned_extend_i16(val)
+        }
+    }
+}
+
+impl_op! {
+    fn zeroes<u8> {
+        for Avx512() -> __m512i {
+            _mm512_setzero_si512()
+        }
+        for Avx2() -> __m256i {
+            _mm256_setzero_si256()
+        }
+        for Sse41() -> __m128i {
+            _mm_setzero_si128()
+        }
+        for Sse2() -> __m128i {
+            _mm_setzero_si128()
+        }
+        for Scalar() -> u8 {
+            0
+        }
+        for Neon() -> uint8x16_t {
+            vdupq_n_u8(0)
+        }
+        for Wasm() -> v128 {
+            u8x16_splat(0)
+        }
+    }
+}
+
+impl_op! {
+    fn set1<u8> {
+        for Avx512(val: u8) -> __m512i {
+            _mm512_set1_epi8(val as i8)
+        }
+        for Avx2(val: u8) -> __m256i {
+            _mm256_set1_epi8(val as i8)
+        }
+        for Sse41(val: u8) -> __m128i {
+            _mm_set1_epi8(val as i8)
+        }
+        for Sse2(val: u8) -> __m128i {
+            _mm_set1_epi8(val as i8)
+        }
+        for Scalar(val: u8) -> u8 {
+            val
+        }
+        for Neon(val: u8) -> uint8x16_t {
+            vdupq_n_u8(val)
+        }
+        for Wasm(val: u8) -> v128 {
+            u8x16_splat(val)
+        }
+    }
+}
+
+impl_op! {
+    fn load_unaligned<u8> {
+        for Avx512(ptr: *const u8) -> __m512i {
+            _mm512_loadu_si512(ptr as *const __m512i)
+        }
+        for Avx2(ptr: *const u8) -> __m256i {
+            _mm256_loadu_si256(ptr as *const __m256i)
+        }
+        for Sse41(ptr: *const u8) -> __m128i {
+            _mm_loadu_si128(ptr as *const __m128i)
+        }
+        for Sse2(ptr: *const u8) -> __m128i {
+            _mm_loadu_si128(ptr as *const __m128i)
+        }
+        for Scalar(ptr: *const u8) -> u8 {
+            unsafe { *ptr }
+        }
+        for Neon(ptr: *const u8) -> uint8x16_t {
+            vld1q_u8(ptr)
+        }
+        for Wasm(ptr: *const u8) -> v128 {
+            unsafe { v128_load(ptr as *const v128) }
+        }
+    }
+}
+
+impl_op! {
+    fn load_aligned<u8> {
+        for Avx512(ptr: *const u8) -> __m512i {
+            _mm512_load_si512(ptr as *const __m512i)
+        }
+        for Avx2(ptr: *const u8) -> __m256i {
+            _mm256_load_si256(ptr as *const __m256i)
+        }
+        for Sse41(ptr: *const u8) -> __m128i {
+            _mm_load_si128(ptr as *const __m128i)
+        }
+        for Sse2(ptr: *const u8) -> __m128i {
+            _mm_load_si128(ptr as *const __m128i)
+        }
+        for Scalar(ptr: *const u8) -> u8 {
+            unsafe { *ptr }
+        }
+        for Neon(ptr: *const u8) -> uint8x16_t {
+            vld1q_u8(ptr)
+        }
+        for Wasm(ptr: *const u8) -> v128 {
+            *(ptr as *const v128)
+        }
+    }
+}
+
+impl_op! {
+    fn store_unaligned<u8> {
+        for Avx512(ptr: *mut u8, a: __m512i) {
+            _mm512_storeu_si512(ptr as *mut __m512i, a)
+        }
+        for Avx2(ptr: *mut u8, a: __m256i) {
+            _mm256_storeu_si256(ptr as *mut __m256i, a)
+        }
+        for Sse41(ptr: *mut u8, a: __m128i) {
+            _mm_storeu_si128(ptr as *mut __m128i, a)
+        }
+        for Sse2(ptr: *mut u8, a: __m128i) {
+            _mm_storeu_si128(ptr as *mut __m128i, a)
+        }
+        for Scalar(ptr: *mut u8, a: u8) {
+            unsafe { *ptr = a }
+        }
+        for Neon(ptr: *mut u8, a: uint8x16_t) {
+            vst1q_u8(ptr, a)
+        }
+        for Wasm(ptr: *mut u8, a: v128) {
+            unsafe { v128_store(ptr as *mut v128, a) }
+        }
+    }
+}
+
+impl_op! {
+    fn store_aligned<u8> {
+        for Avx512(ptr: *mut u8, a: __m512i) {
+            _mm512_store_si512(ptr as *mut __m512i, a)
+        }
+        for Avx2(ptr: *mut u8, a: __m256i) {
+            _mm256_store_si256(ptr as *mut __m256i, a)
+        }
+        for Sse41(ptr: *mut u8, a: __m128i) {
+            _mm_store_si128(ptr as *mut __m128i, a)
+        }
+        for Sse2(ptr: *mut u8, a: __m128i) {
+            _mm_store_si128(ptr as *mut __m128i, a)
+        }
+        for Scalar(ptr: *mut u8, a: u8) {
+            unsafe { *ptr = a }
+        }
+        for Neon(ptr: *mut u8, a: uint8x16_t) {
+            vst1q_u8(ptr, a)
+        }
+        for Wasm(ptr: *mut u8, a: v128) {
+            *(ptr as *mut v128) = a;
+        }
+    }
+}
+
+impl_op! {
+    fn saturating_add<u8> {
+        for Avx512(a: __m512i, b: __m512i) -> __m512i {
+            _mm512_adds_epu8(a, b)
+        }
+        for Avx2(a: __m256i, b: __m256i) -> __m256i {
+            _mm256_adds_epu8(a, b)
+        }
+        for Sse41(a: __m128i, b: __m128i) -> __m128i {
+            _mm_adds_epu8(a, b)
+        }
+        for Sse2(a: __m128i, b: __m128i) -> __m128i {
+            _mm_adds_epu8(a, b)
+        }
+        for Scalar(a: u8, b: u8) -> u8 {
+            a.saturating_add(b)
+        }
+        for Neon(a: uint8x16_t, b: uint8x16_t) -> uint8x16_t {
+            vqaddq_u8(a, b)
+        }
+        for Wasm(a: v128, b: v128) -> v128 {
+            u8x16_add_sat(a, b)
+        }
+    }
+}
+
+impl_op! {
+    fn saturating_sub<u8> {
+        for Avx512(a: __m512i, b: __m512i) -> __m512i {
+            _mm512_subs_epu8(a, b)
+        }
+        for Avx2(a: __m256i, b: __m256i) -> __m256i {
+            _mm256_subs_epu8(a, b)
+        }
+        for Sse41(a: __m128i, b: __m128i) -> __m128i {
+            _mm_subs_epu8(a, b)
+        }
+        for Sse2(a: __m128i, b: __m128i) -> __m128i {
+            _mm_subs_epu8(a, b)
+        }
+        for Scalar(a: u8, b: u8) -> u8 {
+            a.saturating_sub(b)
+        }
+        for Neon(a: uint8x16_t, b: uint8x16_t) -> uint8x16_t {
+            vqsubq_u8(a, b)
+        }
+        for Wasm(a: v128, b: v128) -> v128 {
+            u8x16_sub_sat(a, b)
+        }
+    }
+}
+
+impl_op! {
+    fn avg<u8> {
+        for Avx512(a: __m512i, b: __m512i) -> __m512i {
+            _mm512_avg_epu8(a, b)
+        }
+        for Avx2(a: __m256i, b: __m256i) -> __m256i {
+            _mm256_avg_epu8(a, b)
+        }
+        for Sse41(a: __m128i, b: __m128i) -> __m128i {
+            _mm_avg_epu8(a, b)
+        }
+        for Sse2(a: __m128i, b: __m128i) -> __m128i {
+            _mm_avg_epu8(a, b)
+        }
+        for Scalar(a: u8, b: u8) -> u8 {
+            ((a as u16 + b as u16 + 1) >> 1) as u8
+        }
+        for Neon(a: uint8x16_t, b: uint8x16_t) -> uint8x16_t {
+            vrhaddq_u8(a, b)
+        }
+        for Wasm(a: v128, b: v128) -> v128 {
+            u8x16_avgr(a, b)
+        }
+    }
+}
+
+// `pmaddubsw`: multiplies unsigned bytes of `a` with signed bytes of `b` and adds adjacent pairs
+// of the i16 products with saturation. The scalar engine has a single product and no pair.
+impl_op! {
+    fn mul_add_pairs<u8> {
+        for Avx512(a: __m512i, b: __m512i) -> __m512i {
+            _mm512_maddubs_epi16(a, b)
+        }
+        for Avx2(a: __m256i, b: __m256i) -> __m256i {
+            _mm256_maddubs_epi16(a, b)
+        }
+        for Sse41(a: __m128i, b: __m128i) -> __m128i {
+            _mm_maddubs_epi16(a, b)
+        }
+        for Sse2(a: __m128i, b: __m128i) -> __m128i {
+            let a_even = _mm_and_si128(a, _mm_set1_epi16(0x00FF));
+            let a_odd = _mm_srli_epi16(a, 8);
+            let b_even = _mm_srai_epi16(_mm_slli_epi16(b, 8), 8);
+            let b_odd = _mm_srai_epi16(b, 8);
+            _mm_adds_epi16(
+                _mm_mullo_epi16(a_even, b_even),
+                _mm_mullo_epi16(a_odd, b_odd),
+            )
+        }
+        for Scalar(a: u8, b: i8) -> i16 {
+            a as i16 * b as i16
+        }
+        for Neon(a: uint8x16_t, b: int8x16_t) -> int16x8_t {
+            let low = vmulq_s16(
+                vreinterpretq_s16_u16(vmovl_u8(vget_low_u8(a))),
+                vmovl_s8(vget_low_s8(b)),
+            );
+            let high = vmulq_s16(
+                vreinterpretq_s16_u16(vmovl_high_u8(a)),
+                vmovl_high_s8(b),
+            );
+            vqaddq_s16(vuzp1q_s16(low, high), vuzp2q_s16(low, high))
+        }
+        for Wasm(a: v128, b: v128) -> v128 {
+            let low = i16x8_mul(u16x8_extend_low_u8x16(a), i16x8_extend_low_i8x16(b));
+            let high = i16x8_mul(u16x8_extend_high_u8x16(a), i16x8_extend_high_i8x16(b));
+            i16x8_add_sat(
+                i16x8_shuffle::<0, 2, 4, 6, 8, 10, 12, 14>(low, high),
+                i16x8_shuffle::<1, 3, 5, 7, 9, 11, 13, 15>(low, high),
+            )
+        }
+    }
+}
+
+// `psadbw`: sums the absolute differences of each group of 8 bytes into one u64 lane.
+impl_op! {
+    fn sad<u8> {
+        for Avx512(a: __m512i, b: __m512i) -> __m512i {
+            _mm512_sad_epu8(a, b)
+        }
+        for Avx2(a: __m256i, b: __m256i) -> __m256i {
+            _mm256_sad_epu8(a, b)
+        }
+        for Sse41(a: __m128i, b: __m128i) -> __m128i {
+            _mm_sad_epu8(a, b)
+        }
+        for Sse2(a: __m128i, b: __m128i) -> __m128i {
+            _mm_sad_epu8(a, b)
+        }
+        for Scalar(a: u8, b: u8) -> u64 {
+            a.abs_diff(b) as u64
+        }
+        for Neon(a: uint8x16_t, b: uint8x16_t) -> uint64x2_t {
+            vpaddlq_u32(vpaddlq_u16(vpaddlq_u8(vabdq_u8(a, b))))
+        }
+        for Wasm(a: v128, b: v128) -> v128 {
+            let diff = v128_or(u8x16_sub_sat(a, b), u8x16_sub_sat(b, a));
+            let sums = u32x4_extadd_pairwise_u16x8(u16x8_extadd_pairwise_u8x16(diff));
+            i64x2_add(
+                v128_and(sums, u64x2_splat(u32::MAX as u64)),
+                u64x2_shr(sums, 32),
+            )
+        }
+    }
+}
+
+impl_op! {
+    fn shr_arithmetic<u8> {
+        for Avx512(a: __m512i, rhs: i32) -> __m512i {
+            Ops::<Avx512, i8>::shr_arithmetic(a, rhs)
+        }
+        for Avx2(a: __m256i, rhs: i32) -> __m256i {
+            Ops::<Avx2, i8>::shr_arithmetic(a, rhs)
+        }
+        for Sse41(a: __m128i, rhs: i32) -> __m128i {
+            Ops::<Sse41, i8>::shr_arithmetic(a, rhs)
+        }
+        for Sse2(a: __m128i, rhs: i32) -> __m128i {
+            Ops::<Sse2, i8>::shr_arithmetic(a, rhs)
+        }
+        for Scalar(a: u8, rhs: i32) -> u8 {
+            (a as i8).wrapping_shr(rhs as u32) as u8
+        }
+        for Neon(a: uint8x16_t, rhs: i32) -> uint8x16_t {
+            vreinterpretq_u8_s8(Ops::<Neon, i8>::shr_arithmetic(vreinterpretq_s8_u8(a), rhs))
+        }
+        for Wasm(a: v128, rhs: i32) -> v128 {
+            Ops::<Wasm, i8>::shr_arithmetic(a, rhs)
+        }
+    }
+}
+
+impl_op! {
+    fn shl_lanes<u8> {
+        for Avx512(a: __m512i, counts: __m512i) -> __m512i {
+            Ops::<Avx512, i8>::shl_lanes(a, counts)
+        }
+        for Avx2(a: __m256i, counts: __m256i) -> __m256i {
+            Ops::<Avx2, i8>::shl_lanes(a, counts)
+        }
+        for Sse41(a: __m128i, counts: __m128i) -> __m128i {
+            Ops::<Sse41, i8>::shl_lanes(a, counts)
+        }
+        for Sse2(a: __m128i, counts: __m128i) -> __m128i {
+            Ops::<Sse2, i8>::shl_lanes(a, counts)
+        }
+        for Scalar(a: u8, counts: u8) -> u8 {
+            a.wrapping_shl(counts as u32)
+        }
+        for Neon(a: uint8x16_t, counts: uint8x16_t) -> uint8x16_t {
+            let counts = vreinterpretq_s8_u8(counts);
+            vreinterpretq_u8_s8(Ops::<Neon, i8>::shl_lanes(vreinterpretq_s8_u8(a), counts))
+        }
+        for Wasm(a: v128, counts: v128) -> v128 {
+            Ops::<Wasm, i8>::shl_lanes(a, counts)
+        }
+    }
+}
+
+impl_op! {
+    fn shr_lanes<u8> {
+        for Avx512(a: __m512i, counts: __m512i) -> __m512i {
+            Ops::<Avx512, i8>::shr_lanes(a, counts)
+        }
+        for Avx2(a: __m256i, counts: __m256i) -> __m256i {
+            Ops::<Avx2, i8>::shr_lanes(a, counts)
+        }
+        for Sse41(a: __m128i, counts: __m128i) -> __m128i {
+            Ops::<Sse41, i8>::shr_lanes(a, counts)
+        }
+        for Sse2(a: __m128i, counts: __m128i) -> __m128i {
+            Ops::<Sse2, i8>::shr_lanes(a, counts)
+        }
+        for Scalar(a: u8, counts: u8) -> u8 {
+            a.wrapping_shr(counts as u32)
+        }
+        for Neon(a: uint8x16_t, counts: uint8x16_t) -> uint8x16_t {
+            let counts = vreinterpretq_s8_u8(counts);
+            vreinterpretq_u8_s8(Ops::<Neon, i8>::shr_lanes(vreinterpretq_s8_u8(a), counts))
+        }
+        for Wasm(a: v128, counts: v128) -> v128 {
+            Ops::<Wasm, i8>::shr_lanes(a, counts)
+        }
+    }
+}
+
+impl_op! {
+    fn shr_arithmetic_lanes<u8> {
+        for Avx512(a: __m512i, counts: __m512i) -> __m512i {
+            Ops::<Avx512, i8>::shr_arithmetic_lanes(a, counts)
+        }
+        for Avx2(a: __m256i, counts: __m256i) -> __m256i {
+            Ops::<Avx2, i8>::shr_arithmetic_lanes(a, counts)
+        }
+        for Sse41(a: __m128i, counts: __m128i) -> __m128i {
+            Ops::<Sse41, i8>::shr_arithmetic_lanes(a, counts)
+        }
+        for Sse2(a: __m128i, counts: __m128i) -> __m128i {
+            Ops::<Sse2, i8>::shr_arithmetic_lanes(a, counts)
+        }
+        for Scalar(a: u8, counts: u8) -> u8 {
+            (a as i8).wrapping_shr(counts as u32) as u8
+        }
+        for Neon(a: uint8x16_t, counts: uint8x16_t) -> uint8x16_t {
+            let counts = vreinterpretq_s8_u8(counts);
+            vreinterpretq_u8_s8(Ops::<Neon, i8>::shr_arithmetic_lanes(vreinterpretq_s8_u8(a), counts))
+        }
+        for Wasm(a: v128, counts: v128) -> v128 {
+            Ops::<Wasm, i8>::shr_arithmetic_lanes(a, counts)
+        }
+    }
+}
+
+impl_op! {
+    fn rotate_left<u8> {
+        for Avx512(a: __m512i, rhs: i32) -> __m512i {
+            Ops::<Avx512, i8>::rotate_left(a, rhs)
+        }
+        for Avx2(a: __m256i, rhs: i32) -> __m256i {
+            Ops::<Avx2, i8>::rotate_left(a, rhs)
+        }
+        for Sse41(a: __m128i, rhs: i32) -> __m128i {
+            Ops::<Sse41, i8>::rotate_left(a, rhs)
+        }
+        for Sse2(a: __m128i, rhs: i32) -> __m128i {
+            Ops::<Sse2, i8>::rotate_left(a, rhs)
+        }
+        for Scalar(a: u8, rhs: i32) -> u8 {
+            a.rotate_left(rhs as u32)
+        }
+        for Neon(a: uint8x16_t, rhs: i32) -> uint8x16_t {
+            vreinterpretq_u8_s8(Ops::<Neon, i8>::rotate_left(vreinterpretq_s8_u8(a), rhs))
+        }
+        for Wasm(a: v128, rhs: i32) -> v128 {
+            Ops::<Wasm, i8>::rotate_left(a, rhs)
+        }
+    }
+}
+
+impl_op! {
+    fn rotate_right<u8> {
+        for Avx512(a: __m512i, rhs: i32) -> __m512i {
+            Ops::<Avx512, i8>::rotate_right(a, rhs)
+        }
+        for Avx2(a: __m256i, rhs: i32) -> __m256i {
+            Ops::<Avx2, i8>::rotate_right(a, rhs)
+        }
+        for Sse41(a: __m128i, rhs: i32) -> __m128i {
+            Ops::<Sse41, i8>::rotate_right(a, rhs)
+        }
+        for Sse2(a: __m128i, rhs: i32) -> __m128i {
+            Ops::<Sse2, i8>::rotate_right(a, rhs)
+        }
+        for Scalar(a: u8, rhs: i32) -> u8 {
+            a.rotate_right(rhs as u32)
+        }
+        for Neon(a: uint8x16_t, rhs: i32) -> uint8x16_t {
+            vreinterpretq_u8_s8(Ops::<Neon, i8>::rotate_right(vreinterpretq_s8_u8(a), rhs))
+        }
+        for Wasm(a: v128, rhs: i32) -> v128 {
+            Ops::<Wasm, i8>::rotate_right(a, rhs)
+        }
+    }
+}
+
+impl_op! {
+    fn rotate_left_lanes<u8> {
+        for Avx512(a: __m512i, counts: __m512i) -> __m512i {
+            Ops::<Avx512, i8>::rotate_left_lanes(a, counts)
+        }
+        for Avx2(a: __m256i, counts: __m256i) -> __m256i {
+            Ops::<Avx2, i8>::rotate_left_lanes(a, counts)
+        }
+        for Sse41(a: __m128i, counts: __m128i) -> __m128i {
+            Ops::<Sse41, i8>::rotate_left_lanes(a, counts)
+        }
+        for Sse2(a: __m128i, counts: __m128i) -> __m128i {
+            Ops::<Sse2, i8>::rotate_left_lanes(a, counts)
+        }
+        for Scalar(a: u8, counts: u8) -> u8 {
+            a.rotate_left(counts as u32)
+        }
+        for Neon(a: uint8x16_t, counts: uint8x16_t) -> uint8x16_t {
+            let counts = vreinterpretq_s8_u8(counts);
+            vreinterpretq_u8_s8(Ops::<Neon, i8>::rotate_left_lanes(vreinterpretq_s8_u8(a), counts))
+        }
+        for Wasm(a: v128, counts: v128) -> v128 {
+            Ops::<Wasm, i8>::rotate_left_lanes(a, counts)
+        }
+    }
+}
+
+impl_op! {
+    fn rotate_right_lanes<u8> {
+        for Avx512(a: __m512i, counts: __m512i) -> __m512i {
+            Ops::<Avx512, i8>::rotate_right_lanes(a, counts)
+        }
+        for Avx2(a: __m256i, counts: __m256i) -> __m256i {
+            Ops::<Avx2, i8>::rotate_right_lanes(a, counts)
+        }
+        for Sse41(a: __m128i, counts: __m128i) -> __m128i {
+            Ops::<Sse41, i8>::rotate_right_lanes(a, counts)
+        }
+        for Sse2(a: __m128i, counts: __m128i) -> __m128i {
+            Ops::<Sse2, i8>::rotate_right_lanes(a, counts)
+        }
+        for Scalar(a: u8, counts: u8) -> u8 {
+            a.rotate_right(counts as u32)
+        }
+        for Neon(a: uint8x16_t, counts: uint8x16_t) -> uint8x16_t {
+            let counts = vreinterpretq_s8_u8(counts);
+            vreinterpretq_u8_s8(Ops::<Neon, i8>::rotate_right_lanes(vreinterpretq_s8_u8(a), counts))
+        }
+        for Wasm(a: v128, counts: v128) -> v128 {
+            Ops::<Wasm, i8>::rotate_right_lanes(a, counts)
+        }
+    }
+}
+
+impl_op! {
+    fn count_ones<u8> {
+        for Avx512(a: __m512i) -> __m512i {
+            Ops::<Avx512, i8>::count_ones(a)
+        }
+        for Avx2(a: __m256i) -> __m256i {
+            Ops::<Avx2, i8>::count_ones(a)
+        }
+        for Sse41(a: __m128i) -> __m128i {
+            Ops::<Sse41, i8>::count_ones(a)
+        }
+        for Sse2(a: __m128i) -> __m128i {
+            Ops::<Sse2, i8>::count_ones(a)
+        }
+        for Scalar(a: u8) -> u8 {
+            a.count_ones() as u8
+        }
+        for Neon(a: uint8x16_t) -> uint8x16_t {
+            vreinterpretq_u8_s8(Ops::<Neon, i8>::count_ones(vreinterpretq_s8_u8(a)))
+        }
+        for Wasm(a: v128) -> v128 {
+            Ops::<Wasm, i8>::count_ones(a)
+        }
+    }
+}
+
+impl_op! {
+    fn leading_zeros<u8> {
+        for Avx512(a: __m512i) -> __m512i {
+            Ops::<Avx512, i8>::leading_zeros(a)
+        }
+        for Avx2(a: __m256i) -> __m256i {
+            Ops::<Avx2, i8>::leading_zeros(a)
+        }
+        for Sse41(a: __m128i) -> __m128i {
+            Ops::<Sse41, i8>::leading_zeros(a)
+        }
+        for Sse2(a: __m128i) -> __m128i {
+            Ops::<Sse2, i8>::leading_zeros(a)
+        }
+        for Scalar(a: u8) -> u8 {
+            a.leading_zeros() as u8
+        }
+        for Neon(a: uint8x16_t) -> uint8x16_t {
+            vreinterpretq_u8_s8(Ops::<Neon, i8>::leading_zeros(vreinterpretq_s8_u8(a)))
+        }
+        for Wasm(a: v128) -> v128 {
+            Ops::<Wasm, i8>::leading_zeros(a)
+        }
+    }
+}
+
+impl_op! {
+    fn trailing_zeros<u8> {
+        for Avx512(a: __m512i) -> __m512i {
+            Ops::<Avx512, i8>::trailing_zeros(a)
+        }
+        for Avx2(a: __m256i) -> __m256i {
+            Ops::<Avx2, i8>::trailing_zeros(a)
+        }
+        for Sse41(a: __m128i) -> __m128i {
+            Ops::<Sse41, i8>::trailing_zeros(a)
+        }
+        for Sse2(a: __m128i) -> __m128i {
+            Ops::<Sse2, i8>::trailing_zeros(a)
+        }
+        for Scalar(a: u8) -> u8 {
+            a.trailing_zeros() as u8
+        }
+        for Neon(a: uint8x16_t) -> uint8x16_t {
+            vreinterpretq_u8_s8(Ops::<Neon, i8>::trailing_zeros(vreinterpretq_s8_u8(a)))
+        }
+        for Wasm(a: v128) -> v128 {
+            Ops::<Wasm, i8>::trailing_zeros(a)
+        }
+    }
+}
+
+impl_op! {
+    fn reverse_bits<u8> {
+        for Avx512(a: __m512i) -> __m512i {
+            Ops::<Avx512, i8>::reverse_bits(a)
+        }
+        for Avx2(a: __m256i) -> __m256i {
+            Ops::<Avx2, i8>::reverse_bits(a)
+        }
+        for Sse41(a: __m128i) -> __m128i {
+            Ops::<Sse41, i8>::reverse_bits(a)
+        }
+        for Sse2(a: __m128i) -> __m128i {
+            Ops::<Sse2, i8>::reverse_bits(a)
+        }
+        for Scalar(a: u8) -> u8 {
+            a.reverse_bits()
+        }
+        for Neon(a: uint8x16_t) -> uint8x16_t {
+            vreinterpretq_u8_s8(Ops::<Neon, i8>::reverse_bits(vreinterpretq_s8_u8(a)))
+        }
+        for Wasm(a: v128) -> v128 {
+            Ops::<Wasm, i8>::reverse_bits(a)
+        }
+    }
+}
+
+impl_op! {
+    fn popcount_total<u8> {
+        for Avx512(a: __m512i) -> u32 {
+            Ops::<Avx512, i8>::popcount_total(a)
+        }
+        for Avx2(a: __m256i) -> u32 {
+            Ops::<Avx2, i8>::popcount_total(a)
+        }
+        for Sse41(a: __m128i) -> u32 {
+            Ops::<Sse41, i8>::popcount_total(a)
+        }
+        for Sse2(a: __m128i) -> u32 {
+            Ops::<Sse2, i8>::popcount_total(a)
+        }
+        for Scalar(a: u8) -> u32 {
+            a.count_ones()
+        }
+        for Neon(a: uint8x16_t) -> u32 {
+            Ops::<Neon, i8>::popcount_total(vreinterpretq_s8_u8(a))
+        }
+        for Wasm(a: v128) -> u32 {
+            Ops::<Wasm, i8>::popcount_total(a)
+        }
+    }
+}
@@ -353,6 +353,18 @@ macro_rules! horizontal_add_scalar {
     (i64) => {
         i64
     };
+    (u8) => {
+        u64
+    };
+    (u16) => {
+        u64
+    };
+    (u32) => {
+        u64
+    };
+    (u64) => {
+        u64
+    };
     (f32) => {
         f32
     };
@@ -361,6 +373,15 @@ macro_rules! horizontal_add_scalar {
     };
 }
 
+macro_rules! mask_type {
+    (Avx512, $ty:ty) => {
+        KMask<$ty>
+    };
+    ($engine:ident, $ty:ty) => {
+        LaneMask<$ty>
+    };
+}
+
 macro_rules! define_simd_type {
     (Scalar, $ty:ty, $width:literal, $underlying:ty) => {
         paste::item! {
@@ -373,8 +394,10 @@ macro_rules! define_simd_type {
                 type Scalar = $ty;
                 type HorizontalAddScalar = horizontal_add_scalar!($ty);
                 type ArrayRepresentation = [$ty; $width];
+                type Tile = [Self; $width];
                 type UnderlyingType = $underlying;
                 type Engine = Scalar;
+                type Mask = LaneMask<Self>;
             }
 
             impl [<SimdTransmute $ty:upper>] for [<$ty:upper x $width>] {
@@ -402,8 +425,10 @@ macro_rules! define_simd_type {
                 type Scalar = $ty;
                 type HorizontalAddScalar = horizontal_add_scalar!($ty);
                 type ArrayRepresentation = [$ty; $width];
+                type Tile = [Self; $width];
                 type UnderlyingType = $underlying;
                 type Engine = $engine;
+                type Mask = mask_type!($engine, Self);
             }
 
             impl [<SimdTransmute $ty:upper>] for [<$ty:upper x $width>] {
@@ -430,8 +455,10 @@ macro_rules! define_simd_type {
                 type Scalar = $ty;
                 type HorizontalAddScalar = horizontal_add_scalar!($ty);
                 type ArrayRepresentation = [$ty; $width];
+                type Tile = [Self; $width];
                 type UnderlyingType = $underlying;
                 type Engine = $engine;
+                type Mask = mask_type!($engine, Self);
             }
 
             impl [<SimdTransmute $ty:upper>] for [<$ty:upper x $width $suffix >] {
@@ -449,10 +476,735 @@ macro_rules! define_simd_type {
     };
 }
 
+macro_rules! permute_lanes {
+    ($engine:ident, $ty:ident, $scalar_ty:ident, $lane_ty:ident, $value:expr, |$lane:ident| $index:expr) => {
+        paste::paste! {
+            unsafe {
+                let mut lanes = [0 as $lane_ty; <$ty as SimdConsts>::WIDTH];
+                // Lane arithmetic is written against WIDTH, which is 1 for the scalar engine.
+                #[allow(clippy::modulo_one)]
+                for ($lane, slot) in lanes.iter_mut().enumerate() {
+                    *slot = ($index) as $lane_ty;
+                }
+                let lanes = Ops::<$engine, $lane_ty>::load_unaligned(lanes.as_ptr());
+                let value = Ops::<$engine, $scalar_ty>::bitcast_binary($value.0);
+                let value = Ops::<$engine, binary>::[<bitcast_ $lane_ty>](value);
+                let result = Ops::<$engine, $lane_ty>::permute(value, lanes);
+                let result = Ops::<$engine, $lane_ty>::bitcast_binary(result);
+                $ty(Ops::<$engine, binary>::[<bitcast_ $scalar_ty>](result))
+            }
+        }
+    };
+}
+
+macro_rules! permute2_lanes {
+    ($engine:ident, $ty:ident, $scalar_ty:ident, $lane_ty:ident, $a:expr, $b:expr, |$lane:ident| $index:expr) => {
+        paste::paste! {
+            unsafe {
+                let mut lanes = [0 as $lane_ty; <$ty as SimdConsts>::WIDTH];
+                // Lane arithmetic is written against WIDTH, which is 1 for the scalar engine.
+                #[allow(clippy::modulo_one)]
+                for ($lane, slot) in lanes.iter_mut().enumerate() {
+                    *slot = ($index) as $lane_ty;
+                }
+                let lanes = Ops::<$engine, $lane_ty>::load_unaligned(lanes.as_ptr());
+                let a = Ops::<$engine, $scalar_ty>::bitcast_binary($a.0);
+                let a = Ops::<$engine, binary>::[<bitcast_ $lane_ty>](a);
+                let b = Ops::<$engine, $scalar_ty>::bitcast_binary($b.0);
+                let b = Ops::<$engine, binary>::[<bitcast_ $lane_ty>](b);
+                let result = Ops::<$engine, $lane_ty>::permute2(a, b, lanes);
+                let result = Ops::<$engine, $lane_ty>::bitcast_binary(result);
+                $ty(Ops::<$engine, binary>::[<bitcast_ $scalar_ty>](result))
+            }
+        }
+    };
+}
+
+/// Transposes a tile by swapping the off-diagonal blocks of every 2x2 grid of `stride`-lane
+/// blocks, from single lanes up to half the tile. Each stage is a fixed shuffle of `$bits` bit
+/// blocks per pair of rows; stages outside the lane and register widths are skipped.
+macro_rules! transpose_stages {
+    ($engine:ident, $scalar_ty:ident, $rows:ident, [$($bits:literal),+]) => {
+        paste::paste! {
+            $(
+                let stride = $bits / (8 * core::mem::size_of::<$scalar_ty>());
+                if (1..Self::WIDTH).contains(&stride) {
+                    for row in (0..Self::WIDTH).filter(|row| row & stride == 0) {
+                        unsafe {
+                            let upper = Ops::<$engine, $scalar_ty>::bitcast_binary($rows[row].0);
+                            let lower = Ops::<$engine, $scalar_ty>::bitcast_binary($rows[row + stride].0);
+                            let (upper, lower) = Ops::<$engine, binary>::transpose_blocks::<$bits>(upper, lower);
+                            $rows[row] = Self(Ops::<$engine, binary>::[<bitcast_ $scalar_ty>](upper));
+                            $rows[row + stride] = Self(Ops::<$engine, binary>::[<bitcast_ $scalar_ty>](lower));
+                        }
+                    }
+                }
+            )+
+        }
+    };
+}
+
+macro_rules! interleaved_io {
+    (Neon, $ty:ident, $scalar_ty:ident, $lane_ty:ident) => {
+        #[inline(always)]
+        fn load_interleaved2(slice: &[Self::Scalar]) -> (Self, Self) {
+            assert!(
+                slice.len() >= 2 * Self::WIDTH,
+                "slice is too short for 2 interleaved vectors"
+            );
+            let (a, b) = unsafe { Ops::<Neon, $scalar_ty>::load_interleaved2(slice.as_ptr()) };
+            (Self(a), Self(b))
+        }
+
+        #[inline(always)]
+        fn load_interleaved3(slice: &[Self::Scalar]) -> (Self, Self, Self) {
+            assert!(
+                slice.len() >= 3 * Self::WIDTH,
+                "slice is too short for 3 interleaved vectors"
+            );
+            let (a, b, c) = unsafe { Ops::<Neon, $scalar_ty>::load_interleaved3(slice.as_ptr()) };
+            (Self(a), Self(b), Self(c))
+        }
+
+        #[inline(always)]
+        fn load_interleaved4(slice: &[Self::Scalar]) -> (Self, Self, Self, Self) {
+            assert!(
+                slice.len() >= 4 * Self::WIDTH,
+                "slice is too short for 4 interleaved vectors"
+            );
+            let (a, b, c, d) =
+                unsafe { Ops::<Neon, $scalar_ty>::load_interleaved4(slice.as_ptr()) };
+            (Self(a), Self(b), Self(c), Self(d))
+        }
+
+        #[inline(always)]
+        fn store_interleaved2((a, b): (Self, Self), slice: &mut [Self::Scalar]) {
+            assert!(
+                slice.len() >= 2 * Self::WIDTH,
+                "slice is too short for 2 interleaved vectors"
+            );
+            unsafe { Ops::<Neon, $scalar_ty>::store_interleaved2(slice.as_mut_ptr(), a.0, b.0) }
+        }
+
+        #[inline(always)]
+        fn store_interleaved3((a, b, c): (Self, Self, Self), slice: &mut [Self::Scalar]) {
+            assert!(
+                slice.len() >= 3 * Self::WIDTH,
+                "slice is too short for 3 interleaved vectors"
+            );
+            unsafe {
+                Ops::<Neon, $scalar_ty>::store_interleaved3(slice.as_mut_ptr(), a.0, b.0, c.0)
+            }
+        }
+
+        #[inline(always)]
+        fn store_interleaved4((a, b, c, d): (Self, Self, Self, Self), slice: &mut [Self::Scalar]) {
+            assert!(
+                slice.len() >= 4 * Self::WIDTH,
+                "slice is too short for 4 interleaved vectors"
+            );
+            unsafe {
+                Ops::<Neon, $scalar_ty>::store_interleaved4(slice.as_mut_ptr(), a.0, b.0, c.0, d.0)
+            }
+        }
+    };
+    ($engine:ident, $ty:ident, $scalar_ty:ident, $lane_ty:ident) => {
+        #[inline(always)]
+        fn load_interleaved2(slice: &[Self::Scalar]) -> (Self, Self) {
+            assert!(
+                slice.len() >= 2 * Self::WIDTH,
+                "slice is too short for 2 interleaved vectors"
+            );
+            let low = Self::load_from_slice(slice);
+            let high = Self::load_from_slice(&slice[Self::WIDTH..]);
+            (low.unzip_even(high), low.unzip_odd(high))
+        }
+
+        #[inline(always)]
+        fn load_interleaved3(slice: &[Self::Scalar]) -> (Self, Self, Self) {
+            assert!(
+                slice.len() >= 3 * Self::WIDTH,
+                "slice is too short for 3 interleaved vectors"
+            );
+            let first = Self::load_from_slice(slice);
+            let second = Self::load_from_slice(&slice[Self::WIDTH..]);
+            let third = Self::load_from_slice(&slice[2 * Self::WIDTH..]);
+            // Gather each channel from the first two vectors, then patch in the lanes that come
+            // from the third.
+            let channel = |channel: usize| {
+                let head =
+                    permute2_lanes!($engine, $ty, $scalar_ty, $lane_ty, first, second, |lane| {
+                        (3 * lane + channel) % (2 * Self::WIDTH)
+                    });
+                permute2_lanes!($engine, $ty, $scalar_ty, $lane_ty, head, third, |lane| {
+                    let index = 3 * lane + channel;
+                    if index < 2 * Self::WIDTH {
+                        lane
+                    } else {
+                        index - Self::WIDTH
+                    }
+                })
+            };
+            (channel(0), channel(1), channel(2))
+        }
+
+        #[inline(always)]
+        fn load_interleaved4(slice: &[Self::Scalar]) -> (Self, Self, Self, Self) {
+            assert!(
+                slice.len() >= 4 * Self::WIDTH,
+                "slice is too short for 4 interleaved vectors"
+            );
+            let (first, second) = Self::load_interleaved2(slice);
+            let (third, fourth) = Self::load_interleaved2(&slice[2 * Self::WIDTH..]);
+            // `first` and `third` hold channels 0 and 2 interleaved, `second` and `fourth` 1 and 3.
+            (
+                first.unzip_even(third),
+                second.unzip_even(fourth),
+                first.unzip_odd(third),
+                second.unzip_odd(fourth),
+            )
+        }
+
+        #[inline(always)]
+        fn store_interleaved2((a, b): (Self, Self), slice: &mut [Self::Scalar]) {
+            assert!(
+                slice.len() >= 2 * Self::WIDTH,
+                "slice is too short for 2 interleaved vectors"
+            );
+            a.zip_lo(b).copy_to_slice(slice);
+            a.zip_hi(b).copy_to_slice(&mut slice[Self::WIDTH..]);
+        }
+
+        #[inline(always)]
+        fn store_interleaved3((a, b, c): (Self, Self, Self), slice: &mut [Self::Scalar]) {
+            assert!(
+                slice.len() >= 3 * Self::WIDTH,
+                "slice is too short for 3 interleaved vectors"
+            );
+            // Position `p` of the output comes from lane `p / 3` of channel `p % 3`.
+            let part = |part: usize| {
+                let head = permute2_lanes!($engine, $ty, $scalar_ty, $lane_ty, a, b, |lane| {
+                    let position = part * Self::WIDTH + lane;
+                    let source = position / 3;
+                    if position % 3 == 1 {
+                        Self::WIDTH + source
+                    } else {
+                        source
+                    }
+                });
+                permute2_lanes!($engine, $ty, $scalar_ty, $lane_ty, head, c, |lane| {
+                    let position = part * Self::WIDTH + lane;
+                    if position % 3 == 2 {
+                        Self::WIDTH + position / 3
+                    } else {
+                        lane
+                    }
+                })
+            };
+            part(0).copy_to_slice(slice);
+            part(1).copy_to_slice(&mut slice[Self::WIDTH..]);
+            part(2).copy_to_slice(&mut slice[2 * Self::WIDTH..]);
+        }
+
+        #[inline(always)]
+        fn store_interleaved4((a, b, c, d): (Self, Self, Self, Self), slice: &mut [Self::Scalar]) {
+            assert!(
+                slice.len() >= 4 * Self::WIDTH,
+                "slice is too short for 4 interleaved vectors"
+            );
+            let (even_low, even_high) = (a.zip_lo(c), a.zip_hi(c));
+            let (odd_low, odd_high) = (b.zip_lo(d), b.zip_hi(d));
+            Self::store_interleaved2((even_low, odd_low), slice);
+            Self::store_interleaved2((even_high, odd_high), &mut slice[2 * Self::WIDTH..]);
+        }
+    };
+}
+
+macro_rules! compress_ops {
+    (Avx512, $ty:ident, $scalar_ty:ident, $lane_ty:ident) => {
+        #[inline(always)]
+        fn compress(self, mask: Self::Mask) -> (Self, usize) {
+            let count = mask.count_ones() as usize;
+            paste::paste! {
+                unsafe {
+                    let value = Ops::<Avx512, $scalar_ty>::bitcast_binary(self.0);
+                    let value = Ops::<Avx512, binary>::[<bitcast_ $lane_ty>](value);
+                    let packed = Ops::<Avx512, $lane_ty>::compress(value, mask.0);
+                    let packed = Ops::<Avx512, $lane_ty>::bitcast_binary(packed);
+                    (Self(Ops::<Avx512, binary>::[<bitcast_ $scalar_ty>](packed)), count)
+                }
+            }
+        }
+
+        #[inline(always)]
+        fn compress_store(self, slice: &mut [Self::Scalar], mask: Self::Mask) -> usize {
+            let count = mask.count_ones() as usize;
+            assert!(
+                slice.len() >= count,
+                "slice is too short for the compressed lanes"
+            );
+            paste::paste! {
+                unsafe {
+                    let value = Ops::<Avx512, $scalar_ty>::bitcast_binary(self.0);
+                    let value = Ops::<Avx512, binary>::[<bitcast_ $lane_ty>](value);
+                    let ptr = slice.as_mut_ptr() as *mut $lane_ty;
+                    Ops::<Avx512, $lane_ty>::compress_store(ptr, value, mask.0);
+                }
+            }
+            count
+        }
+
+        #[inline(always)]
+        fn expand(self, mask: Self::Mask) -> Self {
+            paste::paste! {
+                unsafe {
+                    let value = Ops::<Avx512, $scalar_ty>::bitcast_binary(self.0);
+                    let value = Ops::<Avx512, binary>::[<bitcast_ $lane_ty>](value);
+                    let spread = Ops::<Avx512, $lane_ty>::expand(value, mask.0);
+                    let spread = Ops::<Avx512, $lane_ty>::bitcast_binary(spread);
+                    Self(Ops::<Avx512, binary>::[<bitcast_ $scalar_ty>](spread))
+                }
+            }
+        }
+    };
+    ($engine:ident, $ty:ident, $scalar_ty:ident, $lane_ty:ident) => {
+        #[inline(always)]
+        fn compress(self, mask: Self::Mask) -> (Self, usize) {
+            let (indices, count) = compress_indices(mask.to_bitmask(), Self::WIDTH);
+            let packed = permute2_lanes!(
+                $engine,
+                $ty,
+                $scalar_ty,
+                $lane_ty,
+                self,
+                Self::zeroes(),
+                |lane| indices[lane]
+            );
+            (packed, count)
+        }
+
+        #[inline(always)]
+        fn compress_store(self, slice: &mut [Self::Scalar], mask: Self::Mask) -> usize {
+            let (packed, count) = self.compress(mask);
+            assert!(
+                slice.len() >= count,
+                "slice is too short for the compressed lanes"
+            );
+            packed.store_partial(&mut slice[..count], Self::Mask::splat(true));
+            count
+        }
+
+        #[inline(always)]
+        fn expand(self, mask: Self::Mask) -> Self {
+            let indices = expand_indices(mask.to_bitmask(), Self::WIDTH);
+            permute2_lanes!(
+                $engine,
+                $ty,
+                $scalar_ty,
+                $lane_ty,
+                self,
+                Self::zeroes(),
+                |lane| indices[lane]
+            )
+        }
+    };
+}
+
+/// The mask operand of the lane ops: the `__mmask*` bits of a [`KMask`] on AVX-512, and the mask
+/// as a vector of `$lane_ty` lanes elsewhere.
+macro_rules! mask_operand {
+    (Avx512, $scalar_ty:ident, $lane_ty:ident, $mask:expr) => {
+        $mask.0
+    };
+    ($engine:ident, $scalar_ty:ident, $lane_ty:ident, $mask:expr) => {
+        paste::paste! {{
+            let mask = Ops::<$engine, $scalar_ty>::bitcast_binary($mask.to_vector().0);
+            Ops::<$engine, binary>::[<bitcast_ $lane_ty>](mask)
+        }}
+    };
+}
+
+macro_rules! impl_simd_index {
+    ($engine:ident, $ty:ident, $scalar_ty:ident, $lane_ty:ident, $index_ty:ident, $index_trait:ident) => {
+        impl $index_trait for $ty {
+            #[inline(always)]
+            fn index_lanes(self) -> $index_ty {
+                paste::paste! {
+                    unsafe {
+                        let value = Ops::<$engine, $scalar_ty>::bitcast_binary(self.0);
+                        $index_ty(Ops::<$engine, binary>::[<bitcast_ $lane_ty>](value))
+                    }
+                }
+            }
+        }
+    };
+}
+
+macro_rules! impl_simd_gather {
+    ($engine:ident, $ty:ident, $scalar_ty:ident, $lane_ty:ident, $index_ty:ident, $trait:ident, $index_trait:ident $(, $extra:ident)?) => {
+        paste::paste! {
+            impl $ty {
+                #[inline(always)]
+                fn to_lanes(self) -> $index_ty {
+                    unsafe {
+                        let value = Ops::<$engine, $scalar_ty>::bitcast_binary(self.0);
+                        $index_ty(Ops::<$engine, binary>::[<bitcast_ $lane_ty>](value))
+                    }
+                }
+
+                #[inline(always)]
+                fn from_lanes(value: $index_ty) -> Self {
+                    unsafe {
+                        let value = Ops::<$engine, $lane_ty>::bitcast_binary(value.0);
+                        Self(Ops::<$engine, binary>::[<bitcast_ $scalar_ty>](value))
+                    }
+                }
+
+                #[inline(always)]
+                fn check_lane_indices<I: SimdBaseIo>(
+                    indices: I,
+                    mask: Option<<Self as SimdConsts>::Mask>,
+                    len: usize,
+                ) where
+                    I::Scalar: Into<i64>,
+                {
+                    let active = mask.map_or(u64::MAX, SimdMask::to_bitmask);
+                    for lane in 0..Self::WIDTH {
+                        unsafe {
+                            if active >> lane & 1 != 0 {
+                                let index: i64 = indices.get_unchecked(lane).into();
+                                assert!(
+                                    index >= 0 && (index as u64) < len as u64,
+                                    "index {} out of bounds for slice of length {}",
+                                    index,
+                                    len
+                                );
+                            }
+                        }
+                    }
+                }
+            }
+
+            impl $trait for $ty {
+                #[inline(always)]
+                fn gather<I: $index_trait<Engine = Self::Engine>>(
+                    base: &[$scalar_ty],
+                    indices: I,
+                ) -> Self {
+                    let indices = indices.index_lanes();
+                    Self::check_lane_indices(indices, None, base.len());
+                    unsafe { Self::gather_unchecked(base.as_ptr(), indices) }
+                }
+
+                #[inline(always)]
+                unsafe fn gather_unchecked<I: $index_trait<Engine = Self::Engine>>(
+                    base: *const $scalar_ty,
+                    indices: I,
+                ) -> Self {
+                    let indices = indices.index_lanes();
+                    let value = Ops::<$engine, $lane_ty>::gather(base as *const $lane_ty, indices.0);
+                    Self::from_lanes($index_ty(value))
+                }
+
+                #[inline(always)]
+                fn gather_masked<I: $index_trait<Engine = Self::Engine>>(
+                    base: &[$scalar_ty],
+                    indices: I,
+                    mask: Self::Mask,
+                    fallback: Self,
+                ) -> Self {
+                    let indices = indices.index_lanes();
+                    Self::check_lane_indices(indices, Some(mask), base.len());
+                    unsafe { Self::gather_masked_unchecked(base.as_ptr(), indices, mask, fallback) }
+                }
+
+                #[inline(always)]
+                unsafe fn gather_masked_unchecked<I: $index_trait<Engine = Self::Engine>>(
+                    base: *const $scalar_ty,
+                    indices: I,
+                    mask: Self::Mask,
+                    fallback: Self,
+                ) -> Self {
+                    let value = Ops::<$engine, $lane_ty>::gather_masked(
+                        fallback.to_lanes().0,
+                        base as *const $lane_ty,
+                        indices.index_lanes().0,
+                        mask_operand!($engine, $scalar_ty, $lane_ty, mask),
+                    );
+                    Self::from_lanes($index_ty(value))
+                }
+
+                #[inline(always)]
+                fn scatter<I: $index_trait<Engine = Self::Engine>>(
+                    self,
+                    base: &mut [$scalar_ty],
+                    indices: I,
+                ) {
+                    let indices = indices.index_lanes();
+                    Self::check_lane_indices(indices, None, base.len());
+                    unsafe { self.scatter_unchecked(base.as_mut_ptr(), indices) }
+                }
+
+                #[inline(always)]
+                unsafe fn scatter_unchecked<I: $index_trait<Engine = Self::Engine>>(
+                    self,
+                    base: *mut $scalar_ty,
+                    indices: I,
+                ) {
+                    Ops::<$engine, $lane_ty>::scatter(
+                        base as *mut $lane_ty,
+                        indices.index_lanes().0,
+                        self.to_lanes().0,
+                    )
+                }
+
+                #[inline(always)]
+                fn scatter_masked<I: $index_trait<Engine = Self::Engine>>(
+                    self,
+                    base: &mut [$scalar_ty],
+                    indices: I,
+                    mask: Self::Mask,
+                ) {
+                    let indices = indices.index_lanes();
+                    Self::check_lane_indices(indices, Some(mask), base.len());
+                    unsafe { self.scatter_masked_unchecked(base.as_mut_ptr(), indices, mask) }
+                }
+
+                #[inline(always)]
+                unsafe fn scatter_masked_unchecked<I: $index_trait<Engine = Self::Engine>>(
+                    self,
+                    base: *mut $scalar_ty,
+                    indices: I,
+                    mask: Self::Mask,
+                ) {
+                    Ops::<$engine, $lane_ty>::scatter_masked(
+                        base as *mut $lane_ty,
+                        indices.index_lanes().0,
+                        self.to_lanes().0,
+                        mask_operand!($engine, $scalar_ty, $lane_ty, mask),
+                    )
+                }
+
+                $($extra!($engine, $scalar_ty, $index_ty);)?
+            }
+        }
+    };
+}
+
+/// The `SimdGather64` methods that take 32 bit indices.
+macro_rules! impl_simd_gather_i32 {
+    ($engine:ident, $scalar_ty:ident, $index_ty:ident) => {
+        #[inline(always)]
+        fn gather_i32<I: SimdIndex32<Engine = Self::Engine>>(
+            base: &[$scalar_ty],
+            indices: I,
+        ) -> Self {
+            let indices = indices.index_lanes();
+            Self::check_lane_indices(indices, None, base.len());
+            unsafe { Self::gather_i32_unchecked(base.as_ptr(), indices) }
+        }
+
+        #[inline(always)]
+        unsafe fn gather_i32_unchecked<I: SimdIndex32<Engine = Self::Engine>>(
+            base: *const $scalar_ty,
+            indices: I,
+        ) -> Self {
+            let value =
+                Ops::<$engine, i64>::gather_i32(base as *const i64, indices.index_lanes().0);
+            Self::from_lanes($index_ty(value))
+        }
+
+        #[inline(always)]
+        fn gather_masked_i32<I: SimdIndex32<Engine = Self::Engine>>(
+            base: &[$scalar_ty],
+            indices: I,
+            mask: Self::Mask,
+            fallback: Self,
+        ) -> Self {
+            let indices = indices.index_lanes();
+            Self::check_lane_indices(indices, Some(mask), base.len());
+            unsafe { Self::gather_masked_i32_unchecked(base.as_ptr(), indices, mask, fallback) }
+        }
+
+        #[inline(always)]
+        unsafe fn gather_masked_i32_unchecked<I: SimdIndex32<Engine = Self::Engine>>(
+            base: *const $scalar_ty,
+            indices: I,
+            mask: Self::Mask,
+            fallback: Self,
+        ) -> Self {
+            let value = Ops::<$engine, i64>::gather_masked_i32(
+                fallback.to_lanes().0,
+                base as *const i64,
+                indices.index_lanes().0,
+                mask_operand!($engine, $scalar_ty, i64, mask),
+            );
+            Self::from_lanes($index_ty(value))
+        }
+
+        #[inline(always)]
+        fn scatter_i32<I: SimdIndex32<Engine = Self::Engine>>(
+            self,
+            base: &mut [$scalar_ty],
+            indices: I,
+        ) {
+            let indices = indices.index_lanes();
+            Self::check_lane_indices(indices, None, base.len());
+            unsafe { self.scatter_i32_unchecked(base.as_mut_ptr(), indices) }
+        }
+
+        #[inline(always)]
+        unsafe fn scatter_i32_unchecked<I: SimdIndex32<Engine = Self::Engine>>(
+            self,
+            base: *mut $scalar_ty,
+            indices: I,
+        ) {
+            Ops::<$engine, i64>::scatter_i32(
+                base as *mut i64,
+                indices.index_lanes().0,
+                self.to_lanes().0,
+            )
+        }
+
+        #[inline(always)]
+        fn scatter_masked_i32<I: SimdIndex32<Engine = Self::Engine>>(
+            self,
+            base: &mut [$scalar_ty],
+            indices: I,
+            mask: Self::Mask,
+        ) {
+            let indices = indices.index_lanes();
+            Self::check_lane_indices(indices, Some(mask), base.len());
+            unsafe { self.scatter_masked_i32_unchecked(base.as_mut_ptr(), indices, mask) }
+        }
+
+        #[inline(always)]
+        unsafe fn scatter_masked_i32_unchecked<I: SimdIndex32<Engine = Self::Engine>>(
+            self,
+            base: *mut $scalar_ty,
+            indices: I,
+            mask: Self::Mask,
+        ) {
+            Ops::<$engine, i64>::scatter_masked_i32(
+                base as *mut i64,
+                indices.index_lanes().0,
+                self.to_lanes().0,
+                mask_operand!($engine, $scalar_ty, i64, mask),
+            )
+        }
+    };
+}
+
+macro_rules! impl_simd_mask {
+    (Avx512, $ty:ident, $scalar_ty:ident, $lane_ty:ident) => {
+        paste::paste! {
+            impl KMask<$ty> {
+                /// Converts a compare result into its mask register.
+                #[inline(always)]
+                pub(crate) fn from_compare(vector: $ty) -> Self {
+                    Self::from_vector(vector)
+                }
+            }
+
+            impl SimdMask for KMask<$ty> {
+                type Vector = $ty;
+
+                #[inline(always)]
+                fn from_bitmask(bits: u64) -> Self {
+                    Self::from_bits(bits)
+                }
+
+                #[inline(always)]
+                fn to_bitmask(self) -> u64 {
+                    self.0
+                }
+
+                #[inline(always)]
+                fn from_vector(vector: $ty) -> Self {
+                    unsafe {
+                        let lanes = Ops::<Avx512, $scalar_ty>::bitcast_binary(vector.0);
+                        let lanes = Ops::<Avx512, binary>::[<bitcast_ $lane_ty>](lanes);
+                        Self::from_bits(Ops::<Avx512, $lane_ty>::bitmask(lanes))
+                    }
+                }
+
+                #[inline(always)]
+                fn to_vector(self) -> $ty {
+                    unsafe {
+                        let lanes = Ops::<Avx512, $lane_ty>::mask_to_vector(self.0);
+                        let lanes = Ops::<Avx512, $lane_ty>::bitcast_binary(lanes);
+                        $ty(Ops::<Avx512, binary>::[<bitcast_ $scalar_ty>](lanes))
+                    }
+                }
+
+                #[inline(always)]
+                fn select(self, if_true: $ty, if_false: $ty) -> $ty {
+                    unsafe { $ty(Ops::<Avx512, $scalar_ty>::mask_blend(self.0, if_false.0, if_true.0)) }
+                }
+            }
+        }
+    };
+    ($engine:ident, $ty:ident, $scalar_ty:ident, $lane_ty:ident) => {
+        paste::paste! {
+            impl SimdMask for LaneMask<$ty> {
+                type Vector = $ty;
+
+                #[inline(always)]
+                fn from_bitmask(bits: u64) -> Self {
+                    let mut lanes = [0 as $lane_ty; <$ty as SimdConsts>::WIDTH];
+                    for (i, lane) in lanes.iter_mut().enumerate() {
+                        if bits >> i & 1 != 0 {
+                            *lane = -1;
+                        }
+                    }
+                    unsafe {
+                        let lanes = Ops::<$engine, $lane_ty>::load_unaligned(lanes.as_ptr());
+                        let lanes = Ops::<$engine, $lane_ty>::bitcast_binary(lanes);
+                        Self($ty(Ops::<$engine, binary>::[<bitcast_ $scalar_ty>](lanes)))
+                    }
+                }
+
+                #[inline(always)]
+                fn to_bitmask(self) -> u64 {
+                    unsafe {
+                        let lanes = Ops::<$engine, $scalar_ty>::bitcast_binary(self.0 .0);
+                        let lanes = Ops::<$engine, binary>::[<bitcast_ $lane_ty>](lanes);
+                        Ops::<$engine, $lane_ty>::bitmask(lanes)
+                    }
+                }
+
+                #[inline(always)]
+                fn from_vector(vector: $ty) -> Self {
+                    unsafe {
+                        // Spread the highest bit across the lane so every backend's blend agrees.
+                        let lanes = Ops::<$engine, $scalar_ty>::bitcast_binary(vector.0);
+                        let lanes = Ops::<$engine, binary>::[<bitcast_ $lane_ty>](lanes);
+                        let lanes = Ops::<$engine, $lane_ty>::gt(Ops::<$engine, $lane_ty>::zeroes(), lanes);
+                        let lanes = Ops::<$engine, $lane_ty>::bitcast_binary(lanes);
+                        Self($ty(Ops::<$engine, binary>::[<bitcast_ $scalar_ty>](lanes)))
+                    }
+                }
+
+                #[inline(always)]
+                fn to_vector(self) -> $ty {
+                    self.0
+                }
+
+                #[inline(always)]
+                fn select(self, if_true: $ty, if_false: $ty) -> $ty {
+                    self.0.blendv(if_false, if_true)
+                }
+            }
+        }
+    };
+}
+
 macro_rules! impl_simd_base {
-    ($engine:ident, $ty:ident, $scalar_ty:ident, |$self:ident| {
+    ($engine:ident, $ty:ident, $scalar_ty:ident, $lane_ty:ident, |$self:ident| {
         $($hadd:tt)*
     }) => {
+        impl_simd_mask!($engine, $ty, $scalar_ty, $lane_ty);
+
         impl SimdBaseIo for $ty {
             #[inline(always)]
             fn zeroes() -> Self {
@@ -479,6 +1231,44 @@ macro_rules! impl_simd_base {
                 unsafe { Ops::<$engine, $scalar_ty>::store_unaligned(ptr, self.0) }
             }
 
+            #[inline(always)]
+            unsafe fn masked_load(ptr: *const Self::Scalar, mask: Self::Mask) -> Self {
+                paste::paste! {
+                    unsafe {
+                        let mask = mask_operand!($engine, $scalar_ty, $lane_ty, mask);
+                        let value = Ops::<$engine, $lane_ty>::masked_load(ptr as *const $lane_ty, mask);
+                        let value = Ops::<$engine, $lane_ty>::bitcast_binary(value);
+                        Self(Ops::<$engine, binary>::[<bitcast_ $scalar_ty>](value))
+                    }
+                }
+            }
+
+            #[inline(always)]
+            unsafe fn masked_store(self, ptr: *mut Self::Scalar, mask: Self::Mask) {
+                paste::paste! {
+                    unsafe {
+                        let mask = mask_operand!($engine, $scalar_ty, $lane_ty, mask);
+                        let value = Ops::<$engine, $scalar_ty>::bitcast_binary(self.0);
+                        let value = Ops::<$engine, binary>::[<bitcast_ $lane_ty>](value);
+                        Ops::<$engine, $lane_ty>::masked_store(ptr as *mut $lane_ty, value, mask)
+                    }
+                }
+            }
+
+            #[inline(always)]
+            fn load_partial(slice: &[Self::Scalar]) -> (Self, Self::Mask) {
+                let mask = Self::Mask::from_bitmask(lane_bits(slice.len()));
+                unsafe { (Self::masked_load(slice.as_ptr(), mask), mask) }
+            }
+
+            #[inline(always)]
+            fn store_partial(self, slice: &mut [Self::Scalar], mask: Self::Mask) {
+                let in_bounds = Self::Mask::from_bitmask(lane_bits(slice.len()));
+                unsafe { self.masked_store(slice.as_mut_ptr(), mask & in_bounds) }
+            }
+
+            interleaved_io!($engine, $ty, $scalar_ty, $lane_ty);
+
             #[inline(always)]
             unsafe fn load_from_ptr_aligned(ptr: *const Self::Scalar) -> Self {
                 unsafe { Self(Ops::<$engine, $scalar_ty>::load_aligned(ptr)) }
@@ -591,33 +1381,33 @@ macro_rules! impl_simd_base {
             }
 
             #[inline(always)]
-            fn cmp_eq(self, rhs: Self) -> Self {
-                unsafe { Self(Ops::<$engine, $scalar_ty>::eq(self.0, rhs.0)) }
+            fn cmp_eq(self, rhs: Self) -> Self::Mask {
+                Self::Mask::from_compare(unsafe { Self(Ops::<$engine, $scalar_ty>::eq(self.0, rhs.0)) })
             }
 
             #[inline(always)]
-            fn cmp_neq(self, rhs: Self) -> Self {
-                unsafe { Self(Ops::<$engine, $scalar_ty>::neq(self.0, rhs.0)) }
+            fn cmp_neq(self, rhs: Self) -> Self::Mask {
+                Self::Mask::from_compare(unsafe { Self(Ops::<$engine, $scalar_ty>::neq(self.0, rhs.0)) })
             }
 
             #[inline(always)]
-            fn cmp_lt(self, rhs: Self) -> Self {
-                unsafe { Self(Ops::<$engine, $scalar_ty>::lt(self.0, rhs.0)) }
+            fn cmp_lt(self, rhs: Self) -> Self::Mask {
+                Self::Mask::from_compare(unsafe { Self(Ops::<$engine, $scalar_ty>::lt(self.0, rhs.0)) })
             }
 
             #[inline(always)]
-            fn cmp_lte(self, rhs: Self) -> Self {
-                unsafe { Self(Ops::<$engine, $scalar_ty>::lte(self.0, rhs.0)) }
+            fn cmp_lte(self, rhs: Self) -> Self::Mask {
+                Self::Mask::from_compare(unsafe { Self(Ops::<$engine, $scalar_ty>::lte(self.0, rhs.0)) })
             }
 
             #[inline(always)]
-            fn cmp_gt(self, rhs: Self) -> Self {
-                unsafe { Self(Ops::<$engine, $scalar_ty>::gt(self.0, rhs.0)) }
+            fn cmp_gt(self, rhs: Self) -> Self::Mask {
+                Self::Mask::from_compare(unsafe { Self(Ops::<$engine, $scalar_ty>::gt(self.0, rhs.0)) })
             }
 
             #[inline(always)]
-            fn cmp_gte(self, rhs: Self) -> Self {
-                unsafe { Self(Ops::<$engine, $scalar_ty>::gte(self.0, rhs.0)) }
+            fn cmp_gte(self, rhs: Self) -> Self::Mask {
+                Self::Mask::from_compare(unsafe { Self(Ops::<$engine, $scalar_ty>::gte(self.0, rhs.0)) })
             }
 
             #[inline(always)]
@@ -630,6 +1420,95 @@ macro_rules! impl_simd_base {
                 unsafe { Self(Ops::<$engine, $scalar_ty>::min(self.0, rhs.0)) }
             }
 
+            #[inline(always)]
+            fn shuffle<const N: usize>(self, indices: [usize; N]) -> Self {
+                assert!(N.is_power_of_two(), "shuffle patterns must have a power of two length");
+                let group = if N < Self::WIDTH { N } else { Self::WIDTH };
+                permute_lanes!($engine, $ty, $scalar_ty, $lane_ty, self, |lane| {
+                    lane - lane % group + indices[lane % group] % group
+                })
+            }
+
+            #[inline(always)]
+            fn shuffle2<const N: usize>(self, other: Self, indices: [usize; N]) -> Self {
+                assert!(N.is_power_of_two(), "shuffle patterns must have a power of two length");
+                let group = if N < Self::WIDTH { N } else { Self::WIDTH };
+                permute2_lanes!($engine, $ty, $scalar_ty, $lane_ty, self, other, |lane| {
+                    let index = indices[lane % group] % (2 * N);
+                    let source = if index < N { 0 } else { Self::WIDTH };
+                    source + lane - lane % group + index % N % group
+                })
+            }
+
+            #[inline(always)]
+            fn reverse_lanes(self) -> Self {
+                permute_lanes!($engine, $ty, $scalar_ty, $lane_ty, self, |lane| {
+                    Self::WIDTH - 1 - lane
+                })
+            }
+
+            #[inline(always)]
+            fn rotate_lanes_left<const BY: usize>(self) -> Self {
+                permute_lanes!($engine, $ty, $scalar_ty, $lane_ty, self, |lane| {
+                    (lane + BY) % Self::WIDTH
+                })
+            }
+
+            #[inline(always)]
+            fn rotate_lanes_right<const BY: usize>(self) -> Self {
+                permute_lanes!($engine, $ty, $scalar_ty, $lane_ty, self, |lane| {
+                    (lane + Self::WIDTH - BY % Self::WIDTH) % Self::WIDTH
+                })
+            }
+
+            #[inline(always)]
+            fn broadcast_lane<const LANE: usize>(self) -> Self {
+                permute_lanes!($engine, $ty, $scalar_ty, $lane_ty, self, |_lane| {
+                    LANE % Self::WIDTH
+                })
+            }
+
+            #[inline(always)]
+            fn zip_lo(self, other: Self) -> Self {
+                // Even positions of the interleaving come from `self`, odd ones from `other`.
+                permute2_lanes!($engine, $ty, $scalar_ty, $lane_ty, self, other, |lane| {
+                    lane % 2 * Self::WIDTH + lane / 2
+                })
+            }
+
+            #[inline(always)]
+            fn zip_hi(self, other: Self) -> Self {
+                permute2_lanes!($engine, $ty, $scalar_ty, $lane_ty, self, other, |lane| {
+                    (Self::WIDTH + lane) % 2 * Self::WIDTH + (Self::WIDTH + lane) / 2
+                })
+            }
+
+            #[inline(always)]
+            fn unzip_even(self, other: Self) -> Self {
+                permute2_lanes!($engine, $ty, $scalar_ty, $lane_ty, self, other, |lane| {
+                    2 * lane
+                })
+            }
+
+            #[inline(always)]
+            fn unzip_odd(self, other: Self) -> Self {
+                permute2_lanes!($engine, $ty, $scalar_ty, $lane_ty, self, other, |lane| {
+                    2 * lane + 1
+                })
+            }
+
+            #[inline(always)]
+            fn transpose(rows: &mut Self::Tile) {
+                transpose_stages!($engine, $scalar_ty, rows, [8, 16, 32, 64, 128, 256]);
+            }
+
+            #[inline(always)]
+            fn tile_from_fn(f: impl FnMut(usize) -> Self) -> Self::Tile {
+                core::array::from_fn(f)
+            }
+
+            compress_ops!($engine, $ty, $scalar_ty, $lane_ty);
+
             #[inline(always)]
             fn horizontal_add($self) -> Self::HorizontalAddScalar {
                 $($hadd)*
@@ -639,7 +1518,7 @@ macro_rules! impl_simd_base {
 }
 
 macro_rules! impl_simd_int {
-    ($engine:ident, $ty:ident, $scalar_ty:ident, |$self:ident| {
+    ($engine:ident, $ty:ident, $scalar_ty:ident, $lane_ty:ident, |$self:ident| {
         $($hadd:tt)*
     }) => {
         impl SimdInt for $ty {
@@ -663,6 +1542,86 @@ macro_rules! impl_simd_int {
                 unsafe { Self(Ops::<$engine, $scalar_ty>::shr_const::<BY>(self.0)) }
             }
 
+            #[inline(always)]
+            fn shr_arithmetic(self, rhs: i32) -> Self {
+                unsafe { Self(Ops::<$engine, $scalar_ty>::shr_arithmetic(self.0, rhs)) }
+            }
+
+            #[inline(always)]
+            fn shl_lanes(self, counts: Self) -> Self {
+                unsafe { Self(Ops::<$engine, $scalar_ty>::shl_lanes(self.0, counts.0)) }
+            }
+
+            #[inline(always)]
+            fn shr_lanes(self, counts: Self) -> Self {
+                unsafe { Self(Ops::<$engine, $scalar_ty>::shr_lanes(self.0, counts.0)) }
+            }
+
+            #[inline(always)]
+            fn shr_arithmetic_lanes(self, counts: Self) -> Self {
+                unsafe { Self(Ops::<$engine, $scalar_ty>::shr_arithmetic_lanes(self.0, counts.0)) }
+            }
+
+            #[inline(always)]
+            fn rotate_left(self, rhs: i32) -> Self {
+                unsafe { Self(Ops::<$engine, $scalar_ty>::rotate_left(self.0, rhs)) }
+            }
+
+            #[inline(always)]
+            fn rotate_right(self, rhs: i32) -> Self {
+                unsafe { Self(Ops::<$engine, $scalar_ty>::rotate_right(self.0, rhs)) }
+            }
+
+            #[inline(always)]
+            fn rotate_left_lanes(self, counts: Self) -> Self {
+                unsafe { Self(Ops::<$engine, $scalar_ty>::rotate_left_lanes(self.0, counts.0)) }
+            }
+
+            #[inline(always)]
+            fn rotate_right_lanes(self, counts: Self) -> Self {
+                unsafe { Self(Ops::<$engine, $scalar_ty>::rotate_right_lanes(self.0, counts.0)) }
+            }
+
+            #[inline(always)]
+            fn count_ones(self) -> Self {
+                unsafe { Self(Ops::<$engine, $scalar_ty>::count_ones(self.0)) }
+            }
+
+            #[inline(always)]
+            fn leading_zeros(self) -> Self {
+                unsafe { Self(Ops::<$engine, $scalar_ty>::leading_zeros(self.0)) }
+            }
+
+            #[inline(always)]
+            fn trailing_zeros(self) -> Self {
+                unsafe { Self(Ops::<$engine, $scalar_ty>::trailing_zeros(self.0)) }
+            }
+
+            #[inline(always)]
+            fn reverse_bits(self) -> Self {
+                unsafe { Self(Ops::<$engine, $scalar_ty>::reverse_bits(self.0)) }
+            }
+
+            #[inline(always)]
+            fn popcount_total(self) -> u32 {
+                unsafe { Ops::<$engine, $scalar_ty>::popcount_total(self.0) }
+            }
+
+            #[inline(always)]
+            fn permute(self, indices: Self) -> Self {
+                paste::paste! {
+                    unsafe {
+                        let value = Ops::<$engine, $scalar_ty>::bitcast_binary(self.0);
+                        let value = Ops::<$engine, binary>::[<bitcast_ $lane_ty>](value);
+                        let indices = Ops::<$engine, $scalar_ty>::bitcast_binary(indices.0);
+                        let indices = Ops::<$engine, binary>::[<bitcast_ $lane_ty>](indices);
+                        let result = Ops::<$engine, $lane_ty>::permute(value, indices);
+                        let result = Ops::<$engine, $lane_ty>::bitcast_binary(result);
+                        Self(Ops::<$engine, binary>::[<bitcast_ $scalar_ty>](result))
+                    }
+                }
+            }
+
             #[inline(always)]
             fn horizontal_unsigned_add($self) -> Self::HorizontalAddScalar {
                 $($hadd)*
@@ -672,6 +1631,21 @@ macro_rules! impl_simd_int {
             fn from_i64(value: i64) -> Self {
                 Self::set1(value as $scalar_ty)
             }
+
+            #[inline(always)]
+            fn saturating_add(self, rhs: Self) -> Self {
+                unsafe { Self(Ops::<$engine, $scalar_ty>::saturating_add(self.0, rhs.0)) }
+            }
+
+            #[inline(always)]
+            fn saturating_sub(self, rhs: Self) -> Self {
+                unsafe { Self(Ops::<$engine, $scalar_ty>::saturating_sub(self.0, rhs.0)) }
+            }
+
+            #[inline(always)]
+            fn avg(self, rhs: Self) -> Self {
+                unsafe { Self(Ops::<$engine, $scalar_ty>::avg(self.0, rhs.0)) }
+            }
         }
     };
 }
@@ -689,6 +1663,11 @@ macro_rules! impl_simd_float {
                 unsafe { Self(Ops::<$engine, $scalar_ty>::ceil(self.0)) }
             }
 
+            #[inline(always)]
+            fn trunc(self) -> Self {
+                unsafe { Self(Ops::<$engine, $scalar_ty>::trunc(self.0)) }
+            }
+
             #[inline(always)]
             fn floor(self) -> Self {
                 unsafe { Self(Ops::<$engine, $scalar_ty>::floor(self.0)) }
@@ -719,6 +1698,11 @@ macro_rules! impl_simd_float {
                 unsafe { Self(Ops::<$engine, $scalar_ty>::mul_add(self.0, a.0, b.0)) }
             }
 
+            #[inline(always)]
+            fn mul_add_fused(self, a: Self, b: Self) -> Self {
+                unsafe { Self(Ops::<$engine, $scalar_ty>::mul_add_fused(self.0, a.0, b.0)) }
+            }
+
             #[inline(always)]
             fn mul_sub(self, a: Self, b: Self) -> Self {
                 unsafe { Self(Ops::<$engine, $scalar_ty>::mul_sub(self.0, a.0, b.0)) }
@@ -753,14 +1737,14 @@ macro_rules! impl_simd_float {
 }
 
 macro_rules! impl_i8_simd_type {
-    ($engine:ident, $i8_ty:ident, $i16_ty:ident, $mask_ty:ty) => {
-        impl_simd_base!($engine, $i8_ty, i8, |self| {
+    ($engine:ident, $i8_ty:ident, $i16_ty:ident, $u8_ty:ident, $mask_ty:ty) => {
+        impl_simd_base!($engine, $i8_ty, i8, i8, |self| {
             self.partial_horizontal_add()
                 .partial_horizontal_add()
                 .partial_horizontal_add()
                 .partial_horizontal_add()
         });
-        impl_simd_int!($engine, $i8_ty, i8, |self| {
+        impl_simd_int!($engine, $i8_ty, i8, i8, |self| {
             self.partial_horizontal_unsigned_add()
                 .partial_horizontal_unsigned_add()
                 .partial_horizontal_unsigned_add()
@@ -770,6 +1754,14 @@ macro_rules! impl_i8_simd_type {
         impl SimdInt8 for $i8_ty {
             type BitMask = $mask_ty;
 
+            #[inline(always)]
+            fn bitcast_u8(self) -> <Self::Engine as Simd>::Vu8 {
+                unsafe {
+                    let val = Ops::<$engine, i8>::bitcast_binary(self.0);
+                    $u8_ty(Ops::<$engine, binary>::bitcast_u8(val))
+                }
+            }
+
             #[inline(always)]
             fn extend_to_i16(self) -> (<Self::Engine as Simd>::Vi16, <Self::Engine as Simd>::Vi16) {
                 let (a, b) = unsafe { Ops::<$engine, i8>::extend_i16(self.0) };
@@ -789,6 +1781,21 @@ macro_rules! impl_i8_simd_type {
                 unsafe { Ops::<$engine, i8>::get_mask(self.0) }
             }
 
+            #[inline(always)]
+            fn lookup16(table: &[i8; 16], indices: Self) -> Self {
+                unsafe { Self(Ops::<$engine, i8>::lookup16(table.as_ptr(), indices.0)) }
+            }
+
+            #[inline(always)]
+            fn lookup32(table: &[i8; 32], indices: Self) -> Self {
+                unsafe { Self(Ops::<$engine, i8>::lookup32(table.as_ptr(), indices.0)) }
+            }
+
+            #[inline(always)]
+            fn lookup64(table: &[i8; 64], indices: Self) -> Self {
+                unsafe { Self(Ops::<$engine, i8>::lookup64(table.as_ptr(), indices.0)) }
+            }
+
             #[inline(always)]
             fn is_truthy(self) -> bool {
                 unsafe { Ops::<$engine, i8>::is_truthy(self.0) }
@@ -798,19 +1805,27 @@ macro_rules! impl_i8_simd_type {
 }
 
 macro_rules! impl_i16_simd_type {
-    ($engine:ident, $i16_ty:ident, $i32_ty:ident) => {
-        impl_simd_base!($engine, $i16_ty, i16, |self| {
+    ($engine:ident, $i16_ty:ident, $i32_ty:ident, $u16_ty:ident, $i8_ty:ident, $u8_ty:ident) => {
+        impl_simd_base!($engine, $i16_ty, i16, i16, |self| {
             self.partial_horizontal_add()
                 .partial_horizontal_add()
                 .partial_horizontal_add()
         });
-        impl_simd_int!($engine, $i16_ty, i16, |self| {
+        impl_simd_int!($engine, $i16_ty, i16, i16, |self| {
             self.partial_horizontal_unsigned_add()
                 .partial_horizontal_unsigned_add()
                 .partial_horizontal_add()
         });
 
         impl SimdInt16 for $i16_ty {
+            #[inline(always)]
+            fn bitcast_u16(self) -> <Self::Engine as Simd>::Vu16 {
+                unsafe {
+                    let val = Ops::<$engine, i16>::bitcast_binary(self.0);
+                    $u16_ty(Ops::<$engine, binary>::bitcast_u16(val))
+                }
+            }
+
             #[inline(always)]
             fn extend_to_i32(self) -> (<Self::Engine as Simd>::Vi32, <Self::Engine as Simd>::Vi32) {
                 let (a, b) = unsafe { Ops::<$engine, i16>::extend_i32(self.0) };
@@ -824,19 +1839,72 @@ macro_rules! impl_i16_simd_type {
                 let (a, b) = unsafe { Ops::<$engine, i16>::unsigned_extend_i32(self.0) };
                 ($i32_ty(a), $i32_ty(b))
             }
+
+            #[inline(always)]
+            fn narrow_to_i8(self, upper: Self) -> <Self::Engine as Simd>::Vi8 {
+                unsafe { $i8_ty(Ops::<$engine, i16>::narrow_i8(self.0, upper.0)) }
+            }
+
+            #[inline(always)]
+            fn saturating_narrow_to_i8(self, upper: Self) -> <Self::Engine as Simd>::Vi8 {
+                unsafe { $i8_ty(Ops::<$engine, i16>::saturating_narrow_i8(self.0, upper.0)) }
+            }
+
+            #[inline(always)]
+            fn saturating_narrow_to_u8(self, upper: Self) -> <Self::Engine as Simd>::Vu8 {
+                unsafe { $u8_ty(Ops::<$engine, i16>::saturating_narrow_u8(self.0, upper.0)) }
+            }
+
+            #[inline(always)]
+            fn mul_hi(self, rhs: Self) -> Self {
+                unsafe { Self(Ops::<$engine, i16>::mul_hi(self.0, rhs.0)) }
+            }
+
+            #[inline(always)]
+            fn mul_widening(
+                self,
+                rhs: Self,
+            ) -> (<Self::Engine as Simd>::Vi32, <Self::Engine as Simd>::Vi32) {
+                let (a, b) = unsafe { Ops::<$engine, i16>::mul_widening(self.0, rhs.0) };
+                ($i32_ty(a), $i32_ty(b))
+            }
+
+            #[inline(always)]
+            fn mul_add_pairs(self, rhs: Self) -> <Self::Engine as Simd>::Vi32 {
+                unsafe { $i32_ty(Ops::<$engine, i16>::mul_add_pairs(self.0, rhs.0)) }
+            }
         }
     };
 }
 
 macro_rules! impl_i32_simd_type {
-    ($engine:ident, $i32_ty:ident, $f32_ty:ident, $i64_ty:ident) => {
-        impl_simd_base!($engine, $i32_ty, i32, |self| {
+    (
+        $engine:ident,
+        $i32_ty:ident,
+        $f32_ty:ident,
+        $i64_ty:ident,
+        $u32_ty:ident,
+        $i16_ty:ident,
+        $u16_ty:ident,
+        $f64_ty:ident
+    ) => {
+        impl_simd_base!($engine, $i32_ty, i32, i32, |self| {
             self.partial_horizontal_add().partial_horizontal_add()
         });
-        impl_simd_int!($engine, $i32_ty, i32, |self| {
+        impl_simd_int!($engine, $i32_ty, i32, i32, |self| {
             self.partial_horizontal_unsigned_add()
                 .partial_horizontal_add()
         });
+        impl_simd_index!($engine, $i32_ty, i32, i32, $i32_ty, SimdIndex32);
+        impl_simd_gather!(
+            $engine,
+            $i32_ty,
+            i32,
+            i32,
+            $i32_ty,
+            SimdGather32,
+            SimdIndex32
+        );
 
         impl SimdInt32 for $i32_ty {
             #[inline(always)]
@@ -844,6 +1912,14 @@ macro_rules! impl_i32_simd_type {
                 unsafe { $f32_ty(Ops::<$engine, i32>::bitcast_f32(self.0)) }
             }
 
+            #[inline(always)]
+            fn bitcast_u32(self) -> <Self::Engine as Simd>::Vu32 {
+                unsafe {
+                    let val = Ops::<$engine, i32>::bitcast_binary(self.0);
+                    $u32_ty(Ops::<$engine, binary>::bitcast_u32(val))
+                }
+            }
+
             #[inline(always)]
             fn cast_f32(self) -> <Self::Engine as Simd>::Vf32 {
                 unsafe { $f32_ty(Ops::<$engine, i32>::cast_f32(self.0)) }
@@ -862,18 +1938,71 @@ macro_rules! impl_i32_simd_type {
                 let (a, b) = unsafe { Ops::<$engine, i32>::unsigned_extend_i64(self.0) };
                 ($i64_ty(a), $i64_ty(b))
             }
+
+            #[inline(always)]
+            fn narrow_to_i16(self, upper: Self) -> <Self::Engine as Simd>::Vi16 {
+                unsafe { $i16_ty(Ops::<$engine, i32>::narrow_i16(self.0, upper.0)) }
+            }
+
+            #[inline(always)]
+            fn saturating_narrow_to_i16(self, upper: Self) -> <Self::Engine as Simd>::Vi16 {
+                unsafe { $i16_ty(Ops::<$engine, i32>::saturating_narrow_i16(self.0, upper.0)) }
+            }
+
+            #[inline(always)]
+            fn saturating_narrow_to_u16(self, upper: Self) -> <Self::Engine as Simd>::Vu16 {
+                unsafe { $u16_ty(Ops::<$engine, i32>::saturating_narrow_u16(self.0, upper.0)) }
+            }
+
+            #[inline(always)]
+            fn mul_hi(self, rhs: Self) -> Self {
+                unsafe { Self(Ops::<$engine, i32>::mul_hi(self.0, rhs.0)) }
+            }
+
+            #[inline(always)]
+            fn mul_widening(
+                self,
+                rhs: Self,
+            ) -> (<Self::Engine as Simd>::Vi64, <Self::Engine as Simd>::Vi64) {
+                let (a, b) = unsafe { Ops::<$engine, i32>::mul_widening(self.0, rhs.0) };
+                ($i64_ty(a), $i64_ty(b))
+            }
+
+            #[inline(always)]
+            fn extend_to_f64(self) -> (<Self::Engine as Simd>::Vf64, <Self::Engine as Simd>::Vf64) {
+                let (a, b) = unsafe { Ops::<$engine, i32>::extend_f64(self.0) };
+                ($f64_ty(a), $f64_ty(b))
+            }
         }
     };
 }
 
 macro_rules! impl_i64_simd_type {
-    ($engine:ident, $i64_ty:ident, $f64_ty:ident) => {
-        impl_simd_base!($engine, $i64_ty, i64, |self| {
+    (
+        $engine:ident,
+        $i64_ty:ident,
+        $f64_ty:ident,
+        $u64_ty:ident,
+        $i32_ty:ident,
+        $u32_ty:ident
+    ) => {
+        impl_simd_base!($engine, $i64_ty, i64, i64, |self| {
             self.partial_horizontal_add()
         });
-        impl_simd_int!($engine, $i64_ty, i64, |self| {
+        impl_simd_int!($engine, $i64_ty, i64, i64, |self| {
             self.partial_horizontal_add()
         });
+        impl_simd_index!($engine, $i64_ty, i64, i64, $i64_ty, SimdIndex64);
+        impl_simd_gather!(
+            $engine,
+            $i64_ty,
+            i64,
+            i64,
+            $i64_ty,
+            SimdGather64,
+            SimdIndex64,
+            impl_simd_gather_i32
+        );
 
         impl SimdInt64 for $i64_ty {
             #[inline(always)]
@@ -881,6 +2010,14 @@ macro_rules! impl_i64_simd_type {
                 unsafe { $f64_ty(Ops::<$engine, i64>::bitcast_f64(self.0)) }
             }
 
+            #[inline(always)]
+            fn bitcast_u64(self) -> <Self::Engine as Simd>::Vu64 {
+                unsafe {
+                    let val = Ops::<$engine, i64>::bitcast_binary(self.0);
+                    $u64_ty(Ops::<$engine, binary>::bitcast_u64(val))
+                }
+            }
+
             #[inline(always)]
             fn cast_f64(self) -> <Self::Engine as Simd>::Vf64 {
                 unsafe { $f64_ty(Ops::<$engine, i64>::cast_f64(self.0)) }
@@ -890,16 +2027,186 @@ macro_rules! impl_i64_simd_type {
             fn partial_horizontal_add(self) -> i64 {
                 unsafe { Ops::<$engine, i64>::horizontal_add(self.0) }
             }
+
+            #[inline(always)]
+            fn narrow_to_i32(self, upper: Self) -> <Self::Engine as Simd>::Vi32 {
+                unsafe { $i32_ty(Ops::<$engine, i64>::narrow_i32(self.0, upper.0)) }
+            }
+
+            #[inline(always)]
+            fn saturating_narrow_to_i32(self, upper: Self) -> <Self::Engine as Simd>::Vi32 {
+                unsafe { $i32_ty(Ops::<$engine, i64>::saturating_narrow_i32(self.0, upper.0)) }
+            }
+
+            #[inline(always)]
+            fn saturating_narrow_to_u32(self, upper: Self) -> <Self::Engine as Simd>::Vu32 {
+                unsafe { $u32_ty(Ops::<$engine, i64>::saturating_narrow_u32(self.0, upper.0)) }
+            }
+        }
+    };
+}
+
+macro_rules! impl_u8_simd_type {
+    ($engine:ident, $u8_ty:ident, $i8_ty:ident, $u16_ty:ident, $i16_ty:ident, $u64_ty:ident) => {
+        impl_simd_base!($engine, $u8_ty, u8, i8, |self| {
+            self.bitcast_i8().horizontal_unsigned_add() as u64
+        });
+        impl_simd_int!($engine, $u8_ty, u8, i8, |self| {
+            self.bitcast_i8().horizontal_unsigned_add() as u64
+        });
+
+        impl SimdUint8 for $u8_ty {
+            #[inline(always)]
+            fn bitcast_i8(self) -> <Self::Engine as Simd>::Vi8 {
+                unsafe {
+                    let val = Ops::<$engine, u8>::bitcast_binary(self.0);
+                    $i8_ty(Ops::<$engine, binary>::bitcast_i8(val))
+                }
+            }
+
+            #[inline(always)]
+            fn extend_to_u16(self) -> (<Self::Engine as Simd>::Vu16, <Self::Engine as Simd>::Vu16) {
+                let (a, b) = unsafe { Ops::<$engine, u8>::extend_u16(self.0) };
+                ($u16_ty(a), $u16_ty(b))
+            }
+
+            #[inline(always)]
+            fn mul_add_pairs(
+                self,
+                rhs: <Self::Engine as Simd>::Vi8,
+            ) -> <Self::Engine as Simd>::Vi16 {
+                unsafe { $i16_ty(Ops::<$engine, u8>::mul_add_pairs(self.0, rhs.0)) }
+            }
+
+            #[inline(always)]
+            fn sad(self, rhs: Self) -> <Self::Engine as Simd>::Vu64 {
+                unsafe { $u64_ty(Ops::<$engine, u8>::sad(self.0, rhs.0)) }
+            }
+        }
+    };
+}
+
+macro_rules! impl_u16_simd_type {
+    ($engine:ident, $u16_ty:ident, $i16_ty:ident, $u32_ty:ident) => {
+        impl_simd_base!($engine, $u16_ty, u16, i16, |self| {
+            self.bitcast_i16().horizontal_unsigned_add() as u64
+        });
+        impl_simd_int!($engine, $u16_ty, u16, i16, |self| {
+            self.bitcast_i16().horizontal_unsigned_add() as u64
+        });
+
+        impl SimdUint16 for $u16_ty {
+            #[inline(always)]
+            fn bitcast_i16(self) -> <Self::Engine as Simd>::Vi16 {
+                unsafe {
+                    let val = Ops::<$engine, u16>::bitcast_binary(self.0);
+                    $i16_ty(Ops::<$engine, binary>::bitcast_i16(val))
+                }
+            }
+
+            #[inline(always)]
+            fn extend_to_u32(self) -> (<Self::Engine as Simd>::Vu32, <Self::Engine as Simd>::Vu32) {
+                let (a, b) = unsafe { Ops::<$engine, u16>::extend_u32(self.0) };
+                ($u32_ty(a), $u32_ty(b))
+            }
+
+            #[inline(always)]
+            fn mul_hi(self, rhs: Self) -> Self {
+                unsafe { Self(Ops::<$engine, u16>::mul_hi(self.0, rhs.0)) }
+            }
+        }
+    };
+}
+
+macro_rules! impl_u32_simd_type {
+    ($engine:ident, $u32_ty:ident, $i32_ty:ident, $u64_ty:ident) => {
+        impl_simd_base!($engine, $u32_ty, u32, i32, |self| {
+            self.bitcast_i32().horizontal_unsigned_add() as u64
+        });
+        impl_simd_int!($engine, $u32_ty, u32, i32, |self| {
+            self.bitcast_i32().horizontal_unsigned_add() as u64
+        });
+        impl_simd_index!($engine, $u32_ty, u32, i32, $i32_ty, SimdIndex32);
+        impl_simd_gather!(
+            $engine,
+            $u32_ty,
+            u32,
+            i32,
+            $i32_ty,
+            SimdGather32,
+            SimdIndex32
+        );
+
+        impl SimdUint32 for $u32_ty {
+            #[inline(always)]
+            fn bitcast_i32(self) -> <Self::Engine as Simd>::Vi32 {
+                unsafe {
+                    let val = Ops::<$engine, u32>::bitcast_binary(self.0);
+                    $i32_ty(Ops::<$engine, binary>::bitcast_i32(val))
+                }
+            }
+
+            #[inline(always)]
+            fn extend_to_u64(self) -> (<Self::Engine as Simd>::Vu64, <Self::Engine as Simd>::Vu64) {
+                let (a, b) = unsafe { Ops::<$engine, u32>::extend_u64(self.0) };
+                ($u64_ty(a), $u64_ty(b))
+            }
+
+            #[inline(always)]
+            fn mul_hi(self, rhs: Self) -> Self {
+                unsafe { Self(Ops::<$engine, u32>::mul_hi(self.0, rhs.0)) }
+            }
+        }
+    };
+}
+
+macro_rules! impl_u64_simd_type {
+    ($engine:ident, $u64_ty:ident, $i64_ty:ident) => {
+        impl_simd_base!($engine, $u64_ty, u64, i64, |self| {
+            self.bitcast_i64().horizontal_add() as u64
+        });
+        impl_simd_int!($engine, $u64_ty, u64, i64, |self| {
+            self.bitcast_i64().horizontal_add() as u64
+        });
+        impl_simd_index!($engine, $u64_ty, u64, i64, $i64_ty, SimdIndex64);
+        impl_simd_gather!(
+            $engine,
+            $u64_ty,
+            u64,
+            i64,
+            $i64_ty,
+            SimdGather64,
+            SimdIndex64,
+            impl_simd_gather_i32
+        );
+
+        impl SimdUint64 for $u64_ty {
+            #[inline(always)]
+            fn bitcast_i64(self) -> <Self::Engine as Simd>::Vi64 {
+                unsafe {
+                    let val = Ops::<$engine, u64>::bitcast_binary(self.0);
+                    $i64_ty(Ops::<$engine, binary>::bitcast_i64(val))
+                }
+            }
         }
     };
 }
 
 macro_rules! impl_f32_simd_type {
-    ($engine:ident, $f32_ty:ident, $i32_ty:ident) => {
-        impl_simd_base!($engine, $f32_ty, f32, |self| {
+    ($engine:ident, $f32_ty:ident, $i32_ty:ident, $f64_ty:ident) => {
+        impl_simd_base!($engine, $f32_ty, f32, i32, |self| {
             unsafe { Ops::<$engine, f32>::horizontal_add(self.0) }
         });
         impl_simd_float!($engine, $f32_ty, f32);
+        impl_simd_gather!(
+            $engine,
+            $f32_ty,
+            f32,
+            i32,
+            $i32_ty,
+            SimdGather32,
+            SimdIndex32
+        );
 
         impl SimdFloat32 for $f32_ty {
             #[inline(always)]
@@ -916,16 +2223,53 @@ macro_rules! impl_f32_simd_type {
             fn fast_inverse(self) -> Self {
                 unsafe { Self(Ops::<$engine, f32>::recip(self.0)) }
             }
+
+            #[inline(always)]
+            fn extend_to_f64(self) -> (<Self::Engine as Simd>::Vf64, <Self::Engine as Simd>::Vf64) {
+                let (a, b) = unsafe { Ops::<$engine, f32>::extend_f64(self.0) };
+                ($f64_ty(a), $f64_ty(b))
+            }
+
+            #[inline(always)]
+            fn permute(self, indices: <Self::Engine as Simd>::Vi32) -> Self {
+                unsafe {
+                    let value = Ops::<$engine, f32>::bitcast_i32(self.0);
+                    let result = Ops::<$engine, i32>::permute(value, indices.0);
+                    Self(Ops::<$engine, i32>::bitcast_f32(result))
+                }
+            }
+
+            #[inline(always)]
+            fn total_cmp_lt(self, rhs: Self) -> Self::Mask {
+                // Flipping the magnitude bits of negative lanes makes the signed integer order
+                // match the total order, as in `f32::total_cmp`.
+                let key = |value: Self| {
+                    let bits = value.bitcast_i32();
+                    bits ^ (bits.shr_arithmetic(31) & i32::MAX)
+                };
+                let less = key(self).cmp_lt(key(rhs)).to_vector();
+                Self::Mask::from_vector(less.bitcast_f32())
+            }
         }
     };
 }
 
 macro_rules! impl_f64_simd_type {
-    ($engine:ident, $f64_ty:ident, $i64_ty:ident) => {
-        impl_simd_base!($engine, $f64_ty, f64, |self| {
+    ($engine:ident, $f64_ty:ident, $i64_ty:ident, $f32_ty:ident, $i32_ty:ident) => {
+        impl_simd_base!($engine, $f64_ty, f64, i64, |self| {
             unsafe { Ops::<$engine, f64>::horizontal_add(self.0) }
         });
         impl_simd_float!($engine, $f64_ty, f64);
+        impl_simd_gather!(
+            $engine,
+            $f64_ty,
+            f64,
+            i64,
+            $i64_ty,
+            SimdGather64,
+            SimdIndex64,
+            impl_simd_gather_i32
+        );
 
         impl SimdFloat64 for $f64_ty {
             #[inline(always)]
@@ -937,6 +2281,37 @@ macro_rules! impl_f64_simd_type {
             fn cast_i64(self) -> <Self::Engine as Simd>::Vi64 {
                 unsafe { $i64_ty(Ops::<$engine, f64>::cast_i64(self.0)) }
             }
+
+            #[inline(always)]
+            fn narrow_to_f32(self, upper: Self) -> <Self::Engine as Simd>::Vf32 {
+                unsafe { $f32_ty(Ops::<$engine, f64>::narrow_f32(self.0, upper.0)) }
+            }
+
+            #[inline(always)]
+            fn narrow_to_i32(self, upper: Self) -> <Self::Engine as Simd>::Vi32 {
+                unsafe { $i32_ty(Ops::<$engine, f64>::narrow_i32(self.0, upper.0)) }
+            }
+
+            #[inline(always)]
+            fn permute(self, indices: <Self::Engine as Simd>::Vi64) -> Self {
+                unsafe {
+                    let value = Ops::<$engine, f64>::bitcast_i64(self.0);
+                    let result = Ops::<$engine, i64>::permute(value, indices.0);
+                    Self(Ops::<$engine, i64>::bitcast_f64(result))
+                }
+            }
+
+            #[inline(always)]
+            fn total_cmp_lt(self, rhs: Self) -> Self::Mask {
+                // Flipping the magnitude bits of negative lanes makes the signed integer order
+                // match the total order, as in `f64::total_cmp`.
+                let key = |value: Self| {
+                    let bits = value.bitcast_i64();
+                    bits ^ (bits.shr_arithmetic(63) & i64::MAX)
+                };
+                let less = key(self).cmp_lt(key(rhs)).to_vector();
+                Self::Mask::from_vector(less.bitcast_f64())
+            }
         }
     };
 }
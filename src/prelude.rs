@@ -1,14 +1,15 @@
 pub use super::{
-    fix_tuple_type, simd_compiletime_select, simd_invoke, simd_runtime_generate, Simd,
-    __simd_generate_base, simd_unsafe_generate_all,
+    __simd_generate_base, fix_tuple_type, simd_compiletime_select, simd_invoke,
+    simd_runtime_generate, simd_unsafe_generate_all, Simd, SimdLevel,
 };
 
 pub use super::invoking::*;
 
 pub use crate::base::{
     SimdArrayIterator, SimdArrayMutIterator, SimdBase, SimdBaseIo, SimdBaseOps, SimdConsts,
-    SimdFloat, SimdFloat32, SimdFloat64, SimdInt, SimdInt16, SimdInt32, SimdInt64, SimdInt8,
-    SimdIter,
+    SimdFloat, SimdFloat32, SimdFloat64, SimdGather32, SimdGather64, SimdIndex32, SimdIndex64,
+    SimdInt, SimdInt16, SimdInt32, SimdInt64, SimdInt8, SimdIter, SimdMask, SimdUint16, SimdUint32,
+    SimdUint64, SimdUint8,
 };
 pub use crate::math::{SimdMathF32, SimdMathF64};
 
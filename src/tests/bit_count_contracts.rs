@@ -0,0 +1,105 @@
+#![allow(dead_code, unused_parens)]
+
+#[cfg(any(target_arch = "x86_64", target_arch = "x86"))]
+use crate::dispatch::{tests::without_features, CpuFeature};
+use crate::prelude::*;
+
+use super::check_all_engines;
+
+macro_rules! test_bit_counts {
+    ($name:ident, $simd_ty:ident, $scalar_ty:ty) => {
+        mod $name {
+            use super::*;
+
+            simd_unsafe_generate_all!(
+                fn bits(a: &[$scalar_ty], b: &[$scalar_ty]) -> (Vec<$scalar_ty>, u32) {
+                    let a = S::$simd_ty::load_from_slice(a);
+                    let b = S::$simd_ty::load_from_slice(b);
+                    let mut out = vec![0; 4 * S::$simd_ty::WIDTH];
+                    let results = [
+                        a.count_ones(),
+                        a.leading_zeros(),
+                        b.trailing_zeros(),
+                        b.reverse_bits(),
+                    ];
+                    for (result, chunk) in results.iter().zip(out.chunks_mut(S::$simd_ty::WIDTH)) {
+                        result.copy_to_slice(chunk);
+                    }
+                    (out, a.popcount_total() + b.popcount_total())
+                }
+            );
+
+            fn values(seed: usize, width: usize) -> Vec<$scalar_ty> {
+                let pattern: [$scalar_ty; 10] = [
+                    0,
+                    1,
+                    <$scalar_ty>::MAX,
+                    <$scalar_ty>::MIN,
+                    !0,
+                    (0x8000_0000_0000_0001u64 as $scalar_ty),
+                    (0x0F0F_0F0F_0F0F_0F0Fu64 as $scalar_ty),
+                    (0x0123_4567_89AB_CDEFu64 as $scalar_ty),
+                    (0x0000_0100_0000_8000u64 as $scalar_ty),
+                    (0x7000_0000_0000_0000u64 as $scalar_ty),
+                ];
+                (0..width)
+                    .map(|i| pattern[(i * 3 + seed) % pattern.len()])
+                    .collect()
+            }
+
+            fn check(
+                width: usize,
+                bits: impl Fn(&[$scalar_ty], &[$scalar_ty]) -> (Vec<$scalar_ty>, u32),
+            ) {
+                for seed in 0..10 {
+                    let a = values(seed, width);
+                    let b = values(seed + 5, width);
+                    let (out, total) = bits(&a, &b);
+
+                    let expected: Vec<$scalar_ty> = [
+                        a.iter()
+                            .map(|x| x.count_ones() as $scalar_ty)
+                            .collect::<Vec<_>>(),
+                        a.iter().map(|x| x.leading_zeros() as $scalar_ty).collect(),
+                        b.iter().map(|x| x.trailing_zeros() as $scalar_ty).collect(),
+                        b.iter().map(|x| x.reverse_bits()).collect(),
+                    ]
+                    .concat();
+                    assert_eq!(out, expected, "bit counts mismatch for {a:?} and {b:?}");
+
+                    let expected_total: u32 = a.iter().chain(&b).map(|x| x.count_ones()).sum();
+                    assert_eq!(
+                        total, expected_total,
+                        "popcount_total mismatch for {a:?} and {b:?}"
+                    );
+                }
+            }
+
+            #[test]
+            fn bit_counts_match_scalar() {
+                check_all_engines!(check, bits, $simd_ty);
+            }
+
+            // The test above takes the BITALG and VPOPCNTDQ paths where the CPU has them.
+            #[cfg(any(target_arch = "x86_64", target_arch = "x86"))]
+            #[test]
+            fn bit_counts_match_scalar_without_bitalg_or_vpopcntdq() {
+                without_features(
+                    &[CpuFeature::Avx512Bitalg, CpuFeature::Avx512Vpopcntdq],
+                    || {
+                        check_all_engines!(check, bits, $simd_ty);
+                    },
+                );
+            }
+        }
+    };
+}
+
+test_bit_counts!(i8_lanes, Vi8, i8);
+test_bit_counts!(i16_lanes, Vi16, i16);
+test_bit_counts!(i32_lanes, Vi32, i32);
+test_bit_counts!(i64_lanes, Vi64, i64);
+test_bit_counts!(u8_lanes, Vu8, u8);
+test_bit_counts!(u16_lanes, Vu16, u16);
+test_bit_counts!(u32_lanes, Vu32, u32);
+test_bit_counts!(u64_lanes, Vu64, u64);
@@ -0,0 +1,107 @@
+#![allow(dead_code, unused_parens)]
+
+#[cfg(any(target_arch = "x86_64", target_arch = "x86"))]
+use crate::dispatch::{tests::without_features, CpuFeature};
+use crate::prelude::*;
+
+use super::check_all_engines;
+
+macro_rules! test_compress {
+    ($name:ident, $simd_ty:ident, $scalar_ty:ty) => {
+        mod $name {
+            use super::*;
+
+            simd_unsafe_generate_all!(
+                fn compress(a: &[$scalar_ty], selected: &[$scalar_ty]) -> (Vec<$scalar_ty>, usize) {
+                    let width = S::$simd_ty::WIDTH;
+                    let a = S::$simd_ty::load_from_slice(a);
+                    let mask =
+                        S::$simd_ty::load_from_slice(selected).cmp_neq(S::$simd_ty::zeroes());
+
+                    // Packed, stored with a guard lane after the count, then expanded.
+                    let mut out = vec![0 as $scalar_ty; 3 * width + 1];
+                    let (packed, count) = a.compress(mask);
+                    packed.copy_to_slice(&mut out[..width]);
+                    out[width + count] = 9 as $scalar_ty;
+                    let stored = a.compress_store(&mut out[width..width + count], mask);
+                    assert_eq!(stored, count);
+                    a.expand(mask).copy_to_slice(&mut out[2 * width + 1..]);
+                    (out, count)
+                }
+            );
+
+            fn check(
+                width: usize,
+                compress: impl Fn(&[$scalar_ty], &[$scalar_ty]) -> (Vec<$scalar_ty>, usize),
+            ) {
+                let a: Vec<$scalar_ty> = (0..width).map(|i| (i + 1) as $scalar_ty).collect();
+                // The last two leave one half of wide vectors empty and the other nearly full.
+                let patterns: [fn(usize) -> bool; 8] = [
+                    |_| false,
+                    |_| true,
+                    |i| i % 2 == 0,
+                    |i| i % 3 == 1,
+                    |i| (i * 7 + 3) % 5 < 2,
+                    |i| i == 0 || i >= 6,
+                    |i| i >= 40,
+                    |i| i < 37 || i == 50,
+                ];
+                for pattern in patterns {
+                    let selected: Vec<$scalar_ty> =
+                        (0..width).map(|i| pattern(i) as u8 as $scalar_ty).collect();
+                    let kept: Vec<$scalar_ty> =
+                        (0..width).filter(|&i| pattern(i)).map(|i| a[i]).collect();
+                    let count = kept.len();
+
+                    let mut packed = kept.clone();
+                    packed.resize(width, 0 as $scalar_ty);
+                    let mut stored = kept.clone();
+                    stored.push(9 as $scalar_ty);
+                    stored.resize(width + 1, 0 as $scalar_ty);
+                    let mut next = a.iter();
+                    let expanded: Vec<$scalar_ty> = (0..width)
+                        .map(|i| {
+                            if pattern(i) {
+                                *next.next().unwrap()
+                            } else {
+                                0 as $scalar_ty
+                            }
+                        })
+                        .collect();
+
+                    let expected = [packed, stored, expanded].concat();
+                    assert_eq!(
+                        compress(&a, &selected),
+                        (expected, count),
+                        "compress of {a:?} by {selected:?}"
+                    );
+                }
+            }
+
+            #[test]
+            fn compress_and_expand_match_scalar() {
+                check_all_engines!(check, compress, $simd_ty);
+            }
+
+            // The test above takes the VBMI2 path where the CPU has it.
+            #[cfg(any(target_arch = "x86_64", target_arch = "x86"))]
+            #[test]
+            fn compress_and_expand_match_scalar_without_vbmi2() {
+                without_features(&[CpuFeature::Avx512Vbmi2], || {
+                    check_all_engines!(check, compress, $simd_ty);
+                });
+            }
+        }
+    };
+}
+
+test_compress!(i8_lanes, Vi8, i8);
+test_compress!(i16_lanes, Vi16, i16);
+test_compress!(i32_lanes, Vi32, i32);
+test_compress!(i64_lanes, Vi64, i64);
+test_compress!(u8_lanes, Vu8, u8);
+test_compress!(u16_lanes, Vu16, u16);
+test_compress!(u32_lanes, Vu32, u32);
+test_compress!(u64_lanes, Vu64, u64);
+test_compress!(f32_lanes, Vf32, f32);
+test_compress!(f64_lanes, Vf64, f64);
@@ -0,0 +1,486 @@
+#![allow(dead_code, unused_parens)]
+
+use crate::prelude::*;
+
+// Narrowing packs `lower` then `upper` into one vector. On every engine that is the first
+// `WIDTH` lanes of the concatenated inputs, converted lane by lane; on the scalar engine that is
+// just the lane of `lower`.
+macro_rules! test_narrowing {
+    (
+        $name:ident,
+        $wide_ty:ident,
+        $wide_scalar:ty,
+        $narrow_ty:ident,
+        $narrow_scalar:ty,
+        $method:ident,
+        $pattern:expr,
+        $reference:expr
+    ) => {
+        mod $name {
+            use super::*;
+
+            simd_unsafe_generate_all!(
+                fn narrow(
+                    lower: &[$wide_scalar],
+                    upper: &[$wide_scalar],
+                    out: &mut [$narrow_scalar],
+                ) {
+                    let lower = S::$wide_ty::load_from_slice(lower);
+                    let upper = S::$wide_ty::load_from_slice(upper);
+                    lower.$method(upper).copy_to_slice(out);
+                }
+            );
+
+            fn check(
+                wide_width: usize,
+                narrow_width: usize,
+                narrow: impl Fn(&[$wide_scalar], &[$wide_scalar], &mut [$narrow_scalar]),
+            ) {
+                let pattern: &[$wide_scalar] = &$pattern;
+                let reference: fn($wide_scalar) -> $narrow_scalar = $reference;
+
+                for seed in 0..pattern.len() {
+                    let lanes: Vec<$wide_scalar> = (0..2 * wide_width)
+                        .map(|i| pattern[(i * 5 + seed) % pattern.len()])
+                        .collect();
+                    let (lower, upper) = lanes.split_at(wide_width);
+
+                    let mut out = vec![<$narrow_scalar>::default(); narrow_width];
+                    narrow(lower, upper, &mut out);
+
+                    let expected: Vec<$narrow_scalar> = lanes[..narrow_width]
+                        .iter()
+                        .map(|&v| reference(v))
+                        .collect();
+                    assert_eq!(
+                        out,
+                        expected,
+                        "{} mismatch for {lower:?} / {upper:?}",
+                        stringify!($method)
+                    );
+                }
+            }
+
+            #[test]
+            fn narrowing_matches_scalar_reference() {
+                #[cfg(any(target_arch = "x86_64", target_arch = "x86"))]
+                {
+                    use crate::engines::{
+                        avx::Avx, avx2::Avx2, avx512::Avx512, scalar::Scalar, sse2::Sse2,
+                        sse41::Sse41,
+                    };
+
+                    check(
+                        <Scalar as Simd>::$wide_ty::WIDTH,
+                        <Scalar as Simd>::$narrow_ty::WIDTH,
+                        narrow_scalar,
+                    );
+                    if std::arch::is_x86_feature_detected!("sse2") {
+                        check(
+                            <Sse2 as Simd>::$wide_ty::WIDTH,
+                            <Sse2 as Simd>::$narrow_ty::WIDTH,
+                            |a, b, out| unsafe { narrow_sse2(a, b, out) },
+                        );
+                    }
+                    if std::arch::is_x86_feature_detected!("sse4.1") {
+                        check(
+                            <Sse41 as Simd>::$wide_ty::WIDTH,
+                            <Sse41 as Simd>::$narrow_ty::WIDTH,
+                            |a, b, out| unsafe { narrow_sse41(a, b, out) },
+                        );
+                    }
+                    if std::arch::is_x86_feature_detected!("avx") {
+                        check(
+                            <Avx as Simd>::$wide_ty::WIDTH,
+                            <Avx as Simd>::$narrow_ty::WIDTH,
+                            |a, b, out| unsafe { narrow_avx(a, b, out) },
+                        );
+                    }
+                    if std::arch::is_x86_feature_detected!("avx2")
+                        && std::arch::is_x86_feature_detected!("fma")
+                    {
+                        check(
+                            <Avx2 as Simd>::$wide_ty::WIDTH,
+                            <Avx2 as Simd>::$narrow_ty::WIDTH,
+                            |a, b, out| unsafe { narrow_avx2(a, b, out) },
+                        );
+                    }
+                    if std::arch::is_x86_feature_detected!("avx512f")
+                        && std::arch::is_x86_feature_detected!("avx512bw")
+                        && std::arch::is_x86_feature_detected!("avx512dq")
+                    {
+                        check(
+                            <Avx512 as Simd>::$wide_ty::WIDTH,
+                            <Avx512 as Simd>::$narrow_ty::WIDTH,
+                            |a, b, out| unsafe { narrow_avx512(a, b, out) },
+                        );
+                    }
+                }
+
+                #[cfg(target_arch = "aarch64")]
+                {
+                    use crate::engines::neon::Neon;
+                    check(
+                        <Neon as Simd>::$wide_ty::WIDTH,
+                        <Neon as Simd>::$narrow_ty::WIDTH,
+                        |a, b, out| unsafe { narrow_neon(a, b, out) },
+                    );
+                }
+
+                #[cfg(target_arch = "wasm32")]
+                {
+                    use crate::engines::wasm32::Wasm;
+                    check(
+                        <Wasm as Simd>::$wide_ty::WIDTH,
+                        <Wasm as Simd>::$narrow_ty::WIDTH,
+                        |a, b, out| unsafe { narrow_wasm(a, b, out) },
+                    );
+                }
+            }
+        }
+    };
+}
+
+// Widening returns the lower half of the input first. The scalar engine only has one lane, so
+// only the first `input.len()` output lanes are compared.
+macro_rules! test_widening {
+    (
+        $name:ident,
+        $narrow_ty:ident,
+        $narrow_scalar:ty,
+        $wide_ty:ident,
+        $wide_scalar:ty,
+        $method:ident,
+        $pattern:expr,
+        $reference:expr
+    ) => {
+        mod $name {
+            use super::*;
+
+            simd_unsafe_generate_all!(
+                fn widen(input: &[$narrow_scalar], out: &mut [$wide_scalar]) {
+                    let (lower, upper) = S::$narrow_ty::load_from_slice(input).$method();
+                    lower.copy_to_slice(&mut out[..S::$wide_ty::WIDTH]);
+                    upper.copy_to_slice(&mut out[S::$wide_ty::WIDTH..]);
+                }
+            );
+
+            fn check(
+                narrow_width: usize,
+                wide_width: usize,
+                widen: impl Fn(&[$narrow_scalar], &mut [$wide_scalar]),
+            ) {
+                let pattern: &[$narrow_scalar] = &$pattern;
+                let reference: fn($narrow_scalar) -> $wide_scalar = $reference;
+
+                for seed in 0..pattern.len() {
+                    let input: Vec<$narrow_scalar> = (0..narrow_width)
+                        .map(|i| pattern[(i * 3 + seed) % pattern.len()])
+                        .collect();
+                    let mut out = vec![<$wide_scalar>::default(); 2 * wide_width];
+                    widen(&input, &mut out);
+
+                    let expected: Vec<$wide_scalar> = input.iter().map(|&v| reference(v)).collect();
+                    assert_eq!(
+                        out[..narrow_width],
+                        expected[..],
+                        "{} mismatch for {input:?}",
+                        stringify!($method)
+                    );
+                }
+            }
+
+            #[test]
+            fn widening_matches_scalar_reference() {
+                #[cfg(any(target_arch = "x86_64", target_arch = "x86"))]
+                {
+                    use crate::engines::{
+                        avx::Avx, avx2::Avx2, avx512::Avx512, scalar::Scalar, sse2::Sse2,
+                        sse41::Sse41,
+                    };
+
+                    check(
+                        <Scalar as Simd>::$narrow_ty::WIDTH,
+                        <Scalar as Simd>::$wide_ty::WIDTH,
+                        widen_scalar,
+                    );
+                    if std::arch::is_x86_feature_detected!("sse2") {
+                        check(
+                            <Sse2 as Simd>::$narrow_ty::WIDTH,
+                            <Sse2 as Simd>::$wide_ty::WIDTH,
+                            |v, out| unsafe { widen_sse2(v, out) },
+                        );
+                    }
+                    if std::arch::is_x86_feature_detected!("sse4.1") {
+                        check(
+                            <Sse41 as Simd>::$narrow_ty::WIDTH,
+                            <Sse41 as Simd>::$wide_ty::WIDTH,
+                            |v, out| unsafe { widen_sse41(v, out) },
+                        );
+                    }
+                    if std::arch::is_x86_feature_detected!("avx") {
+                        check(
+                            <Avx as Simd>::$narrow_ty::WIDTH,
+                            <Avx as Simd>::$wide_ty::WIDTH,
+                            |v, out| unsafe { widen_avx(v, out) },
+                        );
+                    }
+                    if std::arch::is_x86_feature_detected!("avx2")
+                        && std::arch::is_x86_feature_detected!("fma")
+                    {
+                        check(
+                            <Avx2 as Simd>::$narrow_ty::WIDTH,
+                            <Avx2 as Simd>::$wide_ty::WIDTH,
+                            |v, out| unsafe { widen_avx2(v, out) },
+                        );
+                    }
+                    if std::arch::is_x86_feature_detected!("avx512f")
+                        && std::arch::is_x86_feature_detected!("avx512bw")
+                        && std::arch::is_x86_feature_detected!("avx512dq")
+                    {
+                        check(
+                            <Avx512 as Simd>::$narrow_ty::WIDTH,
+                            <Avx512 as Simd>::$wide_ty::WIDTH,
+                            |v, out| unsafe { widen_avx512(v, out) },
+                        );
+                    }
+                }
+
+                #[cfg(target_arch = "aarch64")]
+                {
+                    use crate::engines::neon::Neon;
+                    check(
+                        <Neon as Simd>::$narrow_ty::WIDTH,
+                        <Neon as Simd>::$wide_ty::WIDTH,
+                        |v, out| unsafe { widen_neon(v, out) },
+                    );
+                }
+
+                #[cfg(target_arch = "wasm32")]
+                {
+                    use crate::engines::wasm32::Wasm;
+                    check(
+                        <Wasm as Simd>::$narrow_ty::WIDTH,
+                        <Wasm as Simd>::$wide_ty::WIDTH,
+                        |v, out| unsafe { widen_wasm(v, out) },
+                    );
+                }
+            }
+        }
+    };
+}
+
+const I16_EDGES: [i16; 10] = [0, 1, -1, 127, 128, -128, -129, 255, i16::MAX, i16::MIN];
+const I32_EDGES: [i32; 11] = [
+    0,
+    1,
+    -1,
+    32767,
+    32768,
+    -32768,
+    -32769,
+    65535,
+    65536,
+    i32::MAX,
+    i32::MIN,
+];
+const I64_EDGES: [i64; 11] = [
+    0,
+    1,
+    -1,
+    i32::MAX as i64,
+    i32::MAX as i64 + 1,
+    i32::MIN as i64,
+    i32::MIN as i64 - 1,
+    u32::MAX as i64,
+    u32::MAX as i64 + 1,
+    i64::MAX,
+    i64::MIN,
+];
+
+test_narrowing!(
+    i16_to_i8,
+    Vi16,
+    i16,
+    Vi8,
+    i8,
+    narrow_to_i8,
+    I16_EDGES,
+    |v| v as i8
+);
+test_narrowing!(
+    i16_to_i8_saturating,
+    Vi16,
+    i16,
+    Vi8,
+    i8,
+    saturating_narrow_to_i8,
+    I16_EDGES,
+    |v| v.clamp(i8::MIN as i16, i8::MAX as i16) as i8
+);
+test_narrowing!(
+    i16_to_u8_saturating,
+    Vi16,
+    i16,
+    Vu8,
+    u8,
+    saturating_narrow_to_u8,
+    I16_EDGES,
+    |v| v.clamp(0, u8::MAX as i16) as u8
+);
+
+test_narrowing!(
+    i32_to_i16,
+    Vi32,
+    i32,
+    Vi16,
+    i16,
+    narrow_to_i16,
+    I32_EDGES,
+    |v| v as i16
+);
+test_narrowing!(
+    i32_to_i16_saturating,
+    Vi32,
+    i32,
+    Vi16,
+    i16,
+    saturating_narrow_to_i16,
+    I32_EDGES,
+    |v| v.clamp(i16::MIN as i32, i16::MAX as i32) as i16
+);
+test_narrowing!(
+    i32_to_u16_saturating,
+    Vi32,
+    i32,
+    Vu16,
+    u16,
+    saturating_narrow_to_u16,
+    I32_EDGES,
+    |v| v.clamp(0, u16::MAX as i32) as u16
+);
+
+test_narrowing!(
+    i64_to_i32,
+    Vi64,
+    i64,
+    Vi32,
+    i32,
+    narrow_to_i32,
+    I64_EDGES,
+    |v| v as i32
+);
+test_narrowing!(
+    i64_to_i32_saturating,
+    Vi64,
+    i64,
+    Vi32,
+    i32,
+    saturating_narrow_to_i32,
+    I64_EDGES,
+    |v| v.clamp(i32::MIN as i64, i32::MAX as i64) as i32
+);
+test_narrowing!(
+    i64_to_u32_saturating,
+    Vi64,
+    i64,
+    Vu32,
+    u32,
+    saturating_narrow_to_u32,
+    I64_EDGES,
+    |v| v.clamp(0, u32::MAX as i64) as u32
+);
+
+test_narrowing!(
+    f64_to_f32,
+    Vf64,
+    f64,
+    Vf32,
+    f32,
+    narrow_to_f32,
+    [
+        0.0,
+        -0.0,
+        1.0 / 3.0,
+        -2.5,
+        1e300,
+        -1e300,
+        1e-300,
+        f32::MAX as f64,
+        f64::INFINITY,
+        f64::NEG_INFINITY,
+    ],
+    |v| v as f32
+);
+// Out of range and NaN lanes are backend dependent, so only in-range values and ties are checked.
+test_narrowing!(
+    f64_to_i32,
+    Vf64,
+    f64,
+    Vi32,
+    i32,
+    narrow_to_i32,
+    [
+        0.0,
+        -0.0,
+        0.5,
+        1.5,
+        2.5,
+        -0.5,
+        -1.5,
+        -2.5,
+        1.49,
+        -7.51,
+        2147483647.0,
+        -2147483648.0,
+    ],
+    |v| {
+        let rounded = v.round();
+        let tie_to_even = if (v - v.trunc()).abs() == 0.5 && rounded % 2.0 != 0.0 {
+            rounded - v.signum()
+        } else {
+            rounded
+        };
+        tie_to_even as i32
+    }
+);
+
+test_widening!(
+    f32_to_f64,
+    Vf32,
+    f32,
+    Vf64,
+    f64,
+    extend_to_f64,
+    [
+        0.0,
+        -0.0,
+        0.1,
+        -3.75,
+        f32::MAX,
+        f32::MIN_POSITIVE,
+        f32::INFINITY,
+        f32::NEG_INFINITY,
+    ],
+    |v| v as f64
+);
+test_widening!(
+    i32_to_f64,
+    Vi32,
+    i32,
+    Vf64,
+    f64,
+    extend_to_f64,
+    I32_EDGES,
+    |v| v as f64
+);
+test_widening!(
+    i16_to_f32,
+    Vi16,
+    i16,
+    Vf32,
+    f32,
+    extend_to_f32,
+    I16_EDGES,
+    |v| v as f32
+);
@@ -0,0 +1,138 @@
+#![allow(dead_code)]
+
+use std::sync::Mutex;
+
+use crate::dispatch;
+use crate::prelude::*;
+
+/// Dispatch state is process-wide, so the tests that change it must not overlap.
+static DISPATCH_STATE: Mutex<()> = Mutex::new(());
+
+simd_runtime_generate!(
+    fn lane_count() -> usize {
+        S::Vf32::WIDTH
+    }
+);
+
+simd_runtime_generate!(
+    fn lane_count_of<T: Copy>(_value: T) -> usize {
+        S::Vf32::WIDTH
+    }
+);
+
+fn lanes_of(level: SimdLevel) -> usize {
+    match level {
+        SimdLevel::Scalar => 1,
+        SimdLevel::Sse2 | SimdLevel::Sse41 | SimdLevel::Neon | SimdLevel::Wasm => 4,
+        SimdLevel::Avx | SimdLevel::Avx2 => 8,
+        SimdLevel::Avx512 => 16,
+    }
+}
+
+#[test]
+fn cached_dispatch_matches_selected_level() {
+    let _guard = DISPATCH_STATE.lock().unwrap();
+    let expected = lanes_of(crate::selected_level());
+    for _ in 0..3 {
+        assert_eq!(lane_count(), expected);
+        assert_eq!(lane_count_of(1u8), expected);
+    }
+
+    dispatch::reset();
+    assert_eq!(lane_count(), expected);
+    assert_eq!(lane_count_of(1u8), expected);
+    assert_eq!(lanes_of(crate::selected_level()), expected);
+}
+
+#[test]
+#[cfg(not(target_arch = "wasm32"))]
+fn cached_dispatch_is_shared_across_threads() {
+    let _guard = DISPATCH_STATE.lock().unwrap();
+    let expected = lanes_of(crate::selected_level());
+    let handles: Vec<_> = (0..4)
+        .map(|i| {
+            std::thread::spawn(move || {
+                if i == 0 {
+                    dispatch::reset();
+                }
+                (0..1000).all(|_| lane_count() == expected)
+            })
+        })
+        .collect();
+    for handle in handles {
+        assert!(handle.join().unwrap());
+    }
+}
+
+#[test]
+fn max_level_caps_dispatch() {
+    let _guard = DISPATCH_STATE.lock().unwrap();
+    let detected = crate::detected_level();
+
+    crate::set_max_level(Some(SimdLevel::Scalar));
+    assert_eq!(crate::max_level(), Some(SimdLevel::Scalar));
+    assert_eq!(crate::selected_level(), SimdLevel::Scalar);
+    assert_eq!(crate::detected_level(), detected);
+    assert_eq!(lane_count(), 1);
+    assert_eq!(lane_count_of(1u8), 1);
+
+    #[cfg(any(target_arch = "x86_64", target_arch = "x86"))]
+    {
+        crate::set_max_level(Some(SimdLevel::Sse41));
+        assert!(matches!(
+            crate::selected_level(),
+            SimdLevel::Sse41 | SimdLevel::Sse2 | SimdLevel::Scalar
+        ));
+        assert_eq!(lane_count(), lanes_of(crate::selected_level()));
+
+        // A cap for another architecture leaves x86 alone.
+        crate::set_max_level(Some(SimdLevel::Neon));
+        assert_eq!(crate::selected_level(), detected);
+    }
+
+    crate::set_max_level(None);
+    assert_eq!(crate::selected_level(), detected);
+    assert_eq!(lane_count(), lanes_of(detected));
+}
+
+#[test]
+#[cfg(not(target_arch = "wasm32"))]
+fn max_level_falls_back_to_environment() {
+    let _guard = DISPATCH_STATE.lock().unwrap();
+
+    std::env::set_var("SIMDEEZ_MAX_LEVEL", "SCALAR");
+    crate::set_max_level(None);
+    assert_eq!(crate::max_level(), Some(SimdLevel::Scalar));
+    assert_eq!(lane_count(), 1);
+
+    // An explicit cap wins over the environment.
+    crate::set_max_level(Some(crate::detected_level()));
+    assert_eq!(lane_count(), lanes_of(crate::detected_level()));
+
+    std::env::set_var("SIMDEEZ_MAX_LEVEL", "not-a-level");
+    crate::set_max_level(None);
+    assert_eq!(crate::max_level(), None);
+
+    std::env::remove_var("SIMDEEZ_MAX_LEVEL");
+    crate::set_max_level(None);
+    assert_eq!(lane_count(), lanes_of(crate::detected_level()));
+}
+
+#[test]
+fn level_names_round_trip() {
+    for level in [
+        SimdLevel::Scalar,
+        SimdLevel::Sse2,
+        SimdLevel::Sse41,
+        SimdLevel::Avx,
+        SimdLevel::Avx2,
+        SimdLevel::Avx512,
+        SimdLevel::Neon,
+        SimdLevel::Wasm,
+    ] {
+        assert_eq!(SimdLevel::parse(&level.to_string()), Some(level));
+    }
+    assert_eq!(SimdLevel::parse(" SSE4.1 "), Some(SimdLevel::Sse41));
+    assert_eq!(SimdLevel::parse("Avx-512"), Some(SimdLevel::Avx512));
+    assert_eq!(SimdLevel::parse("avx3"), None);
+}
@@ -0,0 +1,162 @@
+#![allow(dead_code)]
+
+use crate::prelude::*;
+
+trait Pixel: Copy {
+    fn luma(self) -> f32;
+}
+
+#[derive(Copy, Clone)]
+struct Gray(u8);
+
+impl Pixel for Gray {
+    fn luma(self) -> f32 {
+        self.0 as f32
+    }
+}
+
+simd_unsafe_generate_all!(
+    fn sum_luma<P: Pixel, const N: usize>(pixels: &[P; N]) -> f32 {
+        let lanes: [f32; N] = core::array::from_fn(|i| pixels[i].luma());
+        let mut sum = S::Vf32::zeroes();
+        for chunk in lanes.chunks(S::Vf32::WIDTH) {
+            sum += S::Vf32::load_partial(chunk).0;
+        }
+        sum.horizontal_add()
+    }
+);
+
+simd_runtime_generate!(
+    fn scale_all<T>(values: &mut [f32], factor: T) -> usize
+    where
+        T: Into<f32> + Copy,
+    {
+        let factor = S::Vf32::set1(factor.into());
+        for chunk in values.chunks_mut(S::Vf32::WIDTH) {
+            let (v, mask) = S::Vf32::load_partial(chunk);
+            (v * factor).store_partial(chunk, mask);
+        }
+        S::Vf32::WIDTH
+    }
+);
+
+simd_compiletime_select!(
+    fn count_matching(values: &[i32], keep: impl Fn(i32) -> bool) -> usize {
+        let mut count = 0;
+        for chunk in values.chunks(S::Vi32::WIDTH) {
+            let v = S::Vi32::load_partial(chunk).0;
+            count += (0..chunk.len()).filter(|&i| keep(v[i])).count();
+        }
+        count
+    }
+);
+
+struct Accumulator<const N: usize> {
+    totals: [i32; N],
+}
+
+impl<const N: usize> Accumulator<N> {
+    simd_runtime_generate!(
+        fn add(&mut self, values: &[i32; N]) {
+            let totals = self.totals.chunks_mut(S::Vi32::WIDTH);
+            for (total, chunk) in totals.zip(values.chunks(S::Vi32::WIDTH)) {
+                let (t, mask) = S::Vi32::load_partial(total);
+                (t + S::Vi32::load_partial(chunk).0).store_partial(total, mask);
+            }
+        }
+    );
+
+    simd_unsafe_generate_all!(
+        fn total<'a>(&'a self) -> i64 {
+            let mut total = 0i64;
+            for chunk in self.totals.chunks(S::Vi32::WIDTH) {
+                let v = S::Vi32::load_partial(chunk).0;
+                total += (0..chunk.len()).map(|i| v[i] as i64).sum::<i64>();
+            }
+            total
+        }
+    );
+}
+
+#[test]
+fn generic_parameters_are_forwarded() {
+    let pixels = [Gray(1), Gray(2), Gray(3), Gray(4), Gray(5)];
+    assert_eq!(sum_luma(&pixels), 15.0);
+    assert_eq!(
+        sum_luma_generic::<_, 5, crate::engines::scalar::Scalar>(&pixels),
+        15.0
+    );
+
+    #[cfg(any(target_arch = "x86_64", target_arch = "x86"))]
+    {
+        assert_eq!(sum_luma_scalar(&pixels), 15.0);
+        if std::arch::is_x86_feature_detected!("sse2") {
+            assert_eq!(unsafe { sum_luma_sse2(&pixels) }, 15.0);
+        }
+        if std::arch::is_x86_feature_detected!("sse4.1") {
+            assert_eq!(unsafe { sum_luma_sse41(&pixels) }, 15.0);
+        }
+        if std::arch::is_x86_feature_detected!("avx") {
+            assert_eq!(unsafe { sum_luma_avx(&pixels) }, 15.0);
+        }
+        if std::arch::is_x86_feature_detected!("avx2") && std::arch::is_x86_feature_detected!("fma")
+        {
+            assert_eq!(unsafe { sum_luma_avx2(&pixels) }, 15.0);
+        }
+        if std::arch::is_x86_feature_detected!("avx512f")
+            && std::arch::is_x86_feature_detected!("avx512bw")
+            && std::arch::is_x86_feature_detected!("avx512dq")
+        {
+            assert_eq!(unsafe { sum_luma_avx512(&pixels) }, 15.0);
+        }
+    }
+
+    #[cfg(target_arch = "aarch64")]
+    assert_eq!(unsafe { sum_luma_neon(&pixels) }, 15.0);
+
+    #[cfg(target_arch = "wasm32")]
+    assert_eq!(unsafe { sum_luma_wasm(&pixels) }, 15.0);
+}
+
+#[test]
+fn where_clauses_are_forwarded() {
+    let mut values: Vec<f32> = (0..37).map(|i| i as f32).collect();
+    let width = scale_all(&mut values, 2u8);
+    assert!(width >= 1);
+    assert!(values.iter().enumerate().all(|(i, &v)| v == 2.0 * i as f32));
+
+    let mut values = vec![1.5f32; 3];
+    assert_eq!(scale_all_scalar(&mut values, 2i16), 1);
+    assert_eq!(values, [3.0; 3]);
+}
+
+#[test]
+fn impl_trait_arguments_are_forwarded() {
+    let values: Vec<i32> = (0..100).collect();
+    assert_eq!(count_matching(&values, |v| v % 3 == 0), 34);
+    assert_eq!(count_matching_scalar(&values, |v| v > 90), 9);
+}
+
+#[test]
+fn methods_dispatch_through_self() {
+    let mut acc = Accumulator { totals: [0; 19] };
+    let values: [i32; 19] = core::array::from_fn(|i| i as i32);
+    acc.add(&values);
+    acc.add_scalar(&values);
+    acc.add_generic::<crate::engines::scalar::Scalar>(&values);
+    assert_eq!(acc.totals, core::array::from_fn(|i| 3 * i as i32));
+    assert_eq!(acc.total(), 3 * 171);
+
+    #[cfg(any(target_arch = "x86_64", target_arch = "x86"))]
+    assert_eq!(acc.total_scalar(), 3 * 171);
+    #[cfg(any(target_arch = "x86_64", target_arch = "x86"))]
+    {
+        if std::arch::is_x86_feature_detected!("avx") {
+            assert_eq!(unsafe { acc.total_avx() }, 3 * 171);
+        }
+        if std::arch::is_x86_feature_detected!("avx2") && std::arch::is_x86_feature_detected!("fma")
+        {
+            assert_eq!(unsafe { acc.total_avx2() }, 3 * 171);
+        }
+    }
+}
@@ -86,6 +86,14 @@ fn float_cast_i32_ties_to_even_across_backends() {
                 expected
             );
         }
+        if std::arch::is_x86_feature_detected!("avx") {
+            assert_eq!(
+                run_f32(&values, |values, out| unsafe {
+                    cast_f32_to_i32_avx(values, out)
+                }),
+                expected
+            );
+        }
         if std::arch::is_x86_feature_detected!("avx2") && std::arch::is_x86_feature_detected!("fma")
         {
             assert_eq!(
@@ -137,6 +145,14 @@ fn float_cast_i32_respects_defined_range_boundaries_across_backends() {
                 expected
             );
         }
+        if std::arch::is_x86_feature_detected!("avx") {
+            assert_eq!(
+                run_f32(&values, |values, out| unsafe {
+                    cast_f32_to_i32_avx(values, out)
+                }),
+                expected
+            );
+        }
         if std::arch::is_x86_feature_detected!("avx2") && std::arch::is_x86_feature_detected!("fma")
         {
             assert_eq!(
@@ -179,6 +195,14 @@ fn float_cast_i64_ties_to_even_across_backends() {
                 expected
             );
         }
+        if std::arch::is_x86_feature_detected!("avx") {
+            assert_eq!(
+                run_f64(&values, |values, out| unsafe {
+                    cast_f64_to_i64_avx(values, out)
+                }),
+                expected
+            );
+        }
         if std::arch::is_x86_feature_detected!("avx2") && std::arch::is_x86_feature_detected!("fma")
         {
             assert_eq!(
@@ -230,6 +254,14 @@ fn float_cast_i64_respects_defined_range_boundaries_across_backends() {
                 expected
             );
         }
+        if std::arch::is_x86_feature_detected!("avx") {
+            assert_eq!(
+                run_f64(&values, |values, out| unsafe {
+                    cast_f64_to_i64_avx(values, out)
+                }),
+                expected
+            );
+        }
         if std::arch::is_x86_feature_detected!("avx2") && std::arch::is_x86_feature_detected!("fma")
         {
             assert_eq!(
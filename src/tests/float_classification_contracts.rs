@@ -0,0 +1,200 @@
+#![allow(dead_code, unused_parens)]
+
+use crate::prelude::*;
+
+use super::check_all_engines;
+
+macro_rules! test_float_classification {
+    ($name:ident, $simd_ty:ident, $scalar_ty:ident, $large:expr) => {
+        mod $name {
+            use super::*;
+
+            simd_unsafe_generate_all!(
+                fn classify(a: &[$scalar_ty], _b: &[$scalar_ty]) -> Vec<u64> {
+                    let a = S::$simd_ty::load_from_slice(a);
+                    vec![
+                        a.is_nan().to_bitmask(),
+                        a.is_finite().to_bitmask(),
+                        a.is_infinite().to_bitmask(),
+                        a.is_sign_negative().to_bitmask(),
+                    ]
+                }
+            );
+
+            simd_unsafe_generate_all!(
+                fn unary(a: &[$scalar_ty], b: &[$scalar_ty]) -> Vec<$scalar_ty> {
+                    let a = S::$simd_ty::load_from_slice(a);
+                    let b = S::$simd_ty::load_from_slice(b);
+                    let mut out = vec![0.0; 6 * S::$simd_ty::WIDTH];
+                    let results = [
+                        a.trunc(),
+                        a.fract(),
+                        a.recip(),
+                        a.signum(),
+                        a.copysign(b),
+                        a.clamp(S::$simd_ty::set1(-2.5), S::$simd_ty::set1(3.0)),
+                    ];
+                    for (result, chunk) in results.iter().zip(out.chunks_mut(S::$simd_ty::WIDTH)) {
+                        result.copy_to_slice(chunk);
+                    }
+                    out
+                }
+            );
+
+            simd_unsafe_generate_all!(
+                fn mul_add(a: &[$scalar_ty], b: &[$scalar_ty]) -> Vec<$scalar_ty> {
+                    let a = S::$simd_ty::load_from_slice(a);
+                    let b = S::$simd_ty::load_from_slice(b);
+                    let minus_one = S::$simd_ty::set1(-1.0);
+                    let mut out = vec![0.0; 3 * S::$simd_ty::WIDTH];
+                    let results = [
+                        a.mul_add(b, b),
+                        a.mul_add_fused(b, minus_one),
+                        a.mul_add_unfused(b, minus_one),
+                    ];
+                    for (result, chunk) in results.iter().zip(out.chunks_mut(S::$simd_ty::WIDTH)) {
+                        result.copy_to_slice(chunk);
+                    }
+                    out
+                }
+            );
+
+            fn lanes(seed: usize, width: usize) -> Vec<$scalar_ty> {
+                let pattern: [$scalar_ty; 18] = [
+                    0.0,
+                    -0.0,
+                    0.5,
+                    -0.5,
+                    1.5,
+                    -3.75,
+                    2.5,
+                    -2.5,
+                    $large,
+                    -$large,
+                    <$scalar_ty>::MAX,
+                    <$scalar_ty>::MIN,
+                    <$scalar_ty>::MIN_POSITIVE / 4.0,
+                    -<$scalar_ty>::MIN_POSITIVE / 4.0,
+                    <$scalar_ty>::INFINITY,
+                    <$scalar_ty>::NEG_INFINITY,
+                    <$scalar_ty>::NAN,
+                    -<$scalar_ty>::NAN,
+                ];
+                (0..width)
+                    .map(|i| pattern[(i * 5 + seed) % pattern.len()])
+                    .collect()
+            }
+
+            fn bitmask(a: &[$scalar_ty], f: impl Fn($scalar_ty) -> bool) -> u64 {
+                a.iter().enumerate().map(|(i, &x)| (f(x) as u64) << i).sum()
+            }
+
+            fn check_classify(
+                width: usize,
+                classify: impl Fn(&[$scalar_ty], &[$scalar_ty]) -> Vec<u64>,
+            ) {
+                for seed in 0..18 {
+                    let a = lanes(seed, width);
+                    let expected = vec![
+                        bitmask(&a, <$scalar_ty>::is_nan),
+                        bitmask(&a, <$scalar_ty>::is_finite),
+                        bitmask(&a, <$scalar_ty>::is_infinite),
+                        bitmask(&a, <$scalar_ty>::is_sign_negative),
+                    ];
+                    assert_eq!(classify(&a, &a), expected, "classification of {a:?}");
+                }
+            }
+
+            fn check_unary(
+                width: usize,
+                unary: impl Fn(&[$scalar_ty], &[$scalar_ty]) -> Vec<$scalar_ty>,
+            ) {
+                for seed in 0..18 {
+                    let a = lanes(seed, width);
+                    let b = lanes(seed * 7 + 3, width);
+                    let pairs = || a.iter().zip(&b).map(|(&x, &y)| (x, y));
+                    let expected: Vec<$scalar_ty> = [
+                        a.iter().map(|x| x.trunc()).collect::<Vec<_>>(),
+                        a.iter().map(|x| x.fract()).collect(),
+                        a.iter().map(|x| x.recip()).collect(),
+                        a.iter().map(|x| x.signum()).collect(),
+                        pairs().map(|(x, y)| x.copysign(y)).collect(),
+                        a.iter().map(|x| x.clamp(-2.5, 3.0)).collect(),
+                    ]
+                    .concat();
+                    let out = unary(&a, &b);
+                    for (i, (actual, expected)) in out.iter().zip(&expected).enumerate() {
+                        let op =
+                            ["trunc", "fract", "recip", "signum", "copysign", "clamp"][i / width];
+                        if expected.is_nan() {
+                            assert!(actual.is_nan(), "{op} lane {} of {a:?}", i % width);
+                        } else {
+                            assert_eq!(
+                                actual.to_bits(),
+                                expected.to_bits(),
+                                "{op} lane {} of {a:?} and {b:?}: {actual} != {expected}",
+                                i % width
+                            );
+                        }
+                    }
+                }
+            }
+
+            fn check_mul_add(
+                width: usize,
+                mul_add: impl Fn(&[$scalar_ty], &[$scalar_ty]) -> Vec<$scalar_ty>,
+            ) {
+                // (1 + eps)(1 - eps) - 1 is -eps^2 rounded once, but zero with a rounded product.
+                let near_one = (
+                    vec![1.0 + <$scalar_ty>::EPSILON; width],
+                    vec![1.0 - <$scalar_ty>::EPSILON; width],
+                );
+                let inputs = (0..18)
+                    .map(|seed| (lanes(seed, width), lanes(seed * 11 + 1, width)))
+                    .chain([near_one]);
+                for (a, b) in inputs {
+                    let out = mul_add(&a, &b);
+                    for (i, (&x, &y)) in a.iter().zip(&b).enumerate() {
+                        // Fast results may be fused or not, but NaN must agree.
+                        assert_eq!(
+                            out[i].is_nan(),
+                            x.mul_add(y, y).is_nan(),
+                            "mul_add lane {i}: {x} * {y} + {y} = {}",
+                            out[i]
+                        );
+                        for (op, actual, expected) in [
+                            ("mul_add_fused", out[width + i], x.mul_add(y, -1.0)),
+                            ("mul_add_unfused", out[2 * width + i], x * y - 1.0),
+                        ] {
+                            assert!(
+                                actual.to_bits() == expected.to_bits()
+                                    || (actual.is_nan() && expected.is_nan()),
+                                "{op} lane {i}: {x} * {y} - 1 = {actual}, expected {expected}"
+                            );
+                        }
+                    }
+                }
+            }
+
+            #[test]
+            fn classification_matches_scalar() {
+                check_all_engines!(check_classify, classify, $simd_ty);
+            }
+
+            #[test]
+            fn sign_and_rounding_helpers_match_scalar() {
+                check_all_engines!(check_unary, unary, $simd_ty);
+            }
+
+            #[test]
+            fn mul_add_variants_match_scalar() {
+                check_all_engines!(check_mul_add, mul_add, $simd_ty);
+            }
+        }
+    };
+}
+
+// The large values sit just past the point where every float is an integer, so `trunc` must
+// return them unchanged.
+test_float_classification!(f32_lanes, Vf32, f32, 8_388_609.0);
+test_float_classification!(f64_lanes, Vf64, f64, 4_503_599_627_370_497.0);
@@ -44,7 +44,7 @@ simd_unsafe_generate_all!(
         while lhs.len() >= S::Vf32::WIDTH {
             let a = S::Vf32::load_from_slice(lhs);
             let b = S::Vf32::load_from_slice(rhs);
-            a.cmp_neq(b).copy_to_slice(out);
+            a.cmp_neq(b).to_vector().copy_to_slice(out);
 
             lhs = &lhs[S::Vf32::WIDTH..];
             rhs = &rhs[S::Vf32::WIDTH..];
@@ -69,7 +69,7 @@ simd_unsafe_generate_all!(
         while lhs.len() >= S::Vf64::WIDTH {
             let a = S::Vf64::load_from_slice(lhs);
             let b = S::Vf64::load_from_slice(rhs);
-            a.cmp_neq(b).copy_to_slice(out);
+            a.cmp_neq(b).to_vector().copy_to_slice(out);
 
             lhs = &lhs[S::Vf64::WIDTH..];
             rhs = &rhs[S::Vf64::WIDTH..];
@@ -135,6 +135,14 @@ fn float_cmp_neq_nan_semantics_match_scalar_for_f32() {
                 &expected,
             );
         }
+        if std::arch::is_x86_feature_detected!("avx") {
+            assert_f32_bits_eq(
+                &run_f32(&lhs, &rhs, |lhs, rhs, out| unsafe {
+                    compare_neq_mask_f32_avx(lhs, rhs, out)
+                }),
+                &expected,
+            );
+        }
         if std::arch::is_x86_feature_detected!("avx2") && std::arch::is_x86_feature_detected!("fma")
         {
             assert_f32_bits_eq(
@@ -187,6 +195,14 @@ fn float_cmp_neq_nan_semantics_match_scalar_for_f64() {
                 &expected,
             );
         }
+        if std::arch::is_x86_feature_detected!("avx") {
+            assert_f64_bits_eq(
+                &run_f64(&lhs, &rhs, |lhs, rhs, out| unsafe {
+                    compare_neq_mask_f64_avx(lhs, rhs, out)
+                }),
+                &expected,
+            );
+        }
         if std::arch::is_x86_feature_detected!("avx2") && std::arch::is_x86_feature_detected!("fma")
         {
             assert_f64_bits_eq(
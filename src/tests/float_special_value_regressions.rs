@@ -2,6 +2,8 @@
 
 use crate::prelude::*;
 
+use super::check_all_engines;
+
 fn assert_f32_bits_eq(actual: &[f32], expected: &[f32]) {
     assert_eq!(actual.len(), expected.len());
     for (actual, expected) in actual.iter().zip(expected.iter()) {
@@ -469,3 +471,146 @@ fn float_sqrt_special_values_match_scalar_for_f64() {
         &values.iter().map(|&v| v.sqrt()).collect::<Vec<_>>(),
     );
 }
+
+macro_rules! test_portable_min_max {
+    ($name:ident, $simd_ty:ident, $scalar_ty:ident) => {
+        mod $name {
+            use super::*;
+
+            #[inline(always)]
+            fn min_max_lanes<S: Simd>(a: &[$scalar_ty], b: &[$scalar_ty]) -> Vec<$scalar_ty> {
+                let a = S::$simd_ty::load_from_slice(a);
+                let b = S::$simd_ty::load_from_slice(b);
+                let less = a
+                    .total_cmp_lt(b)
+                    .select(S::$simd_ty::set1(1.0), S::$simd_ty::zeroes());
+                let results = [
+                    a.min_ieee(b),
+                    a.max_ieee(b),
+                    a.min_propagate_nan(b),
+                    a.max_propagate_nan(b),
+                    a.total_min(b),
+                    a.total_max(b),
+                    less,
+                ];
+                let mut out = vec![0.0; results.len() * S::$simd_ty::WIDTH];
+                for (result, chunk) in results.iter().zip(out.chunks_mut(S::$simd_ty::WIDTH)) {
+                    result.copy_to_slice(chunk);
+                }
+                out
+            }
+
+            simd_unsafe_generate_all!(
+                fn min_max(a: &[$scalar_ty], b: &[$scalar_ty]) -> Vec<$scalar_ty> {
+                    min_max_lanes::<S>(a, b)
+                }
+            );
+
+            fn edges() -> Vec<$scalar_ty> {
+                let quiet_payload = <$scalar_ty>::from_bits(<$scalar_ty>::NAN.to_bits() | 1);
+                let signaling = <$scalar_ty>::from_bits(<$scalar_ty>::INFINITY.to_bits() | 1);
+                vec![
+                    0.0,
+                    -0.0,
+                    1.0,
+                    -1.0,
+                    2.5,
+                    <$scalar_ty>::MIN_POSITIVE,
+                    <$scalar_ty>::from_bits(1),
+                    -<$scalar_ty>::from_bits(1),
+                    <$scalar_ty>::MAX,
+                    <$scalar_ty>::MIN,
+                    <$scalar_ty>::INFINITY,
+                    <$scalar_ty>::NEG_INFINITY,
+                    <$scalar_ty>::NAN,
+                    -<$scalar_ty>::NAN,
+                    quiet_payload,
+                    signaling,
+                ]
+            }
+
+            fn min_propagate_nan(x: $scalar_ty, y: $scalar_ty) -> $scalar_ty {
+                if x.is_nan() || y.is_nan() {
+                    <$scalar_ty>::NAN
+                } else if x == y {
+                    <$scalar_ty>::from_bits(x.to_bits() | y.to_bits())
+                } else {
+                    x.min(y)
+                }
+            }
+
+            fn max_propagate_nan(x: $scalar_ty, y: $scalar_ty) -> $scalar_ty {
+                if x.is_nan() || y.is_nan() {
+                    <$scalar_ty>::NAN
+                } else if x == y {
+                    <$scalar_ty>::from_bits(x.to_bits() & y.to_bits())
+                } else {
+                    x.max(y)
+                }
+            }
+
+            fn number_first(x: $scalar_ty, y: $scalar_ty) -> ($scalar_ty, $scalar_ty) {
+                match (x.is_nan(), y.is_nan()) {
+                    (true, false) => (y, y),
+                    (false, true) => (x, x),
+                    _ => (x, y),
+                }
+            }
+
+            fn expected(x: $scalar_ty, y: $scalar_ty) -> [$scalar_ty; 7] {
+                let (nx, ny) = number_first(x, y);
+                let less = x.total_cmp(&y).is_lt();
+                [
+                    min_propagate_nan(nx, ny),
+                    max_propagate_nan(nx, ny),
+                    min_propagate_nan(x, y),
+                    max_propagate_nan(x, y),
+                    if y.total_cmp(&x).is_lt() { y } else { x },
+                    if less { y } else { x },
+                    if less { 1.0 } else { 0.0 },
+                ]
+            }
+
+            fn check(
+                width: usize,
+                min_max: impl Fn(&[$scalar_ty], &[$scalar_ty]) -> Vec<$scalar_ty>,
+            ) {
+                let edges = edges();
+                let pairs: Vec<($scalar_ty, $scalar_ty)> = edges
+                    .iter()
+                    .flat_map(|&x| edges.iter().map(move |&y| (x, y)))
+                    .collect();
+                for chunk in pairs.chunks(width) {
+                    let a: Vec<$scalar_ty> = (0..width).map(|i| chunk[i % chunk.len()].0).collect();
+                    let b: Vec<$scalar_ty> = (0..width).map(|i| chunk[i % chunk.len()].1).collect();
+                    let out = min_max(&a, &b);
+                    for (lane, (&x, &y)) in a.iter().zip(&b).enumerate() {
+                        let scalar_engine =
+                            min_max_lanes::<crate::engines::scalar::Scalar>(&[x], &[y]);
+                        for (op, expected) in expected(x, y).iter().enumerate() {
+                            let actual = out[op * width + lane];
+                            assert_eq!(
+                                actual.to_bits(),
+                                expected.to_bits(),
+                                "op {op} of {x:?} and {y:?}: {actual:?} != {expected:?}"
+                            );
+                            assert_eq!(
+                                actual.to_bits(),
+                                scalar_engine[op].to_bits(),
+                                "op {op} of {x:?} and {y:?} differs from the scalar engine"
+                            );
+                        }
+                    }
+                }
+            }
+
+            #[test]
+            fn portable_min_max_and_total_order_are_bit_identical() {
+                check_all_engines!(check, min_max, $simd_ty);
+            }
+        }
+    };
+}
+
+test_portable_min_max!(f32_min_max, Vf32, f32);
+test_portable_min_max!(f64_min_max, Vf64, f64);
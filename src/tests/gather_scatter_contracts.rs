@@ -0,0 +1,351 @@
+#![allow(dead_code, unused_parens)]
+
+use crate::prelude::*;
+
+use super::check_all_engines;
+
+macro_rules! test_gather_scatter {
+    (
+        $name:ident,
+        $simd_ty:ident,
+        $scalar_ty:ty,
+        $idx_simd_ty:ident,
+        $idx_ty:ty,
+        $uidx_simd_ty:ident,
+        $uidx_ty:ty
+    ) => {
+        mod $name {
+            use super::*;
+
+            simd_unsafe_generate_all!(
+                fn gather_scatter(
+                    table: &[$scalar_ty],
+                    idx: &[$idx_ty],
+                    masked_idx: &[$idx_ty],
+                    dup_idx: &[$idx_ty],
+                    flags: &[$scalar_ty],
+                    scattered: &mut [$scalar_ty],
+                    masked_scattered: &mut [$scalar_ty],
+                ) -> Vec<$scalar_ty> {
+                    let width = S::$simd_ty::WIDTH;
+                    let indices = S::$idx_simd_ty::load_from_slice(idx);
+                    let unsigned: Vec<$uidx_ty> = idx.iter().map(|&i| i as $uidx_ty).collect();
+                    let unsigned = S::$uidx_simd_ty::load_from_slice(&unsigned);
+                    let masked_indices = S::$idx_simd_ty::load_from_slice(masked_idx);
+                    let dup_indices = S::$idx_simd_ty::load_from_slice(dup_idx);
+                    let mask = S::$simd_ty::load_from_slice(flags)
+                        .cmp_eq(S::$simd_ty::set1(1 as $scalar_ty));
+                    let fallback = S::$simd_ty::set1(7 as $scalar_ty);
+                    let values: Vec<$scalar_ty> =
+                        (0..width).map(|i| (100 + i) as $scalar_ty).collect();
+                    let values = S::$simd_ty::load_from_slice(&values);
+
+                    let results = [
+                        S::$simd_ty::gather(table, indices),
+                        S::$simd_ty::gather(table, unsigned),
+                        S::$simd_ty::gather_masked(table, masked_indices, mask, fallback),
+                    ];
+                    values.scatter(scattered, dup_indices);
+                    values.scatter_masked(masked_scattered, masked_indices, mask);
+
+                    let mut out = vec![0 as $scalar_ty; results.len() * width];
+                    for (result, chunk) in results.iter().zip(out.chunks_mut(width)) {
+                        result.copy_to_slice(chunk);
+                    }
+                    out
+                }
+            );
+
+            fn check(
+                width: usize,
+                gather_scatter: impl Fn(
+                    &[$scalar_ty],
+                    &[$idx_ty],
+                    &[$idx_ty],
+                    &[$idx_ty],
+                    &[$scalar_ty],
+                    &mut [$scalar_ty],
+                    &mut [$scalar_ty],
+                ) -> Vec<$scalar_ty>,
+            ) {
+                let len = 3 * width + 5;
+                let table: Vec<$scalar_ty> = (0..len).map(|i| (i * 3 + 1) as $scalar_ty).collect();
+                let idx: Vec<$idx_ty> =
+                    (0..width).map(|i| ((i * 7 + 2) % len) as $idx_ty).collect();
+                let active: Vec<bool> = (0..width).map(|i| i % 3 != 1).collect();
+                // Inactive lanes point outside the table and must not be touched or checked.
+                let masked_idx: Vec<$idx_ty> = (0..width)
+                    .map(|i| match (active[i], i % 2) {
+                        (true, _) => ((i * 5 + 1) % len) as $idx_ty,
+                        (false, 0) => (len + 100) as $idx_ty,
+                        (false, _) => -5 as $idx_ty,
+                    })
+                    .collect();
+                let dup_idx: Vec<$idx_ty> = (0..width).map(|i| (i / 2 * 3) as $idx_ty).collect();
+                let flags: Vec<$scalar_ty> = active
+                    .iter()
+                    .map(|&a| if a { 1 as $scalar_ty } else { 0 as $scalar_ty })
+                    .collect();
+                let values: Vec<$scalar_ty> = (0..width).map(|i| (100 + i) as $scalar_ty).collect();
+
+                let mut scattered = vec![0 as $scalar_ty; len];
+                let mut masked_scattered = vec![0 as $scalar_ty; len];
+                let out = gather_scatter(
+                    &table,
+                    &idx,
+                    &masked_idx,
+                    &dup_idx,
+                    &flags,
+                    &mut scattered,
+                    &mut masked_scattered,
+                );
+
+                let gathered: Vec<$scalar_ty> = idx.iter().map(|&i| table[i as usize]).collect();
+                let masked: Vec<$scalar_ty> = (0..width)
+                    .map(|i| {
+                        if active[i] {
+                            table[masked_idx[i] as usize]
+                        } else {
+                            7 as $scalar_ty
+                        }
+                    })
+                    .collect();
+                assert_eq!(&out[..width], &gathered[..], "gather mismatch");
+                assert_eq!(
+                    &out[width..2 * width],
+                    &gathered[..],
+                    "unsigned index gather mismatch"
+                );
+                assert_eq!(&out[2 * width..], &masked[..], "masked gather mismatch");
+
+                let mut expected = vec![0 as $scalar_ty; len];
+                for (i, &index) in dup_idx.iter().enumerate() {
+                    expected[index as usize] = values[i];
+                }
+                assert_eq!(scattered, expected, "scatter mismatch");
+
+                let mut expected = vec![0 as $scalar_ty; len];
+                for (i, &index) in masked_idx.iter().enumerate() {
+                    if active[i] {
+                        expected[index as usize] = values[i];
+                    }
+                }
+                assert_eq!(masked_scattered, expected, "masked scatter mismatch");
+            }
+
+            #[test]
+            fn gather_and_scatter_match_scalar_indexing() {
+                #[cfg(any(target_arch = "x86_64", target_arch = "x86"))]
+                {
+                    use crate::engines::{
+                        avx::Avx, avx2::Avx2, avx512::Avx512, scalar::Scalar, sse2::Sse2,
+                        sse41::Sse41,
+                    };
+
+                    check(<Scalar as Simd>::$simd_ty::WIDTH, gather_scatter_scalar);
+                    if std::arch::is_x86_feature_detected!("sse2") {
+                        check(
+                            <Sse2 as Simd>::$simd_ty::WIDTH,
+                            |t, i, m, d, f, s, ms| unsafe {
+                                gather_scatter_sse2(t, i, m, d, f, s, ms)
+                            },
+                        );
+                    }
+                    if std::arch::is_x86_feature_detected!("sse4.1") {
+                        check(
+                            <Sse41 as Simd>::$simd_ty::WIDTH,
+                            |t, i, m, d, f, s, ms| unsafe {
+                                gather_scatter_sse41(t, i, m, d, f, s, ms)
+                            },
+                        );
+                    }
+                    if std::arch::is_x86_feature_detected!("avx") {
+                        check(
+                            <Avx as Simd>::$simd_ty::WIDTH,
+                            |t, i, m, d, f, s, ms| unsafe {
+                                gather_scatter_avx(t, i, m, d, f, s, ms)
+                            },
+                        );
+                    }
+                    if std::arch::is_x86_feature_detected!("avx2")
+                        && std::arch::is_x86_feature_detected!("fma")
+                    {
+                        check(
+                            <Avx2 as Simd>::$simd_ty::WIDTH,
+                            |t, i, m, d, f, s, ms| unsafe {
+                                gather_scatter_avx2(t, i, m, d, f, s, ms)
+                            },
+                        );
+                    }
+                    if std::arch::is_x86_feature_detected!("avx512f")
+                        && std::arch::is_x86_feature_detected!("avx512bw")
+                        && std::arch::is_x86_feature_detected!("avx512dq")
+                    {
+                        check(
+                            <Avx512 as Simd>::$simd_ty::WIDTH,
+                            |t, i, m, d, f, s, ms| unsafe {
+                                gather_scatter_avx512(t, i, m, d, f, s, ms)
+                            },
+                        );
+                    }
+                }
+
+                #[cfg(target_arch = "aarch64")]
+                check(
+                    <crate::engines::neon::Neon as Simd>::$simd_ty::WIDTH,
+                    |t, i, m, d, f, s, ms| unsafe { gather_scatter_neon(t, i, m, d, f, s, ms) },
+                );
+
+                #[cfg(target_arch = "wasm32")]
+                check(
+                    <crate::engines::wasm32::Wasm as Simd>::$simd_ty::WIDTH,
+                    |t, i, m, d, f, s, ms| unsafe { gather_scatter_wasm(t, i, m, d, f, s, ms) },
+                );
+            }
+        }
+    };
+}
+
+test_gather_scatter!(i32_lanes, Vi32, i32, Vi32, i32, Vu32, u32);
+test_gather_scatter!(i64_lanes, Vi64, i64, Vi64, i64, Vu64, u64);
+test_gather_scatter!(u32_lanes, Vu32, u32, Vi32, i32, Vu32, u32);
+test_gather_scatter!(u64_lanes, Vu64, u64, Vi64, i64, Vu64, u64);
+test_gather_scatter!(f32_lanes, Vf32, f32, Vi32, i32, Vu32, u32);
+test_gather_scatter!(f64_lanes, Vf64, f64, Vi64, i64, Vu64, u64);
+
+/// The 64 bit gathers and scatters that take the low lanes of a 32 bit index vector.
+macro_rules! test_gather_scatter_i32 {
+    ($name:ident, $simd_ty:ident, $scalar_ty:ty) => {
+        mod $name {
+            use super::*;
+
+            fn table(width: usize) -> Vec<$scalar_ty> {
+                (0..3 * width + 5)
+                    .map(|i| (i * 3 + 1) as $scalar_ty)
+                    .collect()
+            }
+
+            simd_unsafe_generate_all!(
+                fn gather_scatter_i32(idx: &[i32], masked_idx: &[i32]) -> Vec<$scalar_ty> {
+                    let width = S::$simd_ty::WIDTH;
+                    let table = table(width);
+                    let indices = S::Vi32::load_from_slice(idx);
+                    let unsigned: Vec<u32> = idx.iter().map(|&i| i as u32).collect();
+                    let unsigned = S::Vu32::load_from_slice(&unsigned);
+                    let masked_indices = S::Vi32::load_from_slice(masked_idx);
+                    let flags: Vec<$scalar_ty> = (0..width)
+                        .map(|i| (i % 3 != 1) as u8 as $scalar_ty)
+                        .collect();
+                    let mask = S::$simd_ty::load_from_slice(&flags)
+                        .cmp_eq(S::$simd_ty::set1(1 as $scalar_ty));
+                    let values: Vec<$scalar_ty> =
+                        (0..width).map(|i| (100 + i) as $scalar_ty).collect();
+                    let values = S::$simd_ty::load_from_slice(&values);
+
+                    let mut out = vec![0 as $scalar_ty; 3 * width];
+                    for (chunk, result) in out.chunks_mut(width).zip([
+                        S::$simd_ty::gather_i32(&table, indices),
+                        S::$simd_ty::gather_i32(&table, unsigned),
+                        S::$simd_ty::gather_masked_i32(
+                            &table,
+                            masked_indices,
+                            mask,
+                            S::$simd_ty::set1(7 as $scalar_ty),
+                        ),
+                    ]) {
+                        result.copy_to_slice(chunk);
+                    }
+                    let mut scattered = vec![0 as $scalar_ty; table.len()];
+                    values.scatter_i32(&mut scattered, indices);
+                    out.extend_from_slice(&scattered);
+                    let mut scattered = vec![0 as $scalar_ty; table.len()];
+                    values.scatter_masked_i32(&mut scattered, masked_indices, mask);
+                    out.extend_from_slice(&scattered);
+                    out
+                }
+            );
+
+            fn check(width: usize, gather_scatter: impl Fn(&[i32], &[i32]) -> Vec<$scalar_ty>) {
+                let table = table(width);
+                let len = table.len();
+                let active: Vec<bool> = (0..width).map(|i| i % 3 != 1).collect();
+                // The index vectors have twice `width` lanes except on the scalar engine. Lanes
+                // past `width`, and inactive lanes, point outside the table and must be ignored.
+                let idx: Vec<i32> = (0..2 * width)
+                    .map(|i| {
+                        if i < width {
+                            ((i * 7 + 2) % len) as i32
+                        } else {
+                            -1
+                        }
+                    })
+                    .collect();
+                let masked_idx: Vec<i32> = (0..2 * width)
+                    .map(|i| match i < width && active[i] {
+                        true => ((i * 5 + 1) % len) as i32,
+                        false => (len + 100) as i32,
+                    })
+                    .collect();
+                let values: Vec<$scalar_ty> = (0..width).map(|i| (100 + i) as $scalar_ty).collect();
+
+                let mut expected: Vec<$scalar_ty> =
+                    idx[..width].iter().map(|&i| table[i as usize]).collect();
+                expected.extend_from_within(..width);
+                expected.extend((0..width).map(|i| {
+                    if active[i] {
+                        table[masked_idx[i] as usize]
+                    } else {
+                        7 as $scalar_ty
+                    }
+                }));
+                let mut scattered = vec![0 as $scalar_ty; len];
+                for i in 0..width {
+                    scattered[idx[i] as usize] = values[i];
+                }
+                expected.extend_from_slice(&scattered);
+                let mut scattered = vec![0 as $scalar_ty; len];
+                for i in (0..width).filter(|&i| active[i]) {
+                    scattered[masked_idx[i] as usize] = values[i];
+                }
+                expected.extend_from_slice(&scattered);
+
+                assert_eq!(gather_scatter(&idx, &masked_idx), expected);
+            }
+
+            #[test]
+            fn gather_and_scatter_with_i32_indices_match_scalar_indexing() {
+                check_all_engines!(check, gather_scatter_i32, $simd_ty);
+            }
+        }
+    };
+}
+
+test_gather_scatter_i32!(i64_lanes_i32_indices, Vi64, i64);
+test_gather_scatter_i32!(u64_lanes_i32_indices, Vu64, u64);
+test_gather_scatter_i32!(f64_lanes_i32_indices, Vf64, f64);
+
+mod bounds {
+    use super::*;
+    use crate::engines::scalar::Scalar;
+
+    #[test]
+    #[should_panic(expected = "out of bounds")]
+    fn gather_rejects_index_past_end() {
+        let table = [1.0f32, 2.0, 3.0];
+        <Scalar as Simd>::Vf32::gather(&table, <Scalar as Simd>::Vi32::set1(3));
+    }
+
+    #[test]
+    #[should_panic(expected = "out of bounds")]
+    fn scatter_rejects_unsigned_index_with_high_bit() {
+        let mut table = [0u64; 4];
+        <Scalar as Simd>::Vu64::set1(1).scatter(&mut table, <Scalar as Simd>::Vu64::set1(1 << 63));
+    }
+
+    #[test]
+    #[should_panic(expected = "out of bounds")]
+    fn gather_i32_rejects_negative_index() {
+        let table = [1.0f64, 2.0];
+        <Scalar as Simd>::Vf64::gather_i32(&table, <Scalar as Simd>::Vi32::set1(-1));
+    }
+}
@@ -0,0 +1,242 @@
+#![allow(dead_code, unused_parens)]
+
+use crate::prelude::*;
+
+use super::check_all_engines;
+
+macro_rules! test_int_reductions {
+    ($name:ident, $simd_ty:ident, $scalar_ty:ty) => {
+        mod $name {
+            use super::*;
+
+            simd_unsafe_generate_all!(
+                fn reductions(a: &[$scalar_ty], _b: &[$scalar_ty]) -> Vec<$scalar_ty> {
+                    let a = S::$simd_ty::load_from_slice(a);
+                    vec![
+                        a.horizontal_min(),
+                        a.horizontal_max(),
+                        a.horizontal_mul(),
+                        a.horizontal_and(),
+                        a.horizontal_or(),
+                        a.horizontal_xor(),
+                        a.argmin() as $scalar_ty,
+                        a.argmax() as $scalar_ty,
+                    ]
+                }
+            );
+
+            fn check(
+                width: usize,
+                reductions: impl Fn(&[$scalar_ty], &[$scalar_ty]) -> Vec<$scalar_ty>,
+            ) {
+                let pattern: [$scalar_ty; 9] = [
+                    3,
+                    <$scalar_ty>::MAX,
+                    7,
+                    <$scalar_ty>::MIN,
+                    1,
+                    <$scalar_ty>::MAX,
+                    5,
+                    <$scalar_ty>::MIN,
+                    0b0110,
+                ];
+                for seed in 0..pattern.len() {
+                    let a: Vec<$scalar_ty> = (0..width)
+                        .map(|i| pattern[(i * 4 + seed) % pattern.len()])
+                        .collect();
+                    let min = *a.iter().min().unwrap();
+                    let max = *a.iter().max().unwrap();
+                    let expected = vec![
+                        min,
+                        max,
+                        a.iter().fold(1, |acc: $scalar_ty, &x| acc.wrapping_mul(x)),
+                        a.iter().fold(!0, |acc, &x| acc & x),
+                        a.iter().fold(0, |acc, &x| acc | x),
+                        a.iter().fold(0, |acc, &x| acc ^ x),
+                        a.iter().position(|&x| x == min).unwrap() as $scalar_ty,
+                        a.iter().position(|&x| x == max).unwrap() as $scalar_ty,
+                    ];
+                    assert_eq!(reductions(&a, &a), expected, "reductions of {a:?}");
+                }
+            }
+
+            #[test]
+            fn reductions_match_scalar() {
+                check_all_engines!(check, reductions, $simd_ty);
+            }
+        }
+    };
+}
+
+test_int_reductions!(i8_lanes, Vi8, i8);
+test_int_reductions!(i16_lanes, Vi16, i16);
+test_int_reductions!(i32_lanes, Vi32, i32);
+test_int_reductions!(i64_lanes, Vi64, i64);
+test_int_reductions!(u8_lanes, Vu8, u8);
+test_int_reductions!(u16_lanes, Vu16, u16);
+test_int_reductions!(u32_lanes, Vu32, u32);
+test_int_reductions!(u64_lanes, Vu64, u64);
+
+macro_rules! test_float_reductions {
+    ($name:ident, $simd_ty:ident, $scalar_ty:ident) => {
+        mod $name {
+            use super::*;
+
+            simd_unsafe_generate_all!(
+                fn reductions(a: &[$scalar_ty], _b: &[$scalar_ty]) -> Vec<$scalar_ty> {
+                    let a = S::$simd_ty::load_from_slice(a);
+                    vec![
+                        a.horizontal_add(),
+                        a.horizontal_mul(),
+                        a.horizontal_min(),
+                        a.horizontal_max(),
+                        a.argmin() as $scalar_ty,
+                        a.argmax() as $scalar_ty,
+                    ]
+                }
+            );
+
+            // Sums every prefix of `data`, so ragged tails of each length are covered.
+            simd_unsafe_generate_all!(
+                fn sum(data: &[$scalar_ty], _b: &[$scalar_ty]) -> Vec<$scalar_ty> {
+                    (0..=data.len())
+                        .map(|len| S::$simd_ty::sum_pairwise(&data[..len]))
+                        .collect()
+                }
+            );
+
+            fn pairwise(
+                values: &[$scalar_ty],
+                op: impl Fn($scalar_ty, $scalar_ty) -> $scalar_ty + Copy,
+            ) -> $scalar_ty {
+                match values.len() {
+                    1 => values[0],
+                    len => {
+                        let (left, right) = values.split_at(len / 2);
+                        op(pairwise(left, op), pairwise(right, op))
+                    }
+                }
+            }
+
+            // The largest power of two not above the length is summed pairwise, then joined to
+            // the sum of what is left.
+            fn reference_sum(values: &[$scalar_ty]) -> $scalar_ty {
+                match values.len() {
+                    0 => 0.0,
+                    len if len.is_power_of_two() => pairwise(values, |a, b| a + b),
+                    len => {
+                        let (left, right) = values.split_at(1 << len.ilog2());
+                        pairwise(left, |a, b| a + b) + reference_sum(right)
+                    }
+                }
+            }
+
+            fn min_number(a: $scalar_ty, b: $scalar_ty) -> $scalar_ty {
+                match (a.is_nan(), b.is_nan()) {
+                    (true, true) => <$scalar_ty>::NAN,
+                    (true, false) => b,
+                    (false, true) => a,
+                    _ if a == b => <$scalar_ty>::from_bits(a.to_bits() | b.to_bits()),
+                    _ => a.min(b),
+                }
+            }
+
+            fn max_number(a: $scalar_ty, b: $scalar_ty) -> $scalar_ty {
+                match (a.is_nan(), b.is_nan()) {
+                    (true, true) => <$scalar_ty>::NAN,
+                    (true, false) => b,
+                    (false, true) => a,
+                    _ if a == b => <$scalar_ty>::from_bits(a.to_bits() & b.to_bits()),
+                    _ => a.max(b),
+                }
+            }
+
+            fn check(
+                width: usize,
+                reductions: impl Fn(&[$scalar_ty], &[$scalar_ty]) -> Vec<$scalar_ty>,
+            ) {
+                let pattern: [$scalar_ty; 11] = [
+                    1.0e8,
+                    0.1,
+                    -1.0e8,
+                    -0.0,
+                    3.25,
+                    0.0,
+                    <$scalar_ty>::NAN,
+                    -7.5,
+                    1.0e-3,
+                    <$scalar_ty>::NEG_INFINITY,
+                    2.0,
+                ];
+                for seed in 0..pattern.len() {
+                    let a: Vec<$scalar_ty> = (0..width)
+                        .map(|i| pattern[(i * 3 + seed) % pattern.len()])
+                        .collect();
+                    let min = pairwise(&a, min_number);
+                    let max = pairwise(&a, max_number);
+                    let first_equal = |target: $scalar_ty| {
+                        a.iter().position(|&x| x == target).unwrap_or(0) as $scalar_ty
+                    };
+                    let expected = [
+                        pairwise(&a, |x, y| x + y),
+                        pairwise(&a, |x, y| x * y),
+                        min,
+                        max,
+                        first_equal(min),
+                        first_equal(max),
+                    ];
+                    let out = reductions(&a, &a);
+                    for (op, (actual, expected)) in out.iter().zip(&expected).enumerate() {
+                        if expected.is_nan() {
+                            assert!(actual.is_nan(), "reduction {op} of {a:?}");
+                        } else {
+                            assert_eq!(
+                                actual.to_bits(),
+                                expected.to_bits(),
+                                "reduction {op} of {a:?}: {actual} != {expected}"
+                            );
+                        }
+                    }
+                }
+            }
+
+            fn check_sum(
+                width: usize,
+                sum: impl Fn(&[$scalar_ty], &[$scalar_ty]) -> Vec<$scalar_ty>,
+            ) {
+                // 70 values ends with a partial vector on every engine but the scalar one.
+                let data: Vec<$scalar_ty> = (0..70)
+                    .map(|i| {
+                        let magnitude = [1.0e7, 0.3, 1.0e-4, 12.5][i % 4];
+                        if i % 3 == 0 {
+                            -magnitude
+                        } else {
+                            magnitude * (i as $scalar_ty + 0.7)
+                        }
+                    })
+                    .collect();
+                for (len, actual) in sum(&data, &data).into_iter().enumerate() {
+                    let expected = reference_sum(&data[..len]);
+                    assert_eq!(
+                        actual.to_bits(),
+                        expected.to_bits(),
+                        "sum of {len} values with {width} lanes: {actual} != {expected}"
+                    );
+                }
+            }
+
+            #[test]
+            fn reductions_match_pairwise_scalar() {
+                check_all_engines!(check, reductions, $simd_ty);
+            }
+
+            #[test]
+            fn pairwise_sum_is_independent_of_width() {
+                check_all_engines!(check_sum, sum, $simd_ty);
+            }
+        }
+    };
+}
+
+test_float_reductions!(f32_lanes, Vf32, f32);
+test_float_reductions!(f64_lanes, Vf64, f64);
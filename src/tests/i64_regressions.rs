@@ -71,8 +71,8 @@ simd_unsafe_generate_all!(
         while lhs.len() >= S::Vi64::WIDTH {
             let a = S::Vi64::load_from_slice(lhs);
             let b = S::Vi64::load_from_slice(rhs);
-            a.cmp_gt(b).copy_to_slice(gt_out);
-            a.cmp_eq(b).copy_to_slice(eq_out);
+            a.cmp_gt(b).to_vector().copy_to_slice(gt_out);
+            a.cmp_eq(b).to_vector().copy_to_slice(eq_out);
             a.min(b).copy_to_slice(min_out);
             a.max(b).copy_to_slice(max_out);
 
@@ -181,5 +181,14 @@ fn i64_backend_compare_regression_cases() {
                 &expected,
             );
         }
+
+        if std::arch::is_x86_feature_detected!("avx") {
+            assert_compare_outputs(
+                run_compare_i64(&lhs, &rhs, |lhs, rhs, gt, eq, min, max| unsafe {
+                    compare_i64_edges_avx(lhs, rhs, gt, eq, min, max)
+                }),
+                &expected,
+            );
+        }
     }
 }
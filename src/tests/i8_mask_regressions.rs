@@ -89,6 +89,16 @@ fn i8_mask_helpers_follow_canonical_compare_masks() {
             );
         }
 
+        if std::arch::is_x86_feature_detected!("avx") {
+            assert_mask_summary(
+                unsafe { summarize_i8_mask_helpers_avx(&avx_input) },
+                0x6529_949a,
+                Some(1),
+                Some(30),
+                Some(0),
+                Some(31),
+            );
+        }
         if std::arch::is_x86_feature_detected!("avx2") && std::arch::is_x86_feature_detected!("fma")
         {
             assert_mask_summary(
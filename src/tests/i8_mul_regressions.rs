@@ -61,6 +61,11 @@ fn i8_mul_matches_wrapping_semantics_on_extreme_patterns() {
         ];
         let avx_expected = expected_mul(&avx_a, &avx_b);
 
+        if std::arch::is_x86_feature_detected!("avx") {
+            let mut out = [0; 32];
+            unsafe { mul_i8_lanes_avx(&avx_a, &avx_b, &mut out) };
+            assert_eq!(out.as_slice(), avx_expected.as_slice());
+        }
         if std::arch::is_x86_feature_detected!("avx2") && std::arch::is_x86_feature_detected!("fma")
         {
             let mut out = [0; 32];
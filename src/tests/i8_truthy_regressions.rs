@@ -2,7 +2,7 @@
 use crate::base::SimdBaseIo;
 use crate::engines::scalar::I8x1;
 #[cfg(any(target_arch = "x86_64", target_arch = "x86"))]
-use crate::engines::{avx2::I8x32, sse2::I8x16, sse41::I8x16_41};
+use crate::engines::{avx, avx2::I8x32, sse2::I8x16, sse41::I8x16_41};
 use crate::prelude::*;
 
 #[cfg(any(target_arch = "x86_64", target_arch = "x86"))]
@@ -54,6 +54,19 @@ fn i8_is_truthy_requires_all_lanes_to_be_truthy_mask() {
             assert_truthy_contract(all_nonzero, mixed_zero, all_zero);
         }
 
+        if std::arch::is_x86_feature_detected!("avx") {
+            // The only zero lane sits in the high 128 bit half, which AVX checks separately.
+            let all_nonzero = unsafe { avx::I8x32::load_from_array([-1; 32]) };
+            let mixed_zero = unsafe {
+                avx::I8x32::load_from_array([
+                    -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1,
+                    -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, 0, -1,
+                ])
+            };
+            let all_zero = unsafe { avx::I8x32::load_from_array([0; 32]) };
+            assert_truthy_contract(all_nonzero, mixed_zero, all_zero);
+        }
+
         if std::arch::is_x86_feature_detected!("avx2") && std::arch::is_x86_feature_detected!("fma")
         {
             let all_nonzero = unsafe { I8x32::load_from_array([-1; 32]) };
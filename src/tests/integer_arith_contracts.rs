@@ -0,0 +1,292 @@
+#![allow(dead_code, unused_parens)]
+
+use crate::prelude::*;
+
+use super::check_all_engines;
+
+macro_rules! test_lane_arithmetic {
+    ($name:ident, $simd_ty:ident, $scalar_ty:ty) => {
+        mod $name {
+            use super::*;
+
+            simd_unsafe_generate_all!(
+                fn arithmetic(a: &[$scalar_ty], b: &[$scalar_ty]) -> Vec<$scalar_ty> {
+                    let a = S::$simd_ty::load_from_slice(a);
+                    let b = S::$simd_ty::load_from_slice(b);
+                    let mut out = vec![0; 4 * S::$simd_ty::WIDTH];
+                    let results = [
+                        a.saturating_add(b),
+                        a.saturating_sub(b),
+                        a.avg(b),
+                        a.abs_diff(b),
+                    ];
+                    for (result, chunk) in results.iter().zip(out.chunks_mut(S::$simd_ty::WIDTH)) {
+                        result.copy_to_slice(chunk);
+                    }
+                    out
+                }
+            );
+
+            fn lanes(seed: usize, width: usize) -> Vec<$scalar_ty> {
+                let pattern: [$scalar_ty; 10] = [
+                    0,
+                    1,
+                    2,
+                    3,
+                    <$scalar_ty>::MAX,
+                    <$scalar_ty>::MAX - 1,
+                    <$scalar_ty>::MAX / 2,
+                    <$scalar_ty>::MIN,
+                    <$scalar_ty>::MIN + 1,
+                    <$scalar_ty>::MIN / 2 + 7,
+                ];
+                (0..width)
+                    .map(|i| pattern[(i * 3 + seed) % pattern.len()])
+                    .collect()
+            }
+
+            fn check(
+                width: usize,
+                arithmetic: impl Fn(&[$scalar_ty], &[$scalar_ty]) -> Vec<$scalar_ty>,
+            ) {
+                for seed in 0..10 {
+                    let a = lanes(seed, width);
+                    let b = lanes(seed * 7 + 1, width);
+                    let out = arithmetic(&a, &b);
+
+                    let pairs = || a.iter().zip(&b).map(|(&x, &y)| (x, y));
+                    let expected: Vec<$scalar_ty> = [
+                        pairs()
+                            .map(|(x, y)| x.saturating_add(y))
+                            .collect::<Vec<_>>(),
+                        pairs().map(|(x, y)| x.saturating_sub(y)).collect(),
+                        pairs()
+                            .map(|(x, y)| ((x as i128 + y as i128 + 1) >> 1) as $scalar_ty)
+                            .collect(),
+                        pairs().map(|(x, y)| x.abs_diff(y) as $scalar_ty).collect(),
+                    ]
+                    .concat();
+                    assert_eq!(out, expected, "arithmetic mismatch for {a:?} and {b:?}");
+                }
+            }
+
+            #[test]
+            fn saturating_average_and_difference_match_scalar() {
+                check_all_engines!(check, arithmetic, $simd_ty);
+            }
+        }
+    };
+}
+
+test_lane_arithmetic!(i8_lanes, Vi8, i8);
+test_lane_arithmetic!(i16_lanes, Vi16, i16);
+test_lane_arithmetic!(i32_lanes, Vi32, i32);
+test_lane_arithmetic!(i64_lanes, Vi64, i64);
+test_lane_arithmetic!(u8_lanes, Vu8, u8);
+test_lane_arithmetic!(u16_lanes, Vu16, u16);
+test_lane_arithmetic!(u32_lanes, Vu32, u32);
+test_lane_arithmetic!(u64_lanes, Vu64, u64);
+
+macro_rules! test_mul_hi {
+    ($name:ident, $simd_ty:ident, $scalar_ty:ty, $wide_ty:ty) => {
+        mod $name {
+            use super::*;
+
+            simd_unsafe_generate_all!(
+                fn mul_hi(a: &[$scalar_ty], b: &[$scalar_ty]) -> Vec<$scalar_ty> {
+                    let mut out = vec![0; S::$simd_ty::WIDTH];
+                    S::$simd_ty::load_from_slice(a)
+                        .mul_hi(S::$simd_ty::load_from_slice(b))
+                        .copy_to_slice(&mut out);
+                    out
+                }
+            );
+
+            fn check(
+                width: usize,
+                mul_hi: impl Fn(&[$scalar_ty], &[$scalar_ty]) -> Vec<$scalar_ty>,
+            ) {
+                let pattern = [
+                    0,
+                    1,
+                    <$scalar_ty>::MAX,
+                    <$scalar_ty>::MIN,
+                    <$scalar_ty>::MAX / 3,
+                    <$scalar_ty>::MIN / 3 + 5,
+                    123,
+                ];
+                for seed in 0..pattern.len() {
+                    let a: Vec<$scalar_ty> = (0..width)
+                        .map(|i| pattern[(i + seed) % pattern.len()])
+                        .collect();
+                    let b: Vec<$scalar_ty> = (0..width)
+                        .map(|i| pattern[(i * 2 + seed + 3) % pattern.len()])
+                        .collect();
+                    let expected: Vec<$scalar_ty> = a
+                        .iter()
+                        .zip(&b)
+                        .map(|(&x, &y)| {
+                            ((x as $wide_ty * y as $wide_ty) >> <$scalar_ty>::BITS) as $scalar_ty
+                        })
+                        .collect();
+                    assert_eq!(
+                        mul_hi(&a, &b),
+                        expected,
+                        "mul_hi mismatch for {a:?} and {b:?}"
+                    );
+                }
+            }
+
+            #[test]
+            fn mul_hi_matches_scalar() {
+                check_all_engines!(check, mul_hi, $simd_ty);
+            }
+        }
+    };
+}
+
+test_mul_hi!(i16_mul_hi, Vi16, i16, i32);
+test_mul_hi!(i32_mul_hi, Vi32, i32, i64);
+test_mul_hi!(u16_mul_hi, Vu16, u16, u32);
+test_mul_hi!(u32_mul_hi, Vu32, u32, u64);
+
+simd_unsafe_generate_all!(
+    fn mul_widening_i16(a: &[i16], b: &[i16]) -> Vec<i32> {
+        let (low, high) = S::Vi16::load_from_slice(a).mul_widening(S::Vi16::load_from_slice(b));
+        let mut out = vec![0; 2 * S::Vi32::WIDTH];
+        low.copy_to_slice(&mut out[..S::Vi32::WIDTH]);
+        high.copy_to_slice(&mut out[S::Vi32::WIDTH..]);
+        out
+    }
+);
+
+simd_unsafe_generate_all!(
+    fn mul_widening_i32(a: &[i32], b: &[i32]) -> Vec<i64> {
+        let (low, high) = S::Vi32::load_from_slice(a).mul_widening(S::Vi32::load_from_slice(b));
+        let mut out = vec![0; 2 * S::Vi64::WIDTH];
+        low.copy_to_slice(&mut out[..S::Vi64::WIDTH]);
+        high.copy_to_slice(&mut out[S::Vi64::WIDTH..]);
+        out
+    }
+);
+
+fn check_mul_widening_i16(width: usize, f: impl Fn(&[i16], &[i16]) -> Vec<i32>) {
+    let a: Vec<i16> = (0..width)
+        .map(|i| i16::MIN.wrapping_add((i as i16).wrapping_mul(977)))
+        .collect();
+    let b: Vec<i16> = (0..width)
+        .map(|i| i16::MAX.wrapping_sub((i as i16).wrapping_mul(1511)))
+        .collect();
+    let out = f(&a, &b);
+    for (i, (&x, &y)) in a.iter().zip(&b).enumerate() {
+        assert_eq!(out[i], x as i32 * y as i32, "lane {i} of {a:?} * {b:?}");
+    }
+}
+
+fn check_mul_widening_i32(width: usize, f: impl Fn(&[i32], &[i32]) -> Vec<i64>) {
+    let a: Vec<i32> = (0..width)
+        .map(|i| i32::MIN.wrapping_add((i as i32).wrapping_mul(0x0123_4567)))
+        .collect();
+    let b: Vec<i32> = (0..width)
+        .map(|i| i32::MAX.wrapping_sub((i as i32).wrapping_mul(0x0765_4321)))
+        .collect();
+    let out = f(&a, &b);
+    for (i, (&x, &y)) in a.iter().zip(&b).enumerate() {
+        assert_eq!(out[i], x as i64 * y as i64, "lane {i} of {a:?} * {b:?}");
+    }
+}
+
+// The halves must come back in lane order, which catches the per-128-bit unpacks on AVX2/AVX-512.
+#[test]
+fn mul_widening_keeps_lane_order() {
+    check_all_engines!(check_mul_widening_i16, mul_widening_i16, Vi16);
+    check_all_engines!(check_mul_widening_i32, mul_widening_i32, Vi32);
+}
+
+simd_unsafe_generate_all!(
+    fn madd_i16(a: &[i16], b: &[i16]) -> Vec<i32> {
+        let mut out = vec![0; S::Vi32::WIDTH];
+        S::Vi16::load_from_slice(a)
+            .mul_add_pairs(S::Vi16::load_from_slice(b))
+            .copy_to_slice(&mut out);
+        out
+    }
+);
+
+simd_unsafe_generate_all!(
+    fn madd_u8_i8(a: &[u8], b: &[i8]) -> Vec<i16> {
+        let mut out = vec![0; S::Vi16::WIDTH];
+        S::Vu8::load_from_slice(a)
+            .mul_add_pairs(S::Vi8::load_from_slice(b))
+            .copy_to_slice(&mut out);
+        out
+    }
+);
+
+simd_unsafe_generate_all!(
+    fn sad_u8(a: &[u8], b: &[u8]) -> Vec<u64> {
+        let mut out = vec![0; S::Vu64::WIDTH];
+        S::Vu8::load_from_slice(a)
+            .sad(S::Vu8::load_from_slice(b))
+            .copy_to_slice(&mut out);
+        out
+    }
+);
+
+/// Lane `i` of a pairwise reduction covers input lanes `group * i .. group * (i + 1)`, clipped to
+/// the input width (the scalar engine has a single input lane).
+fn group_of(i: usize, group: usize, width: usize) -> core::ops::Range<usize> {
+    (group * i).min(width)..(group * (i + 1)).min(width)
+}
+
+fn check_madd_i16(width: usize, f: impl Fn(&[i16], &[i16]) -> Vec<i32>) {
+    // i16::MIN * i16::MIN twice is the one pair that wraps.
+    let pattern = [i16::MIN, i16::MIN, i16::MAX, -3, 1000, i16::MIN, 7, -1];
+    let a: Vec<i16> = (0..width).map(|i| pattern[i % pattern.len()]).collect();
+    let b: Vec<i16> = (0..width)
+        .map(|i| pattern[(i + 5) % pattern.len()])
+        .collect();
+    let out = f(&a, &b);
+    for (i, &lane) in out.iter().enumerate() {
+        let expected = group_of(i, 2, width)
+            .map(|k| a[k] as i32 * b[k] as i32)
+            .fold(0i32, i32::wrapping_add);
+        assert_eq!(lane, expected, "pair {i} of {a:?} and {b:?}");
+    }
+}
+
+fn check_madd_u8_i8(width: usize, f: impl Fn(&[u8], &[i8]) -> Vec<i16>) {
+    let unsigned = [255, 255, 0, 17, 128, 255, 1, 200];
+    let signed = [127, 127, -128, -128, 5, -128, -1, 64];
+    let a: Vec<u8> = (0..width).map(|i| unsigned[i % unsigned.len()]).collect();
+    let b: Vec<i8> = (0..width)
+        .map(|i| signed[(i + i / 8) % signed.len()])
+        .collect();
+    let out = f(&a, &b);
+    for (i, &lane) in out.iter().enumerate() {
+        let expected = group_of(i, 2, width)
+            .map(|k| a[k] as i32 * b[k] as i32)
+            .sum::<i32>()
+            .clamp(i16::MIN as i32, i16::MAX as i32) as i16;
+        assert_eq!(lane, expected, "pair {i} of {a:?} and {b:?}");
+    }
+}
+
+fn check_sad(width: usize, f: impl Fn(&[u8], &[u8]) -> Vec<u64>) {
+    let a: Vec<u8> = (0..width).map(|i| (i * 37 + 11) as u8).collect();
+    let b: Vec<u8> = (0..width).map(|i| (i * 101 + 250) as u8).collect();
+    let out = f(&a, &b);
+    for (i, &lane) in out.iter().enumerate() {
+        let expected: u64 = group_of(i, 8, width)
+            .map(|k| a[k].abs_diff(b[k]) as u64)
+            .sum();
+        assert_eq!(lane, expected, "group {i} of {a:?} and {b:?}");
+    }
+}
+
+#[test]
+fn pairwise_multiply_add_and_sad_match_scalar() {
+    check_all_engines!(check_madd_i16, madd_i16, Vi16);
+    check_all_engines!(check_madd_u8_i8, madd_u8_i8, Vu8);
+    check_all_engines!(check_sad, sad_u8, Vu8);
+}
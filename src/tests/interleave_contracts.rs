@@ -0,0 +1,116 @@
+#![allow(dead_code, unused_parens)]
+
+use crate::prelude::*;
+
+use super::check_all_engines;
+
+macro_rules! test_interleave {
+    ($name:ident, $simd_ty:ident, $scalar_ty:ty) => {
+        mod $name {
+            use super::*;
+
+            simd_unsafe_generate_all!(
+                fn zips(a: &[$scalar_ty], b: &[$scalar_ty]) -> Vec<$scalar_ty> {
+                    let a = S::$simd_ty::load_from_slice(a);
+                    let b = S::$simd_ty::load_from_slice(b);
+                    let results = [a.zip_lo(b), a.zip_hi(b), a.unzip_even(b), a.unzip_odd(b)];
+                    let mut out = vec![Default::default(); 4 * S::$simd_ty::WIDTH];
+                    for (result, chunk) in results.iter().zip(out.chunks_mut(S::$simd_ty::WIDTH)) {
+                        result.copy_to_slice(chunk);
+                    }
+                    out
+                }
+            );
+
+            simd_unsafe_generate_all!(
+                fn structures(data: &[$scalar_ty], _b: &[$scalar_ty]) -> Vec<$scalar_ty> {
+                    let width = S::$simd_ty::WIDTH;
+                    let mut out = vec![Default::default(); 18 * width];
+                    let (loads, stores) = out.split_at_mut(9 * width);
+
+                    let pair = S::$simd_ty::load_interleaved2(data);
+                    let triple = S::$simd_ty::load_interleaved3(data);
+                    let quad = S::$simd_ty::load_interleaved4(data);
+                    let channels = [
+                        pair.0, pair.1, triple.0, triple.1, triple.2, quad.0, quad.1, quad.2,
+                        quad.3,
+                    ];
+                    for (channel, chunk) in channels.iter().zip(loads.chunks_mut(width)) {
+                        channel.copy_to_slice(chunk);
+                    }
+
+                    let (pairs, rest) = stores.split_at_mut(2 * width);
+                    let (triples, quads) = rest.split_at_mut(3 * width);
+                    S::$simd_ty::store_interleaved2(pair, pairs);
+                    S::$simd_ty::store_interleaved3(triple, triples);
+                    S::$simd_ty::store_interleaved4(quad, quads);
+                    out
+                }
+            );
+
+            fn lanes(seed: usize, count: usize) -> Vec<$scalar_ty> {
+                (0..count).map(|i| (i * 3 + seed) as $scalar_ty).collect()
+            }
+
+            fn check_zips(
+                width: usize,
+                zips: impl Fn(&[$scalar_ty], &[$scalar_ty]) -> Vec<$scalar_ty>,
+            ) {
+                let a = lanes(1, width);
+                let b = lanes(100, width);
+                let interleaved: Vec<$scalar_ty> =
+                    a.iter().zip(&b).flat_map(|(&x, &y)| [x, y]).collect();
+                let concatenated = [a.clone(), b.clone()].concat();
+                let expected = [
+                    interleaved.clone(),
+                    concatenated.iter().copied().step_by(2).collect(),
+                    concatenated.iter().copied().skip(1).step_by(2).collect(),
+                ]
+                .concat();
+                assert_eq!(zips(&a, &b), expected, "zips of {a:?} and {b:?}");
+            }
+
+            fn check_structures(
+                width: usize,
+                structures: impl Fn(&[$scalar_ty], &[$scalar_ty]) -> Vec<$scalar_ty>,
+            ) {
+                let data = lanes(5, 4 * width);
+                let channels = |count: usize| {
+                    (0..count).flat_map(move |c| (0..width).map(move |i| count * i + c))
+                };
+                let loads: Vec<$scalar_ty> = channels(2)
+                    .chain(channels(3))
+                    .chain(channels(4))
+                    .map(|index| data[index])
+                    .collect();
+                let stores = [&data[..2 * width], &data[..3 * width], &data[..4 * width]].concat();
+                assert_eq!(
+                    structures(&data, &data),
+                    [loads, stores].concat(),
+                    "structure loads and stores of {data:?}"
+                );
+            }
+
+            #[test]
+            fn zip_and_unzip_match_scalar() {
+                check_all_engines!(check_zips, zips, $simd_ty);
+            }
+
+            #[test]
+            fn interleaved_loads_split_channels_and_stores_undo_them() {
+                check_all_engines!(check_structures, structures, $simd_ty);
+            }
+        }
+    };
+}
+
+test_interleave!(i8_lanes, Vi8, i8);
+test_interleave!(i16_lanes, Vi16, i16);
+test_interleave!(i32_lanes, Vi32, i32);
+test_interleave!(i64_lanes, Vi64, i64);
+test_interleave!(u8_lanes, Vu8, u8);
+test_interleave!(u16_lanes, Vu16, u16);
+test_interleave!(u32_lanes, Vu32, u32);
+test_interleave!(u64_lanes, Vu64, u64);
+test_interleave!(f32_lanes, Vf32, f32);
+test_interleave!(f64_lanes, Vf64, f64);
@@ -0,0 +1,178 @@
+#![allow(dead_code, unused_parens)]
+
+use crate::prelude::*;
+
+const PATTERNS: usize = 9;
+
+fn shuffle_reference<T: Copy, const N: usize>(a: &[T], indices: [usize; N]) -> Vec<T> {
+    let group = N.min(a.len());
+    (0..a.len())
+        .map(|i| a[i - i % group + indices[i % group] % group])
+        .collect()
+}
+
+fn shuffle2_reference<T: Copy, const N: usize>(a: &[T], b: &[T], indices: [usize; N]) -> Vec<T> {
+    let group = N.min(a.len());
+    (0..a.len())
+        .map(|i| {
+            let index = indices[i % group] % (2 * N);
+            let source = if index < N { a } else { b };
+            source[i - i % group + index % N % group]
+        })
+        .collect()
+}
+
+fn lane_reference<T: Copy>(a: &[T], index: impl Fn(usize) -> usize) -> Vec<T> {
+    (0..a.len()).map(|i| a[index(i) % a.len()]).collect()
+}
+
+macro_rules! test_lane_shuffles {
+    ($name:ident, $simd_ty:ident, $scalar_ty:ty, $idx_simd_ty:ident, $idx_ty:ty) => {
+        mod $name {
+            use super::*;
+
+            simd_unsafe_generate_all!(
+                fn shuffles(
+                    a: &[$scalar_ty],
+                    b: &[$scalar_ty],
+                    idx: &[$idx_ty],
+                ) -> Vec<$scalar_ty> {
+                    assert_eq!(a.len(), S::$simd_ty::WIDTH);
+
+                    let a = S::$simd_ty::load_from_slice(a);
+                    let b = S::$simd_ty::load_from_slice(b);
+                    let idx = S::$idx_simd_ty::load_from_slice(idx);
+                    let results = [
+                        a.shuffle([3, 2, 1, 0]),
+                        a.shuffle([0, 0, 1, 1]),
+                        a.shuffle2(b, [0, 5, 2, 7]),
+                        a.reverse_lanes(),
+                        a.rotate_lanes_left::<1>(),
+                        a.rotate_lanes_left::<3>(),
+                        a.rotate_lanes_right::<1>(),
+                        a.broadcast_lane::<1>(),
+                        a.permute(idx),
+                    ];
+
+                    let mut out = vec![0 as $scalar_ty; PATTERNS * S::$simd_ty::WIDTH];
+                    for (result, chunk) in results.iter().zip(out.chunks_mut(S::$simd_ty::WIDTH)) {
+                        result.copy_to_slice(chunk);
+                    }
+                    out
+                }
+            );
+
+            fn check(
+                width: usize,
+                shuffles: impl Fn(&[$scalar_ty], &[$scalar_ty], &[$idx_ty]) -> Vec<$scalar_ty>,
+            ) {
+                let a: Vec<$scalar_ty> = (0..width).map(|i| i as $scalar_ty).collect();
+                let b: Vec<$scalar_ty> = (0..width).map(|i| (i + 64) as $scalar_ty).collect();
+                // Mix in-range, out-of-range and negative indices; all of them wrap modulo WIDTH.
+                let idx: Vec<$idx_ty> = (0..width)
+                    .map(|i| match i % 4 {
+                        0 => (i * 5 + 3) as $idx_ty,
+                        1 => (i + width * 3) as $idx_ty,
+                        2 => (-(i as i64) - 1) as $idx_ty,
+                        _ => (width - 1 - i) as $idx_ty,
+                    })
+                    .collect();
+
+                let out = shuffles(&a, &b, &idx);
+                let expected = [
+                    shuffle_reference(&a, [3, 2, 1, 0]),
+                    shuffle_reference(&a, [0, 0, 1, 1]),
+                    shuffle2_reference(&a, &b, [0, 5, 2, 7]),
+                    lane_reference(&a, |i| width - 1 - i),
+                    lane_reference(&a, |i| i + 1),
+                    lane_reference(&a, |i| i + 3),
+                    lane_reference(&a, |i| i + width - 1),
+                    lane_reference(&a, |_| 1),
+                    lane_reference(&a, |i| idx[i] as i64 as u64 as usize),
+                ];
+
+                let names = [
+                    "shuffle [3, 2, 1, 0]",
+                    "shuffle [0, 0, 1, 1]",
+                    "shuffle2 [0, 5, 2, 7]",
+                    "reverse_lanes",
+                    "rotate_lanes_left::<1>",
+                    "rotate_lanes_left::<3>",
+                    "rotate_lanes_right::<1>",
+                    "broadcast_lane::<1>",
+                    "permute",
+                ];
+                for ((name, expected), actual) in names.iter().zip(&expected).zip(out.chunks(width))
+                {
+                    assert_eq!(actual, &expected[..], "{name} mismatch for width {width}");
+                }
+            }
+
+            #[test]
+            fn lane_shuffles_match_reference() {
+                #[cfg(any(target_arch = "x86_64", target_arch = "x86"))]
+                {
+                    use crate::engines::{
+                        avx::Avx, avx2::Avx2, avx512::Avx512, scalar::Scalar, sse2::Sse2,
+                        sse41::Sse41,
+                    };
+
+                    check(<Scalar as Simd>::$simd_ty::WIDTH, shuffles_scalar);
+                    if std::arch::is_x86_feature_detected!("sse2") {
+                        check(<Sse2 as Simd>::$simd_ty::WIDTH, |a, b, idx| unsafe {
+                            shuffles_sse2(a, b, idx)
+                        });
+                    }
+                    if std::arch::is_x86_feature_detected!("sse4.1") {
+                        check(<Sse41 as Simd>::$simd_ty::WIDTH, |a, b, idx| unsafe {
+                            shuffles_sse41(a, b, idx)
+                        });
+                    }
+                    if std::arch::is_x86_feature_detected!("avx") {
+                        check(<Avx as Simd>::$simd_ty::WIDTH, |a, b, idx| unsafe {
+                            shuffles_avx(a, b, idx)
+                        });
+                    }
+                    if std::arch::is_x86_feature_detected!("avx2")
+                        && std::arch::is_x86_feature_detected!("fma")
+                    {
+                        check(<Avx2 as Simd>::$simd_ty::WIDTH, |a, b, idx| unsafe {
+                            shuffles_avx2(a, b, idx)
+                        });
+                    }
+                    if std::arch::is_x86_feature_detected!("avx512f")
+                        && std::arch::is_x86_feature_detected!("avx512bw")
+                        && std::arch::is_x86_feature_detected!("avx512dq")
+                    {
+                        check(<Avx512 as Simd>::$simd_ty::WIDTH, |a, b, idx| unsafe {
+                            shuffles_avx512(a, b, idx)
+                        });
+                    }
+                }
+
+                #[cfg(target_arch = "aarch64")]
+                check(
+                    <crate::engines::neon::Neon as Simd>::$simd_ty::WIDTH,
+                    |a, b, idx| unsafe { shuffles_neon(a, b, idx) },
+                );
+
+                #[cfg(target_arch = "wasm32")]
+                check(
+                    <crate::engines::wasm32::Wasm as Simd>::$simd_ty::WIDTH,
+                    |a, b, idx| unsafe { shuffles_wasm(a, b, idx) },
+                );
+            }
+        }
+    };
+}
+
+test_lane_shuffles!(i8_lanes, Vi8, i8, Vi8, i8);
+test_lane_shuffles!(i16_lanes, Vi16, i16, Vi16, i16);
+test_lane_shuffles!(i32_lanes, Vi32, i32, Vi32, i32);
+test_lane_shuffles!(i64_lanes, Vi64, i64, Vi64, i64);
+test_lane_shuffles!(u8_lanes, Vu8, u8, Vu8, u8);
+test_lane_shuffles!(u16_lanes, Vu16, u16, Vu16, u16);
+test_lane_shuffles!(u32_lanes, Vu32, u32, Vu32, u32);
+test_lane_shuffles!(u64_lanes, Vu64, u64, Vu64, u64);
+test_lane_shuffles!(f32_lanes, Vf32, f32, Vi32, i32);
+test_lane_shuffles!(f64_lanes, Vf64, f64, Vi64, i64);
@@ -53,6 +53,18 @@ const IMPORTANT_I8: [i8; 7] = [0, 1, -1, 2, -2, i8::MAX, i8::MIN];
 const IMPORTANT_I16: [i16; 7] = [0, 1, -1, 2, -2, i16::MAX, i16::MIN];
 const IMPORTANT_I32: [i32; 7] = [0, 1, -1, 2, -2, i32::MAX, i32::MIN];
 const IMPORTANT_I64: [i64; 7] = [0, 1, -1, 2, -2, i64::MAX, i64::MIN];
+const IMPORTANT_U8: [u8; 7] = [0, 1, 2, 0x7f, 0x80, u8::MAX - 1, u8::MAX];
+const IMPORTANT_U16: [u16; 7] = [0, 1, 2, 0x7fff, 0x8000, u16::MAX - 1, u16::MAX];
+const IMPORTANT_U32: [u32; 7] = [0, 1, 2, 0x7fff_ffff, 0x8000_0000, u32::MAX - 1, u32::MAX];
+const IMPORTANT_U64: [u64; 7] = [
+    0,
+    1,
+    2,
+    0x7fff_ffff_ffff_ffff,
+    0x8000_0000_0000_0000,
+    u64::MAX - 1,
+    u64::MAX,
+];
 
 fn iter_arbitrary_f32(interval: usize) -> impl Iterator<Item = f32> {
     assert!(interval > IMPORTANT_F32.len());
@@ -126,6 +138,22 @@ fn iter_arbitrary_i64(interval: usize) -> impl Iterator<Item = i64> {
     iter_arbitrary_ints(interval, &IMPORTANT_I64, i64::MIN..=i64::MAX)
 }
 
+fn iter_arbitrary_u8(interval: usize) -> impl Iterator<Item = u8> {
+    iter_arbitrary_ints(interval, &IMPORTANT_U8, u8::MIN..=u8::MAX)
+}
+
+fn iter_arbitrary_u16(interval: usize) -> impl Iterator<Item = u16> {
+    iter_arbitrary_ints(interval, &IMPORTANT_U16, u16::MIN..=u16::MAX)
+}
+
+fn iter_arbitrary_u32(interval: usize) -> impl Iterator<Item = u32> {
+    iter_arbitrary_ints(interval, &IMPORTANT_U32, u32::MIN..=u32::MAX)
+}
+
+fn iter_arbitrary_u64(interval: usize) -> impl Iterator<Item = u64> {
+    iter_arbitrary_ints(interval, &IMPORTANT_U64, u64::MIN..=u64::MAX)
+}
+
 fn iter_arbitrary_blendv_i8() -> impl Iterator<Item = i8> {
     [-1, 0].iter().cycle().copied()
 }
@@ -142,6 +170,22 @@ fn iter_arbitrary_blendv_i64() -> impl Iterator<Item = i64> {
     [-1, 0].iter().cycle().copied()
 }
 
+fn iter_arbitrary_blendv_u8() -> impl Iterator<Item = u8> {
+    [u8::MAX, 0].iter().cycle().copied()
+}
+
+fn iter_arbitrary_blendv_u16() -> impl Iterator<Item = u16> {
+    [u16::MAX, 0].iter().cycle().copied()
+}
+
+fn iter_arbitrary_blendv_u32() -> impl Iterator<Item = u32> {
+    [u32::MAX, 0].iter().cycle().copied()
+}
+
+fn iter_arbitrary_blendv_u64() -> impl Iterator<Item = u64> {
+    [u64::MAX, 0].iter().cycle().copied()
+}
+
 fn iter_arbitrary_blendv_f32() -> impl Iterator<Item = f32> {
     iter::once(f32::from_bits(u32::MAX))
         .chain(iter::once(f32::from_bits(0)))
@@ -242,6 +286,41 @@ impl RandSimd {
             scalar_size: 8,
         }
     }
+    pub fn u8() -> IterRandSimdForScalar<u8, impl Iterator<Item = u8>, impl Iterator<Item = u8>> {
+        IterRandSimdForScalar {
+            any: Box::new(iter_arbitrary_u8),
+            blendv: Box::new(iter_arbitrary_blendv_u8),
+            important_len: IMPORTANT_U8.len(),
+            scalar_size: 1,
+        }
+    }
+    pub fn u16() -> IterRandSimdForScalar<u16, impl Iterator<Item = u16>, impl Iterator<Item = u16>>
+    {
+        IterRandSimdForScalar {
+            any: Box::new(iter_arbitrary_u16),
+            blendv: Box::new(iter_arbitrary_blendv_u16),
+            important_len: IMPORTANT_U16.len(),
+            scalar_size: 2,
+        }
+    }
+    pub fn u32() -> IterRandSimdForScalar<u32, impl Iterator<Item = u32>, impl Iterator<Item = u32>>
+    {
+        IterRandSimdForScalar {
+            any: Box::new(iter_arbitrary_u32),
+            blendv: Box::new(iter_arbitrary_blendv_u32),
+            important_len: IMPORTANT_U32.len(),
+            scalar_size: 4,
+        }
+    }
+    pub fn u64() -> IterRandSimdForScalar<u64, impl Iterator<Item = u64>, impl Iterator<Item = u64>>
+    {
+        IterRandSimdForScalar {
+            any: Box::new(iter_arbitrary_u64),
+            blendv: Box::new(iter_arbitrary_blendv_u64),
+            important_len: IMPORTANT_U64.len(),
+            scalar_size: 8,
+        }
+    }
 }
 
 impl<N: ScalarNumber, I: Iterator<Item = N>, I2: Iterator<Item = N>>
@@ -416,4 +416,16 @@ macro_rules! test_constify_imm8_for_bitshift {
             _ => $expand!(7),
         }
     };
+    (u64, $imm8:expr, $expand:ident) => {
+        test_constify_imm8_for_bitshift!(i64, $imm8, $expand)
+    };
+    (u32, $imm8:expr, $expand:ident) => {
+        test_constify_imm8_for_bitshift!(i32, $imm8, $expand)
+    };
+    (u16, $imm8:expr, $expand:ident) => {
+        test_constify_imm8_for_bitshift!(i16, $imm8, $expand)
+    };
+    (u8, $imm8:expr, $expand:ident) => {
+        test_constify_imm8_for_bitshift!(i8, $imm8, $expand)
+    };
 }
@@ -15,3 +15,64 @@ mod ulp;
 pub use ulp::*;
 
 mod constify;
+
+/// Calls `$check(width, f)` for every engine available at runtime, where `f` is the
+/// `$function_<engine>` version generated by `simd_unsafe_generate_all!` and `width` is the
+/// width of `$simd_ty` on that engine.
+macro_rules! check_all_engines {
+    ($check:ident, $function:ident, $simd_ty:ident) => {
+        paste::paste! {
+            #[cfg(any(target_arch = "x86_64", target_arch = "x86"))]
+            {
+                use crate::engines::{
+                    avx::Avx, avx2::Avx2, avx512::Avx512, scalar::Scalar, sse2::Sse2, sse41::Sse41,
+                };
+
+                $check(<Scalar as Simd>::$simd_ty::WIDTH, [<$function _scalar>]);
+                if std::arch::is_x86_feature_detected!("sse2") {
+                    $check(<Sse2 as Simd>::$simd_ty::WIDTH, |a, b| unsafe {
+                        [<$function _sse2>](a, b)
+                    });
+                }
+                if std::arch::is_x86_feature_detected!("sse4.1") {
+                    $check(<Sse41 as Simd>::$simd_ty::WIDTH, |a, b| unsafe {
+                        [<$function _sse41>](a, b)
+                    });
+                }
+                if std::arch::is_x86_feature_detected!("avx") {
+                    $check(<Avx as Simd>::$simd_ty::WIDTH, |a, b| unsafe {
+                        [<$function _avx>](a, b)
+                    });
+                }
+                if std::arch::is_x86_feature_detected!("avx2")
+                    && std::arch::is_x86_feature_detected!("fma")
+                {
+                    $check(<Avx2 as Simd>::$simd_ty::WIDTH, |a, b| unsafe {
+                        [<$function _avx2>](a, b)
+                    });
+                }
+                if std::arch::is_x86_feature_detected!("avx512f")
+                    && std::arch::is_x86_feature_detected!("avx512bw")
+                    && std::arch::is_x86_feature_detected!("avx512dq")
+                {
+                    $check(<Avx512 as Simd>::$simd_ty::WIDTH, |a, b| unsafe {
+                        [<$function _avx512>](a, b)
+                    });
+                }
+            }
+
+            #[cfg(target_arch = "aarch64")]
+            $check(
+                <crate::engines::neon::Neon as Simd>::$simd_ty::WIDTH,
+                |a, b| unsafe { [<$function _neon>](a, b) },
+            );
+
+            #[cfg(target_arch = "wasm32")]
+            $check(
+                <crate::engines::wasm32::Wasm as Simd>::$simd_ty::WIDTH,
+                |a, b| unsafe { [<$function _wasm>](a, b) },
+            );
+        }
+    };
+}
+pub(crate) use check_all_engines;
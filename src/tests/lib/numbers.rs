@@ -91,6 +91,30 @@ impl IntScalarNumber for i64 {
     }
 }
 
+impl ScalarNumber for u8 {
+    fn unchecked_add(self, other: Self) -> Self {
+        self.wrapping_add(other)
+    }
+}
+
+impl ScalarNumber for u16 {
+    fn unchecked_add(self, other: Self) -> Self {
+        self.wrapping_add(other)
+    }
+}
+
+impl ScalarNumber for u32 {
+    fn unchecked_add(self, other: Self) -> Self {
+        self.wrapping_add(other)
+    }
+}
+
+impl ScalarNumber for u64 {
+    fn unchecked_add(self, other: Self) -> Self {
+        self.wrapping_add(other)
+    }
+}
+
 impl ScalarNumber for f32 {
     fn almost_eq(self, other: Self, precision: EqPrecision) -> bool {
         if self.is_nan() && other.is_nan() {
@@ -194,6 +194,10 @@ macro_rules! with_feature_flag {
         #[cfg(all(target_feature = "avx2", target_feature = "fma"))]
         $($r)+
     };
+    (Avx, $($r:tt)+) => {
+        #[cfg(target_feature = "avx")]
+        $($r)+
+    };
     (Sse2, $($r:tt)+) => {
         #[cfg(target_feature = "sse2")]
         $($r)+
@@ -240,6 +244,7 @@ macro_rules! elementwise_eq_tester_impl {
         elementwise_eq_tester_impl!(@full Scalar, $simd_ty, $simd_base, $simd_fn, $arg_cnt, $precision);
         elementwise_eq_tester_impl!(@full Avx512, $simd_ty, $simd_base, $simd_fn, $arg_cnt, $precision);
         elementwise_eq_tester_impl!(@full Avx2, $simd_ty, $simd_base, $simd_fn, $arg_cnt, $precision);
+        elementwise_eq_tester_impl!(@full Avx, $simd_ty, $simd_base, $simd_fn, $arg_cnt, $precision);
         elementwise_eq_tester_impl!(@full Sse2, $simd_ty, $simd_base, $simd_fn, $arg_cnt, $precision);
         elementwise_eq_tester_impl!(@full Sse41, $simd_ty, $simd_base, $simd_fn, $arg_cnt, $precision);
         elementwise_eq_tester_impl!(@full Neon, $simd_ty, $simd_base, $simd_fn, $arg_cnt, $precision);
@@ -251,15 +256,36 @@ macro_rules! elementwise_eq_tester_impl {
         elementwise_eq_tester_impl!(@simdkind i16, SimdBaseOps, $simd_fn, $arg_cnt, $precision);
         elementwise_eq_tester_impl!(@simdkind i32, SimdBaseOps, $simd_fn, $arg_cnt, $precision);
         elementwise_eq_tester_impl!(@simdkind i64, SimdBaseOps, $simd_fn, $arg_cnt, $precision);
+        elementwise_eq_tester_impl!(@simdkind u8, SimdBaseOps, $simd_fn, $arg_cnt, $precision);
+        elementwise_eq_tester_impl!(@simdkind u16, SimdBaseOps, $simd_fn, $arg_cnt, $precision);
+        elementwise_eq_tester_impl!(@simdkind u32, SimdBaseOps, $simd_fn, $arg_cnt, $precision);
+        elementwise_eq_tester_impl!(@simdkind u64, SimdBaseOps, $simd_fn, $arg_cnt, $precision);
         elementwise_eq_tester_impl!(@simdkind f32, SimdBaseOps, $simd_fn, $arg_cnt, $precision);
         elementwise_eq_tester_impl!(@simdkind f64, SimdBaseOps, $simd_fn, $arg_cnt, $precision);
     };
 
+    (CompareLanes, $simd_fn:ident, $arg_cnt:ident, $precision:expr) => {
+        elementwise_eq_tester_impl!(@simdkind i8, CompareLanes, $simd_fn, $arg_cnt, $precision);
+        elementwise_eq_tester_impl!(@simdkind i16, CompareLanes, $simd_fn, $arg_cnt, $precision);
+        elementwise_eq_tester_impl!(@simdkind i32, CompareLanes, $simd_fn, $arg_cnt, $precision);
+        elementwise_eq_tester_impl!(@simdkind i64, CompareLanes, $simd_fn, $arg_cnt, $precision);
+        elementwise_eq_tester_impl!(@simdkind u8, CompareLanes, $simd_fn, $arg_cnt, $precision);
+        elementwise_eq_tester_impl!(@simdkind u16, CompareLanes, $simd_fn, $arg_cnt, $precision);
+        elementwise_eq_tester_impl!(@simdkind u32, CompareLanes, $simd_fn, $arg_cnt, $precision);
+        elementwise_eq_tester_impl!(@simdkind u64, CompareLanes, $simd_fn, $arg_cnt, $precision);
+        elementwise_eq_tester_impl!(@simdkind f32, CompareLanes, $simd_fn, $arg_cnt, $precision);
+        elementwise_eq_tester_impl!(@simdkind f64, CompareLanes, $simd_fn, $arg_cnt, $precision);
+    };
+
     (SimdInt, $simd_fn:ident, $arg_cnt:ident, $precision:expr) => {
         elementwise_eq_tester_impl!(@simdkind i8, SimdInt, $simd_fn, $arg_cnt, $precision);
         elementwise_eq_tester_impl!(@simdkind i16, SimdInt, $simd_fn, $arg_cnt, $precision);
         elementwise_eq_tester_impl!(@simdkind i32, SimdInt, $simd_fn, $arg_cnt, $precision);
         elementwise_eq_tester_impl!(@simdkind i64, SimdInt, $simd_fn, $arg_cnt, $precision);
+        elementwise_eq_tester_impl!(@simdkind u8, SimdInt, $simd_fn, $arg_cnt, $precision);
+        elementwise_eq_tester_impl!(@simdkind u16, SimdInt, $simd_fn, $arg_cnt, $precision);
+        elementwise_eq_tester_impl!(@simdkind u32, SimdInt, $simd_fn, $arg_cnt, $precision);
+        elementwise_eq_tester_impl!(@simdkind u64, SimdInt, $simd_fn, $arg_cnt, $precision);
     };
 
     (SimdFloat, $simd_fn:ident, $arg_cnt:ident, $precision:expr) => {
@@ -319,6 +345,7 @@ macro_rules! bitshift_eq_tester_impl {
         bitshift_eq_tester_impl!(@full $is_const, Scalar, $simd_ty, $simd_fn);
         bitshift_eq_tester_impl!(@full $is_const, Avx512, $simd_ty, $simd_fn);
         bitshift_eq_tester_impl!(@full $is_const, Avx2, $simd_ty, $simd_fn);
+        bitshift_eq_tester_impl!(@full $is_const, Avx, $simd_ty, $simd_fn);
         bitshift_eq_tester_impl!(@full $is_const, Sse2, $simd_ty, $simd_fn);
         bitshift_eq_tester_impl!(@full $is_const, Sse41, $simd_ty, $simd_fn);
         bitshift_eq_tester_impl!(@full $is_const, Neon, $simd_ty, $simd_fn);
@@ -330,6 +357,10 @@ macro_rules! bitshift_eq_tester_impl {
         bitshift_eq_tester_impl!(@simdkind $is_const, i16, $simd_fn);
         bitshift_eq_tester_impl!(@simdkind $is_const, i32, $simd_fn);
         bitshift_eq_tester_impl!(@simdkind $is_const, i64, $simd_fn);
+        bitshift_eq_tester_impl!(@simdkind $is_const, u8, $simd_fn);
+        bitshift_eq_tester_impl!(@simdkind $is_const, u16, $simd_fn);
+        bitshift_eq_tester_impl!(@simdkind $is_const, u32, $simd_fn);
+        bitshift_eq_tester_impl!(@simdkind $is_const, u64, $simd_fn);
     };
 }
 
@@ -354,6 +385,7 @@ macro_rules! horizontal_add_tester_impl {
         horizontal_add_tester_impl!(@full $kind, Scalar, $simd_ty);
         horizontal_add_tester_impl!(@full $kind, Avx512, $simd_ty);
         horizontal_add_tester_impl!(@full $kind, Avx2, $simd_ty);
+        horizontal_add_tester_impl!(@full $kind, Avx, $simd_ty);
         horizontal_add_tester_impl!(@full $kind, Sse2, $simd_ty);
         horizontal_add_tester_impl!(@full $kind, Sse41, $simd_ty);
         horizontal_add_tester_impl!(@full $kind, Neon, $simd_ty);
@@ -367,6 +399,10 @@ macro_rules! horizontal_add_tester_impl {
         horizontal_add_tester_impl!(@simdkind signed, i64);
         horizontal_add_tester_impl!(@simdkind signed, f32);
         horizontal_add_tester_impl!(@simdkind signed, f64);
+        horizontal_add_tester_impl!(@simdkind signed, u8);
+        horizontal_add_tester_impl!(@simdkind signed, u16);
+        horizontal_add_tester_impl!(@simdkind signed, u32);
+        horizontal_add_tester_impl!(@simdkind signed, u64);
     };
 
     (unsigned) => {
@@ -0,0 +1,87 @@
+#![allow(dead_code, unused_parens)]
+
+#[cfg(any(target_arch = "x86_64", target_arch = "x86"))]
+use crate::dispatch::{tests::without_features, CpuFeature};
+use crate::prelude::*;
+
+use super::check_all_engines;
+
+fn table<const N: usize>() -> [i8; N] {
+    core::array::from_fn(|i| (i as i8).wrapping_mul(37).wrapping_add(11) | 1)
+}
+
+simd_unsafe_generate_all!(
+    fn lookups(indices: &[i8], _b: &[i8]) -> Vec<i8> {
+        let indices = S::Vi8::load_from_slice(indices);
+        let results = [
+            S::Vi8::lookup16(&table(), indices),
+            S::Vi8::lookup32(&table(), indices),
+            S::Vi8::lookup64(&table(), indices),
+        ];
+        let mut out = vec![0; 3 * S::Vi8::WIDTH];
+        for (result, chunk) in results.iter().zip(out.chunks_mut(S::Vi8::WIDTH)) {
+            result.copy_to_slice(chunk);
+        }
+        out
+    }
+);
+
+fn expected_lookup<const N: usize>(indices: &[i8]) -> Vec<i8> {
+    let table = table::<N>();
+    indices
+        .iter()
+        .map(|&index| table.get(index as u8 as usize).copied().unwrap_or(0))
+        .collect()
+}
+
+fn check(width: usize, lookups: impl Fn(&[i8], &[i8]) -> Vec<i8>) {
+    let pattern: [i8; 17] = [
+        0, 5, 15, 16, 17, 31, 32, 33, 47, 48, 63, 64, 100, 127, -128, -16, -1,
+    ];
+    for seed in 0..pattern.len() {
+        let indices: Vec<i8> = (0..width)
+            .map(|i| pattern[(i * 5 + seed) % pattern.len()])
+            .collect();
+        let expected = [
+            expected_lookup::<16>(&indices),
+            expected_lookup::<32>(&indices),
+            expected_lookup::<64>(&indices),
+        ]
+        .concat();
+        assert_eq!(
+            lookups(&indices, &indices),
+            expected,
+            "lookups of {indices:?}"
+        );
+    }
+}
+
+#[test]
+fn lookups_match_scalar() {
+    check_all_engines!(check, lookups, Vi8);
+}
+
+// The test above takes the VBMI path, and the SSSE3 path on the SSE2 engine, where the CPU has
+// them.
+#[cfg(any(target_arch = "x86_64", target_arch = "x86"))]
+#[test]
+fn lookups_match_scalar_without_vbmi_or_ssse3() {
+    without_features(&[CpuFeature::Avx512Vbmi, CpuFeature::Ssse3], || {
+        check_all_engines!(check, lookups, Vi8);
+    });
+}
+
+#[cfg(any(target_arch = "x86_64", target_arch = "x86"))]
+#[test]
+fn sse2_lookup16_matches_with_and_without_ssse3() {
+    if !is_x86_feature_detected!("ssse3") {
+        return;
+    }
+    let indices: [i8; 16] = core::array::from_fn(|i| (i as i8).wrapping_mul(29).wrapping_sub(40));
+    let fallback = without_features(&[CpuFeature::Ssse3], || unsafe {
+        lookups_sse2(&indices, &indices)
+    });
+    let pshufb = unsafe { lookups_sse2(&indices, &indices) };
+    assert_eq!(pshufb, fallback);
+    assert_eq!(&pshufb[..16], &expected_lookup::<16>(&indices)[..]);
+}
@@ -0,0 +1,195 @@
+#![allow(dead_code, unused_parens)]
+
+use crate::prelude::*;
+
+/// Everything a mask reports about itself, gathered in one engine call.
+#[derive(Debug)]
+struct MaskReport<T> {
+    lt: u64,
+    and: u64,
+    or: u64,
+    xor: u64,
+    not: u64,
+    any: bool,
+    all: bool,
+    none: bool,
+    count: u32,
+    first: Option<usize>,
+    from_bits: u64,
+    from_vector: u64,
+    selected: Vec<T>,
+    expanded: Vec<T>,
+}
+
+macro_rules! test_masks {
+    ($name:ident, $simd_ty:ident, $scalar_ty:ty) => {
+        mod $name {
+            use super::*;
+
+            simd_unsafe_generate_all!(
+                fn report(a: &[$scalar_ty], b: &[$scalar_ty], bits: u64) -> MaskReport<$scalar_ty> {
+                    let width = S::$simd_ty::WIDTH;
+                    let a = S::$simd_ty::load_from_slice(a);
+                    let b = S::$simd_ty::load_from_slice(b);
+                    let lt = a.cmp_lt(b);
+                    let eq = a.cmp_eq(b);
+                    let from_bits = <S::$simd_ty as SimdConsts>::Mask::from_bitmask(bits);
+
+                    let mut selected = vec![0 as $scalar_ty; width];
+                    lt.select(a, b).copy_to_slice(&mut selected);
+                    let mut expanded = vec![0 as $scalar_ty; width];
+                    from_bits.to_vector().copy_to_slice(&mut expanded);
+
+                    MaskReport {
+                        lt: lt.to_bitmask(),
+                        and: (lt & eq).to_bitmask(),
+                        or: (lt | eq).to_bitmask(),
+                        xor: (lt ^ from_bits).to_bitmask(),
+                        not: (!lt).to_bitmask(),
+                        any: lt.any(),
+                        all: lt.all(),
+                        none: lt.none(),
+                        count: lt.count_ones(),
+                        first: lt.first_set(),
+                        from_bits: from_bits.to_bitmask(),
+                        // The difference has its highest bit set exactly where `a < b`.
+                        from_vector: <S::$simd_ty as SimdConsts>::Mask::from_vector(a - b)
+                            .to_bitmask(),
+                        selected,
+                        expanded,
+                    }
+                }
+            );
+
+            fn check(
+                width: usize,
+                report: impl Fn(&[$scalar_ty], &[$scalar_ty], u64) -> MaskReport<$scalar_ty>,
+            ) {
+                let full = if width == 64 {
+                    u64::MAX
+                } else {
+                    (1 << width) - 1
+                };
+                let bits_of = |f: &dyn Fn(usize) -> bool| {
+                    (0..width)
+                        .filter(|&i| f(i))
+                        .fold(0u64, |acc, i| acc | 1 << i)
+                };
+                let lanes = |f: &dyn Fn(usize) -> $scalar_ty| (0..width).map(f).collect::<Vec<_>>();
+
+                let cases: [(&dyn Fn(usize) -> $scalar_ty, &dyn Fn(usize) -> $scalar_ty); 4] = [
+                    (&|i| (i % 5) as $scalar_ty, &|i| (i % 3) as $scalar_ty),
+                    (&|_| 1 as $scalar_ty, &|_| 2 as $scalar_ty),
+                    (&|_| 2 as $scalar_ty, &|_| 2 as $scalar_ty),
+                    (&|i| (i % 2) as $scalar_ty, &|_| 1 as $scalar_ty),
+                ];
+                for (case, (fa, fb)) in cases.iter().enumerate() {
+                    let a = lanes(fa);
+                    let b = lanes(fb);
+                    // Bits above the lane count must be dropped.
+                    let bits = 0xA5A5_A5A5_A5A5_A5A5u64 >> case;
+                    let lt = bits_of(&|i| a[i] < b[i]);
+                    let eq = bits_of(&|i| a[i] == b[i]);
+                    let from_bits = bits & full;
+                    let all_set: $scalar_ty =
+                        <$scalar_ty>::from_ne_bytes([0xFF; core::mem::size_of::<$scalar_ty>()]);
+
+                    let expected = MaskReport {
+                        lt,
+                        and: lt & eq,
+                        or: lt | eq,
+                        xor: lt ^ from_bits,
+                        not: !lt & full,
+                        any: lt != 0,
+                        all: lt == full,
+                        none: lt == 0,
+                        count: lt.count_ones(),
+                        first: (lt != 0).then(|| lt.trailing_zeros() as usize),
+                        from_bits,
+                        from_vector: lt,
+                        selected: lanes(&|i| if a[i] < b[i] { a[i] } else { b[i] }),
+                        expanded: lanes(&|i| {
+                            if from_bits >> i & 1 != 0 {
+                                all_set
+                            } else {
+                                0 as $scalar_ty
+                            }
+                        }),
+                    };
+                    let actual = report(&a, &b, bits);
+                    assert_eq!(
+                        format!("{actual:?}"),
+                        format!("{expected:?}"),
+                        "case {case}"
+                    );
+                }
+            }
+
+            #[test]
+            fn masks_match_lane_by_lane_reference() {
+                #[cfg(any(target_arch = "x86_64", target_arch = "x86"))]
+                {
+                    use crate::engines::{
+                        avx::Avx, avx2::Avx2, avx512::Avx512, scalar::Scalar, sse2::Sse2,
+                        sse41::Sse41,
+                    };
+
+                    check(<Scalar as Simd>::$simd_ty::WIDTH, report_scalar);
+                    if std::arch::is_x86_feature_detected!("sse2") {
+                        check(<Sse2 as Simd>::$simd_ty::WIDTH, |a, b, m| unsafe {
+                            report_sse2(a, b, m)
+                        });
+                    }
+                    if std::arch::is_x86_feature_detected!("sse4.1") {
+                        check(<Sse41 as Simd>::$simd_ty::WIDTH, |a, b, m| unsafe {
+                            report_sse41(a, b, m)
+                        });
+                    }
+                    if std::arch::is_x86_feature_detected!("avx") {
+                        check(<Avx as Simd>::$simd_ty::WIDTH, |a, b, m| unsafe {
+                            report_avx(a, b, m)
+                        });
+                    }
+                    if std::arch::is_x86_feature_detected!("avx2")
+                        && std::arch::is_x86_feature_detected!("fma")
+                    {
+                        check(<Avx2 as Simd>::$simd_ty::WIDTH, |a, b, m| unsafe {
+                            report_avx2(a, b, m)
+                        });
+                    }
+                    if std::arch::is_x86_feature_detected!("avx512f")
+                        && std::arch::is_x86_feature_detected!("avx512bw")
+                        && std::arch::is_x86_feature_detected!("avx512dq")
+                    {
+                        check(<Avx512 as Simd>::$simd_ty::WIDTH, |a, b, m| unsafe {
+                            report_avx512(a, b, m)
+                        });
+                    }
+                }
+
+                #[cfg(target_arch = "aarch64")]
+                check(
+                    <crate::engines::neon::Neon as Simd>::$simd_ty::WIDTH,
+                    |a, b, m| unsafe { report_neon(a, b, m) },
+                );
+
+                #[cfg(target_arch = "wasm32")]
+                check(
+                    <crate::engines::wasm32::Wasm as Simd>::$simd_ty::WIDTH,
+                    |a, b, m| unsafe { report_wasm(a, b, m) },
+                );
+            }
+        }
+    };
+}
+
+test_masks!(i8_lanes, Vi8, i8);
+test_masks!(i16_lanes, Vi16, i16);
+test_masks!(i32_lanes, Vi32, i32);
+test_masks!(i64_lanes, Vi64, i64);
+test_masks!(u8_lanes, Vu8, u8);
+test_masks!(u16_lanes, Vu16, u16);
+test_masks!(u32_lanes, Vu32, u32);
+test_masks!(u64_lanes, Vu64, u64);
+test_masks!(f32_lanes, Vf32, f32);
+test_masks!(f64_lanes, Vf64, f64);
@@ -0,0 +1,169 @@
+#![allow(dead_code, unused_parens)]
+
+use crate::prelude::*;
+
+const CANARY: u8 = 99;
+
+macro_rules! test_masked_io {
+    ($name:ident, $simd_ty:ident, $scalar_ty:ty) => {
+        mod $name {
+            use super::*;
+
+            simd_unsafe_generate_all!(
+                fn partial_io(
+                    input: &[$scalar_ty],
+                    len: usize,
+                    flags: &[$scalar_ty],
+                    out: &mut [$scalar_ty],
+                    masked_out: &mut [$scalar_ty],
+                ) -> Vec<$scalar_ty> {
+                    let width = S::$simd_ty::WIDTH;
+                    let zero = S::$simd_ty::zeroes();
+                    let one = S::$simd_ty::set1(1 as $scalar_ty);
+
+                    let (loaded, mask) = S::$simd_ty::load_partial(&input[..len]);
+                    // An all-set mask must still stop at the end of the slice.
+                    let all = zero.cmp_eq(zero);
+                    loaded.store_partial(&mut out[..len], all);
+
+                    let sparse = S::$simd_ty::load_from_slice(flags).cmp_eq(one);
+                    let masked = unsafe { S::$simd_ty::masked_load(input.as_ptr(), sparse) };
+                    unsafe { loaded.masked_store(masked_out.as_mut_ptr(), sparse) };
+
+                    let results = [loaded, mask.select(one, zero), masked];
+                    let mut lanes = vec![0 as $scalar_ty; results.len() * width];
+                    for (result, chunk) in results.iter().zip(lanes.chunks_mut(width)) {
+                        result.copy_to_slice(chunk);
+                    }
+                    lanes
+                }
+            );
+
+            fn check(
+                width: usize,
+                partial_io: impl Fn(
+                    &[$scalar_ty],
+                    usize,
+                    &[$scalar_ty],
+                    &mut [$scalar_ty],
+                    &mut [$scalar_ty],
+                ) -> Vec<$scalar_ty>,
+            ) {
+                let input: Vec<$scalar_ty> =
+                    (0..width).map(|i| (i * 3 + 5) as $scalar_ty).collect();
+                let flag = |set: bool| {
+                    if set {
+                        1 as $scalar_ty
+                    } else {
+                        0 as $scalar_ty
+                    }
+                };
+                let flags: Vec<$scalar_ty> = (0..width).map(|i| flag(i % 3 == 0)).collect();
+                let canary = CANARY as $scalar_ty;
+
+                for len in 0..=width {
+                    let mut out = vec![canary; width];
+                    let mut masked_out = vec![canary; width];
+                    let lanes = partial_io(&input, len, &flags, &mut out, &mut masked_out);
+
+                    let loaded: Vec<$scalar_ty> = (0..width)
+                        .map(|i| if i < len { input[i] } else { 0 as $scalar_ty })
+                        .collect();
+                    let mask: Vec<$scalar_ty> = (0..width).map(|i| flag(i < len)).collect();
+                    let masked: Vec<$scalar_ty> = (0..width)
+                        .map(|i| {
+                            if i % 3 == 0 {
+                                input[i]
+                            } else {
+                                0 as $scalar_ty
+                            }
+                        })
+                        .collect();
+                    assert_eq!(&lanes[..width], &loaded[..], "load_partial len={len}");
+                    assert_eq!(
+                        &lanes[width..2 * width],
+                        &mask[..],
+                        "partial mask len={len}"
+                    );
+                    assert_eq!(&lanes[2 * width..], &masked[..], "masked_load len={len}");
+
+                    let stored: Vec<$scalar_ty> = (0..width)
+                        .map(|i| if i < len { input[i] } else { canary })
+                        .collect();
+                    assert_eq!(out, stored, "store_partial len={len}");
+
+                    let masked_stored: Vec<$scalar_ty> = (0..width)
+                        .map(|i| if i % 3 == 0 { loaded[i] } else { canary })
+                        .collect();
+                    assert_eq!(masked_out, masked_stored, "masked_store len={len}");
+                }
+            }
+
+            #[test]
+            fn partial_and_masked_io_only_touch_active_lanes() {
+                #[cfg(any(target_arch = "x86_64", target_arch = "x86"))]
+                {
+                    use crate::engines::{
+                        avx::Avx, avx2::Avx2, avx512::Avx512, scalar::Scalar, sse2::Sse2,
+                        sse41::Sse41,
+                    };
+
+                    check(<Scalar as Simd>::$simd_ty::WIDTH, partial_io_scalar);
+                    if std::arch::is_x86_feature_detected!("sse2") {
+                        check(<Sse2 as Simd>::$simd_ty::WIDTH, |i, l, f, o, m| unsafe {
+                            partial_io_sse2(i, l, f, o, m)
+                        });
+                    }
+                    if std::arch::is_x86_feature_detected!("sse4.1") {
+                        check(<Sse41 as Simd>::$simd_ty::WIDTH, |i, l, f, o, m| unsafe {
+                            partial_io_sse41(i, l, f, o, m)
+                        });
+                    }
+                    if std::arch::is_x86_feature_detected!("avx") {
+                        check(<Avx as Simd>::$simd_ty::WIDTH, |i, l, f, o, m| unsafe {
+                            partial_io_avx(i, l, f, o, m)
+                        });
+                    }
+                    if std::arch::is_x86_feature_detected!("avx2")
+                        && std::arch::is_x86_feature_detected!("fma")
+                    {
+                        check(<Avx2 as Simd>::$simd_ty::WIDTH, |i, l, f, o, m| unsafe {
+                            partial_io_avx2(i, l, f, o, m)
+                        });
+                    }
+                    if std::arch::is_x86_feature_detected!("avx512f")
+                        && std::arch::is_x86_feature_detected!("avx512bw")
+                        && std::arch::is_x86_feature_detected!("avx512dq")
+                    {
+                        check(<Avx512 as Simd>::$simd_ty::WIDTH, |i, l, f, o, m| unsafe {
+                            partial_io_avx512(i, l, f, o, m)
+                        });
+                    }
+                }
+
+                #[cfg(target_arch = "aarch64")]
+                check(
+                    <crate::engines::neon::Neon as Simd>::$simd_ty::WIDTH,
+                    |i, l, f, o, m| unsafe { partial_io_neon(i, l, f, o, m) },
+                );
+
+                #[cfg(target_arch = "wasm32")]
+                check(
+                    <crate::engines::wasm32::Wasm as Simd>::$simd_ty::WIDTH,
+                    |i, l, f, o, m| unsafe { partial_io_wasm(i, l, f, o, m) },
+                );
+            }
+        }
+    };
+}
+
+test_masked_io!(i8_lanes, Vi8, i8);
+test_masked_io!(i16_lanes, Vi16, i16);
+test_masked_io!(i32_lanes, Vi32, i32);
+test_masked_io!(i64_lanes, Vi64, i64);
+test_masked_io!(u8_lanes, Vu8, u8);
+test_masked_io!(u16_lanes, Vu16, u16);
+test_masked_io!(u32_lanes, Vu32, u32);
+test_masked_io!(u64_lanes, Vu64, u64);
+test_masked_io!(f32_lanes, Vf32, f32);
+test_masked_io!(f64_lanes, Vf64, f64);
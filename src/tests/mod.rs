@@ -1,17 +1,34 @@
 mod lib;
 use lib::*;
 
+mod bit_count_contracts;
+mod compress_contracts;
+mod conversion_contracts;
+mod dispatch_cache;
+mod dispatch_generics;
 mod float_cast_tie_regressions;
+mod float_classification_contracts;
 mod float_edge_contract_regressions;
 mod float_neq_regressions;
 mod float_special_value_regressions;
+mod gather_scatter_contracts;
+mod horizontal_reduction_contracts;
 mod i64_regressions;
 mod i8_mask_regressions;
 mod i8_mul_regressions;
 mod i8_truthy_regressions;
+mod integer_arith_contracts;
 mod integer_edge_contracts;
+mod interleave_contracts;
+mod lane_shuffle_contracts;
+mod lookup_contracts;
+mod mask_contracts;
+mod masked_io_contracts;
 mod real_world;
 mod run;
+mod shift_rotate_contracts;
 mod simd_math;
 mod simd_math_targeted_edges;
+mod transpose_contracts;
+mod unsigned_lane_contracts;
 mod wasm_unaligned_regressions;
@@ -17,7 +17,7 @@ simd_unsafe_generate_all!(
 
         while data.len() >= S::Vi8::WIDTH {
             let chunk = S::Vi8::load_from_slice(data);
-            if let Some(index) = chunk.cmp_eq(needle).index_of_first_truthy() {
+            if let Some(index) = chunk.cmp_eq(needle).first_set() {
                 return Some(offset + index);
             }
 
@@ -54,6 +54,50 @@ simd_unsafe_generate_all!(
     }
 );
 
+const HEX_DIGITS: [i8; 16] = [
+    b'0' as i8, b'1' as i8, b'2' as i8, b'3' as i8, b'4' as i8, b'5' as i8, b'6' as i8, b'7' as i8,
+    b'8' as i8, b'9' as i8, b'a' as i8, b'b' as i8, b'c' as i8, b'd' as i8, b'e' as i8, b'f' as i8,
+];
+
+fn reference_hex_encode_i8(data: &[i8]) -> Vec<i8> {
+    data.iter()
+        .flat_map(|&value| {
+            let value = value as u8;
+            [
+                HEX_DIGITS[(value >> 4) as usize],
+                HEX_DIGITS[(value & 15) as usize],
+            ]
+        })
+        .collect()
+}
+
+simd_unsafe_generate_all!(
+    fn hex_encode_i8(data: &[i8], output: &mut [i8]) {
+        assert_eq!(output.len(), 2 * data.len());
+
+        let mut data = data;
+        let mut output = output;
+        let nibble = S::Vi8::set1(15);
+
+        while data.len() >= S::Vi8::WIDTH {
+            let chunk = S::Vi8::load_from_slice(data);
+            let high = S::Vi8::lookup16(&HEX_DIGITS, (chunk >> 4) & nibble);
+            let low = S::Vi8::lookup16(&HEX_DIGITS, chunk & nibble);
+            high.zip_lo(low).copy_to_slice(output);
+            high.zip_hi(low).copy_to_slice(&mut output[S::Vi8::WIDTH..]);
+
+            data = &data[S::Vi8::WIDTH..];
+            output = &mut output[2 * S::Vi8::WIDTH..];
+        }
+
+        for (&value, digits) in data.iter().zip(output.chunks_exact_mut(2)) {
+            let value = value as u8;
+            digits[0] = HEX_DIGITS[(value >> 4) as usize];
+            digits[1] = HEX_DIGITS[(value & 15) as usize];
+        }
+    }
+);
+
 fn assert_find_first_eq_matches_all_backends(data: &[i8], needle: i8) {
     let expected = reference_find_first_eq_i8(data, needle);
     assert_eq!(find_first_eq_i8(data, needle), expected);
@@ -66,6 +110,9 @@ fn assert_find_first_eq_matches_all_backends(data: &[i8], needle: i8) {
         if std::arch::is_x86_feature_detected!("sse4.1") {
             assert_eq!(unsafe { find_first_eq_i8_sse41(data, needle) }, expected);
         }
+        if std::arch::is_x86_feature_detected!("avx") {
+            assert_eq!(unsafe { find_first_eq_i8_avx(data, needle) }, expected);
+        }
         if std::arch::is_x86_feature_detected!("avx2") && std::arch::is_x86_feature_detected!("fma")
         {
             assert_eq!(unsafe { find_first_eq_i8_avx2(data, needle) }, expected);
@@ -85,6 +132,9 @@ fn assert_byte_checksum_matches_all_backends(data: &[i8]) {
         if std::arch::is_x86_feature_detected!("sse4.1") {
             assert_eq!(unsafe { byte_checksum_i8_sse41(data) }, expected);
         }
+        if std::arch::is_x86_feature_detected!("avx") {
+            assert_eq!(unsafe { byte_checksum_i8_avx(data) }, expected);
+        }
         if std::arch::is_x86_feature_detected!("avx2") && std::arch::is_x86_feature_detected!("fma")
         {
             assert_eq!(unsafe { byte_checksum_i8_avx2(data) }, expected);
@@ -92,6 +142,51 @@ fn assert_byte_checksum_matches_all_backends(data: &[i8]) {
     }
 }
 
+fn assert_hex_encode_matches_all_backends(data: &[i8]) {
+    let expected = reference_hex_encode_i8(data);
+
+    let mut portable = vec![0; 2 * data.len()];
+    hex_encode_i8(data, &mut portable);
+    assert_eq!(portable, expected);
+
+    #[cfg(any(target_arch = "x86_64", target_arch = "x86"))]
+    {
+        let mut output = vec![0; 2 * data.len()];
+        hex_encode_i8_scalar(data, &mut output);
+        assert_eq!(output, expected);
+
+        if std::arch::is_x86_feature_detected!("sse2") {
+            let mut output = vec![0; 2 * data.len()];
+            unsafe { hex_encode_i8_sse2(data, &mut output) };
+            assert_eq!(output, expected);
+        }
+        if std::arch::is_x86_feature_detected!("sse4.1") {
+            let mut output = vec![0; 2 * data.len()];
+            unsafe { hex_encode_i8_sse41(data, &mut output) };
+            assert_eq!(output, expected);
+        }
+        if std::arch::is_x86_feature_detected!("avx") {
+            let mut output = vec![0; 2 * data.len()];
+            unsafe { hex_encode_i8_avx(data, &mut output) };
+            assert_eq!(output, expected);
+        }
+        if std::arch::is_x86_feature_detected!("avx2") && std::arch::is_x86_feature_detected!("fma")
+        {
+            let mut output = vec![0; 2 * data.len()];
+            unsafe { hex_encode_i8_avx2(data, &mut output) };
+            assert_eq!(output, expected);
+        }
+        if std::arch::is_x86_feature_detected!("avx512f")
+            && std::arch::is_x86_feature_detected!("avx512bw")
+            && std::arch::is_x86_feature_detected!("avx512dq")
+        {
+            let mut output = vec![0; 2 * data.len()];
+            unsafe { hex_encode_i8_avx512(data, &mut output) };
+            assert_eq!(output, expected);
+        }
+    }
+}
+
 // This models parser and codec hot loops that scan byte streams for a sentinel marker without
 // branching on every element, which matters when delimiters land near SIMD-lane boundaries.
 #[test]
@@ -178,3 +273,17 @@ fn real_world_byte_checksum_i8_respects_chunk_and_tail_boundaries() {
         assert_byte_checksum_matches_all_backends(&data[..len]);
     }
 }
+
+// Hex encoding is the classic nibble-table trick: both nibbles of every byte are looked up in a
+// 16 entry digit table, then the digit vectors are interleaved back into byte order.
+#[test]
+fn real_world_hex_encode_i8_matches_reference() {
+    let data = (0..300)
+        .map(|i| (i * 73 + 19) as u8 as i8)
+        .collect::<Vec<_>>();
+
+    assert_hex_encode_matches_all_backends(&[]);
+    assert_hex_encode_matches_all_backends(&data[..9]);
+    assert_hex_encode_matches_all_backends(&data[..64]);
+    assert_hex_encode_matches_all_backends(&data);
+}
@@ -57,6 +57,45 @@ simd_unsafe_generate_all!(
     }
 );
 
+fn reference_pack_unorm_f32_to_u8(input: &[f32]) -> Vec<u8> {
+    input
+        .iter()
+        .map(|&value| (value * 255.0 + 0.5).floor().clamp(0.0, 255.0) as u8)
+        .collect()
+}
+
+simd_unsafe_generate_all!(
+    fn pack_unorm_f32_to_u8(input: &[f32], output: &mut [u8]) {
+        assert_eq!(input.len(), output.len());
+
+        let mut input = input;
+        let mut output = output;
+        let scale = S::Vf32::set1(255.0);
+        let half = S::Vf32::set1(0.5);
+
+        // Four f32 vectors fill one u8 vector; the saturating packs do the clamping.
+        while input.len() >= 4 * S::Vf32::WIDTH {
+            let mut quantized = [S::Vi32::zeroes(); 4];
+            for (i, lanes) in quantized.iter_mut().enumerate() {
+                *lanes = S::Vf32::load_from_slice(&input[i * S::Vf32::WIDTH..])
+                    .mul_add(scale, half)
+                    .floor()
+                    .cast_i32();
+            }
+            let low = quantized[0].saturating_narrow_to_i16(quantized[1]);
+            let high = quantized[2].saturating_narrow_to_i16(quantized[3]);
+            low.saturating_narrow_to_u8(high).copy_to_slice(output);
+
+            input = &input[S::Vu8::WIDTH..];
+            output = &mut output[S::Vu8::WIDTH..];
+        }
+
+        for (value, slot) in input.iter().zip(output.iter_mut()) {
+            *slot = (value * 255.0 + 0.5).floor().clamp(0.0, 255.0) as u8;
+        }
+    }
+);
+
 simd_unsafe_generate_all!(
     fn normalize_luma_f32(input: &[f32], output: &mut [f32]) {
         assert_eq!(input.len(), output.len());
@@ -76,7 +115,7 @@ simd_unsafe_generate_all!(
             let normalized = (values - black_level).mul(inv_range).max(zero).min(one);
             let lifted = normalized.mul(lift).min(one);
             let softened = normalized.mul(soften);
-            let output_chunk = normalized.cmp_gt(threshold).blendv(softened, lifted);
+            let output_chunk = normalized.cmp_gt(threshold).select(lifted, softened);
             output_chunk.copy_to_slice(output);
 
             input = &input[S::Vf32::WIDTH..];
@@ -92,6 +131,103 @@ simd_unsafe_generate_all!(
     }
 );
 
+fn reference_rgb_to_luma_f32(input: &[f32]) -> Vec<f32> {
+    input
+        .chunks_exact(3)
+        .map(|pixel| pixel[0] * 0.2126 + pixel[1] * 0.7152 + pixel[2] * 0.0722)
+        .collect()
+}
+
+simd_unsafe_generate_all!(
+    fn rgb_to_luma_f32(input: &[f32], output: &mut [f32]) {
+        assert_eq!(input.len(), 3 * output.len());
+
+        let mut input = input;
+        let mut output = output;
+        let red_weight = S::Vf32::set1(0.2126);
+        let green_weight = S::Vf32::set1(0.7152);
+        let blue_weight = S::Vf32::set1(0.0722);
+
+        while output.len() >= S::Vf32::WIDTH {
+            let (red, green, blue) = S::Vf32::load_interleaved3(input);
+            let luma = red * red_weight + green * green_weight + blue * blue_weight;
+            luma.copy_to_slice(output);
+
+            input = &input[3 * S::Vf32::WIDTH..];
+            output = &mut output[S::Vf32::WIDTH..];
+        }
+
+        for (pixel, slot) in input.chunks_exact(3).zip(output.iter_mut()) {
+            *slot = pixel[0] * 0.2126 + pixel[1] * 0.7152 + pixel[2] * 0.0722;
+        }
+    }
+);
+
+fn reference_rgba_to_bgra_u8(input: &[u8]) -> Vec<u8> {
+    input
+        .chunks_exact(4)
+        .flat_map(|pixel| [pixel[2], pixel[1], pixel[0], pixel[3]])
+        .collect()
+}
+
+simd_unsafe_generate_all!(
+    fn rgba_to_bgra_u8(input: &[u8], output: &mut [u8]) {
+        assert_eq!(input.len(), output.len());
+
+        let mut input = input;
+        let mut output = output;
+
+        while input.len() >= 4 * S::Vu8::WIDTH {
+            let (red, green, blue, alpha) = S::Vu8::load_interleaved4(input);
+            S::Vu8::store_interleaved4((blue, green, red, alpha), output);
+
+            input = &input[4 * S::Vu8::WIDTH..];
+            output = &mut output[4 * S::Vu8::WIDTH..];
+        }
+
+        for (pixel, slot) in input.chunks_exact(4).zip(output.chunks_exact_mut(4)) {
+            slot.copy_from_slice(&[pixel[2], pixel[1], pixel[0], pixel[3]]);
+        }
+    }
+);
+
+fn reference_transpose_image_f32(input: &[f32], width: usize, height: usize) -> Vec<f32> {
+    (0..width * height)
+        .map(|i| input[(i % height) * width + i / height])
+        .collect()
+}
+
+simd_unsafe_generate_all!(
+    fn transpose_image_f32(input: &[f32], width: usize, height: usize, output: &mut [f32]) {
+        assert_eq!(input.len(), width * height);
+        assert_eq!(output.len(), width * height);
+
+        let tile = S::Vf32::WIDTH;
+
+        for y in (0..height).step_by(tile) {
+            for x in (0..width).step_by(tile) {
+                if y + tile <= height && x + tile <= width {
+                    let mut rows = S::Vf32::tile_from_fn(|i| {
+                        let start = (y + i) * width + x;
+                        S::Vf32::load_from_slice(&input[start..start + tile])
+                    });
+                    S::Vf32::transpose(&mut rows);
+                    for (i, row) in rows.as_ref().iter().enumerate() {
+                        let start = (x + i) * height + y;
+                        row.copy_to_slice(&mut output[start..start + tile]);
+                    }
+                } else {
+                    for row in y..(y + tile).min(height) {
+                        for column in x..(x + tile).min(width) {
+                            output[column * height + row] = input[row * width + column];
+                        }
+                    }
+                }
+            }
+        }
+    }
+);
+
 fn assert_quantize_matches_all_backends(input: &[f32]) {
     let expected = reference_quantize_f32(input);
 
@@ -111,6 +247,11 @@ fn assert_quantize_matches_all_backends(input: &[f32]) {
             unsafe { quantize_f32_to_i32_sse41(input, &mut output) };
             assert_eq!(output, expected);
         }
+        if std::arch::is_x86_feature_detected!("avx") {
+            let mut output = vec![0; input.len()];
+            unsafe { quantize_f32_to_i32_avx(input, &mut output) };
+            assert_eq!(output, expected);
+        }
         if std::arch::is_x86_feature_detected!("avx2") && std::arch::is_x86_feature_detected!("fma")
         {
             let mut output = vec![0; input.len()];
@@ -120,6 +261,51 @@ fn assert_quantize_matches_all_backends(input: &[f32]) {
     }
 }
 
+fn assert_pack_unorm_matches_all_backends(input: &[f32]) {
+    let expected = reference_pack_unorm_f32_to_u8(input);
+
+    let mut portable = vec![0; input.len()];
+    pack_unorm_f32_to_u8(input, &mut portable);
+    assert_eq!(portable, expected);
+
+    #[cfg(any(target_arch = "x86_64", target_arch = "x86"))]
+    {
+        let mut output = vec![0; input.len()];
+        pack_unorm_f32_to_u8_scalar(input, &mut output);
+        assert_eq!(output, expected);
+
+        if std::arch::is_x86_feature_detected!("sse2") {
+            let mut output = vec![0; input.len()];
+            unsafe { pack_unorm_f32_to_u8_sse2(input, &mut output) };
+            assert_eq!(output, expected);
+        }
+        if std::arch::is_x86_feature_detected!("sse4.1") {
+            let mut output = vec![0; input.len()];
+            unsafe { pack_unorm_f32_to_u8_sse41(input, &mut output) };
+            assert_eq!(output, expected);
+        }
+        if std::arch::is_x86_feature_detected!("avx") {
+            let mut output = vec![0; input.len()];
+            unsafe { pack_unorm_f32_to_u8_avx(input, &mut output) };
+            assert_eq!(output, expected);
+        }
+        if std::arch::is_x86_feature_detected!("avx2") && std::arch::is_x86_feature_detected!("fma")
+        {
+            let mut output = vec![0; input.len()];
+            unsafe { pack_unorm_f32_to_u8_avx2(input, &mut output) };
+            assert_eq!(output, expected);
+        }
+        if std::arch::is_x86_feature_detected!("avx512f")
+            && std::arch::is_x86_feature_detected!("avx512bw")
+            && std::arch::is_x86_feature_detected!("avx512dq")
+        {
+            let mut output = vec![0; input.len()];
+            unsafe { pack_unorm_f32_to_u8_avx512(input, &mut output) };
+            assert_eq!(output, expected);
+        }
+    }
+}
+
 fn assert_normalize_luma_matches_all_backends(input: &[f32]) {
     let expected = reference_normalize_luma_f32(input);
 
@@ -139,6 +325,11 @@ fn assert_normalize_luma_matches_all_backends(input: &[f32]) {
             unsafe { normalize_luma_f32_sse41(input, &mut output) };
             assert_f32_slices_close(&output, &expected);
         }
+        if std::arch::is_x86_feature_detected!("avx") {
+            let mut output = vec![0.0; input.len()];
+            unsafe { normalize_luma_f32_avx(input, &mut output) };
+            assert_f32_slices_close(&output, &expected);
+        }
         if std::arch::is_x86_feature_detected!("avx2") && std::arch::is_x86_feature_detected!("fma")
         {
             let mut output = vec![0.0; input.len()];
@@ -148,6 +339,141 @@ fn assert_normalize_luma_matches_all_backends(input: &[f32]) {
     }
 }
 
+fn assert_rgb_to_luma_matches_all_backends(input: &[f32]) {
+    let expected = reference_rgb_to_luma_f32(input);
+
+    let mut portable = vec![0.0; input.len() / 3];
+    rgb_to_luma_f32(input, &mut portable);
+    assert_f32_slices_close(&portable, &expected);
+
+    #[cfg(any(target_arch = "x86_64", target_arch = "x86"))]
+    {
+        let mut output = vec![0.0; input.len() / 3];
+        rgb_to_luma_f32_scalar(input, &mut output);
+        assert_f32_slices_close(&output, &expected);
+
+        if std::arch::is_x86_feature_detected!("sse2") {
+            let mut output = vec![0.0; input.len() / 3];
+            unsafe { rgb_to_luma_f32_sse2(input, &mut output) };
+            assert_f32_slices_close(&output, &expected);
+        }
+        if std::arch::is_x86_feature_detected!("sse4.1") {
+            let mut output = vec![0.0; input.len() / 3];
+            unsafe { rgb_to_luma_f32_sse41(input, &mut output) };
+            assert_f32_slices_close(&output, &expected);
+        }
+        if std::arch::is_x86_feature_detected!("avx") {
+            let mut output = vec![0.0; input.len() / 3];
+            unsafe { rgb_to_luma_f32_avx(input, &mut output) };
+            assert_f32_slices_close(&output, &expected);
+        }
+        if std::arch::is_x86_feature_detected!("avx2") && std::arch::is_x86_feature_detected!("fma")
+        {
+            let mut output = vec![0.0; input.len() / 3];
+            unsafe { rgb_to_luma_f32_avx2(input, &mut output) };
+            assert_f32_slices_close(&output, &expected);
+        }
+        if std::arch::is_x86_feature_detected!("avx512f")
+            && std::arch::is_x86_feature_detected!("avx512bw")
+            && std::arch::is_x86_feature_detected!("avx512dq")
+        {
+            let mut output = vec![0.0; input.len() / 3];
+            unsafe { rgb_to_luma_f32_avx512(input, &mut output) };
+            assert_f32_slices_close(&output, &expected);
+        }
+    }
+}
+
+fn assert_rgba_to_bgra_matches_all_backends(input: &[u8]) {
+    let expected = reference_rgba_to_bgra_u8(input);
+
+    let mut portable = vec![0; input.len()];
+    rgba_to_bgra_u8(input, &mut portable);
+    assert_eq!(portable, expected);
+
+    #[cfg(any(target_arch = "x86_64", target_arch = "x86"))]
+    {
+        let mut output = vec![0; input.len()];
+        rgba_to_bgra_u8_scalar(input, &mut output);
+        assert_eq!(output, expected);
+
+        if std::arch::is_x86_feature_detected!("sse2") {
+            let mut output = vec![0; input.len()];
+            unsafe { rgba_to_bgra_u8_sse2(input, &mut output) };
+            assert_eq!(output, expected);
+        }
+        if std::arch::is_x86_feature_detected!("sse4.1") {
+            let mut output = vec![0; input.len()];
+            unsafe { rgba_to_bgra_u8_sse41(input, &mut output) };
+            assert_eq!(output, expected);
+        }
+        if std::arch::is_x86_feature_detected!("avx") {
+            let mut output = vec![0; input.len()];
+            unsafe { rgba_to_bgra_u8_avx(input, &mut output) };
+            assert_eq!(output, expected);
+        }
+        if std::arch::is_x86_feature_detected!("avx2") && std::arch::is_x86_feature_detected!("fma")
+        {
+            let mut output = vec![0; input.len()];
+            unsafe { rgba_to_bgra_u8_avx2(input, &mut output) };
+            assert_eq!(output, expected);
+        }
+        if std::arch::is_x86_feature_detected!("avx512f")
+            && std::arch::is_x86_feature_detected!("avx512bw")
+            && std::arch::is_x86_feature_detected!("avx512dq")
+        {
+            let mut output = vec![0; input.len()];
+            unsafe { rgba_to_bgra_u8_avx512(input, &mut output) };
+            assert_eq!(output, expected);
+        }
+    }
+}
+
+fn assert_transpose_image_matches_all_backends(input: &[f32], width: usize, height: usize) {
+    let expected = reference_transpose_image_f32(input, width, height);
+
+    let mut portable = vec![0.0; input.len()];
+    transpose_image_f32(input, width, height, &mut portable);
+    assert_eq!(portable, expected);
+
+    #[cfg(any(target_arch = "x86_64", target_arch = "x86"))]
+    {
+        let mut output = vec![0.0; input.len()];
+        transpose_image_f32_scalar(input, width, height, &mut output);
+        assert_eq!(output, expected);
+
+        if std::arch::is_x86_feature_detected!("sse2") {
+            let mut output = vec![0.0; input.len()];
+            unsafe { transpose_image_f32_sse2(input, width, height, &mut output) };
+            assert_eq!(output, expected);
+        }
+        if std::arch::is_x86_feature_detected!("sse4.1") {
+            let mut output = vec![0.0; input.len()];
+            unsafe { transpose_image_f32_sse41(input, width, height, &mut output) };
+            assert_eq!(output, expected);
+        }
+        if std::arch::is_x86_feature_detected!("avx") {
+            let mut output = vec![0.0; input.len()];
+            unsafe { transpose_image_f32_avx(input, width, height, &mut output) };
+            assert_eq!(output, expected);
+        }
+        if std::arch::is_x86_feature_detected!("avx2") && std::arch::is_x86_feature_detected!("fma")
+        {
+            let mut output = vec![0.0; input.len()];
+            unsafe { transpose_image_f32_avx2(input, width, height, &mut output) };
+            assert_eq!(output, expected);
+        }
+        if std::arch::is_x86_feature_detected!("avx512f")
+            && std::arch::is_x86_feature_detected!("avx512bw")
+            && std::arch::is_x86_feature_detected!("avx512dq")
+        {
+            let mut output = vec![0.0; input.len()];
+            unsafe { transpose_image_f32_avx512(input, width, height, &mut output) };
+            assert_eq!(output, expected);
+        }
+    }
+}
+
 // This is the kind of magnitude-to-bucket stage used before writing image intensities or feature
 // maps back to integer buffers, where clamp and floor must agree across portable and native paths.
 #[test]
@@ -190,6 +516,27 @@ fn real_world_normalize_luma_f32_matches_reference() {
     assert_normalize_luma_matches_all_backends(&input);
 }
 
+// Writing normalized float pixels back to an 8-bit image: scale, round, then pack four f32
+// vectors into one u8 vector with saturating narrows instead of clamping in float.
+#[test]
+fn real_world_pack_unorm_f32_to_u8_matches_reference() {
+    let input = [
+        -1.5, -0.01, 0.0, 0.001_9, 0.002_1, 0.1, 0.25, 0.498, 0.5, 0.75, 0.997_9, 0.998_1, 1.0,
+        1.01, 3.0, 1000.0,
+    ]
+    .into_iter()
+    .cycle()
+    .take(203)
+    .enumerate()
+    .map(|(i, value)| value + (i % 7) as f32 * 0.000_25)
+    .collect::<Vec<_>>();
+
+    assert_pack_unorm_matches_all_backends(&[]);
+    assert_pack_unorm_matches_all_backends(&input[..9]);
+    assert_pack_unorm_matches_all_backends(&input[..70]);
+    assert_pack_unorm_matches_all_backends(&input);
+}
+
 #[test]
 fn real_world_quantize_f32_to_i32_threshold_boundaries_match_reference() {
     let input = vec![
@@ -215,3 +562,46 @@ fn real_world_normalize_luma_f32_threshold_boundaries_match_reference() {
     ];
     assert_normalize_luma_matches_all_backends(&input);
 }
+
+// Converting packed RGB float pixels to luma: the interleaved load splits each run of pixels into
+// one vector per channel so the weighting is three multiplies and two adds per vector of pixels.
+#[test]
+fn real_world_rgb_to_luma_f32_matches_reference() {
+    let input = [0.0, 0.05, 0.18, 0.25, 0.5, 0.73, 0.9, 1.0, 1.2]
+        .into_iter()
+        .cycle()
+        .take(3 * 75)
+        .enumerate()
+        .map(|(i, value)| value + (i % 11) as f32 * 0.002)
+        .collect::<Vec<_>>();
+
+    assert_rgb_to_luma_matches_all_backends(&[]);
+    assert_rgb_to_luma_matches_all_backends(&input[..3 * 5]);
+    assert_rgb_to_luma_matches_all_backends(&input[..3 * 33]);
+    assert_rgb_to_luma_matches_all_backends(&input);
+}
+
+// Swapping RGBA to BGRA when handing 8-bit images to APIs with the other channel order.
+#[test]
+fn real_world_rgba_to_bgra_u8_matches_reference() {
+    let input = (0..4 * 150)
+        .map(|i| (i * 37 % 256) as u8)
+        .collect::<Vec<_>>();
+
+    assert_rgba_to_bgra_matches_all_backends(&[]);
+    assert_rgba_to_bgra_matches_all_backends(&input[..4 * 7]);
+    assert_rgba_to_bgra_matches_all_backends(&input[..4 * 70]);
+    assert_rgba_to_bgra_matches_all_backends(&input);
+}
+
+// Rotating or column-filtering an image starts with a transpose; whole tiles go through the
+// in-register transpose and the ragged right and bottom edges are copied lane by lane.
+#[test]
+fn real_world_transpose_image_f32_matches_reference() {
+    for (width, height) in [(1, 1), (4, 4), (16, 16), (37, 21), (64, 48)] {
+        let input = (0..width * height)
+            .map(|i| i as f32 * 0.5 - 100.0)
+            .collect::<Vec<_>>();
+        assert_transpose_image_matches_all_backends(&input, width, height);
+    }
+}
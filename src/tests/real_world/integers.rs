@@ -38,7 +38,7 @@ simd_unsafe_generate_all!(
             let spread = hi - lo;
             let expanded = hi.min(upper_pre_add) + 7;
             let compressed = lo.max(lower_pre_sub) - 7;
-            let selected = spread.cmp_gt(threshold).blendv(compressed, expanded);
+            let selected = spread.cmp_gt(threshold).select(expanded, compressed);
             selected.copy_to_slice(output);
 
             lhs = &lhs[S::Vi32::WIDTH..];
@@ -57,6 +57,33 @@ simd_unsafe_generate_all!(
     }
 );
 
+fn reference_filter_between_i32(values: &[i32], low: i32, high: i32) -> Vec<i32> {
+    values
+        .iter()
+        .copied()
+        .filter(|value| (low..high).contains(value))
+        .collect()
+}
+
+simd_unsafe_generate_all!(
+    fn filter_between_i32(values: &[i32], low: i32, high: i32, output: &mut [i32]) -> usize {
+        assert!(output.len() >= values.len());
+
+        let low = S::Vi32::set1(low);
+        let high = S::Vi32::set1(high);
+        let mut written = 0;
+
+        // The partial load covers the tail, so there is no scalar loop.
+        for chunk in values.chunks(S::Vi32::WIDTH) {
+            let (chunk_values, loaded) = S::Vi32::load_partial(chunk);
+            let keep = loaded & chunk_values.cmp_gte(low) & chunk_values.cmp_lt(high);
+            written += chunk_values.compress_store(&mut output[written..], keep);
+        }
+
+        written
+    }
+);
+
 fn assert_adaptive_select_matches_all_backends(lhs: &[i32], rhs: &[i32]) {
     let expected = reference_adaptive_select_i32(lhs, rhs);
 
@@ -76,6 +103,11 @@ fn assert_adaptive_select_matches_all_backends(lhs: &[i32], rhs: &[i32]) {
             unsafe { adaptive_select_i32_sse41(lhs, rhs, &mut output) };
             assert_eq!(output, expected);
         }
+        if std::arch::is_x86_feature_detected!("avx") {
+            let mut output = vec![0; lhs.len()];
+            unsafe { adaptive_select_i32_avx(lhs, rhs, &mut output) };
+            assert_eq!(output, expected);
+        }
         if std::arch::is_x86_feature_detected!("avx2") && std::arch::is_x86_feature_detected!("fma")
         {
             let mut output = vec![0; lhs.len()];
@@ -85,6 +117,51 @@ fn assert_adaptive_select_matches_all_backends(lhs: &[i32], rhs: &[i32]) {
     }
 }
 
+fn assert_filter_between_matches_all_backends(values: &[i32], low: i32, high: i32) {
+    let expected = reference_filter_between_i32(values, low, high);
+
+    let mut portable = vec![0; values.len()];
+    let written = filter_between_i32(values, low, high, &mut portable);
+    assert_eq!(&portable[..written], expected);
+
+    #[cfg(any(target_arch = "x86_64", target_arch = "x86"))]
+    {
+        let mut output = vec![0; values.len()];
+        let written = filter_between_i32_scalar(values, low, high, &mut output);
+        assert_eq!(&output[..written], expected);
+
+        if std::arch::is_x86_feature_detected!("sse2") {
+            let mut output = vec![0; values.len()];
+            let written = unsafe { filter_between_i32_sse2(values, low, high, &mut output) };
+            assert_eq!(&output[..written], expected);
+        }
+        if std::arch::is_x86_feature_detected!("sse4.1") {
+            let mut output = vec![0; values.len()];
+            let written = unsafe { filter_between_i32_sse41(values, low, high, &mut output) };
+            assert_eq!(&output[..written], expected);
+        }
+        if std::arch::is_x86_feature_detected!("avx") {
+            let mut output = vec![0; values.len()];
+            let written = unsafe { filter_between_i32_avx(values, low, high, &mut output) };
+            assert_eq!(&output[..written], expected);
+        }
+        if std::arch::is_x86_feature_detected!("avx2") && std::arch::is_x86_feature_detected!("fma")
+        {
+            let mut output = vec![0; values.len()];
+            let written = unsafe { filter_between_i32_avx2(values, low, high, &mut output) };
+            assert_eq!(&output[..written], expected);
+        }
+        if std::arch::is_x86_feature_detected!("avx512f")
+            && std::arch::is_x86_feature_detected!("avx512bw")
+            && std::arch::is_x86_feature_detected!("avx512dq")
+        {
+            let mut output = vec![0; values.len()];
+            let written = unsafe { filter_between_i32_avx512(values, low, high, &mut output) };
+            assert_eq!(&output[..written], expected);
+        }
+    }
+}
+
 // This resembles integer decision stages in codecs and DSP control paths where a fast per-lane
 // spread check chooses between compressing noise and preserving strong transients.
 #[test]
@@ -150,3 +227,18 @@ fn real_world_adaptive_select_i32_threshold_and_clamp_boundaries_match_reference
 
     assert_adaptive_select_matches_all_backends(&lhs, &rhs);
 }
+
+// A query-engine filter: rows whose column value falls in a range are left-packed into the output
+// with one compressing store per vector instead of a branch per row.
+#[test]
+fn real_world_filter_between_i32_matches_reference() {
+    let values = (0..500)
+        .map(|i: i32| (i.wrapping_mul(7919) % 1000) - 300)
+        .collect::<Vec<_>>();
+
+    assert_filter_between_matches_all_backends(&[], 0, 10);
+    assert_filter_between_matches_all_backends(&values[..13], -100, 400);
+    assert_filter_between_matches_all_backends(&values, -100, 400);
+    assert_filter_between_matches_all_backends(&values, i32::MIN, i32::MAX);
+    assert_filter_between_matches_all_backends(&values, 5, 5);
+}
@@ -48,8 +48,8 @@ simd_unsafe_generate_all!(
             let values = S::Vf32::load_from_slice(input);
             let magnitude = values.abs();
             let attenuated = values.mul(attenuation);
-            let attenuated_or_silent = magnitude.cmp_gt(low).blendv(zero, attenuated);
-            let gated = magnitude.cmp_gt(high).blendv(attenuated_or_silent, values);
+            let attenuated_or_silent = magnitude.cmp_gt(low).select(attenuated, zero);
+            let gated = magnitude.cmp_gt(high).select(values, attenuated_or_silent);
             gated.copy_to_slice(output);
 
             input = &input[S::Vf32::WIDTH..];
@@ -147,6 +147,11 @@ fn assert_noise_gate_matches_all_backends(input: &[f32]) {
             unsafe { noise_gate_f32_sse41(input, &mut output) };
             assert_f32_slices_close(&output, &expected);
         }
+        if std::arch::is_x86_feature_detected!("avx") {
+            let mut output = vec![0.0; input.len()];
+            unsafe { noise_gate_f32_avx(input, &mut output) };
+            assert_f32_slices_close(&output, &expected);
+        }
         if std::arch::is_x86_feature_detected!("avx2") && std::arch::is_x86_feature_detected!("fma")
         {
             let mut output = vec![0.0; input.len()];
@@ -175,6 +180,11 @@ fn assert_crossfade_matches_all_backends(lhs: &[f32], rhs: &[f32], weights: &[f3
             unsafe { crossfade_f32_sse41(lhs, rhs, weights, &mut output) };
             assert_f32_slices_close(&output, &expected);
         }
+        if std::arch::is_x86_feature_detected!("avx") {
+            let mut output = vec![0.0; lhs.len()];
+            unsafe { crossfade_f32_avx(lhs, rhs, weights, &mut output) };
+            assert_f32_slices_close(&output, &expected);
+        }
         if std::arch::is_x86_feature_detected!("avx2") && std::arch::is_x86_feature_detected!("fma")
         {
             let mut output = vec![0.0; lhs.len()];
@@ -196,6 +206,9 @@ fn assert_window_energy_matches_all_backends(input: &[f32]) {
         if std::arch::is_x86_feature_detected!("sse4.1") {
             assert_f32_close(unsafe { window_energy_f32_sse41(input) }, expected);
         }
+        if std::arch::is_x86_feature_detected!("avx") {
+            assert_f32_close(unsafe { window_energy_f32_avx(input) }, expected);
+        }
         if std::arch::is_x86_feature_detected!("avx2") && std::arch::is_x86_feature_detected!("fma")
         {
             assert_f32_close(unsafe { window_energy_f32_avx2(input) }, expected);
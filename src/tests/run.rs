@@ -4,6 +4,8 @@ use crate::elementwise_eq_tester;
 
 use super::*;
 
+#[cfg(any(target_arch = "x86_64", target_arch = "x86"))]
+use crate::engines::avx::Avx;
 #[cfg(any(target_arch = "x86_64", target_arch = "x86"))]
 use crate::engines::avx512::Avx512;
 #[cfg(target_arch = "aarch64")]
@@ -16,6 +18,31 @@ use crate::engines::{avx2::*, sse2::*, sse41::*};
 
 use crate::*;
 
+/// Compare functions that expand their mask into lane vectors, so they can be checked lane by
+/// lane against the scalar engine.
+trait CompareLanes: SimdBaseOps {
+    fn cmp_eq(self, rhs: Self) -> Self;
+    fn cmp_neq(self, rhs: Self) -> Self;
+    fn cmp_lt(self, rhs: Self) -> Self;
+    fn cmp_lte(self, rhs: Self) -> Self;
+    fn cmp_gt(self, rhs: Self) -> Self;
+    fn cmp_gte(self, rhs: Self) -> Self;
+}
+
+macro_rules! impl_compare_lanes {
+    ($($name:ident),*) => {
+        impl<T: SimdBaseOps> CompareLanes for T {
+            $(
+                fn $name(self, rhs: Self) -> Self {
+                    SimdBaseOps::$name(self, rhs).to_vector()
+                }
+            )*
+        }
+    };
+}
+
+impl_compare_lanes!(cmp_eq, cmp_neq, cmp_lt, cmp_lte, cmp_gt, cmp_gte);
+
 elementwise_eq_tester_impl!(SimdBaseOps, add, two_arg, EqPrecision::exact());
 elementwise_eq_tester_impl!(SimdBaseOps, sub, two_arg, EqPrecision::exact());
 elementwise_eq_tester_impl!(SimdBaseOps, mul, two_arg, EqPrecision::exact());
@@ -29,15 +56,15 @@ elementwise_eq_tester_impl!(SimdBaseOps, and_not, two_arg, EqPrecision::exact())
 // Abs for signed integers uses wrapping semantics so MIN stays MIN.
 elementwise_eq_tester_impl!(SimdBaseOps, abs, one_arg, EqPrecision::exact());
 
-elementwise_eq_tester_impl!(SimdBaseOps, cmp_eq, two_arg, EqPrecision::exact());
-elementwise_eq_tester_impl!(SimdBaseOps, cmp_lt, two_arg, EqPrecision::exact());
-elementwise_eq_tester_impl!(SimdBaseOps, cmp_lte, two_arg, EqPrecision::exact());
-elementwise_eq_tester_impl!(SimdBaseOps, cmp_gt, two_arg, EqPrecision::exact());
-elementwise_eq_tester_impl!(SimdBaseOps, cmp_gte, two_arg, EqPrecision::exact());
+elementwise_eq_tester_impl!(CompareLanes, cmp_eq, two_arg, EqPrecision::exact());
+elementwise_eq_tester_impl!(CompareLanes, cmp_lt, two_arg, EqPrecision::exact());
+elementwise_eq_tester_impl!(CompareLanes, cmp_lte, two_arg, EqPrecision::exact());
+elementwise_eq_tester_impl!(CompareLanes, cmp_gt, two_arg, EqPrecision::exact());
+elementwise_eq_tester_impl!(CompareLanes, cmp_gte, two_arg, EqPrecision::exact());
 
 elementwise_eq_tester_impl!(SimdBaseOps, blendv, iter_blendv_ags, EqPrecision::exact());
 
-elementwise_eq_tester_impl!(SimdBaseOps, cmp_neq, two_arg, EqPrecision::exact());
+elementwise_eq_tester_impl!(CompareLanes, cmp_neq, two_arg, EqPrecision::exact());
 
 elementwise_eq_tester_impl!(SimdBaseOps, max, two_arg_nan_filtered, EqPrecision::exact());
 elementwise_eq_tester_impl!(SimdBaseOps, min, two_arg_nan_filtered, EqPrecision::exact());
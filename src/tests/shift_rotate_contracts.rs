@@ -0,0 +1,130 @@
+#![allow(dead_code, unused_parens)]
+
+use crate::prelude::*;
+
+use super::check_all_engines;
+
+macro_rules! test_shifts_and_rotates {
+    ($name:ident, $simd_ty:ident, $scalar_ty:ty, $signed_ty:ty, $unsigned_ty:ty) => {
+        mod $name {
+            use super::*;
+
+            const RESULTS: usize = 11;
+
+            simd_unsafe_generate_all!(
+                fn shifts(a: &[$scalar_ty], counts: &[$scalar_ty]) -> Vec<$scalar_ty> {
+                    let a = S::$simd_ty::load_from_slice(a);
+                    let counts = S::$simd_ty::load_from_slice(counts);
+                    let mut out = vec![0; RESULTS * S::$simd_ty::WIDTH];
+                    let results = [
+                        a.shr_logical(3),
+                        a.shr_arithmetic(3),
+                        a.shr_arithmetic(-1),
+                        a.rotate_left(5),
+                        a.rotate_right(-3),
+                        a.rotate_left_const::<1>(),
+                        a.rotate_right_const::<7>(),
+                        a.shl_lanes(counts),
+                        a.shr_lanes(counts),
+                        a.shr_arithmetic_lanes(counts),
+                        a.rotate_left_lanes(counts)
+                            .rotate_right_lanes(counts.shl(1)),
+                    ];
+                    for (result, chunk) in results.iter().zip(out.chunks_mut(S::$simd_ty::WIDTH)) {
+                        result.copy_to_slice(chunk);
+                    }
+                    out
+                }
+            );
+
+            fn values(seed: usize, width: usize) -> Vec<$scalar_ty> {
+                let pattern: [$scalar_ty; 8] = [
+                    0,
+                    1,
+                    <$scalar_ty>::MAX,
+                    <$scalar_ty>::MIN,
+                    (0x5A5A_5A5A_5A5A_5A5Au64 as $scalar_ty),
+                    (0xC3C3_C3C3_C3C3_C3C3u64 as $scalar_ty),
+                    (0x8001_8001_8001_8001u64 as $scalar_ty),
+                    (0x0123_4567_89AB_CDEFu64 as $scalar_ty),
+                ];
+                (0..width)
+                    .map(|i| pattern[(i * 3 + seed) % pattern.len()])
+                    .collect()
+            }
+
+            // Counts cover zero, the lane width, values past it and negative bit patterns.
+            fn counts(seed: usize, width: usize) -> Vec<$scalar_ty> {
+                (0..width)
+                    .map(|i| ((i * 37 + seed * 11) % 256) as $scalar_ty)
+                    .collect()
+            }
+
+            fn arithmetic(x: $scalar_ty, n: u32) -> $scalar_ty {
+                (x as $signed_ty).wrapping_shr(n) as $scalar_ty
+            }
+
+            fn logical(x: $scalar_ty, n: u32) -> $scalar_ty {
+                (x as $unsigned_ty).wrapping_shr(n) as $scalar_ty
+            }
+
+            fn check(
+                width: usize,
+                shifts: impl Fn(&[$scalar_ty], &[$scalar_ty]) -> Vec<$scalar_ty>,
+            ) {
+                for seed in 0..8 {
+                    let a = values(seed, width);
+                    let counts = counts(seed, width);
+                    let out = shifts(&a, &counts);
+
+                    let map = |f: &dyn Fn($scalar_ty) -> $scalar_ty| {
+                        a.iter().map(|&x| f(x)).collect::<Vec<_>>()
+                    };
+                    let zip = |f: &dyn Fn($scalar_ty, u32) -> $scalar_ty| {
+                        a.iter()
+                            .zip(&counts)
+                            .map(|(&x, &n)| f(x, n as u32))
+                            .collect::<Vec<_>>()
+                    };
+                    let expected: Vec<$scalar_ty> = [
+                        map(&|x| logical(x, 3)),
+                        map(&|x| arithmetic(x, 3)),
+                        map(&|x| arithmetic(x, u32::MAX)),
+                        map(&|x| x.rotate_left(5)),
+                        map(&|x| x.rotate_right((-3i32) as u32)),
+                        map(&|x| x.rotate_left(1)),
+                        map(&|x| x.rotate_right(7)),
+                        zip(&|x, n| x.wrapping_shl(n)),
+                        zip(&logical),
+                        zip(&arithmetic),
+                        zip(&|x, n| x.rotate_left(n).rotate_right(n.wrapping_shl(1))),
+                    ]
+                    .concat();
+                    assert_eq!(out.len(), RESULTS * width);
+                    for (result, (out, expected)) in
+                        out.chunks(width).zip(expected.chunks(width)).enumerate()
+                    {
+                        assert_eq!(
+                            out, expected,
+                            "result {result} mismatch for {a:?} with counts {counts:?}"
+                        );
+                    }
+                }
+            }
+
+            #[test]
+            fn shifts_and_rotates_match_scalar() {
+                check_all_engines!(check, shifts, $simd_ty);
+            }
+        }
+    };
+}
+
+test_shifts_and_rotates!(i8_lanes, Vi8, i8, i8, u8);
+test_shifts_and_rotates!(i16_lanes, Vi16, i16, i16, u16);
+test_shifts_and_rotates!(i32_lanes, Vi32, i32, i32, u32);
+test_shifts_and_rotates!(i64_lanes, Vi64, i64, i64, u64);
+test_shifts_and_rotates!(u8_lanes, Vu8, u8, i8, u8);
+test_shifts_and_rotates!(u16_lanes, Vu16, u16, i16, u16);
+test_shifts_and_rotates!(u32_lanes, Vu32, u32, i32, u32);
+test_shifts_and_rotates!(u64_lanes, Vu64, u64, i64, u64);
@@ -37,6 +37,24 @@ fn run_f32_exp_u35_contract<S: Simd>() {
     );
 }
 
+fn run_f32_ln_u10_contract<S: Simd>() {
+    check_unary_f32::<S>(
+        "ln_u10",
+        contracts::LN_U10_F32_MAX_ULP,
+        |v| v.ln_u10(),
+        f32::ln,
+    );
+}
+
+fn run_f32_exp_u10_contract<S: Simd>() {
+    check_unary_f32::<S>(
+        "exp_u10",
+        contracts::EXP_U10_F32_MAX_ULP,
+        |v| v.exp_u10(),
+        f32::exp,
+    );
+}
+
 fn run_f32_sin_u35_contract<S: Simd>() {
     check_unary_f32::<S>(
         "sin_u35",
@@ -100,6 +118,24 @@ fn run_f64_exp_u35_contract<S: Simd>() {
     );
 }
 
+fn run_f64_ln_u10_contract<S: Simd>() {
+    check_unary_f64::<S>(
+        "ln_u10",
+        contracts::LN_U10_F64_MAX_ULP,
+        |v| v.ln_u10(),
+        f64::ln,
+    );
+}
+
+fn run_f64_exp_u10_contract<S: Simd>() {
+    check_unary_f64::<S>(
+        "exp_u10",
+        contracts::EXP_U10_F64_MAX_ULP,
+        |v| v.exp_u10(),
+        f64::exp,
+    );
+}
+
 fn run_f64_sin_u35_contract<S: Simd>() {
     check_unary_f64::<S>(
         "sin_u35",
@@ -127,10 +163,248 @@ fn run_f64_tan_u35_contract<S: Simd>() {
     );
 }
 
+fn run_f32_pow_u35_contract<S: Simd>() {
+    check_binary_f32::<S>(
+        "pow_u35",
+        contracts::POW_U35_F32_MAX_ULP,
+        |x, y| x.pow_u35(y),
+        f32::powf,
+    );
+}
+
+fn run_f32_powi_contract<S: Simd>() {
+    for exponent in [-33, -16, -7, -2, -1, 0, 1, 2, 3, 5, 16, 17, 64] {
+        check_unary_f32::<S>(
+            "powi",
+            contracts::POWI_F32_MAX_ULP,
+            |v| v.powi(exponent),
+            |x| f64::from(x).powi(exponent) as f32,
+        );
+    }
+}
+
+fn run_f32_cbrt_u35_contract<S: Simd>() {
+    check_unary_f32::<S>(
+        "cbrt_u35",
+        contracts::CBRT_U35_F32_MAX_ULP,
+        |v| v.cbrt_u35(),
+        f32::cbrt,
+    );
+}
+
+fn run_f32_exp10_u35_contract<S: Simd>() {
+    check_unary_f32::<S>(
+        "exp10_u35",
+        contracts::EXP10_U35_F32_MAX_ULP,
+        |v| v.exp10_u35(),
+        |x| 10f64.powf(f64::from(x)) as f32,
+    );
+}
+
+fn run_f32_expm1_u35_contract<S: Simd>() {
+    check_unary_f32::<S>(
+        "expm1_u35",
+        contracts::EXPM1_U35_F32_MAX_ULP,
+        |v| v.expm1_u35(),
+        f32::exp_m1,
+    );
+}
+
+fn run_f32_log1p_u35_contract<S: Simd>() {
+    check_unary_f32::<S>(
+        "log1p_u35",
+        contracts::LOG1P_U35_F32_MAX_ULP,
+        |v| v.log1p_u35(),
+        f32::ln_1p,
+    );
+}
+
+fn run_f64_pow_u35_contract<S: Simd>() {
+    check_binary_f64::<S>(
+        "pow_u35",
+        contracts::POW_U35_F64_MAX_ULP,
+        |x, y| x.pow_u35(y),
+        f64::powf,
+    );
+}
+
+fn run_f64_powi_contract<S: Simd>() {
+    for exponent in [-33, -16, -7, -2, -1, 0, 1, 2, 3, 5, 16, 17, 64] {
+        check_unary_f64::<S>(
+            "powi",
+            contracts::POWI_F64_MAX_ULP,
+            |v| v.powi(exponent),
+            // `powf` lets signaling NaNs escape `x^0 == 1`; `powi` does not.
+            |x| {
+                if exponent == 0 {
+                    1.0
+                } else {
+                    x.powf(f64::from(exponent))
+                }
+            },
+        );
+    }
+}
+
+fn run_f64_cbrt_u35_contract<S: Simd>() {
+    check_unary_f64::<S>(
+        "cbrt_u35",
+        contracts::CBRT_U35_F64_MAX_ULP,
+        |v| v.cbrt_u35(),
+        f64::cbrt,
+    );
+}
+
+fn run_f64_exp10_u35_contract<S: Simd>() {
+    check_unary_f64::<S>(
+        "exp10_u35",
+        contracts::EXP10_U35_F64_MAX_ULP,
+        |v| v.exp10_u35(),
+        |x| 10f64.powf(x),
+    );
+}
+
+fn run_f64_expm1_u35_contract<S: Simd>() {
+    check_unary_f64::<S>(
+        "expm1_u35",
+        contracts::EXPM1_U35_F64_MAX_ULP,
+        |v| v.expm1_u35(),
+        f64::exp_m1,
+    );
+}
+
+fn run_f64_log1p_u35_contract<S: Simd>() {
+    check_unary_f64::<S>(
+        "log1p_u35",
+        contracts::LOG1P_U35_F64_MAX_ULP,
+        |v| v.log1p_u35(),
+        f64::ln_1p,
+    );
+}
+
+/// `(sin(pi * x), cos(pi * x))` from std `sin`/`cos` after an exact half-turn reduction, so
+/// integers and half-integers produce exact zeros and ones with the IEEE 754 signs.
+fn sin_cos_pi_reference(x: f64) -> (f64, f64) {
+    if !x.is_finite() {
+        return (f64::NAN, f64::NAN);
+    }
+
+    let reduced = x.abs() % 2.0;
+    let half_turns = (reduced * 2.0).round();
+    let r = (reduced - half_turns * 0.5) * ::core::f64::consts::PI;
+    let (s, c) = match half_turns as u8 & 3 {
+        0 => (r.sin(), r.cos()),
+        1 => (r.cos(), -r.sin()),
+        2 => (-r.sin(), -r.cos()),
+        _ => (-r.cos(), r.sin()),
+    };
+    let (s, c) = (s + 0.0, c + 0.0);
+    if x.is_sign_negative() {
+        (-s, c)
+    } else {
+        (s, c)
+    }
+}
+
+fn tan_pi_reference(x: f64) -> f64 {
+    let (s, c) = sin_cos_pi_reference(x.abs());
+    if x.is_sign_negative() {
+        -(s / c)
+    } else {
+        s / c
+    }
+}
+
+fn run_f32_sincos_u35_contract<S: Simd>() {
+    check_unary_f32::<S>(
+        "sincos_u35.0",
+        contracts::SINCOS_U35_F32_MAX_ULP,
+        |v| v.sincos_u35().0,
+        f32::sin,
+    );
+    check_unary_f32::<S>(
+        "sincos_u35.1",
+        contracts::SINCOS_U35_F32_MAX_ULP,
+        |v| v.sincos_u35().1,
+        f32::cos,
+    );
+}
+
+fn run_f32_sinpi_u35_contract<S: Simd>() {
+    check_unary_f32::<S>(
+        "sinpi_u35",
+        contracts::SINPI_U35_F32_MAX_ULP,
+        |v| v.sinpi_u35(),
+        |x| sin_cos_pi_reference(f64::from(x)).0 as f32,
+    );
+}
+
+fn run_f32_cospi_u35_contract<S: Simd>() {
+    check_unary_f32::<S>(
+        "cospi_u35",
+        contracts::COSPI_U35_F32_MAX_ULP,
+        |v| v.cospi_u35(),
+        |x| sin_cos_pi_reference(f64::from(x)).1 as f32,
+    );
+}
+
+fn run_f32_tanpi_u35_contract<S: Simd>() {
+    check_unary_f32::<S>(
+        "tanpi_u35",
+        contracts::TANPI_U35_F32_MAX_ULP,
+        |v| v.tanpi_u35(),
+        |x| tan_pi_reference(f64::from(x)) as f32,
+    );
+}
+
+fn run_f64_sincos_u35_contract<S: Simd>() {
+    check_unary_f64::<S>(
+        "sincos_u35.0",
+        contracts::SINCOS_U35_F64_MAX_ULP,
+        |v| v.sincos_u35().0,
+        f64::sin,
+    );
+    check_unary_f64::<S>(
+        "sincos_u35.1",
+        contracts::SINCOS_U35_F64_MAX_ULP,
+        |v| v.sincos_u35().1,
+        f64::cos,
+    );
+}
+
+fn run_f64_sinpi_u35_contract<S: Simd>() {
+    check_unary_f64::<S>(
+        "sinpi_u35",
+        contracts::SINPI_U35_F64_MAX_ULP,
+        |v| v.sinpi_u35(),
+        |x| sin_cos_pi_reference(x).0,
+    );
+}
+
+fn run_f64_cospi_u35_contract<S: Simd>() {
+    check_unary_f64::<S>(
+        "cospi_u35",
+        contracts::COSPI_U35_F64_MAX_ULP,
+        |v| v.cospi_u35(),
+        |x| sin_cos_pi_reference(x).1,
+    );
+}
+
+fn run_f64_tanpi_u35_contract<S: Simd>() {
+    check_unary_f64::<S>(
+        "tanpi_u35",
+        contracts::TANPI_U35_F64_MAX_ULP,
+        |v| v.tanpi_u35(),
+        tan_pi_reference,
+    );
+}
+
 simd_math_all_backends!(f32_log2_u35_contract, run_f32_log2_u35_contract);
 simd_math_all_backends!(f32_exp2_u35_contract, run_f32_exp2_u35_contract);
 simd_math_all_backends!(f32_ln_u35_contract, run_f32_ln_u35_contract);
 simd_math_all_backends!(f32_exp_u35_contract, run_f32_exp_u35_contract);
+simd_math_all_backends!(f32_ln_u10_contract, run_f32_ln_u10_contract);
+simd_math_all_backends!(f32_exp_u10_contract, run_f32_exp_u10_contract);
 simd_math_all_backends!(f32_sin_u35_contract, run_f32_sin_u35_contract);
 simd_math_all_backends!(f32_cos_u35_contract, run_f32_cos_u35_contract);
 simd_math_all_backends!(f32_tan_u35_contract, run_f32_tan_u35_contract);
@@ -138,6 +412,28 @@ simd_math_all_backends!(f64_log2_u35_contract, run_f64_log2_u35_contract);
 simd_math_all_backends!(f64_exp2_u35_contract, run_f64_exp2_u35_contract);
 simd_math_all_backends!(f64_ln_u35_contract, run_f64_ln_u35_contract);
 simd_math_all_backends!(f64_exp_u35_contract, run_f64_exp_u35_contract);
+simd_math_all_backends!(f64_ln_u10_contract, run_f64_ln_u10_contract);
+simd_math_all_backends!(f64_exp_u10_contract, run_f64_exp_u10_contract);
 simd_math_all_backends!(f64_sin_u35_contract, run_f64_sin_u35_contract);
 simd_math_all_backends!(f64_cos_u35_contract, run_f64_cos_u35_contract);
 simd_math_all_backends!(f64_tan_u35_contract, run_f64_tan_u35_contract);
+simd_math_all_backends!(f32_pow_u35_contract, run_f32_pow_u35_contract);
+simd_math_all_backends!(f32_powi_contract, run_f32_powi_contract);
+simd_math_all_backends!(f32_cbrt_u35_contract, run_f32_cbrt_u35_contract);
+simd_math_all_backends!(f32_exp10_u35_contract, run_f32_exp10_u35_contract);
+simd_math_all_backends!(f32_expm1_u35_contract, run_f32_expm1_u35_contract);
+simd_math_all_backends!(f32_log1p_u35_contract, run_f32_log1p_u35_contract);
+simd_math_all_backends!(f64_pow_u35_contract, run_f64_pow_u35_contract);
+simd_math_all_backends!(f64_powi_contract, run_f64_powi_contract);
+simd_math_all_backends!(f64_cbrt_u35_contract, run_f64_cbrt_u35_contract);
+simd_math_all_backends!(f64_exp10_u35_contract, run_f64_exp10_u35_contract);
+simd_math_all_backends!(f64_expm1_u35_contract, run_f64_expm1_u35_contract);
+simd_math_all_backends!(f64_log1p_u35_contract, run_f64_log1p_u35_contract);
+simd_math_all_backends!(f32_sincos_u35_contract, run_f32_sincos_u35_contract);
+simd_math_all_backends!(f32_sinpi_u35_contract, run_f32_sinpi_u35_contract);
+simd_math_all_backends!(f32_cospi_u35_contract, run_f32_cospi_u35_contract);
+simd_math_all_backends!(f32_tanpi_u35_contract, run_f32_tanpi_u35_contract);
+simd_math_all_backends!(f64_sincos_u35_contract, run_f64_sincos_u35_contract);
+simd_math_all_backends!(f64_sinpi_u35_contract, run_f64_sinpi_u35_contract);
+simd_math_all_backends!(f64_cospi_u35_contract, run_f64_cospi_u35_contract);
+simd_math_all_backends!(f64_tanpi_u35_contract, run_f64_tanpi_u35_contract);
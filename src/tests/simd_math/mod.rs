@@ -13,7 +13,7 @@ use crate::engines::scalar::Scalar;
 #[cfg(target_arch = "wasm32")]
 use crate::engines::wasm32::Wasm;
 #[cfg(any(target_arch = "x86_64", target_arch = "x86"))]
-use crate::engines::{avx2::Avx2, sse2::Sse2, sse41::Sse41};
+use crate::engines::{avx::Avx, avx2::Avx2, sse2::Sse2, sse41::Sse41};
 
 use crate::math::{contracts, SimdMathF32, SimdMathF64};
 use crate::{Simd, SimdBaseIo, SimdConsts};
@@ -303,6 +303,7 @@ macro_rules! simd_math_all_backends {
         simd_math_backend_test!($name, Scalar, $runner);
         simd_math_backend_test!($name, Avx512, $runner);
         simd_math_backend_test!($name, Avx2, $runner);
+        simd_math_backend_test!($name, Avx, $runner);
         simd_math_backend_test!($name, Sse2, $runner);
         simd_math_backend_test!($name, Sse41, $runner);
         simd_math_backend_test!($name, Neon, $runner);
@@ -315,3 +316,5 @@ mod core;
 mod hyperbolic;
 mod inverse_hyperbolic;
 mod inverse_trig;
+#[cfg(feature = "special")]
+mod special;
@@ -0,0 +1,83 @@
+use super::*;
+use crate::math::{SimdMathF32Special, SimdMathF64Special};
+
+fn run_f32_erf_u35_contract<S: Simd>() {
+    check_unary_f32::<S>(
+        "erf_u35",
+        contracts::ERF_U35_F32_MAX_ULP,
+        |v| v.erf_u35(),
+        libm::erff,
+    );
+}
+
+fn run_f32_erfc_u35_contract<S: Simd>() {
+    check_unary_f32::<S>(
+        "erfc_u35",
+        contracts::ERFC_U35_F32_MAX_ULP,
+        |v| v.erfc_u35(),
+        libm::erfcf,
+    );
+}
+
+fn run_f32_tgamma_u35_contract<S: Simd>() {
+    check_unary_f32::<S>(
+        "tgamma_u35",
+        contracts::TGAMMA_U35_F32_MAX_ULP,
+        |v| v.tgamma_u35(),
+        libm::tgammaf,
+    );
+}
+
+fn run_f32_lgamma_u35_contract<S: Simd>() {
+    check_unary_f32::<S>(
+        "lgamma_u35",
+        contracts::LGAMMA_U35_F32_MAX_ULP,
+        |v| v.lgamma_u35(),
+        libm::lgammaf,
+    );
+}
+
+fn run_f64_erf_u35_contract<S: Simd>() {
+    check_unary_f64::<S>(
+        "erf_u35",
+        contracts::ERF_U35_F64_MAX_ULP,
+        |v| v.erf_u35(),
+        libm::erf,
+    );
+}
+
+fn run_f64_erfc_u35_contract<S: Simd>() {
+    check_unary_f64::<S>(
+        "erfc_u35",
+        contracts::ERFC_U35_F64_MAX_ULP,
+        |v| v.erfc_u35(),
+        libm::erfc,
+    );
+}
+
+fn run_f64_tgamma_u35_contract<S: Simd>() {
+    check_unary_f64::<S>(
+        "tgamma_u35",
+        contracts::TGAMMA_U35_F64_MAX_ULP,
+        |v| v.tgamma_u35(),
+        libm::tgamma,
+    );
+}
+
+fn run_f64_lgamma_u35_contract<S: Simd>() {
+    check_unary_f64::<S>(
+        "lgamma_u35",
+        contracts::LGAMMA_U35_F64_MAX_ULP,
+        |v| v.lgamma_u35(),
+        libm::lgamma,
+    );
+}
+
+simd_math_all_backends!(f32_erf_u35_contract, run_f32_erf_u35_contract);
+simd_math_all_backends!(f32_erfc_u35_contract, run_f32_erfc_u35_contract);
+simd_math_all_backends!(f32_tgamma_u35_contract, run_f32_tgamma_u35_contract);
+simd_math_all_backends!(f32_lgamma_u35_contract, run_f32_lgamma_u35_contract);
+simd_math_all_backends!(f64_erf_u35_contract, run_f64_erf_u35_contract);
+simd_math_all_backends!(f64_erfc_u35_contract, run_f64_erfc_u35_contract);
+simd_math_all_backends!(f64_tgamma_u35_contract, run_f64_tgamma_u35_contract);
+simd_math_all_backends!(f64_lgamma_u35_contract, run_f64_lgamma_u35_contract);
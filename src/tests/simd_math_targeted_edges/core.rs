@@ -336,6 +336,13 @@ fn run_f32_log_exp_boundary_lanes<S: Simd>() {
         |v| v.ln_u35(),
         f32::ln,
     );
+    check_targeted_unary_f32::<S>(
+        "ln_u10",
+        &inputs_log,
+        contracts::LN_U10_F32_MAX_ULP,
+        |v| v.ln_u10(),
+        f32::ln,
+    );
 
     let mut inputs_exp = vec![
         -126.0f32,
@@ -390,7 +397,9 @@ fn run_f32_log_exp_boundary_lanes<S: Simd>() {
         f32::NAN,
     ];
 
-    for &center in &[-104.0f32, -103.97, -1.0, 0.0, 1.0, 88.5, 88.7] {
+    for &center in &[
+        -104.0f32, -103.97, -87.0, -1.0, -0.5, 0.0, 0.5, 1.0, 87.0, 88.5, 88.7,
+    ] {
         inputs_exp_e.push(f32::from_bits(center.to_bits().saturating_sub(1)));
         inputs_exp_e.push(center);
         inputs_exp_e.push(f32::from_bits(center.to_bits().saturating_add(1)));
@@ -403,6 +412,13 @@ fn run_f32_log_exp_boundary_lanes<S: Simd>() {
         |v| v.exp_u35(),
         f32::exp,
     );
+    check_targeted_unary_f32::<S>(
+        "exp_u10",
+        &inputs_exp_e,
+        contracts::EXP_U10_F32_MAX_ULP,
+        |v| v.exp_u10(),
+        f32::exp,
+    );
 }
 
 simd_math_targeted_all_backends!(f32_log_exp_boundary_lanes, run_f32_log_exp_boundary_lanes);
@@ -705,6 +721,664 @@ simd_math_targeted_all_backends!(
     run_f64_trig_symmetry_identities
 );
 
+fn run_f32_pow_special_cases<S: Simd>() {
+    let bases = [
+        0.0f32,
+        -0.0,
+        1.0,
+        -1.0,
+        2.0,
+        -2.0,
+        0.5,
+        -0.5,
+        10.0,
+        f32::from_bits(1),
+        f32::MIN_POSITIVE,
+        f32::MAX,
+        f32::INFINITY,
+        f32::NEG_INFINITY,
+        f32::NAN,
+    ];
+    let exponents = [
+        0.0f32,
+        -0.0,
+        1.0,
+        -1.0,
+        2.0,
+        3.0,
+        -3.0,
+        0.5,
+        -0.5,
+        1.5,
+        128.0,
+        -150.0,
+        f32::INFINITY,
+        f32::NEG_INFINITY,
+        f32::NAN,
+    ];
+    let mut x_inputs = Vec::new();
+    let mut y_inputs = Vec::new();
+    for &x in &bases {
+        for &y in &exponents {
+            x_inputs.push(x);
+            y_inputs.push(y);
+        }
+    }
+
+    for (xs_chunk, ys_chunk) in x_inputs
+        .chunks(S::Vf32::WIDTH)
+        .zip(y_inputs.chunks(S::Vf32::WIDTH))
+    {
+        let xv = S::Vf32::load_from_slice(xs_chunk);
+        let yv = S::Vf32::load_from_slice(ys_chunk);
+        let out = xv.pow_u35(yv);
+        for lane in 0..xs_chunk.len() {
+            let x = xs_chunk[lane];
+            let y = ys_chunk[lane];
+            assert_f32_contract(
+                "pow_u35",
+                x,
+                out[lane],
+                x.powf(y),
+                contracts::POW_U35_F32_MAX_ULP,
+            )
+            .unwrap_or_else(|e| panic!("pow_u35({x:?}, {y:?}): {e}"));
+        }
+    }
+}
+
+fn run_f32_power_family_denormal_and_overflow_lanes<S: Simd>() {
+    let subnormals = [
+        f32::from_bits(1),
+        -f32::from_bits(1),
+        f32::from_bits(0x0000_0100),
+        f32::from_bits(0x007F_FFFF),
+        -f32::from_bits(0x007F_FFFF),
+        f32::MIN_POSITIVE,
+        -f32::MIN_POSITIVE,
+        0.0,
+        -0.0,
+    ];
+
+    check_targeted_unary_f32::<S>(
+        "cbrt_u35",
+        &subnormals,
+        contracts::CBRT_U35_F32_MAX_ULP,
+        |v| v.cbrt_u35(),
+        f32::cbrt,
+    );
+    check_targeted_unary_f32::<S>(
+        "log1p_u35",
+        &subnormals,
+        contracts::LOG1P_U35_F32_MAX_ULP,
+        |v| v.log1p_u35(),
+        f32::ln_1p,
+    );
+    check_targeted_unary_f32::<S>(
+        "expm1_u35",
+        &subnormals,
+        contracts::EXPM1_U35_F32_MAX_ULP,
+        |v| v.expm1_u35(),
+        f32::exp_m1,
+    );
+
+    let exp10_inputs = [
+        -46.0f32, -45.0, -38.0, -37.5, -37.0, -1.0, 0.0, 1.0, 37.0, 37.5, 38.0, 38.5, 39.0,
+    ];
+    check_targeted_unary_f32::<S>(
+        "exp10_u35",
+        &exp10_inputs,
+        contracts::EXP10_U35_F32_MAX_ULP,
+        |v| v.exp10_u35(),
+        |x| 10f64.powf(f64::from(x)) as f32,
+    );
+
+    let expm1_inputs = [
+        -104.0f32, -88.0, -87.0, -20.0, -1.0e-4, 1.0e-4, 87.0, 88.0, 88.72, 88.8, 100.0,
+    ];
+    check_targeted_unary_f32::<S>(
+        "expm1_u35",
+        &expm1_inputs,
+        contracts::EXPM1_U35_F32_MAX_ULP,
+        |v| v.expm1_u35(),
+        f32::exp_m1,
+    );
+
+    let log1p_inputs = [
+        -1.0f32,
+        f32::from_bits((-1.0f32).to_bits() - 1),
+        -0.5,
+        -1.0e-6,
+        1.0e-6,
+        1.0e30,
+        f32::MAX,
+        -2.0,
+    ];
+    check_targeted_unary_f32::<S>(
+        "log1p_u35",
+        &log1p_inputs,
+        contracts::LOG1P_U35_F32_MAX_ULP,
+        |v| v.log1p_u35(),
+        f32::ln_1p,
+    );
+
+    let powi_inputs = [
+        1.0e10f32,
+        -1.0e10,
+        1.0e-10,
+        -1.0e-10,
+        f32::from_bits(1),
+        f32::MIN_POSITIVE,
+        f32::MAX,
+        0.0,
+        -0.0,
+        f32::INFINITY,
+        f32::NEG_INFINITY,
+    ];
+    for exponent in [-17, -4, -3, 3, 4, 17] {
+        check_targeted_unary_f32::<S>(
+            "powi",
+            &powi_inputs,
+            contracts::POWI_F32_MAX_ULP,
+            |v| v.powi(exponent),
+            |x| f64::from(x).powi(exponent) as f32,
+        );
+    }
+}
+
+simd_math_targeted_all_backends!(f32_pow_special_cases, run_f32_pow_special_cases);
+simd_math_targeted_all_backends!(
+    f32_power_family_denormal_and_overflow_lanes,
+    run_f32_power_family_denormal_and_overflow_lanes
+);
+
+fn run_f64_pow_special_cases<S: Simd>() {
+    let bases = [
+        0.0f64,
+        -0.0,
+        1.0,
+        -1.0,
+        2.0,
+        -2.0,
+        0.5,
+        -0.5,
+        10.0,
+        f64::from_bits(1),
+        f64::MIN_POSITIVE,
+        f64::MAX,
+        f64::INFINITY,
+        f64::NEG_INFINITY,
+        f64::NAN,
+    ];
+    let exponents = [
+        0.0f64,
+        -0.0,
+        1.0,
+        -1.0,
+        2.0,
+        3.0,
+        -3.0,
+        0.5,
+        -0.5,
+        1.5,
+        1024.0,
+        -1075.0,
+        f64::INFINITY,
+        f64::NEG_INFINITY,
+        f64::NAN,
+    ];
+    let mut x_inputs = Vec::new();
+    let mut y_inputs = Vec::new();
+    for &x in &bases {
+        for &y in &exponents {
+            x_inputs.push(x);
+            y_inputs.push(y);
+        }
+    }
+
+    for (xs_chunk, ys_chunk) in x_inputs
+        .chunks(S::Vf64::WIDTH)
+        .zip(y_inputs.chunks(S::Vf64::WIDTH))
+    {
+        let xv = S::Vf64::load_from_slice(xs_chunk);
+        let yv = S::Vf64::load_from_slice(ys_chunk);
+        let out = xv.pow_u35(yv);
+        for lane in 0..xs_chunk.len() {
+            let x = xs_chunk[lane];
+            let y = ys_chunk[lane];
+            assert_f64_contract(
+                "pow_u35",
+                x,
+                out[lane],
+                x.powf(y),
+                contracts::POW_U35_F64_MAX_ULP,
+            )
+            .unwrap_or_else(|e| panic!("pow_u35({x:?}, {y:?}): {e}"));
+        }
+    }
+}
+
+fn run_f64_power_family_denormal_and_overflow_lanes<S: Simd>() {
+    let subnormals = [
+        f64::from_bits(1),
+        -f64::from_bits(1),
+        f64::from_bits(0x0000_0000_0001_0000),
+        f64::from_bits(0x000F_FFFF_FFFF_FFFF),
+        -f64::from_bits(0x000F_FFFF_FFFF_FFFF),
+        f64::MIN_POSITIVE,
+        -f64::MIN_POSITIVE,
+        0.0,
+        -0.0,
+    ];
+
+    check_targeted_unary_f64::<S>(
+        "cbrt_u35",
+        &subnormals,
+        contracts::CBRT_U35_F64_MAX_ULP,
+        |v| v.cbrt_u35(),
+        f64::cbrt,
+    );
+    check_targeted_unary_f64::<S>(
+        "log1p_u35",
+        &subnormals,
+        contracts::LOG1P_U35_F64_MAX_ULP,
+        |v| v.log1p_u35(),
+        f64::ln_1p,
+    );
+    check_targeted_unary_f64::<S>(
+        "expm1_u35",
+        &subnormals,
+        contracts::EXPM1_U35_F64_MAX_ULP,
+        |v| v.expm1_u35(),
+        f64::exp_m1,
+    );
+
+    let exp10_inputs = [
+        -324.0f64, -323.0, -308.0, -307.0, -1.0, 0.0, 1.0, 307.0, 308.0, 308.3, 309.0,
+    ];
+    check_targeted_unary_f64::<S>(
+        "exp10_u35",
+        &exp10_inputs,
+        contracts::EXP10_U35_F64_MAX_ULP,
+        |v| v.exp10_u35(),
+        |x| 10f64.powf(x),
+    );
+
+    let expm1_inputs = [
+        -746.0f64, -709.0, -708.0, -40.0, -1.0e-9, 1.0e-9, 709.0, 709.7, 709.8, 800.0,
+    ];
+    check_targeted_unary_f64::<S>(
+        "expm1_u35",
+        &expm1_inputs,
+        contracts::EXPM1_U35_F64_MAX_ULP,
+        |v| v.expm1_u35(),
+        f64::exp_m1,
+    );
+
+    let log1p_inputs = [
+        -1.0f64,
+        f64::from_bits((-1.0f64).to_bits() - 1),
+        -0.5,
+        -1.0e-12,
+        1.0e-12,
+        1.0e300,
+        f64::MAX,
+        -2.0,
+    ];
+    check_targeted_unary_f64::<S>(
+        "log1p_u35",
+        &log1p_inputs,
+        contracts::LOG1P_U35_F64_MAX_ULP,
+        |v| v.log1p_u35(),
+        f64::ln_1p,
+    );
+
+    let powi_inputs = [
+        1.0e100f64,
+        -1.0e100,
+        1.0e-100,
+        -1.0e-100,
+        f64::from_bits(1),
+        f64::MIN_POSITIVE,
+        f64::MAX,
+        0.0,
+        -0.0,
+        f64::INFINITY,
+        f64::NEG_INFINITY,
+    ];
+    for exponent in [-17, -4, -3, 3, 4, 17] {
+        check_targeted_unary_f64::<S>(
+            "powi",
+            &powi_inputs,
+            contracts::POWI_F64_MAX_ULP,
+            |v| v.powi(exponent),
+            |x| x.powf(f64::from(exponent)),
+        );
+    }
+}
+
+simd_math_targeted_all_backends!(f64_pow_special_cases, run_f64_pow_special_cases);
+simd_math_targeted_all_backends!(
+    f64_power_family_denormal_and_overflow_lanes,
+    run_f64_power_family_denormal_and_overflow_lanes
+);
+
+// 2^24 + 1 is odd, but rounds to the even 2^24 as an `f32`, which must not flip the sign of a
+// negative base.
+const POWI_ODD_BEYOND_F32: i32 = (1 << 24) + 1;
+
+fn run_powi_odd_exponents_beyond_f32_precision<S: Simd>() {
+    let f32_inputs = [
+        -1.0f32,
+        -(1.0 + f32::EPSILON),
+        -(1.0 - f32::EPSILON / 2.0),
+        -2.0,
+        1.0,
+    ];
+    check_targeted_unary_f32::<S>(
+        "powi",
+        &f32_inputs,
+        contracts::POWI_F32_MAX_ULP,
+        |v| v.powi(POWI_ODD_BEYOND_F32),
+        |x| f64::from(x).powi(POWI_ODD_BEYOND_F32) as f32,
+    );
+
+    let f64_inputs = [
+        -1.0f64,
+        -(1.0 + f64::EPSILON),
+        -(1.0 - f64::EPSILON / 2.0),
+        -2.0,
+        1.0,
+    ];
+    for exponent in [POWI_ODD_BEYOND_F32, i32::MAX] {
+        check_targeted_unary_f64::<S>(
+            "powi",
+            &f64_inputs,
+            contracts::POWI_F64_MAX_ULP,
+            |v| v.powi(exponent),
+            |x| x.powf(f64::from(exponent)),
+        );
+    }
+}
+
+simd_math_targeted_all_backends!(
+    powi_odd_exponents_beyond_f32_precision,
+    run_powi_odd_exponents_beyond_f32_precision
+);
+
+#[test]
+fn scalar_powi_keeps_the_sign_of_odd_exponents_beyond_f32_precision() {
+    use crate::math::scalar::{powi_f32, powi_f64};
+
+    assert_eq!(powi_f32(-1.0, POWI_ODD_BEYOND_F32), -1.0);
+    assert_eq!(powi_f32(-1.0, -POWI_ODD_BEYOND_F32), -1.0);
+    assert_eq!(powi_f32(-1.0, POWI_ODD_BEYOND_F32 + 1), 1.0);
+    assert_eq!(
+        powi_f32(-0.0, POWI_ODD_BEYOND_F32).to_bits(),
+        (-0.0f32).to_bits()
+    );
+    assert_eq!(powi_f32(-0.0, -POWI_ODD_BEYOND_F32), f32::NEG_INFINITY);
+    let near_one = powi_f32(-(1.0 + f32::EPSILON), POWI_ODD_BEYOND_F32);
+    let expected = -(f64::from(1.0 + f32::EPSILON).powf(f64::from(POWI_ODD_BEYOND_F32))) as f32;
+    assert_eq!(near_one, expected);
+
+    assert_eq!(powi_f64(-1.0, POWI_ODD_BEYOND_F32), -1.0);
+    assert_eq!(powi_f64(-1.0, i32::MAX), -1.0);
+    assert_eq!(powi_f64(-1.0, i32::MIN), 1.0);
+    assert_eq!(powi_f64(-2.0, -3), -0.125);
+}
+
+fn run_f32_trig_pi_exact_points<S: Simd>() {
+    let mut inputs = Vec::new();
+    let mut expected_sin = Vec::new();
+    let mut expected_cos = Vec::new();
+    let mut expected_tan = Vec::new();
+    for k in -8i32..=8 {
+        let sign = if k < 0 { -1.0f32 } else { 1.0 };
+        inputs.push(k as f32);
+        expected_sin.push(0.0 * sign);
+        expected_cos.push(if k % 2 == 0 { 1.0 } else { -1.0 });
+        expected_tan.push(if k % 2 == 0 { 0.0 * sign } else { -0.0 * sign });
+
+        let half = k as f32 + 0.5;
+        inputs.push(half);
+        expected_sin.push(if k.rem_euclid(2) == 0 { 1.0 } else { -1.0 });
+        expected_cos.push(0.0);
+        expected_tan.push(if k.rem_euclid(2) == 0 {
+            f32::INFINITY
+        } else {
+            f32::NEG_INFINITY
+        });
+    }
+    inputs.push(-0.0);
+    expected_sin.push(-0.0);
+    expected_cos.push(1.0);
+    expected_tan.push(-0.0);
+    for &big in &[16_777_216.0f32, 16_777_218.0, -16_777_218.0, f32::MAX] {
+        inputs.push(big);
+        expected_sin.push(0.0 * big.signum());
+        expected_cos.push(1.0);
+        expected_tan.push(0.0 * big.signum());
+    }
+
+    for (start, chunk) in inputs
+        .chunks(S::Vf32::WIDTH)
+        .enumerate()
+        .map(|(i, c)| (i * S::Vf32::WIDTH, c))
+    {
+        let x = S::Vf32::load_from_slice(chunk);
+        let (s, c, t) = (x.sinpi_u35(), x.cospi_u35(), x.tanpi_u35());
+        for lane in 0..chunk.len() {
+            let input = chunk[lane];
+            for (name, actual, expected) in [
+                ("sinpi_u35", s[lane], expected_sin[start + lane]),
+                ("cospi_u35", c[lane], expected_cos[start + lane]),
+                ("tanpi_u35", t[lane], expected_tan[start + lane]),
+            ] {
+                assert_eq!(
+                    actual.to_bits(),
+                    expected.to_bits(),
+                    "{name}({input:?}) expected {expected:?}, got {actual:?}"
+                );
+            }
+        }
+    }
+}
+
+fn run_f32_trig_pi_neighborhoods<S: Simd>() {
+    let mut inputs = vec![
+        f32::from_bits(1),
+        -f32::from_bits(1),
+        f32::MIN_POSITIVE,
+        0.25,
+        -0.25,
+        0.75,
+        1.0e-20,
+        f32::NAN,
+        f32::INFINITY,
+        f32::NEG_INFINITY,
+    ];
+    for k in -6..=6 {
+        for center in [k as f32, k as f32 + 0.5] {
+            inputs.push(f32::from_bits(center.to_bits().saturating_sub(1)));
+            inputs.push(f32::from_bits(center.to_bits().saturating_add(1)));
+            inputs.push(center - 1.0e-3);
+            inputs.push(center + 1.0e-3);
+        }
+    }
+
+    check_targeted_unary_f32::<S>(
+        "sinpi_u35",
+        &inputs,
+        contracts::SINPI_U35_F32_MAX_ULP,
+        |v| v.sinpi_u35(),
+        |x| (f64::from(x) * ::core::f64::consts::PI).sin() as f32,
+    );
+    check_targeted_unary_f32::<S>(
+        "cospi_u35",
+        &inputs,
+        contracts::COSPI_U35_F32_MAX_ULP,
+        |v| v.cospi_u35(),
+        |x| (f64::from(x) * ::core::f64::consts::PI).cos() as f32,
+    );
+    check_targeted_unary_f32::<S>(
+        "tanpi_u35",
+        &inputs,
+        contracts::TANPI_U35_F32_MAX_ULP,
+        |v| v.tanpi_u35(),
+        |x| (f64::from(x) * ::core::f64::consts::PI).tan() as f32,
+    );
+}
+
+fn run_f32_sincos_matches_sin_and_cos<S: Simd>() {
+    let inputs = [
+        0.0f32,
+        -0.0,
+        1.0,
+        -3.0,
+        ::core::f32::consts::FRAC_PI_2,
+        100.0,
+        8192.0,
+        8193.0,
+        1.0e30,
+        f32::NAN,
+        f32::INFINITY,
+        f32::NEG_INFINITY,
+    ];
+
+    for chunk in inputs.chunks(S::Vf32::WIDTH) {
+        let x = S::Vf32::load_from_slice(chunk);
+        let (s, c) = x.sincos_u35();
+        let (s_ref, c_ref) = (x.sin_u35(), x.cos_u35());
+        for lane in 0..chunk.len() {
+            assert_eq!(
+                s[lane].to_bits(),
+                s_ref[lane].to_bits(),
+                "sin({:?})",
+                chunk[lane]
+            );
+            assert_eq!(
+                c[lane].to_bits(),
+                c_ref[lane].to_bits(),
+                "cos({:?})",
+                chunk[lane]
+            );
+        }
+    }
+}
+
+simd_math_targeted_all_backends!(f32_trig_pi_exact_points, run_f32_trig_pi_exact_points);
+simd_math_targeted_all_backends!(f32_trig_pi_neighborhoods, run_f32_trig_pi_neighborhoods);
+simd_math_targeted_all_backends!(
+    f32_sincos_matches_sin_and_cos,
+    run_f32_sincos_matches_sin_and_cos
+);
+
+fn run_f64_trig_pi_exact_points<S: Simd>() {
+    let mut inputs = Vec::new();
+    let mut expected_sin = Vec::new();
+    let mut expected_cos = Vec::new();
+    let mut expected_tan = Vec::new();
+    for k in -8i32..=8 {
+        let sign = if k < 0 { -1.0f64 } else { 1.0 };
+        inputs.push(f64::from(k));
+        expected_sin.push(0.0 * sign);
+        expected_cos.push(if k % 2 == 0 { 1.0 } else { -1.0 });
+        expected_tan.push(if k % 2 == 0 { 0.0 * sign } else { -0.0 * sign });
+
+        let half = f64::from(k) + 0.5;
+        inputs.push(half);
+        expected_sin.push(if k.rem_euclid(2) == 0 { 1.0 } else { -1.0 });
+        expected_cos.push(0.0);
+        expected_tan.push(if k.rem_euclid(2) == 0 {
+            f64::INFINITY
+        } else {
+            f64::NEG_INFINITY
+        });
+    }
+    inputs.push(-0.0);
+    expected_sin.push(-0.0);
+    expected_cos.push(1.0);
+    expected_tan.push(-0.0);
+    for &big in &[
+        9_007_199_254_740_992.0f64,
+        9_007_199_254_740_994.0,
+        -9_007_199_254_740_994.0,
+        f64::MAX,
+    ] {
+        inputs.push(big);
+        expected_sin.push(0.0 * big.signum());
+        expected_cos.push(1.0);
+        expected_tan.push(0.0 * big.signum());
+    }
+
+    for (start, chunk) in inputs
+        .chunks(S::Vf64::WIDTH)
+        .enumerate()
+        .map(|(i, c)| (i * S::Vf64::WIDTH, c))
+    {
+        let x = S::Vf64::load_from_slice(chunk);
+        let (s, c, t) = (x.sinpi_u35(), x.cospi_u35(), x.tanpi_u35());
+        for lane in 0..chunk.len() {
+            let input = chunk[lane];
+            for (name, actual, expected) in [
+                ("sinpi_u35", s[lane], expected_sin[start + lane]),
+                ("cospi_u35", c[lane], expected_cos[start + lane]),
+                ("tanpi_u35", t[lane], expected_tan[start + lane]),
+            ] {
+                assert_eq!(
+                    actual.to_bits(),
+                    expected.to_bits(),
+                    "{name}({input:?}) expected {expected:?}, got {actual:?}"
+                );
+            }
+        }
+    }
+}
+
+fn run_f64_sincos_matches_sin_and_cos<S: Simd>() {
+    let inputs = [
+        0.0f64,
+        -0.0,
+        1.0,
+        -3.0,
+        ::core::f64::consts::FRAC_PI_2,
+        100.0,
+        1_048_576.0,
+        1_048_577.0,
+        1.0e300,
+        f64::NAN,
+        f64::INFINITY,
+        f64::NEG_INFINITY,
+    ];
+
+    for chunk in inputs.chunks(S::Vf64::WIDTH) {
+        let x = S::Vf64::load_from_slice(chunk);
+        let (s, c) = x.sincos_u35();
+        let (s_ref, c_ref) = (x.sin_u35(), x.cos_u35());
+        for lane in 0..chunk.len() {
+            assert_eq!(
+                s[lane].to_bits(),
+                s_ref[lane].to_bits(),
+                "sin({:?})",
+                chunk[lane]
+            );
+            assert_eq!(
+                c[lane].to_bits(),
+                c_ref[lane].to_bits(),
+                "cos({:?})",
+                chunk[lane]
+            );
+        }
+    }
+}
+
+simd_math_targeted_all_backends!(f64_trig_pi_exact_points, run_f64_trig_pi_exact_points);
+simd_math_targeted_all_backends!(
+    f64_sincos_matches_sin_and_cos,
+    run_f64_sincos_matches_sin_and_cos
+);
+
 #[cfg(any(target_arch = "x86_64", target_arch = "x86"))]
 #[test]
 fn f32_log2_u35_mixed_exception_lanes_avx2() {
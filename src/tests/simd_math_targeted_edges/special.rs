@@ -0,0 +1,201 @@
+use super::*;
+use crate::math::{SimdMathF32Special, SimdMathF64Special};
+
+fn run_f32_erf_range_boundaries<S: Simd>() {
+    let mut inputs = vec![
+        f32::from_bits(1),
+        -f32::from_bits(1),
+        f32::MIN_POSITIVE,
+        -f32::MIN_POSITIVE,
+        1.0e-20,
+        -1.0e-20,
+        0.0,
+        -0.0,
+        f32::NAN,
+        f32::INFINITY,
+        f32::NEG_INFINITY,
+        f32::MAX,
+        f32::MIN,
+    ];
+    for boundary in [0.25f32, 0.843_75, 1.25, 1.0 / 0.35, 6.0, 9.0, 10.0] {
+        for center in [boundary, -boundary] {
+            inputs.push(center);
+            inputs.push(f32::from_bits(center.to_bits() - 1));
+            inputs.push(f32::from_bits(center.to_bits() + 1));
+        }
+    }
+    for i in -640..=1280 {
+        inputs.push(i as f32 / 64.0);
+    }
+
+    check_targeted_unary_f32::<S>(
+        "erf_u35",
+        &inputs,
+        contracts::ERF_U35_F32_MAX_ULP,
+        |v| v.erf_u35(),
+        libm::erff,
+    );
+    check_targeted_unary_f32::<S>(
+        "erfc_u35",
+        &inputs,
+        contracts::ERFC_U35_F32_MAX_ULP,
+        |v| v.erfc_u35(),
+        libm::erfcf,
+    );
+}
+
+fn run_f32_gamma_poles_and_neighborhoods<S: Simd>() {
+    let mut inputs = vec![
+        f32::from_bits(1),
+        -f32::from_bits(1),
+        f32::MIN_POSITIVE,
+        -f32::MIN_POSITIVE,
+        1.0e-30,
+        -1.0e-30,
+        0.0,
+        -0.0,
+        f32::NAN,
+        f32::INFINITY,
+        f32::NEG_INFINITY,
+        f32::MAX,
+        f32::MIN,
+        35.5,
+        1.0e10,
+        1.0e30,
+        1.0e35,
+    ];
+    for k in -32..=40 {
+        let center = k as f32;
+        inputs.push(center);
+        inputs.push(center - 1.0e-3);
+        inputs.push(center + 1.0e-3);
+        inputs.push(center + 0.5);
+        if k != 0 {
+            inputs.push(f32::from_bits(center.to_bits() - 1));
+            inputs.push(f32::from_bits(center.to_bits() + 1));
+        }
+    }
+    for i in -4000..=5000 {
+        inputs.push(i as f32 / 128.0 + 1.0 / 1024.0);
+    }
+
+    check_targeted_unary_f32::<S>(
+        "tgamma_u35",
+        &inputs,
+        contracts::TGAMMA_U35_F32_MAX_ULP,
+        |v| v.tgamma_u35(),
+        libm::tgammaf,
+    );
+    check_targeted_unary_f32::<S>(
+        "lgamma_u35",
+        &inputs,
+        contracts::LGAMMA_U35_F32_MAX_ULP,
+        |v| v.lgamma_u35(),
+        libm::lgammaf,
+    );
+}
+
+fn run_f64_erf_range_boundaries<S: Simd>() {
+    let mut inputs = vec![
+        f64::from_bits(1),
+        -f64::from_bits(1),
+        f64::MIN_POSITIVE,
+        -f64::MIN_POSITIVE,
+        1.0e-200,
+        -1.0e-200,
+        0.0,
+        -0.0,
+        f64::NAN,
+        f64::INFINITY,
+        f64::NEG_INFINITY,
+        f64::MAX,
+        f64::MIN,
+    ];
+    for boundary in [0.25f64, 0.843_75, 1.25, 1.0 / 0.35, 6.0, 26.0, 28.0] {
+        for center in [boundary, -boundary] {
+            inputs.push(center);
+            inputs.push(f64::from_bits(center.to_bits() - 1));
+            inputs.push(f64::from_bits(center.to_bits() + 1));
+        }
+    }
+    for i in -640..=1920 {
+        inputs.push(i as f64 / 64.0);
+    }
+
+    check_targeted_unary_f64::<S>(
+        "erf_u35",
+        &inputs,
+        contracts::ERF_U35_F64_MAX_ULP,
+        |v| v.erf_u35(),
+        libm::erf,
+    );
+    check_targeted_unary_f64::<S>(
+        "erfc_u35",
+        &inputs,
+        contracts::ERFC_U35_F64_MAX_ULP,
+        |v| v.erfc_u35(),
+        libm::erfc,
+    );
+}
+
+fn run_f64_gamma_poles_and_neighborhoods<S: Simd>() {
+    let mut inputs = vec![
+        f64::from_bits(1),
+        -f64::from_bits(1),
+        f64::MIN_POSITIVE,
+        -f64::MIN_POSITIVE,
+        1.0e-300,
+        -1.0e-300,
+        0.0,
+        -0.0,
+        f64::NAN,
+        f64::INFINITY,
+        f64::NEG_INFINITY,
+        f64::MAX,
+        f64::MIN,
+        171.5,
+        1.0e10,
+        1.0e300,
+        1.0e305,
+    ];
+    for k in -32..=42 {
+        let center = k as f64;
+        inputs.push(center);
+        inputs.push(center - 1.0e-6);
+        inputs.push(center + 1.0e-6);
+        inputs.push(center + 0.5);
+        if k != 0 {
+            inputs.push(f64::from_bits(center.to_bits() - 1));
+            inputs.push(f64::from_bits(center.to_bits() + 1));
+        }
+    }
+    for i in -4000..=5400 {
+        inputs.push(i as f64 / 128.0 + 1.0 / 1024.0);
+    }
+
+    check_targeted_unary_f64::<S>(
+        "tgamma_u35",
+        &inputs,
+        contracts::TGAMMA_U35_F64_MAX_ULP,
+        |v| v.tgamma_u35(),
+        libm::tgamma,
+    );
+    check_targeted_unary_f64::<S>(
+        "lgamma_u35",
+        &inputs,
+        contracts::LGAMMA_U35_F64_MAX_ULP,
+        |v| v.lgamma_u35(),
+        libm::lgamma,
+    );
+}
+
+simd_math_targeted_all_backends!(f32_erf_range_boundaries, run_f32_erf_range_boundaries);
+simd_math_targeted_all_backends!(
+    f32_gamma_poles_and_neighborhoods,
+    run_f32_gamma_poles_and_neighborhoods
+);
+simd_math_targeted_all_backends!(f64_erf_range_boundaries, run_f64_erf_range_boundaries);
+simd_math_targeted_all_backends!(
+    f64_gamma_poles_and_neighborhoods,
+    run_f64_gamma_poles_and_neighborhoods
+);
@@ -0,0 +1,62 @@
+#![allow(dead_code, unused_parens)]
+
+use crate::prelude::*;
+
+use super::check_all_engines;
+
+macro_rules! test_transpose {
+    ($name:ident, $simd_ty:ident, $scalar_ty:ty) => {
+        mod $name {
+            use super::*;
+
+            simd_unsafe_generate_all!(
+                fn transpose(tile: &[$scalar_ty], _b: &[$scalar_ty]) -> Vec<$scalar_ty> {
+                    let width = S::$simd_ty::WIDTH;
+                    let mut rows = S::$simd_ty::tile_from_fn(|row| {
+                        S::$simd_ty::load_from_slice(&tile[row * width..])
+                    });
+                    S::$simd_ty::transpose(&mut rows);
+                    let mut out = vec![Default::default(); width * width];
+                    for (row, chunk) in rows.as_ref().iter().zip(out.chunks_mut(width)) {
+                        row.copy_to_slice(chunk);
+                    }
+                    out
+                }
+            );
+
+            fn check(
+                width: usize,
+                transpose: impl Fn(&[$scalar_ty], &[$scalar_ty]) -> Vec<$scalar_ty>,
+            ) {
+                let tile: Vec<$scalar_ty> = (0..width * width)
+                    .map(|i| (i * 7 + 3) as $scalar_ty)
+                    .collect();
+                let expected: Vec<$scalar_ty> = (0..width * width)
+                    .map(|i| tile[(i % width) * width + i / width])
+                    .collect();
+                assert_eq!(
+                    transpose(&tile, &tile),
+                    expected,
+                    "{width}x{width} transpose"
+                );
+                assert_eq!(transpose(&expected, &expected), tile, "transposing twice");
+            }
+
+            #[test]
+            fn transpose_matches_scalar() {
+                check_all_engines!(check, transpose, $simd_ty);
+            }
+        }
+    };
+}
+
+test_transpose!(i8_lanes, Vi8, i8);
+test_transpose!(i16_lanes, Vi16, i16);
+test_transpose!(i32_lanes, Vi32, i32);
+test_transpose!(i64_lanes, Vi64, i64);
+test_transpose!(u8_lanes, Vu8, u8);
+test_transpose!(u16_lanes, Vu16, u16);
+test_transpose!(u32_lanes, Vu32, u32);
+test_transpose!(u64_lanes, Vu64, u64);
+test_transpose!(f32_lanes, Vf32, f32);
+test_transpose!(f64_lanes, Vf64, f64);
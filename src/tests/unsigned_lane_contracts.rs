@@ -0,0 +1,275 @@
+#![allow(dead_code, unused_parens)]
+
+use crate::prelude::*;
+
+macro_rules! test_unsigned_lanes {
+    ($name:ident, $simd_ty:ident, $scalar_ty:ty, $bits:expr) => {
+        mod $name {
+            use super::*;
+
+            simd_unsafe_generate_all!(
+                fn ordering(a: &[$scalar_ty], b: &[$scalar_ty], out: &mut [$scalar_ty]) {
+                    assert_eq!(a.len(), S::$simd_ty::WIDTH);
+                    assert_eq!(out.len(), 6 * S::$simd_ty::WIDTH);
+
+                    let a = S::$simd_ty::load_from_slice(a);
+                    let b = S::$simd_ty::load_from_slice(b);
+                    let results = [
+                        a.cmp_lt(b).to_vector(),
+                        a.cmp_lte(b).to_vector(),
+                        a.cmp_gt(b).to_vector(),
+                        a.cmp_gte(b).to_vector(),
+                        a.min(b),
+                        a.max(b),
+                    ];
+
+                    for (result, chunk) in results.iter().zip(out.chunks_mut(S::$simd_ty::WIDTH)) {
+                        result.copy_to_slice(chunk);
+                    }
+                }
+            );
+
+            simd_unsafe_generate_all!(
+                fn shift_right(values: &[$scalar_ty], count: i32, out: &mut [$scalar_ty]) {
+                    assert_eq!(values.len(), S::$simd_ty::WIDTH);
+                    let v = S::$simd_ty::load_from_slice(values);
+                    v.shr(count).copy_to_slice(out);
+                }
+            );
+
+            simd_unsafe_generate_all!(
+                fn horizontal_sum(values: &[$scalar_ty]) -> u64 {
+                    S::$simd_ty::load_from_slice(values).horizontal_add()
+                }
+            );
+
+            fn lanes(seed: usize, width: usize) -> Vec<$scalar_ty> {
+                let pattern: [$scalar_ty; 8] = [
+                    0,
+                    1,
+                    <$scalar_ty>::MAX,
+                    <$scalar_ty>::MAX - 1,
+                    1 << ($bits - 1),
+                    (1 << ($bits - 1)) - 1,
+                    (1 << ($bits - 1)) + 1,
+                    0x55,
+                ];
+                (0..width)
+                    .map(|i| pattern[(i * 3 + seed) % pattern.len()])
+                    .collect()
+            }
+
+            fn check(
+                width: usize,
+                ordering: impl Fn(&[$scalar_ty], &[$scalar_ty], &mut [$scalar_ty]),
+                shift_right: impl Fn(&[$scalar_ty], i32, &mut [$scalar_ty]),
+                horizontal_sum: impl Fn(&[$scalar_ty]) -> u64,
+            ) {
+                let truth = |value: bool| if value { <$scalar_ty>::MAX } else { 0 };
+
+                for seed in 0..8 {
+                    let a = lanes(seed, width);
+                    let b = lanes(seed + 5, width);
+                    let mut out = vec![0; 6 * width];
+                    ordering(&a, &b, &mut out);
+
+                    let expected: Vec<$scalar_ty> = [
+                        a.iter()
+                            .zip(&b)
+                            .map(|(&x, &y)| truth(x < y))
+                            .collect::<Vec<_>>(),
+                        a.iter().zip(&b).map(|(&x, &y)| truth(x <= y)).collect(),
+                        a.iter().zip(&b).map(|(&x, &y)| truth(x > y)).collect(),
+                        a.iter().zip(&b).map(|(&x, &y)| truth(x >= y)).collect(),
+                        a.iter().zip(&b).map(|(&x, &y)| x.min(y)).collect(),
+                        a.iter().zip(&b).map(|(&x, &y)| x.max(y)).collect(),
+                    ]
+                    .concat();
+                    assert_eq!(out, expected, "ordering mismatch for {a:?} vs {b:?}");
+
+                    for count in [0, 1, $bits - 1, $bits, $bits + 3] {
+                        let mut shifted = vec![0; width];
+                        shift_right(&a, count, &mut shifted);
+                        let expected: Vec<$scalar_ty> =
+                            a.iter().map(|&v| v.wrapping_shr(count as u32)).collect();
+                        assert_eq!(shifted, expected, "shr mismatch for count={count}");
+                    }
+
+                    let expected_sum = a.iter().fold(0u64, |sum, &v| sum.wrapping_add(v as u64));
+                    assert_eq!(horizontal_sum(&a), expected_sum);
+                }
+            }
+
+            #[test]
+            fn unsigned_ordering_and_logical_shift_match_scalar() {
+                #[cfg(any(target_arch = "x86_64", target_arch = "x86"))]
+                {
+                    use crate::engines::{
+                        avx::Avx, avx2::Avx2, avx512::Avx512, scalar::Scalar, sse2::Sse2,
+                        sse41::Sse41,
+                    };
+
+                    check(
+                        <Scalar as Simd>::$simd_ty::WIDTH,
+                        ordering_scalar,
+                        shift_right_scalar,
+                        horizontal_sum_scalar,
+                    );
+                    if std::arch::is_x86_feature_detected!("sse2") {
+                        check(
+                            <Sse2 as Simd>::$simd_ty::WIDTH,
+                            |a, b, out| unsafe { ordering_sse2(a, b, out) },
+                            |v, c, out| unsafe { shift_right_sse2(v, c, out) },
+                            |v| unsafe { horizontal_sum_sse2(v) },
+                        );
+                    }
+                    if std::arch::is_x86_feature_detected!("sse4.1") {
+                        check(
+                            <Sse41 as Simd>::$simd_ty::WIDTH,
+                            |a, b, out| unsafe { ordering_sse41(a, b, out) },
+                            |v, c, out| unsafe { shift_right_sse41(v, c, out) },
+                            |v| unsafe { horizontal_sum_sse41(v) },
+                        );
+                    }
+                    if std::arch::is_x86_feature_detected!("avx") {
+                        check(
+                            <Avx as Simd>::$simd_ty::WIDTH,
+                            |a, b, out| unsafe { ordering_avx(a, b, out) },
+                            |v, c, out| unsafe { shift_right_avx(v, c, out) },
+                            |v| unsafe { horizontal_sum_avx(v) },
+                        );
+                    }
+                    if std::arch::is_x86_feature_detected!("avx2")
+                        && std::arch::is_x86_feature_detected!("fma")
+                    {
+                        check(
+                            <Avx2 as Simd>::$simd_ty::WIDTH,
+                            |a, b, out| unsafe { ordering_avx2(a, b, out) },
+                            |v, c, out| unsafe { shift_right_avx2(v, c, out) },
+                            |v| unsafe { horizontal_sum_avx2(v) },
+                        );
+                    }
+                    if std::arch::is_x86_feature_detected!("avx512f")
+                        && std::arch::is_x86_feature_detected!("avx512bw")
+                        && std::arch::is_x86_feature_detected!("avx512dq")
+                    {
+                        check(
+                            <Avx512 as Simd>::$simd_ty::WIDTH,
+                            |a, b, out| unsafe { ordering_avx512(a, b, out) },
+                            |v, c, out| unsafe { shift_right_avx512(v, c, out) },
+                            |v| unsafe { horizontal_sum_avx512(v) },
+                        );
+                    }
+                }
+
+                #[cfg(target_arch = "aarch64")]
+                check(
+                    <crate::engines::neon::Neon as Simd>::$simd_ty::WIDTH,
+                    |a, b, out| unsafe { ordering_neon(a, b, out) },
+                    |v, c, out| unsafe { shift_right_neon(v, c, out) },
+                    |v| unsafe { horizontal_sum_neon(v) },
+                );
+
+                #[cfg(target_arch = "wasm32")]
+                check(
+                    <crate::engines::wasm32::Wasm as Simd>::$simd_ty::WIDTH,
+                    |a, b, out| unsafe { ordering_wasm(a, b, out) },
+                    |v, c, out| unsafe { shift_right_wasm(v, c, out) },
+                    |v| unsafe { horizontal_sum_wasm(v) },
+                );
+            }
+        }
+    };
+}
+
+test_unsigned_lanes!(u8_lanes, Vu8, u8, 8);
+test_unsigned_lanes!(u16_lanes, Vu16, u16, 16);
+test_unsigned_lanes!(u32_lanes, Vu32, u32, 32);
+test_unsigned_lanes!(u64_lanes, Vu64, u64, 64);
+
+simd_unsafe_generate_all!(
+    fn widen_and_bitcast(input: &[u8]) -> (Vec<u16>, Vec<u32>, Vec<u64>, Vec<i8>) {
+        let bytes = S::Vu8::load_from_slice(input);
+        let (lo16, hi16) = bytes.extend_to_u16();
+        let (lo32, hi32) = lo16.extend_to_u32();
+        let (lo64, hi64) = lo32.extend_to_u64();
+
+        let mut words = vec![0; S::Vu8::WIDTH];
+        lo16.copy_to_slice(&mut words[..S::Vu16::WIDTH]);
+        hi16.copy_to_slice(&mut words[S::Vu16::WIDTH..]);
+        let mut dwords = vec![0; S::Vu16::WIDTH];
+        lo32.copy_to_slice(&mut dwords[..S::Vu32::WIDTH]);
+        hi32.copy_to_slice(&mut dwords[S::Vu32::WIDTH..]);
+        let mut qwords = vec![0; S::Vu32::WIDTH];
+        lo64.copy_to_slice(&mut qwords[..S::Vu64::WIDTH]);
+        hi64.copy_to_slice(&mut qwords[S::Vu64::WIDTH..]);
+
+        let signed = bytes.bitcast_i8();
+        assert_eq!(signed.bitcast_u8().horizontal_add(), bytes.horizontal_add());
+        let mut signed_out = vec![0; S::Vi8::WIDTH];
+        signed.copy_to_slice(&mut signed_out);
+
+        (words, dwords, qwords, signed_out)
+    }
+);
+
+fn check_widen_and_bitcast(
+    width: usize,
+    f: impl Fn(&[u8]) -> (Vec<u16>, Vec<u32>, Vec<u64>, Vec<i8>),
+) {
+    let input: Vec<u8> = (0..width).map(|i| (i * 37 + 200) as u8).collect();
+    let (words, dwords, qwords, signed) = f(&input);
+
+    let expected_words: Vec<u16> = input.iter().map(|&v| v as u16).collect();
+    assert_eq!(words, expected_words);
+    assert_eq!(
+        dwords,
+        expected_words[..dwords.len()]
+            .iter()
+            .map(|&v| v as u32)
+            .collect::<Vec<_>>()
+    );
+    assert_eq!(
+        qwords,
+        expected_words[..qwords.len()]
+            .iter()
+            .map(|&v| v as u64)
+            .collect::<Vec<_>>()
+    );
+    assert_eq!(signed, input.iter().map(|&v| v as i8).collect::<Vec<_>>());
+}
+
+// Widening must zero-extend (high-bit lanes stay large positives) and keep lane order, and
+// bitcasts between signed and unsigned vectors must preserve every bit.
+#[test]
+fn unsigned_widening_zero_extends_in_lane_order() {
+    #[cfg(any(target_arch = "x86_64", target_arch = "x86"))]
+    {
+        check_widen_and_bitcast(1, widen_and_bitcast_scalar);
+        if std::arch::is_x86_feature_detected!("sse2") {
+            check_widen_and_bitcast(16, |v| unsafe { widen_and_bitcast_sse2(v) });
+        }
+        if std::arch::is_x86_feature_detected!("sse4.1") {
+            check_widen_and_bitcast(16, |v| unsafe { widen_and_bitcast_sse41(v) });
+        }
+        if std::arch::is_x86_feature_detected!("avx") {
+            check_widen_and_bitcast(32, |v| unsafe { widen_and_bitcast_avx(v) });
+        }
+        if std::arch::is_x86_feature_detected!("avx2") && std::arch::is_x86_feature_detected!("fma")
+        {
+            check_widen_and_bitcast(32, |v| unsafe { widen_and_bitcast_avx2(v) });
+        }
+        if std::arch::is_x86_feature_detected!("avx512f")
+            && std::arch::is_x86_feature_detected!("avx512bw")
+            && std::arch::is_x86_feature_detected!("avx512dq")
+        {
+            check_widen_and_bitcast(64, |v| unsafe { widen_and_bitcast_avx512(v) });
+        }
+    }
+
+    #[cfg(target_arch = "aarch64")]
+    check_widen_and_bitcast(16, |v| unsafe { widen_and_bitcast_neon(v) });
+
+    #[cfg(target_arch = "wasm32")]
+    check_widen_and_bitcast(16, |v| unsafe { widen_and_bitcast_wasm(v) });
+}